@@ -0,0 +1,73 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_circuits::{
+	arithmetic::mul::mul,
+	builder::{ConstraintSystemBuilder, types::U},
+	unconstrained::unconstrained,
+};
+use binius_core::{constraint_system, fiat_shamir::HasherChallenger};
+use binius_field::{BinaryField1b, BinaryField8b, tower::CanonicalTowerFamily};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+
+/// Proves the circuit of the `mul` gadget test, whose committed columns are all 1-bit.
+fn prove_mul(c: &mut Criterion) {
+	let mut group = c.benchmark_group("mul");
+	group.sample_size(10);
+	for log_n_muls in [12, 14] {
+		group.bench_function(format!("prove/log_n_muls={log_n_muls}"), |b| {
+			b.iter_batched(
+				bumpalo::Bump::new,
+				|allocator| {
+					let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+					let in_a = (0..2)
+						.map(|i| {
+							unconstrained::<BinaryField1b>(
+								&mut builder,
+								format!("in_a_{i}"),
+								log_n_muls,
+							)
+							.unwrap()
+						})
+						.collect();
+					let in_b = (0..2)
+						.map(|i| {
+							unconstrained::<BinaryField1b>(
+								&mut builder,
+								format!("in_b_{i}"),
+								log_n_muls,
+							)
+							.unwrap()
+						})
+						.collect();
+					mul::<BinaryField8b>(&mut builder, "mul", in_a, in_b).unwrap();
+
+					let witness = builder.take_witness().unwrap();
+					let constraint_system = builder.build().unwrap();
+					constraint_system::prove::<
+						U,
+						CanonicalTowerFamily,
+						Groestl256,
+						Groestl256ByteCompression,
+						HasherChallenger<Groestl256>,
+						_,
+					>(
+						&constraint_system,
+						constraint_system::SecurityLevel::new(100, 1),
+						&[],
+						witness,
+						&make_portable_backend(),
+					)
+					.unwrap()
+				},
+				BatchSize::LargeInput,
+			);
+		});
+	}
+	group.finish();
+}
+
+criterion_group!(mul_benches, prove_mul);
+
+criterion_main!(mul_benches);
@@ -0,0 +1,338 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Comparison and selection of unsigned integers in bit-decomposed form.
+//!
+//! The integers are given as vectors of 1-bit oracles, least significant bit first, following the
+//! same convention as [`mul`](super::mul::mul).
+
+use anyhow::ensure;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField1b, Field, TowerField};
+use binius_macros::arith_expr;
+use binius_maybe_rayon::prelude::*;
+
+use crate::builder::ConstraintSystemBuilder;
+
+/// Returns a 1-bit oracle that is 1 on the rows where `xin_bits < yin_bits` and 0 elsewhere.
+///
+/// The comparison is computed with a ripple chain over the bits, starting from the least
+/// significant one. The $i$-th committed bit $lt_i$ indicates whether the low $i + 1$ bits of $x$
+/// are less than those of $y$, and satisfies
+///
+/// $$
+/// lt_i = (1 + x_i + y_i) \cdot lt_{i-1} + (1 + x_i) \cdot y_i,
+/// $$
+///
+/// with $lt_{-1} = 0$. Every link of the chain is a degree 2 constraint.
+pub fn less_than(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: Vec<OracleId>,
+	yin_bits: Vec<OracleId>,
+) -> Result<OracleId, anyhow::Error> {
+	ensure!(
+		xin_bits.len() == yin_bits.len(),
+		"less_than: operands have different bit widths {} and {}",
+		xin_bits.len(),
+		yin_bits.len()
+	);
+	ensure!(!xin_bits.is_empty(), "less_than: operands must have at least one bit");
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(xin_bits.iter().chain(&yin_bits).copied())?;
+	let lt_bits = (0..xin_bits.len())
+		.map(|i| builder.add_committed(format!("lt_{i}"), log_rows, BinaryField1b::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	if let Some(witness) = builder.witness() {
+		let mut prev: Option<OracleId> = None;
+		for (&xin, &yin, &lt) in itertools::izip!(&xin_bits, &yin_bits, &lt_bits) {
+			let xin = witness.get::<BinaryField1b>(xin)?.as_slice::<u32>();
+			let yin = witness.get::<BinaryField1b>(yin)?.as_slice::<u32>();
+			let mut lt_witness = witness.new_column::<BinaryField1b>(lt)?;
+			let lt_out = lt_witness.as_mut_slice::<u32>();
+			match prev {
+				Some(prev) => {
+					let prev = witness.get::<BinaryField1b>(prev)?.as_slice::<u32>();
+					(xin, yin, prev, lt_out)
+						.into_par_iter()
+						.for_each(|(xin, yin, prev, lt)| {
+							*lt = (!(*xin ^ *yin) & *prev) | (!*xin & *yin);
+						});
+				}
+				None => {
+					(xin, yin, lt_out)
+						.into_par_iter()
+						.for_each(|(xin, yin, lt)| {
+							*lt = !*xin & *yin;
+						});
+				}
+			}
+			prev = Some(lt);
+		}
+	}
+
+	builder.assert_zero(
+		"lt_0",
+		[xin_bits[0], yin_bits[0], lt_bits[0]],
+		arith_expr!([x, y, lt] = lt - (1 + x) * y).convert_field(),
+	);
+	for i in 1..xin_bits.len() {
+		builder.assert_zero(
+			format!("lt_{i}"),
+			[xin_bits[i], yin_bits[i], lt_bits[i - 1], lt_bits[i]],
+			arith_expr!([x, y, prev, lt] = lt - (1 + x + y) * prev - (1 + x) * y).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(*lt_bits.last().expect("operands are non-empty"))
+}
+
+/// Returns a 1-bit oracle that is 1 on the rows where `xin_bits >= yin_bits` and 0 elsewhere.
+///
+/// This is the negation of [`less_than`], and adds no constraints beyond it.
+pub fn greater_or_equal(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: Vec<OracleId>,
+	yin_bits: Vec<OracleId>,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let lt = less_than(builder, "less_than", xin_bits, yin_bits)?;
+	let log_rows = builder.log_rows([lt])?;
+	let gte = builder.add_linear_combination_with_offset(
+		"gte",
+		log_rows,
+		Field::ONE,
+		[(lt, Field::ONE)],
+	)?;
+	if let Some(witness) = builder.witness() {
+		(
+			witness.get::<BinaryField1b>(lt)?.as_slice::<u32>(),
+			witness
+				.new_column::<BinaryField1b>(gte)?
+				.as_mut_slice::<u32>(),
+		)
+			.into_par_iter()
+			.for_each(|(lt, gte)| {
+				*gte = !*lt;
+			});
+	}
+	builder.pop_namespace();
+	Ok(gte)
+}
+
+/// Returns a 1-bit oracle indicating `xin_bits < yin_bits` for 32-bit operands.
+pub fn u32_less_than(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: Vec<OracleId>,
+	yin_bits: Vec<OracleId>,
+) -> Result<OracleId, anyhow::Error> {
+	ensure_u32_operands(&xin_bits, &yin_bits)?;
+	less_than(builder, name, xin_bits, yin_bits)
+}
+
+/// Returns a 1-bit oracle indicating `xin_bits >= yin_bits` for 32-bit operands.
+pub fn u32_gte(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: Vec<OracleId>,
+	yin_bits: Vec<OracleId>,
+) -> Result<OracleId, anyhow::Error> {
+	ensure_u32_operands(&xin_bits, &yin_bits)?;
+	greater_or_equal(builder, name, xin_bits, yin_bits)
+}
+
+fn ensure_u32_operands(xin_bits: &[OracleId], yin_bits: &[OracleId]) -> Result<(), anyhow::Error> {
+	ensure!(
+		xin_bits.len() == 32 && yin_bits.len() == 32,
+		"u32 operands must have 32 bits, got {} and {}",
+		xin_bits.len(),
+		yin_bits.len()
+	);
+	Ok(())
+}
+
+/// Selects between two bit vectors row by row.
+///
+/// Returns the bits of `then_bits` on the rows where the 1-bit oracle `cond` is 1, and the bits
+/// of `else_bits` elsewhere. Each output bit is committed and constrained by
+/// $out_i = e_i + c \cdot (t_i + e_i)$.
+pub fn select(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	cond: OracleId,
+	then_bits: Vec<OracleId>,
+	else_bits: Vec<OracleId>,
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	ensure!(
+		then_bits.len() == else_bits.len(),
+		"select: operands have different bit widths {} and {}",
+		then_bits.len(),
+		else_bits.len()
+	);
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(
+		[cond]
+			.into_iter()
+			.chain(then_bits.iter().chain(&else_bits).copied()),
+	)?;
+	let out_bits = (0..then_bits.len())
+		.map(|i| builder.add_committed(format!("out_{i}"), log_rows, BinaryField1b::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	if let Some(witness) = builder.witness() {
+		let cond = witness.get::<BinaryField1b>(cond)?.as_slice::<u32>();
+		for (&then_bit, &else_bit, &out) in itertools::izip!(&then_bits, &else_bits, &out_bits) {
+			(
+				cond,
+				witness.get::<BinaryField1b>(then_bit)?.as_slice::<u32>(),
+				witness.get::<BinaryField1b>(else_bit)?.as_slice::<u32>(),
+				witness
+					.new_column::<BinaryField1b>(out)?
+					.as_mut_slice::<u32>(),
+			)
+				.into_par_iter()
+				.for_each(|(cond, then_bit, else_bit, out)| {
+					*out = (*cond & *then_bit) | (!*cond & *else_bit);
+				});
+		}
+	}
+
+	for (i, (&then_bit, &else_bit, &out)) in
+		itertools::izip!(&then_bits, &else_bits, &out_bits).enumerate()
+	{
+		builder.assert_zero(
+			format!("select_{i}"),
+			[cond, then_bit, else_bit, out],
+			arith_expr!([c, t, e, out] = out - e - c * (t + e)).convert_field(),
+		);
+	}
+
+	builder.pop_namespace();
+	Ok(out_bits)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::packed::get_packed_slice;
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::{test_utils::test_circuit, witness};
+
+	fn u32_bits(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		values: &[u32],
+	) -> Vec<OracleId> {
+		let bits = (0..32)
+			.map(|i| {
+				builder.add_committed(format!("{name}_{i}"), log_size, BinaryField1b::TOWER_LEVEL)
+			})
+			.collect::<Vec<_>>();
+		if let Some(witness) = builder.witness() {
+			for (i, &bit) in bits.iter().enumerate() {
+				let mut column = witness.new_column::<BinaryField1b>(bit).unwrap();
+				for (word, chunk) in column
+					.as_mut_slice::<u32>()
+					.iter_mut()
+					.zip(values.chunks(32))
+				{
+					*word = chunk
+						.iter()
+						.enumerate()
+						.fold(0, |acc, (j, value)| acc | (((value >> i) & 1) << j));
+				}
+			}
+		}
+		bits
+	}
+
+	fn row_bit(witness: &witness::Builder, id: OracleId, row: usize) -> bool {
+		let packed = witness.get::<BinaryField1b>(id).unwrap().packed();
+		get_packed_slice(packed, row) == BinaryField1b::ONE
+	}
+
+	fn random_values(log_size: usize, rng: &mut StdRng) -> Vec<u32> {
+		(0..1 << log_size).map(|_| rng.r#gen()).collect()
+	}
+
+	#[test]
+	fn test_u32_less_than_equal_operands() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let xs = random_values(log_size, &mut StdRng::seed_from_u64(0));
+			let xin = u32_bits(builder, "x", log_size, &xs);
+			let lt = u32_less_than(builder, "lt", xin.clone(), xin.clone())?;
+			let gte = u32_gte(builder, "gte", xin.clone(), xin)?;
+			if let Some(witness) = builder.witness() {
+				for row in 0..1 << log_size {
+					assert!(!row_bit(witness, lt, row));
+					assert!(row_bit(witness, gte, row));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u32_less_than_random() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let mut rng = StdRng::seed_from_u64(0);
+			let xs = random_values(log_size, &mut rng);
+			let ys = random_values(log_size, &mut rng);
+			let xin = u32_bits(builder, "x", log_size, &xs);
+			let yin = u32_bits(builder, "y", log_size, &ys);
+			let lt = u32_less_than(builder, "lt", xin.clone(), yin.clone())?;
+			let gte = u32_gte(builder, "gte", xin, yin)?;
+			if let Some(witness) = builder.witness() {
+				for row in 0..1 << log_size {
+					assert_eq!(row_bit(witness, lt, row), xs[row] < ys[row]);
+					assert_eq!(row_bit(witness, gte, row), xs[row] >= ys[row]);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_select() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let mut rng = StdRng::seed_from_u64(0);
+			let xs = random_values(log_size, &mut rng);
+			let ys = random_values(log_size, &mut rng);
+			let xin = u32_bits(builder, "x", log_size, &xs);
+			let yin = u32_bits(builder, "y", log_size, &ys);
+			let lt = u32_less_than(builder, "lt", xin.clone(), yin.clone())?;
+			let min = select(builder, "min", lt, xin, yin)?;
+			if let Some(witness) = builder.witness() {
+				for row in 0..1 << log_size {
+					let expected = xs[row].min(ys[row]);
+					for (i, &bit) in min.iter().enumerate() {
+						assert_eq!(row_bit(witness, bit, row), (expected >> i) & 1 == 1);
+					}
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u32_less_than_rejects_wrong_width() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let bits = (0..16)
+			.map(|i| builder.add_committed(format!("x_{i}"), 10, BinaryField1b::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+		assert!(u32_less_than(&mut builder, "lt", bits.clone(), bits).is_err());
+	}
+}
@@ -1,6 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+pub mod compare;
+pub mod modmul;
 pub mod mul;
+pub mod shift;
 pub mod static_exp;
 pub mod u32;
 
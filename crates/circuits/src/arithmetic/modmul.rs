@@ -0,0 +1,237 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Modular multiplication of 256-bit integers.
+//!
+//! The product $a \cdot b \bmod n$ is proven by committing the quotient $q$ and the remainder $r$
+//! of the division of $a \cdot b$ by $n$, and checking that $a \cdot b = q \cdot n + r$ as 512-bit
+//! integers, with $r < n$. The products are proven with the exponentiation-based multiplication of
+//! [`super::mul`], and all the integers are decomposed in committed bit columns, which range checks
+//! every limb.
+
+use std::array;
+
+use alloy_primitives::{U256, U512};
+use anyhow::Error;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField1b, Field, TowerField};
+use binius_macros::arith_expr;
+use binius_utils::bail;
+
+use super::mul::{add_bits, mul_limbs};
+use crate::{builder::ConstraintSystemBuilder, transparent};
+
+/// Computes $a \cdot b \bmod n$ for 256-bit integers given in little-endian bit columns.
+///
+/// The quotient $\lfloor a \cdot b / n \rfloor$ must fit in 256 bits, which holds when $a < n$ or
+/// $b < n$, otherwise the witness generation fails.
+pub fn modmul(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: [OracleId; 256],
+	yin_bits: [OracleId; 256],
+	modulus: U256,
+) -> Result<[OracleId; 256], anyhow::Error> {
+	if modulus.is_zero() {
+		bail!(anyhow::anyhow!("modulus must be non-zero"));
+	}
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(xin_bits.iter().chain(&yin_bits).copied())?;
+
+	let quotient: [OracleId; 256] = array::from_fn(|i| {
+		builder.add_committed(format!("quotient_{i}"), log_rows, BinaryField1b::TOWER_LEVEL)
+	});
+	let remainder: [OracleId; 256] = array::from_fn(|i| {
+		builder.add_committed(format!("remainder_{i}"), log_rows, BinaryField1b::TOWER_LEVEL)
+	});
+	// The difference n - 1 - r, which is non-negative iff r < n.
+	let slack: [OracleId; 256] = array::from_fn(|i| {
+		builder.add_committed(format!("slack_{i}"), log_rows, BinaryField1b::TOWER_LEVEL)
+	});
+	let modulus_bits = (0..256)
+		.map(|i| {
+			let bit = if modulus.bit(i) {
+				BinaryField1b::ONE
+			} else {
+				BinaryField1b::ZERO
+			};
+			transparent::constant(builder, format!("modulus_{i}"), log_rows, bit)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	if let Some(witness) = builder.witness() {
+		let xs = bits_to_u512(witness, &xin_bits, log_rows)?;
+		let ys = bits_to_u512(witness, &yin_bits, log_rows)?;
+		let modulus = u256_to_u512(modulus);
+
+		let mut quotient_columns =
+			array_util::try_map(quotient, |id| witness.new_column::<BinaryField1b>(id))?;
+		let mut remainder_columns =
+			array_util::try_map(remainder, |id| witness.new_column::<BinaryField1b>(id))?;
+		let mut slack_columns =
+			array_util::try_map(slack, |id| witness.new_column::<BinaryField1b>(id))?;
+		let mut quotient_columns = quotient_columns
+			.iter_mut()
+			.map(|column| column.as_mut_slice::<u8>())
+			.collect::<Vec<_>>();
+		let mut remainder_columns = remainder_columns
+			.iter_mut()
+			.map(|column| column.as_mut_slice::<u8>())
+			.collect::<Vec<_>>();
+		let mut slack_columns = slack_columns
+			.iter_mut()
+			.map(|column| column.as_mut_slice::<u8>())
+			.collect::<Vec<_>>();
+
+		for (row, (x, y)) in xs.into_iter().zip(ys).enumerate() {
+			let product = x * y;
+			let quotient = product / modulus;
+			let remainder = product % modulus;
+			if quotient.bit_len() > 256 {
+				bail!(anyhow::anyhow!("the quotient of row {row} does not fit in 256 bits"));
+			}
+			let slack = modulus - U512::from(1u8) - remainder;
+
+			set_row_bits(&mut quotient_columns, row, quotient);
+			set_row_bits(&mut remainder_columns, row, remainder);
+			set_row_bits(&mut slack_columns, row, slack);
+		}
+	}
+
+	let product = mul_limbs(builder, "product", &xin_bits, &yin_bits)?;
+	let quotient_product = mul_limbs(builder, "quotient_product", &quotient, &modulus_bits)?;
+	let sum = add_bits(builder, "sum", &quotient_product, &remainder, 0, 512)?;
+	for (i, (&lhs, &rhs)) in product.iter().zip(&sum).enumerate() {
+		builder.assert_zero(
+			format!("product_{i}"),
+			[lhs, rhs],
+			arith_expr!([lhs, rhs] = lhs - rhs).convert_field(),
+		);
+	}
+
+	// r + (n - 1 - r) = n - 1 without overflow implies r < n.
+	let modulus_minus_one = modulus - U256::from(1u8);
+	let remainder_bound = add_bits(builder, "remainder_bound", &remainder, &slack, 0, 256)?;
+	for (i, &bit) in remainder_bound.iter().enumerate() {
+		if modulus_minus_one.bit(i) {
+			builder.assert_zero(
+				format!("remainder_bound_{i}"),
+				[bit],
+				arith_expr!([bit] = bit - 1).convert_field(),
+			);
+		} else {
+			builder.assert_zero(
+				format!("remainder_bound_{i}"),
+				[bit],
+				arith_expr!([bit] = bit).convert_field(),
+			);
+		}
+	}
+
+	builder.pop_namespace();
+	Ok(remainder)
+}
+
+fn u256_to_u512(value: U256) -> U512 {
+	let mut result = U512::ZERO;
+	result.as_limbs_mut()[..4].copy_from_slice(value.as_limbs());
+	result
+}
+
+/// Reads the rows of little-endian bit columns as integers.
+fn bits_to_u512(
+	witness: &crate::builder::witness::Builder,
+	bits: &[OracleId],
+	log_rows: usize,
+) -> Result<Vec<U512>, Error> {
+	let mut numbers = vec![U512::ZERO; 1 << log_rows];
+	for (i, &id) in bits.iter().enumerate() {
+		let column = witness.get::<BinaryField1b>(id)?.as_slice::<u8>();
+		for (row, number) in numbers.iter_mut().enumerate() {
+			if (column[row / 8] >> (row % 8)) & 1 == 1 {
+				number.set_bit(i, true);
+			}
+		}
+	}
+	Ok(numbers)
+}
+
+/// Writes the low bits of `value` in a row of little-endian bit columns.
+fn set_row_bits(columns: &mut [&mut [u8]], row: usize, value: U512) {
+	for (i, column) in columns.iter_mut().enumerate() {
+		let mask = 1 << (row % 8);
+		if value.bit(i) {
+			column[row / 8] |= mask;
+		} else {
+			column[row / 8] &= !mask;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloy_primitives::U256;
+	use binius_core::oracle::OracleId;
+	use binius_field::{BinaryField1b, Field};
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::{bits_to_u512, modmul, u256_to_u512};
+	use crate::{
+		builder::{ConstraintSystemBuilder, test_utils::test_circuit},
+		transparent,
+		unconstrained::unconstrained,
+	};
+
+	/// The order of the secp256k1 group.
+	fn secp256k1_order() -> U256 {
+		U256::from_be_slice(&[
+			0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+			0xff, 0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c,
+			0xd0, 0x36, 0x41, 0x41,
+		])
+	}
+
+	fn unconstrained_bits(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+	) -> [OracleId; 256] {
+		std::array::from_fn(|i| {
+			unconstrained::<BinaryField1b>(builder, format!("{name}_{i}"), log_size).unwrap()
+		})
+	}
+
+	#[test]
+	fn test_modmul_secp256k1_order() {
+		test_circuit(|builder| {
+			let log_size = 7;
+			let mut xin = unconstrained_bits(builder, "x", log_size);
+			let yin = unconstrained_bits(builder, "y", log_size);
+			// x < 2^255 < n, so that the quotient fits in 256 bits.
+			xin[255] = transparent::constant(builder, "x_255", log_size, BinaryField1b::ZERO)?;
+			let result = modmul(builder, "modmul", xin, yin, secp256k1_order())?;
+			if let Some(witness) = builder.witness() {
+				let xs = bits_to_u512(witness, &xin, log_size)?;
+				let ys = bits_to_u512(witness, &yin, log_size)?;
+				let results = bits_to_u512(witness, &result, log_size)?;
+				let modulus = u256_to_u512(secp256k1_order());
+				for ((x, y), result) in xs.into_iter().zip(ys).zip(results) {
+					assert_eq!(x * y % modulus, result);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_modmul_rejects_large_quotient() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let modulus = U256::from(rng.r#gen::<u64>() | 1);
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let xin = unconstrained_bits(&mut builder, "x", 7);
+		let yin = unconstrained_bits(&mut builder, "y", 7);
+		assert!(modmul(&mut builder, "modmul", xin, yin, modulus).is_err());
+	}
+}
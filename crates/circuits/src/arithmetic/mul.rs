@@ -8,7 +8,7 @@
 //!
 //! You can read more information in [Integer Multiplication in Binius](https://www.irreducible.com/posts/integer-multiplication-in-binius).
 
-use std::array;
+use std::{array, marker::PhantomData};
 
 use anyhow::Error;
 use binius_core::oracle::OracleId;
@@ -25,64 +25,146 @@ use binius_utils::bail;
 use itertools::izip;
 
 use super::static_exp::u16_static_exp_lookups;
-use crate::builder::{
-	ConstraintSystemBuilder,
-	types::{F, U},
+use crate::{
+	builder::{
+		ConstraintSystemBuilder,
+		types::{F, U},
+		witness,
+	},
+	gadget::Gadget,
 };
 
-pub fn mul<FExpBase>(
-	builder: &mut ConstraintSystemBuilder,
-	name: impl ToString,
-	xin_bits: Vec<OracleId>,
-	yin_bits: Vec<OracleId>,
-) -> Result<Vec<OracleId>, anyhow::Error>
+/// Multiplication of two little-endian bit columns, see the [module documentation](self).
+///
+/// The input is the pair of operands and the output holds the bits of the product.
+pub struct Mul<FExpBase> {
+	name: String,
+	_f_marker: PhantomData<FExpBase>,
+}
+
+impl<FExpBase> Mul<FExpBase> {
+	pub fn new(name: impl ToString) -> Self {
+		Self {
+			name: name.to_string(),
+			_f_marker: PhantomData,
+		}
+	}
+}
+
+impl<FExpBase> Gadget for Mul<FExpBase>
 where
 	FExpBase: TowerField,
 	F: From<FExpBase>,
 {
-	let name = name.to_string();
-
-	let log_rows = builder.log_rows([xin_bits.clone(), yin_bits.clone()].into_iter().flatten())?;
-
-	// $g^x$
-	let xin_exp_result_id =
-		builder.add_committed(format!("{name} xin_exp_result"), log_rows, FExpBase::TOWER_LEVEL);
-
-	// $(g^x)^y$
-	let yin_exp_result_id =
-		builder.add_committed(format!("{name} yin_exp_result"), log_rows, FExpBase::TOWER_LEVEL);
+	type Input = (Vec<OracleId>, Vec<OracleId>);
+	type Output = Vec<OracleId>;
 
-	// $g^{clow}$
-	let cout_low_exp_result_id = builder.add_committed(
-		format!("{name} cout_low_exp_result"),
-		log_rows,
-		FExpBase::TOWER_LEVEL,
-	);
+	fn name(&self) -> &str {
+		&self.name
+	}
 
-	// $(g^{2^{len(clow)}})^{chigh}$
-	let cout_high_exp_result_id = builder.add_committed(
-		format!("{name} cout_high_exp_result"),
-		log_rows,
-		FExpBase::TOWER_LEVEL,
-	);
+	fn constrain(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		(xin_bits, yin_bits): &Self::Input,
+	) -> Result<Vec<OracleId>, anyhow::Error> {
+		let name = &self.name;
+
+		let log_rows = builder.log_rows(xin_bits.iter().chain(yin_bits).copied())?;
+
+		// $g^x$
+		let xin_exp_result_id = builder.add_committed(
+			format!("{name} xin_exp_result"),
+			log_rows,
+			FExpBase::TOWER_LEVEL,
+		);
+
+		// $(g^x)^y$
+		let yin_exp_result_id = builder.add_committed(
+			format!("{name} yin_exp_result"),
+			log_rows,
+			FExpBase::TOWER_LEVEL,
+		);
+
+		// $g^{clow}$
+		let cout_low_exp_result_id = builder.add_committed(
+			format!("{name} cout_low_exp_result"),
+			log_rows,
+			FExpBase::TOWER_LEVEL,
+		);
+
+		// $(g^{2^{len(clow)}})^{chigh}$
+		let cout_high_exp_result_id = builder.add_committed(
+			format!("{name} cout_high_exp_result"),
+			log_rows,
+			FExpBase::TOWER_LEVEL,
+		);
+
+		let result_bits = xin_bits.len() + yin_bits.len();
+
+		if result_bits > FExpBase::N_BITS {
+			bail!(anyhow::anyhow!("FExpBase to small"));
+		}
+
+		let cout_bits = (0..result_bits)
+			.map(|i| {
+				builder.add_committed(
+					format!("{i} bit of {name}"),
+					log_rows,
+					BinaryField1b::TOWER_LEVEL,
+				)
+			})
+			.collect::<Vec<_>>();
 
-	let result_bits = xin_bits.len() + yin_bits.len();
+		// Handling special case when $x == 0$ $y == 0$ $c == 2^{2 \cdot n} -1$
+		builder.assert_zero(
+			name,
+			[xin_bits[0], yin_bits[0], cout_bits[0]],
+			arith_expr!([xin, yin, cout] = xin * yin - cout).convert_field(),
+		);
+
+		// $(g^x)^y = g^{clow} * (g^{2^{len(clow)}})^{chigh}$
+		builder.assert_zero(
+			name,
+			[
+				yin_exp_result_id,
+				cout_low_exp_result_id,
+				cout_high_exp_result_id,
+			],
+			arith_expr!([yin, low, high] = low * high - yin).convert_field(),
+		);
+
+		let (cout_low_bits, cout_high_bits) = cout_bits.split_at(cout_bits.len() / 2);
+
+		builder.add_static_exp(
+			xin_bits.clone(),
+			xin_exp_result_id,
+			FExpBase::MULTIPLICATIVE_GENERATOR.into(),
+			FExpBase::TOWER_LEVEL,
+		);
+		builder.add_dynamic_exp(yin_bits.clone(), yin_exp_result_id, xin_exp_result_id);
+		builder.add_static_exp(
+			cout_low_bits.to_vec(),
+			cout_low_exp_result_id,
+			FExpBase::MULTIPLICATIVE_GENERATOR.into(),
+			FExpBase::TOWER_LEVEL,
+		);
+		builder.add_static_exp(
+			cout_high_bits.to_vec(),
+			cout_high_exp_result_id,
+			exp_pow2(FExpBase::MULTIPLICATIVE_GENERATOR, cout_low_bits.len()).into(),
+			FExpBase::TOWER_LEVEL,
+		);
 
-	if result_bits > FExpBase::N_BITS {
-		bail!(anyhow::anyhow!("FExpBase to small"));
+		Ok(cout_bits)
 	}
 
-	let cout_bits = (0..result_bits)
-		.map(|i| {
-			builder.add_committed(
-				format!("{i} bit of {name}"),
-				log_rows,
-				BinaryField1b::TOWER_LEVEL,
-			)
-		})
-		.collect::<Vec<_>>();
-
-	if let Some(witness) = builder.witness() {
+	fn fill_witness(
+		&self,
+		witness: &mut witness::Builder,
+		(xin_bits, yin_bits): &Self::Input,
+		cout_bits: &Self::Output,
+	) -> Result<(), anyhow::Error> {
 		let xin_columns = xin_bits
 			.iter()
 			.map(|&id| witness.get::<BinaryField1b>(id).map(|x| x.packed()))
@@ -102,7 +184,7 @@ where
 		let mut cout_columns = cout_bits
 			.iter()
 			.map(|&id| witness.new_column::<BinaryField1b>(id))
-			.collect::<Vec<_>>();
+			.collect::<Result<Vec<_>, _>>()?;
 
 		let mut cout_columns_u8 = cout_columns
 			.iter_mut()
@@ -110,49 +192,208 @@ where
 			.collect::<Vec<_>>();
 
 		numbers_to_columns(&result, &mut cout_columns_u8);
+		Ok(())
 	}
+}
 
-	// Handling special case when $x == 0$ $y == 0$ $c == 2^{2 \cdot n} -1$
-	builder.assert_zero(
-		name.clone(),
-		[xin_bits[0], yin_bits[0], cout_bits[0]],
-		arith_expr!([xin, yin, cout] = xin * yin - cout).convert_field(),
-	);
+pub fn mul<FExpBase>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: Vec<OracleId>,
+	yin_bits: Vec<OracleId>,
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	FExpBase: TowerField,
+	F: From<FExpBase>,
+{
+	Mul::<FExpBase>::new(name).build(builder, (xin_bits, yin_bits))
+}
 
-	// $(g^x)^y = g^{clow} * (g^{2^{len(clow)}})^{chigh}$
-	builder.assert_zero(
-		name,
-		[
-			yin_exp_result_id,
-			cout_low_exp_result_id,
-			cout_high_exp_result_id,
-		],
-		arith_expr!([yin, low, high] = low * high - yin).convert_field(),
-	);
+/// Multiplies two 64-bit integers given in little-endian bit columns.
+///
+/// [`mul`] can only prove products of up to 128 bits, so the operands are split into 32-bit
+/// limbs, the limb products are proven with [`mul`], and the 128-bit product is recombined from
+/// them with constrained ripple-carry additions.
+pub fn mul_u64(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: [OracleId; 64],
+	yin_bits: [OracleId; 64],
+) -> Result<[OracleId; 128], anyhow::Error> {
+	let result = mul_limbs(builder, name, &xin_bits, &yin_bits)?;
+	Ok(result
+		.try_into()
+		.expect("the product of two 64-bit integers has 128 bits"))
+}
 
-	let (cout_low_bits, cout_high_bits) = cout_bits.split_at(cout_bits.len() / 2);
+/// Multiplies two 128-bit integers given in little-endian bit columns.
+///
+/// See [`mul_u64`].
+pub fn mul_u128(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: [OracleId; 128],
+	yin_bits: [OracleId; 128],
+) -> Result<[OracleId; 256], anyhow::Error> {
+	let result = mul_limbs(builder, name, &xin_bits, &yin_bits)?;
+	Ok(result
+		.try_into()
+		.expect("the product of two 128-bit integers has 256 bits"))
+}
 
-	builder.add_static_exp(
-		xin_bits,
-		xin_exp_result_id,
-		FExpBase::MULTIPLICATIVE_GENERATOR.into(),
-		FExpBase::TOWER_LEVEL,
-	);
-	builder.add_dynamic_exp(yin_bits, yin_exp_result_id, xin_exp_result_id);
-	builder.add_static_exp(
-		cout_low_bits.to_vec(),
-		cout_low_exp_result_id,
-		FExpBase::MULTIPLICATIVE_GENERATOR.into(),
-		FExpBase::TOWER_LEVEL,
-	);
-	builder.add_static_exp(
-		cout_high_bits.to_vec(),
-		cout_high_exp_result_id,
-		exp_pow2(FExpBase::MULTIPLICATIVE_GENERATOR, cout_low_bits.len()).into(),
-		FExpBase::TOWER_LEVEL,
-	);
+/// The number of bits of the limbs multiplied with [`mul`].
+///
+/// The product of two limbs fits in the exponent of the multiplicative group of `BinaryField64b`.
+const LIMB_BITS: usize = 32;
 
-	Ok(cout_bits)
+/// Schoolbook multiplication of equally sized operands, splitting them in halves down to
+/// [`LIMB_BITS`].
+pub(super) fn mul_limbs(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: &[OracleId],
+	yin_bits: &[OracleId],
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	if xin_bits.len() != yin_bits.len() {
+		bail!(anyhow::anyhow!("operands must have the same number of bits"));
+	}
+	let n_bits = xin_bits.len();
+	if n_bits <= LIMB_BITS {
+		return mul::<BinaryField64b>(builder, name, xin_bits.to_vec(), yin_bits.to_vec());
+	}
+
+	builder.push_namespace(name);
+	let half = n_bits / 2;
+	let (x_low, x_high) = xin_bits.split_at(half);
+	let (y_low, y_high) = yin_bits.split_at(half);
+
+	let low = mul_limbs(builder, "low", x_low, y_low)?;
+	let cross_0 = mul_limbs(builder, "cross_0", x_low, y_high)?;
+	let cross_1 = mul_limbs(builder, "cross_1", x_high, y_low)?;
+	let high = mul_limbs(builder, "high", x_high, y_high)?;
+
+	// Every partial sum is bounded by the product, so none overflows 2 * n_bits bits.
+	let sum = add_bits(builder, "sum_0", &low, &cross_0, half, 2 * n_bits)?;
+	let sum = add_bits(builder, "sum_1", &sum, &cross_1, half, 2 * n_bits)?;
+	let result = add_bits(builder, "sum_2", &sum, &high, n_bits, 2 * n_bits)?;
+
+	builder.pop_namespace();
+	Ok(result)
+}
+
+/// Adds `xin_bits` and `yin_bits` shifted left by `offset`, with a ripple-carry adder.
+///
+/// The sum has at most `max_bits` bits, which is enforced by constraining the carry out of the
+/// last bit to zero. Bits of the sum with a single summand reuse the summand column, the other
+/// bits are linear combinations, and the carries are committed.
+pub(super) fn add_bits(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	xin_bits: &[OracleId],
+	yin_bits: &[OracleId],
+	offset: usize,
+	max_bits: usize,
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(xin_bits.iter().chain(yin_bits).copied())?;
+
+	let mut sum_bits = Vec::with_capacity(max_bits);
+	let mut carry = None;
+	for bit in 0..max_bits {
+		let summands = [
+			xin_bits.get(bit).copied(),
+			bit.checked_sub(offset)
+				.and_then(|i| yin_bits.get(i).copied()),
+			carry,
+		]
+		.into_iter()
+		.flatten()
+		.collect::<Vec<_>>();
+
+		let (sum, next_carry) = add_bit(builder, bit, &summands, log_rows)?;
+		let Some(sum) = sum else {
+			break;
+		};
+		sum_bits.push(sum);
+		carry = next_carry;
+	}
+
+	if let Some(carry) = carry {
+		builder.assert_zero("overflow", [carry], arith_expr!([c] = c).convert_field());
+	}
+
+	builder.pop_namespace();
+	Ok(sum_bits)
+}
+
+/// Adds the summand bits, returning the sum bit and the carry, if any.
+fn add_bit(
+	builder: &mut ConstraintSystemBuilder,
+	bit: usize,
+	summands: &[OracleId],
+	log_rows: usize,
+) -> Result<(Option<OracleId>, Option<OracleId>), anyhow::Error> {
+	match *summands {
+		[] => Ok((None, None)),
+		[summand] => Ok((Some(summand), None)),
+		[x, y] => {
+			let sum = builder.add_linear_combination(
+				format!("sum_{bit}"),
+				log_rows,
+				[(x, F::ONE), (y, F::ONE)],
+			)?;
+			let carry =
+				builder.add_committed(format!("carry_{bit}"), log_rows, BinaryField1b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let x = witness.get::<BinaryField1b>(x)?.as_slice::<u8>();
+				let y = witness.get::<BinaryField1b>(y)?.as_slice::<u8>();
+				let mut sum = witness.new_column::<BinaryField1b>(sum)?;
+				let mut carry = witness.new_column::<BinaryField1b>(carry)?;
+				izip!(x, y, sum.as_mut_slice::<u8>(), carry.as_mut_slice::<u8>()).for_each(
+					|(x, y, sum, carry)| {
+						*sum = x ^ y;
+						*carry = x & y;
+					},
+				);
+			}
+			builder.assert_zero(
+				format!("carry_{bit}"),
+				[x, y, carry],
+				arith_expr!([x, y, carry] = x * y - carry).convert_field(),
+			);
+			Ok((Some(sum), Some(carry)))
+		}
+		[x, y, z] => {
+			let sum = builder.add_linear_combination(
+				format!("sum_{bit}"),
+				log_rows,
+				[(x, F::ONE), (y, F::ONE), (z, F::ONE)],
+			)?;
+			let carry =
+				builder.add_committed(format!("carry_{bit}"), log_rows, BinaryField1b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let x = witness.get::<BinaryField1b>(x)?.as_slice::<u8>();
+				let y = witness.get::<BinaryField1b>(y)?.as_slice::<u8>();
+				let z = witness.get::<BinaryField1b>(z)?.as_slice::<u8>();
+				let mut sum = witness.new_column::<BinaryField1b>(sum)?;
+				let mut carry = witness.new_column::<BinaryField1b>(carry)?;
+				izip!(x, y, z, sum.as_mut_slice::<u8>(), carry.as_mut_slice::<u8>()).for_each(
+					|(x, y, z, sum, carry)| {
+						*sum = x ^ y ^ z;
+						*carry = (x & y) | (y & z) | (z & x);
+					},
+				);
+			}
+			// The majority of three bits is the sum of their pairwise products in GF(2).
+			builder.assert_zero(
+				format!("carry_{bit}"),
+				[x, y, z, carry],
+				arith_expr!([x, y, z, carry] = x * y + y * z + z * x - carry).convert_field(),
+			);
+			Ok((Some(sum), Some(carry)))
+		}
+		_ => unreachable!("at most three bits are added"),
+	}
 }
 
 /// u32 Multiplication based on plain lookups for static exponentiation
@@ -190,10 +431,10 @@ pub fn u32_mul<const LOG_MAX_MULTIPLICITY: usize>(
 
 		let xin_numbers = columns_to_numbers(&xin_columns);
 
-		let mut xin_low = witness.new_column::<BinaryField16b>(xin_low);
+		let mut xin_low = witness.new_column::<BinaryField16b>(xin_low)?;
 		let xin_low = xin_low.as_mut_slice::<u16>();
 
-		let mut xin_high = witness.new_column::<BinaryField16b>(xin_high);
+		let mut xin_high = witness.new_column::<BinaryField16b>(xin_high)?;
 		let xin_high = xin_high.as_mut_slice::<u16>();
 
 		izip!(xin_numbers, xin_low, xin_high).for_each(|(xin, low, high)| {
@@ -243,7 +484,7 @@ pub fn u32_mul<const LOG_MAX_MULTIPLICITY: usize>(
 			.get::<BinaryField64b>(xin_high_exp_res_id)?
 			.as_slice::<BinaryField64b>();
 
-		let mut xin_exp_res = witness.new_column::<BinaryField64b>(xin_exp_res_id);
+		let mut xin_exp_res = witness.new_column::<BinaryField64b>(xin_exp_res_id)?;
 		let xin_exp_res = xin_exp_res.as_mut_slice::<BinaryField64b>();
 		xin_exp_res
 			.par_iter_mut()
@@ -294,7 +535,7 @@ pub fn u32_mul<const LOG_MAX_MULTIPLICITY: usize>(
 		let mut cout_columns = cout_bits
 			.iter()
 			.map(|&id| witness.new_column::<BinaryField1b>(id))
-			.collect::<Vec<_>>();
+			.collect::<Result<Vec<_>, _>>()?;
 
 		let mut cout_columns = cout_columns
 			.iter_mut()
@@ -303,7 +544,7 @@ pub fn u32_mul<const LOG_MAX_MULTIPLICITY: usize>(
 
 		numbers_to_columns(&result, &mut cout_columns);
 
-		let mut cout = cout.map(|id| witness.new_column::<BinaryField16b>(id));
+		let mut cout = array_util::try_map(cout, |id| witness.new_column::<BinaryField16b>(id))?;
 
 		let mut cout = cout
 			.iter_mut()
@@ -401,17 +642,21 @@ fn numbers_to_columns(numbers: &[u128], columns: &mut [&mut [PackedType<U, Binar
 
 #[cfg(test)]
 mod tests {
+	use std::array;
+
 	use binius_core::{
 		constraint_system::{self},
 		fiat_shamir::HasherChallenger,
+		oracle::OracleId,
 	};
 	use binius_field::{BinaryField1b, BinaryField8b, tower::CanonicalTowerFamily};
 	use binius_hal::make_portable_backend;
 	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+	use itertools::izip;
 
-	use super::mul;
+	use super::{columns_to_numbers, mul, mul_u64, mul_u128};
 	use crate::{
-		builder::{ConstraintSystemBuilder, types::U},
+		builder::{ConstraintSystemBuilder, test_utils::test_circuit, types::U},
 		unconstrained::unconstrained,
 	};
 
@@ -452,7 +697,13 @@ mod tests {
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
 			_,
-		>(&constraint_system, 1, 10, &[], witness, &backend)
+		>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&[],
+			witness,
+			&backend,
+		)
 		.unwrap();
 
 		constraint_system::verify::<
@@ -461,7 +712,80 @@ mod tests {
 			Groestl256,
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
-		>(&constraint_system, 1, 10, &[], proof)
+		>(&constraint_system, constraint_system::SecurityLevel::new(10, 1), &[], proof)
+		.unwrap();
+	}
+
+	fn unconstrained_bits<const N: usize>(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+	) -> [OracleId; N] {
+		array::from_fn(|i| {
+			unconstrained::<BinaryField1b>(builder, format!("{name}_{i}"), log_size).unwrap()
+		})
+	}
+
+	fn bits_to_numbers(builder: &mut ConstraintSystemBuilder, bits: &[OracleId]) -> Vec<u128> {
+		let witness = builder.witness().unwrap();
+		let entries = bits
+			.iter()
+			.map(|&id| witness.get::<BinaryField1b>(id).unwrap().packed())
+			.collect::<Vec<_>>();
+		columns_to_numbers(&entries)
+	}
+
+	/// Returns the high 128 bits of the 256-bit product `x * y`.
+	fn mul_high_u128(x: u128, y: u128) -> u128 {
+		let (x_lo, x_hi) = (x as u64 as u128, x >> 64);
+		let (y_lo, y_hi) = (y as u64 as u128, y >> 64);
+		let lo = x_lo * y_lo;
+		let mid_0 = x_lo * y_hi;
+		let mid_1 = x_hi * y_lo;
+		let carry = ((lo >> 64) + (mid_0 as u64 as u128) + (mid_1 as u64 as u128)) >> 64;
+		x_hi * y_hi + (mid_0 >> 64) + (mid_1 >> 64) + carry
+	}
+
+	#[test]
+	fn test_mul_u64() {
+		test_circuit(|builder| {
+			let log_size = 8;
+			let xin = unconstrained_bits::<64>(builder, "x", log_size);
+			let yin = unconstrained_bits::<64>(builder, "y", log_size);
+			let product = mul_u64(builder, "mul_u64", xin, yin)?;
+			if builder.witness().is_some() {
+				let xs = bits_to_numbers(builder, &xin);
+				let ys = bits_to_numbers(builder, &yin);
+				let products = bits_to_numbers(builder, &product);
+				for (x, y, product) in izip!(xs, ys, products) {
+					assert_eq!(x * y, product);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_mul_u128() {
+		test_circuit(|builder| {
+			let log_size = 7;
+			let xin = unconstrained_bits::<128>(builder, "x", log_size);
+			let yin = unconstrained_bits::<128>(builder, "y", log_size);
+			let product = mul_u128(builder, "mul_u128", xin, yin)?;
+			if builder.witness().is_some() {
+				let xs = bits_to_numbers(builder, &xin);
+				let ys = bits_to_numbers(builder, &yin);
+				let products_low = bits_to_numbers(builder, &product[..128]);
+				let products_high = bits_to_numbers(builder, &product[128..]);
+				for (x, y, product_low, product_high) in izip!(xs, ys, products_low, products_high)
+				{
+					assert_eq!(x.wrapping_mul(y), product_low);
+					assert_eq!(mul_high_u128(x, y), product_high);
+				}
+			}
+			Ok(vec![])
+		})
 		.unwrap();
 	}
 }
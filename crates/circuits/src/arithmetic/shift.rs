@@ -0,0 +1,331 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Shifts and rotations of bit-decomposed integers by a variable amount.
+//!
+//! Shifts by a constant amount are free, since they only reindex the bit oracles. Shifts by an
+//! amount that varies per row are implemented with a barrel shifter: the shift amount is
+//! decomposed into bits and, for every bit $k$ of the amount, a stage conditionally shifts the
+//! value by $2^k$. The value bits are given least significant bit first, following the same
+//! convention as [`mul`](super::mul::mul).
+
+use anyhow::ensure;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField1b, ExtensionField, TowerField, as_packed_field::PackScalar};
+use binius_macros::arith_expr;
+use bytemuck::Pod;
+use itertools::izip;
+
+use crate::{
+	builder::{
+		ConstraintSystemBuilder,
+		types::{F, U},
+	},
+	range_check::range_check,
+};
+
+/// Shifts the value left by `shift` bits, filling the low bits with zeros.
+///
+/// `shift` is a column of `FS` elements whose values must be less than the bit width of the value,
+/// which must be a power of two.
+pub fn shl<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input_bits: Vec<OracleId>,
+	shift: OracleId,
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField,
+{
+	barrel_shift::<FS>(builder, name, input_bits, shift, |i, amount, _| i.checked_sub(amount))
+}
+
+/// Shifts the value right by `shift` bits, filling the high bits with zeros.
+///
+/// See [`shl`] for the requirements on the arguments.
+pub fn shr<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input_bits: Vec<OracleId>,
+	shift: OracleId,
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField,
+{
+	barrel_shift::<FS>(builder, name, input_bits, shift, |i, amount, width| {
+		Some(i + amount).filter(|&j| j < width)
+	})
+}
+
+/// Rotates the value left by `shift` bits.
+///
+/// See [`shl`] for the requirements on the arguments.
+pub fn rotl<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input_bits: Vec<OracleId>,
+	shift: OracleId,
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField,
+{
+	barrel_shift::<FS>(builder, name, input_bits, shift, |i, amount, width| {
+		Some((i + width - amount) % width)
+	})
+}
+
+/// Rotates the value right by `shift` bits.
+///
+/// See [`shl`] for the requirements on the arguments.
+pub fn rotr<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input_bits: Vec<OracleId>,
+	shift: OracleId,
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField,
+{
+	barrel_shift::<FS>(builder, name, input_bits, shift, |i, amount, width| {
+		Some((i + amount) % width)
+	})
+}
+
+/// Builds a barrel shifter.
+///
+/// `source(i, amount, width)` returns the index of the input bit that moves to output bit `i` when
+/// shifting by `amount`, or `None` if the output bit is zero.
+fn barrel_shift<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input_bits: Vec<OracleId>,
+	shift: OracleId,
+	source: impl Fn(usize, usize, usize) -> Option<usize>,
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField,
+{
+	let width = input_bits.len();
+	ensure!(width.is_power_of_two(), "barrel shifter: bit width {width} is not a power of two");
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(input_bits.iter().copied().chain([shift]))?;
+	let shift_bits = range_check::<FS>(builder, "shift_bits", shift, width.ilog2() as usize)?;
+
+	let mut current = input_bits;
+	for (k, &shift_bit) in shift_bits.iter().enumerate() {
+		let amount = 1 << k;
+		let sources = (0..width)
+			.map(|i| source(i, amount, width))
+			.collect::<Vec<_>>();
+		let next = (0..width)
+			.map(|i| {
+				builder.add_committed(
+					format!("stage_{k}_bit_{i}"),
+					log_rows,
+					BinaryField1b::TOWER_LEVEL,
+				)
+			})
+			.collect::<Vec<_>>();
+
+		if let Some(witness) = builder.witness() {
+			let sel = witness.get::<BinaryField1b>(shift_bit)?.as_slice::<u32>();
+			let mut fill = witness.parallel_fill();
+			for (i, &out) in next.iter().enumerate() {
+				let cur = witness.get::<BinaryField1b>(current[i])?.as_slice::<u32>();
+				let src = match sources[i] {
+					Some(j) => Some(witness.get::<BinaryField1b>(current[j])?.as_slice::<u32>()),
+					None => None,
+				};
+				fill.column::<BinaryField1b>(out, move |out| {
+					let out = out.as_mut_slice::<u32>();
+					match src {
+						Some(src) => {
+							for (out, sel, cur, src) in izip!(out, sel, cur, src) {
+								*out = (sel & src) | (!sel & cur);
+							}
+						}
+						None => {
+							for (out, sel, cur) in izip!(out, sel, cur) {
+								*out = !sel & cur;
+							}
+						}
+					}
+					Ok(())
+				});
+			}
+			fill.run()?;
+		}
+
+		for (i, &out) in next.iter().enumerate() {
+			match sources[i] {
+				Some(j) => builder.assert_zero(
+					format!("stage_{k}_bit_{i}"),
+					[shift_bit, current[i], current[j], out],
+					arith_expr!([s, cur, src, out] = out - cur - s * (src + cur)).convert_field(),
+				),
+				None => builder.assert_zero(
+					format!("stage_{k}_bit_{i}"),
+					[shift_bit, current[i], out],
+					arith_expr!([s, cur, out] = out - (1 + s) * cur).convert_field(),
+				),
+			}
+		}
+
+		current = next;
+	}
+
+	builder.pop_namespace();
+	Ok(current)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField8b, Field, packed::get_packed_slice};
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	type B8 = BinaryField8b;
+
+	fn value_bits(
+		builder: &mut ConstraintSystemBuilder,
+		log_size: usize,
+		width: usize,
+		values: &[u64],
+	) -> Vec<OracleId> {
+		let bits = (0..width)
+			.map(|i| {
+				builder.add_committed(format!("value_{i}"), log_size, BinaryField1b::TOWER_LEVEL)
+			})
+			.collect::<Vec<_>>();
+		if let Some(witness) = builder.witness() {
+			for (i, &bit) in bits.iter().enumerate() {
+				let mut column = witness.new_column::<BinaryField1b>(bit).unwrap();
+				for (word, chunk) in column
+					.as_mut_slice::<u32>()
+					.iter_mut()
+					.zip(values.chunks(32))
+				{
+					*word = chunk
+						.iter()
+						.enumerate()
+						.fold(0, |acc, (j, value)| acc | ((((value >> i) & 1) as u32) << j));
+				}
+			}
+		}
+		bits
+	}
+
+	fn test_barrel_shift(
+		width: usize,
+		gadget: fn(
+			&mut ConstraintSystemBuilder,
+			&str,
+			Vec<OracleId>,
+			OracleId,
+		) -> Result<Vec<OracleId>, anyhow::Error>,
+		expected: fn(u64, u32) -> u64,
+	) {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let mut rng = StdRng::seed_from_u64(0);
+			let mask = if width == 64 {
+				u64::MAX
+			} else {
+				(1 << width) - 1
+			};
+			let values = (0..1 << log_size)
+				.map(|_| rng.r#gen::<u64>() & mask)
+				.collect::<Vec<_>>();
+			let shifts = (0..1 << log_size)
+				.map(|_| rng.gen_range(0..width as u8))
+				.collect::<Vec<_>>();
+
+			let input_bits = value_bits(builder, log_size, width, &values);
+			let shift = builder.add_committed("shift", log_size, B8::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				witness
+					.new_column::<B8>(shift)?
+					.as_mut_slice::<u8>()
+					.copy_from_slice(&shifts);
+			}
+
+			let output_bits = gadget(builder, "shift", input_bits, shift)?;
+			if let Some(witness) = builder.witness() {
+				for row in 0..1 << log_size {
+					let expected = expected(values[row], shifts[row] as u32) & mask;
+					for (i, &bit) in output_bits.iter().enumerate() {
+						let packed = witness.get::<BinaryField1b>(bit)?.packed();
+						assert_eq!(
+							get_packed_slice(packed, row) == BinaryField1b::ONE,
+							(expected >> i) & 1 == 1
+						);
+					}
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_shl_u32() {
+		test_barrel_shift(32, |b, n, x, s| shl::<B8>(b, n, x, s), |x, s| x << s);
+	}
+
+	#[test]
+	fn test_shr_u64() {
+		test_barrel_shift(64, |b, n, x, s| shr::<B8>(b, n, x, s), |x, s| x >> s);
+	}
+
+	#[test]
+	fn test_rotl_u32() {
+		test_barrel_shift(
+			32,
+			|b, n, x, s| rotl::<B8>(b, n, x, s),
+			|x, s| (x as u32).rotate_left(s) as u64,
+		);
+	}
+
+	#[test]
+	fn test_rotr_u64() {
+		test_barrel_shift(64, |b, n, x, s| rotr::<B8>(b, n, x, s), |x, s| x.rotate_right(s));
+	}
+
+	#[test]
+	fn test_shift_rejects_amount_out_of_range() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let input_bits = value_bits(&mut builder, 10, 32, &[0; 1 << 10]);
+		let shift = builder.add_committed("shift", 10, B8::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<B8>(shift)
+				.unwrap()
+				.as_mut_slice::<u8>()
+				.fill(32);
+		}
+		assert!(shl::<B8>(&mut builder, "shl", input_bits, shift).is_err());
+	}
+
+	#[test]
+	fn test_shift_rejects_width_not_power_of_two() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let input_bits = (0..24)
+			.map(|i| builder.add_committed(format!("x_{i}"), 10, BinaryField1b::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+		let shift = builder.add_committed("shift", 10, B8::TOWER_LEVEL);
+		assert!(shl::<B8>(&mut builder, "shl", input_bits, shift).is_err());
+	}
+}
@@ -23,7 +23,7 @@ pub fn build_exp_table(
 	let table = builder.add_committed(name, 16, BinaryField128b::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut table = witness.new_column::<BinaryField128b>(table);
+		let mut table = witness.new_column::<BinaryField128b>(table)?;
 
 		let table = table.as_mut_slice::<u128>();
 
@@ -76,7 +76,7 @@ pub fn u16_static_exp_lookups<const LOG_MAX_MULTIPLICITY: usize>(
 	let multiplicities = if let Some(witness) = builder.witness() {
 		let xin = witness.get::<BinaryField16b>(xin)?.as_slice::<u16>();
 
-		let mut exp_result = witness.new_column::<BinaryField64b>(exp_result);
+		let mut exp_result = witness.new_column::<BinaryField64b>(exp_result)?;
 
 		let exp_result = exp_result.as_mut_slice::<BinaryField64b>();
 
@@ -84,7 +84,7 @@ pub fn u16_static_exp_lookups<const LOG_MAX_MULTIPLICITY: usize>(
 			*exp = g.pow(xin[i] as u64);
 		});
 
-		let mut lookup_values = witness.new_column::<BinaryField128b>(lookup_values);
+		let mut lookup_values = witness.new_column::<BinaryField128b>(lookup_values)?;
 
 		let lookup_values = lookup_values.as_mut_slice::<u128>();
 
@@ -102,9 +102,9 @@ pub fn add(
 		(
 			witness.get::<B1>(xin)?.as_slice::<u32>(),
 			witness.get::<B1>(yin)?.as_slice::<u32>(),
-			witness.new_column::<B1>(zout).as_mut_slice::<u32>(),
-			witness.new_column::<B1>(cout).as_mut_slice::<u32>(),
-			witness.new_column::<B1>(cin).as_mut_slice::<u32>(),
+			witness.new_column::<B1>(zout)?.as_mut_slice::<u32>(),
+			witness.new_column::<B1>(cout)?.as_mut_slice::<u32>(),
+			witness.new_column::<B1>(cin)?.as_mut_slice::<u32>(),
 		)
 			.into_par_iter()
 			.for_each(|(xin, yin, zout, cout, cin)| {
@@ -158,9 +158,9 @@ pub fn sub(
 		(
 			witness.get::<B1>(zin)?.as_slice::<u32>(),
 			witness.get::<B1>(yin)?.as_slice::<u32>(),
-			witness.new_column::<B1>(xout).as_mut_slice::<u32>(),
-			witness.new_column::<B1>(cout).as_mut_slice::<u32>(),
-			witness.new_column::<B1>(cin).as_mut_slice::<u32>(),
+			witness.new_column::<B1>(xout)?.as_mut_slice::<u32>(),
+			witness.new_column::<B1>(cout)?.as_mut_slice::<u32>(),
+			witness.new_column::<B1>(cin)?.as_mut_slice::<u32>(),
 		)
 			.into_par_iter()
 			.for_each(|(zout, yin, xin, cout, cin)| {
@@ -225,7 +225,7 @@ pub fn shl(
 	let shifted = builder.add_shifted(name, input, offset, 5, ShiftVariant::LogicalLeft)?;
 	if let Some(witness) = builder.witness() {
 		(
-			witness.new_column::<B1>(shifted).as_mut_slice::<u32>(),
+			witness.new_column::<B1>(shifted)?.as_mut_slice::<u32>(),
 			witness.get::<B1>(input)?.as_slice::<u32>(),
 		)
 			.into_par_iter()
@@ -248,7 +248,7 @@ pub fn shr(
 	let shifted = builder.add_shifted(name, input, offset, 5, ShiftVariant::LogicalRight)?;
 	if let Some(witness) = builder.witness() {
 		(
-			witness.new_column::<B1>(shifted).as_mut_slice::<u32>(),
+			witness.new_column::<B1>(shifted)?.as_mut_slice::<u32>(),
 			witness.get::<B1>(input)?.as_slice::<u32>(),
 		)
 			.into_par_iter()
@@ -272,7 +272,7 @@ pub fn select_bit(
 	let bits = builder.add_projected(name, input, query, 0)?;
 
 	if let Some(witness) = builder.witness() {
-		let mut bits = witness.new_column::<B1>(bits);
+		let mut bits = witness.new_column::<B1>(bits)?;
 		let bits = bits.packed();
 		let input = witness.get::<B1>(input)?.as_slice::<u32>();
 		input.iter().enumerate().for_each(|(i, &val)| {
@@ -297,7 +297,7 @@ pub fn constant(
 	// This would not need to be committed if we had `builder.add_unpacked(..)`
 	let output = builder.add_committed("output", log_count + 5, B1::TOWER_LEVEL);
 	if let Some(witness) = builder.witness() {
-		witness.new_column::<B1>(output).as_mut_slice().fill(value);
+		witness.new_column::<B1>(output)?.as_mut_slice().fill(value);
 	}
 
 	let output_packed = builder.add_packed("output_packed", output, 5)?;
@@ -355,10 +355,10 @@ pub fn u32const_repeating(
 	)?;
 
 	if let Some(witness) = builder.witness() {
-		let mut transparent_witness = witness.new_column::<B1>(transparent_id);
+		let mut transparent_witness = witness.new_column::<B1>(transparent_id)?;
 		transparent_witness.as_mut_slice::<u32>().fill(x);
 
-		let mut repeating_witness = witness.new_column::<B1>(repeating_id);
+		let mut repeating_witness = witness.new_column::<B1>(repeating_id)?;
 		repeating_witness.as_mut_slice::<u32>().fill(x);
 	}
 
@@ -377,7 +377,7 @@ mod tests {
 			let a = builder.add_committed("a", 5, BinaryField1b::TOWER_LEVEL);
 			if let Some(witness) = builder.witness() {
 				witness
-					.new_column::<BinaryField1b>(a)
+					.new_column::<BinaryField1b>(a)?
 					.as_mut_slice::<u32>()
 					.iter_mut()
 					.for_each(|v| *v = 0b01000000_00000000_00000000_00000000u32);
@@ -21,7 +21,7 @@ pub fn and(
 			witness.get::<BinaryField1b>(xin)?.as_slice::<u32>(),
 			witness.get::<BinaryField1b>(yin)?.as_slice::<u32>(),
 			witness
-				.new_column::<BinaryField1b>(zout)
+				.new_column::<BinaryField1b>(zout)?
 				.as_mut_slice::<u32>(),
 		)
 			.into_par_iter()
@@ -53,7 +53,7 @@ pub fn xor(
 			witness.get::<BinaryField1b>(xin)?.as_slice::<u32>(),
 			witness.get::<BinaryField1b>(yin)?.as_slice::<u32>(),
 			witness
-				.new_column::<BinaryField1b>(zout)
+				.new_column::<BinaryField1b>(zout)?
 				.as_mut_slice::<u32>(),
 		)
 			.into_par_iter()
@@ -79,7 +79,7 @@ pub fn or(
 			witness.get::<BinaryField1b>(xin)?.as_slice::<u32>(),
 			witness.get::<BinaryField1b>(yin)?.as_slice::<u32>(),
 			witness
-				.new_column::<BinaryField1b>(zout)
+				.new_column::<BinaryField1b>(zout)?
 				.as_mut_slice::<u32>(),
 		)
 			.into_par_iter()
@@ -189,38 +189,39 @@ pub fn blake3_compress(
 
 		// columns creation
 
-		let mut state_cols = state_transitions.map(|id| witness.new_column::<F32>(id));
-		let mut input_cols = input.map(|id| witness.new_column::<F32>(id));
-		let mut output_cols = output.map(|id| witness.new_column::<F32>(id));
-
-		let mut cv_col = witness.new_column::<F32>(cv);
-		let mut state_i_col = witness.new_column::<F32>(state_i);
-		let mut state_i_8_col = witness.new_column::<F32>(state_i_8);
-		let mut state_i_xor_state_i_8_col = witness.new_column::<F32>(state_i_xor_state_i_8);
-		let mut cv_oracle_xor_state_i_8_col = witness.new_column::<F32>(cv_oracle_xor_state_i_8);
-
-		let mut a_in_col = witness.new_column::<F1>(a_in);
-		let mut b_in_col = witness.new_column::<F1>(b_in);
-		let mut c_in_col = witness.new_column::<F1>(c_in);
-		let mut d_in_col = witness.new_column::<F1>(d_in);
-		let mut mx_in_col = witness.new_column::<F1>(mx_in);
-		let mut my_in_col = witness.new_column::<F1>(my_in);
-		let mut a_0_tmp_col = witness.new_column::<F1>(a_0_tmp);
-		let mut a_0_col = witness.new_column::<F1>(a_0);
-		let mut b_in_xor_c_0_col = witness.new_column::<F1>(b_in_xor_c_0);
-		let mut b_0_col = witness.new_column::<F1>(b_0);
-		let mut c_0_col = witness.new_column::<F1>(c_0);
-		let mut d_in_xor_a_0_col = witness.new_column::<F1>(d_in_xor_a_0);
-		let mut d_0_col = witness.new_column::<F1>(d_0);
-		let mut a_1_tmp_col = witness.new_column::<F1>(a_1_tmp);
-		let mut a_1_col = witness.new_column::<F1>(a_1);
-		let mut d_0_xor_a_1_col = witness.new_column::<F1>(d_0_xor_a_1);
-		let mut d_1_col = witness.new_column::<F1>(d_1);
-		let mut c_1_col = witness.new_column::<F1>(c_1);
-		let mut b_0_xor_c_1_col = witness.new_column::<F1>(b_0_xor_c_1);
-		let mut b_1_col = witness.new_column::<F1>(b_1);
-		let mut cout_cols = cout.map(|id| witness.new_column::<F1>(id));
-		let mut cin_cols = cin.map(|id| witness.new_column::<F1>(id));
+		let mut state_cols =
+			array_util::try_map(state_transitions, |id| witness.new_column::<F32>(id))?;
+		let mut input_cols = array_util::try_map(input, |id| witness.new_column::<F32>(id))?;
+		let mut output_cols = array_util::try_map(output, |id| witness.new_column::<F32>(id))?;
+
+		let mut cv_col = witness.new_column::<F32>(cv)?;
+		let mut state_i_col = witness.new_column::<F32>(state_i)?;
+		let mut state_i_8_col = witness.new_column::<F32>(state_i_8)?;
+		let mut state_i_xor_state_i_8_col = witness.new_column::<F32>(state_i_xor_state_i_8)?;
+		let mut cv_oracle_xor_state_i_8_col = witness.new_column::<F32>(cv_oracle_xor_state_i_8)?;
+
+		let mut a_in_col = witness.new_column::<F1>(a_in)?;
+		let mut b_in_col = witness.new_column::<F1>(b_in)?;
+		let mut c_in_col = witness.new_column::<F1>(c_in)?;
+		let mut d_in_col = witness.new_column::<F1>(d_in)?;
+		let mut mx_in_col = witness.new_column::<F1>(mx_in)?;
+		let mut my_in_col = witness.new_column::<F1>(my_in)?;
+		let mut a_0_tmp_col = witness.new_column::<F1>(a_0_tmp)?;
+		let mut a_0_col = witness.new_column::<F1>(a_0)?;
+		let mut b_in_xor_c_0_col = witness.new_column::<F1>(b_in_xor_c_0)?;
+		let mut b_0_col = witness.new_column::<F1>(b_0)?;
+		let mut c_0_col = witness.new_column::<F1>(c_0)?;
+		let mut d_in_xor_a_0_col = witness.new_column::<F1>(d_in_xor_a_0)?;
+		let mut d_0_col = witness.new_column::<F1>(d_0)?;
+		let mut a_1_tmp_col = witness.new_column::<F1>(a_1_tmp)?;
+		let mut a_1_col = witness.new_column::<F1>(a_1)?;
+		let mut d_0_xor_a_1_col = witness.new_column::<F1>(d_0_xor_a_1)?;
+		let mut d_1_col = witness.new_column::<F1>(d_1)?;
+		let mut c_1_col = witness.new_column::<F1>(c_1)?;
+		let mut b_0_xor_c_1_col = witness.new_column::<F1>(b_0_xor_c_1)?;
+		let mut b_1_col = witness.new_column::<F1>(b_1)?;
+		let mut cout_cols = array_util::try_map(cout, |id| witness.new_column::<F1>(id))?;
+		let mut cin_cols = array_util::try_map(cin, |id| witness.new_column::<F1>(id))?;
 
 		// values
 
@@ -1,29 +1,45 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+	cell::RefCell,
+	collections::{BTreeSet, HashMap, HashSet},
+	fmt::{self, Display},
+	mem,
+	rc::Rc,
+};
 
 use anyhow::{anyhow, ensure};
 use binius_core::{
 	constraint_system::{
 		ConstraintSystem,
-		channel::{ChannelId, Flush, FlushDirection, OracleOrConst},
+		channel::{Boundary, ChannelId, Flush, FlushDirection, OracleOrConst},
 		exp::Exp,
 	},
 	oracle::{
-		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId, ShiftVariant,
+		CommitBatching, ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId,
+		ShiftVariant,
 	},
 	polynomial::MultivariatePoly,
 	transparent::step_down::StepDown,
 	witness::MultilinearExtensionIndex,
 };
 use binius_field::{
-	BinaryField1b,
+	BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b, BinaryField16b, BinaryField32b,
+	BinaryField64b, BinaryField128b, ExtensionField, Field, TowerField,
 	as_packed_field::{PackScalar, PackedType},
 };
-use binius_math::ArithCircuit;
+use binius_math::{ArithCircuit, DegreeReduction, MultilinearPoly};
 use binius_utils::bail;
+use bytemuck::Pod;
+use itertools::Itertools;
 
 use crate::builder::{
+	lookup::{LookupTable, LookupTableId},
+	memory::{Memory, MemoryId},
+	padding::{PaddedTable, PaddingPolicy},
+	permutation::{self, RowPermutation},
+	storage::WitnessStorage,
+	symbols::SymbolTable,
 	types::{F, U},
 	witness,
 };
@@ -38,7 +54,61 @@ pub struct ConstraintSystemBuilder<'arena> {
 	step_down_dedup: HashMap<(usize, usize), OracleId>,
 	witness: Option<witness::Builder<'arena>>,
 	next_channel_id: ChannelId,
+	public_inputs: Vec<Boundary<F>>,
 	namespace_path: Vec<String>,
+	lookup_tables: Vec<LookupTable>,
+	memories: Vec<Memory>,
+	max_constraint_degree: Option<usize>,
+	high_degree_constraints: Vec<HighDegreeConstraint>,
+	disabled_scopes: Vec<Vec<String>>,
+	skipped_constraints: Vec<String>,
+	oracle_names: HashSet<String>,
+	name_collisions: BTreeSet<String>,
+}
+
+/// A zerocheck constraint which doesn't hold on the witness, see
+/// [`ConstraintSystemBuilder::check_witness`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintViolation {
+	/// The name of the constraint.
+	pub name: String,
+	/// The columns the constraint is evaluated on.
+	pub oracle_ids: Vec<OracleId>,
+	/// The first rows where the constraint doesn't hold.
+	pub rows: Vec<usize>,
+}
+
+impl Display for ConstraintViolation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"constraint {} over oracles {:?} does not hold at rows {:?}",
+			self.name, self.oracle_ids, self.rows
+		)
+	}
+}
+
+/// A zerocheck constraint whose degree is reduced when the constraint system is built.
+struct HighDegreeConstraint {
+	name: String,
+	oracle_ids: Vec<OracleId>,
+	composition: ArithCircuit<F>,
+	max_degree: usize,
+}
+
+/// The state of a [`ConstraintSystemBuilder`] to roll back to, see
+/// [`ConstraintSystemBuilder::rollback`].
+struct Checkpoint {
+	n_oracles: usize,
+	n_constraints: usize,
+	n_non_zero_oracle_ids: usize,
+	n_flushes: usize,
+	n_exponents: usize,
+	next_channel_id: ChannelId,
+	n_public_inputs: usize,
+	n_high_degree_constraints: usize,
+	n_skipped_constraints: usize,
+	name_collisions: BTreeSet<String>,
 }
 
 impl<'arena> ConstraintSystemBuilder<'arena> {
@@ -46,23 +116,32 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		Self::default()
 	}
 
-	pub fn new_with_witness(allocator: &'arena bumpalo::Bump) -> Self {
+	/// Creates a builder that also builds the witness, with the columns allocated in `storage`.
+	pub fn new_with_witness(storage: &'arena dyn WitnessStorage) -> Self {
 		let oracles = Rc::new(RefCell::new(MultilinearOracleSet::new()));
 		Self {
-			witness: Some(witness::Builder::new(allocator, oracles.clone())),
+			witness: Some(witness::Builder::new(storage, oracles.clone())),
 			oracles,
 			..Default::default()
 		}
 	}
 
 	#[allow(clippy::type_complexity)]
-	pub fn build(self) -> Result<ConstraintSystem<F>, anyhow::Error> {
+	pub fn build(mut self) -> Result<ConstraintSystem<F>, anyhow::Error> {
+		self.compile_memories()?;
+		self.compile_lookup_tables()?;
+		self.compile_high_degree_constraints()?;
 		let table_constraints = self.constraints.build(&self.oracles.borrow())?;
 		Ok(ConstraintSystem {
 			max_channel_id: self
 				.flushes
 				.iter()
 				.map(|flush| flush.channel_id)
+				.chain(
+					self.public_inputs
+						.iter()
+						.map(|boundary| boundary.channel_id),
+				)
 				.max()
 				.unwrap_or(0),
 			table_constraints,
@@ -84,6 +163,11 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 	pub fn take_witness(
 		&mut self,
 	) -> Result<MultilinearExtensionIndex<'arena, PackedType<U, F>>, anyhow::Error> {
+		if self.witness.is_some() {
+			self.compile_memories()?;
+			self.compile_lookup_tables()?;
+			self.compile_high_degree_constraints()?;
+		}
 		Option::take(&mut self.witness)
 			.ok_or_else(|| {
 				anyhow!(
@@ -139,7 +223,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			)?;
 
 			if let Some(witness) = self.witness() {
-				step_down.populate(witness.new_column::<BinaryField1b>(selector).packed());
+				step_down.populate(witness.new_column::<BinaryField1b>(selector)?.packed());
 			}
 
 			self.step_down_dedup.insert((n_vars, count), selector);
@@ -211,14 +295,431 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		self.flush(FlushDirection::Pull, channel_id, count, oracle_ids)
 	}
 
+	/// Registers a lookup table whose rows are the tuples of values in `columns`.
+	///
+	/// All columns must have the same number of rows and hold `FTable` values. Values are looked up
+	/// in the table with [`Self::read_from_table`]. The lookup argument is added to the constraint
+	/// system when the witness is taken or the constraint system is built, so all reads must be
+	/// made before then.
+	pub fn add_lookup_table<FTable>(
+		&mut self,
+		name: impl ToString,
+		columns: impl IntoIterator<Item = OracleId>,
+	) -> anyhow::Result<LookupTableId>
+	where
+		U: PackScalar<FTable> + Pod,
+		F: ExtensionField<FTable>,
+		FTable: TowerField,
+	{
+		let columns = columns.into_iter().collect::<Vec<_>>();
+		self.log_rows(columns.iter().copied())?;
+		let table_id = LookupTableId(self.lookup_tables.len());
+		self.lookup_tables
+			.push(LookupTable::new::<FTable>(self.scoped_name(name), columns));
+		Ok(table_id)
+	}
+
+	/// Constrains the first `count` rows of `columns` to be rows of a lookup table.
+	///
+	/// The columns must match the table columns in number and field, and have the same number of
+	/// rows, which must be at least `count`.
+	pub fn read_from_table(
+		&mut self,
+		table_id: LookupTableId,
+		count: usize,
+		columns: impl IntoIterator<Item = OracleId>,
+	) -> anyhow::Result<()> {
+		let columns = columns.into_iter().collect::<Vec<_>>();
+		let log_rows = self.log_rows(columns.iter().copied())?;
+		ensure!(count <= 1 << log_rows, "read_from_table: count exceeds the number of rows");
+
+		let table = self
+			.lookup_tables
+			.get_mut(table_id.0)
+			.ok_or_else(|| anyhow!("read_from_table: unknown lookup table {table_id:?}"))?;
+		ensure!(
+			columns.len() == table.n_columns(),
+			"read_from_table: expected {} columns, got {}",
+			table.n_columns(),
+			columns.len()
+		);
+		table.add_read(count, columns);
+		Ok(())
+	}
+
+	/// Adds the lookup arguments for all registered lookup tables.
+	///
+	/// Table names are already scoped, so the lookups are compiled outside of any namespace. A
+	/// table that fails to compile is rolled back, so that compiling it again doesn't add its
+	/// columns twice.
+	fn compile_lookup_tables(&mut self) -> anyhow::Result<()> {
+		let namespace_path = mem::take(&mut self.namespace_path);
+		let mut tables = mem::take(&mut self.lookup_tables);
+		let mut n_compiled = 0;
+		let result = tables.iter().try_for_each(|table| {
+			let checkpoint = self.checkpoint();
+			if let Err(err) = table.compile(self) {
+				self.rollback(checkpoint);
+				return Err(err);
+			}
+			n_compiled += 1;
+			Ok(())
+		});
+		if result.is_err() {
+			// Keep the tables that were not compiled, so that they are not lost on failure.
+			self.lookup_tables = tables.split_off(n_compiled);
+		}
+		self.namespace_path = namespace_path;
+		result
+	}
+
+	fn checkpoint(&self) -> Checkpoint {
+		Checkpoint {
+			n_oracles: self.oracles.borrow().size(),
+			n_constraints: self.constraints.len(),
+			n_non_zero_oracle_ids: self.non_zero_oracle_ids.len(),
+			n_flushes: self.flushes.len(),
+			n_exponents: self.exponents.len(),
+			next_channel_id: self.next_channel_id,
+			n_public_inputs: self.public_inputs.len(),
+			n_high_degree_constraints: self.high_degree_constraints.len(),
+			n_skipped_constraints: self.skipped_constraints.len(),
+			name_collisions: self.name_collisions.clone(),
+		}
+	}
+
+	/// Removes the columns, constraints, flushes and channels added since `checkpoint` was taken.
+	fn rollback(&mut self, checkpoint: Checkpoint) {
+		let Checkpoint {
+			n_oracles,
+			n_constraints,
+			n_non_zero_oracle_ids,
+			n_flushes,
+			n_exponents,
+			next_channel_id,
+			n_public_inputs,
+			n_high_degree_constraints,
+			n_skipped_constraints,
+			name_collisions,
+		} = checkpoint;
+
+		let mut oracles = self.oracles.borrow_mut();
+		oracles.truncate(n_oracles);
+		self.oracle_names = oracles
+			.polys()
+			.filter_map(|oracle| oracle.name.clone())
+			.collect();
+		drop(oracles);
+		if let Some(witness) = &self.witness {
+			witness.truncate(n_oracles);
+		}
+		self.name_collisions = name_collisions;
+		self.step_down_dedup
+			.retain(|_, selector| selector.index() < n_oracles);
+
+		self.constraints.truncate(n_constraints);
+		self.non_zero_oracle_ids.truncate(n_non_zero_oracle_ids);
+		self.flushes.truncate(n_flushes);
+		self.exponents.truncate(n_exponents);
+		self.next_channel_id = next_channel_id;
+		self.public_inputs.truncate(n_public_inputs);
+		self.high_degree_constraints
+			.truncate(n_high_degree_constraints);
+		self.skipped_constraints.truncate(n_skipped_constraints);
+	}
+
+	/// Registers a read-write memory with the given addresses and initial contents.
+	///
+	/// `addresses` is a [`BinaryField32b`] column of distinct addresses and `init_values` an
+	/// `FValue` column of the same number of rows holding the initial value at each address. The
+	/// memory is read and written with [`Self::access_memory`]. The memory checking argument is
+	/// added to the constraint system when the witness is taken or the constraint system is
+	/// built, so all accesses must be made before then.
+	pub fn add_memory<FValue>(
+		&mut self,
+		name: impl ToString,
+		addresses: OracleId,
+		init_values: OracleId,
+	) -> anyhow::Result<MemoryId>
+	where
+		U: PackScalar<FValue> + Pod,
+		F: ExtensionField<FValue>,
+		FValue: TowerField,
+	{
+		self.log_rows([addresses, init_values])?;
+		let tower_levels = {
+			let oracles = self.oracles.borrow();
+			[addresses, init_values].map(|id| oracles.oracle(id).tower_level)
+		};
+		ensure!(
+			tower_levels == [BinaryField32b::TOWER_LEVEL, FValue::TOWER_LEVEL],
+			"add_memory: expected 32-bit addresses and values at tower level {}",
+			FValue::TOWER_LEVEL
+		);
+		let memory_id = MemoryId(self.memories.len());
+		self.memories
+			.push(Memory::new::<FValue>(self.scoped_name(name), addresses, init_values));
+		Ok(memory_id)
+	}
+
+	/// Adds an access to a memory for each of the first `count` rows of the columns.
+	///
+	/// The access at a row reads `read_value` from the address `address` and writes
+	/// `write_value` to it; a load writes back the value it reads, by passing the same column
+	/// twice. `timestamp` is a [`BinaryField32b`] column of positive integers ordering the
+	/// accesses, which must be distinct across all the accesses to the memory. Every access must
+	/// read the value written by the access to the same address with the greatest smaller
+	/// timestamp, or the initial value if there is none.
+	pub fn access_memory(
+		&mut self,
+		memory_id: MemoryId,
+		count: usize,
+		address: OracleId,
+		read_value: OracleId,
+		write_value: OracleId,
+		timestamp: OracleId,
+	) -> anyhow::Result<()> {
+		let log_rows = self.log_rows([address, read_value, write_value, timestamp])?;
+		ensure!(count <= 1 << log_rows, "access_memory: count exceeds the number of rows");
+
+		let memory = self
+			.memories
+			.get_mut(memory_id.0)
+			.ok_or_else(|| anyhow!("access_memory: unknown memory {memory_id:?}"))?;
+		memory.add_access(count, address, read_value, write_value, timestamp);
+		Ok(())
+	}
+
+	/// Adds the memory checking arguments for all registered memories.
+	///
+	/// Memory names are already scoped, so the memories are compiled outside of any namespace.
+	fn compile_memories(&mut self) -> anyhow::Result<()> {
+		let namespace_path = mem::take(&mut self.namespace_path);
+		let result = mem::take(&mut self.memories)
+			.into_iter()
+			.try_for_each(|memory| memory.compile(self));
+		self.namespace_path = namespace_path;
+		result
+	}
+
+	/// Sets the maximum degree of the zerocheck constraints.
+	///
+	/// Constraints asserted afterwards with a higher degree are split into constraints of degree
+	/// at most `max_degree`, with the high-degree subexpressions committed as auxiliary columns.
+	/// Trading a higher constraint degree for extra commitments pays off for large compositions,
+	/// as the zerocheck prover cost grows with the composition degree.
+	///
+	/// ## Panics
+	///
+	/// * if `max_degree` is less than 2
+	pub fn set_max_constraint_degree(&mut self, max_degree: usize) {
+		assert!(max_degree >= 2, "max constraint degree must be at least 2");
+		self.max_constraint_degree = Some(max_degree);
+	}
+
+	/// Asserts that `composition` of the `oracle_ids` columns is zero on every row.
+	///
+	/// The constraint is named `name` in the current namespace. Inside a disabled scope, the
+	/// constraint is skipped, see [`Self::set_scope_enabled`].
+	///
+	/// Common subexpressions of the composition are merged. If a maximum constraint degree is set
+	/// and the composition exceeds it, the degree is reduced when the constraint system is built,
+	/// see [`Self::set_max_constraint_degree`].
 	pub fn assert_zero(
 		&mut self,
 		name: impl ToString,
 		oracle_ids: impl IntoIterator<Item = OracleId>,
 		composition: ArithCircuit<F>,
 	) {
-		self.constraints
-			.add_zerocheck(name, oracle_ids, composition);
+		let name = self.scoped_name(name);
+		if !self.is_scope_enabled() {
+			self.skipped_constraints.push(name);
+			return;
+		}
+		let composition = composition.optimize();
+		match self.max_constraint_degree {
+			Some(max_degree) if composition.degree() > max_degree => {
+				self.high_degree_constraints.push(HighDegreeConstraint {
+					name,
+					oracle_ids: oracle_ids.into_iter().collect(),
+					composition,
+					max_degree,
+				});
+			}
+			_ => {
+				self.constraints
+					.add_zerocheck(name, oracle_ids, composition);
+			}
+		}
+	}
+
+	/// Asserts that `composition` of the `oracle_ids` columns is zero on every row where the
+	/// 1-bit `selector` column is one.
+	///
+	/// The constraint is compiled to the product of the selector with the composition, so its
+	/// degree is one more than the degree of the composition. The selector may also appear in
+	/// `oracle_ids`. Otherwise, this behaves like [`Self::assert_zero`].
+	pub fn assert_zero_filtered(
+		&mut self,
+		name: impl ToString,
+		selector: OracleId,
+		oracle_ids: impl IntoIterator<Item = OracleId>,
+		composition: ArithCircuit<F>,
+	) -> anyhow::Result<()> {
+		let selector_tower_level = self.oracles.borrow().oracle(selector).binary_tower_level();
+		ensure!(
+			selector_tower_level == 0,
+			"selector {selector} of constraint {} is not a 1-bit column",
+			name.to_string()
+		);
+
+		let mut oracle_ids = oracle_ids.into_iter().collect::<Vec<_>>();
+		let selector_index = match oracle_ids.iter().position(|&id| id == selector) {
+			Some(index) => index,
+			None => {
+				oracle_ids.push(selector);
+				oracle_ids.len() - 1
+			}
+		};
+		self.assert_zero(name, oracle_ids, ArithCircuit::var(selector_index) * composition);
+		Ok(())
+	}
+
+	/// Asserts that the `permuted` column holds the rows of the `source` column in the order given
+	/// by `permutation`, see [`RowPermutation`].
+	///
+	/// Both columns must have the same number of rows. With a witness, the `source` column must be
+	/// filled before the call.
+	pub fn assert_permuted<FS>(
+		&mut self,
+		name: impl ToString,
+		source: OracleId,
+		permuted: OracleId,
+		permutation: &RowPermutation,
+	) -> anyhow::Result<()>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		permutation::assert_permuted::<FS>(self, name.to_string(), source, permuted, permutation)
+	}
+
+	/// Splits the constraints exceeding the maximum degree, committing the auxiliary columns.
+	///
+	/// Constraint names are already scoped, so the constraints are compiled outside of any
+	/// namespace.
+	fn compile_high_degree_constraints(&mut self) -> anyhow::Result<()> {
+		let namespace_path = mem::take(&mut self.namespace_path);
+		let result = mem::take(&mut self.high_degree_constraints)
+			.into_iter()
+			.try_for_each(|constraint| self.reduce_constraint_degree(constraint));
+		self.namespace_path = namespace_path;
+		result
+	}
+
+	fn reduce_constraint_degree(&mut self, constraint: HighDegreeConstraint) -> anyhow::Result<()> {
+		let HighDegreeConstraint {
+			name,
+			oracle_ids: mut inner,
+			composition,
+			max_degree,
+		} = constraint;
+		ensure!(
+			composition.n_vars() <= inner.len(),
+			"{name}: composition has {} variables, but only {} columns were given",
+			composition.n_vars(),
+			inner.len()
+		);
+
+		let n_vars = self.log_rows(inner.iter().copied())?;
+		let DegreeReduction {
+			aux_definitions,
+			circuit,
+		} = composition.reduce_degree(inner.len(), max_degree);
+
+		for (i, definition) in aux_definitions.into_iter().enumerate() {
+			let aux_name = format!("{name}_aux_{i}");
+			let tower_level = {
+				let oracles = self.oracles.borrow();
+				inner
+					.iter()
+					.map(|&id| oracles.oracle(id).tower_level)
+					.max()
+					.unwrap_or(0)
+					.max(definition.binary_tower_level())
+			};
+			let aux_id = self.add_committed(&aux_name, n_vars, tower_level);
+
+			if let Some(witness) = self.witness() {
+				fill_composite_at_tower_level(witness, tower_level, aux_id, &inner, &definition)?;
+			}
+
+			let aux_var = ArithCircuit::var(inner.len());
+			inner.push(aux_id);
+			self.constraints
+				.add_zerocheck(aux_name, inner.iter().copied(), definition - aux_var);
+		}
+
+		self.constraints.add_zerocheck(name, inner, circuit);
+		Ok(())
+	}
+
+	/// Evaluates every zerocheck constraint over the witness and reports the rows where the
+	/// constraints don't hold.
+	///
+	/// This is a debugging aid: a failing zerocheck otherwise surfaces as an opaque proving
+	/// error. For each violated constraint, at most `max_rows` of the failing rows are reported.
+	/// Returns an empty vector if all constraints hold.
+	pub fn check_witness(&self, max_rows: usize) -> anyhow::Result<Vec<ConstraintViolation>> {
+		let witness = self
+			.witness
+			.as_ref()
+			.ok_or_else(|| anyhow!("check_witness: the builder has no witness"))?;
+
+		let constraints =
+			self.constraints
+				.zerochecks()
+				.chain(self.high_degree_constraints.iter().map(|constraint| {
+					(
+						constraint.name.as_str(),
+						constraint.oracle_ids.as_slice(),
+						&constraint.composition,
+					)
+				}));
+
+		let mut violations = Vec::new();
+		for (name, oracle_ids, composition) in constraints {
+			let n_vars = self.log_rows(oracle_ids.iter().copied())?;
+			let multilinears = oracle_ids
+				.iter()
+				.map(|&id| witness.get_multilin_poly(id))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let mut rows = Vec::new();
+			let mut query = Vec::with_capacity(multilinears.len());
+			for row in 0..1 << n_vars {
+				if rows.len() == max_rows {
+					break;
+				}
+				query.clear();
+				for multilinear in &multilinears {
+					query.push(multilinear.evaluate_on_hypercube(row)?);
+				}
+				if composition.evaluate(&query)? != F::ZERO {
+					rows.push(row);
+				}
+			}
+
+			if !rows.is_empty() {
+				violations.push(ConstraintViolation {
+					name: name.to_string(),
+					oracle_ids: oracle_ids.to_vec(),
+					rows,
+				});
+			}
+		}
+		Ok(violations)
 	}
 
 	pub fn assert_not_zero(&mut self, oracle_id: OracleId) {
@@ -231,15 +732,83 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		channel_id
 	}
 
+	/// Adds a public input or output of the statement, flushed to a channel of the circuit.
+	///
+	/// The public inputs are not part of the constraint system, so that one constraint system can
+	/// prove many statements. Pass [`Self::public_inputs`] as the boundaries of
+	/// `constraint_system::prove` and `constraint_system::verify`, which bind the proof to them.
+	pub fn add_public_input(&mut self, boundary: Boundary<F>) -> Result<(), anyhow::Error> {
+		ensure!(
+			boundary.channel_id < self.next_channel_id,
+			"public input flushed to channel {} that was not added",
+			boundary.channel_id
+		);
+		self.public_inputs.push(boundary);
+		Ok(())
+	}
+
+	/// The public inputs and outputs added with [`Self::add_public_input`].
+	pub fn public_inputs(&self) -> &[Boundary<F>] {
+		&self.public_inputs
+	}
+
+	/// Starts a new commit phase. Oracles committed from now on are committed and opened
+	/// separately from those added before, after the earlier commitments are observed.
+	///
+	/// Returns the index of the new commit phase.
+	pub fn start_commit_phase(&mut self) -> usize {
+		self.oracles.borrow_mut().start_commit_phase()
+	}
+
+	/// Adds a column with the value of the challenge sampled at the start of the current commit
+	/// phase, after the commitments of the previous phases are observed.
+	///
+	/// The prover fills the witness of the column, see
+	/// [`binius_core::constraint_system::prove_with_commit_phases`]. Fails in the first commit
+	/// phase, which has no challenge.
+	pub fn add_commit_phase_challenge(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+	) -> Result<OracleId, OracleError> {
+		let name = self.oracle_name(name);
+		self.oracles
+			.borrow_mut()
+			.add_named(name)
+			.commit_phase_challenge(n_vars)
+	}
+
+	/// Sets how the committed oracles of every commit phase are partitioned into separately
+	/// committed batches. See [`CommitBatching`].
+	pub fn set_commit_batching(&mut self, commit_batching: CommitBatching) {
+		self.oracles
+			.borrow_mut()
+			.set_commit_batching(commit_batching);
+	}
+
+	/// Adds a table of `n_rows` rows, whose columns are padded to the next power of two
+	/// according to `policy`.
+	///
+	/// See [`PaddedTable`].
+	pub fn add_padded_table(
+		&mut self,
+		name: impl ToString,
+		n_rows: usize,
+		policy: PaddingPolicy,
+	) -> anyhow::Result<PaddedTable> {
+		PaddedTable::new(self, name.to_string(), n_rows, policy)
+	}
+
 	pub fn add_committed(
 		&mut self,
 		name: impl ToString,
 		n_vars: usize,
 		tower_level: usize,
 	) -> OracleId {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.committed(n_vars, tower_level)
 	}
 
@@ -292,9 +861,10 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		n_vars: usize,
 		tower_level: usize,
 	) -> [OracleId; N] {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.committed_multiple(n_vars, tower_level)
 	}
 
@@ -304,9 +874,10 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		n_vars: usize,
 		inner: impl IntoIterator<Item = (OracleId, F)>,
 	) -> Result<OracleId, OracleError> {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.linear_combination(n_vars, inner)
 	}
 
@@ -317,9 +888,10 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		offset: F,
 		inner: impl IntoIterator<Item = (OracleId, F)>,
 	) -> Result<OracleId, OracleError> {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.linear_combination_with_offset(n_vars, offset, inner)
 	}
 
@@ -330,21 +902,146 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		inner: impl IntoIterator<Item = OracleId>,
 		comp: ArithCircuit<F>,
 	) -> Result<OracleId, OracleError> {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.composite_mle(n_vars, inner, comp)
 	}
 
+	/// Adds a virtual column defined by an arithmetic expression over existing columns.
+	///
+	/// The column is a composite oracle, so it is never committed: the verifier reduces
+	/// evaluation claims on it to claims on the `inner` columns. When the builder has a witness,
+	/// the column is filled by evaluating `comp` row by row. `FS` is the field of the column
+	/// values, and must match the tower level of the oracle, which is the highest tower level
+	/// of the inner columns.
+	pub fn add_virtual<FS>(
+		&mut self,
+		name: impl ToString,
+		inner: impl IntoIterator<Item = OracleId>,
+		comp: ArithCircuit<F>,
+	) -> Result<OracleId, anyhow::Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let inner = inner.into_iter().collect::<Vec<_>>();
+		let n_vars = self.log_rows(inner.iter().copied())?;
+		let id = self.add_composite_mle(name, n_vars, inner.iter().copied(), comp.clone())?;
+
+		let tower_level = self.oracles.borrow().oracle(id).tower_level;
+		ensure!(
+			tower_level == FS::TOWER_LEVEL,
+			"virtual column has tower level {tower_level}, but values were requested at tower level {}",
+			FS::TOWER_LEVEL
+		);
+
+		if let Some(witness) = self.witness() {
+			witness.fill_composite::<FS>(id, &inner, &comp)?;
+		}
+		Ok(id)
+	}
+
+	/// Decomposes the values of the column `id` into `n_bits` committed 1-bit columns, least
+	/// significant bit first, and constrains their recomposition to equal `id`.
+	///
+	/// The bits of a value are its coordinates in the canonical basis over [`BinaryField1b`], so
+	/// this also constrains the values of `id` to be less than `2^n_bits` as unsigned integers.
+	/// When the builder has a witness, the bit columns are filled from the witness of `id`, which
+	/// must be set already and have values in `FS`.
+	pub fn decompose_to_bits<FS>(
+		&mut self,
+		name: impl ToString,
+		id: OracleId,
+		n_bits: usize,
+	) -> Result<Vec<OracleId>, anyhow::Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		ensure!(
+			n_bits <= <FS as ExtensionField<BinaryField1b>>::DEGREE,
+			"{n_bits} bits exceed the width of tower level {}",
+			FS::TOWER_LEVEL
+		);
+
+		self.push_namespace(name);
+		let log_rows = self.log_rows([id])?;
+		let bits = (0..n_bits)
+			.map(|i| self.add_committed(format!("bit_{i}"), log_rows, BinaryField1b::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+
+		if let Some(witness) = self.witness() {
+			witness.fill_bit_decomposition::<FS>(id, &bits)?;
+		}
+
+		let recomposed = (0..n_bits)
+			.map(|i| {
+				ArithCircuit::var(i + 1)
+					* ArithCircuit::constant(<F as ExtensionField<BinaryField1b>>::basis(i))
+			})
+			.sum::<ArithCircuit<F>>();
+		self.assert_zero(
+			"recompose",
+			[id].into_iter().chain(bits.iter().copied()),
+			recomposed - ArithCircuit::var(0),
+		);
+
+		self.pop_namespace();
+		Ok(bits)
+	}
+
+	/// Adds a virtual column whose values have the 1-bit columns `bits` as bits, least
+	/// significant bit first.
+	///
+	/// The column is a linear combination of the bits, so its tower level is the smallest one
+	/// that fits `bits.len()` bits, and `FS` must be the field of that level. When the builder has
+	/// a witness, the column is filled from the witnesses of `bits`, which must be set already.
+	pub fn recompose_from_bits<FS>(
+		&mut self,
+		name: impl ToString,
+		bits: &[OracleId],
+	) -> Result<OracleId, anyhow::Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let n_vars = self.log_rows(bits.iter().copied())?;
+		let id = self.add_linear_combination(
+			name,
+			n_vars,
+			bits.iter()
+				.enumerate()
+				.map(|(i, &bit)| (bit, <F as ExtensionField<BinaryField1b>>::basis(i))),
+		)?;
+
+		let tower_level = self.oracles.borrow().oracle(id).tower_level;
+		ensure!(
+			tower_level == FS::TOWER_LEVEL,
+			"recomposed column has tower level {tower_level}, but values were requested at tower level {}",
+			FS::TOWER_LEVEL
+		);
+
+		if let Some(witness) = self.witness() {
+			witness.fill_bit_recomposition::<FS>(id, bits)?;
+		}
+		Ok(id)
+	}
+
 	pub fn add_packed(
 		&mut self,
 		name: impl ToString,
 		id: OracleId,
 		log_degree: usize,
 	) -> Result<OracleId, OracleError> {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.packed(id, log_degree)
 	}
 
@@ -356,9 +1053,10 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		values: Vec<F>,
 		start_index: usize,
 	) -> Result<OracleId, OracleError> {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.projected(id, values, start_index)
 	}
 
@@ -369,9 +1067,10 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		id: OracleId,
 		values: Vec<F>,
 	) -> Result<OracleId, OracleError> {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.projected_last_vars(id, values)
 	}
 
@@ -381,9 +1080,10 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		id: OracleId,
 		log_count: usize,
 	) -> Result<OracleId, OracleError> {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.repeating(id, log_count)
 	}
 
@@ -395,21 +1095,54 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		block_bits: usize,
 		variant: ShiftVariant,
 	) -> Result<OracleId, OracleError> {
+		let name = self.oracle_name(name);
 		self.oracles
 			.borrow_mut()
-			.add_named(self.scoped_name(name))
+			.add_named(name)
 			.shifted(id, offset, block_bits, variant)
 	}
 
+	/// Adds a view of the column `id` in which row `i` reads row `i + offset`, for expressing
+	/// transitions between rows without committing the shifted values.
+	///
+	/// The last `offset` rows of the view are zero, so transition constraints on them must be
+	/// disabled, for instance with a [`crate::transparent::step_down`] selector. When the builder
+	/// has a witness, the view is filled from the witness of `id`, which must be set already. `FS`
+	/// is the field of the column values and must match the tower level of `id`.
+	pub fn add_next_row<FS>(
+		&mut self,
+		name: impl ToString,
+		id: OracleId,
+		offset: usize,
+	) -> Result<OracleId, anyhow::Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let n_vars = self.log_rows([id])?;
+		let next = self.add_shifted(name, id, offset, n_vars, ShiftVariant::LogicalRight)?;
+
+		let tower_level = self.oracles.borrow().oracle(next).tower_level;
+		ensure!(
+			tower_level == FS::TOWER_LEVEL,
+			"column has tower level {tower_level}, but values were requested at tower level {}",
+			FS::TOWER_LEVEL
+		);
+
+		if let Some(witness) = self.witness() {
+			witness.fill_shifted::<FS>(next, id, offset, n_vars, ShiftVariant::LogicalRight)?;
+		}
+		Ok(next)
+	}
+
 	pub fn add_transparent(
 		&mut self,
 		name: impl ToString,
 		poly: impl MultivariatePoly<F> + 'static,
 	) -> Result<OracleId, OracleError> {
-		self.oracles
-			.borrow_mut()
-			.add_named(self.scoped_name(name))
-			.transparent(poly)
+		let name = self.oracle_name(name);
+		self.oracles.borrow_mut().add_named(name).transparent(poly)
 	}
 
 	/// Adds a zero padding starting at `start_index`, resulting in an output with `n_vars`
@@ -429,10 +1162,22 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		nonzero_index: usize,
 		start_index: usize,
 	) -> Result<OracleId, OracleError> {
-		self.oracles
-			.borrow_mut()
-			.add_named(self.scoped_name(name))
-			.zero_padded(id, n_pad_vars, nonzero_index, start_index)
+		let name = self.oracle_name(name);
+		self.oracles.borrow_mut().add_named(name).zero_padded(
+			id,
+			n_pad_vars,
+			nonzero_index,
+			start_index,
+		)
+	}
+
+	/// Scopes the name of a new oracle, recording it if it is already taken.
+	fn oracle_name(&mut self, name: impl ToString) -> String {
+		let name = self.scoped_name(name);
+		if !self.oracle_names.insert(name.clone()) {
+			self.name_collisions.insert(name.clone());
+		}
+		name
 	}
 
 	fn scoped_name(&self, name: impl ToString) -> String {
@@ -478,6 +1223,91 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		self.namespace_path.pop();
 	}
 
+	/// Runs `f` in the scope `path`, a dot-separated list of namespaces such as `keccak.round3`.
+	///
+	/// The namespaces are pushed for the duration of `f`, so the names of the oracles and
+	/// constraints added by `f` are prefixed with the scope. Scopes nest, and the constraints of a
+	/// scope can be skipped with [`Self::set_scope_enabled`] to test subsets of a large circuit.
+	///
+	/// Example
+	/// ```
+	/// use binius_circuits::builder::ConstraintSystemBuilder;
+	/// use binius_field::{BinaryField1b, TowerField};
+	///
+	/// let mut builder = ConstraintSystemBuilder::new();
+	/// let x = builder.scope("keccak.round3", |builder| {
+	///     builder.add_committed("x", 4, BinaryField1b::TOWER_LEVEL)
+	/// });
+	///
+	/// let system = builder.build().unwrap();
+	/// assert_eq!(system.oracles.oracle(x).name().unwrap(), "keccak::round3::x");
+	/// ```
+	pub fn scope<R>(&mut self, path: &str, f: impl FnOnce(&mut Self) -> R) -> R {
+		let depth = self.namespace_path.len();
+		self.namespace_path
+			.extend(path.split('.').map(str::to_string));
+		let result = f(self);
+		self.namespace_path.truncate(depth);
+		result
+	}
+
+	/// Enables or disables the constraints of the scope `path`, relative to the current
+	/// namespace, and of all the scopes nested in it.
+	///
+	/// The constraints passed to [`Self::assert_zero`] inside a disabled scope are not added to
+	/// the constraint system; their names are reported by [`Self::skipped_constraints`]. Oracles,
+	/// witness columns and channel flushes are still added, so that the enabled scopes can use
+	/// them. Scopes are enabled by default.
+	pub fn set_scope_enabled(&mut self, path: &str, enabled: bool) {
+		let scope = self
+			.namespace_path
+			.iter()
+			.cloned()
+			.chain(path.split('.').map(str::to_string))
+			.collect::<Vec<_>>();
+		self.disabled_scopes.retain(|disabled| *disabled != scope);
+		if !enabled {
+			self.disabled_scopes.push(scope);
+		}
+	}
+
+	/// Whether the constraints of the current namespace are enabled.
+	pub fn is_scope_enabled(&self) -> bool {
+		!self
+			.disabled_scopes
+			.iter()
+			.any(|disabled| self.namespace_path.starts_with(disabled))
+	}
+
+	/// The names of the constraints skipped because their scope was disabled.
+	pub fn skipped_constraints(&self) -> &[String] {
+		&self.skipped_constraints
+	}
+
+	/// The fully scoped names given to more than one column, in name order.
+	///
+	/// Collisions are detected when the columns are added.
+	pub const fn name_collisions(&self) -> &BTreeSet<String> {
+		&self.name_collisions
+	}
+
+	/// Returns the table of the named columns added so far.
+	///
+	/// Fails if two columns have the same fully scoped name.
+	pub fn symbol_table(&self) -> anyhow::Result<SymbolTable> {
+		ensure!(
+			self.name_collisions.is_empty(),
+			"duplicate oracle names: {}",
+			self.name_collisions.iter().join(", ")
+		);
+		SymbolTable::new(&self.oracles.borrow())
+	}
+
+	/// Returns the tower level of the values of the column `id`.
+	pub fn tower_level(&self, id: OracleId) -> usize {
+		self.oracles.borrow().oracle(id).binary_tower_level()
+	}
+
 	/// Returns the number of rows shared by a set of columns.
 	///
 	/// Fails if no columns are provided, or not all columns have the same number of rows.
@@ -500,3 +1330,207 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		Ok(log_rows)
 	}
 }
+
+/// Dispatches [`witness::Builder::fill_composite`] on a tower level known at runtime.
+fn fill_composite_at_tower_level(
+	witness: &witness::Builder,
+	tower_level: usize,
+	id: OracleId,
+	inner: &[OracleId],
+	composition: &ArithCircuit<F>,
+) -> anyhow::Result<()> {
+	match tower_level {
+		0 => witness.fill_composite::<BinaryField1b>(id, inner, composition),
+		1 => witness.fill_composite::<BinaryField2b>(id, inner, composition),
+		2 => witness.fill_composite::<BinaryField4b>(id, inner, composition),
+		3 => witness.fill_composite::<BinaryField8b>(id, inner, composition),
+		4 => witness.fill_composite::<BinaryField16b>(id, inner, composition),
+		5 => witness.fill_composite::<BinaryField32b>(id, inner, composition),
+		6 => witness.fill_composite::<BinaryField64b>(id, inner, composition),
+		7 => witness.fill_composite::<BinaryField128b>(id, inner, composition),
+		_ => bail!(anyhow!("unsupported tower level {tower_level}")),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_macros::arith_expr;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	type B8 = BinaryField8b;
+
+	fn product_circuit(builder: &mut ConstraintSystemBuilder) -> anyhow::Result<()> {
+		builder.set_max_constraint_degree(2);
+
+		let log_size = 8;
+		let [x0, x1, x2, x3, product] = builder.add_committed_multiple("x", log_size, 3);
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut columns =
+				array_util::try_map([x0, x1, x2, x3], |id| witness.new_column::<B8>(id))?;
+			let mut product_column = witness.new_column::<B8>(product)?;
+			for row in 0..1 << log_size {
+				let mut value = B8::ONE;
+				for column in &mut columns {
+					let x = B8::random(&mut rng);
+					column.as_mut_slice::<B8>()[row] = x;
+					value *= x;
+				}
+				product_column.as_mut_slice::<B8>()[row] = value;
+			}
+		}
+
+		builder.assert_zero(
+			"product",
+			[x0, x1, x2, x3, product],
+			arith_expr!([x0, x1, x2, x3, product] = x0 * x1 * x2 * x3 - product).convert_field(),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_assert_zero_reduces_degree() {
+		test_circuit(|builder| {
+			product_circuit(builder)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_assert_zero_commits_auxiliary_columns() {
+		let mut builder = ConstraintSystemBuilder::new();
+		product_circuit(&mut builder).unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		// x0 * x1 and x0 * x1 * x2 are committed
+		assert_eq!(constraint_system.oracles.size(), 7);
+		for constraint_set in &constraint_system.table_constraints {
+			for constraint in &constraint_set.constraints {
+				assert!(constraint.composition.degree() <= 2);
+			}
+		}
+	}
+
+	#[test]
+	fn test_check_witness_reports_failing_rows() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 4;
+		let [x, y] = builder.add_committed_multiple("x", log_size, B8::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut x_column = witness.new_column::<B8>(x).unwrap();
+			let mut y_column = witness.new_column::<B8>(y).unwrap();
+			for (row, (x, y)) in x_column
+				.as_mut_slice::<u8>()
+				.iter_mut()
+				.zip(y_column.as_mut_slice::<u8>())
+				.enumerate()
+			{
+				*x = row as u8;
+				*y = if row % 5 == 3 { 0 } else { row as u8 };
+			}
+		}
+		builder.assert_zero("equal", [x, y], arith_expr!([x, y] = x - y).convert_field());
+		builder.assert_zero("masked", [x, y], arith_expr!([x, y] = y * (x - y)).convert_field());
+
+		assert_eq!(
+			builder.check_witness(2).unwrap(),
+			vec![ConstraintViolation {
+				name: "equal".to_string(),
+				oracle_ids: vec![x, y],
+				rows: vec![3, 8],
+			}]
+		);
+		assert_eq!(builder.check_witness(10).unwrap()[0].rows, vec![3, 8, 13]);
+	}
+
+	#[test]
+	fn test_assert_zero_filtered() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 4;
+		let [x, y] = builder.add_committed_multiple("x", log_size, B8::TOWER_LEVEL);
+		let selector = builder.add_committed("selector", log_size, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut x_column = witness.new_column::<B8>(x).unwrap();
+			let mut y_column = witness.new_column::<B8>(y).unwrap();
+			for (row, (x, y)) in x_column
+				.as_mut_slice::<u8>()
+				.iter_mut()
+				.zip(y_column.as_mut_slice::<u8>())
+				.enumerate()
+			{
+				*x = row as u8;
+				*y = if row < 10 { row as u8 } else { 0 };
+			}
+			// Rows 0..12 are selected.
+			witness
+				.new_column::<BinaryField1b>(selector)
+				.unwrap()
+				.as_mut_slice::<u16>()[0] = 0x0fff;
+		}
+		builder
+			.assert_zero_filtered(
+				"equal",
+				selector,
+				[x, y],
+				arith_expr!([x, y] = x - y).convert_field(),
+			)
+			.unwrap();
+
+		let violations = builder.check_witness(10).unwrap();
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].oracle_ids, vec![x, y, selector]);
+		assert_eq!(violations[0].rows, vec![10, 11]);
+
+		assert!(
+			builder
+				.assert_zero_filtered("bad", x, [y], arith_expr!([y] = y).convert_field())
+				.is_err()
+		);
+	}
+
+	#[test]
+	fn test_check_witness_accepts_valid_witness() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		product_circuit(&mut builder).unwrap();
+		assert_eq!(builder.check_witness(1).unwrap(), vec![]);
+	}
+
+	#[test]
+	fn test_disabled_scope_skips_constraints() {
+		let mut builder = ConstraintSystemBuilder::new();
+		builder.set_scope_enabled("a.b", false);
+		let (x, y) = builder.scope("a", |builder| {
+			let x = builder.add_committed("x", 4, B8::TOWER_LEVEL);
+			builder.assert_zero("x", [x], arith_expr!([x] = x).convert_field());
+			let y = builder.scope("b", |builder| {
+				assert!(!builder.is_scope_enabled());
+				let y = builder.add_committed("y", 4, B8::TOWER_LEVEL);
+				builder.assert_zero("y", [x, y], arith_expr!([x, y] = x - y).convert_field());
+				y
+			});
+			(x, y)
+		});
+		assert!(builder.is_scope_enabled());
+		assert_eq!(builder.skipped_constraints(), ["a::b::y"]);
+
+		let system = builder.build().unwrap();
+		assert_eq!(system.oracles.oracle(x).name().unwrap(), "a::x");
+		assert_eq!(system.oracles.oracle(y).name().unwrap(), "a::b::y");
+		let names = system
+			.table_constraints
+			.iter()
+			.flat_map(|constraint_set| &constraint_set.constraints)
+			.map(|constraint| constraint.name.as_str())
+			.collect::<Vec<_>>();
+		assert_eq!(names, ["a::x"]);
+	}
+}
@@ -0,0 +1,212 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Lookup tables managed by the [`ConstraintSystemBuilder`].
+//!
+//! Tables are registered with [`ConstraintSystemBuilder::add_lookup_table`] and read from with
+//! [`ConstraintSystemBuilder::read_from_table`]. The reads are collected until the witness is taken
+//! or the constraint system is built. At that point every table is compiled to a
+//! [`plain_lookup`](crate::plain_lookup::plain_lookup) argument, whose channel balancing is proven
+//! with the LogUp GKR protocol. The multiplicities of the table rows are counted from the witness.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{
+	ExtensionField, TowerField, as_packed_field::PackScalar, packed::get_packed_slice,
+};
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+use bytemuck::Pod;
+
+use super::{
+	ConstraintSystemBuilder,
+	types::{F, U},
+};
+use crate::plain_lookup::{count_multiplicities, plain_lookup_with_log_max_multiplicity};
+
+/// Identifier of a lookup table registered with [`ConstraintSystemBuilder::add_lookup_table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LookupTableId(pub(super) usize);
+
+/// A lookup table with the reads made from it so far.
+pub(super) struct LookupTable {
+	name: String,
+	columns: Vec<OracleId>,
+	/// The number of values read and the columns they are read from.
+	reads: Vec<(usize, Vec<OracleId>)>,
+	/// Compiles the table, monomorphized over the field of the table values.
+	compile: fn(&mut ConstraintSystemBuilder<'_>, &Self) -> Result<()>,
+}
+
+impl LookupTable {
+	pub fn new<FTable>(name: String, columns: Vec<OracleId>) -> Self
+	where
+		U: PackScalar<FTable> + Pod,
+		F: ExtensionField<FTable>,
+		FTable: TowerField,
+	{
+		Self {
+			name,
+			columns,
+			reads: Vec::new(),
+			compile: compile_lookup_table::<FTable>,
+		}
+	}
+
+	pub fn n_columns(&self) -> usize {
+		self.columns.len()
+	}
+
+	pub fn add_read(&mut self, count: usize, columns: Vec<OracleId>) {
+		self.reads.push((count, columns));
+	}
+
+	pub fn compile(&self, builder: &mut ConstraintSystemBuilder<'_>) -> Result<()> {
+		(self.compile)(builder, self)
+	}
+}
+
+fn compile_lookup_table<FTable>(
+	builder: &mut ConstraintSystemBuilder<'_>,
+	table: &LookupTable,
+) -> Result<()>
+where
+	U: PackScalar<FTable> + Pod,
+	F: ExtensionField<FTable>,
+	FTable: TowerField,
+{
+	let LookupTable {
+		name,
+		columns,
+		reads,
+		..
+	} = table;
+
+	let (n_lookups, lookups_u): (Vec<_>, Vec<_>) = reads.iter().cloned().unzip();
+	let log_max_multiplicity = log2_ceil_usize(n_lookups.iter().sum::<usize>() + 1);
+	let t_log_rows = builder.log_rows(columns.iter().copied())?;
+
+	let multiplicities = match builder.witness() {
+		Some(witness) => {
+			let rows = |columns: &[OracleId], count: usize| -> Result<Vec<Vec<FTable>>> {
+				let packed_columns = columns
+					.iter()
+					.map(|&id| Ok(witness.get::<FTable>(id)?.packed()))
+					.collect::<Result<Vec<_>>>()?;
+				Ok((0..count)
+					.map(|i| {
+						packed_columns
+							.iter()
+							.map(|packed| get_packed_slice(packed, i))
+							.collect()
+					})
+					.collect())
+			};
+
+			let table_rows = rows(columns, 1 << t_log_rows)?;
+			let mut read_rows = Vec::with_capacity(n_lookups.iter().sum());
+			for (lookup_u, &count) in lookups_u.iter().zip(&n_lookups) {
+				read_rows.extend(rows(lookup_u, count)?);
+			}
+			Some(count_multiplicities(&table_rows, &read_rows, true)?)
+		}
+		None => None,
+	};
+
+	plain_lookup_with_log_max_multiplicity::<FTable>(
+		builder,
+		name,
+		log_max_multiplicity,
+		&n_lookups,
+		&lookups_u,
+		columns,
+		multiplicities,
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::Cell;
+
+	use anyhow::ensure;
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::BinaryField16b;
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{
+		builder::{WitnessStorage, test_utils::test_circuit},
+		transparent,
+	};
+
+	type B16 = BinaryField16b;
+
+	fn u8_range_check(builder: &mut ConstraintSystemBuilder, max_value: u16) -> Result<()> {
+		let table_values = (0..=255).map(B16::new).collect::<Vec<_>>();
+		let table = transparent::make_transparent(builder, "u8_table", &table_values)?;
+		let table_id = builder.add_lookup_table::<B16>("u8_range", [table])?;
+
+		let log_size = 10;
+		let values = builder.add_committed("values", log_size, B16::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut values_witness = witness.new_column::<B16>(values)?;
+			for value in values_witness.as_mut_slice::<u16>() {
+				*value = rng.gen_range(0..=max_value);
+			}
+		}
+
+		builder.read_from_table(table_id, 1000, [values])
+	}
+
+	#[test]
+	fn test_read_from_table() {
+		test_circuit(|builder| {
+			u8_range_check(builder, 255)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_lookup_table_compiles_after_failure() {
+		/// A storage that fails to allocate columns while `fail` is set.
+		#[derive(Default)]
+		struct FailingStorage {
+			allocator: bumpalo::Bump,
+			fail: Cell<bool>,
+		}
+
+		impl WitnessStorage for FailingStorage {
+			fn alloc_column(&self, len: usize, value: U) -> Result<&mut [U]> {
+				ensure!(!self.fail.get(), "out of witness storage");
+				self.allocator.alloc_column(len, value)
+			}
+		}
+
+		let mut verifier_builder = ConstraintSystemBuilder::new();
+		u8_range_check(&mut verifier_builder, 255).unwrap();
+		let constraint_system = verifier_builder.build().unwrap();
+
+		let storage = FailingStorage::default();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&storage);
+		u8_range_check(&mut builder, 255).unwrap();
+		// The multiplicity columns of the table are added before their witness fails to allocate.
+		storage.fail.set(true);
+		assert!(builder.take_witness().is_err());
+		storage.fail.set(false);
+
+		let witness = builder.take_witness().unwrap();
+		assert!(builder.name_collisions().is_empty());
+		let prover_constraint_system = builder.build().unwrap();
+		assert_eq!(prover_constraint_system.oracles.size(), constraint_system.oracles.size());
+		assert_eq!(prover_constraint_system.flushes.len(), constraint_system.flushes.len());
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_read_from_table_rejects_value_not_in_table() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		u8_range_check(&mut builder, 1000).unwrap();
+		assert!(builder.take_witness().is_err());
+	}
+}
@@ -0,0 +1,377 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Read-write memories managed by the [`ConstraintSystemBuilder`].
+//!
+//! Memories are registered with [`ConstraintSystemBuilder::add_memory`] and accessed with
+//! [`ConstraintSystemBuilder::access_memory`]. The accesses are collected until the witness is
+//! taken or the constraint system is built, and every memory is then compiled to an offline memory
+//! checking argument over a channel of `(address, value, timestamp)` tuples:
+//!
+//! * the initial contents are pushed with timestamp 0,
+//! * every access pulls the tuple last written to its address and pushes the tuple it writes, with
+//!   a timestamp greater than the pulled one,
+//! * the final contents are pulled.
+//!
+//! The channel balances if and only if every access reads the value last written to its address,
+//! given that the timestamps of the accesses are distinct. The timestamps of the pulled tuples and
+//! the final contents are committed by the prover and computed from the witness.
+
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow, ensure};
+use binius_core::{constraint_system::channel::OracleOrConst, oracle::OracleId};
+use binius_field::{
+	BinaryField32b, ExtensionField, Field, TowerField,
+	as_packed_field::PackScalar,
+	packed::{get_packed_slice, set_packed_slice},
+};
+use binius_math::ArithCircuit;
+use bytemuck::Pod;
+
+use super::{
+	ConstraintSystemBuilder,
+	types::{F, U},
+};
+use crate::{arithmetic::compare::less_than, range_check::range_check, transparent::step_down};
+
+type B32 = BinaryField32b;
+
+/// Identifier of a memory registered with [`ConstraintSystemBuilder::add_memory`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MemoryId(pub(super) usize);
+
+/// A batch of memory accesses, one per row of the first `count` rows of the columns.
+struct MemoryAccess {
+	count: usize,
+	address: OracleId,
+	read_value: OracleId,
+	write_value: OracleId,
+	timestamp: OracleId,
+}
+
+/// A memory with the accesses made to it so far.
+pub(super) struct Memory {
+	name: String,
+	addresses: OracleId,
+	init_values: OracleId,
+	accesses: Vec<MemoryAccess>,
+	/// Compiles the memory, monomorphized over the field of the memory values.
+	compile: fn(&mut ConstraintSystemBuilder<'_>, Self) -> Result<()>,
+}
+
+impl Memory {
+	pub fn new<FValue>(name: String, addresses: OracleId, init_values: OracleId) -> Self
+	where
+		U: PackScalar<FValue> + Pod,
+		F: ExtensionField<FValue>,
+		FValue: TowerField,
+	{
+		Self {
+			name,
+			addresses,
+			init_values,
+			accesses: Vec::new(),
+			compile: compile_memory::<FValue>,
+		}
+	}
+
+	pub fn add_access(
+		&mut self,
+		count: usize,
+		address: OracleId,
+		read_value: OracleId,
+		write_value: OracleId,
+		timestamp: OracleId,
+	) {
+		self.accesses.push(MemoryAccess {
+			count,
+			address,
+			read_value,
+			write_value,
+			timestamp,
+		});
+	}
+
+	pub fn compile(self, builder: &mut ConstraintSystemBuilder<'_>) -> Result<()> {
+		(self.compile)(builder, self)
+	}
+}
+
+/// The committed values of a memory that are computed by replaying the accesses on the witness.
+struct MemoryTrace<FValue> {
+	/// For every access batch, the timestamps of the tuples pulled by the accesses.
+	prev_timestamps: Vec<Vec<u32>>,
+	final_values: Vec<FValue>,
+	final_timestamps: Vec<u32>,
+}
+
+/// Replays the accesses in timestamp order, checking that every access reads the value last
+/// written to its address.
+fn replay_accesses<FValue>(
+	builder: &mut ConstraintSystemBuilder<'_>,
+	memory: &Memory,
+) -> Result<Option<MemoryTrace<FValue>>>
+where
+	U: PackScalar<FValue> + Pod,
+	F: ExtensionField<FValue>,
+	FValue: TowerField,
+{
+	let log_rows = builder.log_rows([memory.addresses, memory.init_values])?;
+	let access_log_rows = memory
+		.accesses
+		.iter()
+		.map(|access| builder.log_rows([access.timestamp]))
+		.collect::<Result<Vec<_>>>()?;
+	let Some(witness) = builder.witness() else {
+		return Ok(None);
+	};
+
+	let addresses = witness.get::<B32>(memory.addresses)?.as_slice::<u32>();
+	let init_values = witness.get::<FValue>(memory.init_values)?.packed();
+
+	let mut rows = HashMap::with_capacity(1 << log_rows);
+	for (row, &address) in addresses.iter().enumerate().take(1 << log_rows) {
+		ensure!(
+			rows.insert(address, row).is_none(),
+			"memory {}: address {address} is repeated",
+			memory.name
+		);
+	}
+	let mut final_values = (0..1 << log_rows)
+		.map(|row| get_packed_slice(init_values, row))
+		.collect::<Vec<FValue>>();
+	let mut final_timestamps = vec![0u32; 1 << log_rows];
+
+	let mut accesses = Vec::new();
+	for (batch, access) in memory.accesses.iter().enumerate() {
+		let timestamps = witness.get::<B32>(access.timestamp)?.as_slice::<u32>();
+		accesses.extend((0..access.count).map(|row| (timestamps[row], batch, row)));
+	}
+	accesses.sort_unstable();
+	for pair in accesses.windows(2) {
+		ensure!(
+			pair[0].0 != pair[1].0,
+			"memory {}: timestamp {} is repeated",
+			memory.name,
+			pair[0].0
+		);
+	}
+
+	let mut prev_timestamps = access_log_rows
+		.iter()
+		.map(|&log_rows| vec![0u32; 1 << log_rows])
+		.collect::<Vec<_>>();
+	for (timestamp, batch, row) in accesses {
+		let access = &memory.accesses[batch];
+		let address = witness.get::<B32>(access.address)?.as_slice::<u32>()[row];
+		let read_value = get_packed_slice(witness.get::<FValue>(access.read_value)?.packed(), row);
+		let write_value =
+			get_packed_slice(witness.get::<FValue>(access.write_value)?.packed(), row);
+
+		let &memory_row = rows.get(&address).ok_or_else(|| {
+			anyhow!(
+				"memory {}: access at timestamp {timestamp} to unknown address {address}",
+				memory.name
+			)
+		})?;
+		ensure!(timestamp > 0, "memory {}: access timestamps must be positive", memory.name);
+		ensure!(
+			read_value == final_values[memory_row],
+			"memory {}: access at timestamp {timestamp} reads a stale value from address {address}",
+			memory.name
+		);
+
+		prev_timestamps[batch][row] = final_timestamps[memory_row];
+		final_values[memory_row] = write_value;
+		final_timestamps[memory_row] = timestamp;
+	}
+
+	Ok(Some(MemoryTrace {
+		prev_timestamps,
+		final_values,
+		final_timestamps,
+	}))
+}
+
+fn compile_memory<FValue>(builder: &mut ConstraintSystemBuilder<'_>, memory: Memory) -> Result<()>
+where
+	U: PackScalar<FValue> + Pod,
+	F: ExtensionField<FValue>,
+	FValue: TowerField,
+{
+	let trace = replay_accesses::<FValue>(builder, &memory)?;
+	let Memory {
+		name,
+		addresses,
+		init_values,
+		accesses,
+		..
+	} = memory;
+
+	builder.push_namespace(name);
+	let channel = builder.add_channel();
+	let log_rows = builder.log_rows([addresses, init_values])?;
+	let final_values = builder.add_committed("final_values", log_rows, FValue::TOWER_LEVEL);
+	let final_timestamps = builder.add_committed("final_timestamps", log_rows, B32::TOWER_LEVEL);
+	let prev_timestamps = accesses
+		.iter()
+		.enumerate()
+		.map(|(i, access)| {
+			let log_rows = builder.log_rows([
+				access.address,
+				access.read_value,
+				access.write_value,
+				access.timestamp,
+			])?;
+			Ok(builder.add_committed(format!("prev_timestamps_{i}"), log_rows, B32::TOWER_LEVEL))
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	if let (Some(witness), Some(trace)) = (builder.witness(), trace) {
+		let mut final_values_witness = witness.new_column::<FValue>(final_values)?;
+		for (row, &value) in trace.final_values.iter().enumerate() {
+			set_packed_slice(final_values_witness.packed(), row, value);
+		}
+		witness
+			.new_column::<B32>(final_timestamps)?
+			.as_mut_slice::<u32>()[..1 << log_rows]
+			.copy_from_slice(&trace.final_timestamps);
+		for (&id, values) in prev_timestamps.iter().zip(&trace.prev_timestamps) {
+			witness.new_column::<B32>(id)?.as_mut_slice::<u32>()[..values.len()]
+				.copy_from_slice(values);
+		}
+	}
+
+	let zero_timestamp = OracleOrConst::Const {
+		base: F::ZERO,
+		tower_level: B32::TOWER_LEVEL,
+	};
+	builder.send(
+		channel,
+		1 << log_rows,
+		[
+			OracleOrConst::Oracle(addresses),
+			OracleOrConst::Oracle(init_values),
+			zero_timestamp,
+		],
+	)?;
+	builder.receive(
+		channel,
+		1 << log_rows,
+		[addresses, final_values, final_timestamps].map(OracleOrConst::Oracle),
+	)?;
+
+	for (i, (access, &prev_timestamp)) in accesses.iter().zip(&prev_timestamps).enumerate() {
+		builder.receive(
+			channel,
+			access.count,
+			[access.address, access.read_value, prev_timestamp].map(OracleOrConst::Oracle),
+		)?;
+		builder.send(
+			channel,
+			access.count,
+			[access.address, access.write_value, access.timestamp].map(OracleOrConst::Oracle),
+		)?;
+
+		builder.push_namespace(format!("access_{i}"));
+		let prev_bits = range_check::<B32>(builder, "prev_timestamp_bits", prev_timestamp, 32)?;
+		let bits = range_check::<B32>(builder, "timestamp_bits", access.timestamp, 32)?;
+		let ordered = less_than(builder, "ordered", prev_bits, bits)?;
+		let log_rows = builder.log_rows([access.timestamp])?;
+		let enabled = step_down(builder, "enabled", log_rows, access.count)?;
+		builder.assert_zero(
+			"ordered",
+			[ordered, enabled],
+			(ArithCircuit::var(0) + ArithCircuit::one()) * ArithCircuit::var(1),
+		);
+		builder.pop_namespace();
+	}
+
+	builder.pop_namespace();
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField8b;
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{builder::test_utils::test_circuit, transparent};
+
+	type B8 = BinaryField8b;
+
+	const LOG_MEMORY_SIZE: usize = 4;
+	const LOG_ACCESSES: usize = 6;
+	const N_ACCESSES: usize = 50;
+
+	/// Adds a memory of bytes and a batch of random loads and stores, with the read values
+	/// computed by running the accesses. If `corrupt` is set, one load reads a wrong value.
+	fn loads_and_stores(builder: &mut ConstraintSystemBuilder, corrupt: bool) -> Result<()> {
+		let addresses = (0..1 << LOG_MEMORY_SIZE)
+			.map(|address| B32::new(0x1000 + 4 * address))
+			.collect::<Vec<_>>();
+		let addresses = transparent::make_transparent(builder, "addresses", &addresses)?;
+		let init_values = builder.add_committed("init_values", LOG_MEMORY_SIZE, B8::TOWER_LEVEL);
+		let memory = builder.add_memory::<B8>("memory", addresses, init_values)?;
+
+		let address = builder.add_committed("address", LOG_ACCESSES, B32::TOWER_LEVEL);
+		let read_value = builder.add_committed("read_value", LOG_ACCESSES, B8::TOWER_LEVEL);
+		let write_value = builder.add_committed("write_value", LOG_ACCESSES, B8::TOWER_LEVEL);
+		let timestamp = builder.add_committed("timestamp", LOG_ACCESSES, B32::TOWER_LEVEL);
+
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut contents = [0u8; 1 << LOG_MEMORY_SIZE];
+			rng.fill(&mut contents);
+			witness.new_column::<B8>(init_values)?.as_mut_slice::<u8>()[..contents.len()]
+				.copy_from_slice(&contents);
+
+			let mut address_witness = witness.new_column::<B32>(address)?;
+			let mut read_witness = witness.new_column::<B8>(read_value)?;
+			let mut write_witness = witness.new_column::<B8>(write_value)?;
+			let mut timestamp_witness = witness.new_column::<B32>(timestamp)?;
+			let (address_values, read_values, write_values, timestamps) = (
+				address_witness.as_mut_slice::<u32>(),
+				read_witness.as_mut_slice::<u8>(),
+				write_witness.as_mut_slice::<u8>(),
+				timestamp_witness.as_mut_slice::<u32>(),
+			);
+			// Write the accesses in reverse timestamp order, to exercise the replay ordering.
+			for (i, row) in (0..N_ACCESSES).rev().enumerate() {
+				let index = rng.gen_range(0..contents.len());
+				address_values[row] = 0x1000 + 4 * index as u32;
+				timestamps[row] = 3 * i as u32 + 1;
+				read_values[row] = contents[index];
+				write_values[row] = if rng.r#gen() {
+					rng.r#gen()
+				} else {
+					contents[index]
+				};
+				contents[index] = write_values[row];
+			}
+			if corrupt {
+				read_values[N_ACCESSES / 2] ^= 1;
+			}
+		}
+
+		builder.access_memory(memory, N_ACCESSES, address, read_value, write_value, timestamp)
+	}
+
+	#[test]
+	fn test_memory_loads_and_stores() {
+		test_circuit(|builder| {
+			loads_and_stores(builder, false)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_memory_rejects_stale_read() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		loads_and_stores(&mut builder, true).unwrap();
+		assert!(builder.take_witness().is_err());
+	}
+}
@@ -1,8 +1,20 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 pub mod constraint_system;
+pub mod lookup;
+pub mod memory;
+pub mod padding;
+pub mod permutation;
+pub mod storage;
+pub mod symbols;
 pub mod test_utils;
 pub mod types;
 pub mod witness;
 
-pub use constraint_system::ConstraintSystemBuilder;
+pub use constraint_system::{ConstraintSystemBuilder, ConstraintViolation};
+pub use lookup::LookupTableId;
+pub use memory::MemoryId;
+pub use padding::{PaddedTable, PaddingPolicy};
+pub use permutation::RowPermutation;
+pub use storage::{ChunkAccess, ChunkedMultilinear, MmapWitnessStorage, WitnessStorage};
+pub use symbols::{Symbol, SymbolTable};
@@ -0,0 +1,302 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Tables whose number of rows is not a power of two.
+//!
+//! Columns always have a power-of-two number of rows. A [`PaddedTable`], added with
+//! [`ConstraintSystemBuilder::add_padded_table`], declares the real number of rows of a table and
+//! a [`PaddingPolicy`] for the rows up to the next power of two. The columns added with
+//! [`PaddedTable::add_committed`] are constrained to follow the policy, and
+//! [`PaddedTable::fill`] writes the real rows of a column and fills the padding rows to match.
+
+use std::collections::HashMap;
+
+use anyhow::{Result, ensure};
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{
+	ExtensionField, TowerField, as_packed_field::PackScalar, packed::set_packed_slice,
+};
+use binius_macros::arith_expr;
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+
+use super::{
+	ConstraintSystemBuilder,
+	types::{F, U},
+};
+use crate::transparent::step_down;
+
+/// How the rows of a [`PaddedTable`] past its real rows are filled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaddingPolicy {
+	/// The padding rows are zero.
+	Zeros,
+	/// The padding rows repeat the last real row.
+	RepeatLastRow,
+	/// The padding rows are zero, but not constrained. Instead, the constraints and flushes of
+	/// the table must be gated by [`PaddedTable::selector`], for example with
+	/// [`ConstraintSystemBuilder::assert_zero_filtered`].
+	DisabledSelector,
+}
+
+/// A table of `n_rows` rows, padded to the next power of two.
+#[derive(Debug, Clone)]
+pub struct PaddedTable {
+	name: String,
+	n_rows: usize,
+	log_rows: usize,
+	policy: PaddingPolicy,
+	selector: OracleId,
+	/// The selectors of the rows whose next row must be equal, for
+	/// [`PaddingPolicy::RepeatLastRow`].
+	repeat_selectors: Option<[OracleId; 2]>,
+	/// The next-row views of the columns padded with [`PaddingPolicy::RepeatLastRow`].
+	next_rows: HashMap<OracleId, OracleId>,
+}
+
+impl PaddedTable {
+	pub(super) fn new(
+		builder: &mut ConstraintSystemBuilder,
+		name: String,
+		n_rows: usize,
+		policy: PaddingPolicy,
+	) -> Result<Self> {
+		ensure!(n_rows > 0, "padded table {name} must have at least one row");
+		let log_rows = log2_ceil_usize(n_rows);
+		let selector = step_down(builder, format!("{name}_selector"), log_rows, n_rows)?;
+		Ok(Self {
+			name,
+			n_rows,
+			log_rows,
+			policy,
+			selector,
+			repeat_selectors: None,
+			next_rows: HashMap::new(),
+		})
+	}
+
+	/// The number of real rows.
+	pub const fn n_rows(&self) -> usize {
+		self.n_rows
+	}
+
+	/// The binary logarithm of the number of rows of the columns, including padding.
+	pub const fn log_rows(&self) -> usize {
+		self.log_rows
+	}
+
+	pub const fn policy(&self) -> PaddingPolicy {
+		self.policy
+	}
+
+	/// The 1-bit transparent column that is one on the real rows and zero on the padding rows.
+	pub const fn selector(&self) -> OracleId {
+		self.selector
+	}
+
+	const fn has_padding(&self) -> bool {
+		self.n_rows < 1 << self.log_rows
+	}
+
+	/// Adds a committed column of `FS` values to the table, constrained according to the padding
+	/// policy.
+	pub fn add_committed<FS: TowerField>(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+	) -> Result<OracleId> {
+		let name = name.to_string();
+		let id = builder.add_committed(&name, self.log_rows, FS::TOWER_LEVEL);
+		if !self.has_padding() {
+			return Ok(id);
+		}
+
+		match self.policy {
+			PaddingPolicy::Zeros => {
+				builder.assert_zero(
+					format!("{name}_padding"),
+					[id, self.selector],
+					arith_expr!([x, real] = x * (1 - real)).convert_field(),
+				);
+			}
+			PaddingPolicy::RepeatLastRow => {
+				let [not_last, before_last_real] = self.repeat_selectors(builder)?;
+				let next = builder.add_shifted(
+					format!("{name}_next"),
+					id,
+					1,
+					self.log_rows,
+					ShiftVariant::LogicalRight,
+				)?;
+				// The selectors differ exactly on the rows from the last real row to the row
+				// before the last, whose next row must be equal.
+				builder.assert_zero(
+					format!("{name}_padding"),
+					[id, next, not_last, before_last_real],
+					arith_expr!(
+						[x, next, not_last, before_last_real] =
+							(not_last - before_last_real) * (next - x)
+					)
+					.convert_field(),
+				);
+				self.next_rows.insert(id, next);
+			}
+			PaddingPolicy::DisabledSelector => {}
+		}
+		Ok(id)
+	}
+
+	fn repeat_selectors(&mut self, builder: &mut ConstraintSystemBuilder) -> Result<[OracleId; 2]> {
+		if let Some(selectors) = self.repeat_selectors {
+			return Ok(selectors);
+		}
+		let not_last = step_down(
+			builder,
+			format!("{}_not_last", self.name),
+			self.log_rows,
+			(1 << self.log_rows) - 1,
+		)?;
+		let before_last_real = step_down(
+			builder,
+			format!("{}_before_last_real", self.name),
+			self.log_rows,
+			self.n_rows - 1,
+		)?;
+		let selectors = [not_last, before_last_real];
+		self.repeat_selectors = Some(selectors);
+		Ok(selectors)
+	}
+
+	/// Writes `values` to the real rows of the column `id` and fills the padding rows according
+	/// to the padding policy.
+	///
+	/// Does nothing if the builder has no witness.
+	pub fn fill<FS>(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		id: OracleId,
+		values: &[FS],
+	) -> Result<()>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		ensure!(
+			values.len() == self.n_rows,
+			"padded table {} has {} rows, but {} values were given",
+			self.name,
+			self.n_rows,
+			values.len()
+		);
+		ensure!(
+			builder.log_rows([id])? == self.log_rows,
+			"column {id} does not belong to padded table {}",
+			self.name
+		);
+		let Some(witness) = builder.witness() else {
+			return Ok(());
+		};
+
+		{
+			let mut column = witness.new_column::<FS>(id)?;
+			let packed = column.packed();
+			for (row, &value) in values.iter().enumerate() {
+				set_packed_slice(packed, row, value);
+			}
+			if self.policy == PaddingPolicy::RepeatLastRow {
+				let last = values[self.n_rows - 1];
+				for row in self.n_rows..1 << self.log_rows {
+					set_packed_slice(packed, row, last);
+				}
+			}
+		}
+
+		if let Some(&next) = self.next_rows.get(&id) {
+			witness.fill_shifted::<FS>(next, id, 1, self.log_rows, ShiftVariant::LogicalRight)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::channel::Boundary;
+	use binius_field::BinaryField8b;
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	type B8 = BinaryField8b;
+
+	const N_ROWS: usize = 37;
+
+	fn padded_column(
+		builder: &mut ConstraintSystemBuilder,
+		policy: PaddingPolicy,
+	) -> Result<Vec<Boundary<F>>> {
+		let mut table = builder.add_padded_table("table", N_ROWS, policy)?;
+		assert_eq!(table.log_rows(), 6);
+
+		let x = table.add_committed::<B8>(builder, "x")?;
+		let values = (0..N_ROWS)
+			.map(|i| B8::new(3 * i as u8 + 1))
+			.collect::<Vec<_>>();
+		table.fill(builder, x, &values)?;
+		Ok(vec![])
+	}
+
+	#[test]
+	fn test_zero_padding() {
+		test_circuit(|builder| padded_column(builder, PaddingPolicy::Zeros)).unwrap();
+	}
+
+	#[test]
+	fn test_repeat_last_row_padding() {
+		test_circuit(|builder| padded_column(builder, PaddingPolicy::RepeatLastRow)).unwrap();
+	}
+
+	#[test]
+	fn test_disabled_selector_padding() {
+		test_circuit(|builder| {
+			let mut table =
+				builder.add_padded_table("table", N_ROWS, PaddingPolicy::DisabledSelector)?;
+			let x = table.add_committed::<B8>(builder, "x")?;
+			let y = table.add_committed::<B8>(builder, "y")?;
+			let x_values = (0..N_ROWS).map(|i| B8::new(i as u8)).collect::<Vec<_>>();
+			let y_values = x_values.iter().map(|&x| x + B8::new(1)).collect::<Vec<_>>();
+			table.fill(builder, x, &x_values)?;
+			table.fill(builder, y, &y_values)?;
+
+			// Fails on the zero padding rows unless gated by the selector.
+			builder.assert_zero_filtered(
+				"y = x + 1",
+				table.selector(),
+				[x, y],
+				arith_expr!([x, y] = y - x - 1).convert_field(),
+			)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_zero_padding_rejects_nonzero_rows() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let mut table = builder
+			.add_padded_table("table", N_ROWS, PaddingPolicy::Zeros)
+			.unwrap();
+		let x = table.add_committed::<B8>(&mut builder, "x").unwrap();
+		builder
+			.witness()
+			.unwrap()
+			.new_column::<B8>(x)
+			.unwrap()
+			.as_mut_slice::<u8>()
+			.fill(1);
+
+		let violations = builder.check_witness(1).unwrap();
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].name, "x_padding");
+		assert_eq!(violations[0].rows, [N_ROWS]);
+	}
+}
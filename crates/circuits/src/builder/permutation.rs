@@ -0,0 +1,251 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Fixed permutations of the rows of a column.
+//!
+//! [`ConstraintSystemBuilder::assert_permuted`] proves that a committed column holds the rows of
+//! another column in a fixed order, without committing any additional data.
+//!
+//! * Rotations are compiled to an equality with a circular shift of the source column, which is a
+//!   virtual oracle.
+//! * Other permutations are compiled to a channel. Every row `i` of the source column is pushed
+//!   together with its index `i`, and every row `j` of the permuted column is pulled together with
+//!   the index `σ(j)` of the row it is taken from. As `σ` is a bijection, the channel balances if
+//!   and only if the permuted column equals the source column at row `σ(j)` on every row `j`. Both
+//!   index columns are transparent.
+
+use anyhow::{Result, ensure};
+use binius_core::{
+	constraint_system::channel::OracleOrConst,
+	oracle::{OracleId, ShiftVariant},
+};
+use binius_field::{BinaryField32b, ExtensionField, TowerField, as_packed_field::PackScalar};
+use binius_macros::arith_expr;
+
+use super::{
+	ConstraintSystemBuilder,
+	types::{F, U},
+};
+use crate::transparent::make_transparent;
+
+type B32 = BinaryField32b;
+
+/// A fixed permutation of the rows of a column, given by the source row of every row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RowPermutation {
+	/// Row `i` is taken from row `i + k`, modulo the number of rows.
+	Rotate(usize),
+	/// Row `i` is taken from the row whose index is `i` with its bits reversed.
+	BitReverse,
+	/// Row `i` is taken from row `sources[i]`. The sources must be a permutation of the rows.
+	Custom(Vec<usize>),
+}
+
+impl RowPermutation {
+	/// Returns the source row of every row of a column with `2^log_rows` rows.
+	pub fn source_rows(&self, log_rows: usize) -> Result<Vec<usize>> {
+		let n_rows = 1 << log_rows;
+		let sources = match self {
+			Self::Rotate(k) => (0..n_rows).map(|i| (i + k) % n_rows).collect(),
+			Self::BitReverse => (0..n_rows).map(|i| reverse_bits(i, log_rows)).collect(),
+			Self::Custom(sources) => {
+				ensure!(
+					sources.len() == n_rows,
+					"permutation of {} rows applied to a column of {n_rows} rows",
+					sources.len()
+				);
+				let mut seen = vec![false; n_rows];
+				for &source in sources {
+					ensure!(
+						source < n_rows && !seen[source],
+						"permutation sources are not a permutation of the rows"
+					);
+					seen[source] = true;
+				}
+				sources.clone()
+			}
+		};
+		Ok(sources)
+	}
+
+	/// Permutes the rows of `values`, which must have a power-of-two length.
+	pub fn apply<T: Copy>(&self, values: &[T]) -> Result<Vec<T>> {
+		ensure!(values.len().is_power_of_two(), "the number of rows must be a power of two");
+		let sources = self.source_rows(values.len().ilog2() as usize)?;
+		Ok(sources.into_iter().map(|source| values[source]).collect())
+	}
+}
+
+const fn reverse_bits(x: usize, log_len: usize) -> usize {
+	if log_len == 0 {
+		0
+	} else {
+		x.reverse_bits() >> (usize::BITS as usize - log_len)
+	}
+}
+
+pub(super) fn assert_permuted<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: String,
+	source: OracleId,
+	permuted: OracleId,
+	permutation: &RowPermutation,
+) -> Result<()>
+where
+	FS: TowerField,
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+{
+	let log_rows = builder.log_rows([source, permuted])?;
+	let sources = permutation.source_rows(log_rows)?;
+
+	if let RowPermutation::Rotate(k) = permutation {
+		let offset = (1 << log_rows) - k % (1 << log_rows);
+		let rotated = if offset == 1 << log_rows {
+			source
+		} else {
+			let rotated = builder.add_shifted(
+				format!("{name}_rotated"),
+				source,
+				offset,
+				log_rows,
+				ShiftVariant::CircularLeft,
+			)?;
+			if let Some(witness) = builder.witness() {
+				witness.fill_shifted::<FS>(
+					rotated,
+					source,
+					offset,
+					log_rows,
+					ShiftVariant::CircularLeft,
+				)?;
+			}
+			rotated
+		};
+		builder.assert_zero(
+			name,
+			[permuted, rotated],
+			arith_expr!([permuted, rotated] = permuted - rotated).convert_field(),
+		);
+		return Ok(());
+	}
+
+	ensure!(log_rows <= 32, "permuted columns must have at most 2^32 rows");
+	let identity = (0..1 << log_rows)
+		.map(|i| B32::new(i as u32))
+		.collect::<Vec<_>>();
+	let sources = sources
+		.into_iter()
+		.map(|i| B32::new(i as u32))
+		.collect::<Vec<_>>();
+	let index = make_transparent(builder, format!("{name}_index"), &identity)?;
+	let source_index = make_transparent(builder, format!("{name}_source_index"), &sources)?;
+
+	let channel = builder.add_channel();
+	builder.send(channel, 1 << log_rows, [index, source].map(OracleOrConst::Oracle))?;
+	builder.receive(channel, 1 << log_rows, [source_index, permuted].map(OracleOrConst::Oracle))?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::channel::Boundary;
+	use binius_field::BinaryField8b;
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	type B8 = BinaryField8b;
+
+	const LOG_ROWS: usize = 5;
+
+	/// Adds a column of bytes and its permutation by `permutation`. If `corrupt` is set, one row
+	/// of the permuted column is wrong.
+	fn permuted_columns(
+		builder: &mut ConstraintSystemBuilder,
+		permutation: &RowPermutation,
+		corrupt: bool,
+	) -> Result<()> {
+		let source = builder.add_committed("source", LOG_ROWS, B8::TOWER_LEVEL);
+		let permuted = builder.add_committed("permuted", LOG_ROWS, B8::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let values = (0..1 << LOG_ROWS)
+				.map(|i| (7 * i + 3) as u8)
+				.collect::<Vec<_>>();
+			let mut permuted_values = permutation.apply(&values)?;
+			if corrupt {
+				permuted_values[3] ^= 1;
+			}
+			witness.new_column::<B8>(source)?.as_mut_slice::<u8>()[..values.len()]
+				.copy_from_slice(&values);
+			witness.new_column::<B8>(permuted)?.as_mut_slice::<u8>()[..values.len()]
+				.copy_from_slice(&permuted_values);
+		}
+		builder.assert_permuted::<B8>("permutation", source, permuted, permutation)
+	}
+
+	#[test]
+	fn test_source_rows() {
+		assert_eq!(RowPermutation::Rotate(3).source_rows(2).unwrap(), [3, 0, 1, 2]);
+		assert_eq!(RowPermutation::BitReverse.source_rows(3).unwrap(), [0, 4, 2, 6, 1, 5, 3, 7]);
+		assert_eq!(RowPermutation::BitReverse.source_rows(0).unwrap(), [0]);
+		assert!(
+			RowPermutation::Custom(vec![0, 1, 1, 2])
+				.source_rows(2)
+				.is_err()
+		);
+		assert!(RowPermutation::Custom(vec![0, 1]).source_rows(2).is_err());
+	}
+
+	fn rotation<const K: usize>(builder: &mut ConstraintSystemBuilder) -> Result<Vec<Boundary<F>>> {
+		permuted_columns(builder, &RowPermutation::Rotate(K), false)?;
+		Ok(vec![])
+	}
+
+	#[test]
+	fn test_rotation() {
+		test_circuit(rotation::<0>).unwrap();
+		test_circuit(rotation::<1>).unwrap();
+		test_circuit(rotation::<5>).unwrap();
+		test_circuit(rotation::<{ 1 << LOG_ROWS }>).unwrap();
+	}
+
+	#[test]
+	fn test_bit_reversal() {
+		test_circuit(|builder| {
+			permuted_columns(builder, &RowPermutation::BitReverse, false)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_custom_permutation() {
+		test_circuit(|builder| {
+			let sources = (0..1 << LOG_ROWS)
+				.map(|i| (5 * i + 2) % (1 << LOG_ROWS))
+				.collect();
+			permuted_columns(builder, &RowPermutation::Custom(sources), false)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_rotation_rejects_wrong_row() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		permuted_columns(&mut builder, &RowPermutation::Rotate(5), true).unwrap();
+		let violations = builder.check_witness(1).unwrap();
+		assert_eq!(violations.len(), 1);
+		assert_eq!(violations[0].rows, [3]);
+	}
+
+	#[test]
+	fn test_bit_reversal_rejects_wrong_row() {
+		let result = test_circuit(|builder| {
+			permuted_columns(builder, &RowPermutation::BitReverse, true)?;
+			Ok(vec![])
+		});
+		assert!(result.is_err());
+	}
+}
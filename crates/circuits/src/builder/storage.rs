@@ -0,0 +1,445 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	cell::RefCell,
+	fs::{self, OpenOptions},
+	ops::Range,
+	path::{Path, PathBuf},
+	slice,
+	sync::{
+		Arc,
+		atomic::{AtomicUsize, Ordering},
+	},
+};
+
+use anyhow::{Context, Error, anyhow};
+use binius_core::witness::MultilinearWitness;
+use binius_field::{PackedField, underlier::UnderlierType};
+use binius_math::{Error as MathError, MultilinearExtension, MultilinearPoly, MultilinearQueryRef};
+use memmap2::MmapMut;
+use tempfile::TempDir;
+
+use super::types::U;
+
+/// Storage for the data of the witness columns.
+///
+/// The allocated slices live as long as the storage, which must outlive the witness. The witness
+/// columns are read by the provers through the slices, so the storage decides where the witness
+/// resides, e.g. [`bumpalo::Bump`] keeps it in memory while [`MmapWitnessStorage`] keeps it in
+/// files.
+pub trait WitnessStorage {
+	/// Allocates a slice of `len` underliers initialized to `value`.
+	fn alloc_column(&self, len: usize, value: U) -> Result<&mut [U], Error>;
+
+	/// Returns the read-ahead hints of `column`, a slice allocated by this storage.
+	///
+	/// Storages that page the columns in lazily return the hints, and the witness multilinears of
+	/// the columns then announce the chunks of rows that the provers are about to read, see
+	/// [`ChunkedMultilinear`]. The default implementation returns `None`.
+	fn chunk_access(&self, _column: &[U]) -> Option<ChunkAccess> {
+		None
+	}
+}
+
+impl WitnessStorage for bumpalo::Bump {
+	fn alloc_column(&self, len: usize, value: U) -> Result<&mut [U], Error> {
+		self.try_alloc_slice_fill_copy(len, value)
+			.map_err(|err| anyhow!("failed to allocate a witness column: {err}"))
+	}
+}
+
+/// A witness storage backed by memory-mapped files.
+///
+/// Every column is stored in its own file in the storage directory, so witnesses can exceed the
+/// physical memory: the operating system pages the columns in as the witness generation and the
+/// provers access them, and writes them back under memory pressure. The provers read the
+/// multilinears in chunks of rows, and the witness multilinears of the columns request the next
+/// chunk from the operating system while the current one is processed, see [`ChunkAccess`].
+///
+/// The files hold the witness in plaintext, including its secret columns. On Unix, they are
+/// created readable and writable by their owner only, and [`Self::in_temp_dir`] puts them in a
+/// new directory that only its owner can access. A directory given to [`Self::new`] should not be
+/// writable by untrusted users either. The files are removed when the storage is dropped, but are
+/// left behind if the process is killed.
+pub struct MmapWitnessStorage {
+	dir: PathBuf,
+	maps: RefCell<Vec<(PathBuf, Arc<MmapMut>)>>,
+	// Removed with its contents when the storage is dropped, after the files are unmapped.
+	_temp_dir: Option<TempDir>,
+}
+
+impl MmapWitnessStorage {
+	/// Creates a storage with the column files in the directory `dir`, which must exist.
+	pub fn new(dir: impl Into<PathBuf>) -> Self {
+		Self {
+			dir: dir.into(),
+			maps: RefCell::new(Vec::new()),
+			_temp_dir: None,
+		}
+	}
+
+	/// Creates a storage with the column files in a new private directory in the temporary
+	/// directory of the system.
+	///
+	/// The directory has a random name and, on Unix, is accessible by its owner only. It is
+	/// removed when the storage is dropped.
+	pub fn in_temp_dir() -> Result<Self, Error> {
+		let mut builder = tempfile::Builder::new();
+		builder.prefix("binius-witness-");
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			builder.permissions(fs::Permissions::from_mode(0o700));
+		}
+		let temp_dir = builder
+			.tempdir()
+			.context("failed to create a private directory for the witness column files")?;
+		Ok(Self {
+			dir: temp_dir.path().to_path_buf(),
+			maps: RefCell::new(Vec::new()),
+			_temp_dir: Some(temp_dir),
+		})
+	}
+
+	/// The directory of the column files.
+	pub fn dir(&self) -> &Path {
+		&self.dir
+	}
+
+	fn map_new_file(&self, len: usize) -> Result<(PathBuf, MmapMut), Error> {
+		static NEXT_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+		let path = self.dir.join(format!(
+			"binius-witness-{}-{}.bin",
+			std::process::id(),
+			NEXT_FILE_ID.fetch_add(1, Ordering::Relaxed)
+		));
+		let mut options = OpenOptions::new();
+		options.read(true).write(true).create_new(true);
+		// The file holds the plaintext witness, so only its owner may access it.
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::OpenOptionsExt;
+			options.mode(0o600);
+		}
+		let file = options.open(&path)?;
+		file.set_len((len * size_of::<U>()) as u64)?;
+		// SAFETY: The file was just created by this storage, and is not modified by any other
+		// means while it is mapped.
+		let map = unsafe { MmapMut::map_mut(&file)? };
+		Ok((path, map))
+	}
+}
+
+impl WitnessStorage for MmapWitnessStorage {
+	fn alloc_column(&self, len: usize, value: U) -> Result<&mut [U], Error> {
+		if len == 0 {
+			return Ok(&mut []);
+		}
+
+		let (path, mut map) = self.map_new_file(len).with_context(|| {
+			format!("failed to map a witness column file in {}", self.dir.display())
+		})?;
+		// SAFETY: The mapping is page aligned, which satisfies the alignment of `U`, and spans
+		// `len * size_of::<U>()` bytes of the file, which is zero-filled. `U` is `Pod`, so the
+		// zero bytes are a valid value. Moving `map` into `self.maps` doesn't move the mapped
+		// memory, which stays valid until the storage is dropped. The returned slice borrows the
+		// storage, so it can't outlive the mapping, and no other slice aliases the mapping.
+		let data = unsafe { slice::from_raw_parts_mut(map.as_mut_ptr().cast::<U>(), len) };
+		self.maps.borrow_mut().push((path, Arc::new(map)));
+
+		data.fill(value);
+		Ok(data)
+	}
+
+	fn chunk_access(&self, column: &[U]) -> Option<ChunkAccess> {
+		self.maps
+			.borrow()
+			.iter()
+			.find(|(_, map)| map.as_ptr() == column.as_ptr().cast())
+			.map(|(_, map)| ChunkAccess { map: map.clone() })
+	}
+}
+
+/// Read-ahead hints for a column of a [`MmapWitnessStorage`].
+#[derive(Debug, Clone)]
+pub struct ChunkAccess {
+	map: Arc<MmapMut>,
+}
+
+impl ChunkAccess {
+	/// Hints that the underliers `range` of the column are about to be read.
+	///
+	/// The hint is advisory: the pages are read ahead asynchronously, and a failure to issue the
+	/// hint is ignored.
+	pub fn will_read(&self, range: Range<usize>) {
+		#[cfg(unix)]
+		{
+			let len = self.map.len();
+			let start = (range.start * size_of::<U>()).min(len);
+			let end = (range.end * size_of::<U>()).min(len);
+			if start < end {
+				let _ = self
+					.map
+					.advise_range(memmap2::Advice::WillNeed, start, end - start);
+			}
+		}
+		#[cfg(not(unix))]
+		let _ = range;
+	}
+}
+
+/// A witness multilinear that reads ahead the chunks of rows of its column.
+///
+/// The provers read the multilinears subcube by subcube, with [`MultilinearPoly::subcube_evals`]
+/// and its partial evaluation variants. Before returning a subcube, this multilinear hints the
+/// storage to read the next subcube of the column, so that it is paged in while the current one
+/// is processed. All methods are delegated to the inner multilinear.
+#[derive(Debug)]
+pub struct ChunkedMultilinear<'a, P: PackedField> {
+	inner: MultilinearWitness<'a, P>,
+	access: ChunkAccess,
+	log_scalars_per_underlier: usize,
+}
+
+impl<'a, P: PackedField> ChunkedMultilinear<'a, P> {
+	/// Wraps the witness of a column with `2^log_scalars_per_underlier` scalars per underlier.
+	pub fn new(
+		inner: MultilinearWitness<'a, P>,
+		access: ChunkAccess,
+		log_scalars_per_underlier: usize,
+	) -> Self {
+		Self {
+			inner,
+			access,
+			log_scalars_per_underlier,
+		}
+	}
+
+	/// Hints the read of the subcube after `subcube_index`.
+	fn read_ahead(&self, subcube_vars: usize, subcube_index: usize) {
+		let next_subcube = (subcube_index + 1) << subcube_vars..(subcube_index + 2) << subcube_vars;
+		self.access.will_read(
+			next_subcube.start >> self.log_scalars_per_underlier
+				..next_subcube
+					.end
+					.div_ceil(1 << self.log_scalars_per_underlier),
+		);
+	}
+}
+
+impl<P: PackedField> MultilinearPoly<P> for ChunkedMultilinear<'_, P> {
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars()
+	}
+
+	fn log_extension_degree(&self) -> usize {
+		self.inner.log_extension_degree()
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<P::Scalar, MathError> {
+		self.inner.evaluate_on_hypercube(index)
+	}
+
+	fn evaluate_on_hypercube_and_scale(
+		&self,
+		index: usize,
+		scalar: P::Scalar,
+	) -> Result<P::Scalar, MathError> {
+		self.inner.evaluate_on_hypercube_and_scale(index, scalar)
+	}
+
+	fn evaluate(&self, query: MultilinearQueryRef<P>) -> Result<P::Scalar, MathError> {
+		self.inner.evaluate(query)
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, MathError> {
+		self.inner.evaluate_partial_low(query)
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, MathError> {
+		self.inner.evaluate_partial_high(query)
+	}
+
+	fn evaluate_partial(
+		&self,
+		query: MultilinearQueryRef<P>,
+		start_index: usize,
+	) -> Result<MultilinearExtension<P>, MathError> {
+		self.inner.evaluate_partial(query, start_index)
+	}
+
+	fn zero_pad(
+		&self,
+		n_pad_vars: usize,
+		start_index: usize,
+		nonzero_index: usize,
+	) -> Result<MultilinearExtension<P>, MathError> {
+		self.inner.zero_pad(n_pad_vars, start_index, nonzero_index)
+	}
+
+	fn subcube_partial_low_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_low_evals: &mut [P],
+	) -> Result<(), MathError> {
+		self.read_ahead(subcube_vars + query.n_vars(), subcube_index);
+		self.inner
+			.subcube_partial_low_evals(query, subcube_vars, subcube_index, partial_low_evals)
+	}
+
+	fn subcube_partial_high_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_high_evals: &mut [P],
+	) -> Result<(), MathError> {
+		self.inner.subcube_partial_high_evals(
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_high_evals,
+		)
+	}
+
+	fn subcube_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		log_embedding_degree: usize,
+		evals: &mut [P],
+	) -> Result<(), MathError> {
+		self.read_ahead(subcube_vars, subcube_index);
+		self.inner
+			.subcube_evals(subcube_vars, subcube_index, log_embedding_degree, evals)
+	}
+
+	fn packed_evals(&self) -> Option<&[P]> {
+		self.inner.packed_evals()
+	}
+}
+
+/// Wraps the witness of `column` into a [`ChunkedMultilinear`] if `storage` provides read-ahead
+/// hints for it.
+pub(super) fn chunked_witness<'a, P: PackedField>(
+	storage: &dyn WitnessStorage,
+	column: &[U],
+	tower_level: usize,
+	witness: MultilinearWitness<'a, P>,
+) -> MultilinearWitness<'a, P> {
+	match storage.chunk_access(column) {
+		Some(access) => {
+			Arc::new(ChunkedMultilinear::new(witness, access, U::LOG_BITS - tower_level))
+		}
+		None => witness,
+	}
+}
+
+impl Drop for MmapWitnessStorage {
+	fn drop(&mut self) {
+		for (path, map) in self.maps.get_mut().drain(..) {
+			drop(map);
+			let _ = fs::remove_file(path);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField32b, underlier::UnderlierWithBitOps};
+	use binius_macros::arith_expr;
+
+	use super::*;
+	use crate::{builder::test_utils::test_circuit_with_storage, unconstrained::unconstrained};
+
+	#[test]
+	fn test_mmap_storage_allocates_filled_slices() {
+		let storage = MmapWitnessStorage::in_temp_dir().unwrap();
+		let zeros = storage.alloc_column(1000, U::ZERO).unwrap();
+		assert!(zeros.iter().all(|&x| x == U::ZERO));
+		let ones = storage.alloc_column(10, U::ONES).unwrap();
+		assert!(ones.iter().all(|&x| x == U::ONES));
+		assert!(storage.alloc_column(0, U::ONES).unwrap().is_empty());
+
+		let paths = storage
+			.maps
+			.borrow()
+			.iter()
+			.map(|(path, _)| path.clone())
+			.collect::<Vec<_>>();
+		assert_eq!(paths.len(), 2);
+		assert!(paths.iter().all(|path| path.exists()));
+		drop(storage);
+		assert!(paths.iter().all(|path| !path.exists()));
+	}
+
+	#[test]
+	fn test_mmap_storage_uses_a_private_temp_dir() {
+		let storage = MmapWitnessStorage::in_temp_dir().unwrap();
+		let dir = storage.dir().to_path_buf();
+		assert_ne!(dir, std::env::temp_dir());
+		assert!(dir.starts_with(std::env::temp_dir()));
+		storage.alloc_column(10, U::ZERO).unwrap();
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+
+			let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+			assert_eq!(mode(&dir), 0o700);
+			let (path, _) = &storage.maps.borrow()[0];
+			assert_eq!(mode(path), 0o600);
+		}
+
+		drop(storage);
+		assert!(!dir.exists());
+	}
+
+	#[test]
+	fn test_mmap_storage_reports_allocation_failure() {
+		let storage = MmapWitnessStorage::new(std::env::temp_dir().join("binius-missing-dir"));
+		assert!(storage.alloc_column(10, U::ZERO).is_err());
+	}
+
+	#[test]
+	fn test_mmap_storage_chunk_access() {
+		let storage = MmapWitnessStorage::in_temp_dir().unwrap();
+		let column = storage.alloc_column(1000, U::ZERO).unwrap();
+		let access = storage.chunk_access(column).unwrap();
+		access.will_read(0..100);
+		access.will_read(900..2000);
+		assert!(storage.chunk_access(&column[1..]).is_none());
+		assert!(bumpalo::Bump::new().chunk_access(column).is_none());
+	}
+
+	#[test]
+	fn test_circuit_with_mmap_storage() {
+		let storage = MmapWitnessStorage::in_temp_dir().unwrap();
+		test_circuit_with_storage(&storage, |builder| {
+			let log_size = 12;
+			let x = unconstrained::<BinaryField32b>(builder, "x", log_size)?;
+			let y = unconstrained::<BinaryField32b>(builder, "y", log_size)?;
+			let sum = builder.add_virtual::<BinaryField32b>(
+				"sum",
+				[x, y],
+				arith_expr!([x, y] = x + y).convert_field(),
+			)?;
+			builder.assert_zero(
+				"sum",
+				[x, y, sum],
+				arith_expr!([x, y, sum] = x + y - sum).convert_field(),
+			);
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
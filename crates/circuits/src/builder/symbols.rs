@@ -0,0 +1,142 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::collections::{BTreeMap, btree_map::Entry};
+
+use anyhow::ensure;
+use binius_core::oracle::{MultilinearOracleSet, OracleId};
+use itertools::Itertools;
+
+use super::types::F;
+
+/// A named column of a constraint system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+	pub oracle_id: OracleId,
+	pub n_vars: usize,
+	pub tower_level: usize,
+}
+
+/// Maps the fully scoped names of the columns of a constraint system to their oracles.
+///
+/// Names are sorted, so the table and its JSON export only depend on the circuit, which lets
+/// debugging tools and external witness generators refer to columns by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolTable {
+	symbols: BTreeMap<String, Symbol>,
+}
+
+impl SymbolTable {
+	/// Collects the named oracles of `oracles`.
+	///
+	/// Fails if two oracles have the same name, listing all the colliding names.
+	pub fn new(oracles: &MultilinearOracleSet<F>) -> Result<Self, anyhow::Error> {
+		let mut symbols = BTreeMap::new();
+		let mut collisions = BTreeMap::<String, Vec<OracleId>>::new();
+		for (oracle_id, oracle) in oracles.iter() {
+			let Some(name) = oracle.name() else {
+				continue;
+			};
+			let symbol = Symbol {
+				oracle_id,
+				n_vars: oracle.n_vars(),
+				tower_level: oracle.binary_tower_level(),
+			};
+			match symbols.entry(name.to_string()) {
+				Entry::Vacant(entry) => {
+					entry.insert(symbol);
+				}
+				Entry::Occupied(entry) => collisions
+					.entry(name.to_string())
+					.or_insert_with(|| vec![entry.get().oracle_id])
+					.push(oracle_id),
+			}
+		}
+
+		ensure!(
+			collisions.is_empty(),
+			"duplicate oracle names: {}",
+			collisions
+				.iter()
+				.map(|(name, ids)| format!("{name} ({})", ids.iter().join(", ")))
+				.join(", ")
+		);
+		Ok(Self { symbols })
+	}
+
+	/// Returns the oracle with the given fully scoped name.
+	pub fn get(&self, name: &str) -> Option<OracleId> {
+		self.symbols.get(name).map(|symbol| symbol.oracle_id)
+	}
+
+	pub fn symbol(&self, name: &str) -> Option<&Symbol> {
+		self.symbols.get(name)
+	}
+
+	/// Iterates over the symbols in name order.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &Symbol)> + '_ {
+		self.symbols
+			.iter()
+			.map(|(name, symbol)| (name.as_str(), symbol))
+	}
+
+	pub fn len(&self) -> usize {
+		self.symbols.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.symbols.is_empty()
+	}
+
+	/// Exports the table as a JSON object mapping each name to the oracle index, the number of
+	/// variables and the tower level of the column.
+	pub fn to_json(&self) -> String {
+		let symbols = self
+			.symbols
+			.iter()
+			.map(|(name, symbol)| {
+				let value = serde_json::json!({
+					"id": symbol.oracle_id.index(),
+					"n_vars": symbol.n_vars,
+					"tower_level": symbol.tower_level,
+				});
+				(name.clone(), value)
+			})
+			.collect::<serde_json::Map<_, _>>();
+		serde_json::Value::Object(symbols).to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, BinaryField32b, TowerField};
+
+	use crate::builder::ConstraintSystemBuilder;
+
+	#[test]
+	fn test_symbol_table() {
+		let mut builder = ConstraintSystemBuilder::new();
+		builder.push_namespace("a");
+		let x = builder.add_committed("x", 4, BinaryField32b::TOWER_LEVEL);
+		builder.pop_namespace();
+		let y = builder.add_committed("y", 3, BinaryField1b::TOWER_LEVEL);
+
+		let symbols = builder.symbol_table().unwrap();
+		assert_eq!(symbols.len(), 2);
+		assert_eq!(symbols.get("a::x"), Some(x));
+		assert_eq!(symbols.get("y"), Some(y));
+		assert_eq!(symbols.get("x"), None);
+		assert_eq!(
+			symbols.to_json(),
+			format!(
+				r#"{{"a::x":{{"id":{},"n_vars":4,"tower_level":5}},"y":{{"id":{},"n_vars":3,"#,
+				x.index(),
+				y.index()
+			) + r#""tower_level":0}}"#
+		);
+
+		assert!(builder.name_collisions().is_empty());
+		builder.add_committed("y", 3, BinaryField1b::TOWER_LEVEL);
+		assert!(builder.name_collisions().contains("y"));
+		assert!(builder.symbol_table().is_err());
+	}
+}
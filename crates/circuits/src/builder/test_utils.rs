@@ -1,23 +1,97 @@
 // Copyright 2025 Irreducible Inc.
 
-use binius_core::constraint_system::{channel::Boundary, validate::validate_witness};
+use binius_core::constraint_system::{
+	ConstraintSystem, channel::Boundary, validate::validate_witness,
+};
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 
-use super::{ConstraintSystemBuilder, types::F};
+use super::{ConstraintSystemBuilder, WitnessStorage, types::F};
 
 pub fn test_circuit(
 	build_circuit: fn(&mut ConstraintSystemBuilder) -> Result<Vec<Boundary<F>>, anyhow::Error>,
+) -> Result<(), anyhow::Error> {
+	test_circuit_with_storage(&bumpalo::Bump::new(), build_circuit)
+}
+
+/// Same as [`test_circuit`], with the witness columns allocated in `storage`.
+pub fn test_circuit_with_storage(
+	storage: &dyn WitnessStorage,
+	build_circuit: fn(&mut ConstraintSystemBuilder) -> Result<Vec<Boundary<F>>, anyhow::Error>,
 ) -> Result<(), anyhow::Error> {
 	let mut verifier_builder = ConstraintSystemBuilder::new();
 	let verifier_boundaries = build_circuit(&mut verifier_builder)?;
 	let verifier_constraint_system = verifier_builder.build()?;
 
-	let allocator = bumpalo::Bump::new();
-	let mut prover_builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+	let mut prover_builder = ConstraintSystemBuilder::new_with_witness(storage);
 	let prover_boundaries = build_circuit(&mut prover_builder)?;
 	let prover_witness = prover_builder.take_witness()?;
 	let _prover_constraint_system = prover_builder.build()?;
 
 	assert_eq!(verifier_boundaries, prover_boundaries);
 	validate_witness(&verifier_constraint_system, &verifier_boundaries, &prover_witness)?;
+
+	// The constraint system survives a serialization round trip.
+	let mode = SerializationMode::CanonicalTower;
+	let mut serialized = Vec::new();
+	verifier_constraint_system.serialize(&mut serialized, mode)?;
+	let deserialized = ConstraintSystem::<F>::deserialize(serialized.as_slice(), mode)?;
+	let mut reserialized = Vec::new();
+	deserialized.serialize(&mut reserialized, mode)?;
+	assert_eq!(serialized, reserialized);
+	validate_witness(&deserialized, &verifier_boundaries, &prover_witness)?;
+	Ok(())
+}
+
+/// Proves the circuit and checks that the size estimated by
+/// [`binius_core::constraint_system::estimate_proof_size`] is at least the size of the proof, and
+/// at most twice that size.
+#[cfg(test)]
+pub fn test_proof_size_estimate(
+	build_circuit: fn(&mut ConstraintSystemBuilder) -> Result<Vec<Boundary<F>>, anyhow::Error>,
+) -> Result<(), anyhow::Error> {
+	use binius_core::{
+		constraint_system::{self, SecurityLevel, estimate_proof_size},
+		fiat_shamir::HasherChallenger,
+	};
+	use binius_field::tower::CanonicalTowerFamily;
+	use binius_hal::make_portable_backend;
+	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+
+	use super::types::U;
+
+	let security = SecurityLevel::new(100, 1);
+
+	let allocator = bumpalo::Bump::new();
+	let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+	let boundaries = build_circuit(&mut builder)?;
+	let witness = builder.take_witness()?;
+	let constraint_system = builder.build()?;
+
+	let estimate = estimate_proof_size::<
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+	>(&constraint_system, security)?;
+
+	let backend = make_portable_backend();
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(&constraint_system, security, &boundaries, witness, &backend)?;
+
+	let proof_size = proof.get_proof_size();
+	assert!(
+		estimate.total() >= proof_size,
+		"estimate {estimate:?} is below the proof size {proof_size}"
+	);
+	assert!(
+		estimate.total() <= 2 * proof_size,
+		"estimate {estimate:?} is more than twice the proof size {proof_size}"
+	);
+	assert_eq!(estimate.serialized_size() - estimate.total(), proof.serialized_size() - proof_size);
 	Ok(())
 }
@@ -1,25 +1,41 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use std::{
+	cell::RefCell,
+	io::{Read, Write},
+	marker::PhantomData,
+	rc::Rc,
+};
 
-use anyhow::{Error, anyhow};
+use anyhow::{Error, anyhow, ensure};
 use binius_core::{
-	oracle::{MultilinearOracleSet, OracleId},
+	oracle::{MultilinearOracleSet, OracleId, ShiftVariant},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 use binius_field::{
-	ExtensionField, PackedField, TowerField,
+	BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b, BinaryField16b, BinaryField32b,
+	BinaryField64b, BinaryField128b, ExtensionField, Field, PackedBinaryField8x1b,
+	PackedBinaryField16x1b, PackedBinaryField32x1b, PackedBinaryField64x1b,
+	PackedBinaryField128x1b, PackedField, TowerField,
 	as_packed_field::{PackScalar, PackedType},
+	le_bytes,
+	packed::{get_packed_slice, set_packed_slice},
+	transpose_scalars,
 	underlier::WithUnderlier,
 };
-use binius_math::MultilinearExtension;
+use binius_math::{ArithCircuit, MultilinearExtension, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
 use bytemuck::{Pod, must_cast_slice, must_cast_slice_mut};
+use tiny_keccak::{Hasher, Keccak};
 
-use super::types::{F, U};
+use super::{
+	storage::{WitnessStorage, chunked_witness},
+	types::{F, U},
+};
 
 pub struct Builder<'arena> {
-	bump: &'arena bumpalo::Bump,
+	storage: &'arena dyn WitnessStorage,
 
 	oracles: Rc<RefCell<MultilinearOracleSet<F>>>,
 
@@ -36,17 +52,20 @@ struct WitnessBuilderEntry<'arena> {
 
 impl<'arena> Builder<'arena> {
 	pub fn new(
-		allocator: &'arena bumpalo::Bump,
+		storage: &'arena dyn WitnessStorage,
 		oracles: Rc<RefCell<MultilinearOracleSet<F>>>,
 	) -> Self {
 		Self {
-			bump: allocator,
+			storage,
 			oracles,
 			entries: Rc::new(RefCell::new(Vec::new())),
 		}
 	}
 
-	pub fn new_column<FS: TowerField>(&self, id: OracleId) -> EntryBuilder<'arena, FS>
+	pub fn new_column<FS: TowerField>(
+		&self,
+		id: OracleId,
+	) -> Result<EntryBuilder<'arena, FS>, Error>
 	where
 		U: PackScalar<FS>,
 		F: ExtensionField<FS>,
@@ -59,7 +78,7 @@ impl<'arena> Builder<'arena> {
 		&self,
 		id: OracleId,
 		nonzero_scalars_prefix: usize,
-	) -> EntryBuilder<'arena, FS>
+	) -> Result<EntryBuilder<'arena, FS>, Error>
 	where
 		U: PackScalar<FS>,
 		F: ExtensionField<FS>,
@@ -68,22 +87,22 @@ impl<'arena> Builder<'arena> {
 		let log_rows = oracles.n_vars(id);
 		// TODO: validate nonzero_scalars_prefix
 		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
-		let data = bumpalo::vec![in self.bump; U::default(); len].into_bump_slice_mut();
-		EntryBuilder {
+		let data = self.storage.alloc_column(len, U::default())?;
+		Ok(EntryBuilder {
 			_marker: PhantomData,
 			entries: self.entries.clone(),
 			id,
 			log_rows,
 			nonzero_scalars_prefix,
 			data: Some(data),
-		}
+		})
 	}
 
 	pub fn new_column_with_default<FS: TowerField>(
 		&self,
 		id: OracleId,
 		default: FS,
-	) -> EntryBuilder<'arena, FS>
+	) -> Result<EntryBuilder<'arena, FS>, Error>
 	where
 		U: PackScalar<FS>,
 		F: ExtensionField<FS>,
@@ -93,15 +112,15 @@ impl<'arena> Builder<'arena> {
 		let nonzero_scalars_prefix = 1 << log_rows;
 		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
 		let default = WithUnderlier::to_underlier(PackedType::<U, FS>::broadcast(default));
-		let data = bumpalo::vec![in self.bump; default; len].into_bump_slice_mut();
-		EntryBuilder {
+		let data = self.storage.alloc_column(len, default)?;
+		Ok(EntryBuilder {
 			_marker: PhantomData,
 			entries: self.entries.clone(),
 			id,
 			log_rows,
 			nonzero_scalars_prefix,
 			data: Some(data),
-		}
+		})
 	}
 
 	pub fn get<FS>(&self, id: OracleId) -> Result<WitnessEntry<'arena, FS>, Error>
@@ -137,6 +156,275 @@ impl<'arena> Builder<'arena> {
 		})
 	}
 
+	/// Fills the column `id` with `composition` evaluated row by row on the `inner` columns.
+	///
+	/// The inner columns may have any tower level. Fails if a value of the composition does not
+	/// lie in `FS`.
+	pub fn fill_composite<FS>(
+		&self,
+		id: OracleId,
+		inner: &[OracleId],
+		composition: &ArithCircuit<F>,
+	) -> Result<(), Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let inner_witnesses = inner
+			.iter()
+			.map(|&inner_id| self.get_multilin_poly(inner_id))
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let log_rows = self.oracles.borrow().n_vars(id);
+		let values = (0..1 << log_rows)
+			.into_par_iter()
+			.map(|row| {
+				let query = inner_witnesses
+					.iter()
+					.map(|witness| witness.evaluate_on_hypercube(row))
+					.collect::<Result<Vec<_>, _>>()?;
+				let value = composition.evaluate(&query)?;
+				value.try_into().map_err(|_| {
+					anyhow!(
+						"value {value} at row {row} does not fit in tower level {}",
+						FS::TOWER_LEVEL
+					)
+				})
+			})
+			.collect::<Result<Vec<FS>, Error>>()?;
+
+		let mut column = self.new_column::<FS>(id)?;
+		let packed = column.packed();
+		for (row, value) in values.into_iter().enumerate() {
+			set_packed_slice(packed, row, value);
+		}
+		Ok(())
+	}
+
+	/// Fills the column `id` with the values of the column `inner` shifted within blocks of
+	/// `1 << block_bits` rows, with the semantics of [`ShiftVariant`].
+	///
+	/// Fails if the witness for `inner` is missing or a value of `inner` does not lie in `FS`.
+	pub fn fill_shifted<FS>(
+		&self,
+		id: OracleId,
+		inner: OracleId,
+		offset: usize,
+		block_bits: usize,
+		variant: ShiftVariant,
+	) -> Result<(), Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let inner_witness = self.get_multilin_poly(inner)?;
+
+		let log_rows = self.oracles.borrow().n_vars(id);
+		let block_len = 1 << block_bits;
+		let values = (0..1 << log_rows)
+			.into_par_iter()
+			.map(|row| {
+				let block_start = row & !(block_len - 1);
+				let row_in_block = row - block_start;
+				let inner_row = match variant {
+					ShiftVariant::CircularLeft => {
+						Some(block_start + (row_in_block + block_len - offset) % block_len)
+					}
+					ShiftVariant::LogicalLeft => row_in_block
+						.checked_sub(offset)
+						.map(|row| block_start + row),
+					ShiftVariant::LogicalRight => {
+						(row_in_block + offset < block_len).then_some(row + offset)
+					}
+				};
+				let Some(inner_row) = inner_row else {
+					return Ok(FS::ZERO);
+				};
+				let value = inner_witness.evaluate_on_hypercube(inner_row)?;
+				value.try_into().map_err(|_| {
+					anyhow!(
+						"value {value} at row {inner_row} does not fit in tower level {}",
+						FS::TOWER_LEVEL
+					)
+				})
+			})
+			.collect::<Result<Vec<FS>, Error>>()?;
+
+		let mut column = self.new_column::<FS>(id)?;
+		let packed = column.packed();
+		for (row, value) in values.into_iter().enumerate() {
+			set_packed_slice(packed, row, value);
+		}
+		Ok(())
+	}
+
+	/// Fills the 1-bit columns `bits` with the bits of the values of the column `input`, least
+	/// significant bit first.
+	///
+	/// The bits of a value are its coordinates in the canonical basis over [`BinaryField1b`].
+	/// Values of at least 8 bits are transposed from their packed representation with packed
+	/// 1-bit field transposes, in chunks of rows processed in parallel. Fails if a value has a set
+	/// bit beyond the number of bit columns.
+	pub fn fill_bit_decomposition<FS>(
+		&self,
+		input: OracleId,
+		bits: &[OracleId],
+	) -> Result<(), Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let value_bits = <FS as ExtensionField<BinaryField1b>>::DEGREE;
+		ensure!(
+			bits.len() <= value_bits,
+			"{} bits do not fit in tower level {}",
+			bits.len(),
+			FS::TOWER_LEVEL
+		);
+
+		let log_rows = self.oracles.borrow().n_vars(input);
+		let input_entry = self.get::<FS>(input)?;
+		if value_bits < 8 || log_rows < 3 {
+			let values = (0..1 << log_rows)
+				.map(|row| get_packed_slice(input_entry.packed(), row))
+				.collect::<Vec<_>>();
+			if let Some(row) = values.iter().position(|value| {
+				ExtensionField::<BinaryField1b>::iter_bases(value)
+					.skip(bits.len())
+					.any(|bit| bit != BinaryField1b::ZERO)
+			}) {
+				bail!(anyhow!("value at row {row} does not fit in {} bits", bits.len()));
+			}
+			for (i, &bit) in bits.iter().enumerate() {
+				let mut column = self.new_column::<BinaryField1b>(bit)?;
+				let packed = column.packed();
+				for (row, value) in values.iter().enumerate() {
+					set_packed_slice(
+						packed,
+						row,
+						ExtensionField::<BinaryField1b>::get_base(value, i),
+					);
+				}
+			}
+			return Ok(());
+		}
+
+		let value_bytes = value_bits / 8;
+		let input_bytes = &input_entry.as_slice::<u8>()[..value_bytes << log_rows];
+		if let Some(row) = input_bytes
+			.chunks_exact(value_bytes)
+			.position(|value| has_bits_above(value, bits.len()))
+		{
+			bail!(anyhow!("value at row {row} does not fit in {} bits", bits.len()));
+		}
+
+		// Every chunk of rows is a bit matrix with a row per value, whose transpose has a row per
+		// bit.
+		let log_value_bits = FS::TOWER_LEVEL;
+		let log_chunk_rows = log_rows.min(LOG_TRANSPOSE_CHUNK_ROWS);
+		let chunk_bytes = 1 << (log_chunk_rows - 3);
+		let transposed_chunks = input_bytes
+			.par_chunks_exact(value_bytes << log_chunk_rows)
+			.map(|values| {
+				let mut transposed = vec![0; values.len()];
+				transpose_bits(values, &mut transposed, log_chunk_rows, log_value_bits)?;
+				Ok(transposed)
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		for (i, &bit) in bits.iter().enumerate() {
+			let mut column = self.new_column::<BinaryField1b>(bit)?;
+			for (bit_bytes, transposed) in column.as_mut_slice::<u8>()[..1 << (log_rows - 3)]
+				.chunks_exact_mut(chunk_bytes)
+				.zip(&transposed_chunks)
+			{
+				bit_bytes.copy_from_slice(&transposed[i * chunk_bytes..(i + 1) * chunk_bytes]);
+			}
+		}
+		Ok(())
+	}
+
+	/// Fills the column `id` with the values whose bits, least significant first, are the values
+	/// of the 1-bit columns `bits`.
+	///
+	/// This is the inverse of [`Self::fill_bit_decomposition`].
+	pub fn fill_bit_recomposition<FS>(&self, id: OracleId, bits: &[OracleId]) -> Result<(), Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let value_bits = <FS as ExtensionField<BinaryField1b>>::DEGREE;
+		ensure!(
+			bits.len() <= value_bits,
+			"{} bits do not fit in tower level {}",
+			bits.len(),
+			FS::TOWER_LEVEL
+		);
+
+		let log_rows = self.oracles.borrow().n_vars(id);
+		let bit_entries = bits
+			.iter()
+			.map(|&bit| self.get::<BinaryField1b>(bit))
+			.collect::<Result<Vec<_>, _>>()?;
+		let mut column = self.new_column::<FS>(id)?;
+		if value_bits < 8 || log_rows < 3 {
+			for row in 0..1 << log_rows {
+				let value = bit_entries
+					.iter()
+					.enumerate()
+					.filter(|(_, entry)| {
+						get_packed_slice(entry.packed(), row) == BinaryField1b::ONE
+					})
+					.map(|(i, _)| <FS as ExtensionField<BinaryField1b>>::basis(i))
+					.sum::<FS>();
+				set_packed_slice(column.packed(), row, value);
+			}
+			return Ok(());
+		}
+
+		// Every chunk of rows is the transpose of a bit matrix with a row per bit.
+		let value_bytes = value_bits / 8;
+		let log_value_bits = FS::TOWER_LEVEL;
+		let log_chunk_rows = log_rows.min(LOG_TRANSPOSE_CHUNK_ROWS);
+		let chunk_bytes = 1 << (log_chunk_rows - 3);
+		let bit_bytes = bit_entries
+			.iter()
+			.map(|entry| entry.as_slice::<u8>())
+			.collect::<Vec<_>>();
+		column.as_mut_slice::<u8>()[..value_bytes << log_rows]
+			.par_chunks_exact_mut(value_bytes << log_chunk_rows)
+			.enumerate()
+			.try_for_each(|(chunk, values)| {
+				let mut bit_matrix = vec![0; values.len()];
+				for (row, bit_bytes) in bit_matrix.chunks_exact_mut(chunk_bytes).zip(&bit_bytes) {
+					row.copy_from_slice(&bit_bytes[chunk * chunk_bytes..(chunk + 1) * chunk_bytes]);
+				}
+				transpose_bits(&bit_matrix, values, log_value_bits, log_chunk_rows)
+			})
+	}
+
+	/// Returns the witness of the column `id` as a multilinear over the extension field.
+	pub fn get_multilin_poly(
+		&self,
+		id: OracleId,
+	) -> Result<MultilinearWitness<'arena, PackedType<U, F>>, Error> {
+		let entries = self.entries.borrow();
+		let oracles = self.oracles.borrow();
+		let entry = entries
+			.get(id.index())
+			.and_then(|entry| entry.as_ref())
+			.ok_or_else(|| anyhow!("Witness for {} is missing", oracles.label(id)))?;
+		entry
+			.witness
+			.as_ref()
+			.cloned()
+			.map_err(|err| anyhow!("{err}"))
+	}
+
 	pub fn set<FS: TowerField>(
 		&self,
 		oracle_id: OracleId,
@@ -165,6 +453,21 @@ impl<'arena> Builder<'arena> {
 		Ok(())
 	}
 
+	/// Removes the columns of the oracles added after the first `n_oracles` ones.
+	pub(super) fn truncate(&self, n_oracles: usize) {
+		self.entries.borrow_mut().truncate(n_oracles);
+	}
+
+	/// Starts a batch of column fills that are executed in parallel.
+	///
+	/// See [`ParallelFill`].
+	pub fn parallel_fill<'scope>(&self) -> ParallelFill<'_, 'arena, 'scope> {
+		ParallelFill {
+			builder: self,
+			fills: Vec::new(),
+		}
+	}
+
 	pub fn build(self) -> Result<MultilinearExtensionIndex<'arena, PackedType<U, F>>, Error> {
 		let mut result = MultilinearExtensionIndex::new();
 		let entries = Rc::into_inner(self.entries)
@@ -172,11 +475,201 @@ impl<'arena> Builder<'arena> {
 			.into_inner()
 			.into_iter()
 			.enumerate()
-			.filter_map(|(index, entry)| entry.map(|entry| Ok((OracleId::from_index(index), entry.witness?, entry.nonzero_scalars_prefix))))
+			.filter_map(|(index, entry)| entry.map(|entry| {
+				let witness = chunked_witness(self.storage, entry.data, entry.tower_level, entry.witness?);
+				Ok((OracleId::from_index(index), witness, entry.nonzero_scalars_prefix))
+			}))
 			.collect::<Result<Vec<_>, Error>>()?;
 		result.update_multilin_poly_with_nonzero_scalars_prefixes(entries)?;
 		Ok(result)
 	}
+
+	/// Writes the filled columns to `writer` in a columnar binary format.
+	///
+	/// The output starts with [`WITNESS_MAGIC`], the format version, the number of oracles of the
+	/// constraint system and the number of columns. Each column follows with its oracle index,
+	/// tower level, number of variables, nonzero scalars prefix and data length in bytes, the
+	/// Keccak-256 checksum of the data and the data, which is the little-endian encoding of the
+	/// underliers of the column described in [`binius_field::le_bytes`]. Integers are
+	/// little-endian `u64`s.
+	///
+	/// Columns can be generated by other tools in this format and loaded with
+	/// [`Self::read_columns`].
+	pub fn write_columns(&self, mut writer: impl Write) -> Result<(), Error> {
+		let entries = self.entries.borrow();
+		let oracles = self.oracles.borrow();
+		let columns = entries
+			.iter()
+			.enumerate()
+			.filter_map(|(index, entry)| entry.as_ref().map(|entry| (index, entry)))
+			.collect::<Vec<_>>();
+
+		writer.write_all(WITNESS_MAGIC)?;
+		for value in [WITNESS_FORMAT_VERSION, oracles.size(), columns.len()] {
+			writer.write_all(&(value as u64).to_le_bytes())?;
+		}
+		for (index, entry) in columns {
+			let data = le_bytes::to_le_bytes(entry.data);
+			let header = [
+				index,
+				entry.tower_level,
+				oracles.n_vars(OracleId::from_index(index)),
+				entry.nonzero_scalars_prefix,
+				data.len(),
+			];
+			for value in header {
+				writer.write_all(&(value as u64).to_le_bytes())?;
+			}
+			writer.write_all(&keccak256(&data))?;
+			writer.write_all(&data)?;
+		}
+		Ok(())
+	}
+
+	/// Reads columns written by [`Self::write_columns`] into the witness.
+	///
+	/// The columns are allocated in the witness storage and replace any existing witness of the
+	/// same oracles. Fails if a checksum does not match, or the columns do not match the oracles
+	/// of the constraint system, in which case no column is inserted.
+	pub fn read_columns(&self, mut reader: impl Read) -> Result<(), Error> {
+		let mut magic = [0; WITNESS_MAGIC.len()];
+		reader.read_exact(&mut magic)?;
+		ensure!(&magic == WITNESS_MAGIC, "not a witness file");
+		let version = read_u64(&mut reader)?;
+		ensure!(version == WITNESS_FORMAT_VERSION, "unsupported witness format version {version}");
+
+		let oracles = self.oracles.borrow();
+		let n_oracles = read_u64(&mut reader)?;
+		ensure!(
+			n_oracles == oracles.size(),
+			"the witness has {n_oracles} oracles, the constraint system has {}",
+			oracles.size()
+		);
+
+		// The columns are only inserted once all of them have been read and validated, so that a
+		// malformed file leaves the witness unchanged.
+		let n_columns = read_u64(&mut reader)?;
+		let mut entries = Vec::new();
+		for _ in 0..n_columns {
+			let mut header = [0; 5];
+			for value in &mut header {
+				*value = read_u64(&mut reader)?;
+			}
+			let [
+				index,
+				tower_level,
+				log_rows,
+				nonzero_scalars_prefix,
+				n_bytes,
+			] = header;
+			let mut checksum = [0; 32];
+			reader.read_exact(&mut checksum)?;
+
+			let id = OracleId::from_index(index);
+			ensure!(
+				oracles.is_valid_oracle_id(id),
+				"the witness has a column for unknown oracle {id}"
+			);
+			ensure!(
+				log_rows == oracles.n_vars(id),
+				"the witness column for {} has {log_rows} variables, expected {}",
+				oracles.label(id),
+				oracles.n_vars(id)
+			);
+			let expected_tower_level = oracles.oracle(id).binary_tower_level();
+			ensure!(
+				tower_level == expected_tower_level,
+				"the witness column for {} has tower level {tower_level}, expected \
+				 {expected_tower_level}",
+				oracles.label(id)
+			);
+			ensure!(
+				nonzero_scalars_prefix <= 1 << log_rows,
+				"the witness column for {} has an invalid nonzero scalars prefix",
+				oracles.label(id)
+			);
+
+			let column = ColumnHeader {
+				log_rows,
+				nonzero_scalars_prefix,
+				n_bytes,
+				checksum,
+			};
+			let entry = match tower_level {
+				0 => self.read_column::<BinaryField1b>(&mut reader, column),
+				1 => self.read_column::<BinaryField2b>(&mut reader, column),
+				2 => self.read_column::<BinaryField4b>(&mut reader, column),
+				3 => self.read_column::<BinaryField8b>(&mut reader, column),
+				4 => self.read_column::<BinaryField16b>(&mut reader, column),
+				5 => self.read_column::<BinaryField32b>(&mut reader, column),
+				6 => self.read_column::<BinaryField64b>(&mut reader, column),
+				7 => self.read_column::<BinaryField128b>(&mut reader, column),
+				_ => bail!(anyhow!("unsupported tower level {tower_level}")),
+			}
+			.map_err(|err| anyhow!("the witness column for {}: {err}", oracles.label(id)))?;
+			entries.push((id, entry));
+		}
+
+		for (id, entry) in entries {
+			insert_entry(&self.entries, id, entry);
+		}
+		Ok(())
+	}
+
+	fn read_column<FS>(
+		&self,
+		reader: &mut impl Read,
+		column: ColumnHeader,
+	) -> Result<WitnessBuilderEntry<'arena>, Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let len = 1
+			<< column
+				.log_rows
+				.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
+		ensure!(
+			column.n_bytes == len * size_of::<U>(),
+			"expected {} bytes, got {}",
+			len * size_of::<U>(),
+			column.n_bytes
+		);
+		let data = self.storage.alloc_column(len, U::default())?;
+		reader.read_exact(must_cast_slice_mut::<U, u8>(data))?;
+		ensure!(keccak256(must_cast_slice(data)) == column.checksum, "checksum mismatch");
+		le_bytes::from_le_in_place(data);
+		let data: &'arena [U] = data;
+		Ok(WitnessBuilderEntry::new::<FS>(column.log_rows, column.nonzero_scalars_prefix, data))
+	}
+}
+
+/// The magic bytes at the start of the output of [`Builder::write_columns`].
+pub const WITNESS_MAGIC: &[u8; 4] = b"BNWT";
+
+const WITNESS_FORMAT_VERSION: usize = 1;
+
+/// The header of a column read by [`Builder::read_columns`].
+struct ColumnHeader {
+	log_rows: usize,
+	nonzero_scalars_prefix: usize,
+	n_bytes: usize,
+	checksum: [u8; 32],
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<usize, Error> {
+	let mut bytes = [0; 8];
+	reader.read_exact(&mut bytes)?;
+	Ok(usize::try_from(u64::from_le_bytes(bytes))?)
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+	let mut hasher = Keccak::v256();
+	hasher.update(data);
+	let mut digest = [0; 32];
+	hasher.finalize(&mut digest);
+	digest
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -272,21 +765,406 @@ where
 {
 	fn drop(&mut self) {
 		let data = Option::take(&mut self.data).expect("data is always Some until this point");
-		let mut entries = self.entries.borrow_mut();
-		let oracle_index = self.id.index();
-		let nonzero_scalars_prefix = self.nonzero_scalars_prefix;
-		if oracle_index >= entries.len() {
-			entries.resize_with(oracle_index + 1, || None);
-		}
-		entries[oracle_index] = Some(WitnessBuilderEntry {
+		let entry =
+			WitnessBuilderEntry::new::<FS>(self.log_rows, self.nonzero_scalars_prefix, data);
+		insert_entry(&self.entries, self.id, entry);
+	}
+}
+
+impl<'arena> WitnessBuilderEntry<'arena> {
+	fn new<FS>(log_rows: usize, nonzero_scalars_prefix: usize, data: &'arena [U]) -> Self
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		Self {
 			data,
 			nonzero_scalars_prefix,
 			tower_level: FS::TOWER_LEVEL,
 			witness: MultilinearExtension::new(
-				self.log_rows,
+				log_rows,
 				PackedType::<U, FS>::from_underliers_ref(data),
 			)
 			.map(|x| x.specialize_arc_dyn()),
+		}
+	}
+}
+
+fn insert_entry<'arena>(
+	entries: &RefCell<Vec<Option<WitnessBuilderEntry<'arena>>>>,
+	id: OracleId,
+	entry: WitnessBuilderEntry<'arena>,
+) {
+	let mut entries = entries.borrow_mut();
+	let oracle_index = id.index();
+	if oracle_index >= entries.len() {
+		entries.resize_with(oracle_index + 1, || None);
+	}
+	entries[oracle_index] = Some(entry);
+}
+
+type ColumnFillFn<'arena, 'scope> =
+	Box<dyn FnOnce() -> Result<WitnessBuilderEntry<'arena>, Error> + Send + 'scope>;
+
+/// A batch of column fills that are executed in parallel.
+///
+/// Gadgets register one closure per new column with [`Self::column`], and [`Self::run`] executes
+/// all of them on the rayon thread pool. The closures may read any column that is already in the
+/// witness, but not the columns written by the same batch. The columns are inserted into the
+/// witness in registration order once all fills have completed, so the result does not depend on
+/// the scheduling.
+pub struct ParallelFill<'builder, 'arena, 'scope> {
+	builder: &'builder Builder<'arena>,
+	fills: Vec<(OracleId, ColumnFillFn<'arena, 'scope>)>,
+}
+
+impl<'arena: 'scope, 'scope> ParallelFill<'_, 'arena, 'scope> {
+	/// Registers a closure that fills the new column `id`.
+	///
+	/// The column is zero-initialized before the closure runs. A failure to allocate the column is
+	/// returned by [`Self::run`].
+	pub fn column<FS>(
+		&mut self,
+		id: OracleId,
+		fill: impl FnOnce(&mut ColumnFill<'_, FS>) -> Result<(), Error> + Send + 'scope,
+	) -> &mut Self
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let log_rows = self.builder.oracles.borrow().n_vars(id);
+		let nonzero_scalars_prefix = 1 << log_rows;
+		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
+		let data = self.builder.storage.alloc_column(len, U::default());
+		self.fills.push((
+			id,
+			Box::new(move || {
+				let data = data?;
+				fill(&mut ColumnFill {
+					data: &mut *data,
+					_marker: PhantomData,
+				})?;
+				Ok(WitnessBuilderEntry::new::<FS>(log_rows, nonzero_scalars_prefix, data))
+			}),
+		));
+		self
+	}
+
+	/// Executes the registered fills and inserts the columns into the witness.
+	pub fn run(self) -> Result<(), Error> {
+		let (ids, fills): (Vec<_>, Vec<_>) = self.fills.into_iter().unzip();
+		let entries = fills
+			.into_par_iter()
+			.map(|fill| fill())
+			.collect::<Result<Vec<_>, _>>()?;
+		for (id, entry) in ids.into_iter().zip(entries) {
+			insert_entry(&self.builder.entries, id, entry);
+		}
+		Ok(())
+	}
+}
+
+/// Mutable access to the data of a column filled by a [`ParallelFill`].
+pub struct ColumnFill<'a, FS> {
+	data: &'a mut [U],
+	_marker: PhantomData<FS>,
+}
+
+impl<FS> ColumnFill<'_, FS>
+where
+	FS: TowerField,
+	U: PackScalar<FS>,
+{
+	#[inline]
+	pub fn packed(&mut self) -> &mut [PackedType<U, FS>] {
+		PackedType::<U, FS>::from_underliers_ref_mut(self.data)
+	}
+
+	#[inline]
+	pub fn as_mut_slice<T: Pod>(&mut self) -> &mut [T] {
+		must_cast_slice_mut(self.data)
+	}
+}
+
+/// Returns whether a little-endian integer has a set bit at position `n_bits` or above.
+/// The binary logarithm of the number of rows whose bits are transposed together by
+/// [`Builder::fill_bit_decomposition`] and [`Builder::fill_bit_recomposition`].
+const LOG_TRANSPOSE_CHUNK_ROWS: usize = 12;
+
+/// Transposes the bit matrix with `2^log_rows` rows of `2^log_cols` bits, stored row by row with
+/// the least significant bit first in `src`, into `dst`.
+///
+/// The bits are packed into 1-bit packed fields as wide as the smaller dimension of the matrix, up
+/// to 128 bits, so that [`transpose_scalars`] transposes the matrix in square blocks of packed
+/// elements. Both dimensions must be at least 8.
+fn transpose_bits(
+	src: &[u8],
+	dst: &mut [u8],
+	log_rows: usize,
+	log_cols: usize,
+) -> Result<(), Error> {
+	fn transpose<P: PackedField<Scalar = BinaryField1b> + Pod>(
+		src: &[u8],
+		dst: &mut [u8],
+		log_rows: usize,
+		log_cols: usize,
+	) -> Result<(), Error> {
+		// The byte slices are not aligned for the packed elements.
+		let len = src.len() / size_of::<P>();
+		let mut packed = vec![P::zero(); len];
+		must_cast_slice_mut::<_, u8>(&mut packed).copy_from_slice(src);
+		let mut transposed = vec![P::zero(); len];
+		transpose_scalars(&packed, &mut transposed, log_rows, log_cols)?;
+		dst.copy_from_slice(must_cast_slice(&transposed));
+		Ok(())
+	}
+
+	match log_rows.min(log_cols) {
+		3 => transpose::<PackedBinaryField8x1b>(src, dst, log_rows, log_cols),
+		4 => transpose::<PackedBinaryField16x1b>(src, dst, log_rows, log_cols),
+		5 => transpose::<PackedBinaryField32x1b>(src, dst, log_rows, log_cols),
+		6 => transpose::<PackedBinaryField64x1b>(src, dst, log_rows, log_cols),
+		_ => transpose::<PackedBinaryField128x1b>(src, dst, log_rows, log_cols),
+	}
+}
+
+fn has_bits_above(value: &[u8], n_bits: usize) -> bool {
+	value.iter().enumerate().any(|(i, &byte)| {
+		let low_bits = n_bits.saturating_sub(8 * i).min(8);
+		u16::from(byte) >> low_bits != 0
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField8b, BinaryField32b};
+	use binius_macros::arith_expr;
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{
+		builder::{ConstraintSystemBuilder, test_utils::test_circuit},
+		unconstrained::unconstrained,
+	};
+
+	#[test]
+	fn test_parallel_fill() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let input = builder.add_committed("input", 8, BinaryField32b::TOWER_LEVEL);
+		let outputs = (0..4)
+			.map(|i| builder.add_committed(format!("byte_{i}"), 8, BinaryField8b::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+
+		let witness = builder.witness().unwrap();
+		for (i, value) in witness
+			.new_column::<BinaryField32b>(input)
+			.unwrap()
+			.as_mut_slice::<u32>()
+			.iter_mut()
+			.enumerate()
+		{
+			*value = (i as u32).wrapping_mul(0x9e3779b9);
+		}
+
+		let input_values = witness
+			.get::<BinaryField32b>(input)
+			.unwrap()
+			.as_slice::<u32>();
+		let mut fill = witness.parallel_fill();
+		for (i, &output) in outputs.iter().enumerate() {
+			fill.column::<BinaryField8b>(output, move |column| {
+				for (out, value) in column.as_mut_slice::<u8>().iter_mut().zip(input_values) {
+					*out = (value >> (8 * i)) as u8;
+				}
+				Ok(())
+			});
+		}
+		fill.run().unwrap();
+
+		for (i, &output) in outputs.iter().enumerate() {
+			let bytes = witness
+				.get::<BinaryField8b>(output)
+				.unwrap()
+				.as_slice::<u8>();
+			for (byte, value) in bytes.iter().zip(input_values) {
+				assert_eq!(*byte, (value >> (8 * i)) as u8);
+			}
+		}
+	}
+
+	#[test]
+	fn test_write_read_columns() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let x = unconstrained::<BinaryField8b>(&mut builder, "x", 5).unwrap();
+		let y = unconstrained::<BinaryField1b>(&mut builder, "y", 9).unwrap();
+		let witness = builder.witness().unwrap();
+		let mut bytes = Vec::new();
+		witness.write_columns(&mut bytes).unwrap();
+
+		let mut copy = ConstraintSystemBuilder::new_with_witness(&allocator);
+		copy.add_committed("x", 5, BinaryField8b::TOWER_LEVEL);
+		copy.add_committed("y", 9, BinaryField1b::TOWER_LEVEL);
+		let copy_witness = copy.witness().unwrap();
+		copy_witness.read_columns(bytes.as_slice()).unwrap();
+		assert_eq!(
+			copy_witness
+				.get::<BinaryField8b>(x)
+				.unwrap()
+				.as_slice::<u8>(),
+			witness.get::<BinaryField8b>(x).unwrap().as_slice::<u8>()
+		);
+		assert_eq!(
+			copy_witness
+				.get::<BinaryField1b>(y)
+				.unwrap()
+				.as_slice::<u8>(),
+			witness.get::<BinaryField1b>(y).unwrap().as_slice::<u8>()
+		);
+
+		let mut other_shape = ConstraintSystemBuilder::new_with_witness(&allocator);
+		other_shape.add_committed("x", 5, BinaryField8b::TOWER_LEVEL);
+		other_shape.add_committed("y", 10, BinaryField1b::TOWER_LEVEL);
+		let other_witness = other_shape.witness().unwrap();
+		assert!(other_witness.read_columns(bytes.as_slice()).is_err());
+		// The valid column is not inserted either.
+		assert!(other_witness.get::<BinaryField8b>(x).is_err());
+
+		let mut other_field = ConstraintSystemBuilder::new_with_witness(&allocator);
+		other_field.add_committed("x", 5, BinaryField16b::TOWER_LEVEL);
+		other_field.add_committed("y", 9, BinaryField1b::TOWER_LEVEL);
+		let other_witness = other_field.witness().unwrap();
+		assert!(other_witness.read_columns(bytes.as_slice()).is_err());
+
+		let last = bytes.len() - 1;
+		bytes[last] ^= 1;
+		assert!(copy_witness.read_columns(bytes.as_slice()).is_err());
+	}
+
+	#[test]
+	fn test_parallel_fill_propagates_errors() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let column = builder.add_committed("column", 8, BinaryField8b::TOWER_LEVEL);
+
+		let witness = builder.witness().unwrap();
+		let mut fill = witness.parallel_fill();
+		fill.column::<BinaryField8b>(column, |_| Err(anyhow!("fill failed")));
+		assert!(fill.run().is_err());
+	}
+
+	#[test]
+	fn test_virtual_column() {
+		test_circuit(|builder| {
+			let log_size = 8;
+			let x = builder.add_committed("x", log_size, BinaryField8b::TOWER_LEVEL);
+			let y = builder.add_committed("y", log_size, BinaryField8b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut rng = StdRng::seed_from_u64(0);
+				for id in [x, y] {
+					rng.fill(
+						witness
+							.new_column::<BinaryField8b>(id)?
+							.as_mut_slice::<u8>(),
+					);
+				}
+			}
+
+			let virtual_column = builder.add_virtual::<BinaryField8b>(
+				"x * y + x",
+				[x, y],
+				arith_expr!([x, y] = x * y + x).convert_field(),
+			)?;
+			if let Some(witness) = builder.witness() {
+				assert!(witness.get::<BinaryField8b>(virtual_column).is_ok());
+			}
+			builder.assert_zero(
+				"virtual_column",
+				[x, y, virtual_column],
+				arith_expr!([x, y, p] = x * y + x - p).convert_field(),
+			);
+			Ok(vec![])
 		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_next_row() {
+		test_circuit(|builder| {
+			let log_size = 8;
+			let x = builder.add_committed("x", log_size, BinaryField8b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut rng = StdRng::seed_from_u64(0);
+				rng.fill(witness.new_column::<BinaryField8b>(x)?.as_mut_slice::<u8>());
+			}
+
+			let next = builder.add_next_row::<BinaryField8b>("next", x, 1)?;
+			if let Some(witness) = builder.witness() {
+				let x = witness.get::<BinaryField8b>(x)?.as_slice::<u8>();
+				let next = witness.get::<BinaryField8b>(next)?.as_slice::<u8>();
+				assert_eq!(&next[..next.len() - 1], &x[1..]);
+				assert_eq!(next[next.len() - 1], 0);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_bit_decomposition_round_trip() {
+		test_circuit(|builder| {
+			// The small column takes the per-row path, the large ones the packed transposes, in a
+			// single chunk and in several.
+			for log_size in [2, 8, 14] {
+				let x = builder.add_committed(
+					format!("x_{log_size}"),
+					log_size,
+					BinaryField32b::TOWER_LEVEL,
+				);
+				if let Some(witness) = builder.witness() {
+					let mut rng = StdRng::seed_from_u64(0);
+					rng.fill(
+						witness
+							.new_column::<BinaryField32b>(x)?
+							.as_mut_slice::<u32>(),
+					);
+				}
+
+				let bits = builder.decompose_to_bits::<BinaryField32b>("bits", x, 32)?;
+				let y = builder.recompose_from_bits::<BinaryField32b>("y", &bits)?;
+				if let Some(witness) = builder.witness() {
+					let x = witness.get::<BinaryField32b>(x)?.as_slice::<u32>();
+					let y = witness.get::<BinaryField32b>(y)?.as_slice::<u32>();
+					assert_eq!(x, y);
+
+					for (i, &bit) in bits.iter().enumerate() {
+						let bit = witness.get::<BinaryField1b>(bit)?;
+						for (row, &value) in x.iter().enumerate() {
+							assert_eq!(
+								get_packed_slice(bit.packed(), row),
+								BinaryField1b::from((value >> i) as u8 & 1)
+							);
+						}
+					}
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_virtual_column_rejects_wrong_tower_level() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let x = builder.add_committed("x", 8, BinaryField8b::TOWER_LEVEL);
+		let result = builder.add_virtual::<BinaryField32b>(
+			"x * x",
+			[x],
+			arith_expr!([x] = x * x).convert_field(),
+		);
+		assert!(result.is_err());
 	}
 }
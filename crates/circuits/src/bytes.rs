@@ -0,0 +1,195 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Gadgets over byte arrays stored one byte per column.
+
+use anyhow::ensure;
+use binius_core::oracle::OracleId;
+use binius_field::{
+	BinaryField1b, BinaryField8b, BinaryField32b, Field, TowerField, packed::set_packed_slice,
+};
+use binius_math::ArithCircuit;
+
+use crate::builder::{ConstraintSystemBuilder, types::F};
+
+type B1 = BinaryField1b;
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+/// Constrains the first `len` bytes of the byte arrays `a` and `b` to be equal, row by row.
+///
+/// `a` and `b` hold the bytes of the arrays, one [`BinaryField8b`] column per byte, and `len` is a
+/// [`BinaryField32b`] column holding the length of the compared prefix as an integer, which must
+/// be at most the number of columns. Bytes past the length are unconstrained.
+///
+/// The length is decomposed into a committed prefix mask, one 1-bit column per byte that is set
+/// exactly on the bytes before the length. The mask is constrained to be non-increasing, and the
+/// length is recovered from it as the position of its last set bit, which the one-hot difference
+/// of consecutive mask bits selects. Returns the mask columns.
+pub fn equal_var_len(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: &[OracleId],
+	b: &[OracleId],
+	len: OracleId,
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	ensure!(a.len() == b.len(), "equal_var_len: the byte arrays must have the same size");
+	let max_len = a.len();
+	ensure!(max_len < 1 << 32, "equal_var_len: the byte arrays must have less than 2^32 bytes");
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows(a.iter().chain(b).copied().chain([len]))?;
+	let mask = (0..max_len)
+		.map(|i| builder.add_committed(format!("mask_{i}"), log_rows, B1::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+
+	if let Some(witness) = builder.witness() {
+		let a_bytes = a
+			.iter()
+			.map(|&id| Ok(witness.get::<B8>(id)?.as_slice::<u8>()))
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+		let b_bytes = b
+			.iter()
+			.map(|&id| Ok(witness.get::<B8>(id)?.as_slice::<u8>()))
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+		let lens = witness.get::<B32>(len)?.as_slice::<u32>();
+
+		let mut mask_witnesses = mask
+			.iter()
+			.map(|&bit| witness.new_column::<B1>(bit))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		for (row, &row_len) in lens.iter().enumerate().take(1 << log_rows) {
+			let row_len = row_len as usize;
+			ensure!(
+				row_len <= max_len,
+				"equal_var_len: length {row_len} at row {row} exceeds the array size {max_len}"
+			);
+			ensure!(
+				(0..row_len).all(|i| a_bytes[i][row] == b_bytes[i][row]),
+				"equal_var_len: the byte arrays differ at row {row}"
+			);
+			for mask in &mut mask_witnesses[..row_len] {
+				set_packed_slice(mask.packed(), row, B1::ONE);
+			}
+		}
+	}
+
+	for (i, window) in mask.windows(2).enumerate() {
+		builder.assert_zero(
+			format!("mask_non_increasing_{i}"),
+			window.iter().copied(),
+			ArithCircuit::var(1) * (ArithCircuit::var(0) + ArithCircuit::one()),
+		);
+	}
+
+	for (i, ((&a, &b), &mask)) in a.iter().zip(b).zip(&mask).enumerate() {
+		builder.assert_zero(
+			format!("equal_{i}"),
+			[a, b, mask],
+			(ArithCircuit::var(0) - ArithCircuit::var(1)) * ArithCircuit::var(2),
+		);
+	}
+
+	// The length is the sum over i of (mask_i - mask_{i + 1}) * (i + 1), where at most one of the
+	// differences is nonzero. Grouping the terms by mask bit, as integers encoded in the canonical
+	// basis, gives the coefficient (i + 1) ^ i for mask_i.
+	let recomposed = (0..max_len)
+		.map(|i| {
+			let coefficient = F::from(B32::new(((i + 1) ^ i) as u32));
+			ArithCircuit::var(i + 1) * ArithCircuit::constant(coefficient)
+		})
+		.sum::<ArithCircuit<F>>();
+	builder.assert_zero(
+		"len",
+		[len].into_iter().chain(mask.iter().copied()),
+		recomposed - ArithCircuit::var(0),
+	);
+
+	builder.pop_namespace();
+	Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	const MAX_LEN: usize = 12;
+
+	fn add_byte_arrays(
+		builder: &mut ConstraintSystemBuilder,
+		log_size: usize,
+		differ_past_len: bool,
+	) -> (Vec<OracleId>, Vec<OracleId>, OracleId) {
+		let a = (0..MAX_LEN)
+			.map(|i| builder.add_committed(format!("a_{i}"), log_size, B8::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+		let b = (0..MAX_LEN)
+			.map(|i| builder.add_committed(format!("b_{i}"), log_size, B8::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+		let len = builder.add_committed("len", log_size, B32::TOWER_LEVEL);
+
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut len_witness = witness.new_column::<B32>(len).unwrap();
+			let lens = len_witness.as_mut_slice::<u32>();
+			for row_len in lens.iter_mut() {
+				*row_len = rng.gen_range(0..=MAX_LEN as u32);
+			}
+
+			for (i, (&a, &b)) in a.iter().zip(&b).enumerate() {
+				let mut a_witness = witness.new_column::<B8>(a).unwrap();
+				let mut b_witness = witness.new_column::<B8>(b).unwrap();
+				let a_bytes = a_witness.as_mut_slice::<u8>();
+				let b_bytes = b_witness.as_mut_slice::<u8>();
+				rng.fill(&mut a_bytes[..]);
+				for (row, (a_byte, b_byte)) in a_bytes.iter().zip(b_bytes.iter_mut()).enumerate() {
+					*b_byte = if differ_past_len && i >= lens[row] as usize {
+						!*a_byte
+					} else {
+						*a_byte
+					};
+				}
+			}
+		}
+
+		(a, b, len)
+	}
+
+	#[test]
+	fn test_equal_var_len() {
+		test_circuit(|builder| {
+			let (a, b, len) = add_byte_arrays(builder, 8, true);
+			let mask = equal_var_len(builder, "equal_var_len", &a, &b, len)?;
+			assert_eq!(mask.len(), MAX_LEN);
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_equal_var_len_rejects_different_prefixes() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let (a, b, len) = add_byte_arrays(&mut builder, 8, false);
+		if let Some(witness) = builder.witness() {
+			let lens = witness.get::<B32>(len).unwrap().as_slice::<u32>();
+			let row = lens.iter().position(|&row_len| row_len > 0).unwrap();
+			let mut b_witness = witness.new_column::<B8>(b[0]).unwrap();
+			let a_bytes = witness.get::<B8>(a[0]).unwrap().as_slice::<u8>();
+			let b_bytes = b_witness.as_mut_slice::<u8>();
+			b_bytes.copy_from_slice(a_bytes);
+			b_bytes[row] ^= 1;
+		}
+		assert!(equal_var_len(&mut builder, "equal_var_len", &a, &b, len).is_err());
+	}
+
+	#[test]
+	fn test_equal_var_len_rejects_mismatched_sizes() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let (a, b, len) = add_byte_arrays(&mut builder, 8, false);
+		assert!(equal_var_len(&mut builder, "equal_var_len", &a, &b[1..], len).is_err());
+	}
+}
@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_core::{
-	constraint_system::channel::{Boundary, ChannelId, FlushDirection, OracleOrConst},
+	constraint_system::channel::{Boundary, ChannelId, OracleOrConst},
 	oracle::OracleId,
 };
 use binius_field::{
@@ -61,6 +61,9 @@ impl Collatz {
 		self.odd(builder, channel, odds_count)?;
 
 		let boundaries = self.get_boundaries(channel);
+		for boundary in &boundaries {
+			builder.add_public_input(boundary.clone())?;
+		}
 
 		Ok(boundaries)
 	}
@@ -76,7 +79,7 @@ impl Collatz {
 		if let Some(witness) = builder.witness() {
 			debug_assert_eq!(count, self.evens.len());
 			witness
-				.new_column::<BinaryField1b>(even)
+				.new_column::<BinaryField1b>(even)?
 				.as_mut_slice::<u32>()[..count]
 				.copy_from_slice(&self.evens);
 		}
@@ -106,7 +109,7 @@ impl Collatz {
 		if let Some(witness) = builder.witness() {
 			debug_assert_eq!(count, self.odds.len());
 			witness
-				.new_column::<BinaryField1b>(odd)
+				.new_column::<BinaryField1b>(odd)?
 				.as_mut_slice::<u32>()[..count]
 				.copy_from_slice(&self.odds);
 		}
@@ -137,18 +140,8 @@ impl Collatz {
 
 	fn get_boundaries(&self, channel_id: usize) -> Vec<Boundary<F>> {
 		vec![
-			Boundary {
-				channel_id,
-				direction: FlushDirection::Push,
-				values: vec![BinaryField32b::new(self.x0).into()],
-				multiplicity: 1,
-			},
-			Boundary {
-				channel_id,
-				direction: FlushDirection::Pull,
-				values: vec![BinaryField32b::new(1).into()],
-				multiplicity: 1,
-			},
+			Boundary::push(channel_id, [BinaryField32b::new(self.x0)]),
+			Boundary::pull(channel_id, [BinaryField32b::new(1)]),
 		]
 	}
 }
@@ -193,7 +186,19 @@ pub fn ensure_odd(
 
 #[cfg(test)]
 mod tests {
-	use crate::{builder::test_utils::test_circuit, collatz::Collatz};
+	use binius_core::{constraint_system, fiat_shamir::HasherChallenger};
+	use binius_field::tower::CanonicalTowerFamily;
+	use binius_hal::make_portable_backend;
+	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+
+	use crate::{
+		builder::{
+			ConstraintSystemBuilder,
+			test_utils::{test_circuit, test_proof_size_estimate},
+			types::U,
+		},
+		collatz::Collatz,
+	};
 
 	#[test]
 	fn test_collatz() {
@@ -206,4 +211,66 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	#[test]
+	fn test_collatz_proof_size_estimate() {
+		test_proof_size_estimate(|builder| {
+			let mut collatz = Collatz::new(27);
+			let advice = collatz.init_prover();
+			collatz.build(builder, advice)
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_collatz_public_inputs_bind_proof() {
+		let x0 = 27;
+		let mut collatz = Collatz::new(x0);
+		let advice = collatz.init_prover();
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		collatz.build(&mut builder, advice).unwrap();
+		let public_inputs = builder.public_inputs().to_vec();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let backend = make_portable_backend();
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&public_inputs,
+			witness,
+			&backend,
+		)
+		.unwrap();
+
+		let verify = |x0| {
+			let mut builder = ConstraintSystemBuilder::new();
+			Collatz::new(x0).build(&mut builder, advice).unwrap();
+			let public_inputs = builder.public_inputs().to_vec();
+			let constraint_system = builder.build().unwrap();
+			constraint_system::verify::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(
+				&constraint_system,
+				constraint_system::SecurityLevel::new(10, 1),
+				&public_inputs,
+				proof.clone(),
+			)
+		};
+		verify(x0).unwrap();
+		assert!(verify(x0 + 2).is_err());
+	}
 }
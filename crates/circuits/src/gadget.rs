@@ -0,0 +1,220 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A common interface for circuit gadgets.
+//!
+//! Most gadgets in this crate are free functions that allocate columns, fill their witness if the
+//! builder has one, and add constraints, all in one go. The [`Gadget`] trait splits these steps so
+//! that circuit generators can compose gadgets, inspect their input and output oracles, and fill
+//! witnesses separately from the constraint system.
+
+use binius_core::oracle::OracleId;
+// Re-exported for the expansion of [`circuit!`](crate::circuit).
+#[doc(hidden)]
+pub use binius_field::TowerField;
+#[doc(hidden)]
+pub use binius_macros::arith_expr;
+
+use crate::builder::{ConstraintSystemBuilder, witness};
+
+/// A collection of oracles consumed or produced by a [`Gadget`].
+pub trait GadgetOracles {
+	/// The oracle ids in the collection, in order.
+	fn oracle_ids(&self) -> Vec<OracleId>;
+}
+
+impl GadgetOracles for () {
+	fn oracle_ids(&self) -> Vec<OracleId> {
+		Vec::new()
+	}
+}
+
+impl GadgetOracles for OracleId {
+	fn oracle_ids(&self) -> Vec<OracleId> {
+		vec![*self]
+	}
+}
+
+impl GadgetOracles for Vec<OracleId> {
+	fn oracle_ids(&self) -> Vec<OracleId> {
+		self.clone()
+	}
+}
+
+impl<const N: usize> GadgetOracles for [OracleId; N] {
+	fn oracle_ids(&self) -> Vec<OracleId> {
+		self.to_vec()
+	}
+}
+
+impl<A: GadgetOracles, B: GadgetOracles> GadgetOracles for (A, B) {
+	fn oracle_ids(&self) -> Vec<OracleId> {
+		let mut ids = self.0.oracle_ids();
+		ids.extend(self.1.oracle_ids());
+		ids
+	}
+}
+
+/// A reusable piece of a constraint system.
+///
+/// A gadget takes oracles of type [`Self::Input`], allocates its own columns and constraints in
+/// [`Self::constrain`], and returns the oracles of type [`Self::Output`]. The witness of the
+/// output is computed from the witness of the input in [`Self::fill_witness`].
+pub trait Gadget {
+	type Input: GadgetOracles;
+	type Output: GadgetOracles;
+
+	/// The name of the gadget, used to name its columns and constraints.
+	fn name(&self) -> &str;
+
+	/// Adds the columns and constraints of the gadget to the constraint system.
+	fn constrain(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		input: &Self::Input,
+	) -> Result<Self::Output, anyhow::Error>;
+
+	/// Fills the witness of the columns allocated by [`Self::constrain`].
+	///
+	/// The witness of `input` must have been filled already.
+	fn fill_witness(
+		&self,
+		witness: &mut witness::Builder,
+		input: &Self::Input,
+		output: &Self::Output,
+	) -> Result<(), anyhow::Error>;
+
+	/// Adds the gadget to the constraint system, filling its witness if the builder has one.
+	fn build(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		input: Self::Input,
+	) -> Result<Self::Output, anyhow::Error> {
+		let output = self.constrain(builder, &input)?;
+		if let Some(witness) = builder.witness() {
+			self.fill_witness(witness, &input, &output)?;
+		}
+		Ok(output)
+	}
+}
+
+/// Declares the datapath of a circuit as a sequence of columns, gadgets and constraints.
+///
+/// The first argument is a `&mut ConstraintSystemBuilder` variable, followed by statements which
+/// expand to the corresponding builder calls, in order:
+///
+/// * `committed name: FS, log_rows;` adds a committed column of field `FS` bound to `name`, and
+///   `committed name[N]: FS, log_rows;` adds an array of `N` such columns.
+/// * `let pattern = gadget => input;` builds a [`Gadget`] on `input` and binds its output.
+/// * `assert_zero "name" ([x, y, ...] = expr);` constrains `expr`, in the syntax of
+///   [`binius_macros::arith_expr`], to vanish over the columns `x, y, ...`. The expression expands
+///   to paths in `binius_field` and `binius_math`, which the calling crate must depend on.
+///
+/// The bindings are regular local variables, so outputs are wired to inputs by name. Gadget inputs
+/// are checked against [`Gadget::Input`] and constraint variables double as the oracle list, so
+/// arity mismatches are compile errors. The statements use `?`, so the enclosing function must
+/// return a `Result` whose error type converts from `anyhow::Error`.
+#[macro_export]
+macro_rules! circuit {
+	($builder:ident; $($body:tt)*) => {
+		$crate::circuit!(@stmt $builder; $($body)*);
+	};
+	(@stmt $builder:ident;) => {};
+	(@stmt $builder:ident; committed $name:ident[$n:expr]: $fs:ty, $log_rows:expr; $($rest:tt)*) => {
+		let $name: [_; $n] = ::std::array::from_fn(|i| {
+			$builder.add_committed(
+				format!("{}_{i}", stringify!($name)),
+				$log_rows,
+				<$fs as $crate::gadget::TowerField>::TOWER_LEVEL,
+			)
+		});
+		$crate::circuit!(@stmt $builder; $($rest)*);
+	};
+	(@stmt $builder:ident; committed $name:ident: $fs:ty, $log_rows:expr; $($rest:tt)*) => {
+		let $name = $builder.add_committed(
+			stringify!($name),
+			$log_rows,
+			<$fs as $crate::gadget::TowerField>::TOWER_LEVEL,
+		);
+		$crate::circuit!(@stmt $builder; $($rest)*);
+	};
+	(@stmt $builder:ident; let $out:pat = $gadget:expr => $input:expr; $($rest:tt)*) => {
+		let $out = $crate::gadget::Gadget::build(&$gadget, $builder, $input)?;
+		$crate::circuit!(@stmt $builder; $($rest)*);
+	};
+	(
+		@stmt $builder:ident;
+		assert_zero $name:literal ($($field:path)? [$($var:ident),+] = $($expr:tt)+);
+		$($rest:tt)*
+	) => {
+		$builder.assert_zero(
+			$name,
+			[$($var),+],
+			$crate::gadget::arith_expr!($($field)? [$($var),+] = $($expr)+).convert_field(),
+		);
+		$crate::circuit!(@stmt $builder; $($rest)*);
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, BinaryField8b};
+
+	use super::*;
+	use crate::{
+		arithmetic::mul::Mul, builder::test_utils::test_circuit, unconstrained::Unconstrained,
+	};
+
+	#[test]
+	fn test_circuit_macro() {
+		test_circuit(|builder| {
+			let log_size = 9;
+			crate::circuit!(builder;
+				let a = Unconstrained::<BinaryField1b>::new("a", log_size) => ();
+				let b = Unconstrained::<BinaryField1b>::new("b", log_size) => ();
+				let product = Mul::<BinaryField8b>::new("mul") => (vec![a], vec![b]);
+				committed zeros[2]: BinaryField1b, log_size;
+			);
+			let low = product[0];
+			let [zero_0, zero_1] = zeros;
+			if let Some(witness) = builder.witness() {
+				witness.new_column::<BinaryField1b>(zero_0)?;
+				witness.new_column::<BinaryField1b>(zero_1)?;
+			}
+			crate::circuit!(builder;
+				assert_zero "low bit" ([a, b, low] = a * b - low);
+				assert_zero "zeros" ([zero_0, zero_1] = zero_0 + zero_1);
+			);
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_compose_gadgets() {
+		test_circuit(|builder| {
+			let log_size = 9;
+			let in_a = (0..2)
+				.map(|i| {
+					Unconstrained::<BinaryField1b>::new(format!("in_a_{i}"), log_size)
+						.build(builder, ())
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+			let in_b = (0..2)
+				.map(|i| {
+					Unconstrained::<BinaryField1b>::new(format!("in_b_{i}"), log_size)
+						.build(builder, ())
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let input = (in_a, in_b);
+			let mul = Mul::<BinaryField8b>::new("mul");
+			assert_eq!(mul.name(), "mul");
+			assert_eq!(input.oracle_ids().len(), 4);
+
+			let output = mul.build(builder, input)?;
+			assert_eq!(output.oracle_ids().len(), 4);
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
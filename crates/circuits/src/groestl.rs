@@ -1,406 +1,505 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+//! Gadgets for the [Grøstl] P permutation and the Grøstl-256 output transformation.
+//!
+//! The output transformation is the 2-to-1 compression function used by
+//! [`Groestl256ByteCompression`](binius_hash::Groestl256ByteCompression) to build Merkle trees,
+//! so these gadgets allow Merkle commitments made with the Grøstl hasher to be opened in-circuit.
+//!
+//! The state is represented as 64 columns of [`BinaryField8b`] elements, one per state byte, in
+//! the byte order of the Grøstl specification. Grøstl is defined over the AES field, so each byte
+//! is mapped to the isomorphic element of the canonical tower field, that is
+//! `BinaryField8b::from(AESTowerField8b::new(byte))`. This representation is cheaper to verify,
+//! since the canonical tower field is a subfield of the constraint system field.
+//!
+//! [Grøstl]: <https://www.groestl.info/>
+
 use std::array;
 
 use anyhow::Result;
-use array_util::ArrayExt;
 use binius_core::oracle::OracleId;
 use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	packed::{get_packed_slice, set_packed_slice},
-	AESTowerField8b, BinaryField1b, BinaryField8b, ExtensionField, PackedField, TowerField,
+	AESTowerField8b, BinaryField1b, BinaryField8b, ExtensionField, Field, TowerField,
+	packed::set_packed_slice,
 };
-use binius_math::ArithExpr;
-use binius_maybe_rayon::prelude::*;
-use bytemuck::Pod;
+use binius_macros::arith_expr;
+use binius_math::ArithCircuit;
+
+use crate::builder::{ConstraintSystemBuilder, types::F};
+
+type B1 = BinaryField1b;
+type B8 = BinaryField8b;
+
+/// The number of bytes in the Grøstl-256 state.
+pub const STATE_SIZE: usize = 64;
+
+/// The number of bytes in a Grøstl-256 digest.
+pub const DIGEST_SIZE: usize = 32;
+
+const N_ROUNDS: usize = 10;
+
+/// The first row of the circulant matrix defining the MixBytes step, in the AES basis.
+const MIX_BYTES_VEC: [u8; 8] = [0x02, 0x02, 0x03, 0x04, 0x05, 0x03, 0x05, 0x07];
+
+/// The columns of the affine transformation matrix of the Rijndael S-box, isomorphically converted
+/// to the canonical tower basis.
+const S_BOX_TOWER_MATRIX_COLS: [B8; 8] = [
+	B8::new(0x62),
+	B8::new(0xd2),
+	B8::new(0x79),
+	B8::new(0x41),
+	B8::new(0xf4),
+	B8::new(0xd5),
+	B8::new(0x81),
+	B8::new(0x4e),
+];
 
-use crate::{builder::ConstraintSystemBuilder, transparent, unconstrained::unconstrained};
+/// The affine transformation offset of the Rijndael S-box, isomorphically converted to the
+/// canonical tower basis.
+const S_BOX_TOWER_OFFSET: B8 = B8::new(0x14);
 
-pub fn groestl_p_permutation<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+/// Verifies the Grøstl P permutation on every row of the input state columns.
+///
+/// Returns the columns of the output state.
+pub fn groestl_p_permutation(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
 	log_size: usize,
-) -> Result<[OracleId; STATE_SIZE]>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-	PackedType<U, F>: Pod,
-{
-	let p_in = array_util::try_from_fn(|i| {
-		unconstrained::<U, F, AESTowerField8b>(builder, format!("p_in[{i}]"), log_size)
-	})?;
-	let multiples_16: [_; 8] = array::from_fn(|i| {
-		transparent::constant(
-			builder,
-			format!("multiples_16[{i}]"),
-			log_size,
-			AESTowerField8b::new(i as u8 * 0x10),
-		)
-		.unwrap()
-	});
-
-	let round_consts = permutation_round_consts(builder, log_size, 0, multiples_16, p_in)?;
-	let mut output =
-		groestl_p_permutation_round(builder, "round[0]", log_size, round_consts, p_in)?;
-	for round_index in 1..N_ROUNDS {
-		let round_consts =
-			permutation_round_consts(builder, log_size, round_index, multiples_16, output)?;
-		output = groestl_p_permutation_round(
-			builder,
-			format!("rounds[{round_index}]"),
-			log_size,
-			round_consts,
-			output,
-		)?;
+	state_in: [OracleId; STATE_SIZE],
+) -> Result<[OracleId; STATE_SIZE]> {
+	builder.push_namespace(name);
+
+	let mut state = state_in;
+	for round in 0..N_ROUNDS {
+		state = p_permutation_round(builder, format!("round[{round}]"), log_size, round, state)?;
 	}
-	let p_out = output;
 
 	#[cfg(debug_assertions)]
 	if let Some(witness) = builder.witness() {
-		use binius_field::PackedAESBinaryField64x8b;
-		use binius_hash::Groestl256Core;
+		use binius_hash::groestl::{GroestlShortImpl, GroestlShortInternal};
 
-		let inputs = p_in
-			.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?
-			.map(|col| col.as_slice::<AESTowerField8b>());
-		let outputs = p_out
-			.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?
-			.map(|col| col.as_slice::<AESTowerField8b>());
+		let inputs = state_in
+			.iter()
+			.map(|&id| Ok(witness.get::<B8>(id)?.as_slice::<B8>()))
+			.collect::<Result<Vec<_>>>()?;
+		let outputs = state
+			.iter()
+			.map(|&id| Ok(witness.get::<B8>(id)?.as_slice::<B8>()))
+			.collect::<Result<Vec<_>>>()?;
 
 		for z in 0..1 << log_size {
-			assert_eq!(
-				Groestl256Core.permutation_p(PackedAESBinaryField64x8b::from_fn(|i| inputs[i][z])),
-				PackedAESBinaryField64x8b::from_fn(|i| outputs[i][z])
-			);
+			let bytes = array::from_fn(|b| AESTowerField8b::from(inputs[b][z]).val());
+			let mut expected = GroestlShortImpl::state_from_bytes(&bytes);
+			GroestlShortImpl::p_perm(&mut expected);
+			let expected = GroestlShortImpl::state_to_bytes(&expected);
+			for b in 0..STATE_SIZE {
+				assert_eq!(AESTowerField8b::from(outputs[b][z]).val(), expected[b]);
+			}
 		}
 	}
 
-	Ok(p_out)
+	builder.pop_namespace();
+	Ok(state)
 }
 
-fn groestl_p_permutation_round<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+/// Verifies the Grøstl-256 output transformation, $\omega(x) = \mathrm{trunc}_{256}(P(x) + x)$.
+///
+/// When the input state is the concatenation of two 32-byte digests, this is the compression
+/// function of [`Groestl256ByteCompression`](binius_hash::Groestl256ByteCompression). Returns the
+/// columns of the 32-byte output.
+pub fn groestl_output_transformation(
+	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
 	log_size: usize,
-	round_consts: [OracleId; 8],
-	input: [OracleId; STATE_SIZE],
-) -> Result<[OracleId; STATE_SIZE]>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-{
+	state_in: [OracleId; STATE_SIZE],
+) -> Result<[OracleId; DIGEST_SIZE]> {
 	builder.push_namespace(name);
 
-	let p_sub_bytes_out: [OracleId; STATE_SIZE] = array_util::try_from_fn(|i| {
-		groestl_p_permutation_sbox(
-			builder,
-			format!("s_box[{i}]"),
+	let p_out = groestl_p_permutation(builder, "p_permutation", log_size, state_in)?;
+	let output = array_util::try_from_fn(|i| {
+		let b = STATE_SIZE - DIGEST_SIZE + i;
+		builder.add_linear_combination(
+			format!("output[{i}]"),
 			log_size,
-			if i % 8 == 0 {
-				round_consts[i / 8]
-			} else {
-				input[i]
-			},
+			[(p_out[b], F::ONE), (state_in[b], F::ONE)],
 		)
 	})?;
 
-	// Shift and mix bytes using committed columns
-	let output = builder.add_committed_multiple("output", log_size, BinaryField8b::TOWER_LEVEL);
-
 	if let Some(witness) = builder.witness() {
-		let p_sub_bytes_out =
-			p_sub_bytes_out.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?;
-		let mut output = output.map(|id| witness.new_column::<AESTowerField8b>(id));
-		let mut output = output
-			.iter_mut()
-			.map(|col| col.as_mut_slice::<AESTowerField8b>())
-			.collect::<Vec<_>>();
+		for (i, &id) in output.iter().enumerate() {
+			let b = STATE_SIZE - DIGEST_SIZE + i;
+			let p_out = witness.get::<B8>(p_out[b])?.as_slice::<B8>();
+			let state_in = witness.get::<B8>(state_in[b])?.as_slice::<B8>();
+			let mut output = witness.new_column::<B8>(id)?;
+			for (out, (&p, &x)) in output
+				.as_mut_slice::<B8>()
+				.iter_mut()
+				.zip(p_out.iter().zip(state_in))
+			{
+				*out = p + x;
+			}
+		}
+	}
 
-		let two = AESTowerField8b::new(2);
-		for z in 0..1 << log_size {
-			for j in 0..8 {
-				let a_j: [_; 8] = array::from_fn(|i| {
-					let shift_p = ((i + j) % 8) * 8 + i; // ShiftBytes & MixBytes
-					get_packed_slice(p_sub_bytes_out[shift_p].packed(), z)
-				});
-				for i in 0..8 {
-					let ij = j * 8 + i;
-					let a_i: [AESTowerField8b; 8] = array::from_fn(|k| a_j[(i + k) % 8]);
-					// Here we are using an optimized matrix multiplication, as documented in
-					// section 4.4.2 of https://www.groestl.info/groestl-implementation-guide.pdf
-					let b_ij = two
-						* (two * (a_i[3] + a_i[4] + a_i[6] + a_i[7])
-							+ a_i[0] + a_i[1] + a_i[2]
-							+ a_i[5] + a_i[7])
-						+ a_i[2] + a_i[4] + a_i[5]
-						+ a_i[6] + a_i[7];
-
-					output[ij][z] = b_ij;
-				}
+	builder.pop_namespace();
+	Ok(output)
+}
+
+fn p_permutation_round(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	round: usize,
+	state_in: [OracleId; STATE_SIZE],
+) -> Result<[OracleId; STATE_SIZE]> {
+	builder.push_namespace(name);
+
+	// AddRoundConstant: the constants are added to the first row of the state.
+	let sbox_in: [OracleId; STATE_SIZE] = array_util::try_from_fn(|b| {
+		if b % 8 == 0 {
+			builder.add_linear_combination_with_offset(
+				format!("add_round_const[{b}]"),
+				log_size,
+				round_const(b / 8, round).into(),
+				[(state_in[b], F::ONE)],
+			)
+		} else {
+			Ok(state_in[b])
+		}
+	})?;
+	if let Some(witness) = builder.witness() {
+		for b in (0..STATE_SIZE).step_by(8) {
+			let input = witness.get::<B8>(state_in[b])?.as_slice::<B8>();
+			let mut output = witness.new_column::<B8>(sbox_in[b])?;
+			let round_const = round_const(b / 8, round);
+			for (out, &x) in output.as_mut_slice::<B8>().iter_mut().zip(input) {
+				*out = x + round_const;
 			}
 		}
 	}
 
-	for ij in 0..STATE_SIZE {
-		let i = ij / 8;
-		let j = ij % 8;
+	// SubBytes
+	let sbox_out: [OracleId; STATE_SIZE] =
+		array_util::try_from_fn(|b| s_box(builder, format!("s_box[{b}]"), log_size, sbox_in[b]))?;
 
-		let mut mix_shift_oracles = [OracleId::default(); 9];
-		mix_shift_oracles[0] = output[ij];
-		for k in 0..8 {
-			let j_prime = (j + k) % 8;
-			let i_prime = (i + j_prime) % 8;
-			mix_shift_oracles[k + 1] = p_sub_bytes_out[i_prime * 8 + j_prime];
+	// ShiftBytes is a reindexing of the state: row i of the state is rotated left by i.
+	let shifted = |col: usize, row: usize| sbox_out[((col + row) % 8) * 8 + row];
+
+	// MixBytes
+	let mix_bytes = MIX_BYTES_VEC.map(|byte| B8::from(AESTowerField8b::new(byte)));
+	let state_out =
+		builder.add_committed_multiple::<STATE_SIZE>("mix_bytes", log_size, B8::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		let sbox_out = sbox_out
+			.iter()
+			.map(|&id| Ok(witness.get::<B8>(id)?.as_slice::<B8>()))
+			.collect::<Result<Vec<_>>>()?;
+		for (b, &id) in state_out.iter().enumerate() {
+			let (col, row) = (b / 8, b % 8);
+			let mut output = witness.new_column::<B8>(id)?;
+			for (z, out) in output.as_mut_slice::<B8>().iter_mut().enumerate() {
+				*out = (0..8)
+					.map(|i| sbox_out[((col + i) % 8) * 8 + i][z] * mix_bytes[(8 + i - row) % 8])
+					.sum();
+			}
 		}
-		// This is not required if the columns are virtual
+	}
+	for (b, &out) in state_out.iter().enumerate() {
+		let (col, row) = (b / 8, b % 8);
+		let mix_expr = (0..8)
+			.map(|i| {
+				ArithCircuit::var(i) * ArithCircuit::constant(F::from(mix_bytes[(8 + i - row) % 8]))
+			})
+			.sum::<ArithCircuit<F>>();
 		builder.assert_zero(
-			"mix_column_{ij}",
-			mix_shift_oracles,
-			mix_column_expr().convert_field(),
+			format!("mix_bytes[{b}]"),
+			(0..8).map(|i| shifted(col, i)).chain([out]),
+			mix_expr - ArithCircuit::var(8),
 		);
 	}
 
 	builder.pop_namespace();
-	Ok(output)
+	Ok(state_out)
 }
 
-fn groestl_p_permutation_sbox<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+/// Verifies the Rijndael S-box.
+///
+/// The S-box is the composition of inversion in the AES field with an $\mathbb{F}_2$-affine
+/// transformation. The inverse is committed in bit-decomposed form, so that the affine
+/// transformation, translated to the canonical tower basis, is a linear combination of its bits.
+fn s_box(
+	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
 	log_size: usize,
 	input: OracleId,
-) -> Result<OracleId, anyhow::Error>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-{
+) -> Result<OracleId> {
 	builder.push_namespace(name);
-	let inv_bits: [OracleId; 8] =
-		builder.add_committed_multiple("inv_bits", log_size, BinaryField1b::TOWER_LEVEL);
 
+	let inv_bits = builder.add_committed_multiple::<8>("inv_bits", log_size, B1::TOWER_LEVEL);
 	let inv = builder.add_linear_combination(
 		"inv",
 		log_size,
-		(0..8).map(|b| {
-			let basis = <AESTowerField8b as ExtensionField<BinaryField1b>>::basis(b)
-				.expect("index is less than extension degree");
-			(inv_bits[b], basis.into())
-		}),
+		inv_bits
+			.iter()
+			.enumerate()
+			.map(|(k, &bit)| (bit, <F as ExtensionField<B1>>::basis(k))),
 	)?;
-
 	let output = builder.add_linear_combination_with_offset(
 		"output",
 		log_size,
-		SBOX_VEC.into(),
-		(0..8).map(|b| (inv_bits[b], SBOX_MATRIX[b].into())),
+		S_BOX_TOWER_OFFSET.into(),
+		inv_bits
+			.iter()
+			.zip(S_BOX_TOWER_MATRIX_COLS)
+			.map(|(&bit, col)| (bit, col.into())),
 	)?;
 
 	if let Some(witness) = builder.witness() {
-		let input = witness
-			.get::<AESTowerField8b>(input)?
-			.as_slice::<AESTowerField8b>();
-
-		let mut inv_bits_witness: [_; 8] =
-			inv_bits.map(|id| witness.new_column::<BinaryField1b>(id));
-		let inv_bits = inv_bits_witness.each_mut().map(|bit| bit.packed());
-
-		let mut inv = witness.new_column::<AESTowerField8b>(inv);
-		let inv = inv.as_mut_slice::<AESTowerField8b>();
-
-		let mut output = witness.new_column::<AESTowerField8b>(output);
-		let output = output.as_mut_slice::<AESTowerField8b>();
-
-		for z in 0..(1 << log_size) {
-			inv[z] = input[z].invert_or_zero();
-			output[z] = s_box(input[z]);
-			let inv_bits_bases = ExtensionField::<BinaryField1b>::iter_bases(&inv[z]);
-			for (b, bit) in inv_bits_bases.enumerate() {
-				set_packed_slice(inv_bits[b], z, bit);
+		let input = witness.get::<B8>(input)?.as_slice::<B8>();
+		let mut inv_bits_witness =
+			array_util::try_map(inv_bits, |id| witness.new_column::<B1>(id))?;
+		let mut inv_witness = witness.new_column::<B8>(inv)?;
+		let mut output_witness = witness.new_column::<B8>(output)?;
+		let inv_out = inv_witness.as_mut_slice::<B8>();
+		let output_out = output_witness.as_mut_slice::<B8>();
+		for (z, &x) in input.iter().enumerate() {
+			let inv = x.invert_or_zero();
+			inv_out[z] = inv;
+			output_out[z] = S_BOX_TOWER_OFFSET;
+			for (k, bit) in ExtensionField::<B1>::iter_bases(&inv).enumerate() {
+				set_packed_slice(inv_bits_witness[k].packed(), z, bit);
+				if bit == B1::ONE {
+					output_out[z] += S_BOX_TOWER_MATRIX_COLS[k];
+				}
 			}
 		}
 	}
 
-	builder.assert_zero("s_box", [input, inv], s_box_expr()?);
+	// input * inv == 1 OR inv == 0
+	builder.assert_zero(
+		"inv_valid_or_inv_zero",
+		[input, inv],
+		arith_expr!([x, inv] = x * inv * inv - inv).convert_field(),
+	);
+	// input * inv == 1 OR input == 0
+	builder.assert_zero(
+		"inv_valid_or_input_zero",
+		[input, inv],
+		arith_expr!([x, inv] = x * x * inv - x).convert_field(),
+	);
+
 	builder.pop_namespace();
 	Ok(output)
 }
 
-// TODO: Get rid of round constants and bake them into the constraints
-fn permutation_round_consts<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
-	log_size: usize,
-	round_index: usize,
-	multiples_16: [OracleId; 8],
-	input: [OracleId; STATE_SIZE],
-) -> Result<[OracleId; 8], anyhow::Error>
-where
-	U: PackScalar<F> + PackScalar<BinaryField1b> + PackScalar<AESTowerField8b> + Pod,
-	F: TowerField + ExtensionField<AESTowerField8b>,
-{
-	let round = transparent::constant(
-		builder,
-		format!("round_index[{round_index}]"),
-		log_size,
-		AESTowerField8b::new(round_index as u8),
-	)?;
-
-	let round_consts: [OracleId; 8] = array_util::try_from_fn(|i| {
-		builder.add_linear_combination(
-			format!("round_consts[{i}]"),
-			log_size,
-			[
-				(input[8 * i], F::ONE),
-				(round, F::ONE),
-				(multiples_16[i], F::ONE),
-			],
-		)
-	})?;
-	if let Some(witness) = builder.witness() {
-		let mut round_consts_witness: [_; 8] =
-			round_consts.map(|id| witness.new_column::<AESTowerField8b>(id));
-		{
-			let input = input.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?;
-			let round = witness.get::<AESTowerField8b>(round)?;
-			let multiples_16 = multiples_16.try_map_ext(|id| witness.get::<AESTowerField8b>(id))?;
-
-			round_consts_witness
-				.each_mut()
-				.map(|col| col.packed())
-				.par_iter_mut()
-				.enumerate()
-				.for_each(|(i, round_consts)| {
-					(
-						&mut **round_consts,
-						input[8 * i].packed(),
-						round.packed(),
-						multiples_16[i].packed(),
-					)
-						.into_par_iter()
-						.for_each(|(round_const, input, round, multiple16)| {
-							*round_const = (*input) + (*round) + (*multiple16);
-						});
-				});
-		}
-	}
-	Ok(round_consts)
+/// The round constant added to the byte in the first row of column `col` of the P permutation.
+fn round_const(col: usize, round: usize) -> B8 {
+	B8::from(AESTowerField8b::new(((col * 0x10) ^ round) as u8))
 }
 
-/// Number of rounds in a Grøstl-256 compression
-const N_ROUNDS: usize = 10;
-
-const STATE_SIZE: usize = 64;
-
-/// Constant vector of the Rijndael S-box affine transformation.
-const SBOX_VEC: AESTowerField8b = AESTowerField8b::new(0x63);
-/// Matrix columns of the Rijndael S-box affine transformation.
-const SBOX_MATRIX: [AESTowerField8b; 8] = [
-	AESTowerField8b::new(0b00011111),
-	AESTowerField8b::new(0b00111110),
-	AESTowerField8b::new(0b01111100),
-	AESTowerField8b::new(0b11111000),
-	AESTowerField8b::new(0b11110001),
-	AESTowerField8b::new(0b11100011),
-	AESTowerField8b::new(0b11000111),
-	AESTowerField8b::new(0b10001111),
-];
-/// The first row of the circulant matrix defining the MixBytes step in Grøstl.
-const MIX_BYTES_VEC: [AESTowerField8b; 8] = [
-	AESTowerField8b::new(0x02),
-	AESTowerField8b::new(0x02),
-	AESTowerField8b::new(0x03),
-	AESTowerField8b::new(0x04),
-	AESTowerField8b::new(0x05),
-	AESTowerField8b::new(0x03),
-	AESTowerField8b::new(0x05),
-	AESTowerField8b::new(0x07),
-];
+#[cfg(test)]
+mod tests {
+	use binius_core::merkle_tree::{BinaryMerkleTree, build};
+	use binius_field::BinaryField32b;
+	use binius_hash::{
+		Groestl256, Groestl256ByteCompression, PseudoCompressionFunction,
+		groestl::{GroestlShortImpl, GroestlShortInternal},
+	};
+	use digest::Output;
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	fn to_b8(byte: u8) -> B8 {
+		B8::from(AESTowerField8b::new(byte))
+	}
 
-fn mix_column_expr() -> ArithExpr<AESTowerField8b> {
-	let output = ArithExpr::<AESTowerField8b>::var(0);
-	let mixed = MIX_BYTES_VEC
-		.into_iter()
-		.enumerate()
-		.map(|(i, coeff)| ArithExpr::Var(i + 1) * ArithExpr::Const(coeff))
-		.sum::<ArithExpr<_>>();
-	mixed - output
-}
+	fn committed_bytes<const N: usize>(
+		builder: &mut ConstraintSystemBuilder,
+		name: &str,
+		log_size: usize,
+		values: &[[u8; N]],
+	) -> [OracleId; N] {
+		let ids = builder.add_committed_multiple::<N>(name, log_size, B8::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			for (b, &id) in ids.iter().enumerate() {
+				let mut column = witness.new_column::<B8>(id).unwrap();
+				for (out, value) in column.as_mut_slice::<B8>().iter_mut().zip(values) {
+					*out = to_b8(value[b]);
+				}
+			}
+		}
+		ids
+	}
 
-fn s_box_expr<F: TowerField>() -> Result<ArithExpr<F>> {
-	let x = ArithExpr::Var(0);
-	let inv = ArithExpr::Var(1);
+	fn read_bytes<const N: usize>(
+		builder: &mut ConstraintSystemBuilder,
+		ids: &[OracleId; N],
+		z: usize,
+	) -> Result<[u8; N]> {
+		let witness = builder.witness().expect("witness is present");
+		array_util::try_from_fn(|b| {
+			let value = witness.get::<B8>(ids[b])?.as_slice::<B8>()[z];
+			Ok(AESTowerField8b::from(value).val())
+		})
+	}
 
-	// x * inv == 1
-	let non_zero_case = x.clone() * inv.clone() - ArithExpr::one();
+	#[test]
+	fn test_p_permutation() {
+		test_circuit(|builder| {
+			let log_size = 7;
+			let mut rng = StdRng::seed_from_u64(0);
+			let states = (0..1 << log_size)
+				.map(|_| array::from_fn::<u8, STATE_SIZE, _>(|_| rng.r#gen()))
+				.collect::<Vec<_>>();
+
+			let state_in = committed_bytes(builder, "state_in", log_size, &states);
+			let state_out = groestl_p_permutation(builder, "p", log_size, state_in)?;
+
+			if builder.witness().is_some() {
+				for (z, state) in states.iter().enumerate() {
+					let mut expected = GroestlShortImpl::state_from_bytes(state);
+					GroestlShortImpl::p_perm(&mut expected);
+					let expected = GroestlShortImpl::state_to_bytes(&expected);
+					assert_eq!(read_bytes(builder, &state_out, z)?, expected);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
 
-	// x == 0 AND inv == 0
-	// TODO: Implement `mul_primitive` expression for ArithExpr
-	let beta = <F as ExtensionField<BinaryField1b>>::basis(1 << 3)?;
-	let zero_case = x + inv * ArithExpr::Const(beta);
+	#[test]
+	fn test_output_transformation() {
+		test_circuit(|builder| {
+			let log_size = 7;
+			let mut rng = StdRng::seed_from_u64(0);
+			let states = (0..1 << log_size)
+				.map(|_| array::from_fn::<u8, STATE_SIZE, _>(|_| rng.r#gen()))
+				.collect::<Vec<_>>();
+
+			let state_in = committed_bytes(builder, "state_in", log_size, &states);
+			let output = groestl_output_transformation(builder, "omega", log_size, state_in)?;
+
+			if builder.witness().is_some() {
+				for (z, state) in states.iter().enumerate() {
+					let expected = Groestl256ByteCompression.compress([
+						Output::<Groestl256>::clone_from_slice(&state[..DIGEST_SIZE]),
+						Output::<Groestl256>::clone_from_slice(&state[DIGEST_SIZE..]),
+					]);
+					assert_eq!(read_bytes(builder, &output, z)?[..], expected[..]);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
 
-	// (x * inv == 1) OR (x == 0 AND inv == 0)
-	Ok(non_zero_case * zero_case)
-}
+	/// Verifies openings of a Merkle tree committed with `Groestl256ByteCompression`, one path per
+	/// row, starting from the leaf digests.
+	#[test]
+	fn test_merkle_path_verification() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_len = 3;
+		let batch_size = 4;
+		let elements = (0..batch_size << log_len)
+			.map(|_| <BinaryField32b as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let tree: BinaryMerkleTree<Output<Groestl256>> =
+			build::<_, Groestl256, _>(&Groestl256ByteCompression, &elements, batch_size).unwrap();
+		let leaf_digests = tree.layer(log_len).unwrap();
+		let root: [u8; DIGEST_SIZE] = tree.root().into();
+
+		let log_size = 7;
+		let indices = (0..1 << log_size)
+			.map(|_| rng.gen_range(0..1 << log_len))
+			.collect::<Vec<usize>>();
+		let branches = indices
+			.iter()
+			.map(|&index| tree.branch(index, 0).unwrap())
+			.collect::<Vec<_>>();
 
-fn s_box(x: AESTowerField8b) -> AESTowerField8b {
-	#[rustfmt::skip]
-	const S_BOX: [u8; 256] = [
-		0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5,
-		0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
-		0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0,
-		0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
-		0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc,
-		0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
-		0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a,
-		0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
-		0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0,
-		0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
-		0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b,
-		0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
-		0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85,
-		0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
-		0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5,
-		0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
-		0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17,
-		0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
-		0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88,
-		0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
-		0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c,
-		0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
-		0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9,
-		0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
-		0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6,
-		0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
-		0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e,
-		0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
-		0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94,
-		0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
-		0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68,
-		0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
-	];
-	let idx = u8::from(x) as usize;
-	AESTowerField8b::from(S_BOX[idx])
-}
+		test_circuit(|builder| {
+			let leaves = indices
+				.iter()
+				.map(|&index| leaf_digests[index].into())
+				.collect::<Vec<[u8; DIGEST_SIZE]>>();
+			let mut node = committed_bytes(builder, "leaf", log_size, &leaves);
+
+			for level in 0..log_len {
+				builder.push_namespace(format!("level[{level}]"));
+
+				let siblings = branches
+					.iter()
+					.map(|branch| branch[level].into())
+					.collect::<Vec<[u8; DIGEST_SIZE]>>();
+				let sibling = committed_bytes(builder, "sibling", log_size, &siblings);
+				let index_bit = builder.add_committed("index_bit", log_size, B1::TOWER_LEVEL);
+				let state = builder.add_committed_multiple::<STATE_SIZE>(
+					"state",
+					log_size,
+					B8::TOWER_LEVEL,
+				);
+
+				if let Some(witness) = builder.witness() {
+					let node = node
+						.iter()
+						.map(|&id| Ok(witness.get::<B8>(id)?.as_slice::<B8>()))
+						.collect::<Result<Vec<_>>>()?;
+					let sibling = sibling
+						.iter()
+						.map(|&id| Ok(witness.get::<B8>(id)?.as_slice::<B8>()))
+						.collect::<Result<Vec<_>>>()?;
+					let mut index_bit_witness = witness.new_column::<B1>(index_bit)?;
+					let mut state_witness =
+						array_util::try_map(state, |id| witness.new_column::<B8>(id))?;
+					for (z, &index) in indices.iter().enumerate() {
+						let is_right = (index >> level) & 1 == 1;
+						if is_right {
+							set_packed_slice(index_bit_witness.packed(), z, B1::ONE);
+						}
+						for b in 0..DIGEST_SIZE {
+							let (left, right) = if is_right {
+								(sibling[b][z], node[b][z])
+							} else {
+								(node[b][z], sibling[b][z])
+							};
+							state_witness[b].as_mut_slice::<B8>()[z] = left;
+							state_witness[DIGEST_SIZE + b].as_mut_slice::<B8>()[z] = right;
+						}
+					}
+				}
 
-#[cfg(test)]
-mod tests {
-	use binius_core::constraint_system::validate::validate_witness;
-	use binius_field::{arch::OptimalUnderlier, AESTowerField16b};
+				// The state is (node, sibling) if the index bit is 0 and (sibling, node)
+				// otherwise.
+				for b in 0..DIGEST_SIZE {
+					builder.assert_zero(
+						format!("left[{b}]"),
+						[index_bit, node[b], sibling[b], state[b]],
+						arith_expr!([s, d, sib, left] = left - d - s * (d + sib)).convert_field(),
+					);
+					builder.assert_zero(
+						format!("right[{b}]"),
+						[index_bit, node[b], sibling[b], state[DIGEST_SIZE + b]],
+						arith_expr!([s, d, sib, right] = right - sib - s * (d + sib))
+							.convert_field(),
+					);
+				}
 
-	use super::groestl_p_permutation;
-	use crate::builder::ConstraintSystemBuilder;
+				node = groestl_output_transformation(builder, "compress", log_size, state)?;
+				builder.pop_namespace();
+			}
 
-	#[test]
-	fn test_groestl() {
-		let allocator = bumpalo::Bump::new();
-		let mut builder =
-			ConstraintSystemBuilder::<OptimalUnderlier, AESTowerField16b>::new_with_witness(
-				&allocator,
-			);
-		let log_size = 9;
-		let _state_out = groestl_p_permutation(&mut builder, log_size).unwrap();
-
-		let witness = builder.take_witness().unwrap();
-		let constraint_system = builder.build().unwrap();
-		let boundaries = vec![];
-		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+			for (b, &id) in node.iter().enumerate() {
+				builder.assert_zero(
+					format!("root[{b}]"),
+					[id],
+					ArithCircuit::var(0) - ArithCircuit::constant(F::from(to_b8(root[b]))),
+				);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
 	}
 }
@@ -60,8 +60,9 @@ pub fn keccakf(
 
 	if let Some(witness) = builder.witness() {
 		let mut round_consts_single =
-			round_consts_single.map(|id| witness.new_column::<BinaryField1b>(id));
-		let mut round_consts = round_consts.map(|id| witness.new_column::<BinaryField1b>(id));
+			array_util::try_map(round_consts_single, |id| witness.new_column::<BinaryField1b>(id))?;
+		let mut round_consts =
+			array_util::try_map(round_consts, |id| witness.new_column::<BinaryField1b>(id))?;
 
 		let round_consts_single_u64 = round_consts_single
 			.each_mut()
@@ -217,34 +218,42 @@ pub fn keccakf(
 			.ok_or_else(|| anyhow!("builder witness available and input witness is not"))?
 			.as_ref();
 
-		let mut input = input.map(|id| witness.new_column::<BinaryField64b>(id));
+		let mut input = array_util::try_map(input, |id| witness.new_column::<BinaryField64b>(id))?;
 
 		let mut packed_state_in =
-			packed_state_in.map(|id| witness.new_column::<BinaryField64b>(id));
+			array_util::try_map(packed_state_in, |id| witness.new_column::<BinaryField64b>(id))?;
 
 		let mut packed_state_out =
-			packed_state_out.map(|id| witness.new_column::<BinaryField64b>(id));
+			array_util::try_map(packed_state_out, |id| witness.new_column::<BinaryField64b>(id))?;
 
-		let mut output = output.map(|id| witness.new_column::<BinaryField64b>(id));
+		let mut output =
+			array_util::try_map(output, |id| witness.new_column::<BinaryField64b>(id))?;
 
-		let mut state = state
-			.map(|round_oracles| round_oracles.map(|id| witness.new_column::<BinaryField1b>(id)));
+		let mut state = array_util::try_map(state, |round_oracles| {
+			array_util::try_map(round_oracles, |id| witness.new_column::<BinaryField1b>(id))
+		})?;
 
-		let mut c =
-			c.map(|round_oracles| round_oracles.map(|id| witness.new_column::<BinaryField1b>(id)));
-		let mut d =
-			d.map(|round_oracles| round_oracles.map(|id| witness.new_column::<BinaryField1b>(id)));
-		let mut c_shift = c_shift
-			.map(|round_oracles| round_oracles.map(|id| witness.new_column::<BinaryField1b>(id)));
-		let mut a_theta = a_theta
-			.map(|round_oracles| round_oracles.map(|id| witness.new_column::<BinaryField1b>(id)));
-		let mut b =
-			b.map(|round_oracles| round_oracles.map(|id| witness.new_column::<BinaryField1b>(id)));
-		let mut next_state_in = next_state_in.map(|id| witness.new_column::<BinaryField1b>(id));
+		let mut c = array_util::try_map(c, |round_oracles| {
+			array_util::try_map(round_oracles, |id| witness.new_column::<BinaryField1b>(id))
+		})?;
+		let mut d = array_util::try_map(d, |round_oracles| {
+			array_util::try_map(round_oracles, |id| witness.new_column::<BinaryField1b>(id))
+		})?;
+		let mut c_shift = array_util::try_map(c_shift, |round_oracles| {
+			array_util::try_map(round_oracles, |id| witness.new_column::<BinaryField1b>(id))
+		})?;
+		let mut a_theta = array_util::try_map(a_theta, |round_oracles| {
+			array_util::try_map(round_oracles, |id| witness.new_column::<BinaryField1b>(id))
+		})?;
+		let mut b = array_util::try_map(b, |round_oracles| {
+			array_util::try_map(round_oracles, |id| witness.new_column::<BinaryField1b>(id))
+		})?;
+		let mut next_state_in =
+			array_util::try_map(next_state_in, |id| witness.new_column::<BinaryField1b>(id))?;
 
-		let mut selector_single = witness.new_column::<BinaryField1b>(selector_single);
+		let mut selector_single = witness.new_column::<BinaryField1b>(selector_single)?;
 
-		let mut selector = witness.new_column::<BinaryField1b>(selector);
+		let mut selector = witness.new_column::<BinaryField1b>(selector)?;
 
 		let input_u64 = input.each_mut().map(|col| col.as_mut_slice::<u64>());
 
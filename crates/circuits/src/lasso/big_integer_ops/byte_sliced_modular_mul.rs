@@ -96,21 +96,21 @@ pub fn byte_sliced_modular_mul<LevelIn: TowerLevel, LevelOut: TowerLevel<Base =
 				let this_byte_oracle = quotient[this_byte_idx];
 				witness.new_column::<B8>(this_byte_oracle)
 			})
-			.collect();
+			.collect::<Result<_, anyhow::Error>>()?;
 
 		let mut remainder: Vec<_> = (0..LevelIn::WIDTH)
 			.map(|this_byte_idx| {
 				let this_byte_oracle = remainder[this_byte_idx];
 				witness.new_column::<B8>(this_byte_oracle)
 			})
-			.collect();
+			.collect::<Result<_, anyhow::Error>>()?;
 
 		let mut modulus: Vec<_> = (0..LevelIn::WIDTH)
 			.map(|this_byte_idx| {
 				let this_byte_oracle = modulus[this_byte_idx];
 				witness.new_column::<B8>(this_byte_oracle)
 			})
-			.collect();
+			.collect::<Result<_, anyhow::Error>>()?;
 
 		let mut modulus_u512 = U512::ZERO;
 
@@ -70,11 +70,13 @@ where
 		let c_in = builder.add_committed("c", log_size, BinaryField1b::TOWER_LEVEL);
 
 		if let Some(witness) = builder.witness() {
-			let mut x_in: [_; WIDTH] =
-				array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(x_in[byte_idx]));
-			let mut y_in: [_; WIDTH] =
-				array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(y_in[byte_idx]));
-			let mut c_in = witness.new_column::<BinaryField1b>(c_in);
+			let mut x_in: [_; WIDTH] = array::from_fn(|byte_idx| {
+				witness.new_column::<BinaryField8b>(x_in[byte_idx]).unwrap()
+			});
+			let mut y_in: [_; WIDTH] = array::from_fn(|byte_idx| {
+				witness.new_column::<BinaryField8b>(y_in[byte_idx]).unwrap()
+			});
+			let mut c_in = witness.new_column::<BinaryField1b>(c_in)?;
 
 			let x_in_bytes_u8: [_; WIDTH] = x_in.each_mut().map(|col| col.as_mut_slice::<u8>());
 			let y_in_bytes_u8: [_; WIDTH] = y_in.each_mut().map(|col| col.as_mut_slice::<u8>());
@@ -210,13 +212,19 @@ where
 			(random_u512(&mut StdRng::from_seed([42; 32])) % input_bitmask) + U512::from(1u8);
 
 		if let Some(witness) = builder.witness() {
-			let mut mult_a: [_; WIDTH] =
-				array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(mult_a[byte_idx]));
+			let mut mult_a: [_; WIDTH] = array::from_fn(|byte_idx| {
+				witness
+					.new_column::<BinaryField8b>(mult_a[byte_idx])
+					.unwrap()
+			});
 
 			let mult_a_u8 = mult_a.each_mut().map(|col| col.as_mut_slice::<u8>());
 
-			let mut mult_b: [_; WIDTH] =
-				array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(mult_b[byte_idx]));
+			let mut mult_b: [_; WIDTH] = array::from_fn(|byte_idx| {
+				witness
+					.new_column::<BinaryField8b>(mult_b[byte_idx])
+					.unwrap()
+			});
 
 			let mult_b_u8 = mult_b.each_mut().map(|col| col.as_mut_slice::<u8>());
 
@@ -87,7 +87,7 @@ where
 			Err(anyhow::Error::msg("u_log_rows and u_to_t_mappings must be of the same length"))?;
 		}
 
-		let mut lookup_f_witness = witness.new_column::<FC>(lookup_f);
+		let mut lookup_f_witness = witness.new_column::<FC>(lookup_f)?;
 
 		let lookup_f = lookup_f_witness.packed();
 
@@ -100,8 +100,8 @@ where
 		for (u_to_t_mapping, &n_lookups, &lookup_r, &lookup_w) in
 			izip!(u_to_t_mappings, n_lookups, &lookups_r, &lookups_w)
 		{
-			let mut lookup_r_witness = witness.new_column::<FC>(lookup_r);
-			let mut lookup_w_witness = witness.new_column::<FC>(lookup_w);
+			let mut lookup_r_witness = witness.new_column::<FC>(lookup_r)?;
+			let mut lookup_w_witness = witness.new_column::<FC>(lookup_w)?;
 
 			let lookup_r = lookup_r_witness.packed();
 			let lookup_w = lookup_w_witness.packed();
@@ -20,7 +20,7 @@ pub fn mul_lookup(
 	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_MUL, B32::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		let mut lookup_t = witness.new_column::<B32>(lookup_t)?;
 
 		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
 
@@ -47,7 +47,7 @@ pub fn add_lookup(
 	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		let mut lookup_t = witness.new_column::<B32>(lookup_t)?;
 
 		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
 
@@ -82,7 +82,7 @@ pub fn add_carryfree_lookup(
 	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		let mut lookup_t = witness.new_column::<B32>(lookup_t)?;
 
 		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
 
@@ -119,7 +119,7 @@ pub fn dci_lookup(
 	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_DCI, B32::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		let mut lookup_t = witness.new_column::<B32>(lookup_t)?;
 
 		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
 
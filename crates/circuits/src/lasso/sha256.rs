@@ -39,7 +39,7 @@ impl SeveralBitwise {
 			builder.add_committed("bitwise lookup_t", CH_MAJ_T_LOG_SIZE, B16::TOWER_LEVEL);
 
 		if let Some(witness) = builder.witness() {
-			let mut lookup_t_witness = witness.new_column::<B16>(lookup_t);
+			let mut lookup_t_witness = witness.new_column::<B16>(lookup_t)?;
 
 			let lookup_t = lookup_t_witness.packed();
 			for (i, lookup_t) in lookup_t.iter_mut().enumerate() {
@@ -94,12 +94,12 @@ impl SeveralBitwise {
 		)?;
 
 		if let Some(witness) = builder.witness() {
-			let mut lookup_u_witness = witness.new_column::<B16>(lookup_u);
+			let mut lookup_u_witness = witness.new_column::<B16>(lookup_u)?;
 			let lookup_u_u16 = lookup_u_witness.packed();
 
 			let mut u_to_t_mapping_witness = Vec::with_capacity(1 << (log_size - B4::TOWER_LEVEL));
 
-			let mut res_witness = witness.new_column::<B1>(res);
+			let mut res_witness = witness.new_column::<B1>(res)?;
 			let res_u32 = res_witness.as_mut_slice::<u32>();
 
 			let xin_u32 = witness.get::<B1>(xin)?.as_slice::<u32>();
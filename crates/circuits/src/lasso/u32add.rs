@@ -60,7 +60,7 @@ impl SeveralU32add {
 		let lookup_t = builder.add_committed("lookup_t", ADD_T_LOG_SIZE, B32::TOWER_LEVEL);
 
 		if let Some(witness) = builder.witness() {
-			let mut lookup_t_witness = witness.new_column::<B32>(lookup_t);
+			let mut lookup_t_witness = witness.new_column::<B32>(lookup_t)?;
 
 			let lookup_t = lookup_t_witness.packed();
 			for (i, lookup_t) in lookup_t.iter_mut().enumerate() {
@@ -139,10 +139,10 @@ impl SeveralU32add {
 		)?;
 
 		if let Some(witness) = builder.witness() {
-			let mut sum_witness = witness.new_column::<FOutput>(sum);
-			let mut cin_witness = witness.new_column::<B1>(cin);
-			let mut cout_witness = witness.new_column::<B1>(cout);
-			let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
+			let mut sum_witness = witness.new_column::<FOutput>(sum)?;
+			let mut cin_witness = witness.new_column::<B1>(cin)?;
+			let mut cout_witness = witness.new_column::<B1>(cout)?;
+			let mut lookup_u_witness = witness.new_column::<B32>(lookup_u)?;
 			let mut u_to_t_mapping_witness = vec![0; 1 << (b8_log_size)];
 
 			let x_ints = witness.get::<B8>(xin_u8)?.as_slice::<u8>();
@@ -43,9 +43,9 @@ pub fn u8add(
 	let mut u_to_t_mapping = vec![];
 
 	if let Some(witness) = builder.witness() {
-		let mut sum_witness = witness.new_column::<B8>(sum);
-		let mut carry_out_witness = witness.new_column::<B1>(carry_out);
-		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
+		let mut sum_witness = witness.new_column::<B8>(sum)?;
+		let mut carry_out_witness = witness.new_column::<B1>(carry_out)?;
+		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u)?;
 		let mut u_to_t_mapping_witness = vec![0; 1 << log_size];
 
 		let x_in_u8 = witness.get::<B8>(x_in)?.as_slice::<u8>();
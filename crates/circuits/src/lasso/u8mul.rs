@@ -38,9 +38,9 @@ pub fn u8mul_bytesliced(
 	let mut u_to_t_mapping = Vec::new();
 
 	if let Some(witness) = builder.witness() {
-		let mut product_low_witness = witness.new_column::<B8>(product[0]);
-		let mut product_high_witness = witness.new_column::<B8>(product[1]);
-		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
+		let mut product_low_witness = witness.new_column::<B8>(product[0])?;
+		let mut product_high_witness = witness.new_column::<B8>(product[1])?;
+		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u)?;
 		let mut u_to_t_mapping_witness = vec![0; 1 << log_rows];
 
 		let mult_a_ints = witness.get::<B8>(mult_a)?.as_slice::<u8>();
@@ -107,7 +107,7 @@ pub fn u8mul(
 		let product_low_witness = witness.get::<B8>(product_bytesliced[0])?;
 		let product_high_witness = witness.get::<B8>(product_bytesliced[1])?;
 
-		let mut product_witness = witness.new_column::<B16>(product);
+		let mut product_witness = witness.new_column::<B16>(product)?;
 
 		let product_low_u8 = product_low_witness.as_slice::<u8>();
 		let product_high_u8 = product_high_witness.as_slice::<u8>();
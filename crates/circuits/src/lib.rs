@@ -12,11 +12,16 @@ pub mod arithmetic;
 pub mod bitwise;
 pub mod blake3;
 pub mod builder;
+pub mod bytes;
 pub mod collatz;
+pub mod gadget;
+pub mod groestl;
 pub mod keccakf;
 pub mod lasso;
 mod pack;
 pub mod plain_lookup;
+pub mod range_check;
+pub mod recursion;
 pub mod sha256;
 pub mod transparent;
 pub mod u32fib;
@@ -27,34 +32,43 @@ pub mod vision;
 mod tests {
 	use binius_core::{
 		constraint_system::{
-			self,
+			self, CommitCheckpoint,
 			channel::{Boundary, FlushDirection, OracleOrConst, validate_witness},
 		},
 		fiat_shamir::HasherChallenger,
-		oracle::{OracleId, ShiftVariant},
+		oracle::{CommitBatching, OracleId, ShiftVariant},
 		polynomial::ArithCircuitPoly,
+		transcript::TranscriptCheckpoint,
 	};
 	use binius_field::{
-		BinaryField1b, BinaryField8b, BinaryField64b, BinaryField128b, Field, TowerField,
-		arch::OptimalUnderlier, as_packed_field::PackedType, tower::CanonicalTowerFamily,
-		underlier::WithUnderlier,
+		BinaryField1b, BinaryField8b, BinaryField64b, BinaryField128b, Field, PackedField,
+		TowerField, arch::OptimalUnderlier, as_packed_field::PackedType,
+		tower::CanonicalTowerFamily, underlier::WithUnderlier,
 	};
 	use binius_hal::make_portable_backend;
-	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+	use binius_hash::{
+		PseudoCompressionFunction,
+		blake3::{Blake3, Blake3DigestCompression},
+		groestl::{Groestl256, Groestl256ByteCompression},
+		keccak::{Keccak256, Keccak256ByteCompression},
+	};
 	use binius_macros::arith_expr;
-	use binius_math::CompositionPoly;
+	use binius_math::{CompositionPoly, MLEDirectAdapter, MultilinearExtension};
+	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+	use digest::{Digest, FixedOutputReset, Output, core_api::BlockSizeUser};
 	use rand::{seq::SliceRandom, thread_rng};
 
 	type B128 = BinaryField128b;
 	type B64 = BinaryField64b;
 
 	use crate::{
+		arithmetic,
 		builder::{
 			ConstraintSystemBuilder,
 			test_utils::test_circuit,
 			types::{F, U},
 		},
-		unconstrained::unconstrained,
+		unconstrained::{fixed_u32, unconstrained},
 	};
 
 	#[test]
@@ -98,19 +112,19 @@ mod tests {
 		if let Some(witness) = builder.witness() {
 			let mut current = 6;
 
-			let mut even = witness.new_column::<BinaryField8b>(even);
+			let mut even = witness.new_column::<BinaryField8b>(even).unwrap();
 
 			let even_u8 = even.as_mut_slice::<u8>();
 
-			let mut half = witness.new_column::<BinaryField8b>(half);
+			let mut half = witness.new_column::<BinaryField8b>(half).unwrap();
 
 			let half_u8 = half.as_mut_slice::<u8>();
 
-			let mut odd = witness.new_column::<BinaryField8b>(odd);
+			let mut odd = witness.new_column::<BinaryField8b>(odd).unwrap();
 
 			let odd_u8 = odd.as_mut_slice::<u8>();
 
-			let mut output = witness.new_column::<BinaryField8b>(output);
+			let mut output = witness.new_column::<BinaryField8b>(output).unwrap();
 
 			let output_u8 = output.as_mut_slice::<u8>();
 
@@ -157,17 +171,664 @@ mod tests {
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
 			_,
-		>(&constraint_system, 1, 10, &boundaries, witness, &backend)
+		>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&boundaries,
+			witness,
+			&backend,
+		)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, constraint_system::SecurityLevel::new(10, 1), &boundaries, proof)
+		.unwrap();
+	}
+
+	fn test_prove_verify_u32_add_with_hash<Hash, Compress>()
+	where
+		Hash: Digest + BlockSizeUser + FixedOutputReset + Default + Send + Sync + Clone,
+		Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	{
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 10;
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		arithmetic::u32::add(&mut builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			Hash,
+			Compress,
+			HasherChallenger<Hash>,
+			_,
+		>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&[],
+			witness,
+			&backend,
+		)
+		.unwrap();
+
+		constraint_system::verify::<U, CanonicalTowerFamily, Hash, Compress, HasherChallenger<Hash>>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&[],
+			proof,
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_prove_verify_with_blake3_merkle_tree() {
+		test_prove_verify_u32_add_with_hash::<Blake3, Blake3DigestCompression>();
+	}
+
+	#[test]
+	fn test_prove_verify_with_keccak256_transcript() {
+		test_prove_verify_u32_add_with_hash::<Keccak256, Keccak256ByteCompression>();
+	}
+
+	fn prove_u32_add() -> Result<
+		(constraint_system::ConstraintSystem<F>, constraint_system::Proof),
+		constraint_system::error::Error,
+	> {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 10;
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		arithmetic::u32::add(&mut builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&[],
+			witness,
+			&backend,
+		)?;
+		Ok((constraint_system, proof))
+	}
+
+	#[test]
+	fn test_batch_verify() {
+		let (constraint_system, proof_1) = prove_u32_add().unwrap();
+		let (_, proof_2) = prove_u32_add().unwrap();
+
+		constraint_system::batch_verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&[vec![], vec![]],
+			vec![proof_1, proof_2],
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_prepared_verifier() {
+		let (constraint_system, proof_1) = prove_u32_add().unwrap();
+		let (_, proof_2) = prove_u32_add().unwrap();
+		let (_, mut proof_3) = prove_u32_add().unwrap();
+		let last = proof_3.transcript.len() - 1;
+		proof_3.transcript[last] ^= 1;
+
+		let verifier = constraint_system::PreparedVerifier::<
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+		>::new(&constraint_system, constraint_system::SecurityLevel::new(10, 1))
+		.unwrap();
+		for proof in [proof_1, proof_2] {
+			verifier
+				.verify::<U, HasherChallenger<Groestl256>>(&[], proof)
+				.unwrap();
+		}
+		assert!(
+			verifier
+				.verify::<U, HasherChallenger<Groestl256>>(&[], proof_3)
+				.is_err()
+		);
+	}
+
+	#[test]
+	fn test_batch_verify_reports_invalid_proof() {
+		let (constraint_system, proof_1) = prove_u32_add().unwrap();
+		let (_, mut proof_2) = prove_u32_add().unwrap();
+		let last = proof_2.transcript.len() - 1;
+		proof_2.transcript[last] ^= 1;
+
+		let result = constraint_system::batch_verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&[vec![], vec![]],
+			vec![proof_1, proof_2],
+		);
+		assert!(matches!(
+			result,
+			Err(constraint_system::error::Error::BatchProofInvalid { index: 1, .. })
+		));
+	}
+
+	#[test]
+	fn test_verify_reports_failed_stage() {
+		let (constraint_system, mut proof) = prove_u32_add().unwrap();
+		let last = proof.transcript.len() - 1;
+		proof.transcript[last] ^= 1;
+
+		let err = constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, constraint_system::SecurityLevel::new(10, 1), &[], proof)
+		.unwrap_err();
+		assert_eq!(err.stage(), Some(constraint_system::error::ProofStage::PcsOpening));
+		assert!(matches!(err.root(), constraint_system::error::Error::PIOPCompilerError(_)));
+	}
+
+	#[test]
+	fn test_prove_in_thread_pool() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 10;
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		arithmetic::u32::add(&mut builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let backend = make_portable_backend();
+		let thread_pool = binius_maybe_rayon::ThreadPoolBuilder::new()
+			.num_threads(2)
+			.build()
+			.unwrap();
+
+		let proof = constraint_system::prove_in_thread_pool::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&[],
+			witness,
+			&backend,
+			&thread_pool,
+		)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, constraint_system::SecurityLevel::new(10, 1), &[], proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_prove_multiple_commit_phases() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 10;
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		assert!(
+			builder
+				.add_commit_phase_challenge("first phase gamma", log_size)
+				.is_err()
+		);
+		assert_eq!(builder.start_commit_phase(), 1);
+		arithmetic::u32::add(&mut builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+
+		// A column committed after the challenge is sampled, which must be equal to it
+		let gamma = builder
+			.add_commit_phase_challenge("gamma", log_size)
+			.unwrap();
+		let c = builder.add_committed("c", log_size, B128::TOWER_LEVEL);
+		builder.assert_zero(
+			"c = gamma",
+			[c, gamma],
+			arith_expr!([c, gamma] = c - gamma).convert_field(),
+		);
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+		assert_eq!(constraint_system.oracles.n_commit_phases(), 2);
+
+		let backend = make_portable_backend();
+		let security = constraint_system::SecurityLevel::new(10, 1);
+		let proof = constraint_system::prove_with_commit_phases::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			security,
+			&[],
+			witness,
+			&backend,
+			|phase, challenge, witness| {
+				assert_eq!(phase, 1);
+				let log_width = PackedType::<U, B128>::LOG_WIDTH;
+				let values =
+					vec![PackedType::<U, B128>::broadcast(challenge); 1 << (log_size - log_width)];
+				let poly = MultilinearExtension::new(log_size, values).unwrap();
+				witness
+					.update_multilin_poly([(c, MLEDirectAdapter::from(poly).upcast_arc_dyn())])?;
+				Ok(())
+			},
+		)
+		.unwrap();
+
+		let estimate = constraint_system::estimate_proof_size::<
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+		>(&constraint_system, security)
+		.unwrap();
+		assert_eq!(estimate.commitment, 2 * 32);
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, security, &[], proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_prove_commit_batches_per_tower_level() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		builder.set_commit_batching(CommitBatching::PerTowerLevel);
+
+		let log_size = 10;
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		arithmetic::u32::add(&mut builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+		unconstrained::<BinaryField8b>(&mut builder, "bytes", log_size).unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+		assert_eq!(constraint_system.oracles.commit_batches().len(), 2);
+
+		let backend = make_portable_backend();
+		let security = constraint_system::SecurityLevel::new(10, 1);
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, security, &[], witness, &backend)
+		.unwrap();
+
+		let estimate = constraint_system::estimate_proof_size::<
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+		>(&constraint_system, security)
+		.unwrap();
+		assert_eq!(estimate.commitment, 2 * 32);
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, security, &[], proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_prove_with_checkpoints() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		builder.set_commit_batching(CommitBatching::PerTowerLevel);
+
+		let log_size = 10;
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		arithmetic::u32::add(&mut builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+		unconstrained::<BinaryField8b>(&mut builder, "bytes", log_size).unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let backend = make_portable_backend();
+		let security = constraint_system::SecurityLevel::new(10, 1);
+		let mut checkpoints = Vec::new();
+		let proof = constraint_system::prove_with_checkpoints::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, security, &[], witness, &backend, |checkpoint| {
+			checkpoints.push(checkpoint);
+			Ok(())
+		})
 		.unwrap();
 
+		// Every checkpoint is taken after the commitment of its batch is written to the tape.
+		assert_eq!(checkpoints.len(), 2);
+		for (i, checkpoint) in checkpoints.iter().enumerate() {
+			assert_eq!(checkpoint.batch, i);
+			let tape = checkpoint.transcript.tape();
+			assert!(proof.transcript.starts_with(tape));
+		}
+		assert_eq!(
+			checkpoints[1].transcript.tape().len(),
+			checkpoints[0].transcript.tape().len() + 32
+		);
+
 		constraint_system::verify::<
 			U,
 			CanonicalTowerFamily,
 			Groestl256,
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
-		>(&constraint_system, 1, 10, &boundaries, proof)
+		>(&constraint_system, security, &[], proof)
+		.unwrap();
+	}
+
+	/// A circuit with two commit batches and a witness that is the same on every build.
+	fn build_two_batch_circuit(builder: &mut ConstraintSystemBuilder) {
+		builder.set_commit_batching(CommitBatching::PerTowerLevel);
+
+		let log_size = 10;
+		let values = |seed: u32| {
+			(0..1 << log_size)
+				.map(|i: u32| i.wrapping_mul(0x9e3779b9) ^ seed)
+				.collect::<Vec<_>>()
+		};
+		let a = fixed_u32::<BinaryField1b>(builder, "a", log_size, values(1)).unwrap();
+		let b = fixed_u32::<BinaryField1b>(builder, "b", log_size, values(2)).unwrap();
+		arithmetic::u32::add(builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+		fixed_u32::<BinaryField8b>(builder, "bytes", log_size, values(3)).unwrap();
+	}
+
+	#[test]
+	fn test_prove_resumes_from_checkpoint() {
+		type Error = constraint_system::error::Error;
+
+		let backend = make_portable_backend();
+		let security = constraint_system::SecurityLevel::new(10, 1);
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		build_two_batch_circuit(&mut builder);
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, security, &[], witness, &backend)
+		.unwrap();
+
+		for stop_batch in 0..2 {
+			// Interrupt the prover after the commitment of `stop_batch`, keeping its checkpoint in
+			// serialized form.
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+			build_two_batch_circuit(&mut builder);
+			let witness = builder
+				.take_witness()
+				.expect("builder created with witness");
+			let mut stored = None;
+			let result = constraint_system::prove_with_checkpoints::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+				_,
+			>(&constraint_system, security, &[], witness, &backend, |checkpoint| {
+				if checkpoint.batch < stop_batch {
+					return Ok(());
+				}
+				let mut bytes = Vec::new();
+				checkpoint
+					.transcript
+					.serialize(&mut bytes, SerializationMode::CanonicalTower)
+					.unwrap();
+				stored = Some((checkpoint.batch, bytes));
+				Err(Error::PolyCommitError("prover interrupted".into()))
+			});
+			assert!(matches!(result, Err(Error::PolyCommitError(_))));
+
+			let (batch, bytes) = stored.expect("prover stopped at a checkpoint");
+			let checkpoint = CommitCheckpoint {
+				batch,
+				transcript: TranscriptCheckpoint::deserialize(
+					bytes.as_slice(),
+					SerializationMode::CanonicalTower,
+				)
+				.unwrap(),
+			};
+
+			// Resume from a fresh build of the witness.
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+			build_two_batch_circuit(&mut builder);
+			let witness = builder
+				.take_witness()
+				.expect("builder created with witness");
+			let mut resumed_batches = Vec::new();
+			let resumed_proof = constraint_system::prove_from_checkpoint::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+				_,
+			>(
+				&constraint_system,
+				security,
+				&[],
+				witness,
+				&backend,
+				&checkpoint,
+				|checkpoint| {
+					resumed_batches.push(checkpoint.batch);
+					Ok(())
+				},
+			)
+			.unwrap();
+
+			assert_eq!(resumed_batches, (stop_batch + 1..2).collect::<Vec<_>>());
+			assert_eq!(resumed_proof, proof);
+		}
+	}
+
+	#[test]
+	fn test_prove_from_checkpoint_rejects_other_witness() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		build_two_batch_circuit(&mut builder);
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let backend = make_portable_backend();
+		let security = constraint_system::SecurityLevel::new(10, 1);
+		let mut checkpoints = Vec::new();
+		constraint_system::prove_with_checkpoints::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, security, &[], witness, &backend, |checkpoint| {
+			checkpoints.push(checkpoint);
+			Ok(())
+		})
+		.unwrap();
+
+		// The same circuit with random values commits to a different first batch.
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		builder.set_commit_batching(CommitBatching::PerTowerLevel);
+		let log_size = 10;
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		arithmetic::u32::add(&mut builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+		unconstrained::<BinaryField8b>(&mut builder, "bytes", log_size).unwrap();
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+
+		let result =
+			constraint_system::prove_from_checkpoint::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+				_,
+			>(&constraint_system, security, &[], witness, &backend, &checkpoints[0], |_| Ok(()));
+		assert!(matches!(
+			result,
+			Err(constraint_system::error::Error::CheckpointMismatch { batch: 0 })
+		));
+	}
+
+	#[test]
+	fn test_proof_bound_to_application_context() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 10;
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		arithmetic::u32::add(&mut builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let backend = make_portable_backend();
+		let security = constraint_system::SecurityLevel::new(10, 1);
+		let block_hash = [0x42; 32];
+
+		let proof = constraint_system::prove_with_application_context::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, security, &[], &block_hash, witness, &backend)
 		.unwrap();
+
+		let verify = |application_context: &[u8]| {
+			constraint_system::verify_with_application_context::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(&constraint_system, security, &[], application_context, proof.clone())
+		};
+		verify(&block_hash).unwrap();
+		assert!(verify(&[0x43; 32]).is_err());
+		assert!(verify(&[]).is_err());
+
+		let batch_verify = |application_contexts: &[&[u8]]| {
+			constraint_system::batch_verify_with_application_contexts::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(&constraint_system, security, &[vec![]], application_contexts, vec![proof.clone()])
+		};
+		batch_verify(&[&block_hash]).unwrap();
+		assert!(matches!(
+			batch_verify(&[&[]]),
+			Err(constraint_system::error::Error::BatchProofInvalid { index: 0, .. })
+		));
+		assert!(matches!(
+			batch_verify(&[]),
+			Err(constraint_system::error::Error::BatchApplicationContextsMismatch { .. })
+		));
 	}
 
 	#[test]
@@ -343,6 +1004,7 @@ mod tests {
 				.witness()
 				.unwrap()
 				.new_column::<B128>(oracle_id)
+				.unwrap()
 				.as_mut_slice()
 				.copy_from_slice(values);
 		};
@@ -358,6 +1020,7 @@ mod tests {
 			.witness()
 			.unwrap()
 			.new_column::<B64>(column_z)
+			.unwrap()
 			.as_mut_slice()
 			.copy_from_slice(&values_z);
 
@@ -373,7 +1036,13 @@ mod tests {
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
 			_,
-		>(&constraint_system, log_inv_rate, security_bits, &[], witness, &backend)
+		>(
+			&constraint_system,
+			binius_core::constraint_system::SecurityLevel::new(security_bits, log_inv_rate),
+			&[],
+			witness,
+			&backend,
+		)
 		.unwrap();
 
 		binius_core::constraint_system::verify::<
@@ -382,7 +1051,12 @@ mod tests {
 			Groestl256,
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
-		>(&constraint_system, log_inv_rate, security_bits, &[], proof)
+		>(
+			&constraint_system,
+			binius_core::constraint_system::SecurityLevel::new(security_bits, log_inv_rate),
+			&[],
+			proof,
+		)
 		.unwrap();
 	}
 
@@ -70,6 +70,81 @@ pub fn plain_lookup<FTable, const LOG_MAX_MULTIPLICITY: usize>(
 	lookup_t: impl AsRef<[OracleId]>,
 	multiplicities: Option<impl AsRef<[usize]>>,
 ) -> Result<()>
+where
+	U: PackScalar<FTable> + Pod,
+	F: ExtensionField<FTable>,
+	FTable: TowerField,
+{
+	plain_lookup_impl::<FTable>(
+		builder,
+		name,
+		LOG_MAX_MULTIPLICITY,
+		|builder, t_log_rows| {
+			builder
+				.add_committed_multiple::<LOG_MAX_MULTIPLICITY>(
+					"multiplicity_bits",
+					t_log_rows,
+					BinaryField1b::TOWER_LEVEL,
+				)
+				.to_vec()
+		},
+		n_lookups,
+		lookups_u,
+		lookup_t,
+		multiplicities,
+	)
+}
+
+/// Same as [`plain_lookup`], with the number of multiplicity bits chosen at runtime.
+pub(crate) fn plain_lookup_with_log_max_multiplicity<FTable>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_max_multiplicity: usize,
+	n_lookups: &[usize],
+	lookups_u: &[impl AsRef<[OracleId]>],
+	lookup_t: impl AsRef<[OracleId]>,
+	multiplicities: Option<impl AsRef<[usize]>>,
+) -> Result<()>
+where
+	U: PackScalar<FTable> + Pod,
+	F: ExtensionField<FTable>,
+	FTable: TowerField,
+{
+	plain_lookup_impl::<FTable>(
+		builder,
+		name,
+		log_max_multiplicity,
+		|builder, t_log_rows| {
+			(0..log_max_multiplicity)
+				.map(|i| {
+					builder.add_committed(
+						format!("multiplicity_bits_{i}"),
+						t_log_rows,
+						BinaryField1b::TOWER_LEVEL,
+					)
+				})
+				.collect()
+		},
+		n_lookups,
+		lookups_u,
+		lookup_t,
+		multiplicities,
+	)
+}
+
+/// Adds the lookup, with the `log_max_multiplicity` multiplicity bit columns committed by
+/// `add_multiplicity_bits` inside the lookup namespace.
+#[allow(clippy::too_many_arguments)]
+fn plain_lookup_impl<FTable>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_max_multiplicity: usize,
+	add_multiplicity_bits: impl FnOnce(&mut ConstraintSystemBuilder, usize) -> Vec<OracleId>,
+	n_lookups: &[usize],
+	lookups_u: &[impl AsRef<[OracleId]>],
+	lookup_t: impl AsRef<[OracleId]>,
+	multiplicities: Option<impl AsRef<[usize]>>,
+) -> Result<()>
 where
 	U: PackScalar<FTable> + Pod,
 	F: ExtensionField<FTable>,
@@ -84,16 +159,12 @@ where
 	);
 
 	let lookups_u_count_sum = n_lookups.iter().sum::<usize>();
-	ensure!(lookups_u_count_sum < 1 << LOG_MAX_MULTIPLICITY, "LOG_MAX_MULTIPLICITY too small");
+	ensure!(lookups_u_count_sum < 1 << log_max_multiplicity, "LOG_MAX_MULTIPLICITY too small");
 
 	builder.push_namespace(name);
 
 	let t_log_rows = builder.log_rows(lookup_t.as_ref().iter().copied())?;
-	let bits = builder.add_committed_multiple::<LOG_MAX_MULTIPLICITY>(
-		"multiplicity_bits",
-		t_log_rows,
-		BinaryField1b::TOWER_LEVEL,
-	);
+	let bits = add_multiplicity_bits(builder, t_log_rows);
 
 	let permuted_lookup_t = (0..lookup_t.as_ref().len())
 		.map(|i| builder.add_committed(format!("permuted_t_{i}"), t_log_rows, FTable::TOWER_LEVEL))
@@ -116,14 +187,14 @@ where
 
 		indexed_multiplicities.sort_by_key(|&(_, multiplicity)| Reverse(multiplicity));
 
-		for (i, bit) in bits.into_iter().enumerate() {
+		for (i, &bit) in bits.iter().enumerate() {
 			let nonzero_scalars_prefix =
 				indexed_multiplicities.partition_point(|&(_, count)| count >= 1 << i);
 
 			let mut column = witness.new_column_with_nonzero_scalars_prefix::<BinaryField1b>(
 				bit,
 				nonzero_scalars_prefix,
-			);
+			)?;
 
 			let packed = column.packed();
 
@@ -137,7 +208,7 @@ where
 		for (&permuted, &original) in izip!(&permuted_lookup_t, lookup_t.as_ref()) {
 			let original_slice = witness.get::<FTable>(original)?.packed();
 
-			let mut permuted_column = witness.new_column::<FTable>(permuted);
+			let mut permuted_column = witness.new_column::<FTable>(permuted)?;
 			let permuted_slice = permuted_column.packed();
 
 			let mut iterator = indexed_multiplicities
@@ -237,7 +308,7 @@ pub mod test_plain_lookup {
 		let lookup_values_count = 1 << log_lookup_count;
 
 		let multiplicities = if let Some(witness) = builder.witness() {
-			let mut lookup_values_col = witness.new_column::<BinaryField32b>(lookup_values);
+			let mut lookup_values_col = witness.new_column::<BinaryField32b>(lookup_values)?;
 			let mut_slice = lookup_values_col.as_mut_slice::<u32>();
 			generate_random_u8_mul_claims(&mut mut_slice[0..lookup_values_count]);
 			Some(count_multiplicities(&table_values, mut_slice, true).unwrap())
@@ -387,7 +458,13 @@ mod tests {
 				Groestl256ByteCompression,
 				HasherChallenger<Groestl256>,
 				_,
-			>(&constraint_system, log_inv_rate, security_bits, &[], witness, &backend)
+			>(
+				&constraint_system,
+				binius_core::constraint_system::SecurityLevel::new(security_bits, log_inv_rate),
+				&[],
+				witness,
+				&backend,
+			)
 			.unwrap()
 		};
 
@@ -409,7 +486,12 @@ mod tests {
 				Groestl256,
 				Groestl256ByteCompression,
 				HasherChallenger<Groestl256>,
-			>(&constraint_system, log_inv_rate, security_bits, &[], proof)
+			>(
+				&constraint_system,
+				binius_core::constraint_system::SecurityLevel::new(security_bits, log_inv_rate),
+				&[],
+				proof,
+			)
 			.unwrap();
 		}
 	}
@@ -0,0 +1,107 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::OracleId;
+use binius_field::{ExtensionField, TowerField, as_packed_field::PackScalar};
+use bytemuck::Pod;
+
+use crate::builder::{
+	ConstraintSystemBuilder,
+	types::{F, U},
+};
+
+/// Constrains every value of `input` to be less than `2^bits` as an unsigned integer.
+///
+/// The integer value of a tower field element is its bit representation in the canonical basis
+/// over [`binius_field::BinaryField1b`], so a `u8`, `u16` or `u32` value stored in a column of the
+/// matching field is range-checked by calling this with `bits` set to 8, 16 or 32 respectively, or
+/// with any smaller bound.
+///
+/// The column is decomposed into `bits` committed 1-bit columns with
+/// [`ConstraintSystemBuilder::decompose_to_bits`], which constrains the recomposition of the bits
+/// to equal the input. Returns the bit columns, least significant bit first, so that callers can
+/// reuse the decomposition.
+pub fn range_check<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	bits: usize,
+) -> Result<Vec<OracleId>, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: ExtensionField<FS>,
+	FS: TowerField,
+{
+	builder.decompose_to_bits::<FS>(name, input, bits)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField16b, BinaryField32b};
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	#[test]
+	fn test_range_check_u16_in_b32() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let input = builder.add_committed("input", log_size, BinaryField32b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut rng = StdRng::seed_from_u64(0);
+				for value in witness
+					.new_column::<BinaryField32b>(input)?
+					.as_mut_slice::<u32>()
+				{
+					*value = rng.gen_range(0..1 << 16);
+				}
+			}
+			let bits = range_check::<BinaryField32b>(builder, "range_check", input, 16)?;
+			assert_eq!(bits.len(), 16);
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_range_check_full_width() {
+		test_circuit(|builder| {
+			let log_size = 10;
+			let input = builder.add_committed("input", log_size, BinaryField16b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut rng = StdRng::seed_from_u64(0);
+				for value in witness
+					.new_column::<BinaryField16b>(input)?
+					.as_mut_slice::<u16>()
+				{
+					*value = rng.r#gen();
+				}
+			}
+			range_check::<BinaryField16b>(builder, "range_check", input, 16)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_range_check_rejects_out_of_range_witness() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let input = builder.add_committed("input", 10, BinaryField16b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			witness
+				.new_column::<BinaryField16b>(input)
+				.unwrap()
+				.as_mut_slice::<u16>()
+				.fill(256);
+		}
+		assert!(range_check::<BinaryField16b>(&mut builder, "range_check", input, 8).is_err());
+	}
+
+	#[test]
+	fn test_range_check_rejects_too_many_bits() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let input = builder.add_committed("input", 10, BinaryField16b::TOWER_LEVEL);
+		assert!(range_check::<BinaryField16b>(&mut builder, "range_check", input, 17).is_err());
+	}
+}
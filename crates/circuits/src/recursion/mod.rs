@@ -0,0 +1,19 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Building blocks for verifying Binius proofs inside a constraint system.
+//!
+//! Recursion works by expressing the checks of a verifier as constraints, so that a proof of the
+//! outer constraint system attests to the validity of the inner proofs. Each gadget expresses the
+//! algebraic checks of a verifier in `binius_core` and takes the prover messages as columns of the
+//! outer constraint system, checking one inner proof per row.
+//!
+//! The Fiat-Shamir challenges are not inputs of the gadgets: they are derived in-circuit by a
+//! [`Transcript`](transcript::Transcript) that observes the prover messages with the [`vision`]
+//! permutation gadget, so that a prover cannot choose them. The in-circuit transcript is not
+//! the byte-oriented [`HasherChallenger`](binius_core::fiat_shamir::HasherChallenger) of the
+//! native verifier, so the inner proofs must be produced with a matching transcript.
+//!
+//! [`vision`]: crate::vision
+
+pub mod sumcheck;
+pub mod transcript;
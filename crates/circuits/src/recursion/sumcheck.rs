@@ -0,0 +1,675 @@
+// Copyright 2025 Irreducible Inc.
+
+//! In-circuit verification of the batched sumcheck protocol.
+//!
+//! The gadget follows [`batch_verify`](binius_core::protocols::sumcheck::batch_verify) round by
+//! round, deriving the batching coefficients and challenges from an in-circuit [`Transcript`]:
+//! claims are mixed into the running sum with their batching coefficient when the round reaches
+//! their number of variables, the last coefficient of each round polynomial is recovered from the
+//! running sum, and the round polynomial evaluated at the round challenge becomes the sum for the
+//! next round. The final sum is checked against the batched compositions of the
+//! claimed multilinear evaluations.
+
+use anyhow::ensure;
+use binius_core::oracle::OracleId;
+use binius_field::TowerField;
+use binius_math::ArithCircuit;
+use binius_maybe_rayon::prelude::*;
+use itertools::izip;
+
+use super::transcript::Transcript;
+use crate::builder::{ConstraintSystemBuilder, types::F};
+
+/// A composite sum claim whose claimed sum is given by a column.
+#[derive(Debug, Clone)]
+pub struct CompositeSumClaim {
+	/// The composition of the multilinears of the claim.
+	pub composition: ArithCircuit<F>,
+	/// The column of claimed sums over the hypercube.
+	pub sum: OracleId,
+}
+
+/// A sumcheck claim over a group of multilinears.
+///
+/// This is the in-circuit counterpart of
+/// [`SumcheckClaim`](binius_core::protocols::sumcheck::SumcheckClaim).
+#[derive(Debug, Clone)]
+pub struct SumcheckClaim {
+	pub n_vars: usize,
+	pub n_multilinears: usize,
+	pub composite_sums: Vec<CompositeSumClaim>,
+}
+
+impl SumcheckClaim {
+	fn max_individual_degree(&self) -> usize {
+		self.composite_sums
+			.iter()
+			.map(|composite_sum| composite_sum.composition.degree())
+			.max()
+			.unwrap_or(0)
+	}
+}
+
+/// The prover messages of a batched sumcheck, in the order the native verifier reads them from
+/// the transcript.
+#[derive(Debug, Clone)]
+pub struct SumcheckProof {
+	/// The columns of the truncated round polynomial coefficients of every round, as in
+	/// [`RoundProof`](binius_core::protocols::sumcheck::RoundProof).
+	pub round_proofs: Vec<Vec<OracleId>>,
+	/// The columns of the claimed multilinear evaluations of every claim.
+	pub multilinear_evals: Vec<Vec<OracleId>>,
+}
+
+/// Verifies a batched sumcheck in every row.
+///
+/// The claims must be sorted by descending number of variables. All columns must be columns of
+/// `F` elements with `2^transcript.log_rows()` rows.
+///
+/// The transcript first observes the claimed sums, since they are prover-provided columns here
+/// rather than values the verifier already trusts. The rest follows the native verifier: the
+/// batching coefficient of a claim is sampled when the round reaches its number of variables,
+/// every round proof is observed before its challenge is sampled, and the multilinear evaluations
+/// are observed last.
+///
+/// Returns the challenges of every round, in the order they are sampled. On success, the
+/// multilinears of every claim evaluate to the columns of `proof.multilinear_evals` at the point
+/// given by the challenges, as in
+/// [`BatchSumcheckOutput`](binius_core::protocols::sumcheck::BatchSumcheckOutput).
+pub fn batch_verify(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	transcript: &mut Transcript,
+	claims: &[SumcheckClaim],
+	proof: &SumcheckProof,
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	ensure!(!claims.is_empty(), "sumcheck: no claims to check");
+	ensure!(
+		claims
+			.windows(2)
+			.all(|pair| pair[0].n_vars >= pair[1].n_vars),
+		"sumcheck: claims must be sorted by descending number of variables"
+	);
+	ensure!(
+		proof.multilinear_evals.len() == claims.len(),
+		"sumcheck: expected multilinear evaluations for every claim"
+	);
+	for (claim, evals) in izip!(claims, &proof.multilinear_evals) {
+		ensure!(
+			evals.len() == claim.n_multilinears,
+			"sumcheck: expected {} multilinear evaluations, got {}",
+			claim.n_multilinears,
+			evals.len()
+		);
+		ensure!(
+			claim
+				.composite_sums
+				.iter()
+				.all(|composite_sum| composite_sum.composition.n_vars() <= claim.n_multilinears),
+			"sumcheck: composition has more variables than the claim has multilinears"
+		);
+	}
+
+	let n_rounds = claims[0].n_vars;
+	ensure!(
+		proof.round_proofs.len() == n_rounds,
+		"sumcheck: expected a round proof for each of the {n_rounds} rounds"
+	);
+
+	builder.push_namespace(name);
+	let claimed_sums = claims
+		.iter()
+		.flat_map(|claim| {
+			claim
+				.composite_sums
+				.iter()
+				.map(|composite_sum| composite_sum.sum)
+		})
+		.collect::<Vec<_>>();
+	let log_rows = builder.log_rows(
+		claimed_sums
+			.iter()
+			.copied()
+			.chain(proof.round_proofs.iter().flatten().copied())
+			.chain(proof.multilinear_evals.iter().flatten().copied()),
+	)?;
+	ensure!(
+		log_rows == transcript.log_rows(),
+		"sumcheck: columns must have as many rows as the transcript"
+	);
+
+	transcript.observe(builder, &claimed_sums)?;
+
+	let mut batch_coeffs = Vec::with_capacity(claims.len());
+	let mut challenges = Vec::with_capacity(n_rounds);
+	let mut sum = BatchedSum::new();
+	let mut active_index = 0;
+	let mut max_degree = 0;
+	for (round_no, round_proof) in proof.round_proofs.iter().enumerate() {
+		let n_vars = n_rounds - round_no;
+		while let Some(claim) = claims.get(active_index) {
+			if claim.n_vars != n_vars {
+				break;
+			}
+			let batch_coeff = transcript.sample(builder)?;
+			batch_coeffs.push(batch_coeff);
+			sum.add_claim(claim, batch_coeff);
+			max_degree = max_degree.max(claim.max_individual_degree());
+			active_index += 1;
+		}
+
+		ensure!(
+			round_proof.len() == max_degree,
+			"sumcheck: round {round_no} proof has {} coefficients, expected {max_degree}",
+			round_proof.len()
+		);
+
+		transcript.observe(builder, round_proof)?;
+		let challenge = transcript.sample(builder)?;
+		challenges.push(challenge);
+
+		// Recover the last coefficient as in `RoundProof::recover`.
+		let mut coeffs = round_proof
+			.iter()
+			.map(|&coeff| sum.var(coeff))
+			.collect::<Vec<_>>();
+		let first_coeff = coeffs.first().cloned().unwrap_or_else(ArithCircuit::zero);
+		let last_coeff = coeffs
+			.iter()
+			.cloned()
+			.fold(sum.expr.clone() - first_coeff, |acc, coeff| acc - coeff);
+		coeffs.push(last_coeff);
+
+		let challenge = sum.var(challenge);
+		let round_eval = coeffs
+			.into_iter()
+			.enumerate()
+			.map(|(i, coeff)| match i {
+				0 => coeff,
+				_ => coeff * challenge.clone().pow(i as u64),
+			})
+			.sum::<ArithCircuit<F>>();
+
+		let next_sum = committed_evaluation(
+			builder,
+			format!("round_{round_no}_sum"),
+			log_rows,
+			&sum,
+			round_eval,
+		)?;
+		sum = BatchedSum::from_column(next_sum);
+	}
+
+	// Batch in any claims for 0-variate polynomials.
+	for claim in &claims[active_index..] {
+		let batch_coeff = transcript.sample(builder)?;
+		batch_coeffs.push(batch_coeff);
+		sum.add_claim(claim, batch_coeff);
+	}
+
+	transcript.observe(
+		builder,
+		&proof
+			.multilinear_evals
+			.iter()
+			.flatten()
+			.copied()
+			.collect::<Vec<_>>(),
+	)?;
+
+	let mut expected_sum = ArithCircuit::zero();
+	for (claim, &batch_coeff, evals) in izip!(claims, &batch_coeffs, &proof.multilinear_evals) {
+		let indices = evals
+			.iter()
+			.map(|&eval| sum.index(eval))
+			.collect::<Vec<_>>();
+		let composite_evals = claim
+			.composite_sums
+			.iter()
+			.map(|composite_sum| composite_sum.composition.remap_vars(&indices))
+			.collect::<Result<Vec<_>, _>>()?;
+		expected_sum += sum.batch_weighted(batch_coeff, composite_evals);
+	}
+	builder.assert_zero("batch_evaluation", sum.inputs.clone(), sum.expr - expected_sum);
+
+	builder.pop_namespace();
+	Ok(challenges)
+}
+
+/// The running batched sum, as an expression over a set of columns.
+struct BatchedSum {
+	inputs: Vec<OracleId>,
+	expr: ArithCircuit<F>,
+}
+
+impl BatchedSum {
+	fn new() -> Self {
+		Self {
+			inputs: Vec::new(),
+			expr: ArithCircuit::zero(),
+		}
+	}
+
+	fn from_column(id: OracleId) -> Self {
+		Self {
+			inputs: vec![id],
+			expr: ArithCircuit::var(0),
+		}
+	}
+
+	/// Returns the variable index of a column, adding it to the inputs if needed.
+	fn index(&mut self, id: OracleId) -> usize {
+		match self.inputs.iter().position(|&input| input == id) {
+			Some(index) => index,
+			None => {
+				self.inputs.push(id);
+				self.inputs.len() - 1
+			}
+		}
+	}
+
+	fn var(&mut self, id: OracleId) -> ArithCircuit<F> {
+		ArithCircuit::var(self.index(id))
+	}
+
+	/// Mixes values with the powers of a batching coefficient, matching the native verifier.
+	fn batch_weighted(
+		&mut self,
+		batch_coeff: OracleId,
+		values: impl IntoIterator<Item = ArithCircuit<F>>,
+	) -> ArithCircuit<F> {
+		let batch_coeff = self.var(batch_coeff);
+		values
+			.into_iter()
+			.enumerate()
+			.map(|(i, value)| value * batch_coeff.clone().pow(i as u64 + 1))
+			.sum()
+	}
+
+	fn add_claim(&mut self, claim: &SumcheckClaim, batch_coeff: OracleId) {
+		let sums = claim
+			.composite_sums
+			.iter()
+			.map(|composite_sum| self.var(composite_sum.sum))
+			.collect::<Vec<_>>();
+		let weighted = self.batch_weighted(batch_coeff, sums);
+		self.expr += weighted;
+	}
+}
+
+/// Commits a column equal to `expr` evaluated over the inputs of `sum`, row by row.
+fn committed_evaluation(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_rows: usize,
+	sum: &BatchedSum,
+	expr: ArithCircuit<F>,
+) -> Result<OracleId, anyhow::Error> {
+	let name = name.to_string();
+	let output = builder.add_committed(&name, log_rows, F::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let inputs = sum
+			.inputs
+			.iter()
+			.map(|&id| Ok(witness.get::<F>(id)?.as_slice::<F>()))
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+		let mut output_witness = witness.new_column::<F>(output)?;
+		output_witness
+			.as_mut_slice::<F>()
+			.par_iter_mut()
+			.enumerate()
+			.try_for_each(|(row, out)| -> Result<(), anyhow::Error> {
+				let query = inputs.iter().map(|input| input[row]).collect::<Vec<_>>();
+				*out = expr.evaluate(&query)?;
+				Ok(())
+			})?;
+	}
+
+	builder.assert_zero(
+		name,
+		sum.inputs.iter().copied().chain([output]),
+		expr - ArithCircuit::var(sum.inputs.len()),
+	);
+	Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::protocols::sumcheck::{
+		RoundCoeffs, RoundProof, verify_sumcheck::interpolate_round_proof,
+	};
+	use binius_field::Field;
+	use binius_math::{EvaluationDomain, InterpolationDomain};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{
+		builder::test_utils::test_circuit,
+		recursion::transcript::tests::{NativeTranscript, column},
+	};
+
+	/// The multilinears and compositions of one claim, for a single row.
+	struct Instance {
+		n_vars: usize,
+		multilinears: Vec<Vec<F>>,
+		compositions: Vec<ArithCircuit<F>>,
+	}
+
+	/// The values of one row of a batched sumcheck proof.
+	struct Row {
+		sums: Vec<Vec<F>>,
+		challenges: Vec<F>,
+		round_proofs: Vec<Vec<F>>,
+		multilinear_evals: Vec<Vec<F>>,
+	}
+
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	enum Tamper {
+		None,
+		RoundProof,
+		Challenge,
+	}
+
+	fn batch_weighted_value(batch_coeff: F, values: impl IntoIterator<Item = F>) -> F {
+		let mut weight = batch_coeff;
+		let mut result = F::ZERO;
+		for value in values {
+			result += weight * value;
+			weight *= batch_coeff;
+		}
+		result
+	}
+
+	fn composite_sums(instance: &Instance) -> Vec<F> {
+		instance
+			.compositions
+			.iter()
+			.map(|composition| {
+				(0..1 << instance.n_vars)
+					.map(|i| {
+						let query = instance
+							.multilinears
+							.iter()
+							.map(|multilinear| multilinear[i])
+							.collect::<Vec<_>>();
+						composition.evaluate(&query).unwrap()
+					})
+					.sum::<F>()
+			})
+			.collect()
+	}
+
+	/// Runs the batched sumcheck prover for a single row, binding the low variables first.
+	///
+	/// The transcript is used in the same order as by [`batch_verify`]. With
+	/// [`Tamper::Challenge`], the prover binds the second round to a challenge other than the one
+	/// sampled from the transcript.
+	fn prove(instances: &[Instance], sums: &[Vec<F>], tamper: Tamper) -> (Vec<F>, Row) {
+		let mut transcript = NativeTranscript::new();
+		transcript.observe(&sums.concat());
+
+		let n_rounds = instances[0].n_vars;
+		let mut tables = instances
+			.iter()
+			.map(|instance| instance.multilinears.clone())
+			.collect::<Vec<_>>();
+		let mut batch_coeffs = Vec::new();
+		let mut challenges = Vec::new();
+		let mut round_proofs = Vec::new();
+		for round_no in 0..n_rounds {
+			let n_active = instances
+				.iter()
+				.take_while(|instance| instance.n_vars >= n_rounds - round_no)
+				.count();
+			while batch_coeffs.len() < n_active {
+				batch_coeffs.push(transcript.sample());
+			}
+			let degree = instances[..n_active]
+				.iter()
+				.flat_map(|instance| instance.compositions.iter().map(|c| c.degree()))
+				.max()
+				.unwrap();
+
+			let points = (0..=degree as u128).map(F::new).collect::<Vec<_>>();
+			let values = points
+				.iter()
+				.map(|&x| {
+					izip!(&instances[..n_active], &tables, &batch_coeffs)
+						.map(|(instance, multilinears, &batch_coeff)| {
+							let sums = instance.compositions.iter().map(|composition| {
+								(0..multilinears[0].len() / 2)
+									.map(|i| {
+										let query = multilinears
+											.iter()
+											.map(|m| m[2 * i] + x * (m[2 * i + 1] - m[2 * i]))
+											.collect::<Vec<_>>();
+										composition.evaluate(&query).unwrap()
+									})
+									.sum::<F>()
+							});
+							batch_weighted_value(batch_coeff, sums)
+						})
+						.sum::<F>()
+				})
+				.collect::<Vec<F>>();
+			let domain =
+				InterpolationDomain::from(EvaluationDomain::from_points(points, false).unwrap());
+			let coeffs = domain.interpolate(&values).unwrap();
+			let round_proof = RoundCoeffs(coeffs).truncate().coeffs().to_vec();
+			transcript.observe(&round_proof);
+			round_proofs.push(round_proof);
+
+			let mut challenge = transcript.sample();
+			if tamper == Tamper::Challenge && round_no == 1 {
+				challenge += F::ONE;
+			}
+			challenges.push(challenge);
+
+			for multilinears in &mut tables[..n_active] {
+				for m in multilinears {
+					*m = (0..m.len() / 2)
+						.map(|i| m[2 * i] + challenge * (m[2 * i + 1] - m[2 * i]))
+						.collect();
+				}
+			}
+		}
+		while batch_coeffs.len() < instances.len() {
+			batch_coeffs.push(transcript.sample());
+		}
+
+		let multilinear_evals = tables
+			.into_iter()
+			.map(|multilinears| multilinears.into_iter().map(|m| m[0]).collect())
+			.collect();
+		let row = Row {
+			sums: sums.to_vec(),
+			challenges,
+			round_proofs,
+			multilinear_evals,
+		};
+		(batch_coeffs, row)
+	}
+
+	fn build_batch_sumcheck(
+		builder: &mut ConstraintSystemBuilder,
+		tamper: Tamper,
+	) -> Result<(), anyhow::Error> {
+		let log_size = 4;
+		let mut rng = StdRng::seed_from_u64(0);
+
+		// Claims over 3, 2 and 0 variables, with one row per inner sumcheck.
+		let shapes = [(3, 3), (2, 1), (0, 1)];
+		let compositions = || {
+			[
+				vec![
+					ArithCircuit::var(0) * ArithCircuit::var(1) * ArithCircuit::var(2),
+					ArithCircuit::var(0) + ArithCircuit::var(1),
+				],
+				vec![ArithCircuit::var(0) * ArithCircuit::var(0)],
+				vec![ArithCircuit::var(0)],
+			]
+		};
+
+		let mut rows = Vec::new();
+		for _ in 0..1 << log_size {
+			let instances = izip!(shapes, compositions())
+				.map(|((n_vars, n_multilinears), compositions)| Instance {
+					n_vars,
+					multilinears: (0..n_multilinears)
+						.map(|_| {
+							(0..1 << n_vars)
+								.map(|_| <F as Field>::random(&mut rng))
+								.collect()
+						})
+						.collect(),
+					compositions,
+				})
+				.collect::<Vec<_>>();
+
+			let sums = instances.iter().map(composite_sums).collect::<Vec<_>>();
+			let (batch_coeffs, mut row) = prove(&instances, &sums, tamper);
+
+			// Check the native prover against the native verifier logic.
+			let mut sum = F::ZERO;
+			for (round_no, (round_proof, &challenge)) in
+				izip!(&row.round_proofs, &row.challenges).enumerate()
+			{
+				for (instance, sums, &batch_coeff) in izip!(&instances, &sums, &batch_coeffs) {
+					if instance.n_vars == shapes[0].0 - round_no {
+						sum += batch_weighted_value(batch_coeff, sums.iter().copied());
+					}
+				}
+				sum = interpolate_round_proof(
+					RoundProof(RoundCoeffs(round_proof.clone())),
+					sum,
+					challenge,
+				);
+			}
+			sum += batch_weighted_value(batch_coeffs[2], sums[2].iter().copied());
+			let expected_sum = izip!(&instances, &row.multilinear_evals, &batch_coeffs)
+				.map(|(instance, evals, &batch_coeff)| {
+					let composite_evals = instance
+						.compositions
+						.iter()
+						.map(|composition| composition.evaluate(evals).unwrap());
+					batch_weighted_value(batch_coeff, composite_evals)
+				})
+				.sum::<F>();
+			assert_eq!(sum, expected_sum);
+
+			if tamper == Tamper::RoundProof {
+				row.round_proofs[1][0] += F::ONE;
+			}
+			rows.push(row);
+		}
+
+		let claims = izip!(shapes, compositions())
+			.enumerate()
+			.map(|(j, ((n_vars, n_multilinears), compositions))| SumcheckClaim {
+				n_vars,
+				n_multilinears,
+				composite_sums: compositions
+					.into_iter()
+					.enumerate()
+					.map(|(i, composition)| CompositeSumClaim {
+						composition,
+						sum: column(builder, format!("sum_{j}_{i}"), log_size, |row| {
+							rows[row].sums[j][i]
+						}),
+					})
+					.collect(),
+			})
+			.collect::<Vec<_>>();
+		let proof = SumcheckProof {
+			round_proofs: (0..shapes[0].0)
+				.map(|k| {
+					(0..rows[0].round_proofs[k].len())
+						.map(|i| {
+							column(builder, format!("round_{k}_coeff_{i}"), log_size, |row| {
+								rows[row].round_proofs[k][i]
+							})
+						})
+						.collect()
+				})
+				.collect(),
+			multilinear_evals: (0..shapes.len())
+				.map(|j| {
+					(0..shapes[j].1)
+						.map(|i| {
+							column(builder, format!("eval_{j}_{i}"), log_size, |row| {
+								rows[row].multilinear_evals[j][i]
+							})
+						})
+						.collect()
+				})
+				.collect(),
+		};
+
+		let mut transcript = Transcript::new(builder, log_size)?;
+		let challenges = batch_verify(builder, "sumcheck", &mut transcript, &claims, &proof)?;
+
+		// The evaluation point is the one the honest prover bound the multilinears to.
+		if tamper == Tamper::None {
+			for (k, challenge) in challenges.into_iter().enumerate() {
+				let expected = column(builder, format!("challenge_{k}"), log_size, |row| {
+					rows[row].challenges[k]
+				});
+				builder.assert_zero(
+					format!("challenge_{k}"),
+					[challenge, expected],
+					ArithCircuit::var(0) - ArithCircuit::var(1),
+				);
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_batch_verify_mixed_n_vars() {
+		test_circuit(|builder| {
+			build_batch_sumcheck(builder, Tamper::None)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_batch_verify_rejects_invalid_round_proof() {
+		let result = test_circuit(|builder| {
+			build_batch_sumcheck(builder, Tamper::RoundProof)?;
+			Ok(vec![])
+		});
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn test_batch_verify_rejects_mismatched_challenge() {
+		let result = test_circuit(|builder| {
+			build_batch_sumcheck(builder, Tamper::Challenge)?;
+			Ok(vec![])
+		});
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn test_batch_verify_rejects_wrong_number_of_round_proofs() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let mut transcript = Transcript::new(&mut builder, 4).unwrap();
+		let sum = builder.add_committed("sum", 4, F::TOWER_LEVEL);
+		let eval = builder.add_committed("eval", 4, F::TOWER_LEVEL);
+		let claim = SumcheckClaim {
+			n_vars: 2,
+			n_multilinears: 1,
+			composite_sums: vec![CompositeSumClaim {
+				composition: ArithCircuit::var(0),
+				sum,
+			}],
+		};
+		let proof = SumcheckProof {
+			round_proofs: vec![vec![]],
+			multilinear_evals: vec![vec![eval]],
+		};
+		assert!(batch_verify(&mut builder, "sumcheck", &mut transcript, &[claim], &proof).is_err());
+	}
+}
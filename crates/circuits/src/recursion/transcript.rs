@@ -0,0 +1,361 @@
+// Copyright 2025 Irreducible Inc.
+
+//! An in-circuit Fiat-Shamir transcript over the Vision Mark-32 permutation.
+//!
+//! The transcript is a duplex sponge with the state of the [`vision_permutation`] gadget, and
+//! keeps one independent transcript per row. Observed columns of `F` elements are decomposed into
+//! their four 32-bit words, which overwrite the rate of the state block by block, each block
+//! followed by a permutation. Sampled challenges are packed from four consecutive words of the
+//! rate of the state, permuting again once the rate is exhausted.
+
+use std::array;
+
+use anyhow::ensure;
+use binius_core::{oracle::OracleId, transparent::constant::Constant};
+use binius_field::{BinaryField32b, ExtensionField, Field, TowerField};
+use binius_math::ArithCircuit;
+
+use crate::{
+	builder::{ConstraintSystemBuilder, types::F},
+	vision::{RATE_SIZE, STATE_SIZE, vision_permutation},
+};
+
+type B32 = BinaryField32b;
+
+/// The number of 32-bit words of an `F` element.
+const WORDS_PER_ELEM: usize = <F as ExtensionField<B32>>::DEGREE;
+
+/// A Fiat-Shamir transcript whose challenges are derived in-circuit.
+#[derive(Debug)]
+pub struct Transcript {
+	log_rows: usize,
+	state: [OracleId; STATE_SIZE],
+	/// The number of words of the rate already used for challenges.
+	squeezed: usize,
+	n_permutations: usize,
+	n_observed: usize,
+	n_sampled: usize,
+}
+
+impl Transcript {
+	/// Creates a transcript with the all-zero initial state, for columns with `2^log_rows` rows.
+	pub fn new(
+		builder: &mut ConstraintSystemBuilder,
+		log_rows: usize,
+	) -> Result<Self, anyhow::Error> {
+		builder.push_namespace("transcript_init");
+		let state = array::from_fn(|i| {
+			builder
+				.add_transparent(format!("state_{i}"), Constant::new(log_rows, B32::ZERO))
+				.unwrap()
+		});
+		if let Some(witness) = builder.witness() {
+			for id in state {
+				witness
+					.new_column::<B32>(id)?
+					.as_mut_slice::<B32>()
+					.fill(B32::ZERO);
+			}
+		}
+		builder.pop_namespace();
+
+		Ok(Self {
+			log_rows,
+			state,
+			squeezed: RATE_SIZE,
+			n_permutations: 0,
+			n_observed: 0,
+			n_sampled: 0,
+		})
+	}
+
+	/// The base-2 logarithm of the number of rows of the transcript columns.
+	pub const fn log_rows(&self) -> usize {
+		self.log_rows
+	}
+
+	/// Absorbs the values of columns of `F` elements, in order.
+	pub fn observe(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		values: &[OracleId],
+	) -> Result<(), anyhow::Error> {
+		let mut words = Vec::with_capacity(values.len() * WORDS_PER_ELEM);
+		for &value in values {
+			ensure!(
+				builder.log_rows([value])? == self.log_rows,
+				"transcript: observed column must have 2^{} rows",
+				self.log_rows
+			);
+			words.extend(self.decompose(builder, value)?);
+		}
+
+		for block in words.chunks(RATE_SIZE) {
+			let input = array::from_fn(|i| block.get(i).copied().unwrap_or(self.state[i]));
+			self.permute(builder, input)?;
+			self.squeezed = 0;
+		}
+		Ok(())
+	}
+
+	/// Samples a challenge, returning a column of `F` elements.
+	pub fn sample(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+	) -> Result<OracleId, anyhow::Error> {
+		if self.squeezed + WORDS_PER_ELEM > RATE_SIZE {
+			self.permute(builder, self.state)?;
+			self.squeezed = 0;
+		}
+		let words: [OracleId; WORDS_PER_ELEM] = array::from_fn(|i| self.state[self.squeezed + i]);
+		self.squeezed += WORDS_PER_ELEM;
+
+		let challenge = builder.add_linear_combination(
+			format!("transcript_challenge_{}", self.n_sampled),
+			self.log_rows,
+			words
+				.iter()
+				.enumerate()
+				.map(|(i, &word)| (word, <F as ExtensionField<B32>>::basis(i))),
+		)?;
+		self.n_sampled += 1;
+
+		if let Some(witness) = builder.witness() {
+			let words_witness = words
+				.iter()
+				.map(|&word| witness.get::<B32>(word))
+				.collect::<Result<Vec<_>, _>>()?;
+			let words = words_witness
+				.iter()
+				.map(|word| word.as_slice::<B32>())
+				.collect::<Vec<_>>();
+			let mut challenge_witness = witness.new_column::<F>(challenge)?;
+			for (row, out) in challenge_witness.as_mut_slice::<F>().iter_mut().enumerate() {
+				*out = ExtensionField::<B32>::from_bases(words.iter().map(|word| word[row]))?;
+			}
+		}
+		Ok(challenge)
+	}
+
+	/// Samples `n` challenges.
+	pub fn sample_vec(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		n: usize,
+	) -> Result<Vec<OracleId>, anyhow::Error> {
+		(0..n).map(|_| self.sample(builder)).collect()
+	}
+
+	fn permute(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		input: [OracleId; STATE_SIZE],
+	) -> Result<(), anyhow::Error> {
+		builder.push_namespace(format!("transcript_permutation_{}", self.n_permutations));
+		self.state = vision_permutation(builder, self.log_rows, input)?;
+		builder.pop_namespace();
+		self.n_permutations += 1;
+		Ok(())
+	}
+
+	/// Commits the 32-bit words of a column of `F` elements.
+	fn decompose(
+		&mut self,
+		builder: &mut ConstraintSystemBuilder,
+		value: OracleId,
+	) -> Result<[OracleId; WORDS_PER_ELEM], anyhow::Error> {
+		let name = format!("transcript_observed_{}", self.n_observed);
+		self.n_observed += 1;
+
+		let words = builder.add_committed_multiple::<WORDS_PER_ELEM>(
+			&name,
+			self.log_rows,
+			B32::TOWER_LEVEL,
+		);
+
+		if let Some(witness) = builder.witness() {
+			let value_witness = witness.get::<F>(value)?;
+			let values = value_witness.as_slice::<F>();
+			let mut words_witness = words
+				.iter()
+				.map(|&word| witness.new_column::<B32>(word))
+				.collect::<Result<Vec<_>, _>>()?;
+			let mut words = words_witness
+				.iter_mut()
+				.map(|word| word.as_mut_slice::<B32>())
+				.collect::<Vec<_>>();
+			for (row, value) in values.iter().enumerate() {
+				for (word, base) in words
+					.iter_mut()
+					.zip(ExtensionField::<B32>::iter_bases(value))
+				{
+					word[row] = base;
+				}
+			}
+		}
+
+		let recomposed = (0..WORDS_PER_ELEM)
+			.map(|i| {
+				ArithCircuit::var(i + 1)
+					* ArithCircuit::constant(<F as ExtensionField<B32>>::basis(i))
+			})
+			.sum::<ArithCircuit<F>>();
+		builder.assert_zero(
+			name,
+			[value].into_iter().chain(words),
+			ArithCircuit::var(0) - recomposed,
+		);
+		Ok(words)
+	}
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+	use binius_field::{PackedAESBinaryField8x32b, PackedField};
+	use binius_hash::{Vision32bPermutation, permutation::Permutation};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	/// The native counterpart of [`Transcript`], for a single row.
+	pub(in crate::recursion) struct NativeTranscript {
+		state: [B32; STATE_SIZE],
+		squeezed: usize,
+	}
+
+	impl NativeTranscript {
+		pub fn new() -> Self {
+			Self {
+				state: [B32::ZERO; STATE_SIZE],
+				squeezed: RATE_SIZE,
+			}
+		}
+
+		pub fn observe(&mut self, values: &[F]) {
+			let words = values
+				.iter()
+				.flat_map(ExtensionField::<B32>::iter_bases)
+				.collect::<Vec<B32>>();
+			for block in words.chunks(RATE_SIZE) {
+				self.state[..block.len()].copy_from_slice(block);
+				self.permute();
+				self.squeezed = 0;
+			}
+		}
+
+		pub fn sample(&mut self) -> F {
+			if self.squeezed + WORDS_PER_ELEM > RATE_SIZE {
+				self.permute();
+				self.squeezed = 0;
+			}
+			let words = &self.state[self.squeezed..self.squeezed + WORDS_PER_ELEM];
+			self.squeezed += WORDS_PER_ELEM;
+			ExtensionField::<B32>::from_bases(words.iter().copied()).unwrap()
+		}
+
+		fn permute(&mut self) {
+			let mut state: [_; 3] = array::from_fn(|i| {
+				PackedAESBinaryField8x32b::from_fn(|j| self.state[i * 8 + j].into())
+			});
+			Vision32bPermutation::default().permute_mut(&mut state);
+			for (word, out) in self
+				.state
+				.iter_mut()
+				.zip(PackedAESBinaryField8x32b::iter_slice(&state))
+			{
+				*word = out.into();
+			}
+		}
+	}
+
+	/// Commits a column of `F` elements with the given value in every row.
+	pub(in crate::recursion) fn column(
+		builder: &mut ConstraintSystemBuilder,
+		name: impl ToString,
+		log_size: usize,
+		values: impl Fn(usize) -> F,
+	) -> OracleId {
+		let id = builder.add_committed(name, log_size, F::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			for (row, value) in witness
+				.new_column::<F>(id)
+				.unwrap()
+				.as_mut_slice::<F>()
+				.iter_mut()
+				.enumerate()
+			{
+				*value = values(row);
+			}
+		}
+		id
+	}
+
+	fn build_transcript(
+		builder: &mut ConstraintSystemBuilder,
+		tamper: bool,
+	) -> Result<(), anyhow::Error> {
+		let log_rows = 3;
+		let mut rng = StdRng::seed_from_u64(0);
+
+		// Observe a block and a half, sample across a permutation boundary, then observe again.
+		let rows = (0..1 << log_rows)
+			.map(|_| {
+				let first = (0..6).map(|_| F::random(&mut rng)).collect::<Vec<_>>();
+				let second = (0..2).map(|_| F::random(&mut rng)).collect::<Vec<_>>();
+				let mut native = NativeTranscript::new();
+				native.observe(&first);
+				let mut challenges = (0..5).map(|_| native.sample()).collect::<Vec<_>>();
+				native.observe(&second);
+				challenges.push(native.sample());
+				if tamper {
+					challenges[4] += F::ONE;
+				}
+				(first, second, challenges)
+			})
+			.collect::<Vec<_>>();
+
+		let first = (0..6)
+			.map(|i| column(builder, format!("first_{i}"), log_rows, |row| rows[row].0[i]))
+			.collect::<Vec<_>>();
+		let second = (0..2)
+			.map(|i| column(builder, format!("second_{i}"), log_rows, |row| rows[row].1[i]))
+			.collect::<Vec<_>>();
+		let expected = (0..6)
+			.map(|i| column(builder, format!("expected_{i}"), log_rows, |row| rows[row].2[i]))
+			.collect::<Vec<_>>();
+
+		let mut transcript = Transcript::new(builder, log_rows)?;
+		transcript.observe(builder, &first)?;
+		let mut challenges = transcript.sample_vec(builder, 5)?;
+		transcript.observe(builder, &second)?;
+		challenges.push(transcript.sample(builder)?);
+
+		for (i, (challenge, expected)) in challenges.into_iter().zip(expected).enumerate() {
+			builder.assert_zero(
+				format!("challenge_{i}"),
+				[challenge, expected],
+				ArithCircuit::var(0) - ArithCircuit::var(1),
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_transcript_matches_native() {
+		test_circuit(|builder| {
+			build_transcript(builder, false)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_transcript_rejects_wrong_challenge() {
+		let result = test_circuit(|builder| {
+			build_transcript(builder, true)?;
+			Ok(vec![])
+		});
+		assert!(result.is_err());
+	}
+}
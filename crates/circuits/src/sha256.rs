@@ -69,13 +69,13 @@ pub fn rotate_and_xor(
 	)?;
 
 	if let Some(witness) = builder.witness() {
-		let mut result_witness = witness.new_column::<B1>(result_oracle_id);
+		let mut result_witness = witness.new_column::<B1>(result_oracle_id)?;
 		let result_u32 = result_witness.as_mut_slice::<u32>();
 
 		for ((oracle_id, shift, t), shifted_oracle_id) in r.iter().zip(&shifted_oracle_ids) {
 			let values_u32 = witness.get::<B1>(*oracle_id)?.as_slice::<u32>();
 
-			let mut shifted_witness = witness.new_column::<B1>(*shifted_oracle_id);
+			let mut shifted_witness = witness.new_column::<B1>(*shifted_oracle_id)?;
 			let shifted_u32 = shifted_witness.as_mut_slice::<u32>();
 
 			izip!(shifted_u32.iter_mut(), values_u32, result_u32.iter_mut()).for_each(
@@ -93,6 +93,11 @@ pub fn rotate_and_xor(
 	Ok(result_oracle_id)
 }
 
+/// Applies the SHA-256 compression function to a single message block, starting from the
+/// standard initial hash value [`INIT`].
+///
+/// Each row of the `input` oracles holds one 512-bit block as sixteen big-endian 32-bit words.
+/// Returns the eight 32-bit words of the resulting state.
 pub fn sha256(
 	builder: &mut ConstraintSystemBuilder,
 	input: [OracleId; 16],
@@ -102,6 +107,25 @@ pub fn sha256(
 		Err(anyhow::Error::msg("log_size too small"))?
 	}
 
+	let state = INIT.map(|val| u32const_repeating(log_size, builder, val, "INIT").unwrap());
+	sha256_compress(builder, state, input, log_size)
+}
+
+/// Applies the SHA-256 compression function to a message block and a chaining state.
+///
+/// Both `state` and `input` hold 32-bit words, one value per row. Chaining the output of one
+/// compression into the `state` of the next hashes multi-block messages; the caller is
+/// responsible for the message padding.
+pub fn sha256_compress(
+	builder: &mut ConstraintSystemBuilder,
+	state: [OracleId; 8],
+	input: [OracleId; 16],
+	log_size: usize,
+) -> Result<[OracleId; 8], anyhow::Error> {
+	if log_size < <PackedType<U, BinaryField1b>>::LOG_WIDTH {
+		Err(anyhow::Error::msg("log_size too small"))?
+	}
+
 	let mut w = [OracleId::invalid(); 64];
 
 	w[0..16].copy_from_slice(&input);
@@ -144,9 +168,7 @@ pub fn sha256(
 		)?;
 	}
 
-	let init_oracles = INIT.map(|val| u32const_repeating(log_size, builder, val, "INIT").unwrap());
-
-	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = init_oracles;
+	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
 
 	let k = ROUND_CONSTS_K
 		.map(|val| u32const_repeating(log_size, builder, val, "ROUND_CONSTS_K").unwrap());
@@ -167,7 +189,7 @@ pub fn sha256(
 		)?;
 
 		if let Some(witness) = builder.witness() {
-			let mut ch_witness = witness.new_column::<B1>(ch[i]);
+			let mut ch_witness = witness.new_column::<B1>(ch[i])?;
 			let ch_u32 = ch_witness.as_mut_slice::<u32>();
 			let e_u32 = witness.get::<B1>(e)?.as_slice::<u32>();
 			let f_u32 = witness.get::<B1>(f)?.as_slice::<u32>();
@@ -202,7 +224,7 @@ pub fn sha256(
 		)?;
 
 		if let Some(witness) = builder.witness() {
-			let mut maj_witness = witness.new_column::<B1>(maj[i]);
+			let mut maj_witness = witness.new_column::<B1>(maj[i])?;
 			let maj_u32 = maj_witness.as_mut_slice::<u32>();
 			let a_u32 = witness.get::<B1>(a)?.as_slice::<u32>();
 			let b_u32 = witness.get::<B1>(b)?.as_slice::<u32>();
@@ -244,14 +266,8 @@ pub fn sha256(
 	let abcdefgh = [a, b, c, d, e, f, g, h];
 
 	let output = std::array::from_fn(|i| {
-		arithmetic::u32::add(
-			builder,
-			"output",
-			init_oracles[i],
-			abcdefgh[i],
-			arithmetic::Flags::Unchecked,
-		)
-		.unwrap()
+		arithmetic::u32::add(builder, "output", state[i], abcdefgh[i], arithmetic::Flags::Unchecked)
+			.unwrap()
 	});
 
 	Ok(output)
@@ -259,12 +275,14 @@ pub fn sha256(
 
 #[cfg(test)]
 mod tests {
-	use binius_core::oracle::OracleId;
-	use binius_field::{BinaryField1b, as_packed_field::PackedType};
+	use binius_core::{constraint_system, fiat_shamir::HasherChallenger, oracle::OracleId};
+	use binius_field::{BinaryField1b, as_packed_field::PackedType, tower::CanonicalTowerFamily};
+	use binius_hal::make_portable_backend;
+	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
 	use sha2::{compress256, digest::generic_array::GenericArray};
 
 	use crate::{
-		builder::{test_utils::test_circuit, types::U},
+		builder::{ConstraintSystemBuilder, test_utils::test_circuit, types::U},
 		unconstrained::unconstrained,
 	};
 
@@ -316,4 +334,91 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	#[test]
+	fn test_sha256_compress_chaining() {
+		test_circuit(|builder| {
+			let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+			let blocks: [[OracleId; 16]; 2] = std::array::from_fn(|block| {
+				std::array::from_fn(|i| {
+					unconstrained::<BinaryField1b>(builder, block * 16 + i, log_size).unwrap()
+				})
+			});
+			let state = super::sha256(builder, blocks[0], log_size)?;
+			let digest = super::sha256_compress(builder, state, blocks[1], log_size)?;
+
+			if let Some(witness) = builder.witness() {
+				let block_witnesses = blocks.map(|block| {
+					block.map(|id| witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>())
+				});
+				let digest_witness =
+					digest.map(|id| witness.get::<BinaryField1b>(id).unwrap().as_slice::<u32>());
+
+				for j in 0..digest_witness[0].len() {
+					let generic_array_blocks = block_witnesses.map(|block| {
+						let mut generic_array_block = GenericArray::<u8, _>::default();
+						for (i, word) in block.iter().enumerate() {
+							generic_array_block[i * 4..(i + 1) * 4]
+								.copy_from_slice(&word[j].to_be_bytes());
+						}
+						generic_array_block
+					});
+
+					let mut output = crate::sha256::INIT;
+					compress256(&mut output, &generic_array_blocks);
+
+					for i in 0..8 {
+						assert_eq!(output[i], digest_witness[i][j]);
+					}
+				}
+			}
+
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_sha256_prove_verify() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		let input: [OracleId; 16] = std::array::from_fn(|i| {
+			unconstrained::<BinaryField1b>(&mut builder, i, log_size).unwrap()
+		});
+		super::sha256(&mut builder, input, log_size).unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			constraint_system::SecurityLevel::new(10, 1),
+			&[],
+			witness,
+			&backend,
+		)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, constraint_system::SecurityLevel::new(10, 1), &[], proof)
+		.unwrap();
+	}
 }
@@ -20,7 +20,7 @@ pub fn step_down(
 	let step_down = transparent::step_down::StepDown::new(log_size, index)?;
 	let id = builder.add_transparent(name, step_down.clone())?;
 	if let Some(witness) = builder.witness() {
-		step_down.populate(witness.new_column::<BinaryField1b>(id).packed());
+		step_down.populate(witness.new_column::<BinaryField1b>(id)?.packed());
 	}
 	Ok(id)
 }
@@ -34,7 +34,7 @@ pub fn step_up(
 	let step_up = transparent::step_up::StepUp::new(log_size, index)?;
 	let id = builder.add_transparent(name, step_up.clone())?;
 	if let Some(witness) = builder.witness() {
-		step_up.populate(witness.new_column::<BinaryField1b>(id).packed());
+		step_up.populate(witness.new_column::<BinaryField1b>(id)?.packed());
 	}
 	Ok(id)
 }
@@ -54,7 +54,7 @@ where
 	let id = builder.add_transparent(name, poly)?;
 	if let Some(witness) = builder.witness() {
 		witness
-			.new_column::<FS>(id)
+			.new_column::<FS>(id)?
 			.packed()
 			.fill(<PackedType<U, FS>>::broadcast(value));
 	}
@@ -85,7 +85,7 @@ where
 	let oracle = builder.add_transparent(name, mle)?;
 
 	if let Some(witness) = builder.witness() {
-		let mut entry_builder = witness.new_column::<FS>(oracle);
+		let mut entry_builder = witness.new_column::<FS>(oracle)?;
 		entry_builder.packed().copy_from_slice(&packed_values);
 	}
 
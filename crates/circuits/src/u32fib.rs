@@ -1,9 +1,8 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_core::oracle::OracleId;
 use binius_field::{BinaryField1b, BinaryField32b, TowerField};
 use binius_macros::arith_expr;
-use binius_maybe_rayon::prelude::*;
 use rand::{Rng, thread_rng};
 
 use crate::{
@@ -19,14 +18,8 @@ pub fn u32fib(
 ) -> Result<OracleId, anyhow::Error> {
 	builder.push_namespace(name);
 	let current = builder.add_committed("current", log_size, BinaryField1b::TOWER_LEVEL);
-	let next = builder.add_shifted("next", current, 32, log_size, ShiftVariant::LogicalRight)?;
-	let next_next =
-		builder.add_shifted("next_next", current, 64, log_size, ShiftVariant::LogicalRight)?;
-
 	if let Some(witness) = builder.witness() {
-		let mut current = witness.new_column::<BinaryField1b>(current);
-		let mut next = witness.new_column::<BinaryField1b>(next);
-		let mut next_next = witness.new_column::<BinaryField1b>(next_next);
+		let mut current = witness.new_column::<BinaryField1b>(current)?;
 
 		let mut rng = thread_rng();
 		let current = current.as_mut_slice::<u32>();
@@ -36,17 +29,9 @@ pub fn u32fib(
 			current[i] = rng.r#gen();
 			(current[i], _) = current[i - 1].overflowing_add(current[i - 2]);
 		}
-		(next.as_mut_slice::<u32>(), &current[1..])
-			.into_par_iter()
-			.for_each(|(next, current)| {
-				*next = *current;
-			});
-		(next_next.as_mut_slice::<u32>(), &current[2..])
-			.into_par_iter()
-			.for_each(|(next_next, current)| {
-				*next_next = *current;
-			});
 	}
+	let next = builder.add_next_row::<BinaryField1b>("next", current, 32)?;
+	let next_next = builder.add_next_row::<BinaryField1b>("next_next", current, 64)?;
 
 	let packed_log_size = log_size - 5;
 	let enabled = step_down(builder, "enabled", packed_log_size, (1 << packed_log_size) - 2)?;
@@ -74,7 +59,7 @@ pub fn u32fib(
 
 #[cfg(test)]
 mod tests {
-	use crate::builder::test_utils::test_circuit;
+	use crate::builder::test_utils::{test_circuit, test_proof_size_estimate};
 
 	#[test]
 	fn test_u32fib() {
@@ -85,4 +70,13 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	#[test]
+	fn test_u32fib_proof_size_estimate() {
+		test_proof_size_estimate(|builder| {
+			let _ = super::u32fib(builder, "u32fib", 14)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
 }
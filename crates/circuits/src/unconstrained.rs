@@ -1,64 +1,158 @@
 // Copyright 2024-2025 Irreducible Inc.
+use std::marker::PhantomData;
+
 use binius_core::oracle::OracleId;
 use binius_field::{ExtensionField, TowerField, as_packed_field::PackScalar};
 use binius_maybe_rayon::prelude::*;
 use bytemuck::Pod;
 use rand::{Rng, thread_rng};
 
-use crate::builder::{
-	ConstraintSystemBuilder,
-	types::{F, U},
+use crate::{
+	builder::{
+		ConstraintSystemBuilder,
+		types::{F, U},
+		witness,
+	},
+	gadget::Gadget,
 };
 
-pub fn unconstrained<FS>(
-	builder: &mut ConstraintSystemBuilder,
-	name: impl ToString,
+/// A committed column with random witness values and no constraints.
+pub struct Unconstrained<FS> {
+	name: String,
 	log_size: usize,
-) -> Result<OracleId, anyhow::Error>
+	_fs_marker: PhantomData<FS>,
+}
+
+impl<FS> Unconstrained<FS> {
+	pub fn new(name: impl ToString, log_size: usize) -> Self {
+		Self {
+			name: name.to_string(),
+			log_size,
+			_fs_marker: PhantomData,
+		}
+	}
+}
+
+impl<FS> Gadget for Unconstrained<FS>
 where
 	U: PackScalar<FS> + Pod,
 	F: TowerField + ExtensionField<FS>,
 	FS: TowerField,
 {
-	let rng = builder.add_committed(name, log_size, FS::TOWER_LEVEL);
+	type Input = ();
+	type Output = OracleId;
+
+	fn name(&self) -> &str {
+		&self.name
+	}
+
+	fn constrain(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		_input: &(),
+	) -> Result<OracleId, anyhow::Error> {
+		Ok(builder.add_committed(&self.name, self.log_size, FS::TOWER_LEVEL))
+	}
 
-	if let Some(witness) = builder.witness() {
+	fn fill_witness(
+		&self,
+		witness: &mut witness::Builder,
+		_input: &(),
+		&rng: &OracleId,
+	) -> Result<(), anyhow::Error> {
 		witness
-			.new_column::<FS>(rng)
+			.new_column::<FS>(rng)?
 			.as_mut_slice::<u8>()
 			.into_par_iter()
 			.for_each_init(thread_rng, |rng, data| {
 				*data = rng.r#gen();
 			});
+		Ok(())
 	}
-
-	Ok(rng)
 }
 
-// Same as 'unconstrained' but uses some pre-defined values instead of a random ones
-pub fn fixed_u32<FS>(
-	builder: &mut ConstraintSystemBuilder,
-	name: impl ToString,
+/// Same as [`Unconstrained`] but uses some pre-defined values instead of random ones.
+pub struct FixedU32<FS> {
+	name: String,
 	log_size: usize,
 	values: Vec<u32>,
-) -> Result<OracleId, anyhow::Error>
+	_fs_marker: PhantomData<FS>,
+}
+
+impl<FS> FixedU32<FS> {
+	pub fn new(name: impl ToString, log_size: usize, values: Vec<u32>) -> Self {
+		Self {
+			name: name.to_string(),
+			log_size,
+			values,
+			_fs_marker: PhantomData,
+		}
+	}
+}
+
+impl<FS> Gadget for FixedU32<FS>
 where
 	U: PackScalar<FS> + Pod,
 	F: TowerField + ExtensionField<FS>,
 	FS: TowerField,
 {
-	let fixed = builder.add_committed(name, log_size, FS::TOWER_LEVEL);
+	type Input = ();
+	type Output = OracleId;
+
+	fn name(&self) -> &str {
+		&self.name
+	}
 
-	if let Some(witness) = builder.witness() {
+	fn constrain(
+		&self,
+		builder: &mut ConstraintSystemBuilder,
+		_input: &(),
+	) -> Result<OracleId, anyhow::Error> {
+		Ok(builder.add_committed(&self.name, self.log_size, FS::TOWER_LEVEL))
+	}
+
+	fn fill_witness(
+		&self,
+		witness: &mut witness::Builder,
+		_input: &(),
+		&fixed: &OracleId,
+	) -> Result<(), anyhow::Error> {
 		witness
-			.new_column::<FS>(fixed)
+			.new_column::<FS>(fixed)?
 			.as_mut_slice::<u32>()
 			.into_par_iter()
-			.zip(values.into_par_iter())
+			.zip(self.values.par_iter())
 			.for_each(|(data, value)| {
-				*data = value;
+				*data = *value;
 			});
+		Ok(())
 	}
+}
 
-	Ok(fixed)
+pub fn unconstrained<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	Unconstrained::<FS>::new(name, log_size).build(builder, ())
+}
+
+// Same as 'unconstrained' but uses some pre-defined values instead of a random ones
+pub fn fixed_u32<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	log_size: usize,
+	values: Vec<u32>,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	FixedU32::<FS>::new(name, log_size, values).build(builder, ())
 }
@@ -43,7 +43,7 @@ pub fn vision_permutation(
 			array_util::try_from_fn(|i| witness.get::<B32>(p_in[i]))?;
 		let perm_in_data: [_; STATE_SIZE] = perm_in_data_owned.map(|elem| elem.as_slice::<B32>());
 		let mut round_0_input_data: [_; STATE_SIZE] =
-			round_0_input.map(|id| witness.new_column::<B32>(id));
+			array_util::try_map(round_0_input, |id| witness.new_column::<B32>(id))?;
 		let round_0_input_slice = round_0_input_data
 			.each_mut()
 			.map(|elem| elem.as_mut_slice::<B32>());
@@ -97,8 +97,94 @@ pub fn vision_permutation(
 	Ok(perm_out)
 }
 
+/// The Vision Mark-32 2-to-1 compression function.
+///
+/// Compresses two 32-byte digests, given as 16 columns of 32-bit words in little-endian order,
+/// into a single 32-byte digest. The result matches
+/// [`Vision32Compression`](binius_hash::Vision32Compression) bit-for-bit: the input is absorbed
+/// as one full rate block of the sponge, followed by a padding block, and the digest is the first
+/// 8 words of the final state.
+pub fn vision_compression(
+	builder: &mut ConstraintSystemBuilder,
+	log_size: usize,
+	input: [OracleId; RATE_SIZE],
+) -> Result<[OracleId; DIGEST_SIZE]> {
+	type B32 = BinaryField32b;
+
+	builder.push_namespace("vision_compression");
+
+	let capacity: [OracleId; STATE_SIZE - RATE_SIZE] = array::from_fn(|i| {
+		builder
+			.add_transparent(format!("capacity_{i}"), Constant::new(log_size, B32::ZERO))
+			.unwrap()
+	});
+	let padding: [OracleId; RATE_SIZE] = array::from_fn(|i| {
+		builder
+			.add_transparent(format!("padding_{i}"), Constant::new(log_size, padding_word(i)))
+			.unwrap()
+	});
+	if let Some(witness) = builder.witness() {
+		for id in capacity {
+			witness
+				.new_column::<B32>(id)?
+				.as_mut_slice::<B32>()
+				.fill(B32::ZERO);
+		}
+		for (i, id) in padding.into_iter().enumerate() {
+			witness
+				.new_column::<B32>(id)?
+				.as_mut_slice::<B32>()
+				.fill(padding_word(i));
+		}
+	}
+
+	let absorb_in = array::from_fn(|i| {
+		if i < RATE_SIZE {
+			input[i]
+		} else {
+			capacity[i - RATE_SIZE]
+		}
+	});
+	builder.push_namespace("absorb");
+	let absorb_out = vision_permutation(builder, log_size, absorb_in)?;
+	builder.pop_namespace();
+
+	let padding_in = array::from_fn(|i| {
+		if i < RATE_SIZE {
+			padding[i]
+		} else {
+			absorb_out[i]
+		}
+	});
+	builder.push_namespace("padding");
+	let padding_out = vision_permutation(builder, log_size, padding_in)?;
+	builder.pop_namespace();
+
+	builder.pop_namespace();
+	Ok(array::from_fn(|i| padding_out[i]))
+}
+
+/// The words of the padding block absorbed when the message is a multiple of the rate.
+fn padding_word(i: usize) -> BinaryField32b {
+	let mut block = [0u8; RATE_SIZE * 4];
+	block[0] = 0x80;
+	block[RATE_SIZE * 4 - 1] |= 0x01;
+	BinaryField32b::new(u32::from_le_bytes(
+		block[i * 4..i * 4 + 4]
+			.try_into()
+			.expect("chunk is 4 bytes"),
+	))
+}
+
 const N_ROUNDS: usize = 8;
-const STATE_SIZE: usize = 24;
+
+/// The number of 32-bit words in the Vision Mark-32 state.
+pub const STATE_SIZE: usize = 24;
+
+/// The number of 32-bit words in the rate of the Vision Mark-32 sponge.
+pub const RATE_SIZE: usize = 16;
+
+const DIGEST_SIZE: usize = 8;
 
 #[rustfmt::skip]
 const VISION_RC_EVEN: [[u32; 8]; STATE_SIZE] = [
@@ -341,16 +427,18 @@ where {
 			array_util::try_from_fn(|i| witness.get::<B32>(perm_in[i]))?;
 		let perm_in_data: [_; STATE_SIZE] = perm_in_data_owned.map(|elem| elem.as_slice::<B32>());
 
-		let mut even_round_consts = even_round_consts.map(|id| witness.new_column::<B32>(id));
-		let mut inv_0 = inv_0.map(|id| witness.new_column::<B32>(id));
-		let mut s_box_out_0 = s_box_out_0.map(|id| witness.new_column::<B32>(id));
-		let mut mds_out_0 = mds_out_0.map(|id| witness.new_column::<B32>(id));
-		let mut round_out_0 = round_out_0.map(|id| witness.new_column::<B32>(id));
-		let mut odd_round_consts = odd_round_consts.map(|id| witness.new_column::<B32>(id));
-		let mut inv_1 = inv_1.map(|id| witness.new_column::<B32>(id));
-		let mut s_box_out_1 = s_box_out_1.map(|id| witness.new_column::<B32>(id));
-		let mut mds_out_1 = mds_out_1.map(|id| witness.new_column::<B32>(id));
-		let mut perm_out = perm_out.map(|id| witness.new_column::<B32>(id));
+		let mut even_round_consts =
+			array_util::try_map(even_round_consts, |id| witness.new_column::<B32>(id))?;
+		let mut inv_0 = array_util::try_map(inv_0, |id| witness.new_column::<B32>(id))?;
+		let mut s_box_out_0 = array_util::try_map(s_box_out_0, |id| witness.new_column::<B32>(id))?;
+		let mut mds_out_0 = array_util::try_map(mds_out_0, |id| witness.new_column::<B32>(id))?;
+		let mut round_out_0 = array_util::try_map(round_out_0, |id| witness.new_column::<B32>(id))?;
+		let mut odd_round_consts =
+			array_util::try_map(odd_round_consts, |id| witness.new_column::<B32>(id))?;
+		let mut inv_1 = array_util::try_map(inv_1, |id| witness.new_column::<B32>(id))?;
+		let mut s_box_out_1 = array_util::try_map(s_box_out_1, |id| witness.new_column::<B32>(id))?;
+		let mut mds_out_1 = array_util::try_map(mds_out_1, |id| witness.new_column::<B32>(id))?;
+		let mut perm_out = array_util::try_map(perm_out, |id| witness.new_column::<B32>(id))?;
 
 		let inv_0_slice = inv_0.each_mut().map(|elem| elem.as_mut_slice());
 		let s_box_out_0_slice = s_box_out_0.each_mut().map(|elem| elem.as_mut_slice());
@@ -478,8 +566,11 @@ where {
 mod tests {
 	use binius_core::oracle::OracleId;
 	use binius_field::BinaryField32b;
+	use binius_hash::{PseudoCompressionFunction, Vision32Compression, VisionHasherDigest};
+	use digest::Output;
+	use rand::{Rng, SeedableRng, rngs::StdRng};
 
-	use super::vision_permutation;
+	use super::{vision_compression, vision_permutation};
 	use crate::{builder::test_utils::test_circuit, unconstrained::unconstrained};
 
 	#[test]
@@ -494,4 +585,48 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	#[test]
+	fn test_vision32b_compression() {
+		test_circuit(|builder| {
+			let log_size = 5;
+			let mut rng = StdRng::seed_from_u64(0);
+			let messages = (0..1 << log_size)
+				.map(|_| rng.r#gen::<[u32; 16]>())
+				.collect::<Vec<_>>();
+
+			let input: [OracleId; 16] = std::array::from_fn(|i| {
+				builder.add_committed(format!("input_{i}"), log_size, BinaryField32b::TOWER_LEVEL)
+			});
+			if let Some(witness) = builder.witness() {
+				for (i, &id) in input.iter().enumerate() {
+					let mut column = witness.new_column::<BinaryField32b>(id)?;
+					for (word, message) in column.as_mut_slice::<u32>().iter_mut().zip(&messages) {
+						*word = message[i];
+					}
+				}
+			}
+
+			let output = vision_compression(builder, log_size, input)?;
+
+			if let Some(witness) = builder.witness() {
+				for (z, message) in messages.iter().enumerate() {
+					let bytes = message
+						.iter()
+						.flat_map(|word| word.to_le_bytes())
+						.collect::<Vec<_>>();
+					let expected = Vision32Compression.compress([
+						Output::<VisionHasherDigest>::clone_from_slice(&bytes[..32]),
+						Output::<VisionHasherDigest>::clone_from_slice(&bytes[32..]),
+					]);
+					for (i, &id) in output.iter().enumerate() {
+						let word = witness.get::<BinaryField32b>(id)?.as_slice::<u32>()[z];
+						assert_eq!(word.to_le_bytes(), expected[i * 4..i * 4 + 4]);
+					}
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
 }
@@ -9,7 +9,8 @@ use binius_hash::{
 	groestl::{Groestl256, Groestl256ByteCompression},
 	multi_digest::ParallelDigest,
 };
-use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use binius_maybe_rayon::{ThreadPoolBuilder, current_num_threads};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
 use digest::{FixedOutputReset, Output, core_api::BlockSizeUser};
 use rand::thread_rng;
 
@@ -55,5 +56,40 @@ fn bench_vision_merkle_tree(c: &mut Criterion) {
 	);
 }
 
+/// Measures the scaling of the tree construction with the number of threads, up to the number of
+/// threads of the global thread pool.
+fn bench_merkle_tree_thread_scaling(c: &mut Criterion) {
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let mut rng = thread_rng();
+	let data: Vec<F> = repeat_with(|| Field::random(&mut rng))
+		.take(1 << (LOG_ELEMS + LOG_ELEMS_IN_LEAF))
+		.collect();
+	let mut group = c.benchmark_group("slow/merkle_tree/thread_scaling/Grøstl-256");
+	group.throughput(Throughput::Bytes(
+		((1 << (LOG_ELEMS + LOG_ELEMS_IN_LEAF)) * std::mem::size_of::<F>()) as u64,
+	));
+	group.sample_size(10);
+	let max_threads = current_num_threads();
+	for n_threads in (0..)
+		.map(|i| 1 << i)
+		.take_while(|&n| n < max_threads)
+		.chain([max_threads])
+	{
+		let pool = ThreadPoolBuilder::new()
+			.num_threads(n_threads)
+			.build()
+			.unwrap();
+		group.bench_with_input(BenchmarkId::new("threads", n_threads), &n_threads, |b, _| {
+			b.iter(|| pool.install(|| merkle_prover.commit(&data, 1 << LOG_ELEMS_IN_LEAF)));
+		});
+	}
+	group.finish()
+}
+
 criterion_main!(binary_merkle_tree);
-criterion_group!(binary_merkle_tree, bench_groestl_merkle_tree, bench_vision_merkle_tree);
+criterion_group!(
+	binary_merkle_tree,
+	bench_groestl_merkle_tree,
+	bench_vision_merkle_tree,
+	bench_merkle_tree_thread_scaling
+);
@@ -0,0 +1,96 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_core::{
+	composition::ProductComposition,
+	protocols::sumcheck::{prove::univariate::zerocheck_univariate_evals, zerocheck::domain_size},
+};
+use binius_field::{
+	BinaryField1b, BinaryField8b, BinaryField16b, BinaryField128b, Field, PackedField, TowerField,
+	arch::OptimalUnderlier,
+	as_packed_field::{PackScalar, PackedType},
+};
+use binius_hal::make_portable_backend;
+use binius_math::{MLEEmbeddingAdapter, MultilinearExtension};
+use criterion::{Criterion, criterion_group, criterion_main};
+use rand::{Rng, thread_rng};
+
+type U = OptimalUnderlier;
+type F = BinaryField128b;
+type FDomain = BinaryField8b;
+type FBase = BinaryField16b;
+
+const N_VARS: usize = 20;
+const SKIP_ROUNDS: usize = 6;
+
+/// Embeds columns of bits into multilinears over the field `FColumn`.
+fn column_multilinears<FColumn>(
+	columns: &[Vec<bool>],
+) -> Vec<MLEEmbeddingAdapter<PackedType<U, FColumn>, PackedType<U, F>>>
+where
+	FColumn: TowerField,
+	U: PackScalar<FColumn>,
+{
+	columns
+		.iter()
+		.map(|bits| {
+			let values = bits
+				.chunks(PackedType::<U, FColumn>::WIDTH)
+				.map(|bits| {
+					PackedType::<U, FColumn>::from_fn(|i| {
+						if bits[i] { FColumn::ONE } else { FColumn::ZERO }
+					})
+				})
+				.collect::<Vec<_>>();
+			MultilinearExtension::new(N_VARS, values)
+				.unwrap()
+				.specialize::<PackedType<U, F>>()
+		})
+		.collect()
+}
+
+/// Compares the univariate skip round of a degree-3 zerocheck, as in the AND constraints of the
+/// `mul` gadget, over 1-bit committed columns and over the same bits in 8-bit columns.
+fn zerocheck_univariate_round(c: &mut Criterion) {
+	let mut rng = thread_rng();
+	let columns = repeat_with(|| {
+		repeat_with(|| rng.r#gen::<bool>())
+			.take(1 << N_VARS)
+			.collect::<Vec<_>>()
+	})
+	.take(3)
+	.collect::<Vec<_>>();
+	let zerocheck_challenges = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(N_VARS - SKIP_ROUNDS)
+		.collect::<Vec<_>>();
+	let compositions = [ProductComposition::<3> {}];
+	let backend = make_portable_backend();
+
+	let mut group = c.benchmark_group("ZerocheckUnivariateRound");
+	macro_rules! bench_columns {
+		($name:literal, $field:ty) => {
+			let multilinears = column_multilinears::<$field>(&columns);
+			group.bench_function(format!("{}/n_vars={N_VARS}", $name), |b| {
+				b.iter(|| {
+					zerocheck_univariate_evals::<F, FDomain, FBase, PackedType<U, F>, _, _, _>(
+						&multilinears,
+						&compositions,
+						&zerocheck_challenges,
+						SKIP_ROUNDS,
+						domain_size(3, SKIP_ROUNDS),
+						&backend,
+					)
+					.unwrap()
+				})
+			});
+		};
+	}
+	bench_columns!("BinaryField1b", BinaryField1b);
+	bench_columns!("BinaryField8b", BinaryField8b);
+	group.finish();
+}
+
+criterion_group!(zerocheck_benches, zerocheck_univariate_round);
+
+criterion_main!(zerocheck_benches);
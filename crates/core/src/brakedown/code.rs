@@ -0,0 +1,223 @@
+// Copyright 2025 Irreducible Inc.
+
+//! [Brakedown] codes over binary fields.
+//!
+//! See [`BrakedownCode`] for details.
+//!
+//! [Brakedown]: <https://eprint.iacr.org/2021/1043>
+
+use binius_field::{BinaryField, ExtensionField};
+use binius_math::BinarySubspace;
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+use getset::CopyGetters;
+use rand::{SeedableRng, rngs::StdRng};
+
+use super::{error::Error, matrix::SparseMatrix, params::BrakedownParams};
+use crate::reed_solomon::LinearCode;
+
+/// Messages up to this length are encoded directly with a Reed–Solomon code.
+const BASE_DIM: usize = 32;
+
+/// [Brakedown] codes over binary fields.
+///
+/// The Brakedown code is a systematic linear code with linear-time encoding, built from random
+/// sparse expander matrices as in [GLSTW21]. A message $x$ of length $n$ is encoded as
+/// $(x, z, v)$, where $z$ is the recursive encoding of $x A$ and $v = z B$ for the sparse
+/// matrices $A$ and $B$. Messages of at most 32 elements are encoded with a Reed–Solomon code.
+///
+/// The matrices are sampled from a seeded PRNG, so the prover and verifier must construct the
+/// code with the same seed. The relative distance bound of [`BrakedownParams::relative_distance`]
+/// holds with overwhelming probability over the seed.
+///
+/// Compared to [`crate::reed_solomon::ReedSolomonCode`], encoding takes linear rather than
+/// quasi-linear time, at the cost of a lower distance and hence more test queries.
+///
+/// [Brakedown]: <https://eprint.iacr.org/2021/1043>
+/// [GLSTW21]: <https://eprint.iacr.org/2021/1043>
+#[derive(Debug, CopyGetters)]
+pub struct BrakedownCode<F: BinaryField> {
+	#[get_copy = "pub"]
+	params: BrakedownParams,
+	log_dimension: usize,
+	encoder: Encoder<F>,
+}
+
+impl<F: BinaryField> BrakedownCode<F> {
+	/// Constructs a code of dimension `1 << log_dimension`, sampling its matrices with `seed`.
+	pub fn new(params: BrakedownParams, log_dimension: usize, seed: u64) -> Result<Self, Error> {
+		let mut rng = StdRng::seed_from_u64(seed);
+		let encoder = Encoder::new(&params, 1 << log_dimension, &mut rng)?;
+		Ok(Self {
+			params,
+			log_dimension,
+			encoder,
+		})
+	}
+
+	/// Encodes a message of extension field elements.
+	///
+	/// ## Throws
+	///
+	/// * If the message length is not the dimension of the code.
+	pub fn encode<FE: ExtensionField<F>>(&self, message: &[FE]) -> Result<Vec<FE>, Error> {
+		if message.len() != LinearCode::dim(self) {
+			return Err(Error::IncorrectBufferLength {
+				expected: LinearCode::dim(self),
+				actual: message.len(),
+			});
+		}
+		let mut codeword = vec![FE::ZERO; LinearCode::len(self)];
+		self.encoder.encode_into(message, &mut codeword);
+		Ok(codeword)
+	}
+}
+
+impl<F: BinaryField> LinearCode for BrakedownCode<F> {
+	type Field = F;
+
+	fn log_dim(&self) -> usize {
+		self.log_dimension
+	}
+
+	fn log_len(&self) -> usize {
+		self.log_dimension + self.params.log_inv_rate()
+	}
+
+	fn min_dist(&self) -> usize {
+		(self.relative_distance() * LinearCode::len(self) as f64).ceil() as usize
+	}
+
+	fn relative_distance(&self) -> f64 {
+		self.params.relative_distance()
+	}
+}
+
+/// The encoder for messages of a fixed length.
+#[derive(Debug)]
+enum Encoder<F> {
+	/// Evaluates the message, as polynomial coefficients, at distinct points.
+	ReedSolomon { points: Vec<F> },
+	Expander {
+		dim: usize,
+		inner_dim: usize,
+		first: SparseMatrix<F>,
+		inner: Box<Encoder<F>>,
+		second: SparseMatrix<F>,
+	},
+}
+
+impl<F: BinaryField> Encoder<F> {
+	fn new(params: &BrakedownParams, dim: usize, rng: &mut StdRng) -> Result<Self, Error> {
+		let inv_rate = 1 << params.log_inv_rate();
+		if dim <= BASE_DIM {
+			let subspace = BinarySubspace::<F>::with_dim(log2_ceil_usize(dim * inv_rate))?;
+			let points = subspace.iter().take(dim * inv_rate).collect();
+			return Ok(Self::ReedSolomon { points });
+		}
+
+		let inner_dim = (params.alpha() * dim as f64).ceil() as usize;
+		let Some(second_cols) = (dim * (inv_rate - 1)).checked_sub(inner_dim * inv_rate) else {
+			return Err(Error::AlphaTooLargeForDim {
+				alpha: params.alpha(),
+				dim,
+			});
+		};
+		let first = SparseMatrix::random(dim, inner_dim, params.first_degree(dim), &mut *rng);
+		let inner = Self::new(params, inner_dim, rng)?;
+		let second = SparseMatrix::random(
+			inner_dim * inv_rate,
+			second_cols,
+			params.second_degree(dim, F::N_BITS),
+			&mut *rng,
+		);
+		Ok(Self::Expander {
+			dim,
+			inner_dim,
+			first,
+			inner: Box::new(inner),
+			second,
+		})
+	}
+
+	/// Encodes `message` into `codeword`, which must have `inv_rate` times its length.
+	fn encode_into<FE: ExtensionField<F>>(&self, message: &[FE], codeword: &mut [FE]) {
+		match self {
+			Self::ReedSolomon { points } => {
+				for (value, &point) in codeword.iter_mut().zip(points) {
+					*value = message
+						.iter()
+						.rev()
+						.fold(FE::ZERO, |acc, &coeff| acc * point + coeff);
+				}
+			}
+			Self::Expander {
+				dim,
+				inner_dim,
+				first,
+				inner,
+				second,
+			} => {
+				let inv_rate = codeword.len() / dim;
+				let (systematic, rest) = codeword.split_at_mut(*dim);
+				systematic.copy_from_slice(message);
+
+				let mut inner_message = vec![FE::ZERO; *inner_dim];
+				first.mul_vec_into(message, &mut inner_message);
+				let (inner_codeword, redundancy) = rest.split_at_mut(inner_dim * inv_rate);
+				inner.encode_into(&inner_message, inner_codeword);
+				second.mul_vec_into(inner_codeword, redundancy);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField32b, BinaryField128b, Field};
+
+	use super::*;
+	use crate::{protocols::fri::calculate_n_test_queries, reed_solomon::ReedSolomonCode};
+
+	type F = BinaryField32b;
+
+	#[test]
+	fn test_encoding_is_systematic_and_linear() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let code = BrakedownCode::<F>::new(BrakedownParams::RATE_HALF, 10, 0).unwrap();
+		assert_eq!(LinearCode::len(&code), 1 << 11);
+
+		let x = repeat_with(|| BinaryField128b::random(&mut rng))
+			.take(1 << 10)
+			.collect::<Vec<_>>();
+		let y = repeat_with(|| BinaryField128b::random(&mut rng))
+			.take(1 << 10)
+			.collect::<Vec<_>>();
+		let sum = x.iter().zip(&y).map(|(&x, &y)| x + y).collect::<Vec<_>>();
+
+		let x_codeword = code.encode(&x).unwrap();
+		let y_codeword = code.encode(&y).unwrap();
+		let sum_codeword = code.encode(&sum).unwrap();
+		assert_eq!(&x_codeword[..1 << 10], x.as_slice());
+		for ((x, y), sum) in x_codeword.iter().zip(&y_codeword).zip(&sum_codeword) {
+			assert_eq!(*x + *y, *sum);
+		}
+
+		assert_matches!(code.encode(&x[1..]), Err(Error::IncorrectBufferLength { .. }));
+	}
+
+	#[test]
+	fn test_n_test_queries() {
+		let security_bits = 96;
+		let code = BrakedownCode::<F>::new(BrakedownParams::RATE_HALF, 12, 0).unwrap();
+		let rs_code = ReedSolomonCode::<F>::new(12, 1).unwrap();
+		let n_test_queries =
+			calculate_n_test_queries::<BinaryField128b, _>(security_bits, &code).unwrap();
+		assert!(
+			n_test_queries
+				> calculate_n_test_queries::<BinaryField128b, _>(security_bits, &rs_code).unwrap()
+		);
+	}
+}
@@ -0,0 +1,21 @@
+// Copyright 2025 Irreducible Inc.
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("incorrect buffer length: expected {expected}, got {actual}")]
+	IncorrectBufferLength { expected: usize, actual: usize },
+	#[error("the rate must be less than 1")]
+	RateNotBelowOne,
+	#[error("expected 0 < 1.28 beta < alpha < 1, got alpha = {alpha}, beta = {beta}")]
+	InvalidAlphaBeta { alpha: f64, beta: f64 },
+	#[error("alpha = {alpha} and beta = {beta} are too large for inverse rate 2^{log_inv_rate}")]
+	AlphaBetaTooLargeForRate {
+		alpha: f64,
+		beta: f64,
+		log_inv_rate: usize,
+	},
+	#[error("alpha = {alpha} is too large for messages of length {dim}")]
+	AlphaTooLargeForDim { alpha: f64, dim: usize },
+	#[error("math error: {0}")]
+	Math(#[from] binius_math::Error),
+}
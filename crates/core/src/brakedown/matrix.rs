@@ -0,0 +1,74 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{ExtensionField, Field};
+use rand::Rng;
+
+/// A random sparse matrix with a fixed number of non-zero entries in each row.
+#[derive(Debug, Clone)]
+pub struct SparseMatrix<F> {
+	n_rows: usize,
+	n_cols: usize,
+	degree: usize,
+	/// The column indices of the non-zero entries, `degree` per row.
+	cols: Vec<usize>,
+	/// The values of the non-zero entries, `degree` per row.
+	values: Vec<F>,
+}
+
+impl<F: Field> SparseMatrix<F> {
+	/// Samples a matrix whose rows have `degree` non-zero entries at distinct random columns.
+	///
+	/// The degree is capped at the number of columns.
+	pub fn random(n_rows: usize, n_cols: usize, degree: usize, mut rng: impl Rng) -> Self {
+		let degree = degree.min(n_cols);
+		let mut cols = Vec::with_capacity(n_rows * degree);
+		let mut values = Vec::with_capacity(n_rows * degree);
+		for _ in 0..n_rows {
+			let row_start = cols.len();
+			while cols.len() - row_start < degree {
+				let col = rng.gen_range(0..n_cols);
+				if !cols[row_start..].contains(&col) {
+					cols.push(col);
+				}
+			}
+			values.extend((0..degree).map(|_| {
+				loop {
+					let value = F::random(&mut rng);
+					if value != F::ZERO {
+						break value;
+					}
+				}
+			}));
+		}
+		Self {
+			n_rows,
+			n_cols,
+			degree,
+			cols,
+			values,
+		}
+	}
+
+	/// Writes the product `x^T M` of the row vector `x` with the matrix into `out`.
+	///
+	/// ## Preconditions
+	///
+	/// * `x.len()` must equal the number of rows and `out.len()` the number of columns.
+	pub fn mul_vec_into<FE: ExtensionField<F>>(&self, x: &[FE], out: &mut [FE]) {
+		debug_assert_eq!(x.len(), self.n_rows);
+		debug_assert_eq!(out.len(), self.n_cols);
+		out.fill(FE::ZERO);
+		if self.degree == 0 {
+			return;
+		}
+		for ((&x_i, cols), values) in x
+			.iter()
+			.zip(self.cols.chunks_exact(self.degree))
+			.zip(self.values.chunks_exact(self.degree))
+		{
+			for (&col, &value) in cols.iter().zip(values) {
+				out[col] += x_i * value;
+			}
+		}
+	}
+}
@@ -0,0 +1,16 @@
+// Copyright 2025 Irreducible Inc.
+
+//! [Brakedown] expander codes over binary fields.
+//!
+//! See [`BrakedownCode`] for details.
+//!
+//! [Brakedown]: <https://eprint.iacr.org/2021/1043>
+
+pub mod code;
+mod error;
+mod matrix;
+pub mod params;
+
+pub use code::*;
+pub use error::*;
+pub use params::*;
@@ -0,0 +1,149 @@
+// Copyright 2025 Irreducible Inc.
+
+use getset::CopyGetters;
+
+use super::error::Error;
+
+/// Parameters of a [Brakedown] code.
+///
+/// A message of length $n$ is encoded by multiplying it with a sparse $n \times \alpha n$ matrix,
+/// encoding the result recursively at the same rate, and multiplying that codeword with a second
+/// sparse matrix. The number of non-zero entries per row of the two matrices, $c_n$ and $d_n$, is
+/// chosen by the formulas of [GLSTW21], Section 4, for the sampled matrices to be good enough
+/// expanders with overwhelming probability. The code then has relative distance at least
+/// $\beta / r$, where $r$ is the inverse rate.
+///
+/// [Brakedown]: <https://eprint.iacr.org/2021/1043>
+/// [GLSTW21]: <https://eprint.iacr.org/2021/1043>
+#[derive(Debug, Clone, Copy, PartialEq, CopyGetters)]
+#[get_copy = "pub"]
+pub struct BrakedownParams {
+	/// The ratio between the length of the recursively encoded message and the message.
+	alpha: f64,
+	/// The expansion parameter, which bounds the relative distance from below.
+	beta: f64,
+	log_inv_rate: usize,
+}
+
+impl BrakedownParams {
+	/// Parameters for codes of rate 1/2.
+	pub const RATE_HALF: Self = Self {
+		alpha: 0.238,
+		beta: 0.1205,
+		log_inv_rate: 1,
+	};
+
+	/// Parameters for codes of rate 1/4.
+	pub const RATE_QUARTER: Self = Self {
+		alpha: 0.4,
+		beta: 0.2,
+		log_inv_rate: 2,
+	};
+
+	pub fn new(alpha: f64, beta: f64, log_inv_rate: usize) -> Result<Self, Error> {
+		let params = Self {
+			alpha,
+			beta,
+			log_inv_rate,
+		};
+		if log_inv_rate == 0 {
+			return Err(Error::RateNotBelowOne);
+		}
+		if !(0.0 < alpha && alpha < 1.0 && 0.0 < beta && 1.28 * beta < alpha) {
+			return Err(Error::InvalidAlphaBeta { alpha, beta });
+		}
+		if params.mu() <= params.nu() {
+			return Err(Error::AlphaBetaTooLargeForRate {
+				alpha,
+				beta,
+				log_inv_rate,
+			});
+		}
+		Ok(params)
+	}
+
+	/// The parameters of the presets for the given inverse rate, if any.
+	pub fn for_log_inv_rate(log_inv_rate: usize) -> Option<Self> {
+		match log_inv_rate {
+			1 => Some(Self::RATE_HALF),
+			2 => Some(Self::RATE_QUARTER),
+			_ => None,
+		}
+	}
+
+	pub fn inv_rate(&self) -> f64 {
+		(1usize << self.log_inv_rate) as f64
+	}
+
+	/// The lower bound $\beta / r$ on the relative distance of the code.
+	pub fn relative_distance(&self) -> f64 {
+		self.beta / self.inv_rate()
+	}
+
+	/// The number of non-zero entries per row of the first matrix, $c_n$, for messages of length
+	/// `n`.
+	pub fn first_degree(&self, n: usize) -> usize {
+		let n = n as f64;
+		let Self { alpha, beta, .. } = *self;
+		let bound = (110.0 / n + entropy(beta) + alpha * entropy(1.28 * beta / alpha))
+			/ (beta * (alpha / (1.28 * beta)).log2());
+		(1.28 * beta * n).max(beta * n + 4.0).min(bound).ceil() as usize
+	}
+
+	/// The number of non-zero entries per row of the second matrix, $d_n$, for messages of
+	/// length `n` over a field with `field_bits` bits.
+	pub fn second_degree(&self, n: usize, field_bits: usize) -> usize {
+		let n = n as f64;
+		let Self { alpha, beta, .. } = *self;
+		let r = self.inv_rate();
+		let (mu, nu) = (self.mu(), self.nu());
+		let bound = (r * alpha * entropy(beta / r) + mu * entropy(nu / mu) + 110.0 / n)
+			/ (alpha * beta * (mu / nu).log2());
+		((2.0 * beta + (r - 1.0 + 110.0 / n) / field_bits as f64) * n)
+			.min(bound)
+			.ceil() as usize
+	}
+
+	fn mu(&self) -> f64 {
+		self.inv_rate() - 1.0 - self.inv_rate() * self.alpha
+	}
+
+	fn nu(&self) -> f64 {
+		self.beta + self.alpha * self.beta + 0.03
+	}
+}
+
+/// The binary entropy function.
+fn entropy(p: f64) -> f64 {
+	if p <= 0.0 || p >= 1.0 {
+		return 0.0;
+	}
+	-p * p.log2() - (1.0 - p) * (1.0 - p).log2()
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+
+	use super::*;
+
+	#[test]
+	fn test_presets_are_valid() {
+		for log_inv_rate in 1..=2 {
+			let preset = BrakedownParams::for_log_inv_rate(log_inv_rate).unwrap();
+			let params = BrakedownParams::new(preset.alpha(), preset.beta(), log_inv_rate).unwrap();
+			assert_eq!(params, preset);
+			assert!(params.first_degree(1 << 20) > 0);
+			assert!(params.second_degree(1 << 20, 128) > 0);
+		}
+		assert_matches!(
+			BrakedownParams::new(0.5, 0.2, 1),
+			Err(Error::AlphaBetaTooLargeForRate {
+				log_inv_rate: 1,
+				..
+			})
+		);
+		assert_matches!(BrakedownParams::new(0.1, 0.1, 1), Err(Error::InvalidAlphaBeta { .. }));
+		assert_matches!(BrakedownParams::new(0.5, 0.2, 0), Err(Error::RateNotBelowOne));
+	}
+}
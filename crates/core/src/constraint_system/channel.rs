@@ -75,6 +75,11 @@ pub struct Flush<F: TowerField> {
 	pub multiplicity: u64,
 }
 
+/// Values flushed to a channel by the statement rather than by the witness.
+///
+/// Boundaries are the public inputs and outputs of a constraint system. The prover and verifier
+/// observe them in the transcript before any prover message, and they take part in the balancing
+/// of their channel, so a proof only verifies against the boundaries it was generated for.
 #[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 pub struct Boundary<F: TowerField> {
 	pub values: Vec<F>,
@@ -83,6 +88,37 @@ pub struct Boundary<F: TowerField> {
 	pub multiplicity: u64,
 }
 
+impl<F: TowerField> Boundary<F> {
+	/// A boundary pushing `values` to the channel once, as a public input of the statement.
+	pub fn push(channel_id: ChannelId, values: impl IntoIterator<Item = impl Into<F>>) -> Self {
+		Self::new(channel_id, FlushDirection::Push, values)
+	}
+
+	/// A boundary pulling `values` from the channel once, as a public output of the statement.
+	pub fn pull(channel_id: ChannelId, values: impl IntoIterator<Item = impl Into<F>>) -> Self {
+		Self::new(channel_id, FlushDirection::Pull, values)
+	}
+
+	/// Sets the number of times the values are flushed.
+	pub const fn with_multiplicity(mut self, multiplicity: u64) -> Self {
+		self.multiplicity = multiplicity;
+		self
+	}
+
+	fn new(
+		channel_id: ChannelId,
+		direction: FlushDirection,
+		values: impl IntoIterator<Item = impl Into<F>>,
+	) -> Self {
+		Self {
+			values: values.into_iter().map(Into::into).collect(),
+			channel_id,
+			direction,
+			multiplicity: 1,
+		}
+	}
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 pub enum FlushDirection {
 	Push,
@@ -1,6 +1,18 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::tower::{ProverTowerFamily, TowerFamily};
+use binius_field::{
+	TowerField,
+	tower::{ProverTowerFamily, TowerFamily},
+};
+use bytes::BufMut;
+
+use super::error::Error;
+use crate::{
+	oracle::{CommitBatch, MultilinearOracleSet},
+	piop::eval_claims::EvalClaimAccumulator,
+	protocols::evalcheck::EvalcheckMultilinearClaim,
+	transcript::{TranscriptWriter, write_u64},
+};
 
 /// The cryptographic extension field that the constraint system protocol is defined over.
 pub type FExt<Tower> = <Tower as TowerFamily>::B128;
@@ -20,3 +32,51 @@ pub type FDomain<Tower> = <Tower as TowerFamily>::B8;
 /// This is fixed to be 32-bits, which is large enough to handle trace sizes up to 512 GiB
 /// of committed data.
 pub type FEncode<Tower> = <Tower as TowerFamily>::B32;
+
+/// Observes the application context that a proof is bound to.
+///
+/// Nothing is observed for the empty context, so the proofs that are not bound to a context have
+/// the same transcript as before contexts were introduced. A non-empty context is prefixed with
+/// its length, so that distinct contexts are never observed as the same bytes together with the
+/// boundaries that follow.
+pub fn observe_application_context<B: BufMut>(
+	writer: &mut TranscriptWriter<B>,
+	application_context: &[u8],
+) {
+	if application_context.is_empty() {
+		return;
+	}
+	write_u64(writer, application_context.len() as u64);
+	writer.write_bytes(application_context);
+}
+
+/// Groups the evaluation claims on committed oracles by the commit batch of the oracles.
+///
+/// The claims of each batch are reduced and opened against the commitment of that batch. They are
+/// collected in an [`EvalClaimAccumulator`] per batch, so a claim that is made several times is
+/// opened once, and conflicting claims on the same oracle and point are rejected.
+pub fn eval_claims_by_commit_batch<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	commit_batches: &[CommitBatch],
+	eval_claims: Vec<EvalcheckMultilinearClaim<F>>,
+) -> Result<Vec<Vec<EvalcheckMultilinearClaim<F>>>, Error> {
+	let mut accumulators = vec![EvalClaimAccumulator::new(); commit_batches.len()];
+	let mut eval_claims_by_batch = vec![Vec::new(); commit_batches.len()];
+	for eval_claim in eval_claims {
+		let batch = oracles.commit_batch(eval_claim.id);
+		let index = commit_batches
+			.iter()
+			.position(|&commit_batch| commit_batch == batch)
+			.expect("evaluation claims are on committed oracles");
+		let opening = accumulators[index].add(
+			eval_claim.id.index(),
+			&eval_claim.eval_point,
+			eval_claim.eval,
+		)?;
+		// The accumulator returns the index of a new opening only for a claim not made before.
+		if opening == eval_claims_by_batch[index].len() {
+			eval_claims_by_batch[index].push(eval_claim);
+		}
+	}
+	Ok(eval_claims_by_batch)
+}
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::fmt;
+
 use super::channel::ChannelId;
 use crate::{
 	oracle,
@@ -111,6 +113,99 @@ pub enum Error {
 
 	#[error("gkr exp error: {0}")]
 	GkrExp(#[from] crate::protocols::gkr_exp::Error),
+
+	#[error("invalid security level: {reason}")]
+	InvalidSecurityLevel { reason: String },
+
+	#[error("the checkpoint of commit batch {batch} does not match the rebuilt commitments")]
+	CheckpointMismatch { batch: usize },
+
+	#[error("batch verification got {proofs} proofs but {boundaries} sets of boundaries")]
+	BatchSizeMismatch { proofs: usize, boundaries: usize },
+
+	#[error(
+		"batch verification got {proofs} proofs but {application_contexts} application contexts"
+	)]
+	BatchApplicationContextsMismatch {
+		proofs: usize,
+		application_contexts: usize,
+	},
+
+	#[error("proof {index} of the batch failed verification: {source}")]
+	BatchProofInvalid {
+		index: usize,
+		#[source]
+		source: Box<Error>,
+	},
+
+	#[error("{stage} failed: {source}")]
+	Stage {
+		stage: ProofStage,
+		#[source]
+		source: Box<Error>,
+	},
+}
+
+impl Error {
+	/// Returns the protocol stage in which the error occurred, if it is known.
+	pub fn stage(&self) -> Option<ProofStage> {
+		match self {
+			Self::Stage { stage, .. } => Some(*stage),
+			Self::BatchProofInvalid { source, .. } => source.stage(),
+			_ => None,
+		}
+	}
+
+	/// Returns the underlying error, without the stage and batch context.
+	///
+	/// Match on the result to handle error kinds independently of where they were raised.
+	pub fn root(&self) -> &Self {
+		match self {
+			Self::Stage { source, .. } | Self::BatchProofInvalid { source, .. } => source.root(),
+			_ => self,
+		}
+	}
+}
+
+/// A stage of the constraint system protocol, attached to errors by [`InStage::in_stage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofStage {
+	GkrExp,
+	GrandProduct,
+	ChannelBalance,
+	Zerocheck,
+	Evalcheck,
+	RingSwitch,
+	PcsOpening,
+}
+
+impl fmt::Display for ProofStage {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Self::GkrExp => "GKR exponentiation",
+			Self::GrandProduct => "grand product argument",
+			Self::ChannelBalance => "channel balancing",
+			Self::Zerocheck => "zerocheck",
+			Self::Evalcheck => "evalcheck",
+			Self::RingSwitch => "ring switching",
+			Self::PcsOpening => "PCS opening",
+		};
+		f.write_str(name)
+	}
+}
+
+/// Extension trait attaching a [`ProofStage`] to the error of a result.
+pub trait InStage<T> {
+	fn in_stage(self, stage: ProofStage) -> Result<T, Error>;
+}
+
+impl<T, E: Into<Error>> InStage<T> for Result<T, E> {
+	fn in_stage(self, stage: ProofStage) -> Result<T, Error> {
+		self.map_err(|err| Error::Stage {
+			stage,
+			source: Box::new(err.into()),
+		})
+	}
 }
 
 #[derive(Debug, thiserror::Error)]
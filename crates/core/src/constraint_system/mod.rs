@@ -4,16 +4,28 @@ pub mod channel;
 mod common;
 pub mod error;
 pub mod exp;
+mod proof_size;
 mod prove;
+mod security;
 pub mod validate;
 mod verify;
 
 use binius_field::{BinaryField128b, TowerField};
-use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
+use bytes::{Buf, BufMut};
 use channel::{ChannelId, Flush};
 use exp::Exp;
-pub use prove::prove;
-pub use verify::verify;
+pub use proof_size::{ProofSizeEstimate, estimate_proof_size};
+pub use prove::{
+	CommitCheckpoint, prove, prove_from_checkpoint, prove_in_thread_pool,
+	prove_with_application_context, prove_with_checkpoints, prove_with_commit_phases,
+	prove_with_metrics, prove_with_ntt_context,
+};
+pub use security::SecurityLevel;
+pub use verify::{
+	PreparedVerifier, batch_verify, batch_verify_with_application_contexts, verify,
+	verify_with_application_context,
+};
 
 use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
 
@@ -24,8 +36,19 @@ use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
 ///
 /// As a result, a ConstraintSystem allows us to validate all of these
 /// constraints against a witness, as well as enabling generic prove/verify
-#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
-#[deserialize_bytes(eval_generics(F = BinaryField128b))]
+///
+/// The [`SerializeBytes`] encoding of a constraint system is versioned so that circuits can be
+/// built once and shipped to provers and auditors independently of the code that generated them.
+/// The encoding is
+///
+/// - the 4 magic bytes `b"BNCS"`,
+/// - the format version as a little-endian `u32`,
+/// - the oracles with their commit phases, table constraints, non-zero oracle ids, flushes,
+///   exponentiations and maximum channel id, in this order.
+///
+/// Field elements are encoded according to the [`SerializationMode`], so circuits exchanged
+/// between machines should use [`SerializationMode::CanonicalTower`].
+#[derive(Debug, Clone)]
 pub struct ConstraintSystem<F: TowerField> {
 	pub oracles: MultilinearOracleSet<F>,
 	pub table_constraints: Vec<ConstraintSet<F>>,
@@ -36,19 +59,233 @@ pub struct ConstraintSystem<F: TowerField> {
 }
 
 impl<F: TowerField> ConstraintSystem<F> {
+	/// Magic bytes at the start of every serialized constraint system.
+	pub const MAGIC: [u8; 4] = *b"BNCS";
+
+	/// The version of the binary encoding written by [`SerializeBytes::serialize`].
+	pub const FORMAT_VERSION: u32 = 2;
+
 	pub const fn no_base_constraints(self) -> Self {
 		self
 	}
 }
 
+impl<F: TowerField> SerializeBytes for ConstraintSystem<F> {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		if write_buf.remaining_mut() < Self::MAGIC.len() {
+			return Err(SerializationError::WriteBufferFull);
+		}
+		write_buf.put_slice(&Self::MAGIC);
+		Self::FORMAT_VERSION.serialize(&mut write_buf, mode)?;
+		self.oracles.serialize(&mut write_buf, mode)?;
+		self.table_constraints.serialize(&mut write_buf, mode)?;
+		self.non_zero_oracle_ids.serialize(&mut write_buf, mode)?;
+		self.flushes.serialize(&mut write_buf, mode)?;
+		self.exponents.serialize(&mut write_buf, mode)?;
+		self.max_channel_id.serialize(&mut write_buf, mode)
+	}
+}
+
+impl DeserializeBytes for ConstraintSystem<BinaryField128b> {
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError> {
+		if read_buf.remaining() < Self::MAGIC.len() {
+			return Err(SerializationError::NotEnoughBytes);
+		}
+		let mut magic = [0u8; 4];
+		read_buf.copy_to_slice(&mut magic);
+		if magic != Self::MAGIC {
+			return Err(SerializationError::InvalidConstruction {
+				name: "ConstraintSystem",
+			});
+		}
+
+		let version = u32::deserialize(&mut read_buf, mode)?;
+		if version != Self::FORMAT_VERSION {
+			return Err(SerializationError::UnsupportedVersion {
+				name: "ConstraintSystem",
+				version,
+			});
+		}
+
+		Ok(Self {
+			oracles: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			table_constraints: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			non_zero_oracle_ids: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			flushes: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			exponents: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			max_channel_id: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+		})
+	}
+}
+
 /// Constraint system proof that has been serialized into bytes
-#[derive(Debug, Clone)]
+///
+/// The [`SerializeBytes`] encoding of a proof is versioned so that proofs can be exchanged between
+/// machines and implementations in other languages. The encoding is
+///
+/// - the 4 magic bytes `b"BNPF"`,
+/// - the format version as a little-endian `u32`,
+/// - the transcript length in bytes as a little-endian `u32`,
+/// - the transcript bytes.
+///
+/// The encoding does not depend on the [`SerializationMode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Proof {
 	pub transcript: Vec<u8>,
 }
 
 impl Proof {
+	/// Magic bytes at the start of every serialized proof.
+	pub const MAGIC: [u8; 4] = *b"BNPF";
+
+	/// The version of the binary encoding written by [`SerializeBytes::serialize`].
+	pub const FORMAT_VERSION: u32 = 1;
+
 	pub fn get_proof_size(&self) -> usize {
 		self.transcript.len()
 	}
+
+	/// Returns the size in bytes of the serialized proof, including the header.
+	pub fn serialized_size(&self) -> usize {
+		Self::MAGIC.len() + 2 * std::mem::size_of::<u32>() + self.transcript.len()
+	}
+}
+
+impl SerializeBytes for Proof {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		if write_buf.remaining_mut() < self.serialized_size() {
+			return Err(SerializationError::WriteBufferFull);
+		}
+		write_buf.put_slice(&Self::MAGIC);
+		Self::FORMAT_VERSION.serialize(&mut write_buf, mode)?;
+		self.transcript.len().serialize(&mut write_buf, mode)?;
+		write_buf.put_slice(&self.transcript);
+		Ok(())
+	}
+}
+
+impl DeserializeBytes for Proof {
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError> {
+		if read_buf.remaining() < Self::MAGIC.len() {
+			return Err(SerializationError::NotEnoughBytes);
+		}
+		let mut magic = [0u8; 4];
+		read_buf.copy_to_slice(&mut magic);
+		if magic != Self::MAGIC {
+			return Err(SerializationError::InvalidConstruction { name: "Proof" });
+		}
+
+		let version = u32::deserialize(&mut read_buf, mode)?;
+		if version != Self::FORMAT_VERSION {
+			return Err(SerializationError::UnsupportedVersion {
+				name: "Proof",
+				version,
+			});
+		}
+
+		let len = usize::deserialize(&mut read_buf, mode)?;
+		if read_buf.remaining() < len {
+			return Err(SerializationError::NotEnoughBytes);
+		}
+		let mut transcript = vec![0u8; len];
+		read_buf.copy_to_slice(&mut transcript);
+		Ok(Self { transcript })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_proof_serialization_golden_vector() {
+		let proof = Proof {
+			transcript: vec![0xde, 0xad, 0xbe, 0xef, 0x01],
+		};
+
+		let mut buf = Vec::new();
+		proof
+			.serialize(&mut buf, SerializationMode::CanonicalTower)
+			.unwrap();
+		assert_eq!(
+			buf,
+			[
+				b'B', b'N', b'P', b'F', // magic
+				0x01, 0x00, 0x00, 0x00, // version
+				0x05, 0x00, 0x00, 0x00, // transcript length
+				0xde, 0xad, 0xbe, 0xef, 0x01, // transcript
+			]
+		);
+		assert_eq!(buf.len(), proof.serialized_size());
+
+		let deserialized =
+			Proof::deserialize(buf.as_slice(), SerializationMode::CanonicalTower).unwrap();
+		assert_eq!(deserialized, proof);
+	}
+
+	#[test]
+	fn test_proof_deserialization_rejects_unknown_version() {
+		let buf = [
+			b'B', b'N', b'P', b'F', 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		];
+		assert!(matches!(
+			Proof::deserialize(buf.as_slice(), SerializationMode::Native),
+			Err(SerializationError::UnsupportedVersion { version: 2, .. })
+		));
+	}
+
+	#[test]
+	fn test_proof_deserialization_rejects_truncated_transcript() {
+		let buf = [
+			b'B', b'N', b'P', b'F', 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xff,
+		];
+		assert!(matches!(
+			Proof::deserialize(buf.as_slice(), SerializationMode::Native),
+			Err(SerializationError::NotEnoughBytes)
+		));
+	}
+
+	#[test]
+	fn test_empty_constraint_system_serialization() {
+		let constraint_system = ConstraintSystem::<BinaryField128b> {
+			oracles: MultilinearOracleSet::new(),
+			table_constraints: vec![],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![],
+			exponents: vec![],
+			max_channel_id: 0,
+		};
+
+		let mut buf = Vec::new();
+		constraint_system
+			.serialize(&mut buf, SerializationMode::CanonicalTower)
+			.unwrap();
+		assert_eq!(&buf[..8], [b'B', b'N', b'C', b'S', 0x02, 0x00, 0x00, 0x00]);
+
+		let deserialized =
+			ConstraintSystem::deserialize(buf.as_slice(), SerializationMode::CanonicalTower)
+				.unwrap();
+		assert_eq!(deserialized.oracles.size(), 0);
+		assert_eq!(deserialized.max_channel_id, 0);
+
+		buf[4] = 0x03;
+		assert!(matches!(
+			ConstraintSystem::deserialize(buf.as_slice(), SerializationMode::CanonicalTower),
+			Err(SerializationError::UnsupportedVersion { version: 3, .. })
+		));
+	}
 }
@@ -0,0 +1,355 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::mem::size_of;
+
+use binius_field::{BinaryField, TowerField, tower::TowerFamily};
+use binius_hash::PseudoCompressionFunction;
+use digest::{Digest, Output, core_api::BlockSizeUser};
+
+use super::{
+	ConstraintSystem, Proof, SecurityLevel,
+	channel::OracleOrConst,
+	common::{FDomain, FEncode, FExt},
+	error::Error,
+	verify::max_n_vars_and_skip_rounds,
+};
+use crate::{
+	merkle_tree::BinaryMerkleTreeScheme,
+	oracle::MultilinearPolyVariant,
+	piop,
+	protocols::sumcheck::{constraint_set_zerocheck_claim, zerocheck::domain_size},
+};
+
+/// An estimate of the size in bytes of a proof, broken down by protocol stage.
+///
+/// The stages appear in the transcript in the order of the fields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProofSizeEstimate {
+	/// The commitments to the committed multilinears, one per commit batch.
+	pub commitment: usize,
+	/// The GKR exponentiation argument.
+	pub exp: usize,
+	/// The grand products of the non-zero checks and the channel flushes, with their GKR layers.
+	pub grand_product: usize,
+	/// The batched zerocheck over the table constraints.
+	pub zerocheck: usize,
+	/// The evaluation claims on virtual oracles sent during evalcheck.
+	pub evalcheck: usize,
+	/// The tensor algebra elements and row-batched evaluations of the ring-switching reduction.
+	pub ring_switch: usize,
+	/// The sumcheck interleaved with the FRI folding rounds.
+	pub pcs_sumcheck: usize,
+	/// The FRI round commitments, terminal codeword and query openings.
+	pub fri: usize,
+}
+
+impl ProofSizeEstimate {
+	/// The estimated length of the proof transcript, as returned by [`Proof::get_proof_size`].
+	pub const fn total(&self) -> usize {
+		self.commitment
+			+ self.exp
+			+ self.grand_product
+			+ self.zerocheck
+			+ self.evalcheck
+			+ self.ring_switch
+			+ self.pcs_sumcheck
+			+ self.fri
+	}
+
+	/// The estimated size of the serialized proof, as returned by [`Proof::serialized_size`].
+	pub fn serialized_size(&self) -> usize {
+		Proof { transcript: vec![] }.serialized_size() + self.total()
+	}
+}
+
+/// Estimates the size of a proof for a constraint system without running the prover.
+///
+/// The estimate follows the order in which [`super::verify`] reads the transcript and counts the
+/// field elements and digests of every stage, so it can be used to choose a [`SecurityLevel`].
+/// The sizes of the PCS openings are exact for the FRI parameters derived from the constraint
+/// system. The other stages depend on how the evaluation claims are batched at proving time; they
+/// are estimated assuming one evaluation claim per oracle and one sumcheck per GKR layer and
+/// claim, which makes the estimate an upper bound in practice.
+///
+/// The `Hash` and `Compress` parameters must match those used by [`super::prove`].
+pub fn estimate_proof_size<Tower, Hash, Compress>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+) -> Result<ProofSizeEstimate, Error>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	let ConstraintSystem {
+		oracles,
+		table_constraints,
+		non_zero_oracle_ids,
+		flushes,
+		exponents,
+		..
+	} = constraint_system;
+
+	let scalar_size = size_of::<FExt<Tower>>();
+	let digest_size = <Hash as Digest>::output_size();
+
+	// Each exponentiation layer is a batched sumcheck over the exponents with bits left. With the
+	// eq indicator, a static base layer has degree 3 in 3 multilinears and a dynamic base layer
+	// has degree 5 in 4 multilinears.
+	let max_exponent_bits = exponents
+		.iter()
+		.map(|exp| exp.bits_ids.len())
+		.max()
+		.unwrap_or(0);
+	let exp_layers = (0..max_exponent_bits)
+		.map(|layer_no| {
+			regular_sumcheck_scalars(
+				exponents
+					.iter()
+					.filter(|exp| exp.bits_ids.len() > layer_no)
+					.map(|exp| match exp.base {
+						OracleOrConst::Const { .. } => (exp.n_vars(oracles), 3, 3),
+						OracleOrConst::Oracle(_) => (exp.n_vars(oracles), 5, 4),
+					}),
+			)
+		})
+		.sum::<usize>();
+	let exp = (exponents.len() + exp_layers) * scalar_size;
+
+	// One GKR circuit per grand product, whose layer `i` is a sumcheck over `i` variables of the
+	// product of the two halves of the next layer.
+	let product_n_vars = non_zero_oracle_ids
+		.iter()
+		.map(|&id| oracles.n_vars(id))
+		.chain(flushes.iter().filter_map(|flush| {
+			flush.oracles.iter().find_map(|oracle| match oracle {
+				OracleOrConst::Oracle(id) => Some(oracles.n_vars(*id)),
+				OracleOrConst::Const { .. } => None,
+			})
+		}))
+		.collect::<Vec<_>>();
+	let max_product_n_vars = product_n_vars.iter().copied().max().unwrap_or(0);
+	let gkr_layers = (0..max_product_n_vars)
+		.map(|layer_no| {
+			regular_sumcheck_scalars(
+				product_n_vars
+					.iter()
+					.filter(|&&n_vars| n_vars > layer_no)
+					.map(|_| (layer_no, 3, 3)),
+			)
+		})
+		.sum::<usize>();
+	let grand_product = (product_n_vars.len() + gkr_layers) * scalar_size;
+
+	// The univariate skip round, the multilinear eq-ind rounds and the univariatizing reduction.
+	let zerocheck_claims = table_constraints
+		.iter()
+		.cloned()
+		.map(|constraint_set| {
+			constraint_set_zerocheck_claim(constraint_set).map(|(claim, _)| claim)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	let (_, skip_rounds) = max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
+	let zerocheck = if zerocheck_claims.is_empty() {
+		0
+	} else {
+		let max_domain_size = zerocheck_claims
+			.iter()
+			.map(|claim| domain_size(claim.max_individual_degree(), skip_rounds))
+			.max()
+			.unwrap_or(0);
+		let univariate_round = max_domain_size - (1 << skip_rounds).min(max_domain_size);
+		let multilinear_rounds =
+			front_loaded_sumcheck_scalars(zerocheck_claims.iter().map(|claim| {
+				(
+					claim.n_vars() - skip_rounds,
+					claim.max_individual_degree() + 1,
+					claim.n_multilinears() + 1,
+				)
+			}));
+		let n_multilinears = zerocheck_claims
+			.iter()
+			.map(|claim| claim.n_multilinears())
+			.sum::<usize>();
+		let reduction = front_loaded_sumcheck_scalars([(skip_rounds, 2, n_multilinears + 1)]);
+		(univariate_round + multilinear_rounds + reduction) * scalar_size
+	};
+
+	let n_virtual = oracles
+		.polys()
+		.filter(|oracle| {
+			!matches!(
+				oracle.variant,
+				MultilinearPolyVariant::Committed | MultilinearPolyVariant::Transparent(_)
+			)
+		})
+		.count();
+	let evalcheck = n_virtual * scalar_size;
+
+	// One tensor algebra element and one row-batched evaluation per committed oracle.
+	let ring_switch = oracles
+		.polys()
+		.filter(|oracle| matches!(oracle.variant, MultilinearPolyVariant::Committed))
+		.map(|oracle| {
+			let n_vars = oracle.n_vars();
+			let n_packed_vars =
+				(n_vars + oracle.binary_tower_level()).saturating_sub(<FExt<Tower>>::TOWER_LEVEL);
+			(1 << (n_vars - n_packed_vars)) + 1
+		})
+		.sum::<usize>()
+		* scalar_size;
+
+	// Every commit batch is committed and opened separately.
+	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
+	let commit_batches = oracles.commit_batches();
+	let mut pcs_sumcheck = 0;
+	let mut fri = 0;
+	for &batch in &commit_batches {
+		let (commit_meta, _) = piop::make_oracle_commit_meta_for_batch(oracles, batch)?;
+		let fri_params =
+			security.fri_params::<_, FEncode<Tower>, _>(&commit_meta, &merkle_scheme)?;
+
+		// Each committed multilinear is multiplied by the eq indicator of its evaluation claim.
+		pcs_sumcheck += front_loaded_sumcheck_scalars(
+			commit_meta
+				.n_multilins_by_vars()
+				.iter()
+				.enumerate()
+				.filter(|&(_, &n_multilins)| n_multilins > 0)
+				.map(|(n_vars, &n_multilins)| (n_vars, 2, 2 * n_multilins)),
+		) * scalar_size;
+
+		if commit_meta.total_multilins() > 0 {
+			fri += piop::fri_opening_size(&fri_params, &merkle_scheme);
+		}
+	}
+
+	Ok(ProofSizeEstimate {
+		commitment: commit_batches.len() * digest_size,
+		exp,
+		grand_product,
+		zerocheck,
+		evalcheck,
+		ring_switch,
+		pcs_sumcheck,
+		fri,
+	})
+}
+
+/// Returns the number of field elements in a batched sumcheck that proves all claims over the
+/// maximum number of variables, given the number of variables, degree and number of multilinears
+/// of every claim.
+fn regular_sumcheck_scalars(claims: impl IntoIterator<Item = (usize, usize, usize)>) -> usize {
+	let (max_n_vars, max_degree, n_multilinears) = claims.into_iter().fold(
+		(0, 0, 0),
+		|(max_n_vars, max_degree, n_multilinears), (n_vars, degree, claim_multilinears)| {
+			(max_n_vars.max(n_vars), max_degree.max(degree), n_multilinears + claim_multilinears)
+		},
+	);
+	max_n_vars * max_degree + n_multilinears
+}
+
+/// Returns the number of field elements in a front-loaded batched sumcheck, where every round
+/// message has the maximum degree of the claims that are still active.
+fn front_loaded_sumcheck_scalars(
+	claims: impl IntoIterator<Item = (usize, usize, usize)> + Clone,
+) -> usize {
+	let max_n_vars = claims
+		.clone()
+		.into_iter()
+		.map(|(n_vars, _, _)| n_vars)
+		.max()
+		.unwrap_or(0);
+	let rounds = (0..max_n_vars)
+		.map(|round| {
+			claims
+				.clone()
+				.into_iter()
+				.filter(|&(n_vars, _, _)| n_vars > round)
+				.map(|(_, degree, _)| degree)
+				.max()
+				.unwrap_or(0)
+		})
+		.sum::<usize>();
+	let evals = claims
+		.into_iter()
+		.map(|(_, _, n_multilinears)| n_multilinears)
+		.sum::<usize>();
+	rounds + evals
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, tower::CanonicalTowerFamily};
+	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+
+	use super::*;
+	use crate::oracle::{CommitBatching, MultilinearOracleSet};
+
+	fn estimate(
+		constraint_system: &ConstraintSystem<BinaryField128b>,
+		security_bits: usize,
+	) -> ProofSizeEstimate {
+		estimate_proof_size::<CanonicalTowerFamily, Groestl256, Groestl256ByteCompression>(
+			constraint_system,
+			SecurityLevel::new(security_bits, 1),
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn test_estimate_committed_columns() {
+		let mut oracles = MultilinearOracleSet::new();
+		oracles.add_committed(16, 0);
+		oracles.add_committed(12, 5);
+		let constraint_system = ConstraintSystem {
+			oracles,
+			table_constraints: vec![],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![],
+			exponents: vec![],
+			max_channel_id: 0,
+		};
+
+		let low = estimate(&constraint_system, 64);
+		let high = estimate(&constraint_system, 100);
+		assert_eq!(low.commitment, 32);
+		assert_eq!(low.zerocheck, 0);
+		assert_eq!(low.ring_switch, ((1 << 7) + 1 + (1 << 2) + 1) * 16);
+		assert!(low.fri > 0);
+		assert!(high.fri > low.fri);
+		assert_eq!(high.total() - high.fri, low.total() - low.fri);
+		assert_eq!(low.serialized_size(), low.total() + 12);
+	}
+
+	#[test]
+	fn test_estimate_commit_batches_per_tower_level() {
+		let mut oracles = MultilinearOracleSet::new();
+		oracles.add_committed(16, 0);
+		oracles.add_committed(12, 5);
+		let mut constraint_system = ConstraintSystem {
+			oracles,
+			table_constraints: vec![],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![],
+			exponents: vec![],
+			max_channel_id: 0,
+		};
+		let per_phase = estimate(&constraint_system, 100);
+
+		constraint_system
+			.oracles
+			.set_commit_batching(CommitBatching::PerTowerLevel);
+		let per_tower_level = estimate(&constraint_system, 100);
+		assert_eq!(per_tower_level.commitment, 2 * 32);
+		assert_eq!(per_tower_level.ring_switch, per_phase.ring_switch);
+		assert!(per_tower_level.fri > 0);
+	}
+
+	#[test]
+	fn test_front_loaded_sumcheck_scalars() {
+		// Rounds 0..4 have degree 3, rounds 4..8 degree 2, plus 5 final evaluations.
+		assert_eq!(front_loaded_sumcheck_scalars([(4, 3, 2), (8, 2, 3)]), 4 * 3 + 4 * 2 + 5);
+		assert_eq!(front_loaded_sumcheck_scalars([]), 0);
+	}
+}
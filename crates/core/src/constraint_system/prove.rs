@@ -16,15 +16,15 @@ use binius_math::{
 	CompositionPoly, DefaultEvaluationDomainFactory, EvaluationDomainFactory, EvaluationOrder,
 	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
 };
-use binius_maybe_rayon::prelude::*;
-use binius_ntt::SingleThreadedNTT;
-use binius_utils::bail;
+use binius_maybe_rayon::{ThreadPool, prelude::*};
+use binius_ntt::NTTContext;
+use binius_utils::{bail, sparse_index::SparseIndex};
 use digest::{Digest, FixedOutputReset, Output, core_api::BlockSizeUser};
 use itertools::{chain, izip};
 use tracing::instrument;
 
 use super::{
-	ConstraintSystem, Proof,
+	ConstraintSystem, Proof, SecurityLevel,
 	channel::Boundary,
 	error::Error,
 	verify::{make_flush_oracles, max_n_vars_and_skip_rounds},
@@ -32,38 +32,437 @@ use super::{
 use crate::{
 	constraint_system::{
 		Flush,
-		common::{FDomain, FEncode, FExt, FFastExt},
+		common::{
+			FDomain, FEncode, FExt, FFastExt, eval_claims_by_commit_batch,
+			observe_application_context,
+		},
 		exp::{self, reorder_exponents},
 	},
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeProver,
+	metrics::{MetricsRecorder, ProverMetrics},
 	oracle::{Constraint, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
-	piop,
+	piop::{self, CommitMeta},
 	protocols::{
-		fri::CommitOutput,
+		fri::{CommitOutput, FRIParams},
 		gkr_exp,
 		gkr_gpa::{self, GrandProductBatchProveOutput, GrandProductWitness},
 		greedy_evalcheck::{self, GreedyEvalcheckProveOutput},
+		memoized_queries::MemoizedQueries,
 		sumcheck::{
 			self, constraint_set_zerocheck_claim, prove::ZerocheckProver,
 			standard_switchover_heuristic,
 		},
 	},
 	ring_switch,
-	transcript::ProverTranscript,
+	transcript::{ProverTranscript, TranscriptCheckpoint},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
-#[instrument("constraint_system::prove", skip_all, level = "debug")]
+///
+/// The committed multilinears are opened with FRI-Binius (see [`crate::piop`]). The Merkle tree
+/// commitments in FRI are instantiated with the `Hash` digest and the `Compress` compression
+/// function, so for example Grøstl-256 can be swapped for BLAKE3 when the verifier does not need
+/// to be arithmetized.
 pub fn prove<U, Tower, Hash, Compress, Challenger_, Backend>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
-	log_inv_rate: usize,
-	security_bits: usize,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	prove_with_ntt_context::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		security,
+		boundaries,
+		witness,
+		backend,
+		&NTTContext::new(),
+	)
+}
+
+/// Same as [`prove`], with the NTTs used to encode the committed polynomials taken from
+/// `ntt_context`.
+///
+/// The twiddle factors of the NTT are precomputed on first use of a domain, so sharing a context
+/// between the proofs of the same constraint system saves recomputing them for every proof.
+pub fn prove_with_ntt_context<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	ntt_context: &NTTContext<FEncode<Tower>>,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	prove_with_metrics::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		security,
+		boundaries,
+		witness,
+		backend,
+		ntt_context,
+	)
+	.map(|(proof, _)| proof)
+}
+
+/// Same as [`prove`], with the proof bound to `application_context`.
+///
+/// The application context is arbitrary bytes, such as a block hash or a transaction root, that
+/// are observed by the Fiat-Shamir transcript before the boundaries. The proof is only accepted
+/// by [`super::verify_with_application_context`] with the same bytes. [`prove`] binds proofs to
+/// the empty context.
+pub fn prove_with_application_context<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
 	boundaries: &[Boundary<FExt<Tower>>],
-	mut witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	application_context: &[u8],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
 	backend: &Backend,
 ) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	prove_impl::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		security,
+		boundaries,
+		application_context,
+		witness,
+		backend,
+		&NTTContext::new(),
+		&mut |_, _, _| Ok(()),
+		None,
+		None,
+	)
+	.map(|(proof, _)| proof)
+}
+
+/// Same as [`prove`], with all the parallel work of the prover scheduled on `thread_pool`.
+///
+/// By default the prover runs on the global rayon thread pool, so concurrent proofs compete for
+/// all the cores of the machine. Embedders proving several statements at once, such as proving
+/// servers, can instead give each proof a pool with its own number of threads. The NTTs used to
+/// encode the committed polynomials are split between the threads of the pool.
+pub fn prove_in_thread_pool<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	thread_pool: &ThreadPool,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	thread_pool.install(|| {
+		// The context is created inside the pool for its NTTs to use the threads of the pool.
+		prove_with_ntt_context::<U, Tower, Hash, Compress, Challenger_, Backend>(
+			constraint_system,
+			security,
+			boundaries,
+			witness,
+			backend,
+			&NTTContext::new(),
+		)
+	})
+}
+
+/// Same as [`prove_with_ntt_context`], also returning the [`ProverMetrics`] of the proof.
+///
+/// The metrics summarize the wall time of the phases of the prover and count the expensive
+/// operations, see [`crate::metrics`].
+pub fn prove_with_metrics<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	ntt_context: &NTTContext<FEncode<Tower>>,
+) -> Result<(Proof, ProverMetrics), Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	prove_impl::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		security,
+		boundaries,
+		&[],
+		witness,
+		backend,
+		ntt_context,
+		&mut |_, _, _| Ok(()),
+		None,
+		None,
+	)
+}
+
+/// Same as [`prove`], with the witness of the later commit phases filled by `fill_commit_phase`.
+///
+/// At the start of every commit phase after the first, the prover samples a challenge from the
+/// transcript, which has observed the commitments of the previous phases, fills the witness of
+/// the [`MultilinearOracleSet::add_commit_phase_challenge`] oracles of the phase and calls
+/// `fill_commit_phase` with the phase and the challenge. The callback must fill the witness of
+/// the oracles committed in the phase, such as the columns of a lookup argument that depend on
+/// the challenge.
+pub fn prove_with_commit_phases<'a, U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<'a, PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	mut fill_commit_phase: impl FnMut(
+		usize,
+		FExt<Tower>,
+		&mut MultilinearExtensionIndex<'a, PackedType<U, FExt<Tower>>>,
+	) -> Result<(), Error>,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	prove_impl::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		security,
+		boundaries,
+		&[],
+		witness,
+		backend,
+		&NTTContext::new(),
+		&mut fill_commit_phase,
+		None,
+		None,
+	)
+	.map(|(proof, _)| proof)
+}
+
+/// The state of the prover after the commitment of a commit batch, see [`prove_with_checkpoints`].
+#[derive(Debug, Clone)]
+pub struct CommitCheckpoint {
+	/// The index of the commit batch in [`MultilinearOracleSet::commit_batches`].
+	pub batch: usize,
+	/// The transcript, which has observed the commitments of the batches up to `batch`.
+	pub transcript: TranscriptCheckpoint,
+}
+
+/// Same as [`prove`], calling `on_commit` with a [`CommitCheckpoint`] after every commit batch.
+///
+/// The checkpoints can be serialized and stored by long-running provers, and an interrupted proof
+/// can be finished from the last of them with [`prove_from_checkpoint`]. An error returned by
+/// `on_commit` aborts the proof. Recording the transcript takes memory proportional to the
+/// observed data.
+pub fn prove_with_checkpoints<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	mut on_commit: impl FnMut(CommitCheckpoint) -> Result<(), Error>,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	prove_impl::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		security,
+		boundaries,
+		&[],
+		witness,
+		backend,
+		&NTTContext::new(),
+		&mut |_, _, _| Ok(()),
+		Some(&mut on_commit),
+		None,
+	)
+	.map(|(proof, _)| proof)
+}
+
+/// Same as [`prove_with_checkpoints`], resuming an interrupted proof from `checkpoint`.
+///
+/// The codewords and Merkle trees of the committed batches are not part of a checkpoint, so the
+/// prover rebuilds the commitments of the batches up to `checkpoint.batch` from the witness and
+/// checks that the transcript reaches the state of the checkpoint, which fails with
+/// [`Error::CheckpointMismatch`] if the constraint system, boundaries or witness differ from the
+/// interrupted proof. It then continues with the later batches, calling `on_commit` after each of
+/// them, and the resulting proof is the one the uninterrupted prover would have produced.
+pub fn prove_from_checkpoint<U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	checkpoint: &CommitCheckpoint,
+	mut on_commit: impl FnMut(CommitCheckpoint) -> Result<(), Error>,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable // REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+{
+	prove_impl::<U, Tower, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		security,
+		boundaries,
+		&[],
+		witness,
+		backend,
+		&NTTContext::new(),
+		&mut |_, _, _| Ok(()),
+		Some(&mut on_commit),
+		Some(checkpoint),
+	)
+	.map(|(proof, _)| proof)
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument("constraint_system::prove", skip_all, level = "debug")]
+fn prove_impl<'a, U, Tower, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	application_context: &[u8],
+	mut witness: MultilinearExtensionIndex<'a, PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	ntt_context: &NTTContext<FEncode<Tower>>,
+	fill_commit_phase: &mut dyn FnMut(
+		usize,
+		FExt<Tower>,
+		&mut MultilinearExtensionIndex<'a, PackedType<U, FExt<Tower>>>,
+	) -> Result<(), Error>,
+	mut on_commit: Option<&mut dyn FnMut(CommitCheckpoint) -> Result<(), Error>>,
+	resume_from: Option<&CommitCheckpoint>,
+) -> Result<(Proof, ProverMetrics), Error>
 where
 	U: ProverTowerUnderlier<Tower>,
 	Tower: ProverTowerFamily,
@@ -89,11 +488,19 @@ where
 		"using computation backend: {backend:?}"
 	);
 
+	let mut metrics = MetricsRecorder::new();
+
 	let domain_factory = DefaultEvaluationDomainFactory::<FDomain<Tower>>::default();
 	let fast_domain_factory = IsomorphicEvaluationDomainFactory::<FFastExt<Tower>>::default();
 
-	let mut transcript = ProverTranscript::<Challenger_>::new();
-	transcript.observe().write_slice(boundaries);
+	let mut transcript = if on_commit.is_some() || resume_from.is_some() {
+		ProverTranscript::<Challenger_>::with_checkpoints()
+	} else {
+		ProverTranscript::<Challenger_>::new()
+	};
+	let mut writer = transcript.observe();
+	observe_application_context(&mut writer, application_context);
+	writer.write_slice(boundaries);
 
 	let ConstraintSystem {
 		mut oracles,
@@ -117,39 +524,95 @@ where
 	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
 	let merkle_scheme = merkle_prover.scheme();
 
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
-	let committed_multilins = piop::collect_committed_witnesses::<U, _>(
-		&commit_meta,
-		&oracle_to_commit_index,
-		&oracles,
-		&witness,
-	)?;
-
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
-	let ntt = SingleThreadedNTT::new(fri_params.rs_code().log_len())?
-		.precompute_twiddles()
-		.multithreaded();
-
+	// The commitments of the commit batches are observed in order, so the oracles of a phase are
+	// committed with the commitments of the previous phases already in the transcript.
 	let commit_span =
 		tracing::info_span!("[phase] Commit", phase = "commit", perfetto_category = "phase.main")
 			.entered();
-	let CommitOutput {
-		commitment,
-		committed,
-		codeword,
-	} = piop::commit(&fri_params, &ntt, &merkle_prover, &committed_multilins)?;
-	drop(commit_span);
+	let commit_phase = metrics.start_phase("commit");
+	let batches = oracles.commit_batches();
+	if let Some(checkpoint) = resume_from {
+		if checkpoint.batch >= batches.len() {
+			bail!(Error::CheckpointMismatch {
+				batch: checkpoint.batch
+			});
+		}
+	}
+	let mut commit_batches = Vec::with_capacity(batches.len());
+	let mut phase = 0;
+	for (batch_index, &batch) in batches.iter().enumerate() {
+		if batch.phase != phase {
+			phase = batch.phase;
+			let challenge: FExt<Tower> = transcript.sample();
+			oracles.bind_commit_phase_challenge(phase, challenge);
+			make_commit_phase_challenge_witnesses::<U, Tower>(
+				&oracles,
+				&mut witness,
+				phase,
+				challenge,
+			)?;
+			fill_commit_phase(phase, challenge, &mut witness)?;
+		}
+
+		let (commit_meta, oracle_to_commit_index) =
+			piop::make_oracle_commit_meta_for_batch(&oracles, batch)?;
+		let committed_multilins = piop::collect_committed_witnesses::<U, _>(
+			&commit_meta,
+			&oracle_to_commit_index,
+			&oracles,
+			&witness,
+		)?;
 
-	// Observe polynomial commitment
-	let mut writer = transcript.message();
-	writer.write(&commitment);
+		let fri_params =
+			security.fri_params::<_, FEncode<Tower>, _>(&commit_meta, merkle_scheme)?;
+		let ntt = ntt_context.ntt_with_subspace(fri_params.rs_code().subspace())?;
+
+		let CommitOutput {
+			commitment,
+			committed,
+			codeword,
+		} = piop::commit(&fri_params, &*ntt, &merkle_prover, &committed_multilins)?;
+
+		// Observe polynomial commitment
+		let mut writer = transcript.message();
+		writer.write(&commitment);
+
+		// When resuming, the batches up to the checkpoint are only rebuilt, and their checkpoints
+		// were already taken by the interrupted prover.
+		match resume_from {
+			Some(checkpoint) if batch_index < checkpoint.batch => {}
+			Some(checkpoint) if batch_index == checkpoint.batch => {
+				if transcript.checkpoint()? != checkpoint.transcript {
+					bail!(Error::CheckpointMismatch { batch: batch_index });
+				}
+			}
+			_ => {
+				if let Some(on_commit) = on_commit.as_mut() {
+					on_commit(CommitCheckpoint {
+						batch: batch_index,
+						transcript: transcript.checkpoint()?,
+					})?;
+				}
+			}
+		}
+
+		commit_batches.push(ProverCommitBatch {
+			commit_meta,
+			oracle_to_commit_index,
+			committed_multilins,
+			fri_params,
+			committed,
+			codeword,
+		});
+	}
+	metrics.finish_phase(commit_phase);
+	drop(commit_span);
 
 	// GKR exp
+	let exp_span =
+		tracing::info_span!("[phase] GKR Exp", phase = "gkr_exp", perfetto_category = "phase.main")
+			.entered();
+	let exp_phase = metrics.start_phase("gkr_exp");
 	let exp_challenge = transcript.sample_vec(exp::max_n_vars(&exponents, &oracles));
 
 	let exp_evals = gkr_exp::get_evals_in_point_from_witnesses(&exp_witnesses, &exp_challenge)?
@@ -181,8 +644,17 @@ where
 	.isomorphic();
 
 	let exp_eval_claims = exp::make_eval_claims(&exponents, base_exp_output)?;
+	metrics.finish_phase(exp_phase);
+	drop(exp_span);
 
 	// Grand product arguments
+	let gpa_span = tracing::info_span!(
+		"[phase] Grand Product",
+		phase = "grand_product",
+		perfetto_category = "phase.main"
+	)
+	.entered();
+	let gpa_phase = metrics.start_phase("grand_product");
 	// Grand products for non-zero checking
 	let non_zero_fast_witnesses =
 		make_fast_unmasked_flush_witnesses::<U, _>(&oracles, &witness, &non_zero_oracle_ids)?;
@@ -263,6 +735,8 @@ where
 		chain!(flush_oracle_ids, non_zero_oracle_ids),
 		final_layer_claims,
 	)?;
+	metrics.finish_phase(gpa_phase);
+	drop(gpa_span);
 
 	// Zerocheck
 	let zerocheck_span = tracing::info_span!(
@@ -271,6 +745,7 @@ where
 		perfetto_category = "phase.main",
 	)
 	.entered();
+	let zerocheck_phase = metrics.start_phase("zerocheck");
 
 	let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
 		.iter()
@@ -286,6 +761,7 @@ where
 	let zerocheck_challenges = transcript.sample_vec(max_n_vars - skip_rounds);
 
 	let mut zerocheck_provers = Vec::with_capacity(table_constraints.len());
+	let mut memoized_queries = MemoizedQueries::new();
 
 	for constraint_set in table_constraints {
 		let n_vars = constraint_set.n_vars;
@@ -312,6 +788,7 @@ where
 				constraints,
 				multilinears,
 				zerocheck_challenges,
+				memoized_queries: &mut memoized_queries,
 				domain_factory,
 				backend,
 				_fdomain_marker: PhantomData,
@@ -340,6 +817,7 @@ where
 	let zerocheck_eval_claims =
 		sumcheck::make_zerocheck_eval_claims(zerocheck_oracle_metas, zerocheck_output)?;
 
+	metrics.finish_phase(zerocheck_phase);
 	drop(zerocheck_span);
 
 	let evalcheck_span = tracing::info_span!(
@@ -348,29 +826,37 @@ where
 		perfetto_category = "phase.main"
 	)
 	.entered();
+	let evalcheck_phase = metrics.start_phase("evalcheck");
 
 	// Prove evaluation claims
 	let GreedyEvalcheckProveOutput {
 		eval_claims,
-		memoized_data,
+		mut memoized_data,
 	} = greedy_evalcheck::prove::<_, _, FDomain<Tower>, _, _>(
 		&mut oracles,
 		&mut witness,
 		chain!(prodcheck_eval_claims, zerocheck_eval_claims, exp_eval_claims,),
+		memoized_queries,
 		standard_switchover_heuristic(-2),
 		&mut transcript,
 		&domain_factory,
 		backend,
 	)?;
 
-	// Reduce committed evaluation claims to PIOP sumcheck claims
-	let system = ring_switch::EvalClaimSystem::new(
-		&oracles,
-		&commit_meta,
-		&oracle_to_commit_index,
-		&eval_claims,
-	)?;
+	// Reduce committed evaluation claims to PIOP sumcheck claims, for each commit batch
+	let eval_claims = eval_claims_by_commit_batch(&oracles, &batches, eval_claims)?;
+	let systems = izip!(&commit_batches, &eval_claims)
+		.map(|(commit_batch, eval_claims)| {
+			ring_switch::EvalClaimSystem::new(
+				&oracles,
+				&commit_batch.commit_meta,
+				&commit_batch.oracle_to_commit_index,
+				eval_claims,
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
 
+	metrics.finish_phase(evalcheck_phase);
 	drop(evalcheck_span);
 
 	let ring_switch_span = tracing::info_span!(
@@ -379,16 +865,20 @@ where
 		perfetto_category = "phase.main"
 	)
 	.entered();
-	let ring_switch::ReducedWitness {
-		transparents: transparent_multilins,
-		sumcheck_claims: piop_sumcheck_claims,
-	} = ring_switch::prove::<_, _, _, Tower, _, _>(
-		&system,
-		&committed_multilins,
-		&mut transcript,
-		memoized_data,
-		backend,
-	)?;
+	let ring_switch_phase = metrics.start_phase("ring_switch");
+	let reduced_witnesses = izip!(&systems, &commit_batches)
+		.map(|(system, commit_batch)| {
+			ring_switch::prove::<_, _, _, Tower, _, _>(
+				system,
+				&commit_batch.committed_multilins,
+				&mut transcript,
+				&mut memoized_data,
+				backend,
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	drop(systems);
+	metrics.finish_phase(ring_switch_phase);
 	drop(ring_switch_span);
 
 	// Prove evaluation claims using PIOP compiler
@@ -398,24 +888,42 @@ where
 		perfetto_category = "phase.main"
 	)
 	.entered();
-	piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _, _>(
-		&fri_params,
-		&ntt,
-		&merkle_prover,
-		domain_factory,
-		&commit_meta,
-		committed,
-		&codeword,
-		&committed_multilins,
-		&transparent_multilins,
-		&piop_sumcheck_claims,
-		&mut transcript,
-		&backend,
-	)?;
+	let piop_compiler_phase = metrics.start_phase("piop_compiler");
+	for (commit_batch, reduced_witness) in izip!(commit_batches, reduced_witnesses) {
+		let ProverCommitBatch {
+			commit_meta,
+			committed_multilins,
+			fri_params,
+			committed,
+			codeword,
+			..
+		} = commit_batch;
+		let ring_switch::ReducedWitness {
+			transparents: transparent_multilins,
+			sumcheck_claims: piop_sumcheck_claims,
+		} = reduced_witness;
+
+		let ntt = ntt_context.ntt_with_subspace(fri_params.rs_code().subspace())?;
+		piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _, _>(
+			&fri_params,
+			&*ntt,
+			&merkle_prover,
+			domain_factory.clone(),
+			&commit_meta,
+			committed,
+			&codeword,
+			&committed_multilins,
+			&transparent_multilins,
+			&piop_sumcheck_claims,
+			&mut transcript,
+			&backend,
+		)?;
+	}
+	metrics.finish_phase(piop_compiler_phase);
 	drop(piop_compiler_span);
 
 	let proof = Proof {
-		transcript: transcript.finalize(),
+		transcript: transcript.finalize()?,
 	};
 
 	tracing::event!(
@@ -426,25 +934,45 @@ where
 		unit = "bytes",
 	);
 
-	Ok(proof)
+	let metrics = metrics.finish(proof.get_proof_size());
+	tracing::debug!(?metrics, "prover metrics");
+
+	Ok((proof, metrics))
+}
+
+/// The committed multilinears of a commit batch, see [`MultilinearOracleSet::commit_batches`].
+struct ProverCommitBatch<'a, F, FA, P, Committed>
+where
+	F: BinaryField,
+	FA: BinaryField,
+	P: PackedField<Scalar = F>,
+{
+	commit_meta: CommitMeta,
+	oracle_to_commit_index: SparseIndex<usize>,
+	committed_multilins: Vec<MultilinearWitness<'a, P>>,
+	fri_params: FRIParams<F, FA>,
+	committed: Committed,
+	codeword: Vec<P>,
 }
 
 type TypeErasedZerocheck<'a, P> = Box<dyn ZerocheckProver<'a, P> + 'a>;
 
-struct ZerocheckProverConstructor<'a, P, FDomain, DomainFactory, Backend>
+struct ZerocheckProverConstructor<'a, 'b, P, FDomain, DomainFactory, Backend>
 where
 	P: PackedField,
+	Backend: ComputationBackend,
 {
 	constraints: Vec<Constraint<P::Scalar>>,
 	multilinears: Vec<MultilinearWitness<'a, P>>,
 	domain_factory: DomainFactory,
 	zerocheck_challenges: &'a [P::Scalar],
+	memoized_queries: &'b mut MemoizedQueries<P, Backend>,
 	backend: &'a Backend,
 	_fdomain_marker: PhantomData<FDomain>,
 }
 
 impl<'a, P, F, FDomain, DomainFactory, Backend>
-	ZerocheckProverConstructor<'a, P, FDomain, DomainFactory, Backend>
+	ZerocheckProverConstructor<'a, '_, P, FDomain, DomainFactory, Backend>
 where
 	F: Field,
 	P: PackedField<Scalar = F>,
@@ -466,6 +994,7 @@ where
 				self.multilinears,
 				self.domain_factory,
 				self.zerocheck_challenges,
+				self.memoized_queries,
 				self.backend,
 			)?;
 
@@ -475,6 +1004,33 @@ where
 	}
 }
 
+/// Fills the witness of the challenge oracles of the commit phase `phase` with `challenge`.
+fn make_commit_phase_challenge_witnesses<'a, U, Tower>(
+	oracles: &MultilinearOracleSet<FExt<Tower>>,
+	witness: &mut MultilinearExtensionIndex<'a, PackedType<U, FExt<Tower>>>,
+	phase: usize,
+	challenge: FExt<Tower>,
+) -> Result<(), Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+{
+	let packed_challenge = PackedType::<U, FExt<Tower>>::broadcast(challenge);
+	let challenge_witnesses = oracles
+		.commit_phase_challenges(phase)
+		.map(|id| {
+			let n_vars = oracles.n_vars(id);
+			let log_width = PackedType::<U, FExt<Tower>>::LOG_WIDTH;
+			let values = vec![packed_challenge; 1 << n_vars.saturating_sub(log_width)];
+			let poly = MultilinearExtension::new(n_vars, values)
+				.expect("data is constructed with the correct length");
+			(id, MLEDirectAdapter::from(poly).upcast_arc_dyn())
+		})
+		.collect::<Vec<_>>();
+	witness.update_multilin_poly(challenge_witnesses)?;
+	Ok(())
+}
+
 #[instrument(skip_all, level = "debug")]
 fn make_masked_flush_witnesses<'a, U, Tower>(
 	oracles: &MultilinearOracleSet<FExt<Tower>>,
@@ -0,0 +1,124 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{BinaryField, ExtensionField, TowerField};
+
+use super::error::Error;
+use crate::{
+	merkle_tree::MerkleTreeScheme,
+	piop::{self, CommitMeta},
+	protocols::fri::FRIParams,
+};
+
+/// The soundness parameters of a proof, shared by the prover and the verifier.
+///
+/// All verifier challenges, including the zerocheck and sumcheck challenges, are sampled from the
+/// 128-bit extension field of the constraint system, so the only soundness parameters left to
+/// choose are the target security and the rate of the Reed–Solomon code committed with FRI. The
+/// number of FRI test queries is derived from both with
+/// [`crate::protocols::fri::calculate_n_test_queries`]. A lower rate makes the prover slower and
+/// the proof smaller, see [`super::estimate_proof_size`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecurityLevel {
+	/// The target soundness of the proof in bits.
+	pub security_bits: usize,
+	/// The binary logarithm of the inverse rate of the Reed–Solomon code.
+	pub log_inv_rate: usize,
+}
+
+impl SecurityLevel {
+	/// 100-bit security with a rate 1/2 code.
+	pub const STANDARD: Self = Self::new(100, 1);
+
+	pub const fn new(security_bits: usize, log_inv_rate: usize) -> Self {
+		Self {
+			security_bits,
+			log_inv_rate,
+		}
+	}
+
+	/// Checks that the security level is attainable with challenges sampled from `F`.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::InvalidSecurityLevel`] if the code rate is 1, which makes FRI unsound, or if the
+	///   target security is zero or not below the bit size of `F`
+	pub fn validate<F: TowerField>(&self) -> Result<(), Error> {
+		if self.log_inv_rate == 0 {
+			return Err(Error::InvalidSecurityLevel {
+				reason: "the Reed–Solomon code must have a rate below 1".to_string(),
+			});
+		}
+		if self.security_bits == 0 || self.security_bits >= F::N_BITS {
+			return Err(Error::InvalidSecurityLevel {
+				reason: format!(
+					"{} bits of security are not attainable with {}-bit challenges",
+					self.security_bits,
+					F::N_BITS
+				),
+			});
+		}
+		Ok(())
+	}
+
+	/// Returns the FRI parameters that open the committed batch described by `commit_meta` at
+	/// this security level.
+	///
+	/// The folding arities are chosen per batch to minimize the opening size, see
+	/// [`piop::find_proof_size_optimal_pcs`].
+	pub fn fri_params<F, FEncode, MTScheme>(
+		&self,
+		commit_meta: &CommitMeta,
+		merkle_scheme: &MTScheme,
+	) -> Result<FRIParams<F, FEncode>, Error>
+	where
+		F: TowerField + ExtensionField<FEncode>,
+		FEncode: BinaryField,
+		MTScheme: MerkleTreeScheme<F>,
+	{
+		self.validate::<F>()?;
+		let fri_params = piop::find_proof_size_optimal_pcs(
+			commit_meta,
+			merkle_scheme,
+			self.security_bits,
+			self.log_inv_rate,
+		)?;
+		Ok(fri_params)
+	}
+}
+
+impl Default for SecurityLevel {
+	fn default() -> Self {
+		Self::STANDARD
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+
+	use super::*;
+
+	#[test]
+	fn test_validate_security_level() {
+		assert!(
+			SecurityLevel::STANDARD
+				.validate::<BinaryField128b>()
+				.is_ok()
+		);
+		assert!(
+			SecurityLevel::new(100, 0)
+				.validate::<BinaryField128b>()
+				.is_err()
+		);
+		assert!(
+			SecurityLevel::new(0, 1)
+				.validate::<BinaryField128b>()
+				.is_err()
+		);
+		assert!(
+			SecurityLevel::new(128, 1)
+				.validate::<BinaryField128b>()
+				.is_err()
+		);
+	}
+}
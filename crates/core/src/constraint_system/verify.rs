@@ -6,43 +6,314 @@ use binius_field::{
 };
 use binius_hash::PseudoCompressionFunction;
 use binius_math::{ArithExpr, CompositionPoly, EvaluationOrder};
-use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize, sparse_index::SparseIndex};
 use digest::{Digest, Output, core_api::BlockSizeUser};
-use itertools::{Itertools, chain};
+use itertools::{Itertools, chain, izip};
 use tracing::instrument;
 
 use super::{
-	ConstraintSystem, Proof,
+	ConstraintSystem, Proof, SecurityLevel,
 	channel::{Boundary, OracleOrConst},
-	error::{Error, VerificationError},
+	error::{Error, InStage, ProofStage, VerificationError},
 	exp::{self, reorder_exponents},
 };
 use crate::{
 	constraint_system::{
 		channel::{Flush, FlushDirection},
-		common::{FDomain, FEncode, FExt},
+		common::{
+			FDomain, FEncode, FExt, eval_claims_by_commit_batch, observe_application_context,
+		},
 	},
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeScheme,
-	oracle::{MultilinearOracleSet, OracleId},
-	piop,
+	oracle::{CommitBatch, MultilinearOracleSet, OracleId},
+	piop::{self, CommitMeta},
+	polynomial::ArithCircuitPoly,
 	protocols::{
+		fri::FRIParams,
 		gkr_exp,
 		gkr_gpa::{self},
 		greedy_evalcheck,
-		sumcheck::{self, ZerocheckClaim, constraint_set_zerocheck_claim},
+		sumcheck::{self, OracleClaimMeta, ZerocheckClaim, constraint_set_zerocheck_claim},
 	},
 	ring_switch,
 	transcript::VerifierTranscript,
 };
 
 /// Verifies a proof against a constraint system.
-#[instrument("constraint_system::verify", skip_all, level = "debug")]
+///
+/// The `Hash` and `Compress` parameters must match those used by [`super::prove`].
 pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
-	log_inv_rate: usize,
-	security_bits: usize,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	verify_with_application_context::<U, Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		security,
+		boundaries,
+		&[],
+		proof,
+	)
+}
+
+/// Verifies a proof bound to `application_context` against a constraint system.
+///
+/// See [`super::prove_with_application_context`]. [`verify`] accepts the proofs bound to the
+/// empty context.
+#[instrument("constraint_system::verify", skip_all, level = "debug")]
+pub fn verify_with_application_context<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Boundary<FExt<Tower>>],
+	application_context: &[u8],
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	PreparedVerifier::<Tower, Hash, Compress>::new(constraint_system, security)?
+		.verify_with_application_context::<U, Challenger_>(boundaries, application_context, proof)
+}
+
+/// Verifies a batch of proofs against the same constraint system.
+///
+/// `boundaries[i]` holds the boundary values of `proofs[i]`. This accepts exactly when [`verify`]
+/// accepts every proof. The proofs are verified one after the other: only the preprocessing that
+/// depends on the constraint system is done once for the whole batch, see [`PreparedVerifier`].
+/// The transcript hashing, the Merkle path checks and the FRI queries are not amortized, so the
+/// cost of verifying each proof is otherwise the same as with [`verify`].
+///
+/// ## Throws
+///
+/// * [`Error::BatchSizeMismatch`] if the number of boundary sets and proofs differ
+/// * [`Error::BatchProofInvalid`] with the index of the first proof that fails verification
+pub fn batch_verify<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Vec<Boundary<FExt<Tower>>>],
+	proofs: Vec<Proof>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let application_contexts = vec![&[][..]; proofs.len()];
+	batch_verify_with_application_contexts::<U, Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		security,
+		boundaries,
+		&application_contexts,
+		proofs,
+	)
+}
+
+/// Verifies a batch of proofs bound to application contexts against the same constraint system.
+///
+/// Same as [`batch_verify`], with `proofs[i]` bound to `application_contexts[i]`, see
+/// [`verify_with_application_context`].
+///
+/// ## Throws
+///
+/// * [`Error::BatchSizeMismatch`] if the number of boundary sets and proofs differ
+/// * [`Error::BatchApplicationContextsMismatch`] if the number of application contexts and proofs
+///   differ
+/// * [`Error::BatchProofInvalid`] with the index of the first proof that fails verification
+#[instrument("constraint_system::batch_verify", skip_all, level = "debug")]
+pub fn batch_verify_with_application_contexts<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	security: SecurityLevel,
+	boundaries: &[Vec<Boundary<FExt<Tower>>>],
+	application_contexts: &[&[u8]],
+	proofs: Vec<Proof>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	if boundaries.len() != proofs.len() {
+		bail!(Error::BatchSizeMismatch {
+			proofs: proofs.len(),
+			boundaries: boundaries.len(),
+		});
+	}
+	if application_contexts.len() != proofs.len() {
+		bail!(Error::BatchApplicationContextsMismatch {
+			proofs: proofs.len(),
+			application_contexts: application_contexts.len(),
+		});
+	}
+
+	let verifier = PreparedVerifier::<Tower, Hash, Compress>::new(constraint_system, security)?;
+	for (index, ((boundaries, application_context), proof)) in boundaries
+		.iter()
+		.zip(application_contexts)
+		.zip(proofs)
+		.enumerate()
+	{
+		verifier
+			.verify_with_application_context::<U, Challenger_>(
+				boundaries,
+				application_context,
+				proof,
+			)
+			.map_err(|err| Error::BatchProofInvalid {
+				index,
+				source: Box::new(err),
+			})?;
+	}
+
+	Ok(())
+}
+
+/// A verifier for a constraint system that does the preprocessing independent of the statement
+/// once.
+///
+/// The preprocessing orders the constraint system, computes the commitment layout and the FRI
+/// parameters of every commit batch, and compiles the table constraints to zerocheck claims. A
+/// prepared verifier is meant to be kept around to verify many proofs against the same constraint
+/// system and security level; [`verify`] and [`batch_verify`] prepare one per call.
+pub struct PreparedVerifier<Tower: TowerFamily, Hash, Compress> {
+	/// The constraint system, with the table constraints, flushes and exponents in the order
+	/// expected by the verifier.
+	constraint_system: ConstraintSystem<FExt<Tower>>,
+	merkle_scheme: BinaryMerkleTreeScheme<FExt<Tower>, Hash, Compress>,
+	commit_batches: Vec<VerifierCommitBatch<FExt<Tower>, FEncode<Tower>>>,
+	zerocheck_claims: Vec<ZerocheckClaim<FExt<Tower>, ArithCircuitPoly<FExt<Tower>>>>,
+	zerocheck_oracle_metas: Vec<OracleClaimMeta>,
+	skip_rounds: usize,
+}
+
+/// The commitment layout of a commit batch, see [`MultilinearOracleSet::commit_batches`].
+struct VerifierCommitBatch<F: BinaryField, FA: BinaryField> {
+	batch: CommitBatch,
+	commit_meta: CommitMeta,
+	oracle_to_commit_index: SparseIndex<usize>,
+	fri_params: FRIParams<F, FA>,
+}
+
+impl<Tower, Hash, Compress> PreparedVerifier<Tower, Hash, Compress>
+where
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	/// Prepares the verification of proofs against `constraint_system` at the `security` level.
+	pub fn new(
+		constraint_system: &ConstraintSystem<FExt<Tower>>,
+		security: SecurityLevel,
+	) -> Result<Self, Error> {
+		let mut constraint_system = constraint_system.clone();
+
+		// Stable sort constraint sets in ascending order by number of variables.
+		constraint_system
+			.table_constraints
+			.sort_by_key(|constraint_set| constraint_set.n_vars);
+		constraint_system
+			.flushes
+			.sort_by_key(|flush| flush.channel_id);
+		reorder_exponents(&mut constraint_system.exponents, &constraint_system.oracles);
+
+		let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
+		let commit_batches = constraint_system
+			.oracles
+			.commit_batches()
+			.into_iter()
+			.map(|batch| {
+				let (commit_meta, oracle_to_commit_index) =
+					piop::make_oracle_commit_meta_for_batch(&constraint_system.oracles, batch)?;
+				let fri_params =
+					security.fri_params::<_, FEncode<Tower>, _>(&commit_meta, &merkle_scheme)?;
+				Ok(VerifierCommitBatch {
+					batch,
+					commit_meta,
+					oracle_to_commit_index,
+					fri_params,
+				})
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let (zerocheck_claims, zerocheck_oracle_metas) = constraint_system
+			.table_constraints
+			.iter()
+			.cloned()
+			.map(constraint_set_zerocheck_claim)
+			.collect::<Result<Vec<_>, _>>()?
+			.into_iter()
+			.unzip::<_, _, Vec<_>, Vec<_>>();
+		let (_max_n_vars, skip_rounds) =
+			max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
+
+		Ok(Self {
+			constraint_system,
+			merkle_scheme,
+			commit_batches,
+			zerocheck_claims,
+			zerocheck_oracle_metas,
+			skip_rounds,
+		})
+	}
+
+	/// Verifies a proof, see [`verify`].
+	pub fn verify<U, Challenger_>(
+		&self,
+		boundaries: &[Boundary<FExt<Tower>>],
+		proof: Proof,
+	) -> Result<(), Error>
+	where
+		U: TowerUnderlier<Tower>,
+		Challenger_: Challenger + Default,
+	{
+		self.verify_with_application_context::<U, Challenger_>(boundaries, &[], proof)
+	}
+
+	/// Verifies a proof bound to `application_context`, see [`verify_with_application_context`].
+	pub fn verify_with_application_context<U, Challenger_>(
+		&self,
+		boundaries: &[Boundary<FExt<Tower>>],
+		application_context: &[u8],
+		proof: Proof,
+	) -> Result<(), Error>
+	where
+		U: TowerUnderlier<Tower>,
+		Challenger_: Challenger + Default,
+	{
+		verify_prepared::<U, Tower, Hash, Compress, Challenger_>(
+			self,
+			boundaries,
+			application_context,
+			proof,
+		)
+	}
+}
+
+fn verify_prepared<U, Tower, Hash, Compress, Challenger_>(
+	verifier: &PreparedVerifier<Tower, Hash, Compress>,
 	boundaries: &[Boundary<FExt<Tower>>],
+	application_context: &[u8],
 	proof: Proof,
 ) -> Result<(), Error>
 where
@@ -53,40 +324,44 @@ where
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
 {
+	let PreparedVerifier {
+		constraint_system,
+		merkle_scheme,
+		commit_batches,
+		zerocheck_claims,
+		zerocheck_oracle_metas,
+		skip_rounds,
+	} = verifier;
 	let ConstraintSystem {
 		mut oracles,
-		mut table_constraints,
-		mut flushes,
+		flushes,
 		non_zero_oracle_ids,
 		max_channel_id,
-		mut exponents,
+		exponents,
 		..
 	} = constraint_system.clone();
 
-	// Stable sort constraint sets in ascending order by number of variables.
-	table_constraints.sort_by_key(|constraint_set| constraint_set.n_vars);
-
 	let Proof { transcript } = proof;
 
 	let mut transcript = VerifierTranscript::<Challenger_>::new(transcript);
-	transcript.observe().write_slice(boundaries);
-
-	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		&merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
-
-	// Read polynomial commitment polynomials
-	let mut reader = transcript.message();
-	let commitment = reader.read::<Output<Hash>>()?;
+	let mut writer = transcript.observe();
+	observe_application_context(&mut writer, application_context);
+	writer.write_slice(boundaries);
+
+	// Read the polynomial commitments of the commit batches, sampling the challenge of every
+	// commit phase after the first once the commitments of the previous phases are read
+	let mut commitments = Vec::with_capacity(commit_batches.len());
+	let mut phase = 0;
+	for commit_batch in commit_batches {
+		if commit_batch.batch.phase != phase {
+			phase = commit_batch.batch.phase;
+			let challenge: FExt<Tower> = transcript.sample();
+			oracles.bind_commit_phase_challenge(phase, challenge);
+		}
+		commitments.push(transcript.message().read::<Output<Hash>>()?);
+	}
 
 	// GKR exp multiplication
-	reorder_exponents(&mut exponents, &oracles);
-
 	let exp_challenge = transcript.sample_vec(exp::max_n_vars(&exponents, &oracles));
 
 	let mut reader = transcript.message();
@@ -97,7 +372,8 @@ where
 		.collect::<Vec<_>>();
 
 	let base_exp_output =
-		gkr_exp::batch_verify(EvaluationOrder::HighToLow, &exp_claims, &mut transcript)?;
+		gkr_exp::batch_verify(EvaluationOrder::HighToLow, &exp_claims, &mut transcript)
+			.in_stage(ProofStage::GkrExp)?;
 
 	let exp_eval_claims = exp::make_eval_claims(&exponents, base_exp_output)?;
 
@@ -123,7 +399,6 @@ where
 	// TODO(cryptographers): Find a way to sample less randomness
 	let permutation_challenges = transcript.sample_vec(max_channel_id + 1);
 
-	flushes.sort_by_key(|flush| flush.channel_id);
 	let flush_oracle_ids =
 		make_flush_oracles(&mut oracles, &flushes, mixing_challenge, &permutation_challenges)?;
 
@@ -136,7 +411,8 @@ where
 		boundaries,
 		mixing_challenge,
 		&permutation_challenges,
-	)?;
+	)
+	.in_stage(ProofStage::ChannelBalance)?;
 
 	let flush_prodcheck_claims =
 		gkr_gpa::construct_grand_product_claims(&flush_oracle_ids, &oracles, &flush_products)?;
@@ -146,7 +422,8 @@ where
 		EvaluationOrder::HighToLow,
 		[flush_prodcheck_claims, non_zero_prodcheck_claims].concat(),
 		&mut transcript,
-	)?;
+	)
+	.in_stage(ProofStage::GrandProduct)?;
 
 	// Reduce non_zero_final_layer_claims to evalcheck claims
 	let prodcheck_eval_claims = gkr_gpa::make_eval_claims(
@@ -155,53 +432,65 @@ where
 	)?;
 
 	// Zerocheck
-	let (zerocheck_claims, zerocheck_oracle_metas) = table_constraints
-		.iter()
-		.cloned()
-		.map(constraint_set_zerocheck_claim)
-		.collect::<Result<Vec<_>, _>>()?
-		.into_iter()
-		.unzip::<_, _, Vec<_>, Vec<_>>();
-
-	let (_max_n_vars, skip_rounds) =
-		max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
-
 	let zerocheck_output =
-		sumcheck::batch_verify_zerocheck(&zerocheck_claims, skip_rounds, &mut transcript)?;
+		sumcheck::batch_verify_zerocheck(zerocheck_claims, *skip_rounds, &mut transcript)
+			.in_stage(ProofStage::Zerocheck)?;
 
-	let zerocheck_eval_claims =
-		sumcheck::make_zerocheck_eval_claims(zerocheck_oracle_metas, zerocheck_output)?;
+	let zerocheck_eval_claims = sumcheck::make_zerocheck_eval_claims(
+		zerocheck_oracle_metas.iter().cloned(),
+		zerocheck_output,
+	)?;
 
 	// Evalcheck
 	let eval_claims = greedy_evalcheck::verify(
 		&mut oracles,
 		chain!(prodcheck_eval_claims, zerocheck_eval_claims, exp_eval_claims,),
 		&mut transcript,
-	)?;
+	)
+	.in_stage(ProofStage::Evalcheck)?;
 
-	// Reduce committed evaluation claims to PIOP sumcheck claims
-	let system = ring_switch::EvalClaimSystem::new(
-		&oracles,
-		&commit_meta,
-		&oracle_to_commit_index,
-		&eval_claims,
-	)?;
+	// Reduce committed evaluation claims to PIOP sumcheck claims, for each commit batch
+	let batches = commit_batches
+		.iter()
+		.map(|commit_batch| commit_batch.batch)
+		.collect::<Vec<_>>();
+	let eval_claims = eval_claims_by_commit_batch(&oracles, &batches, eval_claims)?;
+	let systems = izip!(commit_batches, &eval_claims)
+		.map(|(commit_batch, eval_claims)| {
+			ring_switch::EvalClaimSystem::new(
+				&oracles,
+				&commit_batch.commit_meta,
+				&commit_batch.oracle_to_commit_index,
+				eval_claims,
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
 
-	let ring_switch::ReducedClaim {
-		transparents,
-		sumcheck_claims: piop_sumcheck_claims,
-	} = ring_switch::verify::<_, Tower, _>(&system, &mut transcript)?;
+	let reduced_claims = systems
+		.iter()
+		.map(|system| ring_switch::verify::<_, Tower, _>(system, &mut transcript))
+		.collect::<Result<Vec<_>, _>>()
+		.in_stage(ProofStage::RingSwitch)?;
 
 	// Prove evaluation claims using PIOP compiler
-	piop::verify(
-		&commit_meta,
-		&merkle_scheme,
-		&fri_params,
-		&commitment,
-		&transparents,
-		&piop_sumcheck_claims,
-		&mut transcript,
-	)?;
+	for (commit_batch, commitment, reduced_claim) in
+		izip!(commit_batches, &commitments, reduced_claims)
+	{
+		let ring_switch::ReducedClaim {
+			transparents,
+			sumcheck_claims: piop_sumcheck_claims,
+		} = reduced_claim;
+		piop::verify(
+			&commit_batch.commit_meta,
+			merkle_scheme,
+			&commit_batch.fri_params,
+			commitment,
+			&transparents,
+			&piop_sumcheck_claims,
+			&mut transcript,
+		)
+		.in_stage(ProofStage::PcsOpening)?;
+	}
 
 	transcript.finalize()?;
 
@@ -256,6 +545,17 @@ fn verify_channels_balance<F: TowerField>(
 		return Err(VerificationError::IncorrectNumberOfFlushProducts.into());
 	}
 
+	let max_channel_id = permutation_challenges.len().saturating_sub(1);
+	if let Some(boundary) = boundaries
+		.iter()
+		.find(|boundary| boundary.channel_id > max_channel_id)
+	{
+		bail!(Error::ChannelIdOutOfRange {
+			max: max_channel_id,
+			got: boundary.channel_id,
+		});
+	}
+
 	let mut flush_iter = flushes
 		.iter()
 		.zip(flush_products.iter().copied())
@@ -263,41 +563,12 @@ fn verify_channels_balance<F: TowerField>(
 	while let Some((flush, _)) = flush_iter.peek() {
 		let channel_id = flush.channel_id;
 
-		let boundary_products =
-			boundaries
-				.iter()
-				.fold((F::ONE, F::ONE), |(pull_product, push_product), boundary| {
-					let Boundary {
-						channel_id: boundary_channel_id,
-						direction,
-						multiplicity,
-						values,
-						..
-					} = boundary;
-
-					if *boundary_channel_id == channel_id {
-						let (mixed_values, _) = values.iter().fold(
-							(permutation_challenges[channel_id], F::ONE),
-							|(sum, mixing), values| {
-								(sum + mixing * values, mixing * mixing_challenge)
-							},
-						);
-
-						let mixed_values_with_multiplicity =
-							mixed_values.pow_vartime([*multiplicity]);
-
-						return match direction {
-							FlushDirection::Pull => {
-								(pull_product * mixed_values_with_multiplicity, push_product)
-							}
-							FlushDirection::Push => {
-								(pull_product, push_product * mixed_values_with_multiplicity)
-							}
-						};
-					}
-
-					(pull_product, push_product)
-				});
+		let boundary_products = boundary_products(
+			boundaries,
+			channel_id,
+			mixing_challenge,
+			permutation_challenges[channel_id],
+		);
 
 		let (pull_product, push_product) = flush_iter
 			.peeking_take_while(|(flush, _)| flush.channel_id == channel_id)
@@ -318,9 +589,58 @@ fn verify_channels_balance<F: TowerField>(
 		}
 	}
 
+	// The boundaries on channels without flushes must balance each other.
+	for channel_id in boundaries
+		.iter()
+		.map(|boundary| boundary.channel_id)
+		.unique()
+		.filter(|&channel_id| flushes.iter().all(|flush| flush.channel_id != channel_id))
+	{
+		let (pull_product, push_product) = boundary_products(
+			boundaries,
+			channel_id,
+			mixing_challenge,
+			permutation_challenges[channel_id],
+		);
+		if pull_product != push_product {
+			return Err(VerificationError::ChannelUnbalanced { id: channel_id }.into());
+		}
+	}
+
 	Ok(())
 }
 
+/// Returns the products of the pulled and pushed boundary values on a channel.
+fn boundary_products<F: TowerField>(
+	boundaries: &[Boundary<F>],
+	channel_id: usize,
+	mixing_challenge: F,
+	permutation_challenge: F,
+) -> (F, F) {
+	boundaries
+		.iter()
+		.filter(|boundary| boundary.channel_id == channel_id)
+		.fold((F::ONE, F::ONE), |(pull_product, push_product), boundary| {
+			let (mixed_values, _) = boundary
+				.values
+				.iter()
+				.fold((permutation_challenge, F::ONE), |(sum, mixing), values| {
+					(sum + mixing * values, mixing * mixing_challenge)
+				});
+
+			let mixed_values_with_multiplicity = mixed_values.pow_vartime([boundary.multiplicity]);
+
+			match boundary.direction {
+				FlushDirection::Pull => {
+					(pull_product * mixed_values_with_multiplicity, push_product)
+				}
+				FlushDirection::Push => {
+					(pull_product, push_product * mixed_values_with_multiplicity)
+				}
+			}
+		})
+}
+
 /// For each flush,
 /// - if there is a selector $S$, we are taking the Grand product of the composite $1 + S * (-1 + r
 ///   + F_0 + F_1 s + F_2 s^1 + …)$
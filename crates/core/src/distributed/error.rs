@@ -0,0 +1,21 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_utils::SerializationError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("the number of workers must be a power of two")]
+	PowerOfTwoWorkersRequired,
+	#[error("the number of subtree roots must be a power of two")]
+	PowerOfTwoSubtreesRequired,
+	#[error("expected {expected} messages from the workers, got {actual}")]
+	IncorrectNumberOfMessages { expected: usize, actual: usize },
+	#[error("received an unexpected message")]
+	UnexpectedMessage,
+	#[error("worker {worker} failed: {message}")]
+	Worker { worker: usize, message: String },
+	#[error("transport error: {0}")]
+	Transport(Box<dyn std::error::Error + Send + Sync>),
+	#[error("serialization error: {0}")]
+	Serialization(#[from] SerializationError),
+}
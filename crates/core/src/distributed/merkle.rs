@@ -0,0 +1,61 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_hash::PseudoCompressionFunction;
+
+use super::error::Error;
+
+/// Merges the roots of equally sized Merkle subtrees into the root of the whole tree.
+///
+/// The subtrees must commit to consecutive ranges of the leaves, in order. The result is the
+/// root of the binary Merkle tree built over all the leaves with the same compression function.
+pub fn merge_subtree_roots<D, C>(compression: &C, roots: Vec<D>) -> Result<D, Error>
+where
+	D: Clone,
+	C: PseudoCompressionFunction<D, 2>,
+{
+	if !roots.len().is_power_of_two() {
+		return Err(Error::PowerOfTwoSubtreesRequired);
+	}
+
+	let mut layer = roots;
+	while layer.len() > 1 {
+		layer = layer
+			.chunks_exact(2)
+			.map(|pair| compression.compress([pair[0].clone(), pair[1].clone()]))
+			.collect();
+	}
+	Ok(layer.pop().expect("the number of roots is a power of two"))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField8b, Field};
+	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver};
+
+	#[test]
+	fn test_merge_subtree_roots_matches_full_tree() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let data = repeat_with(|| Field::random(&mut rng))
+			.take(256)
+			.collect::<Vec<BinaryField8b>>();
+
+		let prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+		let (commitment, _) = prover.commit(&data, 1).unwrap();
+
+		for log_n_shards in 0..4 {
+			let shard_len = data.len() >> log_n_shards;
+			let roots = data
+				.chunks(shard_len)
+				.map(|shard| prover.commit(shard, 1).unwrap().0.root)
+				.collect();
+			let root = merge_subtree_roots(prover.scheme().compression(), roots).unwrap();
+			assert_eq!(root, commitment.root);
+		}
+	}
+}
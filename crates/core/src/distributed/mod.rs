@@ -0,0 +1,26 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Coordination of proving work sharded across worker processes.
+//!
+//! Large sumcheck instances are split along the variables that are folded last: a worker with
+//! index $j$ holds the restriction of the multilinears to the sub-hypercube where those
+//! variables encode $j$. Since the round polynomials are sums over the hypercube, the coordinator
+//! obtains them by adding up the partial round polynomials of the workers. Once the workers
+//! have folded all their variables, the coordinator gathers their multilinear evaluations and
+//! proves the remaining rounds locally, see [`DistributedSumcheckProver`].
+//!
+//! Merkle commitments are sharded the same way: each worker commits to a contiguous range of
+//! the leaves, and the coordinator merges the subtree roots with [`merge_subtree_roots`].
+//!
+//! The messages are exchanged through the [`CoordinatorTransport`] and [`WorkerTransport`]
+//! traits, which can be implemented on top of any transport such as MPI or gRPC.
+
+mod error;
+mod merkle;
+mod sumcheck;
+mod transport;
+
+pub use error::*;
+pub use merkle::*;
+pub use sumcheck::*;
+pub use transport::*;
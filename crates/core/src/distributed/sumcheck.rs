@@ -0,0 +1,434 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::Field;
+use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_math::EvaluationOrder;
+use binius_utils::{bail, checked_arithmetics::log2_strict_usize};
+
+use super::{
+	error::Error as DistributedError,
+	transport::{self, CoordinatorTransport, WorkerTransport},
+};
+use crate::protocols::sumcheck::{Error, RoundCoeffs, prove::SumcheckProver};
+
+/// A message from the coordinator to the sumcheck workers.
+#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+enum Command<F: Field> {
+	Execute { batch_coeff: F },
+	Fold { challenge: F },
+	Finish,
+}
+
+/// A message from a sumcheck worker to the coordinator.
+#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+enum Response<F: Field> {
+	RoundCoeffs(Vec<F>),
+	Folded,
+	MultilinearEvals(Vec<F>),
+	Failed(String),
+}
+
+impl<F: Field> Response<F> {
+	/// Converts the failure reported by a worker into an error.
+	fn check(self, worker: usize) -> Result<Self, DistributedError> {
+		match self {
+			Self::Failed(message) => Err(DistributedError::Worker { worker, message }),
+			response => Ok(response),
+		}
+	}
+}
+
+enum CoordinatorState<'a, F: Field> {
+	Sharded { round: usize },
+	Tail(Box<dyn SumcheckProver<F> + 'a>),
+}
+
+/// Builds the prover for the rounds that remain after the workers have folded all their
+/// variables.
+///
+/// The argument contains, for each multilinear, the evaluations gathered from the workers,
+/// ordered by the worker index. These are the evaluations of the partially evaluated
+/// multilinear on the hypercube over the variables the workers are sharded on.
+pub type TailProverBuilder<'a, F> =
+	Box<dyn FnOnce(Vec<Vec<F>>) -> Result<Box<dyn SumcheckProver<F> + 'a>, Error> + 'a>;
+
+/// A sumcheck prover that shards the evaluation domain across workers.
+///
+/// Each worker runs [`run_sumcheck_worker`] with a prover over its shard of the hypercube. With
+/// the [`EvaluationOrder::LowToHigh`] order, the worker with index $j$ proves the restriction of
+/// the multilinears to the highest $\log_2 w$ variables encoding $j$, where $w$ is the number of
+/// workers. With [`EvaluationOrder::HighToLow`], the workers are sharded on the lowest variables.
+/// The worker provers must be constructed with the sums over their shards as the claimed sums.
+///
+/// The coordinator adds up the round polynomials of the workers until they have folded all their
+/// variables. The last $\log_2 w$ rounds are then proven locally by the prover returned by the
+/// [`TailProverBuilder`].
+pub struct DistributedSumcheckProver<'a, F: Field, T: CoordinatorTransport> {
+	transport: T,
+	shard_n_vars: usize,
+	log_n_workers: usize,
+	evaluation_order: EvaluationOrder,
+	state: CoordinatorState<'a, F>,
+	build_tail: Option<TailProverBuilder<'a, F>>,
+}
+
+impl<'a, F: Field, T: CoordinatorTransport> DistributedSumcheckProver<'a, F, T> {
+	pub fn new(
+		transport: T,
+		shard_n_vars: usize,
+		evaluation_order: EvaluationOrder,
+		build_tail: TailProverBuilder<'a, F>,
+	) -> Result<Self, Error> {
+		if !transport.n_workers().is_power_of_two() {
+			bail!(DistributedError::PowerOfTwoWorkersRequired);
+		}
+		let log_n_workers = log2_strict_usize(transport.n_workers());
+
+		Ok(Self {
+			transport,
+			shard_n_vars,
+			log_n_workers,
+			evaluation_order,
+			state: CoordinatorState::Sharded { round: 0 },
+			build_tail: Some(build_tail),
+		})
+	}
+
+	/// Switches to the tail prover once the workers have folded all their variables.
+	fn ensure_tail(&mut self) -> Result<(), Error> {
+		if !matches!(self.state, CoordinatorState::Sharded { round } if round == self.shard_n_vars)
+		{
+			return Ok(());
+		}
+
+		transport::broadcast(&mut self.transport, &Command::<F>::Finish)?;
+		let worker_evals = transport::gather::<_, Response<F>>(&mut self.transport)?
+			.into_iter()
+			.enumerate()
+			.map(|(worker, response)| match response.check(worker)? {
+				Response::MultilinearEvals(evals) => Ok(evals),
+				_ => Err(DistributedError::UnexpectedMessage),
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let n_multilinears = worker_evals.first().map_or(0, Vec::len);
+		if worker_evals
+			.iter()
+			.any(|evals| evals.len() != n_multilinears)
+		{
+			bail!(DistributedError::UnexpectedMessage);
+		}
+		let multilinear_evals = (0..n_multilinears)
+			.map(|i| worker_evals.iter().map(|evals| evals[i]).collect())
+			.collect();
+
+		let build_tail = self
+			.build_tail
+			.take()
+			.expect("the tail prover is built once, when leaving the sharded state");
+		let tail = build_tail(multilinear_evals)?;
+		if tail.n_vars() != self.log_n_workers || tail.evaluation_order() != self.evaluation_order {
+			bail!(Error::NumberOfVariablesMismatch);
+		}
+		self.state = CoordinatorState::Tail(tail);
+		Ok(())
+	}
+}
+
+impl<F: Field, T: CoordinatorTransport> SumcheckProver<F> for DistributedSumcheckProver<'_, F, T> {
+	fn n_vars(&self) -> usize {
+		self.shard_n_vars + self.log_n_workers
+	}
+
+	fn evaluation_order(&self) -> EvaluationOrder {
+		self.evaluation_order
+	}
+
+	fn execute(&mut self, batch_coeff: F) -> Result<RoundCoeffs<F>, Error> {
+		self.ensure_tail()?;
+		match &mut self.state {
+			CoordinatorState::Sharded { .. } => {
+				transport::broadcast(&mut self.transport, &Command::Execute { batch_coeff })?;
+				let mut round_coeffs = RoundCoeffs::default();
+				for (worker, response) in transport::gather::<_, Response<F>>(&mut self.transport)?
+					.into_iter()
+					.enumerate()
+				{
+					match response.check(worker)? {
+						Response::RoundCoeffs(coeffs) => round_coeffs += &RoundCoeffs(coeffs),
+						_ => bail!(DistributedError::UnexpectedMessage),
+					}
+				}
+				Ok(round_coeffs)
+			}
+			CoordinatorState::Tail(tail) => tail.execute(batch_coeff),
+		}
+	}
+
+	fn fold(&mut self, challenge: F) -> Result<(), Error> {
+		match &mut self.state {
+			CoordinatorState::Sharded { round } => {
+				if *round == self.shard_n_vars {
+					bail!(Error::ExpectedExecution);
+				}
+				transport::broadcast(&mut self.transport, &Command::Fold { challenge })?;
+				for (worker, response) in transport::gather::<_, Response<F>>(&mut self.transport)?
+					.into_iter()
+					.enumerate()
+				{
+					if !matches!(response.check(worker)?, Response::Folded) {
+						bail!(DistributedError::UnexpectedMessage);
+					}
+				}
+				*round += 1;
+				Ok(())
+			}
+			CoordinatorState::Tail(tail) => tail.fold(challenge),
+		}
+	}
+
+	fn finish(mut self: Box<Self>) -> Result<Vec<F>, Error> {
+		self.ensure_tail()?;
+		match self.state {
+			CoordinatorState::Sharded { .. } => bail!(Error::ExpectedFold),
+			CoordinatorState::Tail(tail) => tail.finish(),
+		}
+	}
+}
+
+/// Serves the requests of a [`DistributedSumcheckProver`] coordinator with a prover over the
+/// shard of this worker, until the coordinator gathers the multilinear evaluations.
+///
+/// Failures of the prover are reported to the coordinator before being returned.
+pub fn run_sumcheck_worker<F, Prover, T>(prover: Prover, transport: &mut T) -> Result<(), Error>
+where
+	F: Field,
+	Prover: SumcheckProver<F>,
+	T: WorkerTransport,
+{
+	let mut prover = Box::new(prover);
+	loop {
+		let response = match transport::receive::<_, Command<F>>(transport)? {
+			Command::Execute { batch_coeff } => prover
+				.execute(batch_coeff)
+				.map(|coeffs| Response::RoundCoeffs(coeffs.0)),
+			Command::Fold { challenge } => prover.fold(challenge).map(|_| Response::Folded),
+			Command::Finish => break,
+		};
+		respond(transport, response)?;
+	}
+
+	let response = prover.finish().map(Response::MultilinearEvals);
+	respond(transport, response)
+}
+
+fn respond<F: Field, T: WorkerTransport>(
+	transport: &mut T,
+	response: Result<Response<F>, Error>,
+) -> Result<(), Error> {
+	match response {
+		Ok(response) => Ok(transport::send(transport, &response)?),
+		Err(err) => {
+			transport::send(transport, &Response::<F>::Failed(err.to_string()))?;
+			Err(err)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		iter::repeat_with,
+		sync::mpsc::{Receiver, Sender, channel},
+		thread,
+	};
+
+	use binius_field::{BinaryField8b, BinaryField128b};
+	use binius_hal::make_portable_backend;
+	use binius_math::{IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::protocols::{
+		sumcheck::{CompositeSumClaim, prove::RegularSumcheckProver},
+		test_utils::TestProductComposition,
+	};
+
+	type F = BinaryField128b;
+	type FDomain = BinaryField8b;
+
+	#[derive(Debug, thiserror::Error)]
+	#[error("channel disconnected")]
+	struct Disconnected;
+
+	struct ChannelCoordinator {
+		workers: Vec<(Sender<Vec<u8>>, Receiver<Vec<u8>>)>,
+	}
+
+	impl CoordinatorTransport for ChannelCoordinator {
+		type Error = Disconnected;
+
+		fn n_workers(&self) -> usize {
+			self.workers.len()
+		}
+
+		fn broadcast(&mut self, message: &[u8]) -> Result<(), Disconnected> {
+			for (sender, _) in &self.workers {
+				sender.send(message.to_vec()).map_err(|_| Disconnected)?;
+			}
+			Ok(())
+		}
+
+		fn gather(&mut self) -> Result<Vec<Vec<u8>>, Disconnected> {
+			self.workers
+				.iter()
+				.map(|(_, receiver)| receiver.recv().map_err(|_| Disconnected))
+				.collect()
+		}
+	}
+
+	struct ChannelWorker {
+		receiver: Receiver<Vec<u8>>,
+		sender: Sender<Vec<u8>>,
+	}
+
+	impl WorkerTransport for ChannelWorker {
+		type Error = Disconnected;
+
+		fn receive(&mut self) -> Result<Vec<u8>, Disconnected> {
+			self.receiver.recv().map_err(|_| Disconnected)
+		}
+
+		fn send(&mut self, message: &[u8]) -> Result<(), Disconnected> {
+			self.sender.send(message.to_vec()).map_err(|_| Disconnected)
+		}
+	}
+
+	fn regular_prover<'a>(
+		evaluation_order: EvaluationOrder,
+		multilinears: &[Vec<F>],
+		composition: &'a TestProductComposition,
+		backend: &'a binius_hal::CpuBackend,
+	) -> Box<dyn SumcheckProver<F> + 'a> {
+		let sum = (0..multilinears[0].len())
+			.map(|i| multilinears.iter().map(|values| values[i]).product::<F>())
+			.sum();
+		let multilinears = multilinears
+			.iter()
+			.map(|values| {
+				let n_vars = log2_strict_usize(values.len());
+				MLEDirectAdapter::from(MultilinearExtension::new(n_vars, values.clone()).unwrap())
+			})
+			.collect::<Vec<_>>();
+
+		let prover = RegularSumcheckProver::<FDomain, _, _, _, _>::new(
+			evaluation_order,
+			multilinears,
+			[CompositeSumClaim { composition, sum }],
+			IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+			|_| 1,
+			backend,
+		)
+		.unwrap();
+		Box::new(prover)
+	}
+
+	fn test_distributed_matches_regular(evaluation_order: EvaluationOrder) {
+		let log_n_workers = 2;
+		let shard_n_vars = 4;
+		let n_vars = shard_n_vars + log_n_workers;
+		let n_multilinears = 3;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let multilinears = repeat_with(|| {
+			repeat_with(|| F::random(&mut rng))
+				.take(1 << n_vars)
+				.collect::<Vec<_>>()
+		})
+		.take(n_multilinears)
+		.collect::<Vec<_>>();
+
+		// Worker j holds the rows whose variables folded last encode j.
+		let shard = |values: &[F], worker: usize| -> Vec<F> {
+			match evaluation_order {
+				EvaluationOrder::LowToHigh => {
+					values[worker << shard_n_vars..(worker + 1) << shard_n_vars].to_vec()
+				}
+				EvaluationOrder::HighToLow => values
+					.iter()
+					.skip(worker)
+					.step_by(1 << log_n_workers)
+					.copied()
+					.collect(),
+			}
+		};
+
+		let composition = TestProductComposition::new(n_multilinears);
+		let backend = make_portable_backend();
+		let challenges = repeat_with(|| F::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+
+		let mut regular = regular_prover(evaluation_order, &multilinears, &composition, &backend);
+
+		thread::scope(|scope| {
+			let mut coordinator_channels = Vec::new();
+			for worker in 0..1 << log_n_workers {
+				let (to_worker, from_coordinator) = channel();
+				let (to_coordinator, from_worker) = channel();
+				coordinator_channels.push((to_worker, from_worker));
+
+				let shards = multilinears
+					.iter()
+					.map(|values| shard(values, worker))
+					.collect::<Vec<_>>();
+				let (composition, backend) = (&composition, &backend);
+				scope.spawn(move || {
+					let prover = regular_prover(evaluation_order, &shards, composition, backend);
+					let mut transport = ChannelWorker {
+						receiver: from_coordinator,
+						sender: to_coordinator,
+					};
+					run_sumcheck_worker(prover, &mut transport).unwrap();
+				});
+			}
+
+			let build_tail: TailProverBuilder<'_, F> = Box::new(|multilinears| {
+				Ok(regular_prover(evaluation_order, &multilinears, &composition, &backend))
+			});
+			let mut distributed = Box::new(
+				DistributedSumcheckProver::new(
+					ChannelCoordinator {
+						workers: coordinator_channels,
+					},
+					shard_n_vars,
+					evaluation_order,
+					build_tail,
+				)
+				.unwrap(),
+			);
+			assert_eq!(distributed.n_vars(), n_vars);
+
+			let batch_coeff = F::random(&mut rng);
+			for &challenge in &challenges {
+				assert_eq!(
+					distributed.execute(batch_coeff).unwrap(),
+					regular.execute(batch_coeff).unwrap()
+				);
+				distributed.fold(challenge).unwrap();
+				regular.fold(challenge).unwrap();
+			}
+			assert_eq!(distributed.finish().unwrap(), regular.finish().unwrap());
+		});
+	}
+
+	#[test]
+	fn test_distributed_sumcheck_low_to_high() {
+		test_distributed_matches_regular(EvaluationOrder::LowToHigh);
+	}
+
+	#[test]
+	fn test_distributed_sumcheck_high_to_low() {
+		test_distributed_matches_regular(EvaluationOrder::HighToLow);
+	}
+}
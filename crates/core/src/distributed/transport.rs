@@ -0,0 +1,79 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+
+use super::error::Error;
+
+/// The coordinator side of the transport between the coordinator and the workers.
+pub trait CoordinatorTransport {
+	type Error: std::error::Error + Send + Sync + 'static;
+
+	/// The number of workers.
+	fn n_workers(&self) -> usize;
+
+	/// Sends a message to all workers.
+	fn broadcast(&mut self, message: &[u8]) -> Result<(), Self::Error>;
+
+	/// Receives one message from every worker, ordered by the worker index.
+	fn gather(&mut self) -> Result<Vec<Vec<u8>>, Self::Error>;
+}
+
+/// The worker side of the transport between the coordinator and the workers.
+pub trait WorkerTransport {
+	type Error: std::error::Error + Send + Sync + 'static;
+
+	/// Receives the next message from the coordinator.
+	fn receive(&mut self) -> Result<Vec<u8>, Self::Error>;
+
+	/// Sends a message to the coordinator.
+	fn send(&mut self, message: &[u8]) -> Result<(), Self::Error>;
+}
+
+// Messages are serialized in the canonical tower basis, so that the parties don't have to agree
+// on the field representation.
+const MODE: SerializationMode = SerializationMode::CanonicalTower;
+
+fn transport_error(err: impl std::error::Error + Send + Sync + 'static) -> Error {
+	Error::Transport(Box::new(err))
+}
+
+pub(super) fn broadcast<T: CoordinatorTransport>(
+	transport: &mut T,
+	message: &impl SerializeBytes,
+) -> Result<(), Error> {
+	let mut bytes = Vec::new();
+	message.serialize(&mut bytes, MODE)?;
+	transport.broadcast(&bytes).map_err(transport_error)
+}
+
+pub(super) fn gather<T: CoordinatorTransport, M: DeserializeBytes>(
+	transport: &mut T,
+) -> Result<Vec<M>, Error> {
+	let messages = transport.gather().map_err(transport_error)?;
+	if messages.len() != transport.n_workers() {
+		return Err(Error::IncorrectNumberOfMessages {
+			expected: transport.n_workers(),
+			actual: messages.len(),
+		});
+	}
+	messages
+		.iter()
+		.map(|bytes| M::deserialize(bytes.as_slice(), MODE).map_err(Error::from))
+		.collect()
+}
+
+pub(super) fn send<T: WorkerTransport>(
+	transport: &mut T,
+	message: &impl SerializeBytes,
+) -> Result<(), Error> {
+	let mut bytes = Vec::new();
+	message.serialize(&mut bytes, MODE)?;
+	transport.send(&bytes).map_err(transport_error)
+}
+
+pub(super) fn receive<T: WorkerTransport, M: DeserializeBytes>(
+	transport: &mut T,
+) -> Result<M, Error> {
+	let bytes = transport.receive().map_err(transport_error)?;
+	Ok(M::deserialize(bytes.as_slice(), MODE)?)
+}
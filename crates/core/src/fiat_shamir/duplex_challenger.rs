@@ -0,0 +1,307 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{cmp::min, mem};
+
+use binius_hash::{Vision32Sponge, poseidon2::Poseidon2Bn254Sponge};
+use bytes::{Buf, BufMut, buf::UninitSlice};
+
+use super::Challenger;
+
+const PADDING_START: u8 = 0x80;
+const PADDING_END: u8 = 0x01;
+
+/// A duplex sponge that a [`DuplexChallenger`] absorbs observed bytes into and squeezes sampled
+/// bytes from.
+///
+/// This is the extension point for Fiat-Shamir transcripts that are not built on a byte-oriented
+/// hash function, such as algebraic sponges over a prime field, which are needed to verify Binius
+/// proofs inside another proof system. The implementation decides how bytes are encoded into and
+/// decoded from its state; the [`DuplexChallenger`] handles buffering, padding and switching
+/// between observing and sampling.
+pub trait DuplexSponge: Default {
+	/// The number of bytes absorbed by [`Self::absorb_block`].
+	const ABSORB_RATE: usize;
+	/// The number of bytes produced by [`Self::squeeze_block`].
+	const SQUEEZE_RATE: usize;
+
+	/// Absorbs a block of exactly [`Self::ABSORB_RATE`] bytes into the state and permutes it.
+	fn absorb_block(&mut self, block: &[u8]);
+
+	/// Writes exactly [`Self::SQUEEZE_RATE`] bytes derived from the state into `out` and permutes
+	/// the state.
+	fn squeeze_block(&mut self, out: &mut [u8]);
+}
+
+impl DuplexSponge for Vision32Sponge {
+	const ABSORB_RATE: usize = Vision32Sponge::RATE;
+	const SQUEEZE_RATE: usize = Vision32Sponge::RATE;
+
+	fn absorb_block(&mut self, block: &[u8]) {
+		Vision32Sponge::absorb_block(self, block)
+	}
+
+	fn squeeze_block(&mut self, out: &mut [u8]) {
+		Vision32Sponge::squeeze_block(self, out)
+	}
+}
+
+impl DuplexSponge for Poseidon2Bn254Sponge {
+	const ABSORB_RATE: usize = Poseidon2Bn254Sponge::ABSORB_RATE;
+	const SQUEEZE_RATE: usize = Poseidon2Bn254Sponge::SQUEEZE_RATE;
+
+	fn absorb_block(&mut self, block: &[u8]) {
+		Poseidon2Bn254Sponge::absorb_block(self, block)
+	}
+
+	fn squeeze_block(&mut self, out: &mut [u8]) {
+		Poseidon2Bn254Sponge::squeeze_block(self, out)
+	}
+}
+
+/// Challenger type which implements `[BufMut]` over a [`DuplexSponge`]
+#[derive(Debug, Clone)]
+pub struct DuplexObserver<S> {
+	index: usize,
+	buffer: Vec<u8>,
+	sponge: S,
+}
+
+/// Challenger type which implements `[Buf]` over a [`DuplexSponge`]
+#[derive(Debug, Clone)]
+pub struct DuplexSampler<S> {
+	index: usize,
+	buffer: Vec<u8>,
+	sponge: S,
+}
+
+/// Challenger interface over a [`DuplexSponge`].
+///
+/// Observed bytes are absorbed in blocks of [`DuplexSponge::ABSORB_RATE`] bytes. When switching to
+/// sampling, the pending bytes are padded with the multi-rate padding `10*1` and absorbed, so that
+/// every sampling phase is preceded by at least one absorbed block. Sampled bytes are read from
+/// consecutive squeezed blocks; the bytes of a squeezed block that are not read before switching
+/// back to observing are discarded.
+#[derive(Debug, Clone)]
+pub enum DuplexChallenger<S> {
+	Observer(DuplexObserver<S>),
+	Sampler(DuplexSampler<S>),
+}
+
+impl<S: DuplexSponge> Default for DuplexChallenger<S> {
+	fn default() -> Self {
+		Self::Observer(DuplexObserver::new(S::default()))
+	}
+}
+
+impl<S: DuplexSponge> Challenger for DuplexChallenger<S> {
+	/// This returns the inner challenger which implements `[BufMut]`
+	fn observer(&mut self) -> &mut impl BufMut {
+		if let Self::Sampler(sampler) = self {
+			let sponge = mem::take(&mut sampler.sponge);
+			*self = Self::Observer(DuplexObserver::new(sponge));
+		}
+		match self {
+			Self::Observer(observer) => observer,
+			Self::Sampler(_) => unreachable!(),
+		}
+	}
+
+	/// This returns the inner challenger which implements `[Buf]`
+	fn sampler(&mut self) -> &mut impl Buf {
+		if let Self::Observer(observer) = self {
+			let sponge = mem::take(observer).into_sponge();
+			*self = Self::Sampler(DuplexSampler::new(sponge));
+		}
+		match self {
+			Self::Sampler(sampler) => sampler,
+			Self::Observer(_) => unreachable!(),
+		}
+	}
+}
+
+impl<S: DuplexSponge> Default for DuplexObserver<S> {
+	fn default() -> Self {
+		Self::new(S::default())
+	}
+}
+
+impl<S: DuplexSponge> DuplexObserver<S> {
+	fn new(sponge: S) -> Self {
+		Self {
+			index: 0,
+			buffer: vec![0; S::ABSORB_RATE],
+			sponge,
+		}
+	}
+
+	/// Pads and absorbs the pending bytes, returning the sponge.
+	fn into_sponge(mut self) -> S {
+		let padding = &mut self.buffer[self.index..];
+		padding.fill(0);
+		padding[0] |= PADDING_START;
+		padding[padding.len() - 1] |= PADDING_END;
+		self.sponge.absorb_block(&self.buffer);
+		self.sponge
+	}
+}
+
+impl<S: DuplexSponge> DuplexSampler<S> {
+	fn new(sponge: S) -> Self {
+		let mut sampler = Self {
+			index: 0,
+			buffer: vec![0; S::SQUEEZE_RATE],
+			sponge,
+		};
+		sampler.fill_buffer();
+		sampler
+	}
+
+	fn fill_buffer(&mut self) {
+		self.sponge.squeeze_block(&mut self.buffer);
+		self.index = 0;
+	}
+}
+
+impl<S: DuplexSponge> Buf for DuplexSampler<S> {
+	fn remaining(&self) -> usize {
+		usize::MAX
+	}
+
+	fn chunk(&self) -> &[u8] {
+		&self.buffer[self.index..]
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		while cnt > 0 {
+			let remaining = min(S::SQUEEZE_RATE - self.index, cnt);
+			cnt -= remaining;
+			self.index += remaining;
+			if self.index == S::SQUEEZE_RATE {
+				self.fill_buffer();
+			}
+		}
+	}
+}
+
+unsafe impl<S: DuplexSponge> BufMut for DuplexObserver<S> {
+	fn remaining_mut(&self) -> usize {
+		usize::MAX
+	}
+
+	unsafe fn advance_mut(&mut self, mut cnt: usize) {
+		while cnt > 0 {
+			let remaining = min(S::ABSORB_RATE - self.index, cnt);
+			cnt -= remaining;
+			self.index += remaining;
+			if self.index == S::ABSORB_RATE {
+				self.sponge.absorb_block(&self.buffer);
+				self.index = 0;
+			}
+		}
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		let buffer = &mut self.buffer[self.index..];
+		buffer.into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+	use rand::{RngCore, thread_rng};
+
+	use super::*;
+	use crate::{fiat_shamir::CanSample, transcript::ProverTranscript};
+
+	/// A toy sponge that records its inputs, for checking the challenger's block handling.
+	#[derive(Debug, Default, Clone)]
+	struct RecordingSponge {
+		absorbed: Vec<Vec<u8>>,
+		squeezed: u8,
+	}
+
+	impl DuplexSponge for RecordingSponge {
+		const ABSORB_RATE: usize = 8;
+		const SQUEEZE_RATE: usize = 4;
+
+		fn absorb_block(&mut self, block: &[u8]) {
+			assert_eq!(block.len(), Self::ABSORB_RATE);
+			self.absorbed.push(block.to_vec());
+		}
+
+		fn squeeze_block(&mut self, out: &mut [u8]) {
+			assert_eq!(out.len(), Self::SQUEEZE_RATE);
+			for byte in out {
+				*byte = self.squeezed;
+				self.squeezed += 1;
+			}
+		}
+	}
+
+	#[test]
+	fn test_duplex_challenger_blocks_and_padding() {
+		let mut challenger = DuplexChallenger::<RecordingSponge>::default();
+
+		challenger.observer().put_slice(&[1, 2, 3, 4, 5]);
+		challenger.observer().put_slice(&[6, 7, 8, 9]);
+
+		let mut out = [0u8; 6];
+		challenger.sampler().copy_to_slice(&mut out);
+		assert_eq!(out, [0, 1, 2, 3, 4, 5]);
+
+		challenger.observer().put_slice(&[10; 8]);
+		let mut out = [0u8; 2];
+		challenger.sampler().copy_to_slice(&mut out);
+
+		let DuplexChallenger::Sampler(sampler) = &challenger else {
+			panic!("challenger must be sampling");
+		};
+		assert_eq!(
+			sampler.sponge.absorbed,
+			vec![
+				vec![1, 2, 3, 4, 5, 6, 7, 8],
+				vec![9, 0x80, 0, 0, 0, 0, 0, 0x01],
+				vec![10; 8],
+				vec![0x80, 0, 0, 0, 0, 0, 0, 0x01],
+			]
+		);
+		// The unread bytes of the first sampling phase were discarded.
+		assert_eq!(out, [8, 9]);
+	}
+
+	#[test]
+	fn test_vision_duplex_challenger_is_deterministic() {
+		let mut observable = [0u8; 300];
+		thread_rng().fill_bytes(&mut observable);
+
+		let sample = |observable: &[u8]| {
+			let mut transcript = ProverTranscript::<DuplexChallenger<Vision32Sponge>>::new();
+			transcript.observe().write_bytes(observable);
+			CanSample::<BinaryField128b>::sample(&mut transcript)
+		};
+
+		assert_eq!(sample(&observable), sample(&observable));
+
+		let mut tampered = observable;
+		tampered[299] ^= 1;
+		assert_ne!(sample(&observable), sample(&tampered));
+		assert_ne!(sample(&observable), sample(&observable[..299]));
+	}
+
+	#[test]
+	fn test_poseidon2_duplex_challenger_vector() {
+		// The observed bytes are padded into a single block of 62 bytes.
+		let mut challenger = DuplexChallenger::<Poseidon2Bn254Sponge>::default();
+		challenger.observer().put_slice(b"binius");
+		let mut out = [0u8; 16];
+		challenger.sampler().copy_to_slice(&mut out);
+		assert_eq!(
+			out,
+			[
+				0xa7, 0xf3, 0x83, 0xba, 0xf3, 0xd8, 0x4e, 0xea, 0x68, 0x04, 0xb2, 0x51, 0x3a, 0xc7,
+				0x72, 0x78
+			]
+		);
+	}
+}
@@ -1,13 +1,19 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod duplex_challenger;
 mod hasher_challenger;
 mod sampling;
 
 use bytes::{Buf, BufMut};
+pub use duplex_challenger::{DuplexChallenger, DuplexSponge};
 pub use hasher_challenger::HasherChallenger;
 pub use sampling::*;
 
 /// A Fiat-Shamir challenger that can observe prover messages and sample verifier randomness.
+///
+/// The transcripts are generic over this trait. [`HasherChallenger`] builds a challenger from any
+/// byte-oriented hash function, and [`DuplexChallenger`] builds one from a [`DuplexSponge`], which
+/// is the extension point for algebraic sponges and other external Fiat-Shamir instantiations.
 pub trait Challenger {
 	/// Returns an infinite buffer for reading pseudo-random bytes.
 	fn sampler(&mut self) -> &mut impl Buf;
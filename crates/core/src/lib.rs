@@ -12,10 +12,13 @@
 #![allow(clippy::suspicious_arithmetic_impl)]
 #![allow(clippy::suspicious_op_assign_impl)]
 
+pub mod brakedown;
 pub mod composition;
 pub mod constraint_system;
+pub mod distributed;
 pub mod fiat_shamir;
 pub mod merkle_tree;
+pub mod metrics;
 pub mod oracle;
 pub mod piop;
 pub mod polynomial;
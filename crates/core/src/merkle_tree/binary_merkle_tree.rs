@@ -5,11 +5,21 @@ use std::{array, fmt::Debug, mem::MaybeUninit};
 use binius_field::TowerField;
 use binius_hash::{PseudoCompressionFunction, multi_digest::ParallelDigest};
 use binius_maybe_rayon::{prelude::*, slice::ParallelSlice};
-use binius_utils::{bail, checked_arithmetics::log2_strict_usize, mem::slice_assume_init_mut};
+use binius_utils::{
+	bail,
+	checked_arithmetics::{log2_ceil_usize, log2_strict_usize},
+	mem::slice_assume_init_mut,
+};
 use digest::{FixedOutputReset, Output, crypto_common::BlockSizeUser};
 use tracing::instrument;
 
 use super::errors::Error;
+use crate::metrics;
+
+/// Base-2 logarithm of the number of subtrees compressed in parallel per thread.
+///
+/// Using more subtrees than threads lets the thread pool balance the load by work stealing.
+const LOG_SUBTREES_PER_THREAD: usize = 2;
 
 /// A binary Merkle tree that commits batches of vectors.
 ///
@@ -67,15 +77,61 @@ where
 	let mut inner_nodes = Vec::with_capacity(total_length);
 
 	hash_leaves(&mut inner_nodes.spare_capacity_mut()[..(1 << log_len)])?;
+	// One hash per leaf and one compression per inner node.
+	metrics::add_hash_invocations(total_length as u64);
+
+	let (leaves, mut remaining) = inner_nodes.spare_capacity_mut().split_at_mut(1 << log_len);
+	let leaves = unsafe {
+		// SAFETY: leaves were initialized by hash_leaves
+		slice_assume_init_mut(leaves)
+	};
+
+	// The lower layers are compressed independently in each subtree, so that a thread only
+	// synchronizes with the others once its subtree is complete rather than after every layer.
+	let log_subtrees = log_len
+		.min(log2_ceil_usize(binius_maybe_rayon::current_num_threads()) + LOG_SUBTREES_PER_THREAD);
+	let subtree_depth = log_len - log_subtrees;
+
+	let mut subtree_layers = (0..1 << log_subtrees)
+		.map(|_| Vec::with_capacity(subtree_depth))
+		.collect::<Vec<_>>();
+	for i in 1..(subtree_depth + 1) {
+		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (log_len - i));
+		remaining = next_remaining;
+
+		for (layers, chunk) in subtree_layers
+			.iter_mut()
+			.zip(next_layer.chunks_mut(1 << (subtree_depth - i)))
+		{
+			layers.push(chunk);
+		}
+	}
 
-	let (prev_layer, mut remaining) = inner_nodes.spare_capacity_mut().split_at_mut(1 << log_len);
+	leaves
+		.par_chunks(1 << subtree_depth)
+		.zip(subtree_layers.into_par_iter())
+		.for_each(|(subtree_leaves, layers)| {
+			let mut prev_layer: &[Digest] = subtree_leaves;
+			for next_layer in layers {
+				compress_layer_serial(compression, prev_layer, next_layer);
+				prev_layer = unsafe {
+					// SAFETY: next_layer was just initialized by compress_layer_serial
+					slice_assume_init_mut(next_layer)
+				};
+			}
+		});
 
+	// The subtree roots are the last layer compressed above.
+	let subtree_roots_start = (1 << (log_len + 1)) - (1 << (log_subtrees + 1));
+	let (compressed, mut remaining) = inner_nodes
+		.spare_capacity_mut()
+		.split_at_mut(subtree_roots_start + (1 << log_subtrees));
 	let mut prev_layer = unsafe {
-		// SAFETY: prev-layer was initialized by hash_leaves
-		slice_assume_init_mut(prev_layer)
+		// SAFETY: the subtree roots were initialized above
+		slice_assume_init_mut(&mut compressed[subtree_roots_start..])
 	};
-	for i in 1..(log_len + 1) {
-		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (log_len - i));
+	for i in 1..(log_subtrees + 1) {
+		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (log_subtrees - i));
 		remaining = next_remaining;
 
 		compress_layer(compression, prev_layer, next_layer);
@@ -169,13 +225,23 @@ where
 		})
 }
 
+fn compress_layer_serial<D, C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
+where
+	D: Clone,
+	C: PseudoCompressionFunction<D, 2>,
+{
+	for (prev_pair, next_digest) in prev_layer.chunks_exact(2).zip(next_layer) {
+		next_digest.write(compression.compress(array::from_fn(|i| prev_pair[i].clone())));
+	}
+}
+
 /// Hashes the elements in chunks of a vector into digests.
 ///
 /// Given a vector of elements and an output buffer of N hash digests, this splits the elements
 /// into N equal-sized chunks and hashes each chunks into the corresponding output digest. This
 /// returns the number of elements hashed into each digest.
 #[tracing::instrument("hash_interleaved", skip_all, level = "debug")]
-fn hash_interleaved<F, H>(
+pub(super) fn hash_interleaved<F, H>(
 	elems: &[F],
 	digests: &mut [MaybeUninit<Output<H::Digest>>],
 ) -> Result<(), Error>
@@ -195,7 +261,7 @@ where
 	hash_iterated::<_, H, _>(hash_data_iter, digests)
 }
 
-fn hash_iterated<F, H, ParIter>(
+pub(super) fn hash_iterated<F, H, ParIter>(
 	iterated_chunks: ParIter,
 	digests: &mut [MaybeUninit<Output<H::Digest>>],
 ) -> Result<(), Error>
@@ -12,6 +12,8 @@ pub enum Error {
 	IncorrectBatchSize,
 	#[error("The argument length must be a power of two.")]
 	PowerOfTwoLengthRequired,
+	#[error("the Merkle tree arity must be a power of two greater than one, got {arity}")]
+	InvalidArity { arity: usize },
 	#[error("The layer does not exist in the Merkle tree")]
 	IncorrectLayerDepth,
 	#[error("transcript error: {0}")]
@@ -0,0 +1,285 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Binary Merkle trees over vectors that grow by appending batches.
+//!
+//! An [`IncrementalMerkleTree`] has the smallest power-of-two number of leaves that holds the
+//! committed batches, and the leaves past the last batch are the digest of an empty batch. The
+//! commitments and openings are those of a [`BinaryMerkleTreeScheme`] over this padded vector, so
+//! they are verified by the existing scheme. Appending a batch recomputes only the digests on the
+//! path from its leaf to the root, and doubles the number of leaves when the tree is full, so an
+//! append costs `O(log n)` compressions amortized.
+
+use binius_field::TowerField;
+use binius_hash::{PseudoCompressionFunction, multi_digest::ParallelDigest};
+use binius_maybe_rayon::{prelude::*, slice::ParallelSlice};
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+use bytes::BufMut;
+use digest::{FixedOutputReset, Output, core_api::BlockSizeUser};
+use getset::Getters;
+
+use super::{
+	binary_merkle_tree::hash_iterated,
+	errors::Error,
+	merkle_tree_vcs::{Commitment, MerkleTreeProver},
+	scheme::{BinaryMerkleTreeScheme, hash_field_elems},
+};
+use crate::transcript::TranscriptWriter;
+
+/// A binary Merkle tree that commits a growing vector of batches.
+#[derive(Debug, Clone)]
+pub struct IncrementalMerkleTree<D> {
+	/// The number of committed batches.
+	len: usize,
+	/// The layers of digests, from the leaves to the root.
+	layers: Vec<Vec<D>>,
+	/// The digests of the empty subtrees of every height up to the root.
+	empty: Vec<D>,
+}
+
+impl<D: Clone + Send + Sync> IncrementalMerkleTree<D> {
+	fn new<C>(compression: &C, mut leaves: Vec<D>, empty_leaf: D) -> Self
+	where
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		let len = leaves.len();
+		let log_len = log2_ceil_usize(len);
+
+		let mut empty = Vec::with_capacity(log_len + 1);
+		empty.push(empty_leaf);
+		for height in 0..log_len {
+			let child = empty[height].clone();
+			empty.push(compression.compress([child.clone(), child]));
+		}
+
+		leaves.resize(1 << log_len, empty[0].clone());
+		let mut layers = Vec::with_capacity(log_len + 1);
+		layers.push(leaves);
+		for height in 0..log_len {
+			let next_layer = layers[height]
+				.par_chunks_exact(2)
+				.map(|pair: &[D]| compression.compress([pair[0].clone(), pair[1].clone()]))
+				.collect();
+			layers.push(next_layer);
+		}
+
+		Self { len, layers, empty }
+	}
+
+	/// The number of committed batches.
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Base-2 logarithm of the number of leaves, including the empty ones.
+	pub fn log_len(&self) -> usize {
+		self.layers.len() - 1
+	}
+
+	pub fn root(&self) -> D {
+		self.layers
+			.last()
+			.and_then(|root| root.first())
+			.expect("IncrementalMerkleTree layers can't be empty")
+			.clone()
+	}
+
+	pub fn commitment(&self) -> Commitment<D> {
+		Commitment {
+			root: self.root(),
+			depth: self.log_len(),
+		}
+	}
+
+	pub fn layer(&self, layer_depth: usize) -> Result<&[D], Error> {
+		if layer_depth > self.log_len() {
+			bail!(Error::IncorrectLayerDepth);
+		}
+		Ok(&self.layers[self.log_len() - layer_depth])
+	}
+
+	/// Get a Merkle branch for the given index
+	///
+	/// Throws if the index is out of range
+	pub fn branch(&self, index: usize, layer_depth: usize) -> Result<Vec<D>, Error> {
+		let log_len = self.log_len();
+		if index >= 1 << log_len || layer_depth > log_len {
+			return Err(Error::IndexOutOfRange {
+				max: (1 << log_len) - 1,
+			});
+		}
+
+		let branch = (0..log_len - layer_depth)
+			.map(|height| self.layers[height][(index >> height) ^ 1].clone())
+			.collect();
+		Ok(branch)
+	}
+
+	fn push_leaf<C>(&mut self, compression: &C, leaf: D)
+	where
+		C: PseudoCompressionFunction<D, 2>,
+	{
+		if self.len == self.layers[0].len() {
+			self.grow(compression);
+		}
+
+		let mut index = self.len;
+		self.layers[0][index] = leaf;
+		for height in 0..self.log_len() {
+			let pair = [
+				self.layers[height][index & !1].clone(),
+				self.layers[height][index | 1].clone(),
+			];
+			index >>= 1;
+			self.layers[height + 1][index] = compression.compress(pair);
+		}
+		self.len += 1;
+	}
+
+	/// Doubles the number of leaves, with the new leaves empty.
+	fn grow<C>(&mut self, compression: &C)
+	where
+		C: PseudoCompressionFunction<D, 2>,
+	{
+		for (layer, empty) in self.layers.iter_mut().zip(&self.empty) {
+			layer.resize(2 * layer.len(), empty.clone());
+		}
+		let top = self
+			.empty
+			.last()
+			.expect("empty subtrees can't be empty")
+			.clone();
+		self.empty.push(compression.compress([top.clone(), top]));
+
+		let old_root = self.layers.last().expect("layers can't be empty");
+		let root = compression.compress([old_root[0].clone(), old_root[1].clone()]);
+		self.layers.push(vec![root]);
+	}
+}
+
+/// A prover for vectors committed with [`IncrementalMerkleTree`]s.
+///
+/// The scheme is a [`BinaryMerkleTreeScheme`], and [`Self::append`] updates a committed tree
+/// without recommitting the vector.
+#[derive(Debug, Getters)]
+pub struct IncrementalMerkleTreeProver<T, H: ParallelDigest, C> {
+	#[getset(get = "pub")]
+	scheme: BinaryMerkleTreeScheme<T, H::Digest, C>,
+}
+
+impl<T, C, H: ParallelDigest> IncrementalMerkleTreeProver<T, H, C> {
+	pub fn new(compression: C) -> Self {
+		Self {
+			scheme: BinaryMerkleTreeScheme::new(compression),
+		}
+	}
+}
+
+impl<F, H, C> IncrementalMerkleTreeProver<F, H, C>
+where
+	F: TowerField,
+	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync,
+{
+	fn empty_leaf() -> Output<H::Digest> {
+		hash_field_elems::<F, H::Digest>(&[])
+	}
+
+	/// Appends `data`, in batches of `batch_size` values, to a committed vector and returns the
+	/// updated commitment.
+	pub fn append(
+		&self,
+		committed: &mut IncrementalMerkleTree<Output<H::Digest>>,
+		data: &[F],
+		batch_size: usize,
+	) -> Result<Commitment<Output<H::Digest>>, Error> {
+		if data.len() % batch_size != 0 {
+			bail!(Error::IncorrectBatchSize);
+		}
+
+		for batch in data.chunks(batch_size) {
+			let leaf = hash_field_elems::<F, H::Digest>(batch);
+			committed.push_leaf(self.scheme.compression(), leaf);
+		}
+		Ok(committed.commitment())
+	}
+}
+
+impl<F, H, C> MerkleTreeProver<F> for IncrementalMerkleTreeProver<F, H, C>
+where
+	F: TowerField,
+	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync,
+{
+	type Scheme = BinaryMerkleTreeScheme<F, H::Digest, C>;
+	type Committed = IncrementalMerkleTree<Output<H::Digest>>;
+
+	fn scheme(&self) -> &Self::Scheme {
+		&self.scheme
+	}
+
+	/// Commits a vector of any number of batches, padded with empty leaves to a power of two.
+	fn commit(
+		&self,
+		data: &[F],
+		batch_size: usize,
+	) -> Result<(Commitment<Output<H::Digest>>, Self::Committed), Error> {
+		if data.len() % batch_size != 0 {
+			bail!(Error::IncorrectBatchSize);
+		}
+
+		let leaves = data
+			.par_chunks(batch_size)
+			.map(hash_field_elems::<F, H::Digest>)
+			.collect();
+		let tree =
+			IncrementalMerkleTree::new(self.scheme.compression(), leaves, Self::empty_leaf());
+		Ok((tree.commitment(), tree))
+	}
+
+	#[allow(clippy::type_complexity)]
+	fn commit_iterated<ParIter>(
+		&self,
+		iterated_chunks: ParIter,
+		log_len: usize,
+	) -> Result<(Commitment<Output<H::Digest>>, Self::Committed), Error>
+	where
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	{
+		let mut leaves = Vec::with_capacity(1 << log_len);
+		hash_iterated::<F, H, _>(
+			iterated_chunks,
+			&mut leaves.spare_capacity_mut()[..1 << log_len],
+		)?;
+		unsafe {
+			// SAFETY: the leaves were initialized by hash_iterated
+			leaves.set_len(1 << log_len);
+		}
+		let tree =
+			IncrementalMerkleTree::new(self.scheme.compression(), leaves, Self::empty_leaf());
+		Ok((tree.commitment(), tree))
+	}
+
+	fn layer<'a>(
+		&self,
+		committed: &'a Self::Committed,
+		depth: usize,
+	) -> Result<&'a [Output<H::Digest>], Error> {
+		committed.layer(depth)
+	}
+
+	fn prove_opening<B: BufMut>(
+		&self,
+		committed: &Self::Committed,
+		layer_depth: usize,
+		index: usize,
+		proof: &mut TranscriptWriter<B>,
+	) -> Result<(), Error> {
+		let branch = committed.branch(index, layer_depth)?;
+		proof.write_slice(&branch);
+		Ok(())
+	}
+}
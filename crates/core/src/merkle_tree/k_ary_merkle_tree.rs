@@ -0,0 +1,447 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Merkle trees in which every inner node compresses `ARITY` children.
+//!
+//! Higher-arity trees trade wider compressions for shallower trees: a 4-ary tree has half the
+//! depth of a binary tree over the same leaves, so an opening verifies half as many compressions.
+//!
+//! Layer depths are measured in bits, as for
+//! [`BinaryMerkleTreeScheme`](super::BinaryMerkleTreeScheme): the layer at depth `d` has `2^d`
+//! nodes. Going up from the leaves, every layer is `log2(ARITY)` bits shallower than the one below
+//! it. When the depth of the leaves is not a multiple of `log2(ARITY)`, the root compresses fewer
+//! than `ARITY` children and the missing children are the default digest. The tree therefore only
+//! has layers at depths `log_len - i * log2(ARITY)` and at depth 0.
+
+use std::{array, marker::PhantomData, mem::MaybeUninit};
+
+use binius_field::TowerField;
+use binius_hash::{PseudoCompressionFunction, multi_digest::ParallelDigest};
+use binius_maybe_rayon::{prelude::*, slice::ParallelSlice};
+use binius_utils::{
+	bail,
+	checked_arithmetics::{log2_ceil_usize, log2_strict_usize},
+};
+use bytes::{Buf, BufMut};
+use digest::{Digest, FixedOutputReset, Output, core_api::BlockSizeUser};
+use getset::Getters;
+
+use super::{
+	binary_merkle_tree::{hash_interleaved, hash_iterated},
+	errors::{Error, VerificationError},
+	merkle_tree_vcs::{Commitment, MerkleTreeProver, MerkleTreeScheme},
+	scheme::hash_field_elems,
+};
+use crate::transcript::{TranscriptReader, TranscriptWriter};
+
+/// A Merkle tree with `ARITY` children per inner node that commits batches of vectors.
+#[derive(Debug, Clone)]
+pub struct KAryMerkleTree<D, const ARITY: usize> {
+	/// Base-2 logarithm of the number of leaves
+	pub log_len: usize,
+	/// The layers of the tree, from the leaf digests to the root
+	pub layers: Vec<Vec<D>>,
+}
+
+impl<D: Clone, const ARITY: usize> KAryMerkleTree<D, ARITY> {
+	pub fn root(&self) -> D {
+		self.layers
+			.last()
+			.and_then(|layer| layer.first())
+			.expect("MerkleTree layers can't be empty")
+			.clone()
+	}
+
+	pub fn layer(&self, layer_depth: usize) -> Result<&[D], Error> {
+		self.layers
+			.iter()
+			.find(|layer| layer.len() == 1 << layer_depth)
+			.map(Vec::as_slice)
+			.ok_or(Error::IncorrectLayerDepth)
+	}
+
+	/// Get a Merkle branch for the given index.
+	///
+	/// The branch holds the siblings of the path from the leaf to the layer at `layer_depth`,
+	/// ordered from the leaves up.
+	pub fn branch(&self, mut index: usize, layer_depth: usize) -> Result<Vec<D>, Error> {
+		if index >= 1 << self.log_len || layer_depth > self.log_len {
+			return Err(Error::IndexOutOfRange {
+				max: (1 << self.log_len) - 1,
+			});
+		}
+		// Check that the layer exists.
+		self.layer(layer_depth)?;
+
+		let mut branch = Vec::new();
+		for layer in self
+			.layers
+			.iter()
+			.take_while(|layer| layer.len() > 1 << layer_depth)
+		{
+			let log_width = group_log_width::<ARITY>(log2_strict_usize(layer.len()));
+			let group_start = (index >> log_width) << log_width;
+			branch.extend(
+				layer[group_start..group_start + (1 << log_width)]
+					.iter()
+					.enumerate()
+					.filter(|&(i, _)| group_start + i != index)
+					.map(|(_, digest)| digest.clone()),
+			);
+			index >>= log_width;
+		}
+		Ok(branch)
+	}
+}
+
+fn build<F, H, C, const ARITY: usize>(
+	compression: &C,
+	elements: &[F],
+	batch_size: usize,
+) -> Result<KAryMerkleTree<Output<H::Digest>, ARITY>, Error>
+where
+	F: TowerField,
+	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	C: PseudoCompressionFunction<Output<H::Digest>, ARITY> + Sync,
+{
+	if elements.len() % batch_size != 0 {
+		bail!(Error::IncorrectBatchSize);
+	}
+
+	let len = elements.len() / batch_size;
+
+	if !len.is_power_of_two() {
+		bail!(Error::PowerOfTwoLengthRequired);
+	}
+
+	let log_len = log2_strict_usize(len);
+
+	internal_build(compression, |leaves| hash_interleaved::<_, H>(elements, leaves), log_len)
+}
+
+fn build_from_iterator<F, H, C, ParIter, const ARITY: usize>(
+	compression: &C,
+	iterated_chunks: ParIter,
+	log_len: usize,
+) -> Result<KAryMerkleTree<Output<H::Digest>, ARITY>, Error>
+where
+	F: TowerField,
+	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	C: PseudoCompressionFunction<Output<H::Digest>, ARITY> + Sync,
+	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+{
+	internal_build(compression, |leaves| hash_iterated::<F, H, _>(iterated_chunks, leaves), log_len)
+}
+
+fn internal_build<D, C, const ARITY: usize>(
+	compression: &C,
+	// Must either successfully initialize the passed in slice or return error
+	hash_leaves: impl FnOnce(&mut [MaybeUninit<D>]) -> Result<(), Error>,
+	log_len: usize,
+) -> Result<KAryMerkleTree<D, ARITY>, Error>
+where
+	D: Clone + Default + Send + Sync,
+	C: PseudoCompressionFunction<D, ARITY> + Sync,
+{
+	check_arity::<ARITY>()?;
+
+	let mut leaves = Vec::with_capacity(1 << log_len);
+	hash_leaves(&mut leaves.spare_capacity_mut()[..1 << log_len])?;
+	unsafe {
+		// SAFETY: the leaves were initialized by hash_leaves
+		leaves.set_len(1 << log_len);
+	}
+
+	let mut layers = vec![leaves];
+	while let Some(next_layer) = layers
+		.last()
+		.filter(|layer| layer.len() > 1)
+		.map(|layer| compress_layer::<_, _, ARITY>(compression, layer))
+	{
+		layers.push(next_layer);
+	}
+
+	Ok(KAryMerkleTree { log_len, layers })
+}
+
+#[tracing::instrument("KAryMerkleTree::compress_layer", skip_all, level = "debug")]
+fn compress_layer<D, C, const ARITY: usize>(compression: &C, layer: &[D]) -> Vec<D>
+where
+	D: Clone + Default + Send + Sync,
+	C: PseudoCompressionFunction<D, ARITY> + Sync,
+{
+	let log_width = group_log_width::<ARITY>(log2_strict_usize(layer.len()));
+	layer
+		.par_chunks_exact(1 << log_width)
+		.map(|children| compress_children::<_, _, ARITY>(compression, children))
+		.collect()
+}
+
+/// Compresses up to `ARITY` children, padding with default digests.
+fn compress_children<D, C, const ARITY: usize>(compression: &C, children: &[D]) -> D
+where
+	D: Clone + Default,
+	C: PseudoCompressionFunction<D, ARITY>,
+{
+	compression.compress(array::from_fn(|i| children.get(i).cloned().unwrap_or_default()))
+}
+
+/// Returns the base-2 logarithm of the number of children of the nodes in the layer above a layer
+/// at the given depth.
+fn group_log_width<const ARITY: usize>(layer_depth: usize) -> usize {
+	(ARITY.ilog2() as usize).min(layer_depth)
+}
+
+fn check_arity<const ARITY: usize>() -> Result<(), Error> {
+	if ARITY < 2 || !ARITY.is_power_of_two() {
+		bail!(Error::InvalidArity { arity: ARITY });
+	}
+	Ok(())
+}
+
+/// Folds a full layer of digests up to the root.
+fn fold_to_root<D, C, const ARITY: usize>(compression: &C, digests: &[D]) -> Result<D, Error>
+where
+	D: Clone + Default + Send + Sync,
+	C: PseudoCompressionFunction<D, ARITY> + Sync,
+{
+	check_arity::<ARITY>()?;
+	if !digests.len().is_power_of_two() {
+		bail!(Error::PowerOfTwoLengthRequired);
+	}
+
+	let mut digests = digests.to_vec();
+	while digests.len() > 1 {
+		digests = compress_layer::<_, _, ARITY>(compression, &digests);
+	}
+	Ok(digests.remove(0))
+}
+
+/// The [`MerkleTreeScheme`] of a [`KAryMerkleTree`].
+#[derive(Debug, Getters)]
+pub struct KAryMerkleTreeScheme<T, H, C, const ARITY: usize> {
+	#[getset(get = "pub")]
+	compression: C,
+	// This makes it so that `KAryMerkleTreeScheme` remains Send + Sync
+	// See https://doc.rust-lang.org/nomicon/phantom-data.html#table-of-phantomdata-patterns
+	_phantom: PhantomData<fn() -> (T, H)>,
+}
+
+impl<T, H, C, const ARITY: usize> KAryMerkleTreeScheme<T, H, C, ARITY> {
+	pub fn new(compression: C) -> Self {
+		Self {
+			compression,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<F, H, C, const ARITY: usize> MerkleTreeScheme<F> for KAryMerkleTreeScheme<F, H, C, ARITY>
+where
+	F: TowerField,
+	H: Digest + BlockSizeUser,
+	C: PseudoCompressionFunction<Output<H>, ARITY> + Sync,
+{
+	type Digest = Output<H>;
+
+	/// The deepest layer of the tree that is not deeper than the one minimizing the proof size of
+	/// a binary tree.
+	fn optimal_verify_layer(&self, n_queries: usize, tree_depth: usize) -> usize {
+		let target = log2_ceil_usize(n_queries).min(tree_depth);
+		layer_depths::<ARITY>(tree_depth)
+			.find(|&depth| depth <= target)
+			.unwrap_or(0)
+	}
+
+	fn proof_size(&self, len: usize, n_queries: usize, layer_depth: usize) -> Result<usize, Error> {
+		if !len.is_power_of_two() {
+			bail!(Error::PowerOfTwoLengthRequired)
+		}
+
+		let log_len = log2_strict_usize(len);
+
+		if !layer_depths::<ARITY>(log_len).any(|depth| depth == layer_depth) {
+			bail!(Error::IncorrectLayerDepth)
+		}
+
+		let siblings_per_query = layer_depths::<ARITY>(log_len)
+			.take_while(|&depth| depth > layer_depth)
+			.map(|depth| (1 << group_log_width::<ARITY>(depth)) - 1)
+			.sum::<usize>();
+		Ok((siblings_per_query * n_queries + (1 << layer_depth)) * <H as Digest>::output_size())
+	}
+
+	fn verify_vector(
+		&self,
+		root: &Self::Digest,
+		data: &[F],
+		batch_size: usize,
+	) -> Result<(), Error> {
+		if data.len() % batch_size != 0 {
+			bail!(Error::IncorrectBatchSize);
+		}
+
+		let digests = data
+			.chunks(batch_size)
+			.map(|chunk| hash_field_elems::<_, H>(chunk))
+			.collect::<Vec<_>>();
+
+		if fold_to_root::<_, _, ARITY>(&self.compression, &digests)? != *root {
+			bail!(VerificationError::InvalidProof)
+		}
+		Ok(())
+	}
+
+	fn verify_layer(
+		&self,
+		root: &Self::Digest,
+		layer_depth: usize,
+		layer_digests: &[Self::Digest],
+	) -> Result<(), Error> {
+		if 1 << layer_depth != layer_digests.len() {
+			bail!(VerificationError::IncorrectVectorLength)
+		}
+
+		if fold_to_root::<_, _, ARITY>(&self.compression, layer_digests)? != *root {
+			bail!(VerificationError::InvalidProof)
+		}
+		Ok(())
+	}
+
+	fn verify_opening<B: Buf>(
+		&self,
+		mut index: usize,
+		values: &[F],
+		layer_depth: usize,
+		tree_depth: usize,
+		layer_digests: &[Self::Digest],
+		proof: &mut TranscriptReader<B>,
+	) -> Result<(), Error> {
+		check_arity::<ARITY>()?;
+
+		if (1 << layer_depth) != layer_digests.len() {
+			bail!(VerificationError::IncorrectVectorLength);
+		}
+
+		if index >= (1 << tree_depth) {
+			bail!(Error::IndexOutOfRange {
+				max: (1 << tree_depth) - 1
+			});
+		}
+
+		if !layer_depths::<ARITY>(tree_depth).any(|depth| depth == layer_depth) {
+			bail!(Error::IncorrectLayerDepth);
+		}
+
+		let mut digest = hash_field_elems::<_, H>(values);
+		for depth in layer_depths::<ARITY>(tree_depth).take_while(|&depth| depth > layer_depth) {
+			let log_width = group_log_width::<ARITY>(depth);
+			let position = index & ((1 << log_width) - 1);
+			let mut children = proof.read_vec((1 << log_width) - 1)?;
+			children.insert(position, digest);
+			digest = compress_children::<_, _, ARITY>(&self.compression, &children);
+			index >>= log_width;
+		}
+
+		(digest == layer_digests[index])
+			.then_some(())
+			.ok_or_else(|| VerificationError::InvalidProof.into())
+	}
+}
+
+/// Returns the depths of the layers of a tree with leaves at depth `log_len`, from the leaves to
+/// the root.
+fn layer_depths<const ARITY: usize>(log_len: usize) -> impl Iterator<Item = usize> {
+	let mut next = Some(log_len);
+	std::iter::from_fn(move || {
+		let depth = next?;
+		next = (depth > 0).then(|| depth - group_log_width::<ARITY>(depth));
+		Some(depth)
+	})
+}
+
+/// The [`MerkleTreeProver`] of a [`KAryMerkleTree`].
+#[derive(Debug, Getters)]
+pub struct KAryMerkleTreeProver<T, H: ParallelDigest, C, const ARITY: usize> {
+	#[getset(get = "pub")]
+	scheme: KAryMerkleTreeScheme<T, H::Digest, C, ARITY>,
+}
+
+impl<T, C, H: ParallelDigest, const ARITY: usize> KAryMerkleTreeProver<T, H, C, ARITY> {
+	pub fn new(compression: C) -> Self {
+		Self {
+			scheme: KAryMerkleTreeScheme::new(compression),
+		}
+	}
+}
+
+impl<F, H, C, const ARITY: usize> MerkleTreeProver<F> for KAryMerkleTreeProver<F, H, C, ARITY>
+where
+	F: TowerField,
+	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	C: PseudoCompressionFunction<Output<H::Digest>, ARITY> + Sync,
+{
+	type Scheme = KAryMerkleTreeScheme<F, H::Digest, C, ARITY>;
+	type Committed = KAryMerkleTree<Output<H::Digest>, ARITY>;
+
+	fn scheme(&self) -> &Self::Scheme {
+		&self.scheme
+	}
+
+	fn commit(
+		&self,
+		data: &[F],
+		batch_size: usize,
+	) -> Result<(Commitment<Output<H::Digest>>, Self::Committed), Error> {
+		let tree = build::<_, H, _, ARITY>(self.scheme.compression(), data, batch_size)?;
+
+		let commitment = Commitment {
+			root: tree.root(),
+			depth: tree.log_len,
+		};
+
+		Ok((commitment, tree))
+	}
+
+	#[allow(clippy::type_complexity)]
+	fn commit_iterated<ParIter>(
+		&self,
+		iterated_chunks: ParIter,
+		log_len: usize,
+	) -> Result<(Commitment<Output<H::Digest>>, Self::Committed), Error>
+	where
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	{
+		let tree = build_from_iterator::<F, H, C, _, ARITY>(
+			self.scheme.compression(),
+			iterated_chunks,
+			log_len,
+		)?;
+
+		let commitment = Commitment {
+			root: tree.root(),
+			depth: tree.log_len,
+		};
+
+		Ok((commitment, tree))
+	}
+
+	fn layer<'a>(
+		&self,
+		committed: &'a Self::Committed,
+		depth: usize,
+	) -> Result<&'a [Output<H::Digest>], Error> {
+		committed.layer(depth)
+	}
+
+	fn prove_opening<B: BufMut>(
+		&self,
+		committed: &Self::Committed,
+		layer_depth: usize,
+		index: usize,
+		proof: &mut TranscriptWriter<B>,
+	) -> Result<(), Error> {
+		let branch = committed.branch(index, layer_depth)?;
+		proof.write_slice(&branch);
+		Ok(())
+	}
+}
@@ -1,9 +1,10 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_maybe_rayon::iter::IndexedParallelIterator;
+use binius_utils::bail;
 use bytes::{Buf, BufMut};
 
-use super::errors::Error;
+use super::errors::{Error, VerificationError};
 use crate::transcript::{TranscriptReader, TranscriptWriter};
 
 /// A Merkle tree commitment.
@@ -18,6 +19,22 @@ pub struct Commitment<Digest> {
 	pub depth: usize,
 }
 
+/// A Merkle tree cap commitment.
+///
+/// Instead of the root, the committer publishes the `2^height` digests of the layer at depth
+/// `height`. Opening proofs then stop at the cap, which saves `height` digests per query in a
+/// binary tree at the cost of a larger commitment. A cap of height 0 is equivalent to a root
+/// commitment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleCap<Digest> {
+	/// The digests of the cap layer.
+	pub digests: Vec<Digest>,
+	/// The depth of the cap layer, i.e. the base-2 logarithm of the number of cap digests.
+	pub height: usize,
+	/// The depth of the Merkle tree.
+	pub depth: usize,
+}
+
 /// A Merkle tree scheme.
 pub trait MerkleTreeScheme<T>: Sync {
 	type Digest: Clone + PartialEq + Eq;
@@ -63,6 +80,40 @@ pub trait MerkleTreeScheme<T>: Sync {
 		layer_digests: &[Self::Digest],
 		proof: &mut TranscriptReader<B>,
 	) -> Result<(), Error>;
+
+	/// Returns the total byte-size of the proofs for multiple opening queries against a cap.
+	///
+	/// Unlike [`Self::proof_size`], this excludes the cap digests, which are part of the
+	/// commitment.
+	fn cap_proof_size(&self, len: usize, n_queries: usize, height: usize) -> Result<usize, Error> {
+		Ok(self.proof_size(len, n_queries, height)? - self.proof_size(len, 0, height)?)
+	}
+
+	/// Verify that a cap is consistent with a root commitment.
+	fn verify_cap(
+		&self,
+		commitment: &Commitment<Self::Digest>,
+		cap: &MerkleCap<Self::Digest>,
+	) -> Result<(), Error> {
+		if commitment.depth != cap.depth {
+			bail!(VerificationError::IncorrectProofShape);
+		}
+		self.verify_layer(&commitment.root, cap.height, &cap.digests)
+	}
+
+	/// Verify an opening proof for an entry in a vector committed with a cap.
+	fn verify_cap_opening<B: Buf>(
+		&self,
+		index: usize,
+		values: &[T],
+		cap: &MerkleCap<Self::Digest>,
+		proof: &mut TranscriptReader<B>,
+	) -> Result<(), Error> {
+		if cap.height > cap.depth {
+			bail!(Error::IncorrectLayerDepth);
+		}
+		self.verify_opening(index, values, cap.height, cap.depth, &cap.digests, proof)
+	}
 }
 
 /// A Merkle tree prover for a particular scheme.
@@ -116,4 +167,21 @@ pub trait MerkleTreeProver<T>: Sync {
 		index: usize,
 		proof: &mut TranscriptWriter<B>,
 	) -> Result<(), Error>;
+
+	/// Returns the cap of the given height of a committed tree.
+	fn cap(
+		&self,
+		commitment: &Commitment<<Self::Scheme as MerkleTreeScheme<T>>::Digest>,
+		committed: &Self::Committed,
+		height: usize,
+	) -> Result<MerkleCap<<Self::Scheme as MerkleTreeScheme<T>>::Digest>, Error> {
+		if height > commitment.depth {
+			bail!(Error::IncorrectLayerDepth);
+		}
+		Ok(MerkleCap {
+			digests: self.layer(committed, height)?.to_vec(),
+			height,
+			depth: commitment.depth,
+		})
+	}
 }
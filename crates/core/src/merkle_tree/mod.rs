@@ -2,6 +2,8 @@
 
 mod binary_merkle_tree;
 mod errors;
+mod incremental_merkle_tree;
+mod k_ary_merkle_tree;
 #[allow(clippy::module_inception)]
 mod merkle_tree_vcs;
 mod prover;
@@ -10,6 +12,8 @@ mod scheme;
 mod tests;
 
 pub use binary_merkle_tree::*;
+pub use incremental_merkle_tree::{IncrementalMerkleTree, IncrementalMerkleTreeProver};
+pub use k_ary_merkle_tree::{KAryMerkleTree, KAryMerkleTreeProver, KAryMerkleTreeScheme};
 pub use merkle_tree_vcs::*;
 pub use prover::BinaryMerkleTreeProver;
 pub use scheme::BinaryMerkleTreeScheme;
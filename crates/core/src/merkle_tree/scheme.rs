@@ -164,7 +164,7 @@ where
 }
 
 /// Hashes a slice of tower field elements.
-fn hash_field_elems<F, H>(elems: &[F]) -> Output<H>
+pub(super) fn hash_field_elems<F, H>(elems: &[F]) -> Output<H>
 where
 	F: TowerField,
 	H: Digest + BlockSizeUser,
@@ -4,10 +4,17 @@ use core::slice;
 use std::iter::repeat_with;
 
 use binius_field::{BinaryField16b, Field};
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::{
+	PseudoCompressionFunction,
+	blake3::{Blake3, Blake3DigestCompression},
+	groestl::{Groestl256, Groestl256ByteCompression},
+};
 use rand::{SeedableRng, rngs::StdRng};
 
-use super::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme};
+use super::{
+	BinaryMerkleTreeProver, IncrementalMerkleTreeProver, KAryMerkleTreeProver, MerkleTreeProver,
+	MerkleTreeScheme, errors::Error,
+};
 use crate::{fiat_shamir::HasherChallenger, transcript::ProverTranscript};
 
 #[test]
@@ -29,7 +36,7 @@ fn test_binary_merkle_vcs_commit_prove_open_correctly() {
 			.prove_opening(&tree, 0, i, &mut proof_writer.message())
 			.unwrap();
 
-		let mut proof_reader = proof_writer.into_verifier();
+		let mut proof_reader = proof_writer.into_verifier().unwrap();
 		mr_prover
 			.scheme()
 			.verify_opening(
@@ -68,7 +75,7 @@ fn test_binary_merkle_vcs_commit_layer_prove_open_correctly() {
 				.prove_opening(&tree, layer_depth, i, &mut proof_writer.message())
 				.unwrap();
 
-			let mut proof_reader = proof_writer.into_verifier();
+			let mut proof_reader = proof_writer.into_verifier().unwrap();
 			mr_prover
 				.scheme()
 				.verify_opening(
@@ -100,3 +107,300 @@ fn test_binary_merkle_vcs_verify_vector() {
 		.verify_vector(&commitment.root, &data, 1)
 		.unwrap();
 }
+
+#[test]
+fn test_binary_merkle_vcs_blake3_commit_prove_open_correctly() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = BinaryMerkleTreeProver::<_, Blake3, _>::new(Blake3DigestCompression::default());
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(16)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+
+	assert_eq!(commitment.root, tree.root());
+
+	for (i, value) in data.iter().enumerate() {
+		let mut proof_writer = ProverTranscript::<HasherChallenger<Blake3>>::new();
+		mr_prover
+			.prove_opening(&tree, 0, i, &mut proof_writer.message())
+			.unwrap();
+
+		let mut proof_reader = proof_writer.into_verifier().unwrap();
+		mr_prover
+			.scheme()
+			.verify_opening(
+				i,
+				slice::from_ref(value),
+				0,
+				4,
+				&[commitment.root],
+				&mut proof_reader.message(),
+			)
+			.unwrap();
+	}
+}
+
+fn check_k_ary_merkle_vcs_open_all_layers<const ARITY: usize>(log_len: usize) {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover =
+		KAryMerkleTreeProver::<_, Blake3, _, ARITY>::new(Blake3DigestCompression::default());
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(1 << log_len)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+
+	assert_eq!(commitment.root, tree.root());
+	assert_eq!(commitment.depth, log_len);
+
+	mr_prover
+		.scheme()
+		.verify_vector(&commitment.root, &data, 1)
+		.unwrap();
+
+	let layer_depths = tree
+		.layers
+		.iter()
+		.map(|layer| layer.len().ilog2() as usize)
+		.collect::<Vec<_>>();
+	for &layer_depth in &layer_depths {
+		let layer = mr_prover.layer(&tree, layer_depth).unwrap();
+		mr_prover
+			.scheme()
+			.verify_layer(&commitment.root, layer_depth, layer)
+			.unwrap();
+
+		for (i, value) in data.iter().enumerate() {
+			let mut proof_writer = ProverTranscript::<HasherChallenger<Blake3>>::new();
+			mr_prover
+				.prove_opening(&tree, layer_depth, i, &mut proof_writer.message())
+				.unwrap();
+
+			let mut proof_reader = proof_writer.into_verifier().unwrap();
+			mr_prover
+				.scheme()
+				.verify_opening(
+					i,
+					slice::from_ref(value),
+					layer_depth,
+					log_len,
+					layer,
+					&mut proof_reader.message(),
+				)
+				.unwrap();
+			proof_reader.finalize().unwrap();
+		}
+	}
+
+	let optimal_layer = mr_prover.scheme().optimal_verify_layer(3, log_len);
+	assert!(layer_depths.contains(&optimal_layer));
+}
+
+#[test]
+fn test_k_ary_merkle_vcs_commit_prove_open_correctly() {
+	check_k_ary_merkle_vcs_open_all_layers::<4>(4);
+	check_k_ary_merkle_vcs_open_all_layers::<8>(6);
+}
+
+#[test]
+fn test_k_ary_merkle_vcs_partial_top_layer() {
+	check_k_ary_merkle_vcs_open_all_layers::<4>(5);
+	check_k_ary_merkle_vcs_open_all_layers::<8>(4);
+}
+
+#[test]
+fn test_k_ary_merkle_vcs_rejects_wrong_value() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover =
+		KAryMerkleTreeProver::<_, Blake3, _, 4>::new(Blake3DigestCompression::default());
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(16)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+
+	let mut proof_writer = ProverTranscript::<HasherChallenger<Blake3>>::new();
+	mr_prover
+		.prove_opening(&tree, 0, 5, &mut proof_writer.message())
+		.unwrap();
+
+	let mut proof_reader = proof_writer.into_verifier().unwrap();
+	let wrong_value = data[5] + BinaryField16b::ONE;
+	assert!(
+		mr_prover
+			.scheme()
+			.verify_opening(
+				5,
+				slice::from_ref(&wrong_value),
+				0,
+				4,
+				&[commitment.root],
+				&mut proof_reader.message(),
+			)
+			.is_err()
+	);
+	assert!(mr_prover.scheme().proof_size(16, 1, 1).is_err());
+	assert_eq!(mr_prover.scheme().proof_size(16, 1, 0).unwrap(), 7 * 32);
+}
+
+#[test]
+fn test_k_ary_merkle_tree_rejects_invalid_arity() {
+	let mr_prover =
+		KAryMerkleTreeProver::<_, Blake3, _, 3>::new(Blake3DigestCompression::default());
+
+	let data = vec![BinaryField16b::ONE; 8];
+	assert!(matches!(mr_prover.commit(&data, 1), Err(Error::InvalidArity { arity: 3 })));
+}
+
+#[test]
+fn test_merkle_cap_commit_prove_open_correctly() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(32)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+
+	for height in 0..=3 {
+		let cap = mr_prover.cap(&commitment, &tree, height).unwrap();
+		assert_eq!(cap.digests.len(), 1 << height);
+		mr_prover.scheme().verify_cap(&commitment, &cap).unwrap();
+
+		for (i, value) in data.iter().enumerate() {
+			let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			mr_prover
+				.prove_opening(&tree, height, i, &mut proof_writer.message())
+				.unwrap();
+
+			let mut proof_reader = proof_writer.into_verifier().unwrap();
+			mr_prover
+				.scheme()
+				.verify_cap_opening(i, slice::from_ref(value), &cap, &mut proof_reader.message())
+				.unwrap();
+		}
+	}
+
+	let cap = mr_prover.cap(&commitment, &tree, 2).unwrap();
+	assert_eq!(
+		mr_prover.scheme().cap_proof_size(32, 10, 2).unwrap()
+			+ cap.digests.len() * cap.digests[0].len(),
+		mr_prover.scheme().proof_size(32, 10, 2).unwrap()
+	);
+
+	let mut bad_cap = cap.clone();
+	bad_cap.digests.swap(0, 1);
+	assert!(
+		mr_prover
+			.scheme()
+			.verify_cap(&commitment, &bad_cap)
+			.is_err()
+	);
+
+	assert!(mr_prover.cap(&commitment, &tree, 6).is_err());
+}
+
+#[test]
+fn test_binary_merkle_tree_layers_are_compressions_of_children() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let compression = Groestl256ByteCompression;
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(compression.clone());
+
+	// The lower layers are built per subtree, so cover trees smaller and larger than the number
+	// of subtrees.
+	for log_len in [0, 1, 3, 9] {
+		let data = repeat_with(|| Field::random(&mut rng))
+			.take(2 << log_len)
+			.collect::<Vec<BinaryField16b>>();
+		let (commitment, tree) = mr_prover.commit(&data, 2).unwrap();
+		assert_eq!(commitment.root, tree.root());
+
+		for layer_depth in 0..log_len {
+			let parents = mr_prover.layer(&tree, layer_depth).unwrap();
+			let children = mr_prover.layer(&tree, layer_depth + 1).unwrap();
+			for (parent, pair) in parents.iter().zip(children.chunks_exact(2)) {
+				assert_eq!(*parent, compression.compress([pair[0], pair[1]]));
+			}
+		}
+	}
+}
+
+#[test]
+fn test_incremental_merkle_tree_appends_match_commit() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = IncrementalMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let binary_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(2 * 20)
+		.collect::<Vec<BinaryField16b>>();
+	let (mut commitment, mut tree) = mr_prover.commit(&[], 2).unwrap();
+	assert!(tree.is_empty());
+
+	for n_batches in 1..=20 {
+		commitment = mr_prover
+			.append(&mut tree, &data[2 * (n_batches - 1)..2 * n_batches], 2)
+			.unwrap();
+		assert_eq!(tree.len(), n_batches);
+
+		let (expected, _) = mr_prover.commit(&data[..2 * n_batches], 2).unwrap();
+		assert_eq!(commitment, expected);
+
+		// A full tree is the binary Merkle tree of the vector.
+		if n_batches.is_power_of_two() {
+			let (expected, _) = binary_prover.commit(&data[..2 * n_batches], 2).unwrap();
+			assert_eq!(commitment, expected);
+		}
+	}
+
+	assert!(mr_prover.append(&mut tree, &data[..3], 2).is_err());
+}
+
+#[test]
+fn test_incremental_merkle_tree_prove_open_correctly() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = IncrementalMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(23)
+		.collect::<Vec<BinaryField16b>>();
+	let (_, mut tree) = mr_prover.commit(&data[..7], 1).unwrap();
+	let commitment = mr_prover.append(&mut tree, &data[7..], 1).unwrap();
+	assert_eq!(commitment.depth, 5);
+
+	for layer_depth in 0..=commitment.depth {
+		let layer = mr_prover.layer(&tree, layer_depth).unwrap();
+		mr_prover
+			.scheme()
+			.verify_layer(&commitment.root, layer_depth, layer)
+			.unwrap();
+
+		for (i, value) in data.iter().enumerate() {
+			let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			mr_prover
+				.prove_opening(&tree, layer_depth, i, &mut proof_writer.message())
+				.unwrap();
+
+			let mut proof_reader = proof_writer.into_verifier().unwrap();
+			mr_prover
+				.scheme()
+				.verify_opening(
+					i,
+					slice::from_ref(value),
+					layer_depth,
+					commitment.depth,
+					layer,
+					&mut proof_reader.message(),
+				)
+				.unwrap();
+		}
+	}
+}
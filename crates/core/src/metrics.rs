@@ -0,0 +1,169 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Prover metrics, for finding the bottlenecks of a proof.
+//!
+//! The prover reports its main phases as `tracing` spans, which are best inspected with a tracing
+//! subscriber. [`ProverMetrics`] gives a summary of the same phases that can be consumed
+//! programmatically, together with counters of the expensive primitive operations: hashing,
+//! additive NTT butterflies and committed data. See
+//! [`crate::constraint_system::prove_with_metrics`].
+//!
+//! The counters are process-wide, so the metrics of proofs generated concurrently in the same
+//! process include the operations of each other.
+
+use std::{
+	sync::atomic::{AtomicU64, Ordering},
+	time::{Duration, Instant},
+};
+
+static HASH_INVOCATIONS: AtomicU64 = AtomicU64::new(0);
+static NTT_BUTTERFLIES: AtomicU64 = AtomicU64::new(0);
+static COMMITTED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Records `count` invocations of a hash or compression function.
+pub(crate) fn add_hash_invocations(count: u64) {
+	HASH_INVOCATIONS.fetch_add(count, Ordering::Relaxed);
+}
+
+/// Records `count` butterflies of an additive NTT.
+pub(crate) fn add_ntt_butterflies(count: u64) {
+	NTT_BUTTERFLIES.fetch_add(count, Ordering::Relaxed);
+}
+
+/// Records the commitment of `count` bytes of codewords.
+pub(crate) fn add_committed_bytes(count: u64) {
+	COMMITTED_BYTES.fetch_add(count, Ordering::Relaxed);
+}
+
+/// The wall time of a phase of the prover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseMetrics {
+	/// The name of the phase, matching the `phase` field of its tracing span.
+	pub name: &'static str,
+	pub duration: Duration,
+}
+
+/// A summary of the work done to generate a proof.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProverMetrics {
+	/// The phases of the prover, in execution order.
+	pub phases: Vec<PhaseMetrics>,
+	/// The total wall time of the prover.
+	pub total_duration: Duration,
+	/// The number of hash and compression function invocations in Merkle tree commitments.
+	pub hash_invocations: u64,
+	/// The number of additive NTT butterflies in Reed–Solomon encodings, each costing one field
+	/// multiplication and two field additions.
+	pub ntt_butterflies: u64,
+	/// The size in bytes of the committed codewords.
+	pub committed_bytes: u64,
+	/// The size in bytes of the proof.
+	pub proof_size: usize,
+}
+
+impl ProverMetrics {
+	/// Returns the wall time of the phase named `name`, if it was run.
+	pub fn phase_duration(&self, name: &str) -> Option<Duration> {
+		self.phases
+			.iter()
+			.filter(|phase| phase.name == name)
+			.map(|phase| phase.duration)
+			.reduce(|a, b| a + b)
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+	hash_invocations: u64,
+	ntt_butterflies: u64,
+	committed_bytes: u64,
+}
+
+impl Counters {
+	fn load() -> Self {
+		Self {
+			hash_invocations: HASH_INVOCATIONS.load(Ordering::Relaxed),
+			ntt_butterflies: NTT_BUTTERFLIES.load(Ordering::Relaxed),
+			committed_bytes: COMMITTED_BYTES.load(Ordering::Relaxed),
+		}
+	}
+}
+
+/// A phase started with [`MetricsRecorder::start_phase`].
+#[derive(Debug)]
+pub(crate) struct Phase {
+	name: &'static str,
+	start: Instant,
+}
+
+/// Collects the [`ProverMetrics`] of one proof.
+#[derive(Debug)]
+pub(crate) struct MetricsRecorder {
+	start: Instant,
+	counters: Counters,
+	phases: Vec<PhaseMetrics>,
+}
+
+impl MetricsRecorder {
+	pub fn new() -> Self {
+		Self {
+			start: Instant::now(),
+			counters: Counters::load(),
+			phases: Vec::new(),
+		}
+	}
+
+	pub fn start_phase(&self, name: &'static str) -> Phase {
+		Phase {
+			name,
+			start: Instant::now(),
+		}
+	}
+
+	pub fn finish_phase(&mut self, phase: Phase) {
+		self.phases.push(PhaseMetrics {
+			name: phase.name,
+			duration: phase.start.elapsed(),
+		});
+	}
+
+	pub fn finish(self, proof_size: usize) -> ProverMetrics {
+		let counters = Counters::load();
+		ProverMetrics {
+			phases: self.phases,
+			total_duration: self.start.elapsed(),
+			hash_invocations: counters.hash_invocations - self.counters.hash_invocations,
+			ntt_butterflies: counters.ntt_butterflies - self.counters.ntt_butterflies,
+			committed_bytes: counters.committed_bytes - self.counters.committed_bytes,
+			proof_size,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_recorder_counts_operations_since_creation() {
+		add_hash_invocations(5);
+		let mut recorder = MetricsRecorder::new();
+		let phase = recorder.start_phase("commit");
+		add_hash_invocations(3);
+		add_ntt_butterflies(7);
+		add_committed_bytes(64);
+		recorder.finish_phase(phase);
+		let phase = recorder.start_phase("commit");
+		recorder.finish_phase(phase);
+
+		let metrics = recorder.finish(100);
+		// Other tests may run concurrently and commit data.
+		assert!(metrics.hash_invocations >= 3);
+		assert!(metrics.ntt_butterflies >= 7);
+		assert!(metrics.committed_bytes >= 64);
+		assert_eq!(metrics.proof_size, 100);
+		assert_eq!(metrics.phases.len(), 2);
+		assert!(metrics.phase_duration("commit").unwrap() <= metrics.total_duration);
+		assert_eq!(metrics.phase_duration("zerocheck"), None);
+	}
+}
@@ -94,6 +94,34 @@ impl<F: Field> ConstraintSetBuilder<F> {
 		});
 	}
 
+	/// The number of constraints added so far.
+	pub fn len(&self) -> usize {
+		self.constraints.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.constraints.is_empty()
+	}
+
+	/// Removes the constraints added after the first `len` ones.
+	pub fn truncate(&mut self, len: usize) {
+		self.constraints.truncate(len);
+	}
+
+	/// Iterates over the zerocheck constraints as `(name, oracle_ids, composition)` tuples.
+	pub fn zerochecks(&self) -> impl Iterator<Item = (&str, &[OracleId], &ArithCircuit<F>)> {
+		self.constraints
+			.iter()
+			.filter(|constraint| matches!(constraint.predicate, ConstraintPredicate::Zero))
+			.map(|constraint| {
+				(
+					constraint.name.as_str(),
+					constraint.oracle_ids.as_slice(),
+					&constraint.composition,
+				)
+			})
+	}
+
 	/// Build a single constraint set, requiring that all included oracle n_vars are the same
 	pub fn build_one(
 		self,
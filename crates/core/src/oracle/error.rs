@@ -26,6 +26,8 @@ pub enum Error {
 	InvalidOracleId(OracleId),
 	#[error("tower_level ({tower_level}) exceeds maximum")]
 	TowerLevelTooHigh { tower_level: usize },
+	#[error("commit phase challenges are sampled after the first commit phase")]
+	CommitPhaseChallengeInFirstPhase,
 	#[error("constraint set is empty")]
 	EmptyConstraintSet,
 	#[error(
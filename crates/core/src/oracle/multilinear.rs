@@ -13,6 +13,7 @@ use crate::{
 	polynomial::{
 		ArithCircuitPoly, Error as PolynomialError, IdentityCompositionPoly, MultivariatePoly,
 	},
+	transparent::constant::Constant,
 };
 
 /// Meta struct that lets you add optional `name` for the Multilinear before adding to the
@@ -43,6 +44,24 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
+	/// Adds an oracle for the challenge sampled at the start of the current commit phase, see
+	/// [`MultilinearOracleSet::add_commit_phase_challenge`].
+	pub fn commit_phase_challenge(self, n_vars: usize) -> Result<OracleId, Error> {
+		let Self { name, mut_ref } = self;
+		if mut_ref.n_commit_phases() == 1 {
+			bail!(Error::CommitPhaseChallengeInFirstPhase);
+		}
+
+		// The value is a placeholder until the challenge is bound.
+		let id = MultilinearOracleSetAddition {
+			name,
+			mut_ref: &mut *mut_ref,
+		}
+		.transparent(Constant::new(n_vars, F::ZERO))?;
+		mut_ref.commit_phase_challenges.push(id);
+		Ok(id)
+	}
+
 	pub fn committed(mut self, n_vars: usize, tower_level: usize) -> OracleId {
 		let name = self.name.take();
 		self.add_committed_with_name(n_vars, tower_level, name)
@@ -354,18 +373,203 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 /// is either transparent, committed, or derived from one or more others. Each oracle is assigned a
 /// unique `OracleId`.
 ///
-/// The oracle set also tracks the committed polynomial in batches where each batch is committed
-/// together with a polynomial commitment scheme.
-#[derive(Default, Debug, Clone, SerializeBytes, DeserializeBytes)]
-#[deserialize_bytes(eval_generics(F = BinaryField128b))]
+/// The oracle set also tracks the committed polynomials in commit phases. The committed oracles of
+/// each phase are committed together with a polynomial commitment scheme, and the phases are
+/// committed in order. See [`Self::start_commit_phase`]. Within a phase, the committed oracles
+/// are partitioned into commit batches according to the [`CommitBatching`] policy.
+#[derive(Default, Debug, Clone, SerializeBytes)]
 pub struct MultilinearOracleSet<F: TowerField> {
 	oracles: Vec<MultilinearPolyOracle<F>>,
+	/// The index of the first oracle of every commit phase but the first.
+	commit_phase_starts: Vec<usize>,
+	/// The oracles of the challenges sampled at the start of the commit phases.
+	commit_phase_challenges: Vec<OracleId>,
+	commit_batching: CommitBatching,
+}
+
+impl DeserializeBytes for MultilinearOracleSet<BinaryField128b> {
+	fn deserialize(
+		mut read_buf: impl bytes::Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		let oracles =
+			Vec::<MultilinearPolyOracle<BinaryField128b>>::deserialize(&mut read_buf, mode)?;
+		let commit_phase_starts = Vec::<usize>::deserialize(&mut read_buf, mode)?;
+		let commit_phase_challenges = Vec::<OracleId>::deserialize(&mut read_buf, mode)?;
+		let commit_batching = CommitBatching::deserialize(read_buf, mode)?;
+
+		if !commit_phase_starts.is_sorted()
+			|| commit_phase_starts
+				.last()
+				.is_some_and(|&start| start > oracles.len())
+		{
+			return Err(SerializationError::InvalidConstruction {
+				name: "MultilinearOracleSet::commit_phase_starts",
+			});
+		}
+		let oracle_set = Self {
+			oracles,
+			commit_phase_starts,
+			commit_phase_challenges,
+			commit_batching,
+		};
+		let valid_challenge = |&id: &OracleId| {
+			oracle_set.is_valid_oracle_id(id)
+				&& oracle_set.commit_phase(id) > 0
+				&& matches!(oracle_set[id].variant, MultilinearPolyVariant::Transparent(_))
+		};
+		if !oracle_set
+			.commit_phase_challenges
+			.iter()
+			.all(valid_challenge)
+		{
+			return Err(SerializationError::InvalidConstruction {
+				name: "MultilinearOracleSet::commit_phase_challenges",
+			});
+		}
+		Ok(oracle_set)
+	}
+}
+
+/// The policy that partitions the committed oracles of a commit phase into batches.
+///
+/// Every batch is committed and opened with its own polynomial commitment, whose parameters are
+/// chosen for the size of the batch.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub enum CommitBatching {
+	/// The committed oracles of a phase are committed in a single batch.
+	#[default]
+	PerPhase,
+	/// The committed oracles of a phase are grouped by binary tower level, with one batch per
+	/// tower level.
+	PerTowerLevel,
+}
+
+/// A batch of committed oracles that are committed and opened together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitBatch {
+	/// The commit phase of the oracles.
+	pub phase: usize,
+	/// The binary tower level of the oracles, if the batches are split by tower level.
+	pub tower_level: Option<usize>,
 }
 
 impl<F: TowerField> MultilinearOracleSet<F> {
 	pub const fn new() -> Self {
 		Self {
 			oracles: Vec::new(),
+			commit_phase_starts: Vec::new(),
+			commit_phase_challenges: Vec::new(),
+			commit_batching: CommitBatching::PerPhase,
+		}
+	}
+
+	/// Starts a new commit phase and returns its index.
+	///
+	/// The committed oracles added from now on belong to the new phase. The prover commits to the
+	/// oracles of a phase only after the commitments of the previous phases are observed by the
+	/// transcript, and each phase is opened with its own commitment. A challenge is sampled
+	/// between the phases, see [`Self::add_commit_phase_challenge`].
+	pub fn start_commit_phase(&mut self) -> usize {
+		self.commit_phase_starts.push(self.oracles.len());
+		self.commit_phase_starts.len()
+	}
+
+	/// Adds a constant oracle with the value of the challenge sampled at the start of the current
+	/// commit phase, after the commitments of the previous phases are observed.
+	///
+	/// The oracle has a placeholder value until the challenge is bound with
+	/// [`Self::bind_commit_phase_challenge`], which the prover and the verifier do when they
+	/// sample it. The witness of the oracle is filled by the prover. Fails in the first commit
+	/// phase, which has no challenge.
+	pub fn add_commit_phase_challenge(&mut self, n_vars: usize) -> Result<OracleId, Error> {
+		self.add().commit_phase_challenge(n_vars)
+	}
+
+	/// The oracles of the challenge sampled at the start of the commit phase `phase`.
+	pub fn commit_phase_challenges(&self, phase: usize) -> impl Iterator<Item = OracleId> + '_ {
+		self.commit_phase_challenges
+			.iter()
+			.copied()
+			.filter(move |&id| self.commit_phase(id) == phase)
+	}
+
+	/// Sets the value of the oracles of the challenge of the commit phase `phase`.
+	pub fn bind_commit_phase_challenge(&mut self, phase: usize, challenge: F) {
+		let ids = self.commit_phase_challenges(phase).collect::<Vec<_>>();
+		for id in ids {
+			let oracle = &mut self.oracles[id.index()];
+			oracle.variant = MultilinearPolyVariant::Transparent(TransparentPolyOracle {
+				poly: Arc::new(Constant::new(oracle.n_vars, challenge)),
+			});
+		}
+	}
+
+	/// The number of commit phases, which is at least one.
+	pub fn n_commit_phases(&self) -> usize {
+		self.commit_phase_starts.len() + 1
+	}
+
+	/// The commit phase an oracle was added in.
+	pub fn commit_phase(&self, id: OracleId) -> usize {
+		self.commit_phase_starts
+			.partition_point(|&start| start <= id.index())
+	}
+
+	pub const fn commit_batching(&self) -> CommitBatching {
+		self.commit_batching
+	}
+
+	pub const fn set_commit_batching(&mut self, commit_batching: CommitBatching) {
+		self.commit_batching = commit_batching;
+	}
+
+	/// The commit batches, ordered by phase and then by tower level.
+	///
+	/// Every phase has at least one batch, even if it has no committed oracles.
+	pub fn commit_batches(&self) -> Vec<CommitBatch> {
+		let mut batches = Vec::new();
+		for phase in 0..self.n_commit_phases() {
+			let mut tower_levels = match self.commit_batching {
+				CommitBatching::PerPhase => vec![],
+				CommitBatching::PerTowerLevel => self
+					.polys()
+					.filter(|oracle| {
+						matches!(oracle.variant, MultilinearPolyVariant::Committed)
+							&& self.commit_phase(oracle.id()) == phase
+					})
+					.map(|oracle| oracle.binary_tower_level())
+					.collect(),
+			};
+			tower_levels.sort_unstable();
+			tower_levels.dedup();
+
+			if tower_levels.is_empty() {
+				batches.push(CommitBatch {
+					phase,
+					tower_level: None,
+				});
+			}
+			batches.extend(tower_levels.into_iter().map(|tower_level| CommitBatch {
+				phase,
+				tower_level: Some(tower_level),
+			}));
+		}
+		batches
+	}
+
+	/// The commit batch of a committed oracle.
+	pub fn commit_batch(&self, id: OracleId) -> CommitBatch {
+		let tower_level = match self.commit_batching {
+			CommitBatching::PerPhase => None,
+			CommitBatching::PerTowerLevel => Some(self.tower_level(id)),
+		};
+		CommitBatch {
+			phase: self.commit_phase(id),
+			tower_level,
 		}
 	}
 
@@ -373,6 +577,15 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.oracles.len()
 	}
 
+	/// Removes the oracles added after the first `size` ones.
+	///
+	/// The commit phases that start at one of the removed oracles are removed with them.
+	pub fn truncate(&mut self, size: usize) {
+		self.oracles.truncate(size);
+		self.commit_phase_starts.retain(|&start| start <= size);
+		self.commit_phase_challenges.retain(|id| id.index() < size);
+	}
+
 	pub fn polys(&self) -> impl Iterator<Item = &MultilinearPolyOracle<F>> + '_ {
 		(0..self.oracles.len()).map(|index| &self[OracleId::from_index(index)])
 	}
@@ -946,9 +1159,12 @@ impl<F: TowerField> MultilinearPolyOracle<F> {
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField1b, BinaryField128b, Field, TowerField};
+	use binius_field::{BinaryField1b, BinaryField32b, BinaryField128b, Field, TowerField};
+	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 
-	use super::MultilinearOracleSet;
+	use super::{
+		CommitBatch, CommitBatching, MultilinearOracleSet, MultilinearPolyVariant, ShiftVariant,
+	};
 
 	#[test]
 	fn add_projection_with_all_vars() {
@@ -961,4 +1177,84 @@ mod tests {
 			.unwrap();
 		let _ = oracles.oracle(projected);
 	}
+
+	#[test]
+	fn commit_phases() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let first = oracles.add_committed(5, BinaryField1b::TOWER_LEVEL);
+		assert_eq!(oracles.start_commit_phase(), 1);
+		let second = oracles.add_committed(5, BinaryField1b::TOWER_LEVEL);
+		let shifted = oracles
+			.add_shifted(first, 1, 5, ShiftVariant::CircularLeft)
+			.unwrap();
+		assert_eq!(oracles.n_commit_phases(), 2);
+		assert_eq!(oracles.commit_phase(first), 0);
+		assert_eq!(oracles.commit_phase(second), 1);
+		assert_eq!(oracles.commit_phase(shifted), 1);
+	}
+
+	#[test]
+	fn commit_phase_challenges() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		assert!(oracles.add_commit_phase_challenge(5).is_err());
+		oracles.start_commit_phase();
+		let challenge = oracles.add_commit_phase_challenge(5).unwrap();
+		assert_eq!(oracles.commit_phase_challenges(1).collect::<Vec<_>>(), [challenge]);
+
+		let value = F::new(0x1234);
+		oracles.bind_commit_phase_challenge(1, value);
+		let MultilinearPolyVariant::Transparent(transparent) = &oracles[challenge].variant else {
+			panic!("challenge oracles are transparent");
+		};
+		assert_eq!(transparent.poly().evaluate(&[F::ONE; 5]).unwrap(), value);
+	}
+
+	#[test]
+	fn deserialize_rejects_invalid_commit_phases() {
+		type F = BinaryField128b;
+		let mode = SerializationMode::CanonicalTower;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		oracles.add_committed(5, BinaryField1b::TOWER_LEVEL);
+		oracles.start_commit_phase();
+		oracles.add_commit_phase_challenge(5).unwrap();
+
+		let mut bytes = Vec::new();
+		oracles.serialize(&mut bytes, mode).unwrap();
+		assert!(MultilinearOracleSet::<F>::deserialize(bytes.as_slice(), mode).is_ok());
+
+		oracles.commit_phase_starts = vec![3];
+		let mut bytes = Vec::new();
+		oracles.serialize(&mut bytes, mode).unwrap();
+		assert!(MultilinearOracleSet::<F>::deserialize(bytes.as_slice(), mode).is_err());
+
+		oracles.commit_phase_starts = vec![1, 0];
+		let mut bytes = Vec::new();
+		oracles.serialize(&mut bytes, mode).unwrap();
+		assert!(MultilinearOracleSet::<F>::deserialize(bytes.as_slice(), mode).is_err());
+	}
+
+	#[test]
+	fn commit_batches_per_tower_level() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		oracles.set_commit_batching(CommitBatching::PerTowerLevel);
+		let bits = oracles.add_committed(5, BinaryField1b::TOWER_LEVEL);
+		let words = oracles.add_committed(5, BinaryField32b::TOWER_LEVEL);
+		oracles.add_committed(7, BinaryField1b::TOWER_LEVEL);
+		oracles.start_commit_phase();
+
+		let batch = |phase, tower_level| CommitBatch { phase, tower_level };
+		assert_eq!(
+			oracles.commit_batches(),
+			[batch(0, Some(0)), batch(0, Some(5)), batch(1, None)]
+		);
+		assert_eq!(oracles.commit_batch(bits), batch(0, Some(0)));
+		assert_eq!(oracles.commit_batch(words), batch(0, Some(5)));
+
+		oracles.set_commit_batching(CommitBatching::PerPhase);
+		assert_eq!(oracles.commit_batches(), [batch(0, None), batch(1, None)]);
+		assert_eq!(oracles.commit_batch(words), batch(0, None));
+	}
 }
@@ -9,7 +9,7 @@ use binius_utils::sparse_index::SparseIndex;
 
 use super::{error::Error, util::ResizeableIndex, verify::CommitMeta};
 use crate::{
-	oracle::{MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant},
+	oracle::{CommitBatch, MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
@@ -19,6 +19,33 @@ use crate::{
 /// 2. a sparse index mapping oracle IDs to committed IDs in the commit metadata
 pub fn make_oracle_commit_meta<F: TowerField>(
 	oracles: &MultilinearOracleSet<F>,
+) -> Result<(CommitMeta, SparseIndex<usize>), Error> {
+	make_commit_meta_for(oracles, |_| true)
+}
+
+/// Same as [`make_oracle_commit_meta`], for the committed oracles of the commit phase `phase`.
+///
+/// See [`MultilinearOracleSet::start_commit_phase`].
+pub fn make_oracle_commit_meta_for_phase<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	phase: usize,
+) -> Result<(CommitMeta, SparseIndex<usize>), Error> {
+	make_commit_meta_for(oracles, |oracle| oracles.commit_phase(oracle.id()) == phase)
+}
+
+/// Same as [`make_oracle_commit_meta`], for the committed oracles of the commit batch `batch`.
+///
+/// See [`MultilinearOracleSet::commit_batches`].
+pub fn make_oracle_commit_meta_for_batch<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	batch: CommitBatch,
+) -> Result<(CommitMeta, SparseIndex<usize>), Error> {
+	make_commit_meta_for(oracles, |oracle| oracles.commit_batch(oracle.id()) == batch)
+}
+
+fn make_commit_meta_for<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	include: impl Fn(&MultilinearPolyOracle<F>) -> bool,
 ) -> Result<(CommitMeta, SparseIndex<usize>), Error> {
 	// We need to construct two structures:
 	//
@@ -42,7 +69,7 @@ pub fn make_oracle_commit_meta<F: TowerField>(
 	let mut first_pass_index = SparseIndex::with_capacity(oracles.size());
 	let mut n_multilins_by_vars = ResizeableIndex::<usize>::new();
 	for oracle in oracles.polys() {
-		if matches!(oracle.variant, MultilinearPolyVariant::Committed) {
+		if matches!(oracle.variant, MultilinearPolyVariant::Committed) && include(oracle) {
 			let n_packed_vars = n_packed_vars_for_committed_oracle(oracle);
 			let n_multilins_for_vars = n_multilins_by_vars.get_mut(n_packed_vars);
 
@@ -118,6 +145,7 @@ mod tests {
 	use binius_field::BinaryField128b;
 
 	use super::*;
+	use crate::oracle::CommitBatching;
 
 	#[test]
 	fn test_make_oracle_commit_meta() {
@@ -149,4 +177,45 @@ mod tests {
 		assert_eq!(index.get(batch_2_2_ids[1].index()).copied(), Some(11));
 		assert_eq!(index.get(repeat.index()).copied(), None);
 	}
+
+	#[test]
+	fn test_make_oracle_commit_meta_for_phase() {
+		let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
+
+		let phase_0_ids = oracles.add_committed_multiple::<2>(8, 0);
+		oracles.start_commit_phase();
+		let phase_1_ids = oracles.add_committed_multiple::<3>(10, 0);
+
+		let (commit_meta, index) = make_oracle_commit_meta_for_phase(&oracles, 0).unwrap();
+		assert_eq!(commit_meta.n_multilins_by_vars(), &[0, 2]);
+		assert_eq!(index.get(phase_0_ids[1].index()).copied(), Some(1));
+		assert_eq!(index.get(phase_1_ids[0].index()).copied(), None);
+
+		let (commit_meta, index) = make_oracle_commit_meta_for_phase(&oracles, 1).unwrap();
+		assert_eq!(commit_meta.n_multilins_by_vars(), &[0, 0, 0, 3]);
+		assert_eq!(index.get(phase_0_ids[0].index()).copied(), None);
+		assert_eq!(index.get(phase_1_ids[2].index()).copied(), Some(2));
+	}
+
+	#[test]
+	fn test_make_oracle_commit_meta_for_batch() {
+		let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
+		oracles.set_commit_batching(CommitBatching::PerTowerLevel);
+
+		let bit_ids = oracles.add_committed_multiple::<2>(8, 0);
+		let word_ids = oracles.add_committed_multiple::<3>(8, 5);
+
+		let batches = oracles.commit_batches();
+		assert_eq!(batches.len(), 2);
+
+		let (commit_meta, index) = make_oracle_commit_meta_for_batch(&oracles, batches[0]).unwrap();
+		assert_eq!(commit_meta.n_multilins_by_vars(), &[0, 2]);
+		assert_eq!(index.get(bit_ids[1].index()).copied(), Some(1));
+		assert_eq!(index.get(word_ids[0].index()).copied(), None);
+
+		let (commit_meta, index) = make_oracle_commit_meta_for_batch(&oracles, batches[1]).unwrap();
+		assert_eq!(commit_meta.n_multilins_by_vars(), &[0, 0, 0, 0, 0, 0, 3]);
+		assert_eq!(index.get(bit_ids[0].index()).copied(), None);
+		assert_eq!(index.get(word_ids[2].index()).copied(), Some(2));
+	}
 }
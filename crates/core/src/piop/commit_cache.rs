@@ -0,0 +1,168 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Persistence of commitments, for reusing the commitment to static data across proofs.
+//!
+//! Applications proving many statements against the same preprocessed multilinears, e.g. a fixed
+//! ROM, can commit them once with [`commit`], save the [`CommitOutput`] with
+//! [`save_commit_output`], and load it with [`load_commit_output`] for every later proof instead
+//! of committing again. The codeword is stored, so loading skips the encoding of the multilinears.
+//! The Merkle tree is rebuilt from the codeword on load, which doubles as an integrity check: the
+//! root of the rebuilt tree must match the stored commitment.
+//!
+//! The encoding is
+//!
+//! - the 4 magic bytes `b"BNCM"`,
+//! - the format version as a little-endian `u32`,
+//! - the commitment,
+//! - the number of codeword elements as a little-endian `u32`,
+//! - the codeword elements.
+//!
+//! [`commit`]: super::commit
+
+use std::{fs, path::Path};
+
+use binius_field::{BinaryField, PackedField};
+use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, SerializeBytes, bail};
+use bytes::{Buf, BufMut};
+
+use super::error::Error;
+use crate::{
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	protocols::fri::{self, CommitOutput, FRIParams},
+};
+
+/// Magic bytes at the start of every saved commitment.
+pub const MAGIC: [u8; 4] = *b"BNCM";
+
+/// The version of the binary encoding written by [`save_commit_output`].
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Writes the commitment and the codeword of a [`CommitOutput`] to a buffer.
+pub fn save_commit_output<P, D, Committed>(
+	output: &CommitOutput<P, D, Committed>,
+	mut write_buf: impl BufMut,
+	mode: SerializationMode,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar: SerializeBytes>,
+	D: SerializeBytes,
+{
+	if write_buf.remaining_mut() < MAGIC.len() {
+		return Err(SerializationError::WriteBufferFull.into());
+	}
+	write_buf.put_slice(&MAGIC);
+	FORMAT_VERSION.serialize(&mut write_buf, mode)?;
+	output.commitment.serialize(&mut write_buf, mode)?;
+	(output.codeword.len() * P::WIDTH).serialize(&mut write_buf, mode)?;
+	for scalar in PackedField::iter_slice(&output.codeword) {
+		scalar.serialize(&mut write_buf, mode)?;
+	}
+	Ok(())
+}
+
+/// Reads a [`CommitOutput`] written by [`save_commit_output`] from a buffer.
+///
+/// The Merkle tree is rebuilt from the codeword with `merkle_prover`, for the FRI parameters the
+/// output was committed with.
+///
+/// ## Throws
+///
+/// * [`Error::CommitmentMismatch`] if the rebuilt Merkle tree root doesn't match the stored
+///   commitment, which means that the data is corrupted, or was committed with different
+///   parameters.
+pub fn load_commit_output<F, FA, P, MTScheme, MTProver>(
+	mut read_buf: impl Buf,
+	mode: SerializationMode,
+	fri_params: &FRIParams<F, FA>,
+	merkle_prover: &MTProver,
+) -> Result<CommitOutput<P, MTScheme::Digest, MTProver::Committed>, Error>
+where
+	F: BinaryField + DeserializeBytes,
+	FA: BinaryField,
+	P: PackedField<Scalar = F>,
+	MTScheme: MerkleTreeScheme<F, Digest: DeserializeBytes + PartialEq>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+{
+	if read_buf.remaining() < MAGIC.len() {
+		return Err(SerializationError::NotEnoughBytes.into());
+	}
+	let mut magic = [0u8; 4];
+	read_buf.copy_to_slice(&mut magic);
+	if magic != MAGIC {
+		return Err(SerializationError::InvalidConstruction {
+			name: "CommitOutput",
+		}
+		.into());
+	}
+
+	let version = u32::deserialize(&mut read_buf, mode)?;
+	if version != FORMAT_VERSION {
+		return Err(SerializationError::UnsupportedVersion {
+			name: "CommitOutput",
+			version,
+		}
+		.into());
+	}
+
+	let commitment = MTScheme::Digest::deserialize(&mut read_buf, mode)?;
+
+	let len = usize::deserialize(&mut read_buf, mode)?;
+	if len != 1 << fri_params.log_len() {
+		bail!(Error::CommitmentMismatch);
+	}
+	let scalars = (0..len)
+		.map(|_| F::deserialize(&mut read_buf, mode))
+		.collect::<Result<Vec<_>, _>>()?;
+	let codeword = scalars
+		.chunks(P::WIDTH)
+		.map(|chunk| P::from_scalars(chunk.iter().copied()))
+		.collect::<Vec<_>>();
+
+	let (rebuilt_commitment, committed) =
+		fri::commit_codeword(fri_params, merkle_prover, &codeword)?;
+	if rebuilt_commitment != commitment {
+		bail!(Error::CommitmentMismatch);
+	}
+
+	Ok(CommitOutput {
+		commitment,
+		committed,
+		codeword,
+	})
+}
+
+/// Saves the commitment and the codeword of a [`CommitOutput`] to a file.
+///
+/// See [`save_commit_output`].
+pub fn save_commit_output_to_file<P, D, Committed>(
+	output: &CommitOutput<P, D, Committed>,
+	path: impl AsRef<Path>,
+) -> Result<(), Error>
+where
+	P: PackedField<Scalar: SerializeBytes>,
+	D: SerializeBytes,
+{
+	let mut buf = Vec::new();
+	save_commit_output(output, &mut buf, SerializationMode::CanonicalTower)?;
+	fs::write(path, buf)?;
+	Ok(())
+}
+
+/// Loads a [`CommitOutput`] saved by [`save_commit_output_to_file`].
+///
+/// See [`load_commit_output`].
+pub fn load_commit_output_from_file<F, FA, P, MTScheme, MTProver>(
+	path: impl AsRef<Path>,
+	fri_params: &FRIParams<F, FA>,
+	merkle_prover: &MTProver,
+) -> Result<CommitOutput<P, MTScheme::Digest, MTProver::Committed>, Error>
+where
+	F: BinaryField + DeserializeBytes,
+	FA: BinaryField,
+	P: PackedField<Scalar = F>,
+	MTScheme: MerkleTreeScheme<F, Digest: DeserializeBytes + PartialEq>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+{
+	let buf = fs::read(path)?;
+	load_commit_output(buf.as_slice(), SerializationMode::CanonicalTower, fri_params, merkle_prover)
+}
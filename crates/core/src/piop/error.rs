@@ -17,12 +17,36 @@ pub enum Error {
 	CommittedPackedEvaluationsMissing { id: OracleId },
 	#[error("invalid committed ID")]
 	InvalidCommittedId { max_index: usize },
+	#[error("expected {expected} committed multilinears, got {actual}")]
+	IncorrectNumberOfMultilinears { expected: usize, actual: usize },
+	#[error("committed multilinear {index} has {actual} variables, expected {expected}")]
+	IncorrectMultilinearVariables {
+		index: usize,
+		expected: usize,
+		actual: usize,
+	},
 	#[error("invalid transparent ID")]
 	InvalidTransparentId { max_index: usize },
 	#[error("the number of variables recorded for oracle {id} is incorrect")]
 	OracleToCommitIndexMalformed { id: OracleId },
 	#[error("the number of variables of the polynomials in sumcheck claim {index} do not match")]
 	SumcheckClaimVariablesMismatch { index: usize },
+	#[error("invalid evaluation point ID, there are only {max_index} evaluation points")]
+	InvalidEvaluationPointId { max_index: usize },
+	#[error("no opening of a {n_vars}-variate multilinear at evaluation point {point}")]
+	MissingOpeningTransparent { n_vars: usize, point: usize },
+	#[error("evaluation point has {actual} coordinates, expected at least {expected}")]
+	IncorrectEvaluationPointLength { expected: usize, actual: usize },
+	#[error("expected {expected} claimed evaluations, got {actual}")]
+	IncorrectNumberOfEvaluations { expected: usize, actual: usize },
+	#[error("conflicting evaluations are claimed for committed polynomial {committed}")]
+	InconsistentEvaluationClaims { committed: usize },
+	#[error("the stored commitment does not match the stored codeword")]
+	CommitmentMismatch,
+	#[error("serialization error: {0}")]
+	Serialization(#[from] binius_utils::SerializationError),
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
 	#[error("binius_math error: {0}")]
 	Math(#[from] binius_math::Error),
 	#[error("Reed-Solomon error: {0}")]
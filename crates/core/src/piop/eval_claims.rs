@@ -0,0 +1,205 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Accumulation of the evaluation claims on committed multilinears into batched openings.
+//!
+//! The reductions of a proof (sumchecks, zerochecks, GKR, ...) each output evaluation claims on
+//! committed multilinears. Instead of opening the commitment for every reduction, the claims are
+//! collected in an [`EvalClaimAccumulator`], which batches them into the openings of a single
+//! invocation of [`prove_evaluation_multi`] / [`verify_evaluation_multi`]:
+//!
+//! * duplicate claims are opened once, and conflicting claims are rejected,
+//! * claims at the same point share the evaluation point,
+//! * a claim on a multilinear with $\ell$ variables is opened at the first $\ell$ coordinates of
+//!   the evaluation point, so a point that is a prefix of another point is merged into it.
+//!
+//! Every distinct evaluation point costs one equality indicator transparent in the opening proof,
+//! so merging points reduces the work of both the prover and the verifier.
+//!
+//! The constraint system protocol collects the claims output by the greedy evalcheck in an
+//! accumulator per commit batch before reducing them to the PIOP sumcheck claims.
+//!
+//! [`prove_evaluation_multi`]: super::fri_pcs::prove_evaluation_multi
+//! [`verify_evaluation_multi`]: super::fri_pcs::verify_evaluation_multi
+
+use binius_field::Field;
+use binius_utils::bail;
+
+use super::{error::Error, fri_pcs::Opening};
+
+/// Collects evaluation claims on a committed batch of multilinears.
+///
+/// No point stored in the accumulator is a prefix of another one.
+#[derive(Debug, Clone)]
+pub struct EvalClaimAccumulator<F: Field> {
+	points: Vec<Vec<F>>,
+	openings: Vec<Opening>,
+	evals: Vec<F>,
+}
+
+impl<F: Field> EvalClaimAccumulator<F> {
+	pub const fn new() -> Self {
+		Self {
+			points: Vec::new(),
+			openings: Vec::new(),
+			evals: Vec::new(),
+		}
+	}
+
+	/// Adds the claim that the committed multilinear with index `committed` evaluates to `eval`
+	/// at `point`.
+	///
+	/// The number of coordinates of `point` must be the number of variables of the multilinear.
+	/// Returns the index of the opening proving the claim.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::InconsistentEvaluationClaims`] if a different evaluation is already claimed for
+	///   the multilinear at the point.
+	pub fn add(&mut self, committed: usize, point: &[F], eval: F) -> Result<usize, Error> {
+		let point_index = self.insert_point(point);
+
+		if let Some(index) = self
+			.openings
+			.iter()
+			.position(|opening| opening.committed == committed && opening.point == point_index)
+		{
+			// The multilinear has as many variables as the point has coordinates, so both claims
+			// are on the same prefix of the stored point.
+			if self.evals[index] != eval {
+				bail!(Error::InconsistentEvaluationClaims { committed });
+			}
+			return Ok(index);
+		}
+
+		self.openings.push(Opening {
+			committed,
+			point: point_index,
+		});
+		self.evals.push(eval);
+		Ok(self.openings.len() - 1)
+	}
+
+	/// Returns the index of a stored point that has `point` as a prefix, inserting it if there is
+	/// none.
+	fn insert_point(&mut self, point: &[F]) -> usize {
+		if let Some(index) = self
+			.points
+			.iter()
+			.position(|stored| stored.starts_with(point))
+		{
+			return index;
+		}
+
+		// At most one stored point is a prefix of `point`, since the stored points are not
+		// prefixes of each other. Extending it keeps all the openings at it valid.
+		if let Some(index) = self
+			.points
+			.iter()
+			.position(|stored| point.starts_with(stored))
+		{
+			self.points[index] = point.to_vec();
+			return index;
+		}
+
+		self.points.push(point.to_vec());
+		self.points.len() - 1
+	}
+
+	/// The number of batched openings.
+	pub fn len(&self) -> usize {
+		self.openings.len()
+	}
+
+	/// Whether no claim has been added.
+	pub fn is_empty(&self) -> bool {
+		self.openings.is_empty()
+	}
+
+	/// The distinct evaluation points.
+	pub fn points(&self) -> Vec<&[F]> {
+		self.points.iter().map(Vec::as_slice).collect()
+	}
+
+	/// The batched openings, indexing into [`Self::points`].
+	pub fn openings(&self) -> &[Opening] {
+		&self.openings
+	}
+
+	/// The claimed evaluations, one for each opening.
+	pub fn evals(&self) -> &[F] {
+		&self.evals
+	}
+}
+
+impl<F: Field> Default for EvalClaimAccumulator<F> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use assert_matches::assert_matches;
+	use binius_field::BinaryField128b;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	type F = BinaryField128b;
+
+	fn random_point(rng: &mut StdRng, n_vars: usize) -> Vec<F> {
+		repeat_with(|| F::random(&mut *rng)).take(n_vars).collect()
+	}
+
+	#[test]
+	fn test_same_point_claims_share_point() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = random_point(&mut rng, 4);
+
+		let mut accumulator = EvalClaimAccumulator::new();
+		assert_eq!(accumulator.add(0, &point, F::new(1)).unwrap(), 0);
+		assert_eq!(accumulator.add(1, &point, F::new(2)).unwrap(), 1);
+		assert_eq!(accumulator.points(), [point.as_slice()]);
+		assert_eq!(accumulator.evals(), [F::new(1), F::new(2)]);
+	}
+
+	#[test]
+	fn test_duplicate_claims_are_opened_once() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = random_point(&mut rng, 4);
+
+		let mut accumulator = EvalClaimAccumulator::new();
+		accumulator.add(0, &point, F::new(1)).unwrap();
+		assert_eq!(accumulator.add(0, &point, F::new(1)).unwrap(), 0);
+		assert_eq!(accumulator.len(), 1);
+		assert_matches!(
+			accumulator.add(0, &point, F::new(2)),
+			Err(Error::InconsistentEvaluationClaims { committed: 0 })
+		);
+	}
+
+	#[test]
+	fn test_prefix_points_are_merged() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = random_point(&mut rng, 6);
+		let other_point = random_point(&mut rng, 6);
+
+		let mut accumulator = EvalClaimAccumulator::new();
+		accumulator.add(0, &point[..3], F::new(1)).unwrap();
+		accumulator.add(1, &point, F::new(2)).unwrap();
+		accumulator.add(2, &point[..5], F::new(3)).unwrap();
+		accumulator.add(3, &other_point, F::new(4)).unwrap();
+
+		assert_eq!(accumulator.points(), [point.as_slice(), other_point.as_slice()]);
+		assert_eq!(
+			accumulator
+				.openings()
+				.iter()
+				.map(|opening| opening.point)
+				.collect::<Vec<_>>(),
+			[0, 0, 0, 1]
+		);
+	}
+}
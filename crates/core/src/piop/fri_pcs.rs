@@ -0,0 +1,450 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A multilinear polynomial commitment scheme interface over the FRI-Binius PIOP compiler.
+//!
+//! The PIOP compiler natively proves sumcheck claims over products of committed and transparent
+//! multilinears. A multilinear evaluation claim $t(r) = s$ is the special case where the
+//! transparent factor is the equality indicator $\text{eq}(X, r)$, because
+//!
+//! $$
+//! \sum_{v \in \mathcal{B}_\ell} t(v) \cdot \text{eq}(v, r) = t(r).
+//! $$
+//!
+//! This module packages that reduction behind a [`commit`] / [`prove_evaluation`] /
+//! [`verify_evaluation`] interface, so that FRI-Binius can be used as a standalone PCS for
+//! multilinears over the cryptographically large field.
+//!
+//! The polynomials in a committed batch may have different numbers of variables. A polynomial with
+//! $\ell$ variables is opened at the first $\ell$ coordinates of the evaluation point.
+//!
+//! Openings at several distinct points are supported by [`prove_evaluation_multi`] and
+//! [`verify_evaluation_multi`]. All openings are proven with a single run of the PIOP compiler,
+//! using one equality indicator transparent for each distinct pair of evaluation point and number
+//! of variables. The openings are batched into the sumcheck with random coefficients, so the FRI
+//! parameters of a batch opened at several points should be made with
+//! [`make_commit_params_for_claims`], given the number of openings.
+//!
+//! [`commit`]: super::commit
+//! [`make_commit_params_for_claims`]: super::make_commit_params_for_claims
+
+use std::collections::BTreeSet;
+
+use binius_field::{BinaryField, ExtensionField, Field, PackedExtension, PackedField, TowerField};
+use binius_hal::ComputationBackend;
+use binius_math::{
+	EvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearQuery,
+};
+use binius_ntt::AdditiveNTT;
+use binius_utils::{DeserializeBytes, SerializeBytes, bail};
+
+use super::{
+	error::Error,
+	prove::prove,
+	verify::{CommitMeta, PIOPSumcheckClaim, verify},
+};
+use crate::{
+	fiat_shamir::Challenger,
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	polynomial::MultivariatePoly,
+	protocols::fri::FRIParams,
+	transcript::{ProverTranscript, VerifierTranscript},
+	transparent::eq_ind::EqIndPartialEval,
+};
+
+/// An opening of a committed multilinear at one of several evaluation points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Opening {
+	/// Index of the committed multilinear in the batch.
+	pub committed: usize,
+	/// Index of the evaluation point.
+	pub point: usize,
+}
+
+/// Proves the evaluations of a committed batch of multilinears at a point.
+///
+/// The arguments corresponding to the committed multilinears must be the output of
+/// [`super::commit`] called on `multilins`. The evaluation point and the claimed evaluations are
+/// observed by the transcript before the opening proof is written.
+///
+/// Returns the evaluations of the multilinears, in the same order as `multilins`.
+///
+/// ## Arguments
+///
+/// * `commit_meta` - metadata about the committed batch, ie. `CommitMeta::with_vars` over the
+///   number of variables of `multilins`
+/// * `multilins` - the committed multilinears, sorted in ascending order by number of variables
+/// * `point` - the evaluation point, with at least as many coordinates as the largest multilinear
+#[allow(clippy::too_many_arguments)]
+pub fn prove_evaluation<
+	F,
+	FDomain,
+	FEncode,
+	P,
+	NTT,
+	DomainFactory,
+	MTScheme,
+	MTProver,
+	Challenger_,
+	Backend,
+>(
+	fri_params: &FRIParams<F, FEncode>,
+	ntt: &NTT,
+	merkle_prover: &MTProver,
+	domain_factory: DomainFactory,
+	commit_meta: &CommitMeta,
+	committed: MTProver::Committed,
+	codeword: &[P],
+	multilins: &[MultilinearExtension<P>],
+	point: &[F],
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<Vec<F>, Error>
+where
+	F: TowerField,
+	FDomain: Field,
+	FEncode: BinaryField,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FDomain>
+		+ PackedExtension<FEncode>,
+	NTT: AdditiveNTT<FEncode> + Sync,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	prove_evaluation_multi(
+		fri_params,
+		ntt,
+		merkle_prover,
+		domain_factory,
+		commit_meta,
+		committed,
+		codeword,
+		multilins,
+		&[point],
+		&single_point_openings(commit_meta),
+		transcript,
+		backend,
+	)
+}
+
+/// Verifies the evaluations of a committed batch of multilinears at a point.
+///
+/// ## Arguments
+///
+/// * `commit_meta` - metadata about the committed batch of multilinears
+/// * `merkle_scheme` - the Merkle tree commitment scheme used in FRI
+/// * `fri_params` - the FRI parameters for the commitment opening protocol
+/// * `commitment` - the commitment to the batch
+/// * `point` - the evaluation point, with at least as many coordinates as the largest multilinear
+/// * `evals` - the claimed evaluations, one for each multilinear in the batch, in commitment order
+pub fn verify_evaluation<F, FEncode, Challenger_, MTScheme>(
+	commit_meta: &CommitMeta,
+	merkle_scheme: &MTScheme,
+	fri_params: &FRIParams<F, FEncode>,
+	commitment: &MTScheme::Digest,
+	point: &[F],
+	evals: &[F],
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<(), Error>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	Challenger_: Challenger,
+	MTScheme: MerkleTreeScheme<F, Digest: DeserializeBytes>,
+{
+	verify_evaluation_multi(
+		commit_meta,
+		merkle_scheme,
+		fri_params,
+		commitment,
+		&[point],
+		&single_point_openings(commit_meta),
+		evals,
+		transcript,
+	)
+}
+
+/// Proves the evaluations of a committed batch of multilinears at several points.
+///
+/// Each opening references a committed multilinear and the evaluation point it is opened at. A
+/// multilinear may be opened at any number of points, including none. The evaluation points and
+/// the claimed evaluations are observed by the transcript before the opening proof is written.
+///
+/// Returns the evaluations, one for each opening, in the same order as `openings`.
+///
+/// ## Arguments
+///
+/// * `multilins` - the committed multilinears, sorted in ascending order by number of variables
+/// * `points` - the evaluation points
+/// * `openings` - the openings to prove
+#[allow(clippy::too_many_arguments)]
+pub fn prove_evaluation_multi<
+	F,
+	FDomain,
+	FEncode,
+	P,
+	NTT,
+	DomainFactory,
+	MTScheme,
+	MTProver,
+	Challenger_,
+	Backend,
+>(
+	fri_params: &FRIParams<F, FEncode>,
+	ntt: &NTT,
+	merkle_prover: &MTProver,
+	domain_factory: DomainFactory,
+	commit_meta: &CommitMeta,
+	committed: MTProver::Committed,
+	codeword: &[P],
+	multilins: &[MultilinearExtension<P>],
+	points: &[&[F]],
+	openings: &[Opening],
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<Vec<F>, Error>
+where
+	F: TowerField,
+	FDomain: Field,
+	FEncode: BinaryField,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FDomain>
+		+ PackedExtension<FEncode>,
+	NTT: AdditiveNTT<FEncode> + Sync,
+	DomainFactory: EvaluationDomainFactory<FDomain>,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	let n_varss = n_varss(commit_meta);
+	if multilins.len() != n_varss.len() {
+		bail!(Error::IncorrectNumberOfMultilinears {
+			expected: n_varss.len(),
+			actual: multilins.len(),
+		});
+	}
+	for (index, (multilin, &n_vars)) in multilins.iter().zip(&n_varss).enumerate() {
+		if multilin.n_vars() != n_vars {
+			bail!(Error::IncorrectMultilinearVariables {
+				index,
+				expected: n_vars,
+				actual: multilin.n_vars(),
+			});
+		}
+	}
+
+	let transparent_keys = transparent_keys(commit_meta, points, openings)?;
+	let queries = transparent_keys
+		.iter()
+		.map(|key| MultilinearQuery::<P>::expand(&points[key.point][..key.n_vars]))
+		.collect::<Vec<_>>();
+
+	let evals = openings
+		.iter()
+		.map(|opening| {
+			let multilin = &multilins[opening.committed];
+			let key = TransparentKey {
+				n_vars: multilin.n_vars(),
+				point: opening.point,
+			};
+			let query = &queries[transparent_index(&transparent_keys, key)?];
+			Ok(multilin.evaluate(query)?)
+		})
+		.collect::<Result<Vec<F>, Error>>()?;
+
+	let committed_multilins = multilins
+		.iter()
+		.map(|multilin| MLEDirectAdapter::from(multilin.to_ref()))
+		.collect::<Vec<_>>();
+	let transparent_multilins = queries
+		.iter()
+		.map(|query| {
+			MultilinearExtension::new(query.n_vars(), query.expansion()).map(MLEDirectAdapter::from)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let claims = make_evaluation_claims(commit_meta, &transparent_keys, openings, &evals)?;
+
+	for point in points {
+		transcript.observe().write_scalar_slice(point);
+	}
+	transcript.observe().write_scalar_slice(&evals);
+
+	prove(
+		fri_params,
+		ntt,
+		merkle_prover,
+		domain_factory,
+		commit_meta,
+		committed,
+		codeword,
+		&committed_multilins,
+		&transparent_multilins,
+		&claims,
+		transcript,
+		backend,
+	)?;
+
+	Ok(evals)
+}
+
+/// Verifies the evaluations of a committed batch of multilinears at several points.
+///
+/// ## Arguments
+///
+/// * `commit_meta` - metadata about the committed batch of multilinears
+/// * `merkle_scheme` - the Merkle tree commitment scheme used in FRI
+/// * `fri_params` - the FRI parameters for the commitment opening protocol
+/// * `commitment` - the commitment to the batch
+/// * `points` - the evaluation points
+/// * `openings` - the openings to verify
+/// * `evals` - the claimed evaluations, one for each opening
+#[allow(clippy::too_many_arguments)]
+pub fn verify_evaluation_multi<F, FEncode, Challenger_, MTScheme>(
+	commit_meta: &CommitMeta,
+	merkle_scheme: &MTScheme,
+	fri_params: &FRIParams<F, FEncode>,
+	commitment: &MTScheme::Digest,
+	points: &[&[F]],
+	openings: &[Opening],
+	evals: &[F],
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<(), Error>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	Challenger_: Challenger,
+	MTScheme: MerkleTreeScheme<F, Digest: DeserializeBytes>,
+{
+	if evals.len() != openings.len() {
+		bail!(Error::IncorrectNumberOfEvaluations {
+			expected: openings.len(),
+			actual: evals.len(),
+		});
+	}
+
+	let transparent_keys = transparent_keys(commit_meta, points, openings)?;
+	let transparents = transparent_keys
+		.iter()
+		.map(|key| EqIndPartialEval::new(&points[key.point][..key.n_vars]))
+		.collect::<Vec<_>>();
+	let transparents = transparents
+		.iter()
+		.map(|poly| poly as &dyn MultivariatePoly<F>)
+		.collect::<Vec<_>>();
+
+	let claims = make_evaluation_claims(commit_meta, &transparent_keys, openings, evals)?;
+
+	for point in points {
+		transcript.observe().write_scalar_slice(point);
+	}
+	transcript.observe().write_scalar_slice(evals);
+
+	verify(commit_meta, merkle_scheme, fri_params, commitment, &transparents, &claims, transcript)
+}
+
+/// Identifies the equality indicator transparent for a number of variables and evaluation point.
+///
+/// The derived ordering sorts first by number of variables, as required by the PIOP compiler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct TransparentKey {
+	n_vars: usize,
+	point: usize,
+}
+
+/// Opens every multilinear in the batch at the single evaluation point with index 0.
+fn single_point_openings(commit_meta: &CommitMeta) -> Vec<Opening> {
+	(0..commit_meta.total_multilins())
+		.map(|committed| Opening {
+			committed,
+			point: 0,
+		})
+		.collect()
+}
+
+/// Validates the openings and returns the sorted, deduplicated transparent keys they require.
+fn transparent_keys<F: Field>(
+	commit_meta: &CommitMeta,
+	points: &[&[F]],
+	openings: &[Opening],
+) -> Result<Vec<TransparentKey>, Error> {
+	let n_varss = n_varss(commit_meta);
+
+	let mut keys = BTreeSet::new();
+	for opening in openings {
+		let Some(&n_vars) = n_varss.get(opening.committed) else {
+			bail!(Error::InvalidCommittedId {
+				max_index: n_varss.len(),
+			});
+		};
+		let Some(point) = points.get(opening.point) else {
+			bail!(Error::InvalidEvaluationPointId {
+				max_index: points.len(),
+			});
+		};
+		if point.len() < n_vars {
+			bail!(Error::IncorrectEvaluationPointLength {
+				expected: n_vars,
+				actual: point.len(),
+			});
+		}
+		keys.insert(TransparentKey {
+			n_vars,
+			point: opening.point,
+		});
+	}
+	Ok(keys.into_iter().collect())
+}
+
+/// Returns the number of variables of each committed multilinear, in commitment order.
+fn n_varss(commit_meta: &CommitMeta) -> Vec<usize> {
+	commit_meta
+		.n_multilins_by_vars()
+		.iter()
+		.enumerate()
+		.flat_map(|(n_vars, &count)| std::iter::repeat_n(n_vars, count))
+		.collect()
+}
+
+fn transparent_index(
+	transparent_keys: &[TransparentKey],
+	key: TransparentKey,
+) -> Result<usize, Error> {
+	transparent_keys
+		.binary_search(&key)
+		.map_err(|_| Error::MissingOpeningTransparent {
+			n_vars: key.n_vars,
+			point: key.point,
+		})
+}
+
+fn make_evaluation_claims<F: Field>(
+	commit_meta: &CommitMeta,
+	transparent_keys: &[TransparentKey],
+	openings: &[Opening],
+	evals: &[F],
+) -> Result<Vec<PIOPSumcheckClaim<F>>, Error> {
+	let n_varss = n_varss(commit_meta);
+	openings
+		.iter()
+		.zip(evals)
+		.map(|(opening, &sum)| {
+			let n_vars = n_varss[opening.committed];
+			let key = TransparentKey {
+				n_vars,
+				point: opening.point,
+			};
+			Ok(PIOPSumcheckClaim {
+				n_vars,
+				committed: opening.committed,
+				transparent: transparent_index(transparent_keys, key)?,
+				sum,
+			})
+		})
+		.collect()
+}
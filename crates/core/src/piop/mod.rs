@@ -32,7 +32,10 @@
 //! [DP24]: <https://eprint.iacr.org/2024/504>
 
 pub mod commit;
+pub mod commit_cache;
 mod error;
+pub mod eval_claims;
+pub mod fri_pcs;
 mod logging;
 mod prove;
 #[cfg(test)]
@@ -43,4 +46,7 @@ mod verify;
 pub use commit::*;
 pub use error::*;
 pub use prove::*;
-pub use verify::{CommitMeta, PIOPSumcheckClaim, make_commit_params_with_optimal_arity, verify};
+pub use verify::{
+	CommitMeta, PIOPSumcheckClaim, find_proof_size_optimal_pcs, fri_opening_size,
+	make_commit_params_for_claims, make_commit_params_with_optimal_arity, verify,
+};
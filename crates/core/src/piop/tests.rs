@@ -12,14 +12,14 @@ use binius_math::{
 	DefaultEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
 };
 use binius_ntt::SingleThreadedNTT;
-use binius_utils::{DeserializeBytes, SerializeBytes};
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
 use super::{
-	PIOPSumcheckClaim, prove,
+	Error, PIOPSumcheckClaim, commit_cache, fri_pcs, prove,
 	prove::commit,
 	verify,
-	verify::{CommitMeta, make_commit_params_with_optimal_arity},
+	verify::{CommitMeta, make_commit_params_for_claims, make_commit_params_with_optimal_arity},
 };
 use crate::{
 	fiat_shamir::HasherChallenger,
@@ -170,7 +170,7 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 	)
 	.unwrap();
 
-	let mut proof = proof.into_verifier();
+	let mut proof = proof.into_verifier().unwrap();
 
 	let transparent_polys = transparent_mles
 		.iter()
@@ -283,3 +283,295 @@ fn test_commit_prove_verify_byte_sliced() {
 		log_inv_rate,
 	);
 }
+
+#[test]
+fn test_fri_pcs_prove_verify_evaluation() {
+	type F = <PackedBinaryField2x128b as PackedField>::Scalar;
+
+	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let merkle_scheme = merkle_prover.scheme();
+	let fri_params = make_commit_params_with_optimal_arity::<_, BinaryField16b, _>(
+		&commit_meta,
+		merkle_scheme,
+		SECURITY_BITS,
+		1,
+	)
+	.unwrap();
+	let ntt = SingleThreadedNTT::new(fri_params.rs_code().log_len()).unwrap();
+	let backend = make_portable_backend();
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let multilins =
+		generate_multilins::<PackedBinaryField2x128b>(commit_meta.n_multilins_by_vars(), &mut rng);
+	let CommitOutput {
+		commitment,
+		committed,
+		codeword,
+	} = commit(
+		&fri_params,
+		&ntt,
+		&merkle_prover,
+		&multilins
+			.iter()
+			.map(|mle| MLEDirectAdapter::from(mle.to_ref()))
+			.collect::<Vec<_>>(),
+	)
+	.unwrap();
+
+	let point = repeat_with(|| F::random(&mut rng))
+		.take(commit_meta.max_n_vars())
+		.collect::<Vec<_>>();
+
+	let mut bad_proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let result = fri_pcs::prove_evaluation(
+		&fri_params,
+		&ntt,
+		&merkle_prover,
+		DefaultEvaluationDomainFactory::<BinaryField8b>::default(),
+		&commit_meta,
+		committed.clone(),
+		&codeword,
+		&multilins[..3],
+		&point,
+		&mut bad_proof,
+		&backend,
+	);
+	assert!(matches!(
+		result,
+		Err(Error::IncorrectNumberOfMultilinears {
+			expected: 4,
+			actual: 3
+		})
+	));
+
+	let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	proof.message().write(&commitment);
+	let evals = fri_pcs::prove_evaluation(
+		&fri_params,
+		&ntt,
+		&merkle_prover,
+		DefaultEvaluationDomainFactory::<BinaryField8b>::default(),
+		&commit_meta,
+		committed,
+		&codeword,
+		&multilins,
+		&point,
+		&mut proof,
+		&backend,
+	)
+	.unwrap();
+	assert_eq!(evals.len(), multilins.len());
+
+	let proof = proof.into_verifier().unwrap();
+
+	let mut verifier_proof = proof.try_clone().unwrap();
+	let commitment = verifier_proof.message().read().unwrap();
+	fri_pcs::verify_evaluation(
+		&commit_meta,
+		merkle_scheme,
+		&fri_params,
+		&commitment,
+		&point,
+		&evals,
+		&mut verifier_proof,
+	)
+	.unwrap();
+	verifier_proof.finalize().unwrap();
+
+	let mut bad_evals = evals;
+	bad_evals[0] += F::ONE;
+	let mut verifier_proof = proof;
+	let commitment = verifier_proof.message().read().unwrap();
+	assert!(
+		fri_pcs::verify_evaluation(
+			&commit_meta,
+			merkle_scheme,
+			&fri_params,
+			&commitment,
+			&point,
+			&bad_evals,
+			&mut verifier_proof,
+		)
+		.is_err()
+	);
+}
+
+#[test]
+fn test_fri_pcs_prove_verify_evaluation_multi() {
+	type F = <PackedBinaryField2x128b as PackedField>::Scalar;
+
+	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let merkle_scheme = merkle_prover.scheme();
+	let openings = [(0, 0), (0, 1), (1, 1), (2, 0), (3, 1), (3, 0)]
+		.map(|(committed, point)| fri_pcs::Opening { committed, point });
+	let fri_params = make_commit_params_for_claims::<_, BinaryField16b, _>(
+		&commit_meta,
+		merkle_scheme,
+		SECURITY_BITS,
+		1,
+		openings.len(),
+	)
+	.unwrap();
+	let ntt = SingleThreadedNTT::new(fri_params.rs_code().log_len()).unwrap();
+	let backend = make_portable_backend();
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let multilins =
+		generate_multilins::<PackedBinaryField2x128b>(commit_meta.n_multilins_by_vars(), &mut rng);
+	let CommitOutput {
+		commitment,
+		committed,
+		codeword,
+	} = commit(
+		&fri_params,
+		&ntt,
+		&merkle_prover,
+		&multilins
+			.iter()
+			.map(|mle| MLEDirectAdapter::from(mle.to_ref()))
+			.collect::<Vec<_>>(),
+	)
+	.unwrap();
+
+	let points = repeat_with(|| {
+		repeat_with(|| F::random(&mut rng))
+			.take(commit_meta.max_n_vars())
+			.collect::<Vec<_>>()
+	})
+	.take(2)
+	.collect::<Vec<_>>();
+	let points = points.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+	let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	proof.message().write(&commitment);
+	let evals = fri_pcs::prove_evaluation_multi(
+		&fri_params,
+		&ntt,
+		&merkle_prover,
+		DefaultEvaluationDomainFactory::<BinaryField8b>::default(),
+		&commit_meta,
+		committed,
+		&codeword,
+		&multilins,
+		&points,
+		&openings,
+		&mut proof,
+		&backend,
+	)
+	.unwrap();
+	assert_eq!(evals.len(), openings.len());
+
+	let mut proof = proof.into_verifier().unwrap();
+	let commitment = proof.message().read().unwrap();
+	fri_pcs::verify_evaluation_multi(
+		&commit_meta,
+		merkle_scheme,
+		&fri_params,
+		&commitment,
+		&points,
+		&openings,
+		&evals,
+		&mut proof,
+	)
+	.unwrap();
+	proof.finalize().unwrap();
+}
+
+#[test]
+fn test_commit_cache_round_trip() {
+	type F = <PackedBinaryField2x128b as PackedField>::Scalar;
+
+	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let merkle_scheme = merkle_prover.scheme();
+	let fri_params = make_commit_params_with_optimal_arity::<_, BinaryField16b, _>(
+		&commit_meta,
+		merkle_scheme,
+		SECURITY_BITS,
+		1,
+	)
+	.unwrap();
+	let ntt = SingleThreadedNTT::new(fri_params.rs_code().log_len()).unwrap();
+	let backend = make_portable_backend();
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let multilins =
+		generate_multilins::<PackedBinaryField2x128b>(commit_meta.n_multilins_by_vars(), &mut rng);
+	let output = commit(
+		&fri_params,
+		&ntt,
+		&merkle_prover,
+		&multilins
+			.iter()
+			.map(|mle| MLEDirectAdapter::from(mle.to_ref()))
+			.collect::<Vec<_>>(),
+	)
+	.unwrap();
+
+	let mut buf = Vec::new();
+	commit_cache::save_commit_output(&output, &mut buf, SerializationMode::CanonicalTower).unwrap();
+
+	let CommitOutput {
+		commitment,
+		committed,
+		codeword,
+	} = commit_cache::load_commit_output::<_, _, PackedBinaryField2x128b, _, _>(
+		buf.as_slice(),
+		SerializationMode::CanonicalTower,
+		&fri_params,
+		&merkle_prover,
+	)
+	.unwrap();
+	assert_eq!(commitment, output.commitment);
+	assert_eq!(codeword, output.codeword);
+
+	// The loaded commitment can be opened without committing again.
+	let point = repeat_with(|| F::random(&mut rng))
+		.take(commit_meta.max_n_vars())
+		.collect::<Vec<_>>();
+	let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	proof.message().write(&commitment);
+	let evals = fri_pcs::prove_evaluation(
+		&fri_params,
+		&ntt,
+		&merkle_prover,
+		DefaultEvaluationDomainFactory::<BinaryField8b>::default(),
+		&commit_meta,
+		committed,
+		&codeword,
+		&multilins,
+		&point,
+		&mut proof,
+		&backend,
+	)
+	.unwrap();
+
+	let mut verifier_proof = proof.into_verifier().unwrap();
+	let commitment = verifier_proof.message().read().unwrap();
+	fri_pcs::verify_evaluation(
+		&commit_meta,
+		merkle_scheme,
+		&fri_params,
+		&commitment,
+		&point,
+		&evals,
+		&mut verifier_proof,
+	)
+	.unwrap();
+	verifier_proof.finalize().unwrap();
+
+	// Corrupt the last codeword element.
+	let last = buf.len() - 1;
+	buf[last] ^= 1;
+	assert!(matches!(
+		commit_cache::load_commit_output::<_, _, PackedBinaryField2x128b, _, _>(
+			buf.as_slice(),
+			SerializationMode::CanonicalTower,
+			&fri_params,
+			&merkle_prover,
+		),
+		Err(Error::CommitmentMismatch)
+	));
+}
@@ -119,6 +119,7 @@ fn make_commit_params_with_constant_arity<F, FEncode>(
 	security_bits: usize,
 	log_inv_rate: usize,
 	arity: usize,
+	n_claims: usize,
 ) -> Result<FRIParams<F, FEncode>, Error>
 where
 	F: BinaryField + ExtensionField<FEncode>,
@@ -129,7 +130,11 @@ where
 	let log_dim = commit_meta.total_vars.saturating_sub(arity);
 	let log_batch_size = commit_meta.total_vars.min(arity);
 	let rs_code = ReedSolomonCode::new(log_dim, log_inv_rate)?;
-	let n_test_queries = fri::calculate_n_test_queries::<F, _>(security_bits, &rs_code)?;
+	let n_test_queries = fri::calculate_n_test_queries_for_claims::<F, _>(
+		security_bits,
+		&rs_code.interleaved(log_batch_size),
+		n_claims,
+	)?;
 
 	let cap_height = log2_ceil_usize(n_test_queries);
 	let fold_arities = std::iter::repeat_n(
@@ -187,10 +192,31 @@ where
 }
 
 pub fn make_commit_params_with_optimal_arity<F, FEncode, MTScheme>(
+	commit_meta: &CommitMeta,
+	merkle_scheme: &MTScheme,
+	security_bits: usize,
+	log_inv_rate: usize,
+) -> Result<FRIParams<F, FEncode>, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	make_commit_params_for_claims(commit_meta, merkle_scheme, security_bits, log_inv_rate, 1)
+}
+
+/// Same as [`make_commit_params_with_optimal_arity`], for openings that prove `n_claims` sumcheck
+/// claims at once, such as the openings at several points of
+/// [`super::fri_pcs::prove_evaluation_multi`].
+///
+/// The number of test queries accounts for the batching of the claims, see
+/// [`fri::calculate_n_test_queries_for_claims`].
+pub fn make_commit_params_for_claims<F, FEncode, MTScheme>(
 	commit_meta: &CommitMeta,
 	_merkle_scheme: &MTScheme,
 	security_bits: usize,
 	log_inv_rate: usize,
+	n_claims: usize,
 ) -> Result<FRIParams<F, FEncode>, Error>
 where
 	F: BinaryField + ExtensionField<FEncode>,
@@ -202,7 +228,94 @@ where
 		size_of::<MTScheme::Digest>(),
 		size_of::<F>(),
 	);
-	make_commit_params_with_constant_arity(commit_meta, security_bits, log_inv_rate, arity)
+	make_commit_params_with_constant_arity(
+		commit_meta,
+		security_bits,
+		log_inv_rate,
+		arity,
+		n_claims,
+	)
+}
+
+/// Returns the FRI parameters for the batch described by `commit_meta` that minimize the size of
+/// the opening proof.
+///
+/// Unlike [`make_commit_params_with_optimal_arity`], which picks the folding arity with a
+/// closed-form approximation, this computes the exact opening size with [`fri_opening_size`] for
+/// every folding arity and keeps the smallest one. Ties are broken towards the smaller arity,
+/// which is cheaper for the prover.
+pub fn find_proof_size_optimal_pcs<F, FEncode, MTScheme>(
+	commit_meta: &CommitMeta,
+	merkle_scheme: &MTScheme,
+	security_bits: usize,
+	log_inv_rate: usize,
+) -> Result<FRIParams<F, FEncode>, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	let max_arity = (commit_meta.total_vars + log_inv_rate).max(1);
+	(1..=max_arity)
+		.filter_map(|arity| {
+			make_commit_params_with_constant_arity(
+				commit_meta,
+				security_bits,
+				log_inv_rate,
+				arity,
+				1,
+			)
+			.ok()
+		})
+		.min_by_key(|fri_params| fri_opening_size(fri_params, merkle_scheme))
+		.map_or_else(
+			|| {
+				make_commit_params_with_optimal_arity(
+					commit_meta,
+					merkle_scheme,
+					security_bits,
+					log_inv_rate,
+				)
+			},
+			Ok,
+		)
+}
+
+/// Returns the size in bytes of the FRI part of a PCS opening with the given parameters.
+///
+/// This counts the FRI round commitments, the terminal codeword and the query openings, but not
+/// the sumcheck that is interleaved with the folding rounds.
+pub fn fri_opening_size<F, FA, MTScheme>(
+	fri_params: &FRIParams<F, FA>,
+	merkle_scheme: &MTScheme,
+) -> usize
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	let scalar_size = size_of::<F>();
+	let digest_size = size_of::<MTScheme::Digest>();
+
+	let n_queries = fri_params.n_test_queries();
+	let round_commitments = fri_params.n_oracles() * digest_size;
+	let terminate_codeword = (1
+		<< (fri_params.n_final_challenges() + fri_params.rs_code().log_inv_rate()))
+		* scalar_size;
+
+	// Every oracle but the terminal one is opened on a coset of the size of its folding arity,
+	// with a Merkle path down to the layer sent once for all queries.
+	let mut log_n_cosets = fri_params.log_len();
+	let mut layers = 0;
+	let mut queries = 0;
+	for &arity in fri_params.fold_arities() {
+		log_n_cosets -= arity;
+		let layer_depth = merkle_scheme.optimal_verify_layer(n_queries, log_n_cosets);
+		layers += (1 << layer_depth) * digest_size;
+		queries += (1 << arity) * scalar_size + (log_n_cosets - layer_depth) * digest_size;
+	}
+
+	round_commitments + terminate_codeword + layers + n_queries * queries
 }
 
 /// A description of a sumcheck claim arising from a FRI PCS sumcheck.
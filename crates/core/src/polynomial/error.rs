@@ -27,8 +27,13 @@ pub enum Error {
 	IndexCompositionIndicesOutOfBounds,
 	#[error("mixed polynomial was not provided")]
 	MixedMultilinearNotFound,
-	#[error("MultilinearComposite constructed with incorrect arguments: {0}")]
-	MultilinearCompositeValidation(String),
+	#[error(
+		"composition has {composition_n_vars} variables, but {n_multilinears} multilinears were given"
+	)]
+	CompositionArityMismatch {
+		composition_n_vars: usize,
+		n_multilinears: usize,
+	},
 	#[error("argument {arg} must be in the range {range:?}")]
 	ArgumentRangeError { arg: String, range: Range<usize> },
 	#[error("{0}")]
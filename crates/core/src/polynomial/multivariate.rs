@@ -182,21 +182,17 @@ where
 {
 	pub fn new(n_vars: usize, composition: C, multilinears: Vec<M>) -> Result<Self, Error> {
 		if composition.n_vars() != multilinears.len() {
-			let err_str = format!(
-				"Number of variables in composition {} does not match length of multilinears {}",
-				composition.n_vars(),
-				multilinears.len()
-			);
-			bail!(Error::MultilinearCompositeValidation(err_str));
+			bail!(Error::CompositionArityMismatch {
+				composition_n_vars: composition.n_vars(),
+				n_multilinears: multilinears.len(),
+			});
 		}
 		for multilin in multilinears.iter().map(Borrow::borrow) {
 			if multilin.n_vars() != n_vars {
-				let err_str = format!(
-					"Number of variables in multilinear {} does not match n_vars {}",
-					multilin.n_vars(),
-					n_vars
-				);
-				bail!(Error::MultilinearCompositeValidation(err_str));
+				bail!(Error::IncorrectNumberOfVariables {
+					expected: n_vars,
+					actual: multilin.n_vars(),
+				});
 			}
 		}
 		Ok(Self {
@@ -1,9 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::TowerField;
-
 use crate::{
-	oracle::{CompositePolyOracle, Error as OracleError, OracleId},
+	oracle::{Error as OracleError, OracleId},
 	polynomial::Error as PolynomialError,
 };
 
@@ -35,10 +33,8 @@ pub enum Error {
 
 #[derive(Debug, thiserror::Error)]
 pub enum VerificationError {
-	#[error("evaluation is incorrect for oracle: {0}")]
-	IncorrectEvaluation(String),
-	#[error("CompositePolyOracle verification failed: {0}")]
-	IncorrectCompositePolyEvaluation(String),
+	#[error("evaluation is incorrect for oracle {oracle}")]
+	IncorrectEvaluation { oracle: OracleId },
 	#[error("subproof type or shape does not match the claim")]
 	SubproofMismatch,
 	#[error("Advised MLECheck ConstraintSet positios must has the same eval_point")]
@@ -48,17 +44,3 @@ pub enum VerificationError {
 	#[error("The referenced duplicate claim is different from expected")]
 	DuplicateClaimMismatch,
 }
-
-impl VerificationError {
-	pub fn incorrect_composite_poly_evaluation<F: TowerField>(
-		oracle: &CompositePolyOracle<F>,
-	) -> Self {
-		let names = oracle
-			.inner_polys()
-			.iter()
-			.map(|inner| inner.label())
-			.collect::<Vec<_>>();
-		let s = format!("Composition: {:?} with inner: {:?}", oracle.composition(), names);
-		Self::IncorrectCompositePolyEvaluation(s)
-	}
-}
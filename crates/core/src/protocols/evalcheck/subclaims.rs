@@ -9,10 +9,8 @@
 //!  * one multilin (the multiplier) is transparent (`shift_ind`, `eq_ind`, or tower basis)
 //!  * other multilin is a projection of one of the evalcheck claim multilins to its first variables
 
-use std::collections::HashSet;
-
 use binius_field::{ExtensionField, Field, PackedExtension, PackedField, TowerField};
-use binius_hal::{ComputationBackend, ComputationBackendExt};
+use binius_hal::ComputationBackend;
 use binius_math::{
 	ArithExpr, CompositionPoly, EvaluationDomainFactory, EvaluationOrder, MLEDirectAdapter,
 	MultilinearExtension, MultilinearQuery,
@@ -29,13 +27,16 @@ use crate::{
 		MultilinearOracleSet, MultilinearPolyVariant, OracleId, Packed, Shifted,
 	},
 	polynomial::MultivariatePoly,
-	protocols::sumcheck::{
-		self, Error as SumcheckError,
-		prove::{
-			front_loaded,
-			oracles::{
-				MLECheckProverWithMeta, SumcheckProversWithMetas,
-				constraint_sets_mlecheck_prover_meta, constraint_sets_sumcheck_provers_metas,
+	protocols::{
+		memoized_queries::MemoizedQueries,
+		sumcheck::{
+			self, Error as SumcheckError,
+			prove::{
+				front_loaded,
+				oracles::{
+					MLECheckProverWithMeta, SumcheckProversWithMetas,
+					constraint_sets_mlecheck_prover_meta, constraint_sets_sumcheck_provers_metas,
+				},
 			},
 		},
 	},
@@ -340,40 +341,36 @@ where
 
 /// Struct for memoizing tensor expansions of evaluation points and partial evaluations of
 /// multilinears
-#[allow(clippy::type_complexity)]
 pub struct MemoizedData<'a, P: PackedField, Backend: ComputationBackend> {
-	query: Vec<(Vec<P::Scalar>, MultilinearQuery<P, Backend::Vec<P>>)>,
+	queries: MemoizedQueries<P, Backend>,
 	partial_evals: EvalPointOracleIdMap<MultilinearWitness<'a, P>, P::Scalar>,
 }
 
 impl<'a, P: PackedField, Backend: ComputationBackend> MemoizedData<'a, P, Backend> {
 	#[allow(clippy::new_without_default)]
 	pub fn new() -> Self {
+		Self::with_queries(MemoizedQueries::new())
+	}
+
+	/// Creates the memoized data from tensor expansions memoized by an earlier protocol.
+	pub fn with_queries(queries: MemoizedQueries<P, Backend>) -> Self {
 		Self {
-			query: Vec::new(),
+			queries,
 			partial_evals: EvalPointOracleIdMap::new(),
 		}
 	}
 
+	/// Returns the memoized tensor expansions of evaluation points.
+	pub const fn queries_mut(&mut self) -> &mut MemoizedQueries<P, Backend> {
+		&mut self.queries
+	}
+
 	pub fn full_query(
 		&mut self,
 		eval_point: &[P::Scalar],
 		backend: &Backend,
 	) -> Result<&MultilinearQuery<P, Backend::Vec<P>>, binius_hal::Error> {
-		if let Some(index) = self
-			.query
-			.iter()
-			.position(|(memo_eval_point, _)| memo_eval_point.as_slice() == eval_point)
-		{
-			let (_, query) = &self.query[index];
-			return Ok(query);
-		}
-
-		let query = backend.multilinear_query(eval_point)?;
-		self.query.push((eval_point.to_vec(), query));
-
-		let (_, query) = self.query.last().expect("pushed query immediately above");
-		Ok(query)
+		self.queries.full_query(eval_point, backend)
 	}
 
 	/// Finds a `MultilinearQuery` corresponding to the given `eval_point`.
@@ -381,36 +378,15 @@ impl<'a, P: PackedField, Backend: ComputationBackend> MemoizedData<'a, P, Backen
 		&self,
 		eval_point: &[P::Scalar],
 	) -> Option<&MultilinearQuery<P, Backend::Vec<P>>> {
-		self.query
-			.iter()
-			.position(|(memo_eval_point, _)| memo_eval_point.as_slice() == eval_point)
-			.map(|index| {
-				let (_, query) = &self.query[index];
-				query
-			})
+		self.queries.full_query_readonly(eval_point)
 	}
 
-	#[instrument(skip_all, name = "Evalcheck::memoize_query_par", level = "debug")]
 	pub fn memoize_query_par<'b>(
 		&mut self,
 		eval_points: impl IntoIterator<Item = &'b [P::Scalar]>,
 		backend: &Backend,
 	) -> Result<(), binius_hal::Error> {
-		let deduplicated_eval_points = eval_points.into_iter().collect::<HashSet<_>>();
-
-		let new_queries = deduplicated_eval_points
-			.into_par_iter()
-			.filter(|ep| self.full_query_readonly(ep).is_none())
-			.map(|ep| {
-				backend
-					.multilinear_query::<P>(ep)
-					.map(|res| (ep.to_vec(), res))
-			})
-			.collect::<Result<Vec<_>, binius_hal::Error>>()?;
-
-		self.query.extend(new_queries);
-
-		Ok(())
+		self.queries.memoize_query_par(eval_points, backend)
 	}
 
 	pub fn memoize_partial_evals(
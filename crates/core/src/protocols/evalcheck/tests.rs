@@ -115,7 +115,7 @@ where
 	prover_state.prove(claims.clone(), &mut transcript).unwrap();
 	assert_eq!(prover_state.committed_eval_claims().len(), 1);
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
 
 	verifier_state.verify(claims, &mut transcript).unwrap();
@@ -195,7 +195,7 @@ where
 	prover_state.prove(claims.clone(), &mut transcript).unwrap();
 	assert_eq!(prover_state.committed_eval_claims().len(), 1);
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
 	verifier_state.verify(claims, &mut transcript).unwrap();
 	assert_eq!(verifier_state.committed_eval_claims().len(), 1);
@@ -295,7 +295,7 @@ where
 		.prove(vec![claim.clone()], &mut transcript)
 		.unwrap();
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
 	verifier_state.verify(vec![claim], &mut transcript).unwrap();
 }
@@ -371,7 +371,7 @@ where
 		.prove(vec![claim.clone()], &mut transcript)
 		.unwrap();
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
 	verifier_state.verify(vec![claim], &mut transcript).unwrap();
 }
@@ -477,7 +477,7 @@ where
 		.prove(vec![claim.clone()], &mut transcript)
 		.unwrap();
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
 	verifier_state.verify(vec![claim], &mut transcript).unwrap();
 }
@@ -542,7 +542,7 @@ where
 		.prove(vec![claim.clone()], &mut transcript)
 		.unwrap();
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
 	verifier_state.verify(vec![claim], &mut transcript).unwrap();
 }
@@ -635,7 +635,7 @@ where
 		.prove(vec![claim.clone()], &mut transcript)
 		.unwrap();
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
 	verifier_state.verify(vec![claim], &mut transcript).unwrap();
 }
@@ -657,7 +657,7 @@ fn test_evalcheck_serialization() {
 	serialize_evalcheck_proof(&mut writer, &EvalcheckHint::NewClaim);
 	serialize_evalcheck_proof(&mut writer, &EvalcheckHint::DuplicateClaim(6));
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 	let mut reader = transcript.message();
 
 	let out_1 = deserialize_evalcheck_proof(&mut reader).unwrap();
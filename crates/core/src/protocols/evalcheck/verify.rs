@@ -151,12 +151,11 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 		} = evalcheck_claim;
 
 		let multilinear = self.oracles.oracle(id);
-		let multilinear_label = multilinear.label();
 		match multilinear.variant {
 			MultilinearPolyVariant::Transparent(inner) => {
 				let actual_eval = inner.poly().evaluate(&eval_point)?;
 				if actual_eval != eval {
-					return Err(VerificationError::IncorrectEvaluation(multilinear_label).into());
+					return Err(VerificationError::IncorrectEvaluation { oracle: id }.into());
 				}
 			}
 
@@ -235,7 +234,7 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 					+ inner_product_unchecked::<F, F>(evals, linear_combination.coefficients());
 
 				if actual_eval != eval {
-					return Err(VerificationError::IncorrectEvaluation(multilinear_label).into());
+					return Err(VerificationError::IncorrectEvaluation { oracle: id }.into());
 				}
 			}
 			MultilinearPolyVariant::ZeroPadded(padded) => {
@@ -9,8 +9,9 @@ use binius_utils::bail;
 use getset::{CopyGetters, Getters};
 
 use crate::{
-	merkle_tree::MerkleTreeScheme, protocols::fri::Error,
-	reed_solomon::reed_solomon::ReedSolomonCode,
+	merkle_tree::MerkleTreeScheme,
+	protocols::fri::Error,
+	reed_solomon::{LinearCode, reed_solomon::ReedSolomonCode},
 };
 
 /// Calculate fold of `values` at `index` with `r` random coefficient.
@@ -274,23 +275,50 @@ pub type TerminateCodeword<F> = Vec<F>;
 
 /// Calculates the number of test queries required to achieve a target security level.
 ///
+/// For an interleaved code, the batched messages are committed together as the leading variables
+/// of the multilinear, which count towards the sumcheck error, while the proximity test runs on
+/// codewords over the interleaved alphabet, which have the distance of the inner code.
+///
 /// Throws [`Error::ParameterError`] if the security level is unattainable given the code
 /// parameters.
 pub fn calculate_n_test_queries<F, FEncode>(
 	security_bits: usize,
-	code: &ReedSolomonCode<FEncode>,
+	code: &impl LinearCode<Field = FEncode>,
+) -> Result<usize, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+{
+	calculate_n_test_queries_for_claims::<F, _>(security_bits, code, 1)
+}
+
+/// Calculates the number of test queries required to achieve a target security level, when the
+/// opening proves `n_claims` sumcheck claims batched with random coefficients.
+///
+/// This is the case of openings at several evaluation points. The batching adds an error of at
+/// most `(n_claims - 1) / |F|`, which is subtracted from the error allowed for the queries.
+///
+/// Throws [`Error::ParameterError`] if the security level is unattainable given the code
+/// parameters.
+pub fn calculate_n_test_queries_for_claims<F, FEncode>(
+	security_bits: usize,
+	code: &impl LinearCode<Field = FEncode>,
+	n_claims: usize,
 ) -> Result<usize, Error>
 where
 	F: BinaryField + ExtensionField<FEncode>,
 	FEncode: BinaryField,
 {
 	let field_size = 2.0_f64.powi(F::N_BITS as i32);
-	let sumcheck_err = (2 * code.log_dim()) as f64 / field_size;
-	// 2 ⋅ ℓ' / |T_{τ}|
+	let sumcheck_err = (2 * (code.log_dim() + code.log_batch_size())) as f64 / field_size;
+	// 2 ⋅ ℓ / |T_{τ}|
 	let folding_err = code.len() as f64 / field_size;
 	// 2^{ℓ' + R} / |T_{τ}|
-	let per_query_err = 0.5 * (1f64 + 2.0f64.powi(-(code.log_inv_rate() as i32)));
-	let allowed_query_err = 2.0_f64.powi(-(security_bits as i32)) - sumcheck_err - folding_err;
+	let batching_err = n_claims.saturating_sub(1) as f64 / field_size;
+	// (k - 1) / |T_{τ}|
+	let per_query_err = 1.0 - code.relative_distance() / 2.0;
+	let allowed_query_err =
+		2.0_f64.powi(-(security_bits as i32)) - sumcheck_err - folding_err - batching_err;
 	if allowed_query_err <= 0.0 {
 		return Err(Error::ParameterError);
 	}
@@ -354,6 +382,51 @@ mod tests {
 		assert_eq!(n_test_queries, 143);
 	}
 
+	#[test]
+	fn test_calculate_n_test_queries_interleaved() {
+		let security_bits = 96;
+		let rs_code = ReedSolomonCode::<BinaryField32b>::new(24, 2).unwrap();
+		let n_test_queries =
+			calculate_n_test_queries::<BinaryField128b, _>(security_bits, &rs_code).unwrap();
+		for log_batch_size in [0, 4] {
+			let interleaved = rs_code.interleaved(log_batch_size);
+			assert_eq!(
+				calculate_n_test_queries::<BinaryField128b, _>(security_bits, &interleaved)
+					.unwrap(),
+				n_test_queries
+			);
+		}
+	}
+
+	#[test]
+	fn test_calculate_n_test_queries_for_claims() {
+		let security_bits = 96;
+		let rs_code = ReedSolomonCode::<BinaryField32b>::new(28, 1).unwrap();
+		let n_test_queries =
+			calculate_n_test_queries::<BinaryField128b, _>(security_bits, &rs_code).unwrap();
+		assert_eq!(
+			calculate_n_test_queries_for_claims::<BinaryField128b, _>(security_bits, &rs_code, 1)
+				.unwrap(),
+			n_test_queries
+		);
+		assert!(
+			calculate_n_test_queries_for_claims::<BinaryField128b, _>(
+				security_bits,
+				&rs_code,
+				1 << 30
+			)
+			.unwrap() > n_test_queries
+		);
+		assert_matches!(
+			calculate_n_test_queries_for_claims::<BinaryField128b, _>(
+				security_bits,
+				&rs_code,
+				1 << 40
+			),
+			Err(Error::ParameterError)
+		);
+	}
+
 	#[test]
 	fn test_calculate_n_test_queries_unsatisfiable() {
 		let security_bits = 128;
@@ -8,8 +8,14 @@ use crate::{reed_solomon, transcript};
 pub enum Error {
 	#[error("cannot calculate parameters satisfying the security target")]
 	ParameterError,
-	#[error("conflicting or incorrect constructor argument: {0}")]
-	InvalidArgs(String),
+	#[error("got {actual} round commitments, expected {expected}")]
+	IncorrectNumberOfRoundCommitments { expected: usize, actual: usize },
+	#[error("got {actual} folding challenges, expected {expected}")]
+	IncorrectNumberOfChallenges { expected: usize, actual: usize },
+	#[error("interleaved message has {actual} elements, expected {expected}")]
+	IncorrectMessageLength { expected: usize, actual: usize },
+	#[error("codeword has {actual} elements, expected {expected}")]
+	IncorrectCodewordLength { expected: usize, actual: usize },
 	#[error("FRI message dimension is too small")]
 	MessageDimensionIsTooSmall,
 	#[error("fold arities total exceeds the number of fold rounds")]
@@ -22,6 +22,7 @@ use super::{
 use crate::{
 	fiat_shamir::{CanSampleBits, Challenger},
 	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	metrics,
 	protocols::fri::{common::fold_interleaved_chunk, logging::FRIFoldData},
 	reed_solomon::reed_solomon::ReedSolomonCode,
 	transcript::{ProverTranscript, TranscriptWriter},
@@ -151,9 +152,10 @@ where
 {
 	let n_elems = rs_code.dim() << params.log_batch_size();
 	if message.len() * P::WIDTH != n_elems {
-		bail!(Error::InvalidArgs(
-			"interleaved message length does not match code parameters".to_string()
-		));
+		bail!(Error::IncorrectMessageLength {
+			expected: n_elems,
+			actual: message.len() * P::WIDTH,
+		});
 	}
 
 	commit_interleaved_with(params, ntt, merkle_prover, move |buffer| {
@@ -213,8 +215,48 @@ where
 	)
 	.in_scope(|| rs_code.encode_ext_batch_inplace(ntt, &mut encoded, log_batch_size))?;
 
+	let (commitment, committed) = commit_codeword(params, merkle_prover, &encoded)?;
+	metrics::add_committed_bytes(size_of_val(encoded.as_slice()) as u64);
+	Ok(CommitOutput {
+		commitment,
+		committed,
+		codeword: encoded,
+	})
+}
+
+/// Commits an encoded codeword to a Merkle tree.
+///
+/// This is the Merkle tree commitment step of [`commit_interleaved_with`], which can be used to
+/// recover the committed data of a codeword that was encoded earlier, e.g. after loading it from
+/// storage.
+///
+/// ## Arguments
+///
+/// * `params` - common FRI protocol parameters.
+/// * `merkle_prover` - the Merkle tree prover to use for committing
+/// * `codeword` - the interleaved codeword
+pub fn commit_codeword<F, FA, P, MerkleProver, VCS>(
+	params: &FRIParams<F, FA>,
+	merkle_prover: &MerkleProver,
+	codeword: &[P],
+) -> Result<(VCS::Digest, MerkleProver::Committed), Error>
+where
+	F: BinaryField,
+	FA: BinaryField,
+	P: PackedField<Scalar = F>,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F>,
+{
+	if codeword.len() * P::WIDTH != 1 << params.log_len() {
+		bail!(Error::IncorrectCodewordLength {
+			expected: 1 << params.log_len(),
+			actual: codeword.len() * P::WIDTH,
+		});
+	}
+
 	// Take the first arity as coset_log_len, or use the value such that the number of leaves equals
 	// 1 << log_inv_rate if arities is empty
+	let log_elems = params.rs_code().log_dim() + params.log_batch_size();
 	let coset_log_len = params.fold_arities().first().copied().unwrap_or(log_elems);
 
 	let log_len = params.log_len() - coset_log_len;
@@ -227,13 +269,13 @@ where
 	)
 	.entered();
 	let (commitment, vcs_committed) = if coset_log_len > P::LOG_WIDTH {
-		let iterated_big_chunks = to_par_scalar_big_chunks(&encoded, 1 << coset_log_len);
+		let iterated_big_chunks = to_par_scalar_big_chunks(codeword, 1 << coset_log_len);
 
 		merkle_prover
 			.commit_iterated(iterated_big_chunks, log_len)
 			.map_err(|err| Error::VectorCommit(Box::new(err)))?
 	} else {
-		let iterated_small_chunks = to_par_scalar_small_chunks(&encoded, 1 << coset_log_len);
+		let iterated_small_chunks = to_par_scalar_small_chunks(codeword, 1 << coset_log_len);
 
 		merkle_prover
 			.commit_iterated(iterated_small_chunks, log_len)
@@ -241,11 +283,7 @@ where
 	};
 	drop(merkle_tree_span);
 
-	Ok(CommitOutput {
-		commitment: commitment.root,
-		committed: vcs_committed,
-		codeword: encoded,
-	})
+	Ok((commitment.root, vcs_committed))
 }
 
 pub enum FoldRoundOutput<VCSCommitment> {
@@ -291,9 +329,10 @@ where
 		committed: &'a MerkleProver::Committed,
 	) -> Result<Self, Error> {
 		if len_packed_slice(committed_codeword) < 1 << params.log_len() {
-			bail!(Error::InvalidArgs(
-				"Reed–Solomon code length must match interleaved codeword length".to_string(),
-			));
+			bail!(Error::IncorrectCodewordLength {
+				expected: 1 << params.log_len(),
+				actual: len_packed_slice(committed_codeword),
+			});
 		}
 
 		let next_commit_round = params.fold_arities().first().copied();
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{iter::repeat_with, vec};
+use std::{fmt::Debug, iter::repeat_with, vec};
 
 use binius_field::{
 	BinaryField, BinaryField16b, BinaryField32b, BinaryField128b, ExtensionField,
@@ -10,17 +10,22 @@ use binius_field::{
 	underlier::UnderlierType,
 };
 use binius_hal::{ComputationBackendExt, make_portable_backend};
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::{
+	blake3::{Blake3, Blake3DigestCompression},
+	groestl::{Groestl256, Groestl256ByteCompression},
+};
 use binius_math::MultilinearExtension;
 use binius_maybe_rayon::prelude::ParallelIterator;
 use binius_ntt::SingleThreadedNTT;
-use binius_utils::checked_arithmetics::log2_strict_usize;
+use binius_utils::{DeserializeBytes, SerializeBytes, checked_arithmetics::log2_strict_usize};
 use rand::prelude::*;
 
 use super::to_par_scalar_big_chunks;
 use crate::{
 	fiat_shamir::{CanSample, HasherChallenger},
-	merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver},
+	merkle_tree::{
+		BinaryMerkleTreeProver, KAryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme,
+	},
 	protocols::fri::{
 		self, CommitOutput, FRIFolder, FRIParams, FRIVerifier, FoldRoundOutput,
 		to_par_scalar_small_chunks,
@@ -41,9 +46,32 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	PackedType<U, F>: PackedField,
 	PackedType<U, FA>: PackedField,
 {
-	let mut rng = StdRng::seed_from_u64(0);
-
 	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	test_commit_prove_verify_success_with_merkle_prover::<U, F, FA, _, _>(
+		&merkle_prover,
+		log_dimension,
+		log_inv_rate,
+		log_batch_size,
+		arities,
+	);
+}
+
+fn test_commit_prove_verify_success_with_merkle_prover<U, F, FA, MerkleProver, VCS>(
+	merkle_prover: &MerkleProver,
+	log_dimension: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	arities: &[usize],
+) where
+	U: UnderlierType + PackScalar<F> + PackScalar<FA>,
+	F: TowerField + ExtensionField<FA> + PackedField<Scalar = F>,
+	FA: BinaryField,
+	PackedType<U, F>: PackedField,
+	PackedType<U, FA>: PackedField,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F, Digest: SerializeBytes + DeserializeBytes + Debug>,
+{
+	let mut rng = StdRng::seed_from_u64(0);
 
 	let committed_rs_code = ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate).unwrap();
 
@@ -67,11 +95,11 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		commitment: mut codeword_commitment,
 		committed: codeword_committed,
 		codeword,
-	} = fri::commit_interleaved(&committed_rs_code, &params, &ntt, &merkle_prover, &msg).unwrap();
+	} = fri::commit_interleaved(&committed_rs_code, &params, &ntt, merkle_prover, &msg).unwrap();
 
 	// Run the prover to generate the proximity proof
 	let mut round_prover =
-		FRIFolder::new(&params, &ntt, &merkle_prover, &codeword, &codeword_committed).unwrap();
+		FRIFolder::new(&params, &ntt, merkle_prover, &codeword, &codeword_committed).unwrap();
 
 	let mut prover_challenger = ProverTranscript::<HasherChallenger<Groestl256>>::new();
 	prover_challenger.message().write(&codeword_commitment);
@@ -90,14 +118,14 @@ fn test_commit_prove_verify_success<U, F, FA>(
 
 	round_prover.finish_proof(&mut prover_challenger).unwrap();
 	// Now run the verifier
-	let mut verifier_challenger = prover_challenger.into_verifier();
+	let mut verifier_challenger = prover_challenger.into_verifier().unwrap();
 	codeword_commitment = verifier_challenger.message().read().unwrap();
 	let mut verifier_challenges = Vec::with_capacity(params.n_fold_rounds());
 
 	assert_eq!(round_commitments.len(), n_round_commitments);
 	for (i, commitment) in round_commitments.iter().enumerate() {
 		verifier_challenges.append(&mut verifier_challenger.sample_vec(params.fold_arities()[i]));
-		let mut _commitment = *commitment;
+		let mut _commitment = commitment.clone();
 		_commitment = verifier_challenger.message().read().unwrap();
 	}
 
@@ -125,7 +153,7 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	)
 	.unwrap();
 
-	let mut cloned_verifier_challenger = verifier_challenger.clone();
+	let mut cloned_verifier_challenger = verifier_challenger.try_clone().unwrap();
 
 	let terminate_codeword_len =
 		1 << (params.n_final_challenges() + params.rs_code().log_inv_rate());
@@ -136,7 +164,7 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	let log_batch_size =
 		log2_strict_usize(terminate_codeword.len()).saturating_sub(params.rs_code().log_inv_rate());
 
-	let (commitment, tree) = merkle_prover
+	let (commitment, _) = merkle_prover
 		.commit(&terminate_codeword, 1 << log_batch_size)
 		.unwrap();
 
@@ -145,7 +173,7 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	assert_eq!(*last_round_commitment, commitment.root);
 
 	// Verify that the Merkle tree has exactly inv_rate leaves.
-	assert_eq!(tree.log_len, params.rs_code().log_inv_rate());
+	assert_eq!(commitment.depth, params.rs_code().log_inv_rate());
 
 	let final_fri_value = verifier.verify(&mut cloned_verifier_challenger).unwrap();
 	assert_eq!(computed_eval, final_fri_value);
@@ -183,6 +211,23 @@ fn test_commit_prove_verify_success_128b_higher_arity() {
 	);
 }
 
+#[test]
+fn test_commit_prove_verify_success_k_ary_merkle_tree() {
+	let log_dimension = 8;
+	let log_inv_rate = 2;
+	let arities = [3, 2, 1];
+
+	let merkle_prover =
+		KAryMerkleTreeProver::<_, Blake3, _, 4>::new(Blake3DigestCompression::default());
+	test_commit_prove_verify_success_with_merkle_prover::<
+		OptimalUnderlier128b,
+		BinaryField128b,
+		BinaryField16b,
+		_,
+		_,
+	>(&merkle_prover, log_dimension, log_inv_rate, 0, &arities);
+}
+
 #[test]
 fn test_commit_prove_verify_success_128b_interleaved() {
 	let log_dimension = 6;
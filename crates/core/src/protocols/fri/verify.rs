@@ -56,19 +56,17 @@ where
 		challenges: &'a [F],
 	) -> Result<Self, Error> {
 		if round_commitments.len() != params.n_oracles() {
-			bail!(Error::InvalidArgs(format!(
-				"got {} round commitments, expected {}",
-				round_commitments.len(),
-				params.n_oracles(),
-			)));
+			bail!(Error::IncorrectNumberOfRoundCommitments {
+				expected: params.n_oracles(),
+				actual: round_commitments.len(),
+			});
 		}
 
 		if challenges.len() != params.n_fold_rounds() {
-			bail!(Error::InvalidArgs(format!(
-				"got {} folding challenges, expected {}",
-				challenges.len(),
-				params.n_fold_rounds(),
-			)));
+			bail!(Error::IncorrectNumberOfChallenges {
+				expected: params.n_fold_rounds(),
+				actual: challenges.len(),
+			});
 		}
 
 		let (interleave_challenges, fold_challenges) = challenges.split_at(params.log_batch_size());
@@ -319,7 +319,7 @@ fn good_proof_verifies() {
 		)
 		.unwrap();
 
-		let mut verifier_transcript = transcript.into_verifier();
+		let mut verifier_transcript = transcript.into_verifier().unwrap();
 
 		let _reduced_claims =
 			batch_verify::batch_verify(evaluation_order, &claims, &mut verifier_transcript)
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::{Field, PackedField, packed::get_packed_slice};
+use binius_field::{Field, PackedField, packed::get_packed_slice, util::mul_packed_slices};
 use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
 use bytemuck::zeroed_vec;
@@ -59,11 +59,19 @@ impl<P: PackedField> GrandProductWitness<P> {
 					let pivot = prev_layer.len().saturating_sub(packed_len);
 
 					if pivot > 0 {
+						// Chosen so that every chunk is large enough for the vectorized slice
+						// multiplication to pay off.
+						const CHUNK_SIZE: usize = 1024;
+
 						let (evals_0, evals_1) = prev_layer.split_at(packed_len);
-						(layer.as_mut_slice(), evals_0, evals_1)
+						(
+							layer[..pivot].par_chunks_mut(CHUNK_SIZE),
+							evals_0[..pivot].par_chunks(CHUNK_SIZE),
+							evals_1.par_chunks(CHUNK_SIZE),
+						)
 							.into_par_iter()
-							.for_each(|(product, &eval_0, &eval_1)| {
-								*product = eval_0 * eval_1;
+							.for_each(|(products, evals_0, evals_1)| {
+								mul_packed_slices(evals_0, evals_1, products);
 							});
 					}
 
@@ -187,7 +187,7 @@ where
 	)
 	.unwrap();
 
-	let mut verify_transcript = prover_transcript.into_verifier();
+	let mut verify_transcript = prover_transcript.into_verifier().unwrap();
 	let verified_evalcheck_multilinear_claims =
 		batch_verify(evaluation_order, claims.clone(), &mut verify_transcript).unwrap();
 
@@ -8,11 +8,15 @@ use super::{error::Error, logging::RegularSumcheckDimensionsData};
 use crate::{
 	fiat_shamir::Challenger,
 	oracle::MultilinearOracleSet,
-	protocols::evalcheck::{
-		ConstraintSetEqIndPoint, EvalcheckMultilinearClaim, EvalcheckProver,
-		subclaims::{
-			MemoizedData, prove_bivariate_sumchecks_with_switchover, prove_mlecheck_with_switchover,
+	protocols::{
+		evalcheck::{
+			ConstraintSetEqIndPoint, EvalcheckMultilinearClaim, EvalcheckProver,
+			subclaims::{
+				MemoizedData, prove_bivariate_sumchecks_with_switchover,
+				prove_mlecheck_with_switchover,
+			},
 		},
+		memoized_queries::MemoizedQueries,
 	},
 	transcript::ProverTranscript,
 	witness::MultilinearExtensionIndex,
@@ -28,6 +32,7 @@ pub fn prove<'a, F, P, DomainField, Challenger_, Backend>(
 	oracles: &mut MultilinearOracleSet<F>,
 	witness_index: &'a mut MultilinearExtensionIndex<P>,
 	claims: impl IntoIterator<Item = EvalcheckMultilinearClaim<F>>,
+	memoized_queries: MemoizedQueries<P, Backend>,
 	switchover_fn: impl Fn(usize) -> usize + Clone + 'static,
 	transcript: &mut ProverTranscript<Challenger_>,
 	domain_factory: impl EvaluationDomainFactory<DomainField>,
@@ -44,6 +49,7 @@ where
 {
 	let mut evalcheck_prover =
 		EvalcheckProver::<F, P, Backend>::new(oracles, witness_index, backend);
+	evalcheck_prover.memoized_data = MemoizedData::with_queries(memoized_queries);
 
 	let claims: Vec<_> = claims.into_iter().collect();
 
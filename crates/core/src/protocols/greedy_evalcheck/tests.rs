@@ -21,6 +21,7 @@ use crate::{
 	protocols::{
 		evalcheck::EvalcheckMultilinearClaim,
 		greedy_evalcheck::{prove, verify},
+		memoized_queries::MemoizedQueries,
 		sumcheck::standard_switchover_heuristic,
 	},
 	transcript::ProverTranscript,
@@ -162,6 +163,7 @@ where
 		&mut oracles,
 		&mut witness_index,
 		[composite_claim.clone(), shifted_claim.clone()],
+		MemoizedQueries::new(),
 		standard_switchover_heuristic(-2),
 		&mut transcript,
 		&domain_factory,
@@ -169,7 +171,7 @@ where
 	)
 	.unwrap();
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 	verify(&mut oracles, [composite_claim, shifted_claim], &mut transcript).unwrap();
 }
 
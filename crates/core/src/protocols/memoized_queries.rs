@@ -0,0 +1,86 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Memoization of the tensor expansions of evaluation points, shared by the provers of the
+//! zerocheck and evalcheck protocols.
+
+use std::collections::HashSet;
+
+use binius_field::PackedField;
+use binius_hal::{ComputationBackend, ComputationBackendExt};
+use binius_math::MultilinearQuery;
+use binius_maybe_rayon::prelude::*;
+use tracing::instrument;
+
+/// Memoized tensor expansions of evaluation points.
+///
+/// Every point is expanded at most once, so the provers that need the equality indicator at the
+/// same point share a single $2^n$ table.
+#[allow(clippy::type_complexity)]
+pub struct MemoizedQueries<P: PackedField, Backend: ComputationBackend> {
+	queries: Vec<(Vec<P::Scalar>, MultilinearQuery<P, Backend::Vec<P>>)>,
+}
+
+impl<P: PackedField, Backend: ComputationBackend> MemoizedQueries<P, Backend> {
+	#[allow(clippy::new_without_default)]
+	pub const fn new() -> Self {
+		Self {
+			queries: Vec::new(),
+		}
+	}
+
+	/// Returns the `MultilinearQuery` of `eval_point`, expanding and memoizing it if needed.
+	pub fn full_query(
+		&mut self,
+		eval_point: &[P::Scalar],
+		backend: &Backend,
+	) -> Result<&MultilinearQuery<P, Backend::Vec<P>>, binius_hal::Error> {
+		if let Some(index) = self
+			.queries
+			.iter()
+			.position(|(memo_eval_point, _)| memo_eval_point.as_slice() == eval_point)
+		{
+			let (_, query) = &self.queries[index];
+			return Ok(query);
+		}
+
+		let query = backend.multilinear_query(eval_point)?;
+		self.queries.push((eval_point.to_vec(), query));
+
+		let (_, query) = self.queries.last().expect("pushed query immediately above");
+		Ok(query)
+	}
+
+	/// Finds a `MultilinearQuery` corresponding to the given `eval_point`.
+	pub fn full_query_readonly(
+		&self,
+		eval_point: &[P::Scalar],
+	) -> Option<&MultilinearQuery<P, Backend::Vec<P>>> {
+		self.queries
+			.iter()
+			.find(|(memo_eval_point, _)| memo_eval_point.as_slice() == eval_point)
+			.map(|(_, query)| query)
+	}
+
+	#[instrument(skip_all, name = "Evalcheck::memoize_query_par", level = "debug")]
+	pub fn memoize_query_par<'b>(
+		&mut self,
+		eval_points: impl IntoIterator<Item = &'b [P::Scalar]>,
+		backend: &Backend,
+	) -> Result<(), binius_hal::Error> {
+		let deduplicated_eval_points = eval_points.into_iter().collect::<HashSet<_>>();
+
+		let new_queries = deduplicated_eval_points
+			.into_par_iter()
+			.filter(|ep| self.full_query_readonly(ep).is_none())
+			.map(|ep| {
+				backend
+					.multilinear_query::<P>(ep)
+					.map(|res| (ep.to_vec(), res))
+			})
+			.collect::<Result<Vec<_>, binius_hal::Error>>()?;
+
+		self.queries.extend(new_queries);
+
+		Ok(())
+	}
+}
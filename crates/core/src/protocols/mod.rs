@@ -34,6 +34,8 @@ pub mod fri;
 pub mod gkr_exp;
 pub mod gkr_gpa;
 pub mod greedy_evalcheck;
+pub mod memoized_queries;
+pub mod multiset_check;
 pub mod sumcheck;
 
 #[allow(dead_code)]
@@ -0,0 +1,29 @@
+// Copyright 2025 Irreducible Inc.
+
+use crate::{protocols::gkr_gpa, transcript};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("the claim must have a positive width")]
+	EmptyRows,
+	#[error("the witness does not match the claim")]
+	ProverClaimWitnessMismatch,
+	#[error("the rows of the tables do not form the same multiset")]
+	MultisetMismatch,
+	#[error("verification failure: {0}")]
+	Verification(#[from] VerificationError),
+	#[error("grand product error: {0}")]
+	GrandProduct(#[from] gkr_gpa::Error),
+	#[error("transcript error: {0}")]
+	Transcript(#[from] transcript::Error),
+	#[error("math error: {0}")]
+	Math(#[from] binius_math::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+	#[error("the grand products of the tables differ")]
+	ProductsMismatch,
+	#[error("the column evaluations are inconsistent with the grand product claims")]
+	IncorrectColumnEvals,
+}
@@ -0,0 +1,68 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A multiset check between the rows of two tables of committed columns.
+//!
+//! The protocol reduces the claim that the rows of two tables form the same multiset to
+//! evaluation claims on their columns, which the caller discharges with a polynomial commitment
+//! scheme. It is the argument behind the channels of [`crate::constraint_system`], exposed for
+//! protocols that are not expressed as a constraint system.
+//!
+//! Each row $(c_0, \ldots, c_{w-1})$ is fingerprinted as $\gamma + \sum_i \alpha^i c_i$ with
+//! verifier challenges $\alpha$ and $\gamma$, and the grand products of the fingerprints of the two
+//! tables are compared. The grand products are proven with [`crate::protocols::gkr_gpa`], which
+//! outputs evaluation claims on the fingerprint multilinears; the prover then sends the column
+//! evaluations at the same points, from which the verifier recomputes the fingerprint
+//! evaluations.
+//!
+//! The challenges are sampled from the transcript at the start of the protocol, so the columns must
+//! be committed to the transcript before.
+
+mod error;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use error::*;
+pub use prove::*;
+pub use verify::*;
+
+/// A claim that the rows of two tables of multilinears form the same multiset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisetClaim {
+	/// The number of columns of each table.
+	pub width: usize,
+	/// The number of variables of the columns of the left-hand table.
+	pub lhs_n_vars: usize,
+	/// The number of variables of the columns of the right-hand table.
+	pub rhs_n_vars: usize,
+}
+
+/// Evaluations of the columns of a table at a common point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableEvalClaim<F> {
+	pub eval_point: Vec<F>,
+	/// The evaluation of each column at `eval_point`.
+	pub evals: Vec<F>,
+}
+
+/// The evaluation claims that a multiset claim reduces to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisetCheckOutput<F> {
+	pub lhs: TableEvalClaim<F>,
+	pub rhs: TableEvalClaim<F>,
+}
+
+/// Returns the fingerprint of a row.
+fn fingerprint<F: binius_field::Field>(
+	row: impl IntoIterator<Item = F>,
+	mixing_challenge: F,
+	permutation_challenge: F,
+) -> F {
+	let (fingerprint, _) = row
+		.into_iter()
+		.fold((permutation_challenge, F::ONE), |(sum, mixing), value| {
+			(sum + mixing * value, mixing * mixing_challenge)
+		});
+	fingerprint
+}
@@ -0,0 +1,128 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{Field, PackedExtension, PackedField, TowerField, packed::pack_slice};
+use binius_hal::ComputationBackend;
+use binius_math::{
+	EvaluationDomainFactory, EvaluationOrder, MultilinearExtension, MultilinearQuery,
+};
+use binius_utils::bail;
+use tracing::instrument;
+
+use super::{Error, MultisetCheckOutput, MultisetClaim, TableEvalClaim, fingerprint};
+use crate::{
+	fiat_shamir::{CanSample, Challenger},
+	protocols::gkr_gpa::{self, GrandProductClaim, GrandProductWitness},
+	transcript::ProverTranscript,
+};
+
+/// Proves that the rows of two tables form the same multiset.
+///
+/// `lhs` and `rhs` are the columns of the two tables, which must have been committed to the
+/// transcript before this is called.
+///
+/// REQUIRES:
+/// * `lhs` and `rhs` both have `claim.width` columns
+/// * the columns of `lhs` have `claim.lhs_n_vars` variables, those of `rhs` `claim.rhs_n_vars`
+#[instrument(skip_all, name = "multiset_check::prove", level = "debug")]
+pub fn prove<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	claim: &MultisetClaim,
+	lhs: &[MultilinearExtension<P>],
+	rhs: &[MultilinearExtension<P>],
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<MultisetCheckOutput<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	if claim.width == 0 {
+		bail!(Error::EmptyRows);
+	}
+	for (columns, n_vars) in [(lhs, claim.lhs_n_vars), (rhs, claim.rhs_n_vars)] {
+		if columns.len() != claim.width || columns.iter().any(|column| column.n_vars() != n_vars) {
+			bail!(Error::ProverClaimWitnessMismatch);
+		}
+	}
+
+	let mixing_challenge = transcript.sample();
+	let permutation_challenge = transcript.sample();
+
+	let fingerprints =
+		[(lhs, claim.lhs_n_vars), (rhs, claim.rhs_n_vars)].map(|(columns, n_vars)| {
+			let columns = columns
+				.iter()
+				.map(|column| {
+					PackedField::iter_slice(column.evals())
+						.take(1 << n_vars)
+						.collect::<Vec<_>>()
+				})
+				.collect::<Vec<_>>();
+			(0..1 << n_vars)
+				.map(|i| {
+					fingerprint(
+						columns.iter().map(|column| column[i]),
+						mixing_challenge,
+						permutation_challenge,
+					)
+				})
+				.collect::<Vec<F>>()
+		});
+
+	let witnesses = fingerprints
+		.iter()
+		.zip([claim.lhs_n_vars, claim.rhs_n_vars])
+		.map(|(fingerprints, n_vars)| GrandProductWitness::new(n_vars, pack_slice(fingerprints)))
+		.collect::<Result<Vec<_>, _>>()?;
+	let products = witnesses
+		.iter()
+		.map(|witness| witness.grand_product_evaluation())
+		.collect::<Vec<_>>();
+	if products[0] != products[1] {
+		bail!(Error::MultisetMismatch);
+	}
+	transcript.message().write_scalar_slice(&products);
+
+	let gpa_claims = [claim.lhs_n_vars, claim.rhs_n_vars]
+		.into_iter()
+		.zip(&products)
+		.map(|(n_vars, &product)| GrandProductClaim { n_vars, product })
+		.collect::<Vec<_>>();
+	let gkr_gpa::GrandProductBatchProveOutput { final_layer_claims } =
+		gkr_gpa::batch_prove::<F, P, FDomain, _, _>(
+			evaluation_order,
+			witnesses,
+			&gpa_claims,
+			evaluation_domain_factory,
+			transcript,
+			backend,
+		)?;
+
+	let [lhs_claim, rhs_claim] = [lhs, rhs]
+		.into_iter()
+		.zip(final_layer_claims)
+		.map(|(columns, layer_claim)| {
+			let query = MultilinearQuery::<F>::expand(&layer_claim.eval_point);
+			let evals = columns
+				.iter()
+				.map(|column| column.evaluate(&query))
+				.collect::<Result<Vec<F>, _>>()?;
+			transcript.message().write_scalar_slice(&evals);
+			Ok(TableEvalClaim {
+				eval_point: layer_claim.eval_point,
+				evals,
+			})
+		})
+		.collect::<Result<Vec<_>, Error>>()?
+		.try_into()
+		.expect("there are two grand product claims");
+
+	Ok(MultisetCheckOutput {
+		lhs: lhs_claim,
+		rhs: rhs_claim,
+	})
+}
@@ -0,0 +1,130 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{
+	BinaryField32b, BinaryField128b, Field, PackedBinaryField2x128b, packed::pack_slice,
+};
+use binius_hash::groestl::Groestl256;
+use binius_math::{
+	EvaluationOrder, IsomorphicEvaluationDomainFactory, MultilinearExtension, MultilinearQuery,
+};
+use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+use super::{Error, MultisetClaim, VerificationError, prove, verify};
+use crate::{
+	fiat_shamir::HasherChallenger,
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+type F = BinaryField128b;
+type P = PackedBinaryField2x128b;
+type FDomain = BinaryField32b;
+
+const N_VARS: usize = 5;
+const WIDTH: usize = 3;
+
+fn random_rows(rng: &mut StdRng) -> Vec<[F; WIDTH]> {
+	repeat_with(|| [(); WIDTH].map(|_| F::random(&mut *rng)))
+		.take(1 << N_VARS)
+		.collect()
+}
+
+fn columns(rows: &[[F; WIDTH]]) -> Vec<MultilinearExtension<P>> {
+	(0..WIDTH)
+		.map(|i| {
+			let column = rows.iter().map(|row| row[i]).collect::<Vec<_>>();
+			MultilinearExtension::from_values(pack_slice(&column)).unwrap()
+		})
+		.collect()
+}
+
+fn claim() -> MultisetClaim {
+	MultisetClaim {
+		width: WIDTH,
+		lhs_n_vars: N_VARS,
+		rhs_n_vars: N_VARS,
+	}
+}
+
+#[test]
+fn test_prove_verify_permuted_table() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let lhs_rows = random_rows(&mut rng);
+	let mut rhs_rows = lhs_rows.clone();
+	rhs_rows.shuffle(&mut rng);
+	let (lhs, rhs) = (columns(&lhs_rows), columns(&rhs_rows));
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let prove_output = prove::<F, P, FDomain, _, _>(
+		EvaluationOrder::HighToLow,
+		&claim(),
+		&lhs,
+		&rhs,
+		IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+		&mut prover_transcript,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+
+	let mut verifier_transcript = prover_transcript.into_verifier().unwrap();
+	let verify_output =
+		verify::<F, _>(EvaluationOrder::HighToLow, &claim(), &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+	assert_eq!(verify_output, prove_output);
+
+	for (columns, table_claim) in [(&lhs, &verify_output.lhs), (&rhs, &verify_output.rhs)] {
+		let query = MultilinearQuery::<F>::expand(&table_claim.eval_point);
+		for (column, &eval) in columns.iter().zip(&table_claim.evals) {
+			assert_eq!(column.evaluate(&query).unwrap(), eval);
+		}
+	}
+}
+
+#[test]
+fn test_prove_rejects_different_tables() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let lhs_rows = random_rows(&mut rng);
+	let mut rhs_rows = lhs_rows.clone();
+	rhs_rows.reverse();
+	rhs_rows[0][1] += F::ONE;
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let result = prove::<F, P, FDomain, _, _>(
+		EvaluationOrder::HighToLow,
+		&claim(),
+		&columns(&lhs_rows),
+		&columns(&rhs_rows),
+		IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+		&mut prover_transcript,
+		&binius_hal::make_portable_backend(),
+	);
+	assert!(matches!(result, Err(Error::MultisetMismatch)));
+}
+
+#[test]
+fn test_verify_rejects_tampered_column_evals() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let lhs_rows = random_rows(&mut rng);
+	let mut rhs_rows = lhs_rows.clone();
+	rhs_rows.shuffle(&mut rng);
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	prove::<F, P, FDomain, _, _>(
+		EvaluationOrder::HighToLow,
+		&claim(),
+		&columns(&lhs_rows),
+		&columns(&rhs_rows),
+		IsomorphicEvaluationDomainFactory::<FDomain>::default(),
+		&mut prover_transcript,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+
+	// Flip a bit of the last column evaluation of the right-hand table, at the end of the proof.
+	let mut proof = prover_transcript.finalize().unwrap();
+	*proof.last_mut().unwrap() ^= 1;
+	let mut verifier_transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+	let result = verify::<F, _>(EvaluationOrder::HighToLow, &claim(), &mut verifier_transcript);
+	assert!(matches!(result, Err(Error::Verification(VerificationError::IncorrectColumnEvals))));
+}
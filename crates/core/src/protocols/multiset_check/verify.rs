@@ -0,0 +1,69 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::TowerField;
+use binius_math::EvaluationOrder;
+use binius_utils::bail;
+use tracing::instrument;
+
+use super::{
+	Error, MultisetCheckOutput, MultisetClaim, TableEvalClaim, VerificationError, fingerprint,
+};
+use crate::{
+	fiat_shamir::{CanSample, Challenger},
+	protocols::gkr_gpa::{self, GrandProductClaim},
+	transcript::VerifierTranscript,
+};
+
+/// Verifies that the rows of two tables form the same multiset, reducing the claim to evaluation
+/// claims on the columns of the tables.
+#[instrument(skip_all, name = "multiset_check::verify", level = "debug")]
+pub fn verify<F, Challenger_>(
+	evaluation_order: EvaluationOrder,
+	claim: &MultisetClaim,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<MultisetCheckOutput<F>, Error>
+where
+	F: TowerField,
+	Challenger_: Challenger,
+{
+	if claim.width == 0 {
+		bail!(Error::EmptyRows);
+	}
+
+	let mixing_challenge = transcript.sample();
+	let permutation_challenge = transcript.sample();
+
+	let products = transcript.message().read_scalar_slice::<F>(2)?;
+	if products[0] != products[1] {
+		bail!(VerificationError::ProductsMismatch);
+	}
+
+	let gpa_claims = [claim.lhs_n_vars, claim.rhs_n_vars]
+		.into_iter()
+		.zip(products)
+		.map(|(n_vars, product)| GrandProductClaim { n_vars, product });
+	let final_layer_claims = gkr_gpa::batch_verify(evaluation_order, gpa_claims, transcript)?;
+
+	let [lhs_claim, rhs_claim] = final_layer_claims
+		.into_iter()
+		.map(|layer_claim| {
+			let evals = transcript.message().read_scalar_slice::<F>(claim.width)?;
+			if fingerprint(evals.iter().copied(), mixing_challenge, permutation_challenge)
+				!= layer_claim.eval
+			{
+				bail!(VerificationError::IncorrectColumnEvals);
+			}
+			Ok(TableEvalClaim {
+				eval_point: layer_claim.eval_point,
+				evals,
+			})
+		})
+		.collect::<Result<Vec<_>, Error>>()?
+		.try_into()
+		.expect("there are two grand product claims");
+
+	Ok(MultisetCheckOutput {
+		lhs: lhs_claim,
+		rhs: rhs_claim,
+	})
+}
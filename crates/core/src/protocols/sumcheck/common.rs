@@ -6,7 +6,10 @@ use binius_field::{
 	ExtensionField, Field, PackedField,
 	util::{inner_product_unchecked, powers},
 };
-use binius_math::{CompositionPoly, EvaluationDomainFactory, InterpolationDomain, MultilinearPoly};
+use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_math::{
+	CompositionPoly, EvaluationDomainFactory, EvaluationOrder, InterpolationDomain, MultilinearPoly,
+};
 use binius_utils::bail;
 use getset::{CopyGetters, Getters};
 
@@ -52,18 +55,15 @@ where
 	///
 	/// * [`Error::InvalidComposition`] if any of the composition polynomials in the composite
 	///   claims vector do not have their number of variables equal to `n_multilinears`
+	/// * [`Error::IncorrectCompositionDegree`] if any of the composition polynomials declares a
+	///   degree other than the degree of its expression
 	pub fn new(
 		n_vars: usize,
 		n_multilinears: usize,
 		composite_sums: Vec<CompositeSumClaim<F, Composition>>,
 	) -> Result<Self, Error> {
 		for CompositeSumClaim { composition, .. } in &composite_sums {
-			if composition.n_vars() != n_multilinears {
-				bail!(Error::InvalidComposition {
-					actual: composition.n_vars(),
-					expected: n_multilinears,
-				});
-			}
+			validate_composition(composition, n_multilinears)?;
 		}
 		Ok(Self {
 			n_vars,
@@ -86,10 +86,40 @@ where
 	}
 }
 
+/// Checks that a composition polynomial is over `n_multilinears` variables and declares the degree
+/// of its arithmetic expression.
+///
+/// Round messages are sized by the declared degree, so an inconsistent declaration is rejected
+/// when claims and provers are constructed rather than surfacing as a verification failure.
+pub fn validate_composition<P, Composition>(
+	composition: &Composition,
+	n_multilinears: usize,
+) -> Result<(), Error>
+where
+	P: PackedField,
+	Composition: CompositionPoly<P> + ?Sized,
+{
+	if composition.n_vars() != n_multilinears {
+		bail!(Error::InvalidComposition {
+			actual: composition.n_vars(),
+			expected: n_multilinears,
+		});
+	}
+
+	let expected = composition.expression().degree();
+	if composition.degree() != expected {
+		bail!(Error::IncorrectCompositionDegree {
+			declared: composition.degree(),
+			expected,
+		});
+	}
+	Ok(())
+}
+
 /// A univariate polynomial in monomial basis.
 ///
 /// The coefficient at position `i` in the inner vector corresponds to the term $X^i$.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 pub struct RoundCoeffs<F: Field>(pub Vec<F>);
 
 impl<F: Field> RoundCoeffs<F> {
@@ -149,7 +179,7 @@ impl<F: Field> MulAssign<F> for RoundCoeffs<F> {
 /// Since the verifier knows the claimed sum of the polynomial values at the points 0 and 1, the
 /// high-degree term coefficient can be easily recovered. Truncating the coefficient off saves a
 /// small amount of proof data.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 pub struct RoundProof<F: Field>(pub RoundCoeffs<F>);
 
 impl<F: Field> RoundProof<F> {
@@ -193,7 +223,7 @@ impl<F: Field> RoundProof<F> {
 }
 
 /// A sumcheck batch proof.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 pub struct Proof<F: Field> {
 	/// The round proofs for each round.
 	pub rounds: Vec<RoundProof<F>>,
@@ -229,6 +259,43 @@ impl<F: Field> BatchSumcheckOutput<F> {
 	}
 }
 
+/// Output of a batched sumcheck reduction over claims with individual evaluation orders.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BatchSumcheckMixedOutput<F: Field> {
+	/// Sumcheck challenges in the order they were sampled, one per round.
+	pub challenges: Vec<F>,
+	/// Evaluation point of each claim, in descending `n_vars` order. See [`claim_eval_point`].
+	pub eval_points: Vec<Vec<F>>,
+	/// Values of each multilinear (per claim, in descending `n_vars` order) at the evaluation
+	/// point of its claim.
+	pub multilinear_evals: Vec<Vec<F>>,
+}
+
+/// Returns the evaluation point that a batched sumcheck reduces a claim to.
+///
+/// A claim over `n_vars` variables joins the batch in the last `n_vars` rounds, so it is bound to
+/// a suffix of the round challenges. With [`EvaluationOrder::LowToHigh`] the suffix assigns the
+/// variables in increasing index order, with [`EvaluationOrder::HighToLow`] in decreasing order.
+///
+/// ## Preconditions
+///
+/// * `n_vars` is at most the number of round challenges
+pub fn claim_eval_point<F: Field>(
+	evaluation_order: EvaluationOrder,
+	round_challenges: &[F],
+	n_vars: usize,
+) -> Vec<F> {
+	assert!(
+		n_vars <= round_challenges.len(),
+		"precondition: n_vars must not exceed the number of rounds"
+	);
+	let suffix = &round_challenges[round_challenges.len() - n_vars..];
+	match evaluation_order {
+		EvaluationOrder::LowToHigh => suffix.to_vec(),
+		EvaluationOrder::HighToLow => suffix.iter().rev().copied().collect(),
+	}
+}
+
 /// Constructs a switchover function thaw returns the round number where folded multilinear is at
 /// least 2^k times smaller (in bytes) than the original, or 1 when not applicable.
 pub fn standard_switchover_heuristic(k: isize) -> impl Fn(usize) -> usize + Copy {
@@ -339,6 +406,7 @@ pub fn get_nontrivial_evaluation_points<F: Field>(
 #[cfg(test)]
 mod tests {
 	use binius_field::BinaryField64b;
+	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 
 	use super::*;
 
@@ -357,4 +425,23 @@ mod tests {
 		let truncated = coeffs.truncate();
 		assert!(truncated.0.0.is_empty());
 	}
+
+	#[test]
+	fn test_proof_serialization_round_trip() {
+		let proof = Proof {
+			rounds: vec![
+				RoundCoeffs(vec![F::from(1), F::from(2)]).truncate(),
+				RoundCoeffs(vec![F::from(3), F::from(4), F::from(5)]).truncate(),
+			],
+			multilinear_evals: vec![vec![F::from(6)], vec![F::from(7), F::from(8)]],
+		};
+
+		let mut buf = Vec::new();
+		proof
+			.serialize(&mut buf, SerializationMode::CanonicalTower)
+			.unwrap();
+		let deserialized =
+			Proof::<F>::deserialize(buf.as_slice(), SerializationMode::CanonicalTower).unwrap();
+		assert_eq!(deserialized, proof);
+	}
 }
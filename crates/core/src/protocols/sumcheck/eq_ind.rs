@@ -7,7 +7,7 @@ use getset::CopyGetters;
 use itertools::{Either, izip};
 
 use super::{
-	common::{CompositeSumClaim, SumcheckClaim},
+	common::{CompositeSumClaim, SumcheckClaim, validate_composition},
 	error::{Error, VerificationError},
 };
 use crate::protocols::sumcheck::BatchSumcheckOutput;
@@ -36,18 +36,15 @@ where
 	///
 	/// * [`Error::InvalidComposition`] if any of the composition polynomials in the composite
 	///   claims vector do not have their number of variables equal to `n_multilinears`
+	/// * [`Error::IncorrectCompositionDegree`] if any of the composition polynomials declares a
+	///   degree other than the degree of its expression
 	pub fn new(
 		n_vars: usize,
 		n_multilinears: usize,
 		eq_ind_composite_sums: Vec<CompositeSumClaim<F, Composition>>,
 	) -> Result<Self, Error> {
 		for CompositeSumClaim { composition, .. } in &eq_ind_composite_sums {
-			if composition.n_vars() != n_multilinears {
-				bail!(Error::InvalidComposition {
-					actual: composition.n_vars(),
-					expected: n_multilinears,
-				});
-			}
+			validate_composition(composition, n_multilinears)?;
 		}
 		Ok(Self {
 			n_vars,
@@ -374,7 +371,7 @@ mod tests {
 		let _sumcheck_proof_output =
 			sumcheck::prove::batch_prove(vec![prover], &mut transcript).unwrap();
 
-		let mut verifier_transcript = transcript.into_verifier();
+		let mut verifier_transcript = transcript.into_verifier().unwrap();
 
 		let eq_ind_sumcheck_verifier_claim =
 			EqIndSumcheckClaim::new(n_vars, 2, vec![composite_claim]).unwrap();
@@ -566,7 +563,7 @@ mod tests {
 			multilinear_evals: multilinear_evals_2,
 		} = sumcheck::batch_prove(vec![optimized_prover], &mut prove_transcript_2).unwrap();
 
-		assert_eq!(prove_transcript_1.finalize(), prove_transcript_2.finalize());
+		assert_eq!(prove_transcript_1.finalize().unwrap(), prove_transcript_2.finalize().unwrap());
 		assert_eq!(multilinear_evals_1, multilinear_evals_2);
 		assert_eq!(sumcheck_challenges_1, sumcheck_challenges_2);
 	}
@@ -647,7 +644,7 @@ mod tests {
 		.unwrap();
 
 		let prover_sample = CanSample::<FE>::sample(&mut prove_transcript);
-		let mut verify_transcript = prove_transcript.into_verifier();
+		let mut verify_transcript = prove_transcript.into_verifier().unwrap();
 		let _: Vec<BinaryField128b> = verify_transcript.sample_vec(n_vars);
 
 		let regular_sumcheck_claims =
@@ -11,10 +11,16 @@ pub enum Error {
 		"composition polynomial has an incorrect number of variables; expected {expected}, got {actual}"
 	)]
 	InvalidComposition { actual: usize, expected: usize },
+	#[error(
+		"composition polynomial declares degree {declared}, but its expression has degree {expected}"
+	)]
+	IncorrectCompositionDegree { declared: usize, expected: usize },
 	#[error("claims must be sorted by number of variables")]
 	ClaimsOutOfOrder,
 	#[error("claims have inconsistent evaluation orders")]
 	InconsistentEvaluationOrder,
+	#[error("the number of evaluation orders must equal the number of claims, expected {expected}")]
+	IncorrectNumberOfEvaluationOrders { expected: usize },
 	#[error("failed to downcast a composition expression into a subfield expression")]
 	CircuitFieldDowncastFailed,
 	#[error("expected a call to try_finish_claim")]
@@ -105,6 +111,8 @@ pub enum Error {
 	LagrangeDomainTooSmall,
 	#[error("adding together Lagrange basis evaluations over domains of different sizes")]
 	LagrangeRoundEvalsSizeMismatch,
+	#[error("sumcheck mask must have {expected} variables")]
+	IncorrectMaskNumberOfVariables { expected: usize },
 	#[error("oracle error: {0}")]
 	Oracle(#[from] OracleError),
 	#[error("witness error: {0}")]
@@ -119,6 +127,8 @@ pub enum Error {
 	MathError(#[from] binius_math::Error),
 	#[error("HAL error: {0}")]
 	HalError(#[from] binius_hal::Error),
+	#[error("distributed proving error: {0}")]
+	Distributed(#[from] crate::distributed::Error),
 	#[error("Transcript error: {0}")]
 	TranscriptError(#[from] crate::transcript::Error),
 }
@@ -151,4 +161,6 @@ pub enum VerificationError {
 	IncorrectLagrangeRoundEvalsLen,
 	#[error("claimed multilinear evaluations do not match univariate round at challenge point")]
 	ClaimedSumRoundEvalsMismatch,
+	#[error("univariate skip round evaluations over the skipped subspace do not sum to the claim")]
+	IncorrectUnivariateSkipSum,
 }
@@ -14,18 +14,28 @@ mod oracles;
 pub mod prove;
 #[cfg(test)]
 mod tests;
+pub mod univariate_skip;
 pub mod verify_sumcheck;
 pub mod verify_zerocheck;
 pub mod zerocheck;
+pub mod zk;
 
 pub use common::{
-	BatchSumcheckOutput, CompositeSumClaim, RoundCoeffs, RoundProof, SumcheckClaim,
-	equal_n_vars_check, immediate_switchover_heuristic, standard_switchover_heuristic,
+	BatchSumcheckMixedOutput, BatchSumcheckOutput, CompositeSumClaim, RoundCoeffs, RoundProof,
+	SumcheckClaim, claim_eval_point, equal_n_vars_check, immediate_switchover_heuristic,
+	standard_switchover_heuristic, validate_composition,
 };
 pub use eq_ind::EqIndSumcheckClaim;
 pub use error::*;
 pub use oracles::*;
-pub use prove::{batch_prove, batch_prove_zerocheck};
-pub use verify_sumcheck::batch_verify;
+pub use prove::{
+	batch_prove, batch_prove_mixed, batch_prove_univariate_skip, batch_prove_zerocheck,
+	batch_prove_zk,
+};
+pub use univariate_skip::{
+	BatchUnivariateSkipOutput, batch_verify as batch_verify_univariate_skip,
+};
+pub use verify_sumcheck::{batch_verify, batch_verify_mixed, batch_verify_zk};
 pub use verify_zerocheck::batch_verify as batch_verify_zerocheck;
 pub use zerocheck::{BatchZerocheckOutput, ZerocheckClaim};
+pub use zk::{BatchSumcheckZkOutput, SumcheckMask};
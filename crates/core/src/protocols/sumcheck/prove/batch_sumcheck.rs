@@ -9,8 +9,9 @@ use tracing::instrument;
 use crate::{
 	fiat_shamir::{CanSample, Challenger},
 	protocols::sumcheck::{
-		common::{BatchSumcheckOutput, RoundCoeffs},
+		common::{BatchSumcheckMixedOutput, BatchSumcheckOutput, RoundCoeffs, claim_eval_point},
 		error::Error,
+		zk::{BatchSumcheckZkOutput, MaskRoundState, SumcheckMask},
 	},
 	transcript::ProverTranscript,
 };
@@ -98,7 +99,7 @@ impl<F: Field, Prover: SumcheckProver<F> + ?Sized> SumcheckProver<F> for Box<Pro
 /// provided to [`crate::protocols::sumcheck::batch_verify`] during proof verification.
 #[instrument(skip_all, name = "sumcheck::batch_prove")]
 pub fn batch_prove<F, Prover, Challenger_>(
-	mut provers: Vec<Prover>,
+	provers: Vec<Prover>,
 	transcript: &mut ProverTranscript<Challenger_>,
 ) -> Result<BatchSumcheckOutput<F>, Error>
 where
@@ -106,14 +107,89 @@ where
 	Prover: SumcheckProver<F>,
 	Challenger_: Challenger,
 {
-	let Some(first_prover) = provers.first() else {
-		return Ok(BatchSumcheckOutput {
-			challenges: Vec::new(),
-			multilinear_evals: Vec::new(),
-		});
-	};
+	let (output, _) = batch_prove_with_mask(provers, None, transcript)?;
+	Ok(output)
+}
+
+/// Prove a batched sumcheck protocol execution in zero-knowledge.
+///
+/// The round polynomials are masked with the random polynomial `mask`, which must be defined over
+/// as many variables as the largest prover. The caller must commit to `mask` before calling this
+/// function and later prove the returned mask evaluation against that commitment. See
+/// [`crate::protocols::sumcheck::zk`] for details.
+///
+/// The provers must be in the same order as the corresponding claims provided to
+/// [`crate::protocols::sumcheck::batch_verify_zk`] during proof verification.
+#[instrument(skip_all, name = "sumcheck::batch_prove_zk")]
+pub fn batch_prove_zk<F, Prover, Challenger_>(
+	provers: Vec<Prover>,
+	mask: &SumcheckMask<F>,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchSumcheckZkOutput<F>, Error>
+where
+	F: TowerField,
+	Prover: SumcheckProver<F>,
+	Challenger_: Challenger,
+{
+	let (output, mask_eval) = batch_prove_with_mask(provers, Some(mask), transcript)?;
+	Ok(BatchSumcheckZkOutput {
+		output,
+		mask_eval: mask_eval.expect("mask_eval is Some when mask is Some"),
+	})
+}
 
-	let evaluation_order = first_prover.evaluation_order();
+/// Prove a batched sumcheck protocol execution where the provers may have different evaluation
+/// orders.
+///
+/// This produces the same transcript as [`batch_prove`]; the only difference is that each prover
+/// is reduced to an evaluation point in its own [`SumcheckProver::evaluation_order`], so that
+/// protocols binding variables in different orders can share one batch. See
+/// [`claim_eval_point`] for how the points are derived.
+///
+/// The provers must be in the same order as the corresponding claims provided to
+/// [`crate::protocols::sumcheck::batch_verify_mixed`] during proof verification.
+#[instrument(skip_all, name = "sumcheck::batch_prove_mixed")]
+pub fn batch_prove_mixed<F, Prover, Challenger_>(
+	provers: Vec<Prover>,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchSumcheckMixedOutput<F>, Error>
+where
+	F: TowerField,
+	Prover: SumcheckProver<F>,
+	Challenger_: Challenger,
+{
+	let claim_descs = provers
+		.iter()
+		.map(|prover| (prover.evaluation_order(), prover.n_vars()))
+		.collect::<Vec<_>>();
+
+	let (challenges, multilinear_evals, _) = batch_prove_rounds(provers, None, transcript)?;
+
+	let eval_points = claim_descs
+		.into_iter()
+		.map(|(evaluation_order, n_vars)| claim_eval_point(evaluation_order, &challenges, n_vars))
+		.collect();
+
+	Ok(BatchSumcheckMixedOutput {
+		challenges,
+		eval_points,
+		multilinear_evals,
+	})
+}
+
+fn batch_prove_with_mask<F, Prover, Challenger_>(
+	provers: Vec<Prover>,
+	mask: Option<&SumcheckMask<F>>,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<(BatchSumcheckOutput<F>, Option<F>), Error>
+where
+	F: TowerField,
+	Prover: SumcheckProver<F>,
+	Challenger_: Challenger,
+{
+	let evaluation_order = provers
+		.first()
+		.map_or(EvaluationOrder::LowToHigh, |prover| prover.evaluation_order());
 
 	if provers
 		.iter()
@@ -122,6 +198,33 @@ where
 		bail!(Error::InconsistentEvaluationOrder);
 	}
 
+	let (mut challenges, multilinear_evals, mask_eval) =
+		batch_prove_rounds(provers, mask, transcript)?;
+
+	if EvaluationOrder::HighToLow == evaluation_order {
+		challenges.reverse();
+	}
+
+	let output = BatchSumcheckOutput {
+		challenges,
+		multilinear_evals,
+	};
+
+	Ok((output, mask_eval))
+}
+
+/// Runs the batched sumcheck rounds, returning the challenges in the order they were sampled,
+/// the multilinear evaluations of each prover, and the mask evaluation if a mask is given.
+fn batch_prove_rounds<F, Prover, Challenger_>(
+	mut provers: Vec<Prover>,
+	mask: Option<&SumcheckMask<F>>,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<(Vec<F>, Vec<Vec<F>>, Option<F>), Error>
+where
+	F: TowerField,
+	Prover: SumcheckProver<F>,
+	Challenger_: Challenger,
+{
 	// Check that the provers are in non-ascending order by n_vars
 	if !is_sorted_ascending(provers.iter().map(|prover| prover.n_vars()).rev()) {
 		bail!(Error::ClaimsOutOfOrder);
@@ -133,6 +236,20 @@ where
 		.max()
 		.unwrap_or(0);
 
+	// The mask sum is sent before the mask coefficient is sampled, so that the prover is bound to
+	// the mask before it learns how it is mixed in.
+	let mut mask_state = match mask {
+		Some(mask) => {
+			if mask.n_vars() != n_rounds {
+				bail!(Error::IncorrectMaskNumberOfVariables { expected: n_rounds });
+			}
+			transcript.message().write_scalar(mask.sum());
+			let mask_coeff: F = transcript.sample();
+			Some((MaskRoundState::new(mask), mask_coeff))
+		}
+		None => None,
+	};
+
 	let mut batch_coeffs = Vec::with_capacity(provers.len());
 	let mut challenges = Vec::with_capacity(n_rounds);
 	for round_no in 0..n_rounds {
@@ -154,6 +271,9 @@ where
 			let prover_coeffs = prover.execute(batch_coeff)?;
 			round_coeffs += &(prover_coeffs * batch_coeff);
 		}
+		if let Some((mask_state, mask_coeff)) = &mask_state {
+			round_coeffs += &(mask_state.round_coeffs() * *mask_coeff);
+		}
 
 		let round_proof = round_coeffs.truncate();
 		transcript
@@ -166,6 +286,9 @@ where
 		for prover in &mut provers[..batch_coeffs.len()] {
 			prover.fold(challenge)?;
 		}
+		if let Some((mask_state, _)) = &mut mask_state {
+			mask_state.fold(challenge);
+		}
 	}
 
 	// sample next_batch_coeffs for 0-variate (ie. constant) provers to match with verify
@@ -184,14 +307,10 @@ where
 		writer.write_scalar_slice(multilinear_evals);
 	}
 
-	if EvaluationOrder::HighToLow == evaluation_order {
-		challenges.reverse();
+	let mask_eval = mask_state.map(|(mask_state, _)| mask_state.finish());
+	if let Some(mask_eval) = mask_eval {
+		writer.write_scalar(mask_eval);
 	}
 
-	let output = BatchSumcheckOutput {
-		challenges,
-		multilinear_evals,
-	};
-
-	Ok(output)
+	Ok((challenges, multilinear_evals, mask_eval))
 }
@@ -112,7 +112,7 @@ impl<'a, P: PackedField, Prover: ZerocheckProver<'a, P> + ?Sized> ZerocheckProve
 	}
 }
 
-fn univariatizing_reduction_prover<F, FDomain, P>(
+pub(super) fn univariatizing_reduction_prover<F, FDomain, P>(
 	mut projected_multilinears: Vec<Arc<dyn MultilinearPoly<P> + Send + Sync>>,
 	skip_rounds: usize,
 	univariatized_multilinear_evals: Vec<Vec<F>>,
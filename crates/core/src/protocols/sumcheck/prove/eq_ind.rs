@@ -24,7 +24,7 @@ use crate::{
 		CompositeSumClaim, Error,
 		common::{
 			RoundCoeffs, equal_n_vars_check, get_nontrivial_evaluation_points,
-			interpolation_domains_for_composition_degrees,
+			interpolation_domains_for_composition_degrees, validate_composition,
 		},
 		prove::{ProverState, SumcheckInterpolator, SumcheckProver, common::fold_partial_eq_ind},
 	},
@@ -304,12 +304,7 @@ where
 		}
 
 		for claim in &composite_claims {
-			if claim.composition.n_vars() != multilinears.len() {
-				bail!(Error::InvalidComposition {
-					expected: multilinears.len(),
-					actual: claim.composition.n_vars(),
-				});
-			}
+			validate_composition(&claim.composition, multilinears.len())?;
 		}
 
 		let (compositions, claimed_sums) = determine_const_eval_suffixes(
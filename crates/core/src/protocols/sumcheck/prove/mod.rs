@@ -10,9 +10,10 @@ pub mod oracles;
 pub mod prover_state;
 pub mod regular_sumcheck;
 pub mod univariate;
+pub mod univariate_skip;
 pub mod zerocheck;
 
-pub use batch_sumcheck::{SumcheckProver, batch_prove};
+pub use batch_sumcheck::{SumcheckProver, batch_prove, batch_prove_mixed, batch_prove_zk};
 pub use batch_zerocheck::{ZerocheckProver, batch_prove as batch_prove_zerocheck};
 pub use logging::PIOPCompilerFoldData;
 pub use oracles::{
@@ -20,4 +21,5 @@ pub use oracles::{
 };
 pub use prover_state::{ProverState, SumcheckInterpolator};
 pub use regular_sumcheck::RegularSumcheckProver;
+pub use univariate_skip::{UnivariateSkipProver, batch_prove as batch_prove_univariate_skip};
 pub use zerocheck::ZerocheckProverImpl;
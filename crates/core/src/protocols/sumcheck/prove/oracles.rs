@@ -14,11 +14,13 @@ use crate::{
 	polynomial::ArithCircuitPoly,
 	protocols::{
 		evalcheck::{EvalPoint, subclaims::MemoizedData},
+		memoized_queries::MemoizedQueries,
 		sumcheck::{
 			CompositeSumClaim, Error, OracleClaimMeta, constraint_set_mlecheck_claim,
 			constraint_set_sumcheck_claim,
 		},
 	},
+	transparent::eq_ind::EqIndPartialEval,
 	witness::{IndexEntry, MultilinearExtensionIndex, MultilinearWitness},
 };
 
@@ -54,11 +56,16 @@ pub type OracleMLECheckProver<'a, FDomain, P, Backend> = EqIndSumcheckProver<
 
 /// Construct zerocheck prover from the constraint set. Fails when constraint set contains regular
 /// sumchecks.
+///
+/// The tensor expansion of `zerocheck_challenges` is taken from `memoized_queries`, so that
+/// provers sharing the challenges and later protocols evaluating the equality indicator at them
+/// don't expand them again.
 pub fn constraint_set_zerocheck_prover<'a, P, F, FBase, FDomain, DomainFactory, Backend>(
 	constraints: Vec<Constraint<P::Scalar>>,
 	multilinears: Vec<MultilinearWitness<'a, P>>,
 	domain_factory: DomainFactory,
 	zerocheck_challenges: &[F],
+	memoized_queries: &mut MemoizedQueries<P, Backend>,
 	backend: &'a Backend,
 ) -> Result<OracleZerocheckProver<'a, P, FBase, FDomain, DomainFactory, Backend>, Error>
 where
@@ -95,13 +102,18 @@ where
 		}
 	}
 
+	let partial_eq_ind_evals = EqIndPartialEval::new(zerocheck_challenges)
+		.tensor_expansion(memoized_queries, backend)?
+		.to_vec();
+
 	let prover = OracleZerocheckProver::<_, _, FDomain, _, _>::new(
 		multilinears,
 		zeros,
 		zerocheck_challenges,
 		domain_factory,
 		backend,
-	)?;
+	)?
+	.with_partial_eq_ind_evals(Backend::to_hal_slice(partial_eq_ind_evals));
 
 	Ok(prover)
 }
@@ -219,9 +231,8 @@ where
 		EvaluationOrder::HighToLow => &eq_ind_challenges[..n_vars.saturating_sub(1)],
 	};
 
-	let eq_ind_partial_evals = memoized_data
-		.full_query(eq_ind_partial, backend)?
-		.expansion()
+	let eq_ind_partial_evals = EqIndPartialEval::new(eq_ind_partial)
+		.tensor_expansion(memoized_data.queries_mut(), backend)?
 		.to_vec();
 
 	let prover = EqIndSumcheckProverBuilder::with_switchover(multilinears, switchover_fn, backend)?
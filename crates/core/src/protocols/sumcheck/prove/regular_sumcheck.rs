@@ -19,7 +19,7 @@ use crate::{
 	protocols::sumcheck::{
 		common::{
 			CompositeSumClaim, RoundCoeffs, equal_n_vars_check, get_nontrivial_evaluation_points,
-			interpolation_domains_for_composition_degrees,
+			interpolation_domains_for_composition_degrees, validate_composition,
 		},
 		error::Error,
 		prove::{ProverState, SumcheckInterpolator, SumcheckProver},
@@ -117,12 +117,7 @@ where
 		}
 
 		for claim in &composite_claims {
-			if claim.composition.n_vars() != multilinears.len() {
-				bail!(Error::InvalidComposition {
-					actual: claim.composition.n_vars(),
-					expected: multilinears.len(),
-				});
-			}
+			validate_composition(&claim.composition, multilinears.len())?;
 		}
 
 		let claimed_sums = composite_claims
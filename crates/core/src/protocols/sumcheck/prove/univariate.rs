@@ -3,7 +3,8 @@
 use std::{collections::HashMap, iter::repeat_n};
 
 use binius_field::{
-	BinaryField, ExtensionField, Field, PackedExtension, PackedField, PackedSubfield, TowerField,
+	BinaryField, BinaryField1b, ExtensionField, Field, PackedExtension, PackedField,
+	PackedSubfield, TowerField,
 	packed::{get_packed_slice, get_packed_slice_checked},
 	recast_packed_mut,
 	util::inner_product_unchecked,
@@ -240,6 +241,45 @@ pub fn zerocheck_univariate_evals<F, FDomain, FBase, P, Composition, M, Backend>
 	max_domain_size: usize,
 	backend: &Backend,
 ) -> Result<ZerocheckUnivariateEvalsOutput<F, P, Backend>, Error>
+where
+	FDomain: TowerField,
+	FBase: ExtensionField<FDomain>,
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FBase> + PackedExtension<FDomain>,
+	Composition: CompositionPoly<PackedSubfield<P, FBase>>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Backend: ComputationBackend,
+{
+	zerocheck_univariate_evals_with_eq_ind::<_, FDomain, FBase, _, _, _, _>(
+		multilinears,
+		compositions,
+		zerocheck_challenges,
+		None,
+		skip_rounds,
+		max_domain_size,
+		backend,
+	)
+}
+
+/// Same as [`zerocheck_univariate_evals`], with the tensor expansion of `zerocheck_challenges`
+/// optionally computed by the caller.
+pub(super) fn zerocheck_univariate_evals_with_eq_ind<
+	F,
+	FDomain,
+	FBase,
+	P,
+	Composition,
+	M,
+	Backend,
+>(
+	multilinears: &[M],
+	compositions: &[Composition],
+	zerocheck_challenges: &[F],
+	partial_eq_ind_evals: Option<Backend::Vec<P>>,
+	skip_rounds: usize,
+	max_domain_size: usize,
+	backend: &Backend,
+) -> Result<ZerocheckUnivariateEvalsOutput<F, P, Backend>, Error>
 where
 	FDomain: TowerField,
 	FBase: ExtensionField<FDomain>,
@@ -285,6 +325,23 @@ where
 		.expect("FDomain cardinality checked before")
 		.precompute_twiddles();
 
+	// Subcubes of 1-bit multilinears are extrapolated with table lookups instead of NTTs.
+	let boolean_multilinears = multilinears
+		.iter()
+		.map(|multilinear| {
+			multilinear.log_extension_degree() == <F as ExtensionField<BinaryField1b>>::LOG_DEGREE
+		})
+		.collect::<Vec<_>>();
+	let boolean_extrapolation = if boolean_multilinears.contains(&true) {
+		BooleanExtrapolation::<PackedSubfield<P, FBase>>::new(
+			&fdomain_ntt,
+			skip_rounds,
+			composition_max_degree.saturating_sub(1),
+		)?
+	} else {
+		None
+	};
+
 	// Smaller subcubes are batched together to reduce interpolation/evaluation overhead.
 	// REVIEW: make this a heuristic dependent on base field size and/or number of multilinears
 	//         to guarantee L1 cache (or accelerator scratchpad) non-eviction.
@@ -295,17 +352,22 @@ where
 	// where each tensor expansion element serves as a constant factor of the whole
 	// univariatized subcube.
 	// NB: expansion of the first `skip_rounds` variables is applied to the round evals sum
-	let dimensions_data = ExpandQueryData::new(zerocheck_challenges);
-	let expand_span = tracing::debug_span!(
-		"[task] Expand Query",
-		phase = "zerocheck",
-		perfetto_category = "task.main",
-		?dimensions_data,
-	)
-	.entered();
-	let partial_eq_ind_evals: <Backend as ComputationBackend>::Vec<P> =
-		backend.tensor_product_full_query(zerocheck_challenges)?;
-	drop(expand_span);
+	let partial_eq_ind_evals = if let Some(partial_eq_ind_evals) = partial_eq_ind_evals {
+		if partial_eq_ind_evals.len() != 1 << remaining_rounds.saturating_sub(P::LOG_WIDTH) {
+			bail!(Error::IncorrectEqIndPartialEvalsSize);
+		}
+		partial_eq_ind_evals
+	} else {
+		let dimensions_data = ExpandQueryData::new(zerocheck_challenges);
+		let _expand_span = tracing::debug_span!(
+			"[task] Expand Query",
+			phase = "zerocheck",
+			perfetto_category = "task.main",
+			?dimensions_data,
+		)
+		.entered();
+		backend.tensor_product_full_query(zerocheck_challenges)?
+	};
 
 	// Evaluate each composition on a minimal packed prefix corresponding to the degree
 	let pbase_prefix_lens = composition_degrees
@@ -361,8 +423,8 @@ where
 				} = &mut par_fold_states;
 
 				// Interpolate multilinear evals for each multilinear
-				for (multilinear, extrapolated_evals) in
-					izip!(multilinears, extrapolated_evals.iter_mut())
+				for (multilinear, extrapolated_evals, &is_boolean) in
+					izip!(multilinears, extrapolated_evals.iter_mut(), &boolean_multilinears)
 				{
 					// Sample evals subcube from a multilinear poly
 					multilinear.subcube_evals(
@@ -377,6 +439,13 @@ where
 					// 2^skip_rounds, exploiting the fact that extension field NTT is a strided
 					// base field NTT.
 					let evals_base = <P as PackedExtension<FBase>>::cast_bases_mut(evals);
+					if let Some(boolean_extrapolation) =
+						boolean_extrapolation.as_ref().filter(|_| is_boolean)
+					{
+						boolean_extrapolation.extrapolate(evals_base, extrapolated_evals);
+						continue;
+					}
+
 					let evals_domain = recast_packed_mut::<P, FBase, FDomain>(evals_base);
 					let extrapolated_evals_domain =
 						recast_packed_mut::<P, FBase, FDomain>(extrapolated_evals);
@@ -574,6 +643,41 @@ fn extrapolate_round_evals<F, FDomain, TA>(
 	skip_rounds: usize,
 	max_domain_size: usize,
 ) -> Result<Vec<Vec<F>>, Error>
+where
+	F: BinaryField + ExtensionField<FDomain>,
+	FDomain: BinaryField,
+	TA: TwiddleAccess<FDomain>,
+{
+	// Re-add zero evaluations at the beginning.
+	for round_evals in &mut round_evals {
+		round_evals.splice(0..0, repeat_n(F::ZERO, 1 << skip_rounds));
+	}
+
+	let mut round_evals = extrapolate_lagrange_evals(ntt, round_evals, max_domain_size)?;
+
+	for round_evals in &mut round_evals {
+		// Sanity check: first 1 << skip_rounds evals are still zeros.
+		debug_assert!(
+			round_evals[..1 << skip_rounds]
+				.iter()
+				.all(|&coeff| coeff == F::ZERO)
+		);
+
+		round_evals.drain(..1 << skip_rounds);
+	}
+
+	Ok(round_evals)
+}
+
+/// Extrapolates evaluations over a prefix of the NTT domain to its first `max_domain_size` points.
+///
+/// The vectors of `evals` must not be longer than `max_domain_size`, which in turn must fit into
+/// the domain of the `ntt`.
+pub(super) fn extrapolate_lagrange_evals<F, FDomain, TA>(
+	ntt: &SingleThreadedNTT<FDomain, TA>,
+	mut evals: Vec<Vec<F>>,
+	max_domain_size: usize,
+) -> Result<Vec<Vec<F>>, Error>
 where
 	F: BinaryField + ExtensionField<FDomain>,
 	FDomain: BinaryField,
@@ -597,50 +701,37 @@ where
 	// Cache OddInterpolate instances, which, albeit small in practice, take cubic time to create.
 	let mut odd_interpolates = HashMap::new();
 
-	for round_evals in &mut round_evals {
-		// Re-add zero evaluations at the beginning.
-		round_evals.splice(0..0, repeat_n(F::ZERO, 1 << skip_rounds));
-
-		let n = round_evals.len();
+	for evals in &mut evals {
+		let n = evals.len();
 
 		// Get OddInterpolate instance of required size.
 		let odd_interpolate = odd_interpolates.entry(n).or_insert_with(|| {
 			let ell = n.trailing_zeros() as usize;
-			assert!(ell >= skip_rounds);
-
 			OddInterpolate::new(n >> ell, ell, ntt.twiddles())
 				.expect("domain large enough by construction")
 		});
 
-		// Obtain novel polynomial basis representation of round evaluations.
-		odd_interpolate.inverse_transform(&ntt, round_evals)?;
+		// Obtain novel polynomial basis representation of the evals.
+		odd_interpolate.inverse_transform(&ntt, evals)?;
 
 		// Use forward NTT to extrapolate novel representation to the max domain size.
 		let next_log_n = ntt.log_domain_size();
-		round_evals.resize(1 << next_log_n, F::ZERO);
+		evals.resize(1 << next_log_n, F::ZERO);
 
 		let shape = NTTShape {
 			log_y: next_log_n,
 			..Default::default()
 		};
-		ntt.forward_transform(round_evals, shape, 0, 0)?;
-
-		// Sanity check: first 1 << skip_rounds evals are still zeros.
-		debug_assert!(
-			round_evals[..1 << skip_rounds]
-				.iter()
-				.all(|&coeff| coeff == F::ZERO)
-		);
+		ntt.forward_transform(evals, shape, 0, 0)?;
 
 		// Trim the result.
-		round_evals.resize(max_domain_size, F::ZERO);
-		round_evals.drain(..1 << skip_rounds);
+		evals.truncate(max_domain_size);
 	}
 
-	Ok(round_evals)
+	Ok(evals)
 }
 
-fn ntt_extrapolate<NTT, P>(
+pub(super) fn ntt_extrapolate<NTT, P>(
 	ntt: &NTT,
 	skip_rounds: usize,
 	log_stride_batch: usize,
@@ -674,6 +765,157 @@ where
 	Ok(())
 }
 
+/// The number of subcube positions covered by a single lookup in [`BooleanExtrapolation`].
+const BOOLEAN_CHUNK_BITS: usize = 4;
+
+/// The largest number of skipped rounds for which boolean subcubes are extrapolated with lookups.
+///
+/// The table size grows linearly with the subcube size, while the NTT it replaces grows only
+/// quasi-linearly, so beyond this size the table no longer fits in L1 cache.
+const MAX_BOOLEAN_SKIP_ROUNDS: usize = 7;
+
+/// Lookup tables to extrapolate the subcubes of 1-bit multilinears.
+///
+/// Extrapolation is linear, hence the extrapolation of a $\{0, 1\}$-valued subcube is the sum of
+/// the extrapolations of the unit vectors at the positions of its ones. The table stores these
+/// sums for every subset of each chunk of [`BOOLEAN_CHUNK_BITS`] consecutive positions, which
+/// replaces the inverse and forward NTTs of [`ntt_extrapolate`] with a bitmask computation and a
+/// few additions (XORs) of precomputed rows per coset.
+#[derive(Debug)]
+struct BooleanExtrapolation<PBase> {
+	skip_rounds: usize,
+	n_cosets: usize,
+	/// Rows of `1 << (skip_rounds - PBase::LOG_WIDTH)` packed elements, indexed by the coset,
+	/// the chunk and the subset of the chunk positions, in order of decreasing stride.
+	table: Vec<PBase>,
+}
+
+impl<PBase: PackedField> BooleanExtrapolation<PBase> {
+	/// Precomputes the tables for the first `n_cosets` cosets after the skipped subcube.
+	///
+	/// Returns `None` when the lookups do not pay off: the skipped subcube must consist of whole
+	/// packed elements, and have between `1 << BOOLEAN_CHUNK_BITS` and
+	/// `1 << MAX_BOOLEAN_SKIP_ROUNDS` elements.
+	fn new<FDomain, NTT>(
+		ntt: &NTT,
+		skip_rounds: usize,
+		n_cosets: usize,
+	) -> Result<Option<Self>, Error>
+	where
+		FDomain: BinaryField,
+		PBase::Scalar: ExtensionField<FDomain>,
+		NTT: AdditiveNTT<FDomain>,
+	{
+		if n_cosets == 0
+			|| skip_rounds < PBase::LOG_WIDTH.max(BOOLEAN_CHUNK_BITS)
+			|| skip_rounds > MAX_BOOLEAN_SKIP_ROUNDS
+		{
+			return Ok(None);
+		}
+
+		let mut extrapolation = Self {
+			skip_rounds,
+			n_cosets,
+			table: Vec::new(),
+		};
+		let row_len = extrapolation.row_len();
+		extrapolation.table = vec![
+			PBase::zero();
+			(n_cosets * extrapolation.n_chunks() * row_len)
+				<< BOOLEAN_CHUNK_BITS
+		];
+
+		// Extrapolate the unit vectors into the rows of the singleton subsets.
+		let mut unit = vec![FDomain::ZERO; 1 << skip_rounds];
+		let mut extrapolated = vec![FDomain::ZERO; n_cosets << skip_rounds];
+		for position in 0..1 << skip_rounds {
+			unit.fill(FDomain::ZERO);
+			unit[position] = FDomain::ONE;
+			ntt_extrapolate(ntt, skip_rounds, 0, 0, &mut unit, &mut extrapolated)?;
+
+			let chunk = position / BOOLEAN_CHUNK_BITS;
+			let subset = 1 << (position % BOOLEAN_CHUNK_BITS);
+			for (coset, coset_evals) in extrapolated.chunks_exact(1 << skip_rounds).enumerate() {
+				let row_index = extrapolation.row_index(coset, chunk, subset);
+				for (i, packed) in extrapolation.table[row_index..][..row_len]
+					.iter_mut()
+					.enumerate()
+				{
+					*packed = PBase::from_fn(|j| coset_evals[i << PBase::LOG_WIDTH | j].into());
+				}
+			}
+		}
+
+		// The row of a subset is the sum of the rows of its lowest position and the rest.
+		for rows in extrapolation
+			.table
+			.chunks_exact_mut(row_len << BOOLEAN_CHUNK_BITS)
+		{
+			for subset in 1..1 << BOOLEAN_CHUNK_BITS {
+				let rest = subset & (subset - 1);
+				if rest == 0 {
+					continue;
+				}
+				let lowest = subset ^ rest;
+				for i in 0..row_len {
+					rows[subset * row_len + i] =
+						rows[rest * row_len + i] + rows[lowest * row_len + i];
+				}
+			}
+		}
+
+		Ok(Some(extrapolation))
+	}
+
+	/// Extrapolates $\{0, 1\}$-valued `evals` with the layout and result of [`ntt_extrapolate`]
+	/// with a `log_stride_batch` of zero.
+	fn extrapolate(&self, evals: &[PBase], extrapolated_evals: &mut [PBase]) {
+		let row_len = self.row_len();
+		let n_chunks = self.n_chunks();
+
+		let extrapolated_evals = &mut extrapolated_evals[..self.n_cosets * evals.len()];
+		extrapolated_evals.fill(PBase::zero());
+
+		for (batch_index, batch) in evals.chunks_exact(row_len).enumerate() {
+			let mut masks = [0usize; MAX_BOOLEAN_SKIP_ROUNDS.div_ceil(BOOLEAN_CHUNK_BITS)];
+			for (position, scalar) in batch.iter().flat_map(PackedField::iter).enumerate() {
+				debug_assert!(scalar == PBase::Scalar::ZERO || scalar == PBase::Scalar::ONE);
+				if scalar == PBase::Scalar::ONE {
+					masks[position / BOOLEAN_CHUNK_BITS] |= 1 << (position % BOOLEAN_CHUNK_BITS);
+				}
+			}
+
+			for (coset, extrapolated_chunk) in
+				extrapolated_evals.chunks_exact_mut(evals.len()).enumerate()
+			{
+				let extrapolated_batch =
+					&mut extrapolated_chunk[batch_index * row_len..][..row_len];
+				for (chunk, &mask) in masks[..n_chunks].iter().enumerate() {
+					if mask == 0 {
+						continue;
+					}
+					let row = &self.table[self.row_index(coset, chunk, mask)..][..row_len];
+					for (dst, &src) in izip!(extrapolated_batch.iter_mut(), row) {
+						*dst += src;
+					}
+				}
+			}
+		}
+	}
+
+	const fn row_len(&self) -> usize {
+		1 << (self.skip_rounds - PBase::LOG_WIDTH)
+	}
+
+	const fn n_chunks(&self) -> usize {
+		self.skip_rounds.div_ceil(BOOLEAN_CHUNK_BITS)
+	}
+
+	const fn row_index(&self, coset: usize, chunk: usize, subset: usize) -> usize {
+		((coset * self.n_chunks() + chunk) << BOOLEAN_CHUNK_BITS | subset) * self.row_len()
+	}
+}
+
 const fn extrapolated_evals_packed_len<P: PackedField>(
 	composition_degree: usize,
 	skip_rounds: usize,
@@ -687,8 +929,9 @@ mod tests {
 	use std::sync::Arc;
 
 	use binius_field::{
-		BinaryField1b, BinaryField8b, BinaryField16b, BinaryField128b, ExtensionField, Field,
-		PackedBinaryField4x32b, PackedExtension, PackedField, PackedFieldIndexable, TowerField,
+		BinaryField1b, BinaryField8b, BinaryField16b, BinaryField32b, BinaryField128b,
+		ExtensionField, Field, PackedBinaryField4x32b, PackedExtension, PackedField,
+		PackedFieldIndexable, TowerField,
 		arch::{OptimalUnderlier128b, OptimalUnderlier512b},
 		as_packed_field::{PackScalar, PackedType},
 		underlier::UnderlierType,
@@ -696,7 +939,7 @@ mod tests {
 	use binius_hal::make_portable_backend;
 	use binius_math::{BinarySubspace, CompositionPoly, EvaluationDomain, MultilinearPoly};
 	use binius_ntt::SingleThreadedNTT;
-	use rand::{SeedableRng, prelude::StdRng};
+	use rand::{Rng, SeedableRng, prelude::StdRng};
 
 	use crate::{
 		composition::{IndexComposition, ProductComposition},
@@ -780,6 +1023,58 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn boolean_extrapolation_matches_ntt() {
+		type P = PackedBinaryField4x32b;
+		type FDomain = BinaryField16b;
+		let log_extension_degree_p_domain = 1;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<FDomain>::new(10).unwrap();
+
+		assert!(
+			super::BooleanExtrapolation::<P>::new(&ntt, 3, 2)
+				.unwrap()
+				.is_none()
+		);
+		for skip_rounds in 4..=super::MAX_BOOLEAN_SKIP_ROUNDS {
+			for n_cosets in 1..4usize {
+				let boolean_extrapolation =
+					super::BooleanExtrapolation::<P>::new(&ntt, skip_rounds, n_cosets)
+						.unwrap()
+						.unwrap();
+				let log_batch = 2;
+				let evals_len = 1 << (skip_rounds + log_batch - P::LOG_WIDTH);
+				let evals = (0..evals_len)
+					.map(|_| {
+						P::from_fn(|_| {
+							if rng.r#gen() {
+								BinaryField32b::ONE
+							} else {
+								BinaryField32b::ZERO
+							}
+						})
+					})
+					.collect::<Vec<_>>();
+
+				let mut extrapolated_evals = vec![P::zero(); n_cosets * evals_len];
+				boolean_extrapolation.extrapolate(&evals, &mut extrapolated_evals);
+
+				let mut expected_evals = vec![P::zero(); n_cosets * evals_len];
+				super::ntt_extrapolate(
+					&ntt,
+					skip_rounds,
+					log_extension_degree_p_domain,
+					log_batch,
+					P::cast_bases_mut(&mut evals.clone()),
+					P::cast_bases_mut(&mut expected_evals),
+				)
+				.unwrap();
+				assert_eq!(extrapolated_evals, expected_evals);
+			}
+		}
+	}
+
 	#[test]
 	fn zerocheck_univariate_evals_invariants_basic() {
 		zerocheck_univariate_evals_invariants_helper::<
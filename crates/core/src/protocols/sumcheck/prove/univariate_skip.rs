@@ -0,0 +1,622 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{iter, marker::PhantomData, sync::Arc};
+
+use binius_field::{
+	ExtensionField, Field, PackedExtension, PackedField, PackedSubfield, TowerField,
+	packed::{copy_packed_from_scalars_slice, get_packed_slice},
+	recast_packed_mut,
+	util::{inner_product_unchecked, powers},
+};
+use binius_hal::{ComputationBackend, ComputationBackendExt, make_portable_backend};
+use binius_math::{
+	BinarySubspace, CompositionPoly, Error as MathError, EvaluationDomain, EvaluationOrder,
+	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearPoly, MultilinearQuery,
+	RowsBatchRef,
+};
+use binius_maybe_rayon::prelude::*;
+use binius_ntt::SingleThreadedNTT;
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize, sorting::is_sorted_ascending};
+use bytemuck::zeroed_vec;
+use stackalloc::stackalloc_with_iter;
+use tracing::instrument;
+
+use crate::{
+	fiat_shamir::{CanSample, Challenger},
+	protocols::sumcheck::{
+		BatchSumcheckOutput, Error,
+		common::{
+			CompositeSumClaim, equal_n_vars_check, small_field_embedding_degree_check,
+			validate_composition,
+		},
+		immediate_switchover_heuristic,
+		prove::{
+			RegularSumcheckProver,
+			batch_zerocheck::univariatizing_reduction_prover,
+			front_loaded,
+			univariate::{extrapolate_lagrange_evals, ntt_extrapolate},
+		},
+		univariate_skip::{BatchUnivariateSkipOutput, univariate_domain},
+		verify_zerocheck::domain_size,
+	},
+	transcript::ProverTranscript,
+};
+
+/// A prover for a sumcheck claim reduced with a univariate skip.
+///
+/// The batch prover must obey a specific sequence of calls: [`Self::execute_univariate_round`]
+/// should be followed by [`Self::fold_univariate_round`], and then
+/// [`Self::project_to_skipped_variables`]. Folding the univariate round results in a
+/// [`RegularSumcheckProver`] over the univariatized multilinears that can be driven to completion
+/// to prove the remaining multilinear rounds.
+///
+/// The witness multilinears must be embeddable into the small field `FBase`. The univariate round
+/// is computed in that field, the same way as in
+/// [`zerocheck_univariate_evals`](super::univariate::zerocheck_univariate_evals): each subcube is
+/// extrapolated with an additive NTT over `FDomain`, and the `CompositionBase` counterparts of the
+/// compositions are evaluated over the extrapolated subcubes with packed subfield arithmetic.
+pub struct UnivariateSkipProver<'a, FDomain, FBase, P, CompositionBase, Composition, M, Backend>
+where
+	P: PackedField,
+	Backend: ComputationBackend,
+{
+	n_vars: usize,
+	multilinears: Vec<M>,
+	compositions_base: Vec<CompositionBase>,
+	composite_claims: Vec<CompositeSumClaim<P::Scalar, Composition>>,
+	univariate_round_evals: Option<Vec<Vec<P::Scalar>>>,
+	backend: &'a Backend,
+	_marker: PhantomData<(FDomain, FBase)>,
+}
+
+impl<'a, F, FDomain, FBase, P, CompositionBase, Composition, M, Backend>
+	UnivariateSkipProver<'a, FDomain, FBase, P, CompositionBase, Composition, M, Backend>
+where
+	F: TowerField + ExtensionField<FDomain> + ExtensionField<FBase>,
+	FDomain: TowerField,
+	FBase: TowerField + ExtensionField<FDomain>,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain> + PackedExtension<FBase>,
+	CompositionBase: CompositionPoly<PackedSubfield<P, FBase>>,
+	Composition: CompositionPoly<P> + Clone,
+	M: MultilinearPoly<P> + Send + Sync,
+	Backend: ComputationBackend,
+{
+	/// Creates a prover for the composite sum claims over `multilinears`.
+	///
+	/// Every claim comes with the same composition over the packed subfield of `FBase`, which is
+	/// used to compute the univariate round.
+	pub fn new(
+		multilinears: Vec<M>,
+		composite_claims: impl IntoIterator<Item = (CompositionBase, CompositeSumClaim<F, Composition>)>,
+		backend: &'a Backend,
+	) -> Result<Self, Error> {
+		let n_vars = equal_n_vars_check(&multilinears)?;
+		let n_multilinears = multilinears.len();
+
+		let (compositions_base, composite_claims): (Vec<_>, Vec<_>) =
+			composite_claims.into_iter().unzip();
+
+		for (composition_base, claim) in iter::zip(&compositions_base, &composite_claims) {
+			validate_composition(&claim.composition, n_multilinears)?;
+			if composition_base.n_vars() != n_multilinears
+				|| composition_base.degree() != claim.composition.degree()
+			{
+				bail!(Error::InvalidComposition {
+					actual: composition_base.n_vars(),
+					expected: n_multilinears,
+				});
+			}
+		}
+
+		small_field_embedding_degree_check::<_, FBase, P, _>(&multilinears)?;
+
+		Ok(Self {
+			n_vars,
+			multilinears,
+			compositions_base,
+			composite_claims,
+			univariate_round_evals: None,
+			backend,
+			_marker: PhantomData,
+		})
+	}
+
+	/// The number of variables in the multivariate polynomial.
+	pub const fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// Maximal required Lagrange domain size among compositions in this prover.
+	pub fn domain_size(&self, skip_rounds: usize) -> usize {
+		let max_degree = self
+			.composite_claims
+			.iter()
+			.map(|claim| claim.composition.degree())
+			.max()
+			.unwrap_or(0);
+		domain_size(max_degree.max(1), skip_rounds)
+	}
+
+	/// Computes the prover message for the univariate round as a univariate polynomial.
+	///
+	/// The univariate polynomials of the composites are mixed with the powers of `batch_coeff`,
+	/// using the same approach as [`SumcheckProver::execute`](super::SumcheckProver::execute).
+	/// The returned polynomial is in Lagrange basis over the first `max_domain_size` points of the
+	/// univariate domain.
+	#[instrument(
+		skip_all,
+		level = "debug",
+		name = "UnivariateSkipProver::execute_univariate_round"
+	)]
+	pub fn execute_univariate_round(
+		&mut self,
+		skip_rounds: usize,
+		max_domain_size: usize,
+		batch_coeff: F,
+	) -> Result<Vec<F>, Error> {
+		if self.n_vars < skip_rounds {
+			bail!(Error::TooManySkippedRounds);
+		}
+
+		if max_domain_size < self.domain_size(skip_rounds) {
+			bail!(Error::LagrangeDomainTooSmall);
+		}
+
+		let composite_round_evals = univariate_round_evals::<F, FDomain, FBase, P, _, _>(
+			&self.multilinears,
+			&self.compositions_base,
+			skip_rounds,
+			max_domain_size,
+		)?;
+
+		let mut round_evals = vec![F::ZERO; max_domain_size];
+		for (composite_evals, coeff) in composite_round_evals.iter().zip(powers(batch_coeff)) {
+			for (round_eval, &composite_eval) in round_evals.iter_mut().zip(composite_evals) {
+				*round_eval += coeff * composite_eval;
+			}
+		}
+
+		self.univariate_round_evals = Some(composite_round_evals);
+		Ok(round_evals)
+	}
+
+	/// Folds into a regular multilinear prover for the remaining rounds.
+	#[instrument(
+		skip_all,
+		level = "debug",
+		name = "UnivariateSkipProver::fold_univariate_round"
+	)]
+	pub fn fold_univariate_round(
+		&mut self,
+		skip_rounds: usize,
+		challenge: F,
+	) -> Result<
+		RegularSumcheckProver<'a, FDomain, P, Composition, MLEDirectAdapter<P>, Backend>,
+		Error,
+	> {
+		let Some(composite_round_evals) = self.univariate_round_evals.take() else {
+			bail!(Error::ExpectedExecution);
+		};
+
+		// The claimed sums of the remaining rounds are the composite round polynomials evaluated
+		// at the challenge.
+		let max_domain_size = composite_round_evals.first().map_or(0, Vec::len);
+		let claimed_sums = if max_domain_size > 0 {
+			let lagrange_coeffs =
+				univariate_domain::<F>(max_domain_size)?.lagrange_evals(challenge);
+			composite_round_evals
+				.iter()
+				.map(|evals| {
+					inner_product_unchecked(evals.iter().copied(), lagrange_coeffs.iter().copied())
+				})
+				.collect::<Vec<_>>()
+		} else {
+			Vec::new()
+		};
+
+		// For each subcube of size 2**skip_rounds, compute its inner product with the Lagrange
+		// coefficients at the challenge point to obtain the univariatized multilinears.
+		let subcube_lagrange_coeffs = skipped_domain::<F>(skip_rounds)?.lagrange_evals(challenge);
+		let mut packed_subcube_lagrange_coeffs =
+			zeroed_vec::<P>(1 << skip_rounds.saturating_sub(P::LOG_WIDTH));
+		copy_packed_from_scalars_slice(
+			&subcube_lagrange_coeffs,
+			&mut packed_subcube_lagrange_coeffs,
+		);
+		let lagrange_coeffs_query =
+			MultilinearQuery::with_expansion(skip_rounds, packed_subcube_lagrange_coeffs)?;
+
+		let folded_multilinears = self
+			.multilinears
+			.par_iter()
+			.map(|multilinear| -> Result<_, Error> {
+				let folded_multilinear =
+					multilinear.evaluate_partial_low(lagrange_coeffs_query.to_ref())?;
+				Ok(MLEDirectAdapter::from(folded_multilinear))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let composite_claims = self
+			.composite_claims
+			.iter()
+			.zip(claimed_sums)
+			.map(|(claim, sum)| CompositeSumClaim {
+				composition: claim.composition.clone(),
+				sum,
+			})
+			.collect::<Vec<_>>();
+
+		RegularSumcheckProver::new(
+			EvaluationOrder::HighToLow,
+			folded_multilinears,
+			composite_claims,
+			IsomorphicEvaluationDomainFactory::<FDomain::Canonical>::default(),
+			immediate_switchover_heuristic,
+			self.backend,
+		)
+	}
+
+	/// Projects witness onto the "skipped" variables for the univariatizing reduction.
+	pub fn project_to_skipped_variables(
+		self,
+		skip_rounds: usize,
+		challenges: &[F],
+	) -> Result<Vec<Arc<dyn MultilinearPoly<P> + Send + Sync>>, Error> {
+		let projection_n_vars = self.n_vars - skip_rounds;
+		if challenges.len() < projection_n_vars {
+			bail!(Error::IncorrectNumberOfChallenges);
+		}
+
+		let query = self
+			.backend
+			.multilinear_query(&challenges[challenges.len() - projection_n_vars..])?;
+
+		self.multilinears
+			.par_iter()
+			.map(|multilinear| -> Result<_, Error> {
+				let projected_mle = self
+					.backend
+					.evaluate_partial_high(multilinear, query.to_ref())?;
+				Ok(MLEDirectAdapter::from(projected_mle).upcast_arc_dyn())
+			})
+			.collect()
+	}
+}
+
+fn skipped_domain<F: TowerField>(skip_rounds: usize) -> Result<EvaluationDomain<F>, Error> {
+	let subspace = BinarySubspace::<F::Canonical>::with_dim(skip_rounds)?.isomorphic::<F>();
+	Ok(EvaluationDomain::from_points(subspace.iter().collect(), false)?)
+}
+
+#[derive(Debug)]
+struct ParFoldStates<FBase: Field, P: PackedExtension<FBase>> {
+	/// Evaluations of a multilinear subcube, embedded into P (see MultilinearPoly::subcube_evals).
+	/// Scratch space.
+	evals: Vec<P>,
+	/// Evaluations of the subcube over the first cosets of the univariate domain, per multilinear.
+	extrapolated_evals: Vec<Vec<PackedSubfield<P, FBase>>>,
+	/// Evals of a single composition over extrapolated multilinears. Scratch space.
+	composition_evals: Vec<PackedSubfield<P, FBase>>,
+	/// Packed round evals accumulators, per composition.
+	packed_round_evals: Vec<Vec<PackedSubfield<P, FBase>>>,
+}
+
+/// Computes the univariate round polynomials of the compositions, in Lagrange basis over the first
+/// `max_domain_size` points of the univariate domain.
+///
+/// A composition of degree $d$ over the univariatized multilinears has degree at most
+/// $d (2^k - 1)$, hence it is determined by its sums over the first $d$ cosets of the skipped
+/// subspace. These are computed in `FBase`: the subcubes of the multilinears are extrapolated to
+/// the cosets with an additive NTT and the compositions are evaluated over them with packed
+/// subfield arithmetic. Only the resulting $d \cdot 2^k$ sums are then extrapolated to the full
+/// domain.
+fn univariate_round_evals<F, FDomain, FBase, P, Composition, M>(
+	multilinears: &[M],
+	compositions: &[Composition],
+	skip_rounds: usize,
+	max_domain_size: usize,
+) -> Result<Vec<Vec<F>>, Error>
+where
+	F: TowerField + ExtensionField<FDomain> + ExtensionField<FBase>,
+	FDomain: TowerField,
+	FBase: TowerField + ExtensionField<FDomain>,
+	P: PackedField<Scalar = F> + PackedExtension<FBase> + PackedExtension<FDomain>,
+	Composition: CompositionPoly<PackedSubfield<P, FBase>>,
+	M: MultilinearPoly<P> + Send + Sync,
+{
+	let n_vars = equal_n_vars_check(multilinears)?;
+	let n_multilinears = multilinears.len();
+
+	let log_embedding_degree = <F as ExtensionField<FBase>>::LOG_DEGREE;
+	let log_extension_degree_base_domain = <FBase as ExtensionField<FDomain>>::LOG_DEGREE;
+
+	// Constant compositions still need the evaluations over the skipped subspace.
+	let composition_degrees = compositions
+		.iter()
+		.map(|composition| composition.degree().max(1))
+		.collect::<Vec<_>>();
+	let composition_max_degree = composition_degrees.iter().copied().max().unwrap_or(1);
+
+	// Check that domain field contains the required NTT cosets.
+	let min_domain_bits = log2_ceil_usize(max_domain_size);
+	if min_domain_bits > FDomain::N_BITS {
+		bail!(MathError::DomainSizeTooLarge);
+	}
+
+	let fdomain_ntt = SingleThreadedNTT::<FDomain>::with_canonical_field(min_domain_bits)
+		.expect("FDomain cardinality checked before")
+		.precompute_twiddles();
+
+	// Smaller subcubes are batched together to reduce interpolation/evaluation overhead.
+	const MAX_SUBCUBE_VARS: usize = 12;
+	let log_batch = MAX_SUBCUBE_VARS.min(n_vars).saturating_sub(skip_rounds);
+
+	let subcube_vars = log_batch + skip_rounds;
+	let log_subcube_count = n_vars - subcube_vars;
+
+	// The number of packed base field elements holding a subcube, i.e. one coset of the skipped
+	// subspace for each subcube in the batch.
+	let pbase_coset_len = 1
+		<< subcube_vars.saturating_sub(P::LOG_WIDTH + log_embedding_degree)
+		<< log_embedding_degree;
+
+	let packed_round_evals = (0..1 << log_subcube_count)
+		.into_par_iter()
+		.try_fold(
+			|| ParFoldStates::<FBase, P> {
+				evals: zeroed_vec(pbase_coset_len >> log_embedding_degree),
+				extrapolated_evals: (0..n_multilinears)
+					.map(|_| zeroed_vec(composition_max_degree * pbase_coset_len))
+					.collect(),
+				composition_evals: zeroed_vec(composition_max_degree * pbase_coset_len),
+				packed_round_evals: composition_degrees
+					.iter()
+					.map(|&degree| zeroed_vec(degree * pbase_coset_len))
+					.collect(),
+			},
+			|mut par_fold_states, subcube_index| -> Result<_, Error> {
+				let ParFoldStates {
+					evals,
+					extrapolated_evals,
+					composition_evals,
+					packed_round_evals,
+				} = &mut par_fold_states;
+
+				for (multilinear, extrapolated_evals) in
+					iter::zip(multilinears, extrapolated_evals.iter_mut())
+				{
+					multilinear.subcube_evals(
+						subcube_vars,
+						subcube_index,
+						log_embedding_degree,
+						evals,
+					)?;
+
+					// The first coset is the skipped subspace itself, the others are extrapolated
+					// with an additive NTT.
+					let evals_base = <P as PackedExtension<FBase>>::cast_bases_mut(evals);
+					let (skipped_evals, coset_evals) =
+						extrapolated_evals.split_at_mut(pbase_coset_len);
+					skipped_evals.copy_from_slice(evals_base);
+
+					if composition_max_degree > 1 {
+						ntt_extrapolate(
+							&fdomain_ntt,
+							skip_rounds,
+							log_extension_degree_base_domain,
+							log_batch,
+							recast_packed_mut::<P, FBase, FDomain>(evals_base),
+							recast_packed_mut::<P, FBase, FDomain>(coset_evals),
+						)?;
+					}
+				}
+
+				// Evaluate each composition on a minimal prefix of cosets corresponding to its
+				// degree and accumulate the results.
+				for (composition, packed_round_evals) in
+					iter::zip(compositions, packed_round_evals.iter_mut())
+				{
+					let prefix_len = packed_round_evals.len();
+					let extrapolated_evals_iter =
+						extrapolated_evals.iter().map(|evals| &evals[..prefix_len]);
+
+					stackalloc_with_iter(n_multilinears, extrapolated_evals_iter, |batch_query| {
+						let batch_query = RowsBatchRef::new(batch_query, prefix_len);
+						composition
+							.batch_evaluate(&batch_query, &mut composition_evals[..prefix_len])
+					})?;
+
+					for (round_eval, &composition_eval) in
+						iter::zip(packed_round_evals.iter_mut(), &composition_evals[..prefix_len])
+					{
+						*round_eval += composition_eval;
+					}
+				}
+
+				Ok(par_fold_states)
+			},
+		)
+		.map(|states| -> Result<_, Error> { Ok(states?.packed_round_evals) })
+		.try_reduce(
+			|| {
+				composition_degrees
+					.iter()
+					.map(|&degree| zeroed_vec(degree * pbase_coset_len))
+					.collect()
+			},
+			|lhs, rhs| -> Result<_, Error> {
+				let round_evals_sum = iter::zip(lhs, rhs)
+					.map(|(mut lhs_vals, rhs_vals)| {
+						for (lhs_val, rhs_val) in iter::zip(&mut lhs_vals, rhs_vals) {
+							*lhs_val += rhs_val;
+						}
+						lhs_vals
+					})
+					.collect();
+				Ok(round_evals_sum)
+			},
+		)?;
+
+	// Sum over the batch of subcubes. The sums over coset `i` are the round polynomial
+	// evaluations at the points `i * 2^k..(i + 1) * 2^k` of the univariate domain.
+	let round_evals = packed_round_evals
+		.iter()
+		.map(|packed_round_evals| {
+			packed_round_evals
+				.chunks_exact(pbase_coset_len)
+				.flat_map(|coset_evals| {
+					(0..1 << skip_rounds).map(move |point_index| {
+						(0..1 << log_batch)
+							.map(|batch_index| {
+								get_packed_slice(
+									coset_evals,
+									batch_index << skip_rounds | point_index,
+								)
+							})
+							.sum::<FBase>()
+					})
+				})
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+
+	let round_evals = extrapolate_lagrange_evals(&fdomain_ntt, round_evals, max_domain_size)?;
+
+	Ok(round_evals
+		.into_iter()
+		.map(|evals| evals.into_iter().map(F::from).collect())
+		.collect())
+}
+
+/// Prove a batched sumcheck protocol execution with a univariate skip.
+///
+/// See the [`batch_verify_univariate_skip`](`super::super::batch_verify_univariate_skip`)
+/// docstring for a description of the protocol. The `provers` in this invocation should be
+/// provided in the same order as the corresponding claims during verification.
+pub fn batch_prove<
+	'a,
+	F,
+	FDomain,
+	FBase,
+	P,
+	CompositionBase,
+	Composition,
+	M,
+	Backend,
+	Challenger_,
+>(
+	mut provers: Vec<
+		UnivariateSkipProver<'a, FDomain, FBase, P, CompositionBase, Composition, M, Backend>,
+	>,
+	skip_rounds: usize,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchUnivariateSkipOutput<F>, Error>
+where
+	F: TowerField + ExtensionField<FDomain> + ExtensionField<FBase>,
+	FDomain: TowerField,
+	FBase: TowerField + ExtensionField<FDomain>,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FDomain>
+		+ PackedExtension<FBase>,
+	CompositionBase: CompositionPoly<PackedSubfield<P, FBase>>,
+	Composition: CompositionPoly<P> + Clone,
+	M: MultilinearPoly<P> + Send + Sync,
+	Backend: ComputationBackend,
+	Challenger_: Challenger,
+{
+	// Check that the provers are in non-descending order by n_vars
+	if !is_sorted_ascending(provers.iter().map(|prover| prover.n_vars())) {
+		bail!(Error::ClaimsOutOfOrder);
+	}
+
+	if provers.is_empty() {
+		return Ok(BatchUnivariateSkipOutput {
+			skipped_challenges: Vec::new(),
+			unskipped_challenges: Vec::new(),
+			concat_multilinear_evals: Vec::new(),
+		});
+	}
+
+	let max_domain_size = provers
+		.iter()
+		.map(|prover| prover.domain_size(skip_rounds))
+		.max()
+		.unwrap_or(0);
+
+	// Sample batching coefficients, then batch the round polynomials in the Lagrange domain.
+	let batch_coeffs = transcript.sample_vec(provers.len());
+	let mut round_evals = vec![F::ZERO; max_domain_size];
+	for (prover, &batch_coeff) in provers.iter_mut().zip(&batch_coeffs) {
+		let prover_round_evals =
+			prover.execute_univariate_round(skip_rounds, max_domain_size, batch_coeff)?;
+
+		for (round_eval, prover_round_eval) in round_evals.iter_mut().zip(prover_round_evals) {
+			*round_eval += batch_coeff * prover_round_eval;
+		}
+	}
+
+	// Sample univariate challenge
+	transcript.message().write_scalar_slice(&round_evals);
+	let univariate_challenge = transcript.sample();
+
+	// Prove the remaining multilinear rounds, high-to-low, with front-loaded batching
+	let sumcheck_provers = provers
+		.iter_mut()
+		.map(|prover| prover.fold_univariate_round(skip_rounds, univariate_challenge))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let regular_sumcheck_prover =
+		front_loaded::BatchProver::new_prebatched(batch_coeffs, sumcheck_provers)?;
+
+	let BatchSumcheckOutput {
+		challenges: mut unskipped_challenges,
+		multilinear_evals: univariatized_multilinear_evals,
+	} = regular_sumcheck_prover.run(transcript)?;
+
+	// Reverse challenges since folding high-to-low
+	unskipped_challenges.reverse();
+
+	// Project witness multilinears to "skipped" variables
+	let mut projected_multilinears = Vec::new();
+	for prover in provers {
+		projected_multilinears
+			.extend(prover.project_to_skipped_variables(skip_rounds, &unskipped_challenges)?);
+	}
+
+	// Prove univariatizing reduction sumcheck.
+	// It's small (`skip_rounds` variables), so portable backend is likely fine.
+	let backend = make_portable_backend();
+	let reduction_prover = univariatizing_reduction_prover::<_, FDomain, _>(
+		projected_multilinears,
+		skip_rounds,
+		univariatized_multilinear_evals,
+		univariate_challenge,
+		&backend,
+	)?;
+
+	let batch_reduction_prover =
+		front_loaded::BatchProver::new(vec![reduction_prover], transcript)?;
+
+	let BatchSumcheckOutput {
+		challenges: mut skipped_challenges,
+		multilinear_evals: mut concat_multilinear_evals,
+	} = batch_reduction_prover.run(transcript)?;
+
+	// Reverse challenges since folding high-to-low
+	skipped_challenges.reverse();
+
+	let mut concat_multilinear_evals = concat_multilinear_evals
+		.pop()
+		.expect("multilinear_evals.len() == 1");
+
+	concat_multilinear_evals
+		.pop()
+		.expect("Lagrange coefficients MLE eval at last position");
+
+	Ok(BatchUnivariateSkipOutput {
+		skipped_challenges,
+		unskipped_challenges,
+		concat_multilinear_evals,
+	})
+}
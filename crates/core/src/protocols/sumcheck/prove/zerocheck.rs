@@ -30,7 +30,7 @@ use crate::{
 			eq_ind::EqIndSumcheckProverBuilder,
 			univariate::{
 				ZerocheckUnivariateEvalsOutput, ZerocheckUnivariateFoldResult,
-				zerocheck_univariate_evals,
+				zerocheck_univariate_evals_with_eq_ind,
 			},
 		},
 		zerocheck::{ZerocheckRoundEvals, domain_size},
@@ -148,6 +148,7 @@ pub struct ZerocheckProverImpl<
 {
 	n_vars: usize,
 	zerocheck_challenges: Vec<P::Scalar>,
+	partial_eq_ind_evals: Option<Backend::Vec<P>>,
 	state: ZerocheckProverState<
 		Vec<M>,
 		Vec<Either<M, MLEEmbeddingAdapter<P::PackedSubfield, P>>>,
@@ -260,12 +261,23 @@ where
 		Ok(Self {
 			n_vars,
 			zerocheck_challenges,
+			partial_eq_ind_evals: None,
 			state,
 			backend,
 			_p_base_marker: PhantomData,
 			_fdomain_marker: PhantomData,
 		})
 	}
+
+	/// Uses `partial_eq_ind_evals`, the tensor expansion of the zerocheck challenges, in the
+	/// univariate round instead of expanding the challenges again.
+	///
+	/// This lets provers that share zerocheck challenges share one expansion, see
+	/// [`MemoizedQueries`](crate::protocols::memoized_queries::MemoizedQueries).
+	pub fn with_partial_eq_ind_evals(mut self, partial_eq_ind_evals: Backend::Vec<P>) -> Self {
+		self.partial_eq_ind_evals = Some(partial_eq_ind_evals);
+		self
+	}
 }
 
 impl<'a, F, FDomain, FBase, P, CompositionBase, Composition, M, DomainFactory, Backend>
@@ -342,14 +354,16 @@ where
 
 		// Output contains values that are needed for computations that happen after
 		// the round challenge has been sampled
-		let univariate_evals_output = zerocheck_univariate_evals::<_, _, FBase, _, _, _, _>(
-			&padded_multilinears,
-			&compositions_base,
-			&self.zerocheck_challenges,
-			skip_rounds,
-			max_domain_size,
-			self.backend,
-		)?;
+		let univariate_evals_output =
+			zerocheck_univariate_evals_with_eq_ind::<_, _, FBase, _, _, _, _>(
+				&padded_multilinears,
+				&compositions_base,
+				&self.zerocheck_challenges,
+				self.partial_eq_ind_evals.take(),
+				skip_rounds,
+				max_domain_size,
+				self.backend,
+			)?;
 
 		// Batch together Lagrange round evals using powers of batch_coeff
 		let batched_round_evals = univariate_evals_output
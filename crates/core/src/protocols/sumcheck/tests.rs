@@ -27,13 +27,16 @@ use itertools::izip;
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
 use super::{
-	BatchSumcheckOutput, SumcheckClaim,
+	BatchSumcheckOutput, BatchUnivariateSkipOutput, Error, SumcheckClaim, ZerocheckClaim,
+	batch_prove_univariate_skip, batch_verify_univariate_skip,
 	common::CompositeSumClaim,
 	front_loaded::BatchVerifier as FrontLoadedBatchVerifier,
 	prove::{
-		RegularSumcheckProver, batch_prove, front_loaded::BatchProver as FrontLoadedBatchProver,
+		RegularSumcheckProver, UnivariateSkipProver, batch_prove, batch_prove_mixed,
+		batch_prove_zk, front_loaded::BatchProver as FrontLoadedBatchProver,
 	},
-	verify_sumcheck::batch_verify,
+	verify_sumcheck::{batch_verify, batch_verify_mixed, batch_verify_zk},
+	zk::SumcheckMask,
 };
 use crate::{
 	composition::index_composition,
@@ -43,7 +46,7 @@ use crate::{
 		sumcheck::prove::SumcheckProver,
 		test_utils::{AddOneComposition, TestProductComposition},
 	},
-	transcript::ProverTranscript,
+	transcript::{ProverTranscript, VerifierTranscript},
 };
 
 #[derive(Debug, Clone)]
@@ -175,7 +178,7 @@ fn test_prove_verify_product_helper_with_evaluation_order<U, F, FDomain, FExt>(
 		batch_prove(vec![prover], &mut prover_transcript).expect("failed to prove sumcheck");
 
 	let prover_sample = CanSample::<FExt>::sample(&mut prover_transcript);
-	let mut verifier_transcript = prover_transcript.into_verifier();
+	let mut verifier_transcript = prover_transcript.into_verifier().unwrap();
 	let verifier_reduced_claims =
 		batch_verify(evaluation_order, &[claim], &mut verifier_transcript).unwrap();
 
@@ -416,7 +419,7 @@ fn prove_verify_batch_with_evaluation_order(
 
 	let prover_sample = CanSample::<FE>::sample(&mut prover_transcript);
 
-	let mut verifier_transcript = prover_transcript.into_verifier();
+	let mut verifier_transcript = prover_transcript.into_verifier().unwrap();
 	let verifier_output =
 		batch_verify(evaluation_order, &claims, &mut verifier_transcript).unwrap();
 
@@ -458,6 +461,156 @@ fn test_prove_verify_batch_constant_polys() {
 	]);
 }
 
+fn prove_verify_batch_zk(claim_shapes: &[TestSumcheckClaimShape]) {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+		let mut claims = Vec::with_capacity(claim_shapes.len());
+		let mut provers = Vec::with_capacity(claim_shapes.len());
+		for claim_shape in claim_shapes {
+			let (_, claim, prover) = make_test_sumcheck::<FE, FDomain, P, PE, _>(
+				evaluation_order,
+				claim_shape,
+				&mut rng,
+				&domain_factory,
+				&backend,
+			);
+			claims.push(claim);
+			provers.push(prover);
+		}
+
+		let n_vars = claim_shapes
+			.iter()
+			.map(|shape| shape.n_vars)
+			.max()
+			.unwrap_or(0);
+		let mask_degree = claim_shapes
+			.iter()
+			.map(|shape| shape.degree)
+			.max()
+			.unwrap_or(1);
+		let mask = SumcheckMask::<FE>::random(n_vars, mask_degree, &mut rng);
+
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let prover_output = batch_prove_zk(provers, &mask, &mut prover_transcript)
+			.expect("failed to prove sumcheck");
+
+		let mut round_order_challenges = prover_output.output.challenges.clone();
+		if evaluation_order == EvaluationOrder::HighToLow {
+			round_order_challenges.reverse();
+		}
+		assert_eq!(prover_output.mask_eval, mask.evaluate(&round_order_challenges));
+
+		let mut verifier_transcript = prover_transcript.into_verifier().unwrap();
+		let verifier_output =
+			batch_verify_zk(evaluation_order, &claims, mask_degree, &mut verifier_transcript)
+				.unwrap();
+		verifier_transcript.finalize().unwrap();
+
+		assert_eq!(prover_output, verifier_output);
+	}
+}
+
+#[test]
+fn test_prove_verify_batch_zk() {
+	prove_verify_batch_zk(&[
+		TestSumcheckClaimShape {
+			n_vars: 8,
+			degree: 3,
+		},
+		TestSumcheckClaimShape {
+			n_vars: 6,
+			degree: 3,
+		},
+		TestSumcheckClaimShape {
+			n_vars: 2,
+			degree: 3,
+		},
+	]);
+}
+
+#[test]
+fn test_prove_verify_batch_mixed_evaluation_orders() {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	let claim_shapes = [
+		TestSumcheckClaimShape {
+			n_vars: 8,
+			degree: 3,
+		},
+		TestSumcheckClaimShape {
+			n_vars: 6,
+			degree: 2,
+		},
+		TestSumcheckClaimShape {
+			n_vars: 6,
+			degree: 3,
+		},
+		TestSumcheckClaimShape {
+			n_vars: 2,
+			degree: 1,
+		},
+	];
+	let evaluation_orders = [
+		EvaluationOrder::HighToLow,
+		EvaluationOrder::LowToHigh,
+		EvaluationOrder::HighToLow,
+		EvaluationOrder::LowToHigh,
+	];
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+	let mut mles = Vec::with_capacity(claim_shapes.len());
+	let mut claims = Vec::with_capacity(claim_shapes.len());
+	let mut provers = Vec::with_capacity(claim_shapes.len());
+	for (&evaluation_order, claim_shape) in iter::zip(&evaluation_orders, &claim_shapes) {
+		let (mles_i, claim, prover) = make_test_sumcheck::<FE, FDomain, P, PE, _>(
+			evaluation_order,
+			claim_shape,
+			&mut rng,
+			&domain_factory,
+			&backend,
+		);
+		mles.push(mles_i);
+		claims.push(claim);
+		provers.push(prover);
+	}
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let prover_output =
+		batch_prove_mixed(provers, &mut prover_transcript).expect("failed to prove sumcheck");
+
+	let mut verifier_transcript = prover_transcript.into_verifier().unwrap();
+	let verifier_output =
+		batch_verify_mixed(&evaluation_orders, &claims, &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+
+	assert_eq!(prover_output, verifier_output);
+
+	for (mles_i, eval_point, multilinear_evals_i) in
+		izip!(mles, &verifier_output.eval_points, &verifier_output.multilinear_evals)
+	{
+		let query = MultilinearQuery::<PE>::expand(eval_point);
+		for (mle, &eval) in iter::zip(mles_i, multilinear_evals_i) {
+			assert_eq!(mle.evaluate(&query).unwrap(), eval);
+		}
+	}
+}
+
 fn prove_verify_batch_front_loaded(claim_shapes: &[TestSumcheckClaimShape]) {
 	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
 		test_prove_verify_batch_front_loaded_with_evaluation_order(evaluation_order, claim_shapes);
@@ -500,7 +653,7 @@ fn test_prove_verify_batch_front_loaded_with_evaluation_order(
 
 	let _batch_prover_output = batch_prover.run(&mut transcript).unwrap();
 
-	let mut transcript = transcript.into_verifier();
+	let mut transcript = transcript.into_verifier().unwrap();
 
 	let verifier = FrontLoadedBatchVerifier::new(&claims, &mut transcript).unwrap();
 
@@ -543,3 +696,231 @@ fn test_prove_verify_batch_front_loaded() {
 		},
 	]);
 }
+
+fn prove_verify_batch_univariate_skip(claim_shapes: &[TestSumcheckClaimShape], skip_rounds: usize) {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FBase = BinaryField32b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let backend = make_portable_backend();
+
+	let mut mles = Vec::with_capacity(claim_shapes.len());
+	let mut claims = Vec::with_capacity(claim_shapes.len());
+	let mut provers = Vec::with_capacity(claim_shapes.len());
+	for claim_shape in claim_shapes {
+		let TestSumcheckClaimShape { n_vars, degree } = claim_shape.clone();
+		let mles_i = generate_random_multilinears::<P>(&mut rng, n_vars, 3);
+		let multilins = mles_i
+			.clone()
+			.into_iter()
+			.map(MLEEmbeddingAdapter::<_, PE, _>::from)
+			.collect::<Vec<_>>();
+
+		let product_composition = TestProductComposition::new(3);
+		let product_sum = compute_composite_sum(&multilins, &product_composition);
+		let power_composition =
+			index_composition(&[0, 1, 2], [1], PowerComposition { exponent: degree }).unwrap();
+		let power_sum = compute_composite_sum(&multilins, &power_composition);
+
+		let claim_composite_sums = vec![
+			CompositeSumClaim {
+				composition: Arc::new(product_composition.clone()) as Arc<dyn CompositionPoly<FE>>,
+				sum: product_sum,
+			},
+			CompositeSumClaim {
+				composition: Arc::new(power_composition.clone()),
+				sum: power_sum,
+			},
+		];
+		let prover_composite_sums = vec![
+			(
+				Arc::new(product_composition.clone()) as Arc<dyn CompositionPoly<P>>,
+				CompositeSumClaim {
+					composition: Arc::new(product_composition) as Arc<dyn CompositionPoly<PE>>,
+					sum: product_sum,
+				},
+			),
+			(
+				Arc::new(power_composition.clone()) as Arc<dyn CompositionPoly<P>>,
+				CompositeSumClaim {
+					composition: Arc::new(power_composition),
+					sum: power_sum,
+				},
+			),
+		];
+
+		mles.push(mles_i);
+		claims.push(SumcheckClaim::new(n_vars, 3, claim_composite_sums).unwrap());
+		provers.push(
+			UnivariateSkipProver::<FDomain, FBase, _, _, _, _, _>::new(
+				multilins,
+				prover_composite_sums,
+				&backend,
+			)
+			.unwrap(),
+		);
+	}
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let prover_output = batch_prove_univariate_skip(provers, skip_rounds, &mut prover_transcript)
+		.expect("failed to prove sumcheck");
+
+	let prover_sample = CanSample::<FE>::sample(&mut prover_transcript);
+
+	let mut verifier_transcript = prover_transcript.into_verifier().unwrap();
+	let verifier_output =
+		batch_verify_univariate_skip(&claims, skip_rounds, &mut verifier_transcript).unwrap();
+
+	assert_eq!(prover_output, verifier_output);
+
+	// Check that challengers are in the same state
+	assert_eq!(prover_sample, CanSample::<FE>::sample(&mut verifier_transcript));
+
+	let BatchUnivariateSkipOutput {
+		skipped_challenges,
+		unskipped_challenges,
+		concat_multilinear_evals,
+	} = verifier_output;
+
+	let mut multilinear_evals = concat_multilinear_evals.into_iter();
+	for (claim_shape, mles_i) in iter::zip(claim_shapes, mles) {
+		let n_unskipped = claim_shape.n_vars - skip_rounds;
+		let eval_point = [
+			skipped_challenges.as_slice(),
+			&unskipped_challenges[unskipped_challenges.len() - n_unskipped..],
+		]
+		.concat();
+		let query = MultilinearQuery::<PE>::expand(&eval_point);
+		for mle in mles_i {
+			assert_eq!(mle.evaluate(&query).unwrap(), multilinear_evals.next().unwrap());
+		}
+	}
+	assert!(multilinear_evals.next().is_none());
+}
+
+#[test]
+fn test_prove_verify_batch_univariate_skip() {
+	for skip_rounds in 0..=3 {
+		prove_verify_batch_univariate_skip(
+			&[
+				TestSumcheckClaimShape {
+					n_vars: 3,
+					degree: 2,
+				},
+				TestSumcheckClaimShape {
+					n_vars: 5,
+					degree: 4,
+				},
+				TestSumcheckClaimShape {
+					n_vars: 7,
+					degree: 1,
+				},
+			],
+			skip_rounds,
+		);
+	}
+}
+
+#[test]
+fn test_batch_univariate_skip_rejects_modified_round_polynomial() {
+	type P = PackedBinaryField4x32b;
+	type FDomain = BinaryField8b;
+	type FBase = BinaryField32b;
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField1x128b;
+
+	let n_vars = 6;
+	let skip_rounds = 3;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+
+	let multilins = generate_random_multilinears::<P>(&mut rng, n_vars, 3)
+		.into_iter()
+		.map(MLEEmbeddingAdapter::<_, PE, _>::from)
+		.collect::<Vec<_>>();
+	let composition = TestProductComposition::new(3);
+	let sum = compute_composite_sum(&multilins, &composition);
+
+	let claim = SumcheckClaim::new(
+		n_vars,
+		3,
+		vec![CompositeSumClaim {
+			composition: Arc::new(composition.clone()) as Arc<dyn CompositionPoly<FE>>,
+			sum,
+		}],
+	)
+	.unwrap();
+	let prover = UnivariateSkipProver::<FDomain, FBase, _, _, _, _, _>::new(
+		multilins,
+		[(composition.clone(), CompositeSumClaim { composition, sum })],
+		&backend,
+	)
+	.unwrap();
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	batch_prove_univariate_skip(vec![prover], skip_rounds, &mut prover_transcript).unwrap();
+	let mut proof = prover_transcript.finalize().unwrap();
+
+	// The univariate round polynomial is the first message of the proof. Modify its evaluation at
+	// the first point outside of the skipped subspace, which leaves its sum over the subspace
+	// unchanged.
+	let offset = (1 << skip_rounds) * size_of::<FE>();
+	proof[offset] ^= 1;
+
+	let mut verifier_transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+	assert!(batch_verify_univariate_skip(&[claim], skip_rounds, &mut verifier_transcript).is_err());
+}
+
+#[derive(Debug, Clone)]
+struct UnderstatedDegreeComposition;
+
+impl<P: PackedField> CompositionPoly<P> for UnderstatedDegreeComposition {
+	fn n_vars(&self) -> usize {
+		2
+	}
+
+	fn degree(&self) -> usize {
+		1
+	}
+
+	fn expression(&self) -> ArithCircuit<P::Scalar> {
+		ArithCircuit::var(0) * ArithCircuit::var(1)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		Ok(query[0] * query[1])
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+}
+
+#[test]
+fn test_claim_rejects_incorrect_composition_degree() {
+	type F = BinaryField128b;
+
+	let result = SumcheckClaim::new(
+		4,
+		2,
+		vec![CompositeSumClaim {
+			composition: UnderstatedDegreeComposition,
+			sum: F::ZERO,
+		}],
+	);
+	assert!(matches!(
+		result,
+		Err(Error::IncorrectCompositionDegree {
+			declared: 1,
+			expected: 2
+		})
+	));
+
+	let result = ZerocheckClaim::<F, _>::new(4, 2, vec![UnderstatedDegreeComposition]);
+	assert!(matches!(result, Err(Error::IncorrectCompositionDegree { .. })));
+}
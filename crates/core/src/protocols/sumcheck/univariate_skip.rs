@@ -0,0 +1,206 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The batched sumcheck protocol with a univariate skip.
+//!
+//! Instead of one round per variable, the first `skip_rounds` variables are reduced in a single
+//! univariate round. The boolean hypercube over the skipped variables is identified with a binary
+//! subspace $D$ of dimension `skip_rounds`, and every multilinear $M$ is replaced with its
+//! univariatized counterpart
+//!
+//! $$
+//! \hat{M}(\hat{u}, x_1, \ldots, x_{n-k}) = \sum_{u \in \{0, 1\}^k} M(u, x_1, \ldots, x_{n-k})
+//! \cdot L_u(\hat{u}),
+//! $$
+//!
+//! where $L_u$ are the Lagrange basis polynomials over $D$. The prover sends the univariate round
+//! polynomial, which sums over the multilinear variables, in Lagrange basis over a domain that
+//! extends $D$; the verifier checks its sum over $D$ against the claimed sums and samples the
+//! univariate challenge $\hat{u}$.
+//!
+//! The remaining variables are reduced with a front-loaded batched sumcheck, and the resulting
+//! evaluations of the univariatized multilinears are reduced to regular multilinear evaluations
+//! with the same univariatizing reduction sumcheck as the zerocheck. See
+//! [`verify_zerocheck::batch_verify`](super::verify_zerocheck::batch_verify) for the analogous
+//! zerocheck protocol.
+
+use binius_field::{Field, TowerField, util::inner_product_unchecked};
+use binius_math::{BinarySubspace, CompositionPoly, EvaluationDomain};
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize, sorting::is_sorted_ascending};
+use tracing::instrument;
+
+use super::{
+	BatchSumcheckOutput, SumcheckClaim,
+	common::batch_weighted_value,
+	error::{Error, VerificationError},
+	front_loaded,
+	verify_zerocheck::domain_size,
+	zerocheck::{univariatizing_reduction_claim, verify_reduction_sumcheck_output},
+};
+use crate::{
+	fiat_shamir::{CanSample, Challenger},
+	transcript::VerifierTranscript,
+};
+
+/// Output of the batched sumcheck reduction with a univariate skip.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BatchUnivariateSkipOutput<F: Field> {
+	/// Challenges corresponding to the low indexed variables skipped by the univariate round.
+	/// Assigned by the univariatizing reduction sumcheck.
+	pub skipped_challenges: Vec<F>,
+	/// Challenges corresponding to the high indexed variables, reduced by the front-loaded
+	/// sumcheck. A claim over $n$ variables is evaluated at the last $n - k$ of these.
+	pub unskipped_challenges: Vec<F>,
+	/// Multilinear evals of all batched claims, concatenated in the non-descending `n_vars` order.
+	pub concat_multilinear_evals: Vec<F>,
+}
+
+/// Size of the Lagrange domain of the univariate round for the given claims.
+pub(super) fn univariate_domain_size<F: Field, Composition: CompositionPoly<F>>(
+	claims: &[SumcheckClaim<F, Composition>],
+	skip_rounds: usize,
+) -> usize {
+	claims
+		.iter()
+		.map(|claim| domain_size(claim.max_individual_degree().max(1), skip_rounds))
+		.max()
+		.unwrap_or(0)
+}
+
+/// Returns the evaluation domain of the univariate round, whose first `2^skip_rounds` points are
+/// the skipped subspace.
+pub(super) fn univariate_domain<F: TowerField>(
+	domain_size: usize,
+) -> Result<EvaluationDomain<F>, Error> {
+	let subspace =
+		BinarySubspace::<F::Canonical>::with_dim(log2_ceil_usize(domain_size))?.isomorphic::<F>();
+	Ok(EvaluationDomain::from_points(subspace.iter().take(domain_size).collect(), false)?)
+}
+
+/// Verify a batched sumcheck protocol execution with a univariate skip.
+///
+/// The claims must be sorted in non-descending order by number of variables, and each claim must
+/// have at least `skip_rounds` variables. The protocol consists of three stages:
+///
+///  * A univariate round over the `skip_rounds` low indexed variables, shared by all claims. The
+///    prover sends the batched round polynomial as its evaluations over a domain of `d *
+///    2^skip_rounds` points, where `d` is the maximum individual degree of the compositions.
+///  * A front-loaded batched sumcheck over the remaining high indexed variables, which is sound
+///    because the batched sum is known after the univariate round.
+///  * The univariatizing reduction sumcheck, which reduces the evaluations of the univariatized
+///    multilinears to evaluations of the original multilinears.
+#[instrument(skip_all, level = "debug")]
+pub fn batch_verify<F, Composition, Challenger_>(
+	claims: &[SumcheckClaim<F, Composition>],
+	skip_rounds: usize,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<BatchUnivariateSkipOutput<F>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F> + Clone,
+	Challenger_: Challenger,
+{
+	// Check that the claims are in non-descending order by n_vars
+	if !is_sorted_ascending(claims.iter().map(|claim| claim.n_vars())) {
+		bail!(Error::ClaimsOutOfOrder);
+	}
+
+	if claims
+		.first()
+		.is_some_and(|claim| claim.n_vars() < skip_rounds)
+	{
+		bail!(VerificationError::IncorrectSkippedRoundsCount);
+	}
+
+	if claims.is_empty() {
+		return Ok(BatchUnivariateSkipOutput {
+			skipped_challenges: Vec::new(),
+			unskipped_challenges: Vec::new(),
+			concat_multilinear_evals: Vec::new(),
+		});
+	}
+
+	let batch_coeffs = transcript.sample_vec(claims.len());
+	let claimed_sum = claims
+		.iter()
+		.zip(&batch_coeffs)
+		.map(|(claim, &batch_coeff)| {
+			batch_weighted_value(
+				batch_coeff,
+				claim
+					.composite_sums()
+					.iter()
+					.map(|composite_claim| composite_claim.sum),
+			)
+		})
+		.sum::<F>();
+
+	// Read the univariate round polynomial in Lagrange basis and check its sum over the skipped
+	// subspace, which is a prefix of the evaluation domain.
+	let domain_size = univariate_domain_size(claims, skip_rounds);
+	let round_evals = transcript.message().read_scalar_slice::<F>(domain_size)?;
+	if round_evals[..1 << skip_rounds].iter().copied().sum::<F>() != claimed_sum {
+		bail!(VerificationError::IncorrectUnivariateSkipSum);
+	}
+
+	let univariate_challenge = transcript.sample();
+
+	let evaluation_domain = univariate_domain::<F>(domain_size)?;
+	let sum = inner_product_unchecked::<F, F>(
+		round_evals,
+		evaluation_domain.lagrange_evals(univariate_challenge),
+	);
+
+	// Front-loaded batching of the sumchecks over the unskipped variables. The sums of the
+	// reduced claims are not known individually; only the batched sum is used.
+	let reduced_claims = claims
+		.iter()
+		.map(|claim| {
+			SumcheckClaim::new(
+				claim.n_vars() - skip_rounds,
+				claim.n_multilinears(),
+				claim.composite_sums().to_vec(),
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let batch_sumcheck_verifier =
+		front_loaded::BatchVerifier::new_prebatched(batch_coeffs, sum, &reduced_claims)?;
+	let BatchSumcheckOutput {
+		challenges: mut unskipped_challenges,
+		multilinear_evals: univariatized_multilinear_evals,
+	} = batch_sumcheck_verifier.run(transcript)?;
+
+	// Reverse challenges since folding high-to-low
+	unskipped_challenges.reverse();
+
+	// Univariatizing reduction sumcheck
+	let reduction_claim =
+		univariatizing_reduction_claim(skip_rounds, &univariatized_multilinear_evals)?;
+
+	let univariatize_verifier =
+		front_loaded::BatchVerifier::new(&[reduction_claim.clone()], transcript)?;
+	let mut reduction_sumcheck_output = univariatize_verifier.run(transcript)?;
+
+	// Reverse challenges since folding high-to-low
+	reduction_sumcheck_output.challenges.reverse();
+
+	let BatchSumcheckOutput {
+		challenges: skipped_challenges,
+		multilinear_evals: mut concat_multilinear_evals,
+	} = verify_reduction_sumcheck_output(
+		&reduction_claim,
+		skip_rounds,
+		univariate_challenge,
+		reduction_sumcheck_output,
+	)?;
+
+	let concat_multilinear_evals = concat_multilinear_evals
+		.pop()
+		.expect("multilinear_evals.len() == 1");
+
+	Ok(BatchUnivariateSkipOutput {
+		skipped_challenges,
+		unskipped_challenges,
+		concat_multilinear_evals,
+	})
+}
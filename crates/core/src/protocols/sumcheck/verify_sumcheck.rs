@@ -7,8 +7,12 @@ use itertools::izip;
 
 use super::{
 	RoundCoeffs,
-	common::{BatchSumcheckOutput, RoundProof, SumcheckClaim, batch_weighted_value},
+	common::{
+		BatchSumcheckMixedOutput, BatchSumcheckOutput, RoundProof, SumcheckClaim,
+		batch_weighted_value, claim_eval_point,
+	},
 	error::{Error, VerificationError},
+	zk::BatchSumcheckZkOutput,
 };
 use crate::{
 	fiat_shamir::{CanSample, Challenger},
@@ -32,6 +36,117 @@ pub fn batch_verify<F, Composition, Challenger_>(
 	claims: &[SumcheckClaim<F, Composition>],
 	transcript: &mut VerifierTranscript<Challenger_>,
 ) -> Result<BatchSumcheckOutput<F>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	let (output, _) = batch_verify_with_mask(evaluation_order, claims, None, transcript)?;
+	Ok(output)
+}
+
+/// Verify a zero-knowledge batched sumcheck protocol execution.
+///
+/// This is the verifier counterpart of [`crate::protocols::sumcheck::batch_prove_zk`]. The
+/// round polynomials are masked with a random polynomial of individual degree `mask_degree`,
+/// which the prover committed to before the protocol.
+///
+/// The returned mask evaluation is only claimed by the prover; the caller must check it against
+/// the commitment to the mask for the verification to be sound.
+pub fn batch_verify_zk<F, Composition, Challenger_>(
+	evaluation_order: EvaluationOrder,
+	claims: &[SumcheckClaim<F, Composition>],
+	mask_degree: usize,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<BatchSumcheckZkOutput<F>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	let (output, mask_eval) =
+		batch_verify_with_mask(evaluation_order, claims, Some(mask_degree), transcript)?;
+	Ok(BatchSumcheckZkOutput {
+		output,
+		mask_eval: mask_eval.expect("mask_eval is Some when mask_degree is Some"),
+	})
+}
+
+/// Verify a batched sumcheck protocol execution where the claims may have different evaluation
+/// orders.
+///
+/// `evaluation_orders` gives the evaluation order of each claim. The transcript is the same as in
+/// [`batch_verify`]; each claim is reduced to an evaluation point in its own order, as described
+/// in [`claim_eval_point`].
+///
+/// ## Throws
+///
+/// * [`Error::IncorrectNumberOfEvaluationOrders`] if `evaluation_orders` and `claims` have
+///   different lengths
+pub fn batch_verify_mixed<F, Composition, Challenger_>(
+	evaluation_orders: &[EvaluationOrder],
+	claims: &[SumcheckClaim<F, Composition>],
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<BatchSumcheckMixedOutput<F>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	if evaluation_orders.len() != claims.len() {
+		bail!(Error::IncorrectNumberOfEvaluationOrders {
+			expected: claims.len(),
+		});
+	}
+
+	let (challenges, multilinear_evals, _) = batch_verify_rounds(claims, None, transcript)?;
+
+	let eval_points = izip!(evaluation_orders, claims)
+		.map(|(&evaluation_order, claim)| {
+			claim_eval_point(evaluation_order, &challenges, claim.n_vars())
+		})
+		.collect();
+
+	Ok(BatchSumcheckMixedOutput {
+		challenges,
+		eval_points,
+		multilinear_evals,
+	})
+}
+
+fn batch_verify_with_mask<F, Composition, Challenger_>(
+	evaluation_order: EvaluationOrder,
+	claims: &[SumcheckClaim<F, Composition>],
+	mask_degree: Option<usize>,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<(BatchSumcheckOutput<F>, Option<F>), Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	let (mut challenges, multilinear_evals, mask_eval) =
+		batch_verify_rounds(claims, mask_degree, transcript)?;
+
+	if EvaluationOrder::HighToLow == evaluation_order {
+		challenges.reverse();
+	}
+
+	let output = BatchSumcheckOutput {
+		challenges,
+		multilinear_evals,
+	};
+
+	Ok((output, mask_eval))
+}
+
+/// Verifies the batched sumcheck rounds, returning the challenges in the order they were sampled,
+/// the multilinear evaluations of each claim, and the mask evaluation if a mask is used.
+fn batch_verify_rounds<F, Composition, Challenger_>(
+	claims: &[SumcheckClaim<F, Composition>],
+	mask_degree: Option<usize>,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<(Vec<F>, Vec<Vec<F>>, Option<F>), Error>
 where
 	F: TowerField,
 	Composition: CompositionPoly<F>,
@@ -51,6 +166,19 @@ where
 	let mut challenges = Vec::with_capacity(n_rounds);
 	let mut sum = F::ZERO;
 	let mut max_degree = 0; // Maximum individual degree of the active claims
+
+	// In zero-knowledge mode, the masked sum is batched in before any claim.
+	let mask_coeff = match mask_degree {
+		Some(mask_degree) => {
+			let mask_sum = transcript.message().read_scalar::<F>()?;
+			let mask_coeff: F = transcript.sample();
+			sum += mask_coeff * mask_sum;
+			max_degree = mask_degree;
+			Some(mask_coeff)
+		}
+		None => None,
+	};
+
 	for round_no in 0..n_rounds {
 		let n_vars = n_rounds - round_no;
 
@@ -108,24 +236,26 @@ where
 		multilinear_evals.push(evals);
 	}
 
-	let expected_sum = compute_expected_batch_composite_evaluation_multi_claim(
+	let mut expected_sum = compute_expected_batch_composite_evaluation_multi_claim(
 		batch_coeffs,
 		claims,
 		&multilinear_evals,
 	)?;
 
+	let mask_eval = match mask_coeff {
+		Some(mask_coeff) => {
+			let mask_eval = reader.read_scalar::<F>()?;
+			expected_sum += mask_coeff * mask_eval;
+			Some(mask_eval)
+		}
+		None => None,
+	};
+
 	if sum != expected_sum {
 		return Err(VerificationError::IncorrectBatchEvaluation.into());
 	}
 
-	if EvaluationOrder::HighToLow == evaluation_order {
-		challenges.reverse();
-	}
-
-	Ok(BatchSumcheckOutput {
-		challenges,
-		multilinear_evals,
-	})
+	Ok((challenges, multilinear_evals, mask_eval))
 }
 
 pub fn compute_expected_batch_composite_evaluation_single_claim<F: Field, Composition>(
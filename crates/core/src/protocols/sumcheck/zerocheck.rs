@@ -19,7 +19,7 @@ use crate::{
 	polynomial::Error as PolynomialError,
 	protocols::sumcheck::{
 		BatchSumcheckOutput, CompositeSumClaim, SumcheckClaim, VerificationError,
-		eq_ind::EqIndSumcheckClaim,
+		eq_ind::EqIndSumcheckClaim, validate_composition,
 	},
 };
 
@@ -43,12 +43,7 @@ where
 		composite_zeros: Vec<Composition>,
 	) -> Result<Self, Error> {
 		for composition in &composite_zeros {
-			if composition.n_vars() != n_multilinears {
-				bail!(Error::InvalidComposition {
-					actual: composition.n_vars(),
-					expected: n_multilinears,
-				});
-			}
+			validate_composition(composition, n_multilinears)?;
 		}
 		Ok(Self {
 			n_vars,
@@ -432,7 +427,7 @@ mod tests {
 				)
 				.unwrap();
 
-			let mut verifier_proof = proof.into_verifier();
+			let mut verifier_proof = proof.into_verifier().unwrap();
 
 			let verifier_zerocheck_output = sumcheck::batch_verify_zerocheck(
 				&zerocheck_claims,
@@ -0,0 +1,233 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Masking polynomials for the zero-knowledge variant of the batched sumcheck protocol.
+//!
+//! The standard sumcheck round polynomials are deterministic functions of the witness and leak
+//! information about it. Following the approach of [Libra], the zero-knowledge variant has the
+//! prover commit to a random masking polynomial $g$ up front and prove the sum of
+//! $f + \rho \cdot g$ instead of $f$, where $\rho$ is a verifier challenge sampled after the sum
+//! of $g$ is sent. The round polynomials of the combined instance are then uniformly random
+//! subject to the sumcheck consistency checks.
+//!
+//! The additive mask $\sum_i g_i(X_i)$ used in [Libra] does not work over binary fields, because
+//! its hypercube sums vanish in characteristic 2. Instead, we use
+//!
+//! $$
+//! g(X_0, \ldots, X_{n-1}) = \sum_{i=0}^{n-1} g_i(X_i) \prod_{k=i+1}^{n-1} X_k,
+//! $$
+//!
+//! where the $g_i$ are random univariate polynomials of degree $d$ and the variables are indexed
+//! in the order they are bound by the sumcheck rounds. The hypercube sum of $g$ is
+//! $g_0(0) + g_0(1)$, and the round polynomials of $g$ have the simple closed form computed in
+//! [`MaskRoundState::round_coeffs`].
+//!
+//! The final evaluation of the mask at the challenge point is sent by the prover and returned by
+//! the verifier as a claim. The caller is responsible for checking this claim against the
+//! commitment to the mask. The multilinear evaluations output by the sumcheck are not masked; the
+//! caller must also mask the witness multilinears if their evaluations are sensitive.
+//!
+//! [Libra]: <https://eprint.iacr.org/2019/317>
+
+use binius_field::Field;
+use binius_math::evaluate_univariate;
+use rand::RngCore;
+
+use super::common::{BatchSumcheckOutput, RoundCoeffs};
+
+/// Output of the zero-knowledge batched sumcheck reduction.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BatchSumcheckZkOutput<F: Field> {
+	/// The output of the underlying batched sumcheck reduction.
+	pub output: BatchSumcheckOutput<F>,
+	/// The claimed evaluation of the mask at the sumcheck challenges, in round order.
+	///
+	/// The verifier must check this claim against the commitment to the mask.
+	pub mask_eval: F,
+}
+
+/// A random masking polynomial for the zero-knowledge sumcheck.
+///
+/// See the [module documentation](self) for the structure of the polynomial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SumcheckMask<F: Field> {
+	degree: usize,
+	/// The coefficients of the univariate polynomials $g_i$ in monomial basis, in round order.
+	univariates: Vec<Vec<F>>,
+}
+
+impl<F: Field> SumcheckMask<F> {
+	/// Constructs a mask from the coefficients of its univariate polynomials.
+	///
+	/// ## Preconditions
+	///
+	/// * every univariate has exactly `degree + 1` coefficients
+	/// * `degree` is at least 1
+	pub fn new(degree: usize, univariates: Vec<Vec<F>>) -> Self {
+		assert!(degree >= 1, "precondition: degree must be at least 1");
+		assert!(
+			univariates.iter().all(|coeffs| coeffs.len() == degree + 1),
+			"precondition: every univariate must have degree + 1 coefficients"
+		);
+		Self {
+			degree,
+			univariates,
+		}
+	}
+
+	/// Samples a uniformly random mask over `n_vars` variables with the given individual degree.
+	///
+	/// The degree must be at least the maximum individual degree of the sumcheck claims being
+	/// masked for the round polynomials to be fully masked.
+	pub fn random(n_vars: usize, degree: usize, mut rng: impl RngCore) -> Self {
+		let univariates = (0..n_vars)
+			.map(|_| (0..=degree).map(|_| F::random(&mut rng)).collect())
+			.collect();
+		Self::new(degree, univariates)
+	}
+
+	/// The number of variables of the mask.
+	pub fn n_vars(&self) -> usize {
+		self.univariates.len()
+	}
+
+	/// The individual degree of the mask.
+	pub const fn degree(&self) -> usize {
+		self.degree
+	}
+
+	/// The univariate polynomials $g_i$, in round order.
+	pub fn univariates(&self) -> &[Vec<F>] {
+		&self.univariates
+	}
+
+	/// The sum of the mask over the boolean hypercube.
+	pub fn sum(&self) -> F {
+		self.univariates
+			.first()
+			.map(|coeffs| coeffs.iter().skip(1).sum::<F>())
+			.unwrap_or(F::ZERO)
+	}
+
+	/// Evaluates the mask at a point, given in round order.
+	///
+	/// ## Preconditions
+	///
+	/// * `point` has length equal to [`Self::n_vars`]
+	pub fn evaluate(&self, point: &[F]) -> F {
+		assert_eq!(point.len(), self.n_vars(), "precondition: point length must equal n_vars");
+		let mut state = MaskRoundState::new(self);
+		for &challenge in point {
+			state.fold(challenge);
+		}
+		state.finish()
+	}
+}
+
+/// Tracks the partial evaluation of a [`SumcheckMask`] through the sumcheck rounds.
+///
+/// After binding $X_0, \ldots, X_{j-1}$ to $r_0, \ldots, r_{j-1}$, the accumulator holds
+///
+/// $$
+/// A_j = \sum_{i < j} g_i(r_i) \prod_{k=i+1}^{j-1} r_k,
+/// $$
+///
+/// and the round polynomial is $A_j X + g_j(X) + g_{j+1}(0) + g_{j+1}(1)$, where the last two
+/// terms are omitted in the final round.
+#[derive(Debug)]
+pub(super) struct MaskRoundState<'a, F: Field> {
+	mask: &'a SumcheckMask<F>,
+	round: usize,
+	acc: F,
+}
+
+impl<'a, F: Field> MaskRoundState<'a, F> {
+	pub fn new(mask: &'a SumcheckMask<F>) -> Self {
+		Self {
+			mask,
+			round: 0,
+			acc: F::ZERO,
+		}
+	}
+
+	/// Returns the coefficients of the mask round polynomial for the current round.
+	pub fn round_coeffs(&self) -> RoundCoeffs<F> {
+		let mut coeffs = self.mask.univariates[self.round].clone();
+		coeffs[1] += self.acc;
+		if let Some(next) = self.mask.univariates.get(self.round + 1) {
+			coeffs[0] += next.iter().skip(1).sum::<F>();
+		}
+		RoundCoeffs(coeffs)
+	}
+
+	/// Binds the current round variable to the verifier challenge.
+	pub fn fold(&mut self, challenge: F) {
+		let univariate = &self.mask.univariates[self.round];
+		self.acc = self.acc * challenge + evaluate_univariate(univariate, challenge);
+		self.round += 1;
+	}
+
+	/// Returns the evaluation of the mask at the folding challenges.
+	pub fn finish(self) -> F {
+		debug_assert_eq!(self.round, self.mask.n_vars());
+		self.acc
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	type F = BinaryField128b;
+
+	fn evaluate_naive(mask: &SumcheckMask<F>, point: &[F]) -> F {
+		mask.univariates()
+			.iter()
+			.enumerate()
+			.map(|(i, coeffs)| {
+				evaluate_univariate(coeffs, point[i]) * point[i + 1..].iter().product::<F>()
+			})
+			.sum()
+	}
+
+	#[test]
+	fn test_mask_sum_and_round_polynomials() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 4;
+		let mask = SumcheckMask::<F>::random(n_vars, 3, &mut rng);
+
+		let hypercube_sum = (0..1 << n_vars)
+			.map(|index| {
+				let point = (0..n_vars)
+					.map(|i| {
+						if (index >> i) & 1 == 1 {
+							F::ONE
+						} else {
+							F::ZERO
+						}
+					})
+					.collect::<Vec<_>>();
+				evaluate_naive(&mask, &point)
+			})
+			.sum::<F>();
+		assert_eq!(mask.sum(), hypercube_sum);
+
+		let challenges = (0..n_vars).map(|_| F::random(&mut rng)).collect::<Vec<_>>();
+
+		let mut state = MaskRoundState::new(&mask);
+		let mut sum = mask.sum();
+		for &challenge in &challenges {
+			let coeffs = state.round_coeffs();
+			assert_eq!(
+				evaluate_univariate(&coeffs.0, F::ZERO) + evaluate_univariate(&coeffs.0, F::ONE),
+				sum
+			);
+			sum = evaluate_univariate(&coeffs.0, challenge);
+			state.fold(challenge);
+		}
+		assert_eq!(state.finish(), sum);
+		assert_eq!(mask.evaluate(&challenges), evaluate_naive(&mask, &challenges));
+	}
+}
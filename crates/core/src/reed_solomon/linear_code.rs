@@ -0,0 +1,177 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Linear codes and their interleavings.
+
+use binius_field::{BinaryField, PackedExtension};
+use binius_ntt::AdditiveNTT;
+use getset::CopyGetters;
+
+use super::{ReedSolomonCode, error::Error};
+
+/// A linear block code over a binary field.
+///
+/// The dimension and block length are counted in symbols of the code alphabet. For an interleaved
+/// code, a symbol is a tuple of `1 << log_batch_size()` field elements.
+pub trait LinearCode {
+	/// The field the code is defined over.
+	type Field: BinaryField;
+
+	/// The base-2 log of the dimension.
+	fn log_dim(&self) -> usize;
+
+	/// The base-2 log of the block length.
+	fn log_len(&self) -> usize;
+
+	/// The base-2 log of the number of messages interleaved in a codeword.
+	fn log_batch_size(&self) -> usize {
+		0
+	}
+
+	/// The minimum Hamming distance between two distinct codewords, in alphabet symbols.
+	fn min_dist(&self) -> usize;
+
+	/// A lower bound on the minimum distance relative to the block length.
+	fn relative_distance(&self) -> f64 {
+		self.min_dist() as f64 / self.len() as f64
+	}
+
+	/// The dimension.
+	fn dim(&self) -> usize {
+		1 << self.log_dim()
+	}
+
+	/// The block length.
+	fn len(&self) -> usize {
+		1 << self.log_len()
+	}
+
+	/// The base-2 log of the reciprocal of the rate.
+	fn log_inv_rate(&self) -> usize {
+		self.log_len() - self.log_dim()
+	}
+}
+
+impl<F: BinaryField> LinearCode for ReedSolomonCode<F> {
+	type Field = F;
+
+	fn log_dim(&self) -> usize {
+		Self::log_dim(self)
+	}
+
+	fn log_len(&self) -> usize {
+		Self::log_len(self)
+	}
+
+	/// Reed–Solomon codes are maximum distance separable.
+	fn min_dist(&self) -> usize {
+		Self::len(self) - Self::dim(self) + 1
+	}
+
+	/// The designed distance $1 - \rho$, which is slightly below the true relative distance.
+	fn relative_distance(&self) -> f64 {
+		1.0 - 2.0f64.powi(-(Self::log_inv_rate(self) as i32))
+	}
+}
+
+/// The `1 << log_batch_size`-fold interleaving of a Reed–Solomon code.
+///
+/// A codeword is the encoding of `1 << log_batch_size` messages, whose symbols are the tuples of
+/// the symbols of the individual encodings at the same position. The interleaved code has the
+/// dimension, block length and distance of the inner code over this larger alphabet, so batched
+/// short messages are tested for proximity as a single codeword.
+#[derive(Debug, Clone, Copy, CopyGetters)]
+pub struct InterleavedCode<'a, F: BinaryField> {
+	#[get_copy = "pub"]
+	code: &'a ReedSolomonCode<F>,
+	log_batch_size: usize,
+}
+
+impl<'a, F: BinaryField> InterleavedCode<'a, F> {
+	pub const fn new(code: &'a ReedSolomonCode<F>, log_batch_size: usize) -> Self {
+		Self {
+			code,
+			log_batch_size,
+		}
+	}
+
+	/// Encodes the interleaved messages in-place in a provided buffer.
+	///
+	/// See [`ReedSolomonCode::encode_ext_batch_inplace`].
+	pub fn encode_ext_inplace<PE: PackedExtension<F>, NTT: AdditiveNTT<F> + Sync>(
+		&self,
+		ntt: &NTT,
+		code: &mut [PE],
+	) -> Result<(), Error> {
+		self.code
+			.encode_ext_batch_inplace(ntt, code, self.log_batch_size)
+	}
+}
+
+impl<F: BinaryField> LinearCode for InterleavedCode<'_, F> {
+	type Field = F;
+
+	fn log_dim(&self) -> usize {
+		self.code.log_dim()
+	}
+
+	fn log_len(&self) -> usize {
+		self.code.log_len()
+	}
+
+	fn log_batch_size(&self) -> usize {
+		self.log_batch_size
+	}
+
+	/// Two distinct codewords differ in at least one of the interleaved codewords, so the distance
+	/// is the distance of the inner code.
+	fn min_dist(&self) -> usize {
+		LinearCode::min_dist(self.code)
+	}
+
+	fn relative_distance(&self) -> f64 {
+		LinearCode::relative_distance(self.code)
+	}
+}
+
+impl<F: BinaryField> ReedSolomonCode<F> {
+	/// Returns the `1 << log_batch_size`-fold interleaving of the code.
+	pub const fn interleaved(&self, log_batch_size: usize) -> InterleavedCode<'_, F> {
+		InterleavedCode::new(self, log_batch_size)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField32b, Field};
+	use binius_ntt::SingleThreadedNTT;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	type F = BinaryField32b;
+
+	#[test]
+	fn test_interleaved_code() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let code = ReedSolomonCode::<F>::new(5, 2).unwrap();
+		let interleaved = code.interleaved(3);
+		assert_eq!(LinearCode::dim(&interleaved), 1 << 5);
+		assert_eq!(LinearCode::len(&interleaved), 1 << 7);
+		assert_eq!(LinearCode::log_inv_rate(&interleaved), 2);
+		assert_eq!(interleaved.min_dist(), (1 << 7) - (1 << 5) + 1);
+
+		let ntt = SingleThreadedNTT::<F>::new(code.log_len()).unwrap();
+		let mut message = repeat_with(|| F::random(&mut rng))
+			.take(1 << (5 + 3))
+			.collect::<Vec<_>>();
+		message.resize(1 << (7 + 3), F::ZERO);
+
+		let mut expected = message.clone();
+		code.encode_ext_batch_inplace(&ntt, &mut expected, 3)
+			.unwrap();
+		interleaved.encode_ext_inplace(&ntt, &mut message).unwrap();
+		assert_eq!(message, expected);
+	}
+}
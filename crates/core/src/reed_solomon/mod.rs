@@ -1,7 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 mod error;
+pub mod linear_code;
 pub mod reed_solomon;
 
 pub use error::*;
+pub use linear_code::*;
 pub use reed_solomon::*;
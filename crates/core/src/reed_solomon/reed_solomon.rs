@@ -6,11 +6,12 @@
 
 use binius_field::{BinaryField, ExtensionField, PackedExtension, PackedField};
 use binius_math::BinarySubspace;
-use binius_ntt::{AdditiveNTT, NTTShape};
+use binius_ntt::{AdditiveNTT, NTTContext, NTTShape};
 use binius_utils::bail;
 use getset::{CopyGetters, Getters};
 
 use super::error::Error;
+use crate::metrics;
 
 /// [Reed–Solomon] codes over binary fields.
 ///
@@ -87,16 +88,19 @@ impl<F: BinaryField> ReedSolomonCode<F> {
 	///
 	/// The message symbols are interleaved in the buffer, which improves the cache-efficiency of
 	/// the encoding procedure. The interleaved codeword is stored in the buffer when the method
-	/// completes.
+	/// completes. The codeword consists of the evaluations on the coset with index `coset` of the
+	/// evaluation domain of the code in the domain of the NTT.
 	///
 	/// ## Throws
 	///
 	/// * If the `code` buffer does not have capacity for `len() << log_batch_size` field elements.
+	/// * If the NTT domain is too small to contain the coset.
 	fn encode_batch_inplace<P: PackedField<Scalar = F>, NTT: AdditiveNTT<F> + Sync>(
 		&self,
 		ntt: &NTT,
 		code: &mut [P],
 		log_batch_size: usize,
+		coset: u32,
 	) -> Result<(), Error> {
 		if ntt.subspace(ntt.log_domain_size() - self.log_len()) != self.subspace {
 			bail!(Error::EncoderSubspaceMismatch);
@@ -143,7 +147,11 @@ impl<F: BinaryField> ReedSolomonCode<F> {
 			log_y: self.log_len(),
 			..Default::default()
 		};
-		ntt.forward_transform(code, shape, 0, self.log_inv_rate)?;
+		ntt.forward_transform(code, shape, coset, self.log_inv_rate)?;
+		metrics::add_ntt_butterflies(
+			((self.log_len() - self.log_inv_rate) as u64)
+				<< (self.log_len() + log_batch_size).saturating_sub(1),
+		);
 		Ok(())
 	}
 
@@ -165,11 +173,108 @@ impl<F: BinaryField> ReedSolomonCode<F> {
 		ntt: &NTT,
 		code: &mut [PE],
 		log_batch_size: usize,
+	) -> Result<(), Error> {
+		self.encode_ext_batch_inplace_on_coset(ntt, code, log_batch_size, 0)
+	}
+
+	/// Encode a batch of interleaved messages of extension field elements in-place in a provided
+	/// buffer, evaluating on a coset of the evaluation domain.
+	///
+	/// The domain of the NTT is partitioned into cosets of the evaluation domain of the code, and
+	/// the codeword consists of the evaluations on the coset with index `coset`. The encodings on
+	/// distinct cosets are evaluations at disjoint points of the NTT domain: the encoding on coset
+	/// $c$ is the $c$-th chunk of length `len()` of the encoding of the same message with a code
+	/// over the whole NTT domain. Hence the codewords of messages encoded on distinct cosets can be
+	/// placed side by side in a single Merkle tree commitment without index collisions.
+	///
+	/// [`Self::encode_ext_batch_inplace`] encodes on the coset with index 0.
+	///
+	/// ## Preconditions
+	///
+	/// * `PE::Scalar::DEGREE` must be a power of two.
+	///
+	/// ## Throws
+	///
+	/// * If the `code` buffer does not have capacity for `len() << log_batch_size` field elements.
+	/// * If `coset` is not less than `2^(ntt.log_domain_size() - log_len())`.
+	pub fn encode_ext_batch_inplace_on_coset<PE: PackedExtension<F>, NTT: AdditiveNTT<F> + Sync>(
+		&self,
+		ntt: &NTT,
+		code: &mut [PE],
+		log_batch_size: usize,
+		coset: u32,
 	) -> Result<(), Error> {
 		self.encode_batch_inplace(
 			ntt,
 			PE::cast_bases_mut(code),
 			log_batch_size + PE::Scalar::LOG_DEGREE,
+			coset,
 		)
 	}
+
+	/// Encode a batch of interleaved messages of extension field elements in-place in a provided
+	/// buffer, with the NTT of `context` over the evaluation domain of the code.
+	///
+	/// The NTT and its twiddle factors are created on the first encoding over the domain, and
+	/// reused by the subsequent encodings with the same context. See
+	/// [`Self::encode_ext_batch_inplace`].
+	pub fn encode_ext_batch_inplace_with_context<PE: PackedExtension<F>>(
+		&self,
+		context: &NTTContext<F>,
+		code: &mut [PE],
+		log_batch_size: usize,
+	) -> Result<(), Error> {
+		let ntt = context.ntt_with_subspace(&self.subspace)?;
+		self.encode_ext_batch_inplace(&*ntt, code, log_batch_size)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField32b, Field};
+	use binius_ntt::SingleThreadedNTT;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	type F = BinaryField32b;
+
+	#[test]
+	fn test_coset_encodings_are_chunks_of_larger_encoding() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_dim = 6;
+		let log_inv_rate = 1;
+		let log_cosets = 2;
+
+		let ntt = SingleThreadedNTT::<F>::new(log_dim + log_inv_rate + log_cosets).unwrap();
+		let code = ReedSolomonCode::with_subspace(ntt.subspace(log_cosets), log_dim, log_inv_rate)
+			.unwrap();
+		let full_code = ReedSolomonCode::<F>::new(log_dim, log_inv_rate + log_cosets).unwrap();
+
+		let message = repeat_with(|| F::random(&mut rng))
+			.take(1 << log_dim)
+			.collect::<Vec<_>>();
+
+		let mut full_codeword = message.clone();
+		full_codeword.resize(full_code.len(), F::ZERO);
+		full_code
+			.encode_ext_batch_inplace(&ntt, &mut full_codeword, 0)
+			.unwrap();
+
+		for (coset, chunk) in full_codeword.chunks(code.len()).enumerate() {
+			let mut codeword = message.clone();
+			codeword.resize(code.len(), F::ZERO);
+			code.encode_ext_batch_inplace_on_coset(&ntt, &mut codeword, 0, coset as u32)
+				.unwrap();
+			assert_eq!(codeword, chunk);
+		}
+
+		let mut codeword = vec![F::ZERO; code.len()];
+		assert!(
+			code.encode_ext_batch_inplace_on_coset(&ntt, &mut codeword, 0, 1 << log_cosets)
+				.is_err()
+		);
+	}
 }
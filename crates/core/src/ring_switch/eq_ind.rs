@@ -59,11 +59,10 @@ where
 		mixing_coeff: F,
 	) -> Result<Self, Error> {
 		if row_batch_coeffs.coeffs.len() < F::DEGREE {
-			bail!(Error::InvalidArgs(
-				"RingSwitchEqInd::new expects row_batch_coeffs length greater than or equal to \
-				the extension degree"
-					.into()
-			));
+			bail!(Error::NotEnoughRowBatchCoeffs {
+				min: F::DEGREE,
+				actual: row_batch_coeffs.coeffs.len(),
+			});
 		}
 
 		Ok(Self {
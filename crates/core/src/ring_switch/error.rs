@@ -10,10 +10,10 @@ pub enum Error {
 	PackingDegreeNotSupported { kappa: usize },
 	#[error("cannot call function when argument tower heights do not match")]
 	TowerLevelMismatch,
-	#[error("invalid arguments: {0}")]
-	InvalidArgs(String),
-	#[error("invalid witness: {0}")]
-	InvalidWitness(String),
+	#[error("got {actual} row-batching coefficients, expected at least {min}")]
+	NotEnoughRowBatchCoeffs { min: usize, actual: usize },
+	#[error("got {actual} witness multilinears, expected {expected}")]
+	IncorrectNumberOfWitnesses { expected: usize, actual: usize },
 	#[error("the PIOP compiler cannot handle evaluation claim for derived oracle {id}")]
 	EvalcheckClaimForDerivedPoly { id: OracleId },
 	#[error("the committed oracle {id} is missing from the index")]
@@ -42,7 +42,7 @@ pub fn prove<F, P, M, Tower, Challenger_, Backend>(
 	system: &EvalClaimSystem<F>,
 	witnesses: &[M],
 	transcript: &mut ProverTranscript<Challenger_>,
-	memoized_data: MemoizedData<P, Backend>,
+	memoized_data: &mut MemoizedData<P, Backend>,
 	backend: &Backend,
 ) -> Result<ReducedWitness<P>, Error>
 where
@@ -60,9 +60,10 @@ where
 	Backend: ComputationBackend,
 {
 	if witnesses.len() != system.commit_meta.total_multilins() {
-		return Err(Error::InvalidWitness(
-			"witness length does not match the number of multilinears".into(),
-		));
+		return Err(Error::IncorrectNumberOfWitnesses {
+			expected: system.commit_meta.total_multilins(),
+			actual: witnesses.len(),
+		});
 	}
 
 	// Sample enough randomness to batch tensor elements corresponding to claims that share an
@@ -147,7 +148,7 @@ where
 fn compute_partial_evals<F, P, M, Tower, Backend>(
 	system: &EvalClaimSystem<F>,
 	witnesses: &[M],
-	mut memoized_data: MemoizedData<P, Backend>,
+	memoized_data: &mut MemoizedData<P, Backend>,
 	backend: &Backend,
 ) -> Result<Vec<TowerTensorAlgebra<Tower>>, Error>
 where
@@ -164,6 +165,7 @@ where
 		.collect::<Vec<_>>();
 
 	memoized_data.memoize_query_par(suffixes, backend)?;
+	let memoized_data = &*memoized_data;
 
 	let tensor_elems = system
 		.sumcheck_claim_descs
@@ -253,12 +253,12 @@ fn test_prove_verify_claim_reduction_with_naive_validation() {
 			&system,
 			&witnesses,
 			&mut proof,
-			MemoizedData::new(),
+			&mut MemoizedData::new(),
 			&backend,
 		)
 		.unwrap();
 
-		let mut proof = proof.into_verifier();
+		let mut proof = proof.into_verifier().unwrap();
 		let ReducedClaim {
 			transparents: _,
 			sumcheck_claims: verifier_sumcheck_claims,
@@ -334,7 +334,7 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 		&system,
 		&committed_multilins,
 		&mut proof,
-		MemoizedData::new(),
+		&mut MemoizedData::new(),
 		&backend,
 	)
 	.unwrap();
@@ -356,7 +356,7 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 	)
 	.unwrap();
 
-	let mut proof = proof.into_verifier();
+	let mut proof = proof.into_verifier().unwrap();
 	let commitment = proof.message().read().unwrap();
 
 	let ReducedClaim {
@@ -0,0 +1,204 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Checkpoints of the prover transcript.
+//!
+//! A Fiat-Shamir challenger is a black box whose state cannot be exported in general. Instead, a
+//! transcript created with [`ProverTranscript::with_checkpoints`] records every interaction with
+//! its challenger: the bytes observed, the number of bytes sampled, and the switches between
+//! observing and sampling. A [`TranscriptCheckpoint`] captures the proof tape together with this
+//! log, and [`ProverTranscript::resume`] rebuilds an identical transcript by replaying the log on a
+//! fresh challenger.
+//!
+//! [`ProverTranscript::with_checkpoints`]: super::ProverTranscript::with_checkpoints
+//! [`ProverTranscript::resume`]: super::ProverTranscript::resume
+
+use std::slice;
+
+use binius_macros::{DeserializeBytes, SerializeBytes};
+use bytes::{Buf, BufMut, buf::UninitSlice};
+
+use crate::fiat_shamir::Challenger;
+
+/// The state of a prover transcript at some point of the protocol.
+///
+/// The checkpoint can be serialized with [`binius_utils::SerializeBytes`] and stored, so that a
+/// prover that is interrupted can resume from the last checkpoint instead of starting over.
+#[derive(Debug, Clone, Default, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub struct TranscriptCheckpoint {
+	pub(super) tape: Vec<u8>,
+	pub(super) log: ChallengerLog,
+}
+
+impl TranscriptCheckpoint {
+	/// Returns the proof tape written up to the checkpoint.
+	pub fn tape(&self) -> &[u8] {
+		&self.tape
+	}
+}
+
+/// A single interaction with a challenger.
+#[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+enum ChallengerEvent {
+	/// The challenger switched to observing and observed the given bytes, possibly none.
+	Observe(Vec<u8>),
+	/// The challenger switched to sampling and the given number of bytes were read.
+	Sample(usize),
+}
+
+/// The sequence of interactions with a challenger, with consecutive events of the same kind
+/// merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub(super) struct ChallengerLog {
+	events: Vec<ChallengerEvent>,
+}
+
+impl ChallengerLog {
+	fn observe(&mut self, bytes: &[u8]) {
+		match self.events.last_mut() {
+			Some(ChallengerEvent::Observe(observed)) => observed.extend_from_slice(bytes),
+			_ => self.events.push(ChallengerEvent::Observe(bytes.to_vec())),
+		}
+	}
+
+	fn sample(&mut self, count: usize) {
+		match self.events.last_mut() {
+			Some(ChallengerEvent::Sample(sampled)) => *sampled += count,
+			_ => self.events.push(ChallengerEvent::Sample(count)),
+		}
+	}
+}
+
+/// A challenger wrapper that optionally records the interactions with the inner challenger.
+///
+/// Bytes read through [`Challenger::sampler`] are not counted, as the returned reference gives
+/// direct access to the inner sampler. Reads that must be replayable go through
+/// [`Self::sample`] instead.
+#[derive(Debug, Default, Clone)]
+pub(super) struct RecordingChallenger<C> {
+	inner: C,
+	log: Option<ChallengerLog>,
+}
+
+impl<C: Challenger> RecordingChallenger<C> {
+	pub fn recording() -> Self
+	where
+		C: Default,
+	{
+		Self {
+			inner: C::default(),
+			log: Some(ChallengerLog::default()),
+		}
+	}
+
+	/// Creates a recording challenger in the state reached after the interactions in `log`.
+	///
+	/// Sampling is replayed as a single advance over the total number of bytes read. This is
+	/// equivalent to the original reads for challengers whose sampler advances are additive, which
+	/// is the case for [`HasherChallenger`] and [`DuplexChallenger`].
+	///
+	/// [`HasherChallenger`]: crate::fiat_shamir::HasherChallenger
+	/// [`DuplexChallenger`]: crate::fiat_shamir::DuplexChallenger
+	pub fn replay(log: ChallengerLog) -> Self
+	where
+		C: Default,
+	{
+		let mut inner = C::default();
+		for event in &log.events {
+			match event {
+				ChallengerEvent::Observe(bytes) => inner.observer().put_slice(bytes),
+				ChallengerEvent::Sample(count) => {
+					let sampler = inner.sampler();
+					if *count > 0 {
+						sampler.advance(*count);
+					}
+				}
+			}
+		}
+		Self {
+			inner,
+			log: Some(log),
+		}
+	}
+
+	/// Returns the log of interactions, if the challenger is recording.
+	pub const fn log(&self) -> Option<&ChallengerLog> {
+		self.log.as_ref()
+	}
+
+	/// Reads from the inner sampler with `f`, recording the number of bytes read.
+	pub fn sample<T>(&mut self, f: impl FnOnce(&mut dyn Buf) -> T) -> T {
+		let Some(log) = &mut self.log else {
+			return f(self.inner.sampler());
+		};
+
+		let mut sampler = CountingBuf {
+			inner: self.inner.sampler(),
+			count: 0,
+		};
+		let result = f(&mut sampler);
+		log.sample(sampler.count);
+		result
+	}
+}
+
+impl<C: Challenger> Challenger for RecordingChallenger<C> {
+	fn sampler(&mut self) -> &mut impl Buf {
+		if let Some(log) = &mut self.log {
+			log.sample(0);
+		}
+		self.inner.sampler()
+	}
+
+	fn observer(&mut self) -> &mut impl BufMut {
+		if let Some(log) = &mut self.log {
+			log.observe(&[]);
+		}
+		self
+	}
+}
+
+unsafe impl<C: Challenger> BufMut for RecordingChallenger<C> {
+	fn remaining_mut(&self) -> usize {
+		usize::MAX
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		let observer = self.inner.observer();
+		if let Some(log) = &mut self.log {
+			let written = observer.chunk_mut();
+			assert!(cnt <= written.len());
+
+			// NOTE: The caller guarantees that the next cnt bytes are initialized.
+			let written: &[u8] = unsafe { slice::from_raw_parts(written.as_mut_ptr(), cnt) };
+			log.observe(written);
+		}
+		unsafe {
+			observer.advance_mut(cnt);
+		}
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		self.inner.observer().chunk_mut()
+	}
+}
+
+/// A reader that counts the bytes consumed from the inner buffer.
+struct CountingBuf<'a, B> {
+	inner: &'a mut B,
+	count: usize,
+}
+
+impl<B: Buf> Buf for CountingBuf<'_, B> {
+	fn remaining(&self) -> usize {
+		self.inner.remaining()
+	}
+
+	fn chunk(&self) -> &[u8] {
+		self.inner.chunk()
+	}
+
+	fn advance(&mut self, cnt: usize) {
+		self.count += cnt;
+		self.inner.advance(cnt);
+	}
+}
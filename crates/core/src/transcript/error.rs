@@ -8,4 +8,10 @@ pub enum Error {
 	NotEnoughBytes,
 	#[error("Serialization error: {0}")]
 	Serialization(#[from] binius_utils::SerializationError),
+	#[error("the operation is not supported by a transcript streamed from or to I/O")]
+	StreamedTranscript,
+	#[error("the transcript does not record its interactions with the challenger")]
+	CheckpointsDisabled,
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
 }
@@ -12,14 +12,19 @@
 //! secure to write a Merkle tree root to the transcript tape, sample a random index, then provide
 //! the Merkle leaf opening at that index in the advice tape.
 
+mod checkpoint;
 mod error;
+mod stream;
 
-use std::{iter::repeat_with, slice};
+use std::{io, iter::repeat_with, slice};
 
 use binius_field::{PackedField, TowerField};
 use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 use bytes::{Buf, BufMut, Bytes, BytesMut, buf::UninitSlice};
+use checkpoint::RecordingChallenger;
+pub use checkpoint::TranscriptCheckpoint;
 pub use error::Error;
+use stream::{StreamSource, TranscriptSink, TranscriptSource};
 use tracing::warn;
 
 use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
@@ -31,7 +36,8 @@ use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
 /// data.
 #[derive(Debug)]
 pub struct ProverTranscript<Challenger> {
-	combined: FiatShamirBuf<BytesMut, Challenger>,
+	combined: FiatShamirBuf<BytesMut, RecordingChallenger<Challenger>>,
+	sink: Option<TranscriptSink>,
 	debug_assertions: bool,
 }
 
@@ -40,9 +46,9 @@ pub struct ProverTranscript<Challenger> {
 ///
 /// You must manually call the destructor with `finalize()` to check anything that's written is
 /// fully read out
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct VerifierTranscript<Challenger> {
-	combined: FiatShamirBuf<Bytes, Challenger>,
+	combined: FiatShamirBuf<TranscriptSource, Challenger>,
 	debug_assertions: bool,
 }
 
@@ -107,12 +113,63 @@ impl<Challenger_: Default + Challenger> ProverTranscript<Challenger_> {
 	pub fn new() -> Self {
 		Self {
 			combined: Default::default(),
+			sink: None,
 			debug_assertions: cfg!(debug_assertions),
 		}
 	}
 
-	pub fn into_verifier(self) -> VerifierTranscript<Challenger_> {
-		VerifierTranscript::new(self.finalize())
+	/// Creates a new prover transcript that streams the proof tape to `writer`.
+	///
+	/// The proof data is buffered and written out whenever a new message or decommitment begins
+	/// with at least `flush_threshold` bytes in the buffer, so that the memory used by the
+	/// transcript is bounded by the threshold plus the size of the largest single write. The
+	/// transcript must be completed with [`Self::finish`] instead of [`Self::finalize`].
+	pub fn with_sink(
+		writer: impl io::Write + Send + Sync + 'static,
+		flush_threshold: usize,
+	) -> Self {
+		Self {
+			sink: Some(TranscriptSink::new(writer, flush_threshold)),
+			..Self::new()
+		}
+	}
+
+	/// Creates a new prover transcript that supports [`Self::checkpoint`].
+	///
+	/// The transcript records every interaction with the challenger, so that it can be rebuilt
+	/// with [`Self::resume`]. The log takes memory proportional to the observed data.
+	pub fn with_checkpoints() -> Self {
+		Self {
+			combined: FiatShamirBuf {
+				buffer: BytesMut::new(),
+				challenger: RecordingChallenger::recording(),
+			},
+			..Self::new()
+		}
+	}
+
+	/// Rebuilds the prover transcript from a checkpoint taken with [`Self::checkpoint`].
+	///
+	/// The resumed transcript has the same proof tape and challenger state as the transcript at the
+	/// time of the checkpoint, and it keeps recording, so further checkpoints can be taken.
+	pub fn resume(checkpoint: TranscriptCheckpoint) -> Self {
+		let TranscriptCheckpoint { tape, log } = checkpoint;
+		Self {
+			combined: FiatShamirBuf {
+				buffer: BytesMut::from(tape.as_slice()),
+				challenger: RecordingChallenger::replay(log),
+			},
+			..Self::new()
+		}
+	}
+
+	/// Returns a verifier transcript over the proof tape.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::StreamedTranscript`] if the transcript streams the proof tape to a writer.
+	pub fn into_verifier(self) -> Result<VerifierTranscript<Challenger_>, Error> {
+		Ok(VerifierTranscript::new(self.finalize()?))
 	}
 }
 
@@ -123,8 +180,58 @@ impl<Challenger_: Default + Challenger> Default for ProverTranscript<Challenger_
 }
 
 impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
-	pub fn finalize(self) -> Vec<u8> {
-		self.combined.buffer.to_vec()
+	/// Returns the proof tape.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::StreamedTranscript`] if the transcript streams the proof tape to a writer, see
+	///   [`Self::finish`].
+	pub fn finalize(self) -> Result<Vec<u8>, Error> {
+		if self.sink.is_some() {
+			return Err(Error::StreamedTranscript);
+		}
+		Ok(self.combined.buffer.to_vec())
+	}
+
+	/// Writes out the rest of the proof tape and flushes the writer of a transcript created with
+	/// [`Self::with_sink`], returning the total number of bytes written.
+	///
+	/// For a transcript without a writer, this discards the proof tape and returns its size.
+	pub fn finish(mut self) -> Result<u64, Error> {
+		match self.sink.take() {
+			Some(sink) => Ok(sink.finish(&mut self.combined.buffer)?),
+			None => Ok(self.combined.buffer.len() as u64),
+		}
+	}
+
+	/// Returns a checkpoint of the transcript, from which the prover can [resume].
+	///
+	/// ## Throws
+	///
+	/// * [`Error::CheckpointsDisabled`] if the transcript was not created with
+	///   [`ProverTranscript::with_checkpoints`] or [`ProverTranscript::resume`].
+	/// * [`Error::StreamedTranscript`] if the transcript streams the proof tape to a writer.
+	///
+	/// [resume]: ProverTranscript::resume
+	pub fn checkpoint(&self) -> Result<TranscriptCheckpoint, Error> {
+		if self.sink.is_some() {
+			return Err(Error::StreamedTranscript);
+		}
+		let log = self
+			.combined
+			.challenger
+			.log()
+			.ok_or(Error::CheckpointsDisabled)?;
+		Ok(TranscriptCheckpoint {
+			tape: self.combined.buffer.to_vec(),
+			log: log.clone(),
+		})
+	}
+
+	fn flush_if_full(&mut self) {
+		if let Some(sink) = &mut self.sink {
+			sink.flush_if_full(&mut self.combined.buffer);
+		}
 	}
 
 	/// Sets the debug flag.
@@ -158,6 +265,7 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	/// be written using [`Self::decommitment`] because they are verified with respect to the
 	/// previously sent Merkle root.
 	pub fn decommitment(&mut self) -> TranscriptWriter<impl BufMut> {
+		self.flush_if_full();
 		TranscriptWriter {
 			buffer: &mut self.combined.buffer,
 			debug_assertions: self.debug_assertions,
@@ -171,6 +279,7 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	where
 		'a: 'b,
 	{
+		self.flush_if_full();
 		TranscriptWriter {
 			buffer: &mut self.combined,
 			debug_assertions: self.debug_assertions,
@@ -183,15 +292,62 @@ impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
 		Self {
 			combined: FiatShamirBuf {
 				challenger: Challenger_::default(),
-				buffer: Bytes::from(vec),
+				buffer: TranscriptSource::Bytes(Bytes::from(vec)),
+			},
+			debug_assertions: cfg!(debug_assertions),
+		}
+	}
+
+	/// Creates a new verifier transcript that reads a proof tape of `len` bytes from `reader`.
+	///
+	/// At most `buffer_size` bytes of the proof tape are buffered at a time. An I/O error while
+	/// reading is returned by [`Self::finalize`].
+	///
+	/// Unlike a transcript over an in-memory proof tape, the returned transcript cannot be cloned
+	/// with [`Self::try_clone`].
+	pub fn from_reader(
+		reader: impl io::Read + Send + Sync + 'static,
+		len: usize,
+		buffer_size: usize,
+	) -> Self {
+		Self {
+			combined: FiatShamirBuf {
+				challenger: Challenger_::default(),
+				buffer: TranscriptSource::Stream(StreamSource::new(reader, len, buffer_size)),
 			},
 			debug_assertions: cfg!(debug_assertions),
 		}
 	}
 }
 
+impl<Challenger_: Challenger + Clone> VerifierTranscript<Challenger_> {
+	/// Returns a copy of the transcript, which reads the rest of the proof tape independently.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::StreamedTranscript`] if the proof tape is read from a stream, see
+	///   [`Self::from_reader`].
+	pub fn try_clone(&self) -> Result<Self, Error> {
+		let buffer = self
+			.combined
+			.buffer
+			.try_clone()
+			.ok_or(Error::StreamedTranscript)?;
+		Ok(Self {
+			combined: FiatShamirBuf {
+				buffer,
+				challenger: self.combined.challenger.clone(),
+			},
+			debug_assertions: self.debug_assertions,
+		})
+	}
+}
+
 impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
-	pub fn finalize(self) -> Result<(), Error> {
+	pub fn finalize(mut self) -> Result<(), Error> {
+		if let Some(err) = self.combined.buffer.take_error() {
+			return Err(err.into());
+		}
 		if self.combined.buffer.has_remaining() {
 			return Err(Error::TranscriptNotEmpty {
 				remaining: self.combined.buffer.remaining(),
@@ -434,8 +590,9 @@ where
 {
 	fn sample(&mut self) -> F {
 		let mode = SerializationMode::CanonicalTower;
-		DeserializeBytes::deserialize(self.combined.challenger.sampler(), mode)
-			.expect("challenger has infinite buffer")
+		self.combined.challenger.sample(|sampler| {
+			DeserializeBytes::deserialize(sampler, mode).expect("challenger has infinite buffer")
+		})
 	}
 }
 
@@ -471,7 +628,9 @@ where
 	Challenger_: Challenger,
 {
 	fn sample_bits(&mut self, bits: usize) -> u32 {
-		sample_bits_reader(self.combined.challenger.sampler(), bits)
+		self.combined
+			.challenger
+			.sample(|sampler| sample_bits_reader(sampler, bits))
 	}
 }
 
@@ -521,7 +680,7 @@ mod tests {
 			.write_scalar(BinaryField128bPolyval::new(0xFFFF12345678DDDDEEEE87654321AAAA));
 		let sampled_polyval1: BinaryField128bPolyval = prover_transcript.sample();
 
-		let mut verifier_transcript = prover_transcript.into_verifier();
+		let mut verifier_transcript = prover_transcript.into_verifier().unwrap();
 		let mut readable = verifier_transcript.message();
 
 		let fp_8: BinaryField8b = readable.read_scalar().unwrap();
@@ -575,7 +734,7 @@ mod tests {
 
 		advice_writer.write_scalar(BinaryField128bPolyval::new(0xFFFF12345678DDDDEEEE87654321AAAA));
 
-		let mut verifier_transcript = prover_transcript.into_verifier();
+		let mut verifier_transcript = prover_transcript.into_verifier().unwrap();
 		let mut advice_reader = verifier_transcript.decommitment();
 
 		let fp_8: BinaryField8b = advice_reader.read_scalar().unwrap();
@@ -635,9 +794,9 @@ mod tests {
 			sampled_arrays[i] = challenger_out;
 		}
 
-		let mut taped_transcript = taped_transcript.into_verifier();
+		let mut taped_transcript = taped_transcript.into_verifier().unwrap();
 
-		assert!(untaped_transcript.finalize().is_empty());
+		assert!(untaped_transcript.finalize().unwrap().is_empty());
 
 		for array in sampled_arrays {
 			let _: BinaryField64b = taped_transcript.message().read_scalar().unwrap();
@@ -649,6 +808,152 @@ mod tests {
 		taped_transcript.finalize().unwrap();
 	}
 
+	#[derive(Clone, Default)]
+	struct SharedWriter(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+	impl io::Write for SharedWriter {
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+			io::Write::write(&mut *self.0.lock().unwrap(), buf)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn test_streaming_transcript_matches_in_memory() {
+		const NUM_MESSAGES: usize = 64;
+		let mut rng = thread_rng();
+		let values = (0..NUM_MESSAGES)
+			.map(|_| {
+				BinaryField128b::new(((rng.next_u64() as u128) << 64) | rng.next_u64() as u128)
+			})
+			.collect::<Vec<_>>();
+
+		let writer = SharedWriter::default();
+		let mut streaming =
+			ProverTranscript::<HasherChallenger<Groestl256>>::with_sink(writer.clone(), 100);
+		let mut in_memory = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut samples = Vec::with_capacity(NUM_MESSAGES);
+		for &value in &values {
+			streaming.message().write_scalar(value);
+			streaming.decommitment().write_scalar(value);
+			in_memory.message().write_scalar(value);
+			in_memory.decommitment().write_scalar(value);
+
+			let sample: BinaryField64b = streaming.sample();
+			let expected: BinaryField64b = in_memory.sample();
+			assert_eq!(sample, expected);
+			samples.push(sample);
+			// The buffered data never exceeds the threshold plus one message.
+			assert!(streaming.combined.buffer.len() < 100 + 16);
+		}
+
+		let proof = in_memory.finalize().unwrap();
+		assert_eq!(streaming.finish().unwrap(), proof.len() as u64);
+		assert_eq!(*writer.0.lock().unwrap(), proof);
+
+		let mut verifier = VerifierTranscript::<HasherChallenger<Groestl256>>::from_reader(
+			io::Cursor::new(proof.clone()),
+			proof.len(),
+			7,
+		);
+		for (&value, &sample) in values.iter().zip(&samples) {
+			assert_eq!(verifier.message().read_scalar::<BinaryField128b>().unwrap(), value);
+			assert_eq!(
+				verifier
+					.decommitment()
+					.read_scalar::<BinaryField128b>()
+					.unwrap(),
+				value
+			);
+			let verifier_sample: BinaryField64b = verifier.sample();
+			assert_eq!(verifier_sample, sample);
+		}
+		assert!(matches!(verifier.try_clone(), Err(Error::StreamedTranscript)));
+		verifier.finalize().unwrap();
+
+		// A truncated stream reads as zeros and reports the I/O error.
+		let mut verifier = VerifierTranscript::<HasherChallenger<Groestl256>>::from_reader(
+			io::Cursor::new(proof[..40].to_vec()),
+			proof.len(),
+			7,
+		);
+		assert_eq!(verifier.message().read_scalar::<BinaryField128b>().unwrap(), values[0]);
+		assert_eq!(
+			verifier
+				.decommitment()
+				.read_scalar::<BinaryField128b>()
+				.unwrap(),
+			values[0]
+		);
+		assert_ne!(verifier.message().read_scalar::<BinaryField128b>().unwrap(), values[1]);
+		assert!(matches!(verifier.finalize(), Err(Error::Io(_))));
+	}
+
+	#[test]
+	fn test_streaming_transcript_has_no_proof_tape() {
+		let streaming =
+			ProverTranscript::<HasherChallenger<Groestl256>>::with_sink(io::sink(), 100);
+		assert!(matches!(streaming.finalize(), Err(Error::StreamedTranscript)));
+
+		let streaming =
+			ProverTranscript::<HasherChallenger<Groestl256>>::with_sink(io::sink(), 100);
+		assert!(matches!(streaming.into_verifier(), Err(Error::StreamedTranscript)));
+	}
+
+	#[test]
+	fn test_resume_from_checkpoint() {
+		fn interact(transcript: &mut ProverTranscript<HasherChallenger<Groestl256>>, i: u64) {
+			transcript.observe().write_scalar(BinaryField64b::new(i));
+			transcript
+				.message()
+				.write_scalar(BinaryField128b::new(i as u128));
+			transcript
+				.decommitment()
+				.write_scalar(BinaryField32b::new(i as u32));
+			let _: BinaryField128b = transcript.sample();
+			let _ = transcript.sample_bits(7);
+			// Switch modes without observing anything.
+			transcript.observe();
+		}
+
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::with_checkpoints();
+		for i in 0..5 {
+			interact(&mut transcript, i);
+		}
+
+		let checkpoint = transcript.checkpoint().unwrap();
+		let mut bytes = Vec::new();
+		checkpoint
+			.serialize(&mut bytes, SerializationMode::Native)
+			.unwrap();
+		let checkpoint =
+			TranscriptCheckpoint::deserialize(bytes.as_slice(), SerializationMode::Native).unwrap();
+		let mut resumed = ProverTranscript::<HasherChallenger<Groestl256>>::resume(checkpoint);
+
+		for i in 5..10 {
+			interact(&mut transcript, i);
+			interact(&mut resumed, i);
+			let expected: BinaryField128b = transcript.sample();
+			let sample: BinaryField128b = resumed.sample();
+			assert_eq!(sample, expected);
+		}
+		assert_eq!(resumed.checkpoint().unwrap(), transcript.checkpoint().unwrap());
+		assert_eq!(resumed.finalize().unwrap(), transcript.finalize().unwrap());
+	}
+
+	#[test]
+	fn test_checkpoint_requires_recording() {
+		let transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		assert!(matches!(transcript.checkpoint(), Err(Error::CheckpointsDisabled)));
+
+		let streaming =
+			ProverTranscript::<HasherChallenger<Groestl256>>::with_sink(io::sink(), 100);
+		assert!(matches!(streaming.checkpoint(), Err(Error::StreamedTranscript)));
+	}
+
 	#[test]
 	fn test_transcript_debug() {
 		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
@@ -656,6 +961,7 @@ mod tests {
 		transcript.message().write_debug("test_transcript_debug");
 		transcript
 			.into_verifier()
+			.unwrap()
 			.message()
 			.read_debug("test_transcript_debug");
 	}
@@ -668,6 +974,7 @@ mod tests {
 		transcript.message().write_debug("test_transcript_debug");
 		transcript
 			.into_verifier()
+			.unwrap()
 			.message()
 			.read_debug("test_transcript_debug_should_fail");
 	}
@@ -0,0 +1,194 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Bounded-memory proof tapes backed by I/O streams.
+//!
+//! See [`super::ProverTranscript::with_sink`] and [`super::VerifierTranscript::from_reader`].
+
+use std::{fmt, io};
+
+use bytes::{Buf, Bytes, BytesMut};
+
+/// Writer that the prover transcript flushes its proof tape to.
+pub(super) struct TranscriptSink {
+	writer: Box<dyn io::Write + Send + Sync>,
+	flush_threshold: usize,
+	bytes_written: u64,
+	error: Option<io::Error>,
+}
+
+impl TranscriptSink {
+	pub fn new(writer: impl io::Write + Send + Sync + 'static, flush_threshold: usize) -> Self {
+		Self {
+			writer: Box::new(writer),
+			flush_threshold,
+			bytes_written: 0,
+			error: None,
+		}
+	}
+
+	/// Writes out the buffered proof data if there is at least the flush threshold of it.
+	pub fn flush_if_full(&mut self, buffer: &mut BytesMut) {
+		if buffer.len() >= self.flush_threshold {
+			self.write_out(buffer);
+		}
+	}
+
+	/// Writes out and clears the buffered proof data.
+	///
+	/// After the first I/O error the data is discarded, so that the memory stays bounded; the
+	/// error is reported by [`Self::finish`].
+	pub fn write_out(&mut self, buffer: &mut BytesMut) {
+		if self.error.is_none() {
+			match self.writer.write_all(buffer) {
+				Ok(()) => self.bytes_written += buffer.len() as u64,
+				Err(err) => self.error = Some(err),
+			}
+		}
+		buffer.clear();
+	}
+
+	/// Writes out the remaining proof data, flushes the writer and returns the total number of
+	/// bytes written.
+	pub fn finish(mut self, buffer: &mut BytesMut) -> io::Result<u64> {
+		self.write_out(buffer);
+		if let Some(err) = self.error {
+			return Err(err);
+		}
+		self.writer.flush()?;
+		Ok(self.bytes_written)
+	}
+}
+
+impl fmt::Debug for TranscriptSink {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("TranscriptSink")
+			.field("flush_threshold", &self.flush_threshold)
+			.field("bytes_written", &self.bytes_written)
+			.field("error", &self.error)
+			.finish_non_exhaustive()
+	}
+}
+
+/// The proof tape read by the verifier transcript.
+#[derive(Debug)]
+pub(super) enum TranscriptSource {
+	Bytes(Bytes),
+	Stream(StreamSource),
+}
+
+impl TranscriptSource {
+	/// Returns a copy of the unread proof tape, or `None` if the tape is read from a stream, which
+	/// cannot be duplicated.
+	pub fn try_clone(&self) -> Option<Self> {
+		match self {
+			Self::Bytes(bytes) => Some(Self::Bytes(bytes.clone())),
+			Self::Stream(_) => None,
+		}
+	}
+
+	/// Takes the I/O error that interrupted reading the stream, if any.
+	pub fn take_error(&mut self) -> Option<io::Error> {
+		match self {
+			Self::Bytes(_) => None,
+			Self::Stream(stream) => stream.error.take(),
+		}
+	}
+}
+
+impl Buf for TranscriptSource {
+	fn remaining(&self) -> usize {
+		match self {
+			Self::Bytes(bytes) => bytes.remaining(),
+			Self::Stream(stream) => stream.remaining,
+		}
+	}
+
+	fn chunk(&self) -> &[u8] {
+		match self {
+			Self::Bytes(bytes) => bytes.chunk(),
+			Self::Stream(stream) => &stream.buffer[stream.pos..stream.filled],
+		}
+	}
+
+	fn advance(&mut self, cnt: usize) {
+		match self {
+			Self::Bytes(bytes) => bytes.advance(cnt),
+			Self::Stream(stream) => stream.advance(cnt),
+		}
+	}
+}
+
+/// A reader of a proof tape of known length, buffering a bounded window of it.
+///
+/// The [`Buf`] methods cannot fail, so after an I/O error the rest of the tape reads as zeros
+/// instead. The error itself is reported by [`super::VerifierTranscript::finalize`], which fails
+/// the verification.
+pub(super) struct StreamSource {
+	reader: Box<dyn io::Read + Send + Sync>,
+	buffer: Box<[u8]>,
+	pos: usize,
+	filled: usize,
+	/// The number of unread bytes of the tape, including the buffered ones.
+	remaining: usize,
+	error: Option<io::Error>,
+}
+
+impl StreamSource {
+	pub fn new(
+		reader: impl io::Read + Send + Sync + 'static,
+		len: usize,
+		buffer_size: usize,
+	) -> Self {
+		assert!(buffer_size > 0, "the buffer size must be positive");
+		let mut stream = Self {
+			reader: Box::new(reader),
+			buffer: vec![0; buffer_size.min(len)].into_boxed_slice(),
+			pos: 0,
+			filled: 0,
+			remaining: len,
+			error: None,
+		};
+		stream.refill();
+		stream
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		assert!(cnt <= self.remaining);
+		while cnt > 0 {
+			let step = cnt.min(self.filled - self.pos);
+			self.pos += step;
+			self.remaining -= step;
+			cnt -= step;
+			if self.pos == self.filled {
+				self.refill();
+			}
+		}
+	}
+
+	fn refill(&mut self) {
+		self.pos = 0;
+		self.filled = 0;
+		let target = self.buffer.len().min(self.remaining);
+		while self.error.is_none() && self.filled < target {
+			match self.reader.read(&mut self.buffer[self.filled..target]) {
+				Ok(0) => self.error = Some(io::ErrorKind::UnexpectedEof.into()),
+				Ok(n) => self.filled += n,
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+				Err(err) => self.error = Some(err),
+			}
+		}
+		// After an I/O error, the rest of the tape reads as zeros.
+		self.buffer[self.filled..target].fill(0);
+		self.filled = target;
+	}
+}
+
+impl fmt::Debug for StreamSource {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("StreamSource")
+			.field("buffer_size", &self.buffer.len())
+			.field("remaining", &self.remaining)
+			.field("error", &self.error)
+			.finish_non_exhaustive()
+	}
+}
@@ -1,11 +1,15 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::{Field, PackedField, TowerField};
+use binius_field::{BinaryField128b, Field, PackedField, TowerField};
 use binius_hal::ComputationBackend;
+use binius_macros::{DeserializeBytes, SerializeBytes, erased_serialize_bytes};
 use binius_math::MultilinearExtension;
-use binius_utils::bail;
+use binius_utils::{DeserializeBytes, bail};
 
-use crate::polynomial::{Error, MultivariatePoly};
+use crate::{
+	polynomial::{Error, MultivariatePoly},
+	protocols::memoized_queries::MemoizedQueries,
+};
 
 /// Represents $\text{eq}(X, r)$, the partial evaluation of the
 /// [equality indicator polynomial](https://www.binius.xyz/blueprint/background/multilinears#the-equality-indicator-polynomial)
@@ -26,11 +30,18 @@ use crate::polynomial::{Error, MultivariatePoly};
 /// $$
 /// \text{eq}(X, Y) = \prod_{i=0}^{\mu - 1} \left(X_i Y_i + (1 - X_i)(1 - Y_i)\right).
 /// $$
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
 pub struct EqIndPartialEval<F: Field> {
 	r: Vec<F>,
 }
 
+inventory::submit! {
+	<dyn MultivariatePoly<BinaryField128b>>::register_deserializer(
+		"EqIndPartialEval",
+		|buf, mode| Ok(Box::new(EqIndPartialEval::<BinaryField128b>::deserialize(&mut *buf, mode)?))
+	)
+}
+
 impl<F: Field> EqIndPartialEval<F> {
 	pub fn new(r: impl Into<Vec<F>>) -> Self {
 		Self { r: r.into() }
@@ -40,6 +51,43 @@ impl<F: Field> EqIndPartialEval<F> {
 		self.r.len()
 	}
 
+	/// The point $r$ the equality indicator is partially evaluated at.
+	pub fn point(&self) -> &[F] {
+		&self.r
+	}
+
+	/// Evaluates the polynomial at a query on its lowest `query.len()` variables.
+	///
+	/// Returns the scalar $\text{eq}(r_{low}, q)$ and the equality indicator $\text{eq}(X,
+	/// r_{high})$ on the remaining variables, whose product is the partially evaluated polynomial.
+	pub fn evaluate_partial_low(&self, query: &[F]) -> Result<(F, Self), Error> {
+		if query.len() > self.n_vars() {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars(),
+				actual: query.len()
+			});
+		}
+
+		let (r_low, r_high) = self.r.split_at(query.len());
+		Ok((eq_ind_eval(r_low, query), Self::new(r_high)))
+	}
+
+	/// Evaluates the polynomial at a query on its highest `query.len()` variables.
+	///
+	/// Returns the scalar $\text{eq}(r_{high}, q)$ and the equality indicator $\text{eq}(X,
+	/// r_{low})$ on the remaining variables, whose product is the partially evaluated polynomial.
+	pub fn evaluate_partial_high(&self, query: &[F]) -> Result<(F, Self), Error> {
+		if query.len() > self.n_vars() {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars(),
+				actual: query.len()
+			});
+		}
+
+		let (r_low, r_high) = self.r.split_at(self.n_vars() - query.len());
+		Ok((eq_ind_eval(r_high, query), Self::new(r_low)))
+	}
+
 	pub fn multilinear_extension<P: PackedField<Scalar = F>, Backend: ComputationBackend>(
 		&self,
 		backend: &Backend,
@@ -47,8 +95,26 @@ impl<F: Field> EqIndPartialEval<F> {
 		let multilin_query = backend.tensor_product_full_query(&self.r)?;
 		Ok(MultilinearExtension::new(self.n_vars(), multilin_query)?)
 	}
+
+	/// Returns the hypercube evaluations of the polynomial, reusing the tensor expansion of $r$
+	/// from `memoized_queries` if it has been computed before and memoizing it otherwise.
+	///
+	/// The same expansion is needed by the zerocheck and evalcheck provers, this lets them share
+	/// a single $2^n$ table.
+	pub fn tensor_expansion<'a, P, Backend>(
+		&self,
+		memoized_queries: &'a mut MemoizedQueries<P, Backend>,
+		backend: &Backend,
+	) -> Result<&'a [P], Error>
+	where
+		P: PackedField<Scalar = F>,
+		Backend: ComputationBackend,
+	{
+		Ok(memoized_queries.full_query(&self.r, backend)?.expansion())
+	}
 }
 
+#[erased_serialize_bytes]
 impl<F: TowerField, P: PackedField<Scalar = F>> MultivariatePoly<P> for EqIndPartialEval<F> {
 	fn n_vars(&self) -> usize {
 		self.r.len()
@@ -67,14 +133,7 @@ impl<F: TowerField, P: PackedField<Scalar = F>> MultivariatePoly<P> for EqIndPar
 			});
 		}
 
-		let mut result = P::one();
-		for (&q_i, &r_i) in query.iter().zip(self.r.iter()) {
-			let term_one = q_i * r_i;
-			let term_two = (P::one() - q_i) * (P::one() - r_i);
-			let factor = term_one + term_two;
-			result *= factor;
-		}
-		Ok(result)
+		Ok(eq_ind_eval(&self.r, query))
 	}
 
 	fn binary_tower_level(&self) -> usize {
@@ -82,11 +141,23 @@ impl<F: TowerField, P: PackedField<Scalar = F>> MultivariatePoly<P> for EqIndPar
 	}
 }
 
+/// Evaluates $\text{eq}(r, q)$ for two points of equal length.
+fn eq_ind_eval<F: Field, P: PackedField<Scalar = F>>(r: &[F], query: &[P]) -> P {
+	let mut result = P::one();
+	for (&q_i, &r_i) in query.iter().zip(r.iter()) {
+		let term_one = q_i * r_i;
+		let term_two = (P::one() - q_i) * (P::one() - r_i);
+		let factor = term_one + term_two;
+		result *= factor;
+	}
+	result
+}
+
 #[cfg(test)]
 mod tests {
 	use std::iter::repeat_with;
 
-	use binius_field::{BinaryField32b, PackedBinaryField4x32b, PackedField};
+	use binius_field::{BinaryField32b, Field, PackedBinaryField4x32b, PackedField};
 	use binius_hal::{ComputationBackendExt, make_portable_backend};
 	use rand::{SeedableRng, rngs::StdRng};
 
@@ -125,4 +196,36 @@ mod tests {
 			test_eq_consistency_help(n_vars);
 		}
 	}
+
+	#[test]
+	fn test_evaluate_partial() {
+		type F = BinaryField32b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let r = repeat_with(|| F::random(&mut rng))
+			.take(6)
+			.collect::<Vec<_>>();
+		let query = repeat_with(|| F::random(&mut rng))
+			.take(6)
+			.collect::<Vec<_>>();
+
+		let eq_r = EqIndPartialEval::new(r);
+		let expected = MultivariatePoly::<F>::evaluate(&eq_r, &query).unwrap();
+
+		let (scale, eq_high) = eq_r.evaluate_partial_low(&query[..2]).unwrap();
+		assert_eq!(eq_high.n_vars(), 4);
+		assert_eq!(
+			scale * MultivariatePoly::<F>::evaluate(&eq_high, &query[2..]).unwrap(),
+			expected
+		);
+
+		let (scale, eq_low) = eq_r.evaluate_partial_high(&query[2..]).unwrap();
+		assert_eq!(eq_low.n_vars(), 2);
+		assert_eq!(
+			scale * MultivariatePoly::<F>::evaluate(&eq_low, &query[..2]).unwrap(),
+			expected
+		);
+
+		assert!(eq_r.evaluate_partial_low(&[F::ONE; 7]).is_err());
+	}
 }
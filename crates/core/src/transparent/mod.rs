@@ -1,5 +1,14 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+//! Transparent polynomials, which are multilinears that the verifier can evaluate succinctly.
+//!
+//! The polynomials in this module implement [`MultivariatePoly`] over the binary tower fields,
+//! and the ones that can be referenced by constraint systems also implement serialization, and
+//! register a deserializer for `Box<dyn MultivariatePoly<BinaryField128b>>`, see
+//! [`serialization`].
+//!
+//! [`MultivariatePoly`]: crate::polynomial::MultivariatePoly
+
 pub mod constant;
 pub mod disjoint_product;
 pub mod eq_ind;
@@ -12,4 +21,13 @@ pub mod step_down;
 pub mod step_up;
 pub mod tower_basis;
 
+pub use constant::Constant;
+pub use disjoint_product::DisjointProduct;
+pub use eq_ind::EqIndPartialEval;
 pub use multilinear_extension::*;
+pub use powers::Powers;
+pub use select_row::SelectRow;
+pub use shift_ind::ShiftIndPartialEval;
+pub use step_down::StepDown;
+pub use step_up::StepUp;
+pub use tower_basis::TowerBasis;
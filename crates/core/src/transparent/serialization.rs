@@ -83,3 +83,59 @@ type ErasedDeserializeBytes<F> = fn(
 	&mut dyn bytes::Buf,
 	mode: SerializationMode,
 ) -> Result<Box<dyn MultivariatePoly<F>>, SerializationError>;
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField8b, Field};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{
+		oracle::ShiftVariant,
+		transparent::{
+			Constant, EqIndPartialEval, Powers, SelectRow, ShiftIndPartialEval, StepDown, StepUp,
+			TowerBasis,
+		},
+	};
+
+	type F = BinaryField128b;
+
+	#[test]
+	fn test_transparent_serialization_round_trip() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| F::random(&mut rng))
+			.take(4)
+			.collect::<Vec<_>>();
+
+		let polys: Vec<Box<dyn MultivariatePoly<F>>> = vec![
+			Box::new(Constant::new(4, BinaryField8b::new(7))),
+			Box::new(Powers::new(4, F::random(&mut rng))),
+			Box::new(EqIndPartialEval::new(point.clone())),
+			Box::new(
+				ShiftIndPartialEval::new(4, 3, ShiftVariant::LogicalLeft, point.clone()).unwrap(),
+			),
+			Box::new(SelectRow::new(4, 5).unwrap()),
+			Box::new(StepDown::new(4, 5).unwrap()),
+			Box::new(StepUp::new(4, 5).unwrap()),
+			Box::new(TowerBasis::<F>::new(4, 3).unwrap()),
+		];
+
+		let query = repeat_with(|| F::random(&mut rng))
+			.take(4)
+			.collect::<Vec<_>>();
+		for poly in polys {
+			let mut buf = Vec::new();
+			poly.serialize(&mut buf, SerializationMode::CanonicalTower)
+				.unwrap();
+			let deserialized = Box::<dyn MultivariatePoly<F>>::deserialize(
+				buf.as_slice(),
+				SerializationMode::CanonicalTower,
+			)
+			.unwrap();
+			assert_eq!(deserialized.n_vars(), poly.n_vars());
+			assert_eq!(deserialized.evaluate(&query).unwrap(), poly.evaluate(&query).unwrap());
+		}
+	}
+}
@@ -1,8 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::{Field, PackedField, TowerField, util::eq};
+use binius_field::{BinaryField128b, Field, PackedField, TowerField, util::eq};
+use binius_macros::{DeserializeBytes, SerializeBytes, erased_serialize_bytes};
 use binius_math::MultilinearExtension;
-use binius_utils::bail;
+use binius_utils::{DeserializeBytes, bail};
 
 use crate::{
 	oracle::ShiftVariant,
@@ -85,7 +86,7 @@ use crate::{
 /// and every other pair of $b$-variate hypercube points $x, y \in \{0, 1\}^{b}$ is s.t. f(x, y) =
 /// 0. Using these shift params, if f = [[a_i, b_i, c_i, d_i]_i], then shifted_f = [[0, a_i, b_i,
 /// c_i]_i]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
 pub struct ShiftIndPartialEval<F: Field> {
 	/// Block size $b$, also the number of variables
 	block_size: usize,
@@ -98,6 +99,15 @@ pub struct ShiftIndPartialEval<F: Field> {
 	r: Vec<F>,
 }
 
+inventory::submit! {
+	<dyn MultivariatePoly<BinaryField128b>>::register_deserializer(
+		"ShiftIndPartialEval",
+		|buf, mode| {
+			Ok(Box::new(ShiftIndPartialEval::<BinaryField128b>::deserialize(&mut *buf, mode)?))
+		}
+	)
+}
+
 impl<F: Field> ShiftIndPartialEval<F> {
 	pub fn new(
 		block_size: usize,
@@ -185,6 +195,7 @@ impl<F: Field> ShiftIndPartialEval<F> {
 	}
 }
 
+#[erased_serialize_bytes]
 impl<F: TowerField> MultivariatePoly<F> for ShiftIndPartialEval<F> {
 	fn n_vars(&self) -> usize {
 		self.block_size
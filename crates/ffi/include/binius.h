@@ -0,0 +1,78 @@
+#ifndef BINIUS_H
+#define BINIUS_H
+
+/* Generated with cbindgen from crates/ffi. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The status codes returned by the API.
+ */
+typedef enum BiniusStatus {
+  BINIUS_STATUS_OK = 0,
+  BINIUS_STATUS_NULL_POINTER = 1,
+  BINIUS_STATUS_INVALID_CONSTRAINT_SYSTEM = 2,
+  BINIUS_STATUS_INVALID_BOUNDARIES = 3,
+  BINIUS_STATUS_INVALID_PROOF = 4,
+  BINIUS_STATUS_VERIFICATION_FAILED = 5,
+  BINIUS_STATUS_PANIC = 6,
+} BiniusStatus;
+
+/**
+ * A deserialized constraint system.
+ */
+typedef struct BiniusConstraintSystem BiniusConstraintSystem;
+
+/**
+ * Deserializes a constraint system and stores a handle to it in `*out`.
+ *
+ * The handle must be released with [`binius_constraint_system_free`].
+ *
+ * # Safety
+ * `data` must point to `len` readable bytes and `out` must be a valid pointer.
+ */
+enum BiniusStatus binius_constraint_system_deserialize(const uint8_t *data,
+                                                       uintptr_t len,
+                                                       struct BiniusConstraintSystem **out);
+
+/**
+ * Releases a constraint system handle. Null handles are ignored.
+ *
+ * # Safety
+ * `constraint_system` must be null or a handle returned by
+ * [`binius_constraint_system_deserialize`] that has not been released yet.
+ */
+void binius_constraint_system_free(struct BiniusConstraintSystem *constraint_system);
+
+/**
+ * Verifies a proof against a constraint system and its boundaries.
+ *
+ * `boundaries` holds a serialized list of boundaries, and `proof` a serialized proof. Returns
+ * [`BiniusStatus::Ok`] if the proof is valid and [`BiniusStatus::VerificationFailed`] if it is
+ * not.
+ *
+ * # Safety
+ * `constraint_system` must be a live handle, and `boundaries` and `proof` must point to
+ * `boundaries_len` and `proof_len` readable bytes.
+ */
+enum BiniusStatus binius_verify(const struct BiniusConstraintSystem *constraint_system,
+                                uintptr_t security_bits,
+                                uintptr_t log_inv_rate,
+                                const uint8_t *boundaries,
+                                uintptr_t boundaries_len,
+                                const uint8_t *proof,
+                                uintptr_t proof_len);
+
+/**
+ * Copies the message of the last error on the calling thread into `buf` as a null-terminated
+ * string, truncated to `len` bytes, and returns the length of the full message.
+ *
+ * # Safety
+ * `buf` must be null or point to `len` writable bytes.
+ */
+uintptr_t binius_last_error_message(char *buf, uintptr_t len);
+
+#endif /* BINIUS_H */
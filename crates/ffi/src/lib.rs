@@ -0,0 +1,272 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A C API for verifying Binius proofs.
+//!
+//! The API works on flat byte buffers holding the [`SerializeBytes`] encodings, in
+//! [`SerializationMode::CanonicalTower`], of the constraint system, the boundaries and the proof,
+//! so that hosts in other languages only need to pass bytes around. The constraint system is
+//! deserialized once into an opaque [`BiniusConstraintSystem`] handle, which can verify any number
+//! of proofs.
+//!
+//! Proofs are verified over the canonical tower with Grøstl-256 Merkle trees and Fiat-Shamir
+//! transcript, the configuration of the default prover. The C declarations are in
+//! `include/binius.h`.
+
+use std::{
+	cell::RefCell,
+	ffi::c_char,
+	panic::{AssertUnwindSafe, catch_unwind},
+	ptr, slice,
+};
+
+use binius_core::{
+	constraint_system::{self, ConstraintSystem, Proof, SecurityLevel, channel::Boundary},
+	fiat_shamir::HasherChallenger,
+};
+use binius_field::{BinaryField128b, arch::OptimalUnderlier, tower::CanonicalTowerFamily};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_utils::{DeserializeBytes, SerializationMode};
+
+/// The status codes returned by the API.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BiniusStatus {
+	Ok = 0,
+	NullPointer = 1,
+	InvalidConstraintSystem = 2,
+	InvalidBoundaries = 3,
+	InvalidProof = 4,
+	VerificationFailed = 5,
+	Panic = 6,
+}
+
+/// A deserialized constraint system.
+pub struct BiniusConstraintSystem(ConstraintSystem<BinaryField128b>);
+
+thread_local! {
+	static LAST_ERROR: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+fn fail(status: BiniusStatus, message: impl ToString) -> BiniusStatus {
+	LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message.to_string());
+	status
+}
+
+/// Runs `f`, turning a panic into [`BiniusStatus::Panic`] so that it doesn't unwind into C.
+fn guard(f: impl FnOnce() -> BiniusStatus) -> BiniusStatus {
+	catch_unwind(AssertUnwindSafe(f))
+		.unwrap_or_else(|_| fail(BiniusStatus::Panic, "panic during the call"))
+}
+
+/// # Safety
+/// `data` must be null or point to `len` readable bytes.
+unsafe fn bytes<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+	if data.is_null() {
+		(len == 0).then_some(&[][..])
+	} else {
+		// Safety: guaranteed by the caller.
+		Some(unsafe { slice::from_raw_parts(data, len) })
+	}
+}
+
+/// Deserializes a constraint system and stores a handle to it in `*out`.
+///
+/// The handle must be released with [`binius_constraint_system_free`].
+///
+/// # Safety
+/// `data` must point to `len` readable bytes and `out` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn binius_constraint_system_deserialize(
+	data: *const u8,
+	len: usize,
+	out: *mut *mut BiniusConstraintSystem,
+) -> BiniusStatus {
+	guard(|| {
+		// Safety: guaranteed by the caller.
+		let Some(data) = (unsafe { bytes(data, len) }) else {
+			return fail(BiniusStatus::NullPointer, "data is null");
+		};
+		if out.is_null() {
+			return fail(BiniusStatus::NullPointer, "out is null");
+		}
+		match ConstraintSystem::deserialize(data, SerializationMode::CanonicalTower) {
+			Ok(constraint_system) => {
+				let handle = Box::new(BiniusConstraintSystem(constraint_system));
+				// Safety: `out` is non-null and valid by the caller's guarantee.
+				unsafe { *out = Box::into_raw(handle) };
+				BiniusStatus::Ok
+			}
+			Err(err) => fail(BiniusStatus::InvalidConstraintSystem, err),
+		}
+	})
+}
+
+/// Releases a constraint system handle. Null handles are ignored.
+///
+/// # Safety
+/// `constraint_system` must be null or a handle returned by
+/// [`binius_constraint_system_deserialize`] that has not been released yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn binius_constraint_system_free(
+	constraint_system: *mut BiniusConstraintSystem,
+) {
+	if !constraint_system.is_null() {
+		// Safety: guaranteed by the caller.
+		drop(unsafe { Box::from_raw(constraint_system) });
+	}
+}
+
+/// Verifies a proof against a constraint system and its boundaries.
+///
+/// `boundaries` holds a serialized list of boundaries, and `proof` a serialized proof. Returns
+/// [`BiniusStatus::Ok`] if the proof is valid and [`BiniusStatus::VerificationFailed`] if it is
+/// not.
+///
+/// # Safety
+/// `constraint_system` must be a live handle, and `boundaries` and `proof` must point to
+/// `boundaries_len` and `proof_len` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn binius_verify(
+	constraint_system: *const BiniusConstraintSystem,
+	security_bits: usize,
+	log_inv_rate: usize,
+	boundaries: *const u8,
+	boundaries_len: usize,
+	proof: *const u8,
+	proof_len: usize,
+) -> BiniusStatus {
+	guard(|| {
+		// Safety: guaranteed by the caller.
+		let Some(constraint_system) = (unsafe { constraint_system.as_ref() }) else {
+			return fail(BiniusStatus::NullPointer, "constraint_system is null");
+		};
+		// Safety: guaranteed by the caller.
+		let (Some(boundaries), Some(proof)) =
+			(unsafe { (bytes(boundaries, boundaries_len), bytes(proof, proof_len)) })
+		else {
+			return fail(BiniusStatus::NullPointer, "boundaries or proof is null");
+		};
+
+		let mode = SerializationMode::CanonicalTower;
+		let boundaries = match Vec::<Boundary<BinaryField128b>>::deserialize(boundaries, mode) {
+			Ok(boundaries) => boundaries,
+			Err(err) => return fail(BiniusStatus::InvalidBoundaries, err),
+		};
+		let proof = match Proof::deserialize(proof, mode) {
+			Ok(proof) => proof,
+			Err(err) => return fail(BiniusStatus::InvalidProof, err),
+		};
+
+		match constraint_system::verify::<
+			OptimalUnderlier,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(
+			&constraint_system.0,
+			SecurityLevel::new(security_bits, log_inv_rate),
+			&boundaries,
+			proof,
+		) {
+			Ok(()) => BiniusStatus::Ok,
+			Err(err) => fail(BiniusStatus::VerificationFailed, err),
+		}
+	})
+}
+
+/// Copies the message of the last error on the calling thread into `buf` as a null-terminated
+/// string, truncated to `len` bytes, and returns the length of the full message.
+///
+/// # Safety
+/// `buf` must be null or point to `len` writable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn binius_last_error_message(buf: *mut c_char, len: usize) -> usize {
+	LAST_ERROR.with(|last_error| {
+		let message = last_error.borrow();
+		if !buf.is_null() && len > 0 {
+			let n = message.len().min(len - 1);
+			// Safety: `buf` points to `len` writable bytes and `n < len`.
+			unsafe {
+				ptr::copy_nonoverlapping(message.as_ptr().cast(), buf, n);
+				*buf.add(n) = 0;
+			}
+		}
+		message.len()
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_circuits::{
+		arithmetic,
+		builder::{ConstraintSystemBuilder, types::U},
+		unconstrained::unconstrained,
+	};
+	use binius_field::BinaryField1b;
+	use binius_hal::make_portable_backend;
+	use binius_utils::SerializeBytes;
+
+	use super::*;
+
+	#[test]
+	fn test_verify() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", 10).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", 10).unwrap();
+		arithmetic::u32::add(&mut builder, "a + b", a, b, arithmetic::Flags::Unchecked).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let security = SecurityLevel::new(10, 1);
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, security, &[], witness, &make_portable_backend())
+		.unwrap();
+
+		let mode = SerializationMode::CanonicalTower;
+		let mut cs_bytes = Vec::new();
+		constraint_system.serialize(&mut cs_bytes, mode).unwrap();
+		let mut boundary_bytes = Vec::new();
+		Vec::<Boundary<BinaryField128b>>::new()
+			.serialize(&mut boundary_bytes, mode)
+			.unwrap();
+		let mut proof_bytes = Vec::new();
+		proof.serialize(&mut proof_bytes, mode).unwrap();
+
+		let verify = |handle: *const BiniusConstraintSystem, proof_bytes: &[u8]| unsafe {
+			binius_verify(
+				handle,
+				security.security_bits,
+				security.log_inv_rate,
+				boundary_bytes.as_ptr(),
+				boundary_bytes.len(),
+				proof_bytes.as_ptr(),
+				proof_bytes.len(),
+			)
+		};
+
+		let mut handle = ptr::null_mut();
+		let status = unsafe {
+			binius_constraint_system_deserialize(cs_bytes.as_ptr(), cs_bytes.len(), &mut handle)
+		};
+		assert_eq!(status, BiniusStatus::Ok);
+		assert_eq!(verify(handle, &proof_bytes), BiniusStatus::Ok);
+
+		let last = proof_bytes.len() - 1;
+		proof_bytes[last] ^= 1;
+		assert_eq!(verify(handle, &proof_bytes), BiniusStatus::VerificationFailed);
+		let message_len = unsafe { binius_last_error_message(ptr::null_mut(), 0) };
+		assert!(message_len > 0);
+
+		assert_eq!(verify(handle, &proof_bytes[..3]), BiniusStatus::InvalidProof);
+		assert_eq!(verify(ptr::null(), &proof_bytes), BiniusStatus::NullPointer);
+		unsafe { binius_constraint_system_free(handle) };
+	}
+}
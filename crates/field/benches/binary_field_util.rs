@@ -2,9 +2,12 @@
 
 use std::iter::repeat_with;
 
-use binius_field::{BinaryField1b, BinaryField32b, BinaryField128b, ExtensionField, PackedField};
+use binius_field::{
+	BinaryField1b, BinaryField32b, BinaryField128b, ExtensionField, PackedAESBinaryField16x8b,
+	PackedBinaryPolyval1x128b, PackedField,
+};
 use criterion::{
-	BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::WallTime,
+	BenchmarkGroup, Criterion, Throughput, criterion_group, criterion_main, measurement::WallTime,
 };
 
 pub fn bench_inner_product_par<FX, PX, PY>(
@@ -50,5 +53,48 @@ fn inner_product_par(c: &mut Criterion) {
 	);
 }
 
-criterion_group!(binary_field_utils, inner_product_par);
+pub fn bench_mul_packed_slices<P: PackedField>(
+	group: &mut BenchmarkGroup<WallTime>,
+	name: &str,
+	counts: impl Iterator<Item = usize>,
+) {
+	let mut rng = rand::thread_rng();
+	for count in counts {
+		let lhs = repeat_with(|| P::random(&mut rng))
+			.take(count)
+			.collect::<Vec<P>>();
+		let rhs = repeat_with(|| P::random(&mut rng))
+			.take(count)
+			.collect::<Vec<P>>();
+		let mut out = vec![P::zero(); count];
+		group.throughput(Throughput::Elements((count * P::WIDTH) as u64));
+		group.bench_function(format!("{name}/{count}"), |bench| {
+			bench.iter(|| binius_field::util::mul_packed_slices(&lhs, &rhs, &mut out));
+		});
+		group.bench_function(format!("{name}/{count}/packed"), |bench| {
+			bench.iter(|| {
+				for ((out, &lhs), &rhs) in out.iter_mut().zip(&lhs).zip(&rhs) {
+					*out = lhs * rhs;
+				}
+			});
+		});
+	}
+}
+
+fn mul_packed_slices(c: &mut Criterion) {
+	let mut group = c.benchmark_group("mul_packed_slices");
+	let counts = [128usize, 1024, 1 << 16];
+	bench_mul_packed_slices::<PackedAESBinaryField16x8b>(
+		&mut group,
+		"aes_16x8b",
+		counts.iter().copied(),
+	);
+	bench_mul_packed_slices::<PackedBinaryPolyval1x128b>(
+		&mut group,
+		"polyval_1x128b",
+		counts.iter().copied(),
+	);
+}
+
+criterion_group!(binary_field_utils, inner_product_par, mul_packed_slices);
 criterion_main!(binary_field_utils);
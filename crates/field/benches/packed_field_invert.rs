@@ -22,8 +22,8 @@ fn invert_main<T: PackedField>(val: T) -> T {
 cfg_if! {
 	if #[cfg(feature = "benchmark_alternative_strategies")] {
 		use binius_field::{
-			arch::{HybridRecursiveStrategy, PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
-				PairwiseTableStrategy, SimdStrategy,},
+			arch::{ConstantTimeStrategy, HybridRecursiveStrategy, PackedStrategy,
+				PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy, SimdStrategy, GfniRuntimeStrategy,},
 			arithmetic_traits::TaggedInvertOrZero,
 		};
 
@@ -47,10 +47,18 @@ cfg_if! {
 			val.invert_or_zero()
 		}
 
+		fn invert_constant_time<T: TaggedInvertOrZero<ConstantTimeStrategy>>(val: T) -> T {
+			val.invert_or_zero()
+		}
+
 		fn invert_simd<T: TaggedInvertOrZero<SimdStrategy>>(val: T) -> T {
 			val.invert_or_zero()
 		}
 
+		fn invert_gfni_runtime<T: TaggedInvertOrZero<GfniRuntimeStrategy>>(val: T) -> T {
+			val.invert_or_zero()
+		}
+
 		benchmark_packed_operation!(
 			op_name @ invert,
 			bench_type @ unary_op,
@@ -61,7 +69,9 @@ cfg_if! {
 				(pairwise_table, TaggedInvertOrZero::<PairwiseTableStrategy>, invert_pairwise_table),
 				(hybrid_recursive, TaggedInvertOrZero::<HybridRecursiveStrategy>, invert_hybrid_recursive),
 				(packed, TaggedInvertOrZero::<PackedStrategy>, invert_packed),
+				(constant_time, TaggedInvertOrZero::<ConstantTimeStrategy>, invert_constant_time),
 				(simd, TaggedInvertOrZero::<SimdStrategy>, invert_simd),
+				(gfni_runtime, TaggedInvertOrZero::<GfniRuntimeStrategy>, invert_gfni_runtime),
 			)
 		);
 	} else {
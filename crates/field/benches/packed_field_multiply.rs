@@ -26,8 +26,8 @@ fn mul_main<T: SelfMul>(lhs: T, rhs: T) -> T {
 cfg_if! {
 	if #[cfg(feature = "benchmark_alternative_strategies")] {
 		use binius_field::{
-			arch::{HybridRecursiveStrategy, PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
-				PairwiseTableStrategy, SimdStrategy,},
+			arch::{ConstantTimeStrategy, HybridRecursiveStrategy, PackedStrategy,
+				PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy, SimdStrategy, GfniRuntimeStrategy,},
 			arithmetic_traits::TaggedMul
 		};
 
@@ -51,10 +51,18 @@ cfg_if! {
 			TaggedMul::<HybridRecursiveStrategy>::mul(lhs, rhs)
 		}
 
+		fn mul_constant_time<T: TaggedMul<ConstantTimeStrategy>>(lhs: T, rhs: T) -> T {
+			TaggedMul::<ConstantTimeStrategy>::mul(lhs, rhs)
+		}
+
 		fn mul_simd<T: TaggedMul<SimdStrategy>>(lhs: T, rhs: T) -> T {
 			TaggedMul::<SimdStrategy>::mul(lhs, rhs)
 		}
 
+		fn mul_gfni_runtime<T: TaggedMul<GfniRuntimeStrategy>>(lhs: T, rhs: T) -> T {
+			TaggedMul::<GfniRuntimeStrategy>::mul(lhs, rhs)
+		}
+
 		benchmark_packed_operation!(
 			op_name @ multiply,
 			bench_type @ binary_op,
@@ -65,7 +73,9 @@ cfg_if! {
 				(pairwise_table, TaggedMul::<PairwiseTableStrategy>, mul_pairwise_table),
 				(hybrid_recursive, TaggedMul::<HybridRecursiveStrategy>, mul_hybrid_recursive),
 				(packed, TaggedMul::<PackedStrategy>, mul_packed),
+				(constant_time, TaggedMul::<ConstantTimeStrategy>, mul_constant_time),
 				(simd, TaggedMul::<SimdStrategy>, mul_simd),
+				(gfni_runtime, TaggedMul::<GfniRuntimeStrategy>, mul_gfni_runtime),
 			)
 		);
 	} else {
@@ -23,7 +23,7 @@ cfg_if! {
 	if #[cfg(feature = "benchmark_alternative_strategies")] {
 		use binius_field::{
 			arch::{HybridRecursiveStrategy, PackedStrategy, PairwiseStrategy, PairwiseRecursiveStrategy,
-				PairwiseTableStrategy, SimdStrategy,},
+				PairwiseTableStrategy, SimdStrategy, GfniRuntimeStrategy,},
 			arithmetic_traits::TaggedSquare
 		};
 
@@ -51,6 +51,10 @@ cfg_if! {
 			val.square()
 		}
 
+		fn square_gfni_runtime<T: TaggedSquare<GfniRuntimeStrategy>>(val: T) -> T {
+			val.square()
+		}
+
 		benchmark_packed_operation!(
 			op_name @ square,
 			bench_type @ unary_op,
@@ -62,6 +66,7 @@ cfg_if! {
 				(hybrid_recursive, TaggedSquare::<HybridRecursiveStrategy>, square_hybrid_recursive),
 				(packed, TaggedSquare::<PackedStrategy>, square_packed),
 				(simd, TaggedSquare::<SimdStrategy>, square_simd),
+				(gfni_runtime, TaggedSquare::<GfniRuntimeStrategy>, square_gfni_runtime),
 			)
 		);
 	} else {
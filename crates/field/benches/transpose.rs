@@ -0,0 +1,65 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	PackedBinaryField16x8b, PackedBinaryField128x1b, PackedField, square_transpose,
+	transpose_scalars,
+};
+use criterion::{
+	BenchmarkGroup, Criterion, Throughput, black_box, criterion_group, criterion_main,
+	measurement::WallTime,
+};
+use rand::thread_rng;
+
+const LOG_SIZE: usize = 16;
+
+fn bench_square_transpose<P: PackedField>(group: &mut BenchmarkGroup<'_, WallTime>, id: &str) {
+	let mut rng = thread_rng();
+	let mut elems = (0..1 << (LOG_SIZE - P::LOG_WIDTH))
+		.map(|_| P::random(&mut rng))
+		.collect::<Vec<_>>();
+
+	group.throughput(Throughput::Elements(1 << LOG_SIZE));
+	group.bench_function(id, |b| {
+		b.iter(|| square_transpose(P::LOG_WIDTH, black_box(&mut elems)).unwrap())
+	});
+}
+
+fn bench_transpose_scalars<P: PackedField>(
+	group: &mut BenchmarkGroup<'_, WallTime>,
+	id: &str,
+	log_cols: usize,
+) {
+	let mut rng = thread_rng();
+	let src = (0..1 << (LOG_SIZE - P::LOG_WIDTH))
+		.map(|_| P::random(&mut rng))
+		.collect::<Vec<_>>();
+	let mut dst = vec![P::default(); src.len()];
+
+	group.throughput(Throughput::Elements(1 << LOG_SIZE));
+	group.bench_function(format!("{id}/{log_cols}"), |b| {
+		b.iter(|| {
+			transpose_scalars(black_box(&src), &mut dst, LOG_SIZE - log_cols, log_cols).unwrap()
+		})
+	});
+}
+
+fn square(c: &mut Criterion) {
+	let mut group = c.benchmark_group("square_transpose");
+
+	bench_square_transpose::<PackedBinaryField128x1b>(&mut group, "128x1b");
+	bench_square_transpose::<PackedBinaryField16x8b>(&mut group, "16x8b");
+}
+
+fn scalars(c: &mut Criterion) {
+	let mut group = c.benchmark_group("transpose_scalars");
+
+	// Bit-slicing of 32-bit integers, which takes the scalar path.
+	bench_transpose_scalars::<PackedBinaryField128x1b>(&mut group, "128x1b", 5);
+	bench_transpose_scalars::<PackedBinaryField128x1b>(&mut group, "128x1b", 7);
+	bench_transpose_scalars::<PackedBinaryField128x1b>(&mut group, "128x1b", 8);
+	bench_transpose_scalars::<PackedBinaryField16x8b>(&mut group, "16x8b", 4);
+	bench_transpose_scalars::<PackedBinaryField16x8b>(&mut group, "16x8b", 8);
+}
+
+criterion_group!(transpose, square, scalars);
+criterion_main!(transpose);
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{
+use core::{
 	any::TypeId,
 	fmt::{Debug, Display, Formatter},
 	iter::{Product, Sum},
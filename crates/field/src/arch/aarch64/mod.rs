@@ -1,7 +1,21 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+//! NEON implementations of the 128-bit packed fields.
+//!
+//! There are no native 256-bit or 512-bit registers to target, so the wider packed fields are
+//! the portable [`ScaledPackedField`](super::portable::packed_scaled::ScaledPackedField) types
+//! over two or four of the 128-bit packed fields defined here. Their multiplication, squaring,
+//! inversion and multiplication by alpha forward lane-by-lane to the NEON strategies selected in
+//! [`packed_128`], [`packed_aes_128`] and [`packed_polyval_128`].
+//!
+//! On CPUs with SVE2, the [`sve2`] kernels multiply slices of field elements with registers of
+//! the implementation's vector length, which may be wider than 128 bits. They back
+//! [`mul_packed_slices`](crate::util::mul_packed_slices).
+
 use cfg_if::cfg_if;
 
+pub mod sve2;
+
 cfg_if! {
 	if #[cfg(all(target_feature = "neon", target_feature = "aes"))] {
 		pub(super) mod m128;
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::ops::Mul;
+use core::ops::Mul;
 
 use super::{
 	super::portable::{
@@ -14,8 +14,7 @@
 //! - <https://developer.arm.com/documentation/100069/0608/A64-SIMD-Vector-Instructions/PMULL--PMULL2--vector->
 //! - <https://eprint.iacr.org/2015/688.pdf>
 
-use core::{arch::aarch64::*, mem};
-use std::ops::Mul;
+use core::{arch::aarch64::*, mem, ops::Mul};
 
 use super::{super::portable::packed::PackedPrimitiveType, m128::M128};
 use crate::{
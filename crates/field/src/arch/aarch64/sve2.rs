@@ -0,0 +1,249 @@
+// Copyright 2025 Irreducible Inc.
+
+//! SVE2 kernels for element-wise multiplication of field element slices.
+//!
+//! SVE registers have a length chosen by the implementation, from 128 to 2048 bits, so the packed
+//! field types, which have a fixed width, can't be backed by them. Instead, these kernels process
+//! whole slices in vector-length-agnostic loops, where every iteration handles as many elements as
+//! fit in a register and the last iteration is predicated on the remaining elements. Rust has no
+//! SVE intrinsics, so the loops are written in inline assembly.
+//!
+//! The kernels are selected at runtime with [`CpuFeatures`], and fall back to multiplying the
+//! slices element by element with the scalar field multiplication when the instructions are not
+//! available. [`mul_packed_slices`](crate::util::mul_packed_slices) dispatches slices of packed
+//! elements with these scalars to the kernels only when the instructions are available, and keeps
+//! the packed multiplication otherwise.
+//!
+//! * [`mul_aes_8b`] multiplies [`AESTowerField8b`] elements with the SVE2 `PMULLB`/`PMULLT`
+//!   instructions on bytes.
+//! * [`mul_polyval_128b`] multiplies [`BinaryField128bPolyval`] elements with the 64-bit
+//!   `PMULLB`/`PMULLT` instructions of the SVE2 AES extension.
+
+use core::arch::asm;
+
+use crate::{AESTowerField8b, BinaryField128bPolyval, arch::CpuFeatures};
+
+/// Multiplies `lhs` and `rhs` element-wise into `out`.
+///
+/// ## Preconditions
+///
+/// * `lhs`, `rhs` and `out` must have the same length
+pub fn mul_aes_8b(lhs: &[AESTowerField8b], rhs: &[AESTowerField8b], out: &mut [AESTowerField8b]) {
+	assert_eq!(lhs.len(), rhs.len());
+	assert_eq!(lhs.len(), out.len());
+
+	if CpuFeatures::get().sve2 {
+		let lhs = bytemuck::cast_slice::<_, u8>(lhs);
+		let rhs = bytemuck::cast_slice::<_, u8>(rhs);
+		let out = bytemuck::cast_slice_mut::<_, u8>(out);
+		// Safety: SVE2 support has been detected, and the slices have the same length.
+		unsafe { mul_aes_8b_sve2(lhs.as_ptr(), rhs.as_ptr(), out.as_mut_ptr(), out.len()) }
+	} else {
+		for ((out, &lhs), &rhs) in out.iter_mut().zip(lhs).zip(rhs) {
+			*out = lhs * rhs;
+		}
+	}
+}
+
+/// Multiplies `lhs` and `rhs` element-wise into `out`.
+///
+/// ## Preconditions
+///
+/// * `lhs`, `rhs` and `out` must have the same length
+pub fn mul_polyval_128b(
+	lhs: &[BinaryField128bPolyval],
+	rhs: &[BinaryField128bPolyval],
+	out: &mut [BinaryField128bPolyval],
+) {
+	assert_eq!(lhs.len(), rhs.len());
+	assert_eq!(lhs.len(), out.len());
+
+	let features = CpuFeatures::get();
+	if features.sve2 && features.sve2_aes {
+		let lhs = bytemuck::cast_slice::<_, u64>(lhs);
+		let rhs = bytemuck::cast_slice::<_, u64>(rhs);
+		let out = bytemuck::cast_slice_mut::<_, u64>(out);
+		// Safety: SVE2 and SVE2 AES support has been detected, and the slices have the same
+		// length.
+		unsafe { mul_polyval_128b_sve2(lhs.as_ptr(), rhs.as_ptr(), out.as_mut_ptr(), out.len()) }
+	} else {
+		for ((out, &lhs), &rhs) in out.iter_mut().zip(lhs).zip(rhs) {
+			*out = lhs * rhs;
+		}
+	}
+}
+
+/// Multiplies `len` bytes in GF(2^8) modulo `x^8 + x^4 + x^3 + x + 1`.
+///
+/// The bytes at even and odd positions are multiplied into 16-bit lanes, and every product
+/// `h * x^8 + l` is reduced as `l + h * q(x)`, where `q(x) = x^4 + x^3 + x + 1`, twice, as
+/// `h * q(x)` has up to 12 bits.
+///
+/// # Safety
+/// The CPU must support SVE2, and the pointers must be valid for `len` bytes.
+#[target_feature(enable = "sve2")]
+unsafe fn mul_aes_8b_sve2(lhs: *const u8, rhs: *const u8, out: *mut u8, len: usize) {
+	unsafe {
+		asm!(
+			"dup z6.h, #0x1b",
+			"mov {i}, #0",
+			"whilelo p0.b, {i}, {len}",
+			"b.none 3f",
+			"2:",
+			"ld1b {{z0.b}}, p0/z, [{lhs}, {i}]",
+			"ld1b {{z1.b}}, p0/z, [{rhs}, {i}]",
+			// Products of the even and odd bytes.
+			"pmullb z2.h, z0.b, z1.b",
+			"pmullt z3.h, z0.b, z1.b",
+			// First reduction of the high bytes.
+			"lsr z4.h, z2.h, #8",
+			"lsr z5.h, z3.h, #8",
+			"pmullb z4.h, z4.b, z6.b",
+			"pmullb z5.h, z5.b, z6.b",
+			"eor z2.d, z2.d, z4.d",
+			"eor z3.d, z3.d, z5.d",
+			// Second reduction of the bits of the first one that overflow the low byte.
+			"lsr z4.h, z4.h, #8",
+			"lsr z5.h, z5.h, #8",
+			"pmullb z4.h, z4.b, z6.b",
+			"pmullb z5.h, z5.b, z6.b",
+			"eor z2.d, z2.d, z4.d",
+			"eor z3.d, z3.d, z5.d",
+			// Interleave the low bytes of the even and odd products.
+			"trn1 z0.b, z2.b, z3.b",
+			"st1b {{z0.b}}, p0, [{out}, {i}]",
+			"incb {i}",
+			"whilelo p0.b, {i}, {len}",
+			"b.first 2b",
+			"3:",
+			lhs = in(reg) lhs,
+			rhs = in(reg) rhs,
+			out = in(reg) out,
+			len = in(reg) len,
+			i = out(reg) _,
+			out("v0") _,
+			out("v1") _,
+			out("v2") _,
+			out("v3") _,
+			out("v4") _,
+			out("v5") _,
+			out("v6") _,
+			out("p0") _,
+			options(nostack),
+		);
+	}
+}
+
+/// Multiplies `len / 2` POLYVAL elements, given as pairs of 64-bit words in Montgomery form.
+///
+/// This is the Montgomery multiplication of the NEON backend, with the operations on the halves
+/// of a 128-bit register replaced by `TRN1`/`TRN2` on the pairs of 64-bit lanes of every 128-bit
+/// segment. The product is computed with four multiplications rather than with Karatsuba, as
+/// there is no instruction to swap the halves of a segment.
+///
+/// # Safety
+/// The CPU must support SVE2 and SVE2 AES, `len` must be even, and the pointers must be valid for
+/// `len` words.
+#[target_feature(enable = "sve2,sve2-aes")]
+unsafe fn mul_polyval_128b_sve2(lhs: *const u64, rhs: *const u64, out: *mut u64, len: usize) {
+	// The low and high words of x^127 + x^126 + x^121 + x^63 + x^62 + x^57.
+	const POLY: u64 = 1 << 63 | 1 << 62 | 1 << 57;
+
+	unsafe {
+		asm!(
+			"dup z6.d, {poly}",
+			"dup z7.d, #0",
+			"mov {i}, #0",
+			"whilelo p0.d, {i}, {len}",
+			"b.none 3f",
+			"2:",
+			"ld1d {{z0.d}}, p0/z, [{lhs}, {i}, lsl #3]",
+			"ld1d {{z1.d}}, p0/z, [{rhs}, {i}, lsl #3]",
+			// L = x0 * y0, H = x1 * y1
+			"pmullb z2.q, z0.d, z1.d",
+			"pmullt z3.q, z0.d, z1.d",
+			// M = x0 * y1 + x1 * y0
+			"trn2 z4.d, z1.d, z1.d",
+			"trn1 z5.d, z1.d, z1.d",
+			"pmullb z4.q, z0.d, z4.d",
+			"pmullt z5.q, z0.d, z5.d",
+			"eor z4.d, z4.d, z5.d",
+			// [X1 : X0] = L + [m0 : 0], [X3 : X2] = H + [0 : m1]
+			"trn1 z5.d, z7.d, z4.d",
+			"eor z2.d, z2.d, z5.d",
+			"trn2 z5.d, z4.d, z7.d",
+			"eor z3.d, z3.d, z5.d",
+			// A = X0 * poly, B = [X1 + a0 : X0 + a1]
+			"pmullb z4.q, z2.d, z6.d",
+			"trn2 z5.d, z4.d, z4.d",
+			"trn1 z5.d, z5.d, z4.d",
+			"eor z2.d, z2.d, z5.d",
+			// C = B1 * poly, D = [X3 : X2] + C + B
+			"pmullt z4.q, z2.d, z6.d",
+			"eor z3.d, z3.d, z4.d",
+			"eor z3.d, z3.d, z2.d",
+			"st1d {{z3.d}}, p0, [{out}, {i}, lsl #3]",
+			"incd {i}",
+			"whilelo p0.d, {i}, {len}",
+			"b.first 2b",
+			"3:",
+			poly = in(reg) POLY,
+			lhs = in(reg) lhs,
+			rhs = in(reg) rhs,
+			out = in(reg) out,
+			len = in(reg) len,
+			i = out(reg) _,
+			out("v0") _,
+			out("v1") _,
+			out("v2") _,
+			out("v3") _,
+			out("v4") _,
+			out("v5") _,
+			out("v6") _,
+			out("v7") _,
+			out("p0") _,
+			options(nostack),
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::*;
+
+	proptest! {
+		#[test]
+		fn test_mul_aes_8b_matches_scalar(
+			values in prop::collection::vec(any::<(u8, u8)>(), 0..300),
+		) {
+			let (lhs, rhs): (Vec<_>, Vec<_>) = values
+				.into_iter()
+				.map(|(a, b)| (AESTowerField8b::new(a), AESTowerField8b::new(b)))
+				.unzip();
+			let mut out = vec![AESTowerField8b::default(); lhs.len()];
+			mul_aes_8b(&lhs, &rhs, &mut out);
+
+			for ((&out, &lhs), &rhs) in out.iter().zip(&lhs).zip(&rhs) {
+				prop_assert_eq!(out, lhs * rhs);
+			}
+		}
+
+		#[test]
+		fn test_mul_polyval_128b_matches_scalar(
+			values in prop::collection::vec(any::<(u128, u128)>(), 0..40),
+		) {
+			let (lhs, rhs): (Vec<_>, Vec<_>) = values
+				.into_iter()
+				.map(|(a, b)| (BinaryField128bPolyval::new(a), BinaryField128bPolyval::new(b)))
+				.unzip();
+			let mut out = vec![BinaryField128bPolyval::default(); lhs.len()];
+			mul_polyval_128b(&lhs, &rhs, &mut out);
+
+			for ((&out, &lhs), &rhs) in out.iter().zip(&lhs).zip(&rhs) {
+				prop_assert_eq!(out, lhs * rhs);
+			}
+		}
+	}
+}
@@ -0,0 +1,95 @@
+// Copyright 2025 Irreducible Inc.
+
+/// Instruction set extensions used by the packed field arithmetic that are available on the CPU
+/// the program is running on.
+///
+/// Most backends are selected at compile time with `target_feature` flags. Operations that have a
+/// runtime-dispatched implementation (see [`super::GfniRuntimeStrategy`], the SVE2 kernels on
+/// aarch64 and the Grøstl permutation of `binius_hash`) consult this structure instead, so that a
+/// binary built for a baseline target still uses the faster instructions when they are present.
+/// Only the extensions consulted by such an implementation are detected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CpuFeatures {
+	pub gfni: bool,
+	pub avx512f: bool,
+	pub avx512bw: bool,
+	pub avx512vbmi: bool,
+	pub sve2: bool,
+	pub sve2_aes: bool,
+}
+
+impl CpuFeatures {
+	/// Returns the features of the running CPU.
+	///
+	/// The detection runs once and its result is cached for the lifetime of the process. Without
+	/// the `std` feature, the features are the compile-time target features.
+	#[cfg(feature = "std")]
+	#[inline]
+	pub fn get() -> Self {
+		static FEATURES: std::sync::OnceLock<CpuFeatures> = std::sync::OnceLock::new();
+
+		*FEATURES.get_or_init(Self::detect)
+	}
+
+	/// Returns the features of the running CPU.
+	///
+	/// Without the `std` feature, the features are the compile-time target features.
+	#[cfg(not(feature = "std"))]
+	#[inline]
+	pub const fn get() -> Self {
+		Self {
+			gfni: cfg!(target_feature = "gfni"),
+			avx512f: cfg!(target_feature = "avx512f"),
+			avx512bw: cfg!(target_feature = "avx512bw"),
+			avx512vbmi: cfg!(target_feature = "avx512vbmi"),
+			sve2: cfg!(target_feature = "sve2"),
+			sve2_aes: cfg!(target_feature = "sve2-aes"),
+		}
+	}
+
+	#[cfg(all(feature = "std", target_arch = "x86_64"))]
+	fn detect() -> Self {
+		Self {
+			gfni: std::is_x86_feature_detected!("gfni"),
+			avx512f: std::is_x86_feature_detected!("avx512f"),
+			avx512bw: std::is_x86_feature_detected!("avx512bw"),
+			avx512vbmi: std::is_x86_feature_detected!("avx512vbmi"),
+			..Self::default()
+		}
+	}
+
+	#[cfg(all(feature = "std", target_arch = "aarch64"))]
+	fn detect() -> Self {
+		Self {
+			sve2: std::arch::is_aarch64_feature_detected!("sve2"),
+			sve2_aes: std::arch::is_aarch64_feature_detected!("sve2-aes"),
+			..Self::default()
+		}
+	}
+
+	#[cfg(all(
+		feature = "std",
+		not(any(target_arch = "x86_64", target_arch = "aarch64"))
+	))]
+	fn detect() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_compile_time_features_are_detected() {
+		let features = CpuFeatures::get();
+
+		assert_eq!(features, CpuFeatures::get());
+		assert!(!cfg!(target_feature = "gfni") || features.gfni);
+		assert!(!cfg!(target_feature = "avx512f") || features.avx512f);
+		assert!(!cfg!(target_feature = "avx512bw") || features.avx512bw);
+		assert!(!cfg!(target_feature = "avx512vbmi") || features.avx512vbmi);
+		assert!(!cfg!(target_feature = "sve2") || features.sve2);
+		assert!(!cfg!(target_feature = "sve2-aes") || features.sve2_aes);
+	}
+}
@@ -0,0 +1,212 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Differential tests between the portable packed fields, the packed fields selected for the
+//! target architecture, and the strategies that implement the arithmetic of a packed type.
+//!
+//! The packed types of the SIMD backends (NEON, AVX2, AVX-512, GFNI, ...) are selected at compile
+//! time by the enabled target features, so a single build compares only the backend it was
+//! compiled for against the portable implementation of every packed type, which is always
+//! compiled. Run the tests with different `RUSTFLAGS`, e.g. `-C target-cpu=native` or
+//! `-C target-feature=+avx2`, to cover the different backends. When no SIMD backend is available,
+//! both sides of each comparison are the same type.
+//!
+//! Within a build, the arithmetic strategies are selected at runtime through the
+//! [`TaggedMul`]-style traits, which can instantiate several strategies for the same packed type.
+//! Every target type is also checked against its lane-by-lane [`PairwiseStrategy`], and the
+//! runtime-dispatched strategies are checked against their fallbacks in their own modules.
+
+use proptest::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use super::{PairwiseStrategy, portable};
+use crate::{
+	Field, PackedField,
+	arithmetic_traits::{MulAlpha, TaggedInvertOrZero, TaggedMul, TaggedMulAlpha, TaggedSquare},
+	linear_transformation::{
+		FieldLinearTransformation, PackedTransformationFactory, Transformation,
+	},
+	packed::PackedBinaryField,
+};
+
+/// Returns a pair of random packed elements of `PRef` and their bitwise copies in `PTest`.
+///
+/// Some lanes are set to zero to cover the corner case of inversion.
+fn random_pair<PRef, PTest>(rng: &mut StdRng) -> (PRef, PTest)
+where
+	PRef: PackedField,
+	PTest: PackedField<Scalar = PRef::Scalar>,
+{
+	let mut reference = PRef::random(&mut *rng);
+	for i in 0..PRef::WIDTH {
+		if rng.gen_ratio(1, 8) {
+			reference.set(i, <PRef::Scalar as Field>::ZERO);
+		}
+	}
+	let test = PTest::from_scalars(reference.iter());
+	(reference, test)
+}
+
+fn assert_same<PRef, PTest>(reference: PRef, test: PTest, op: &str)
+where
+	PRef: PackedField,
+	PTest: PackedField<Scalar = PRef::Scalar>,
+{
+	assert!(
+		reference.iter().eq(test.iter()),
+		"{op} mismatch between {} and {}: {reference:?} != {test:?}",
+		core::any::type_name::<PRef>(),
+		core::any::type_name::<PTest>(),
+	);
+}
+
+/// Checks that `PRef` and `PTest` agree on multiplication, squaring, inversion and linear
+/// transformations, and that `PTest` agrees with its [`PairwiseStrategy`] arithmetic.
+fn check_arithmetic<PRef, PTest>(seed: u64)
+where
+	PRef: PackedBinaryField + PackedTransformationFactory<PRef>,
+	PTest: PackedBinaryField<Scalar = PRef::Scalar>
+		+ PackedTransformationFactory<PTest>
+		+ TaggedMul<PairwiseStrategy>
+		+ TaggedSquare<PairwiseStrategy>
+		+ TaggedInvertOrZero<PairwiseStrategy>,
+{
+	let mut rng = StdRng::seed_from_u64(seed);
+	let (a_ref, a_test) = random_pair::<PRef, PTest>(&mut rng);
+	let (b_ref, b_test) = random_pair::<PRef, PTest>(&mut rng);
+
+	assert_same(a_ref * b_ref, a_test * b_test, "mul");
+	assert_same(a_ref.square(), PackedField::square(a_test), "square");
+	assert_same(a_ref.invert_or_zero(), PackedField::invert_or_zero(a_test), "invert_or_zero");
+
+	assert_same(
+		TaggedMul::<PairwiseStrategy>::mul(a_test, b_test),
+		a_test * b_test,
+		"pairwise mul",
+	);
+	assert_same(
+		TaggedSquare::<PairwiseStrategy>::square(a_test),
+		PackedField::square(a_test),
+		"pairwise square",
+	);
+	assert_same(
+		TaggedInvertOrZero::<PairwiseStrategy>::invert_or_zero(a_test),
+		PackedField::invert_or_zero(a_test),
+		"pairwise invert_or_zero",
+	);
+
+	let transformation = FieldLinearTransformation::<PRef::Scalar, _>::random(&mut rng);
+	let transformation_ref = PRef::make_packed_transformation(transformation.clone());
+	let transformation_test = PTest::make_packed_transformation(transformation);
+	assert_same(
+		transformation_ref.transform(&a_ref),
+		transformation_test.transform(&a_test),
+		"linear transformation",
+	);
+}
+
+/// Checks that `PRef` and `PTest` agree on multiplication by alpha, and that `PTest` agrees with
+/// its [`PairwiseStrategy`] multiplication by alpha.
+fn check_mul_alpha<PRef, PTest>(seed: u64)
+where
+	PRef: PackedField + MulAlpha,
+	PTest: PackedField<Scalar = PRef::Scalar> + MulAlpha + TaggedMulAlpha<PairwiseStrategy>,
+{
+	let mut rng = StdRng::seed_from_u64(seed);
+	let (a_ref, a_test) = random_pair::<PRef, PTest>(&mut rng);
+
+	assert_same(a_ref.mul_alpha(), MulAlpha::mul_alpha(a_test), "mul_alpha");
+	assert_same(
+		TaggedMulAlpha::<PairwiseStrategy>::mul_alpha(a_test),
+		MulAlpha::mul_alpha(a_test),
+		"pairwise mul_alpha",
+	);
+}
+
+/// Runs `$check` for every listed type, comparing `portable::$module::$ty` with
+/// `crate::arch::$module::$ty`.
+macro_rules! check_backends {
+	($check:ident, $seed:expr, $module:ident, [$($ty:ident),* $(,)?]) => {
+		$(
+			$check::<portable::$module::$ty, crate::arch::$module::$ty>($seed);
+		)*
+	};
+}
+
+proptest! {
+	#[test]
+	fn test_backends_agree_128(seed in any::<u64>()) {
+		check_backends!(check_arithmetic, seed, packed_128, [
+			PackedBinaryField128x1b, PackedBinaryField64x2b, PackedBinaryField32x4b,
+			PackedBinaryField16x8b, PackedBinaryField8x16b, PackedBinaryField4x32b,
+			PackedBinaryField2x64b, PackedBinaryField1x128b,
+		]);
+		check_backends!(check_mul_alpha, seed, packed_128, [
+			PackedBinaryField64x2b, PackedBinaryField32x4b, PackedBinaryField16x8b,
+			PackedBinaryField8x16b, PackedBinaryField4x32b, PackedBinaryField2x64b,
+			PackedBinaryField1x128b,
+		]);
+	}
+
+	#[test]
+	fn test_backends_agree_256(seed in any::<u64>()) {
+		check_backends!(check_arithmetic, seed, packed_256, [
+			PackedBinaryField256x1b, PackedBinaryField128x2b, PackedBinaryField64x4b,
+			PackedBinaryField32x8b, PackedBinaryField16x16b, PackedBinaryField8x32b,
+			PackedBinaryField4x64b, PackedBinaryField2x128b,
+		]);
+		check_backends!(check_mul_alpha, seed, packed_256, [
+			PackedBinaryField128x2b, PackedBinaryField64x4b, PackedBinaryField32x8b,
+			PackedBinaryField16x16b, PackedBinaryField8x32b, PackedBinaryField4x64b,
+			PackedBinaryField2x128b,
+		]);
+	}
+
+	#[test]
+	fn test_backends_agree_512(seed in any::<u64>()) {
+		check_backends!(check_arithmetic, seed, packed_512, [
+			PackedBinaryField512x1b, PackedBinaryField256x2b, PackedBinaryField128x4b,
+			PackedBinaryField64x8b, PackedBinaryField32x16b, PackedBinaryField16x32b,
+			PackedBinaryField8x64b, PackedBinaryField4x128b,
+		]);
+		check_backends!(check_mul_alpha, seed, packed_512, [
+			PackedBinaryField256x2b, PackedBinaryField128x4b, PackedBinaryField64x8b,
+			PackedBinaryField32x16b, PackedBinaryField16x32b, PackedBinaryField8x64b,
+			PackedBinaryField4x128b,
+		]);
+	}
+
+	#[test]
+	fn test_aes_backends_agree(seed in any::<u64>()) {
+		check_backends!(check_arithmetic, seed, packed_aes_128, [
+			PackedAESBinaryField16x8b, PackedAESBinaryField8x16b, PackedAESBinaryField4x32b,
+			PackedAESBinaryField2x64b, PackedAESBinaryField1x128b,
+		]);
+		check_backends!(check_mul_alpha, seed, packed_aes_128, [
+			PackedAESBinaryField16x8b, PackedAESBinaryField8x16b, PackedAESBinaryField4x32b,
+			PackedAESBinaryField2x64b, PackedAESBinaryField1x128b,
+		]);
+		check_backends!(check_arithmetic, seed, packed_aes_256, [
+			PackedAESBinaryField32x8b, PackedAESBinaryField16x16b, PackedAESBinaryField8x32b,
+			PackedAESBinaryField4x64b, PackedAESBinaryField2x128b,
+		]);
+		check_backends!(check_mul_alpha, seed, packed_aes_256, [
+			PackedAESBinaryField32x8b, PackedAESBinaryField16x16b, PackedAESBinaryField8x32b,
+			PackedAESBinaryField4x64b, PackedAESBinaryField2x128b,
+		]);
+		check_backends!(check_arithmetic, seed, packed_aes_512, [
+			PackedAESBinaryField64x8b, PackedAESBinaryField32x16b, PackedAESBinaryField16x32b,
+			PackedAESBinaryField8x64b, PackedAESBinaryField4x128b,
+		]);
+		check_backends!(check_mul_alpha, seed, packed_aes_512, [
+			PackedAESBinaryField64x8b, PackedAESBinaryField32x16b, PackedAESBinaryField16x32b,
+			PackedAESBinaryField8x64b, PackedAESBinaryField4x128b,
+		]);
+	}
+
+	#[test]
+	fn test_polyval_backends_agree(seed in any::<u64>()) {
+		check_backends!(check_arithmetic, seed, packed_polyval_128, [PackedBinaryPolyval1x128b]);
+		check_backends!(check_arithmetic, seed, packed_polyval_256, [PackedBinaryPolyval2x128b]);
+		check_backends!(check_arithmetic, seed, packed_polyval_512, [PackedBinaryPolyval4x128b]);
+	}
+}
@@ -0,0 +1,253 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Known-answer tests for the 8-bit packed field backends.
+//!
+//! The lookup tables, GFNI affine maps and log/exp tables used by the SIMD backends are checked
+//! against the vectors in `test_vectors/gf256_maps.rsp`, which were computed directly from the
+//! field definitions. Every 8-bit packed type, portable and architecture-specific, is evaluated on
+//! all 256 field elements. As with the differential tests, run with different `RUSTFLAGS` to cover
+//! the different backends.
+
+use binius_utils::test_vectors::parse_known_answers;
+
+use super::portable;
+use crate::{
+	AESTowerField8b, BinaryField8b, PackedField, arithmetic_traits::MulAlpha,
+	linear_transformation::Transformation, make_aes_to_binary_packed_transformer,
+	make_binary_to_aes_packed_transformer,
+};
+
+const GF256_MAPS: &str = include_str!("../../test_vectors/gf256_maps.rsp");
+
+/// The known answers of every map, indexed by the input byte.
+struct Gf256Maps {
+	tower_to_aes: [u8; 256],
+	aes_to_tower: [u8; 256],
+	tower_square: [u8; 256],
+	tower_invert_or_zero: [u8; 256],
+	tower_mul_alpha: [u8; 256],
+	aes_invert_or_zero: [u8; 256],
+	tower_exp: [u8; 256],
+	tower_log: [u8; 256],
+}
+
+impl Gf256Maps {
+	fn load() -> Self {
+		let mut maps = Self {
+			tower_to_aes: [0; 256],
+			aes_to_tower: [0; 256],
+			tower_square: [0; 256],
+			tower_invert_or_zero: [0; 256],
+			tower_mul_alpha: [0; 256],
+			aes_invert_or_zero: [0; 256],
+			tower_exp: [0; 256],
+			tower_log: [0; 256],
+		};
+
+		let records = parse_known_answers(GF256_MAPS).expect("test vectors are well-formed");
+		assert_eq!(records.len(), 256);
+		for record in &records {
+			let byte = |name: &str| match record.field(name) {
+				&[value] => value,
+				value => panic!("{name} at line {} is not a single byte: {value:?}", record.line),
+			};
+			let x = byte("X") as usize;
+			maps.tower_to_aes[x] = byte("TowerToAes");
+			maps.aes_to_tower[x] = byte("AesToTower");
+			maps.tower_square[x] = byte("TowerSquare");
+			maps.tower_invert_or_zero[x] = byte("TowerInvertOrZero");
+			maps.tower_mul_alpha[x] = byte("TowerMulAlpha");
+			maps.aes_invert_or_zero[x] = byte("AesInvertOrZero");
+			maps.tower_exp[x] = byte("TowerExp");
+			maps.tower_log[x] = byte("TowerLog");
+		}
+		maps
+	}
+
+	/// The product of two elements of `BinaryField8b`, computed with the exp/log tables.
+	fn tower_mul(&self, a: u8, b: u8) -> u8 {
+		if a == 0 || b == 0 {
+			return 0;
+		}
+		let log = (self.tower_log[a as usize] as usize + self.tower_log[b as usize] as usize) % 255;
+		self.tower_exp[log]
+	}
+
+	/// The product of two elements of `AESTowerField8b`, computed through the isomorphism.
+	fn aes_mul(&self, a: u8, b: u8) -> u8 {
+		let a = self.aes_to_tower[a as usize];
+		let b = self.aes_to_tower[b as usize];
+		self.tower_to_aes[self.tower_mul(a, b) as usize]
+	}
+}
+
+/// Returns all 256 field elements packed into `P`, in increasing order.
+fn all_elements<P: PackedField>(from_byte: impl Fn(u8) -> P::Scalar) -> Vec<P> {
+	(0..256 / P::WIDTH)
+		.map(|i| P::from_scalars((0..P::WIDTH).map(|j| from_byte((i * P::WIDTH + j) as u8))))
+		.collect()
+}
+
+fn assert_matches<P: PackedField>(
+	results: impl IntoIterator<Item = P>,
+	expected: impl Fn(u8) -> u8,
+	to_byte: impl Fn(P::Scalar) -> u8,
+	op: &str,
+) {
+	let actual = results
+		.into_iter()
+		.flat_map(|p| p.into_iter().map(&to_byte));
+	for (x, actual) in actual.enumerate() {
+		let x = x as u8;
+		assert_eq!(
+			actual,
+			expected(x),
+			"{op} of {x:#04x} in {} differs from the known answer",
+			core::any::type_name::<P>(),
+		);
+	}
+}
+
+fn check_tower<P: PackedField<Scalar = BinaryField8b> + MulAlpha>(maps: &Gf256Maps) {
+	let elements = all_elements::<P>(BinaryField8b::new);
+
+	let square = elements.iter().map(|p| p.square());
+	assert_matches(square, |x| maps.tower_square[x as usize], BinaryField8b::val, "square");
+
+	let invert = elements.iter().map(|p| p.invert_or_zero());
+	assert_matches(invert, |x| maps.tower_invert_or_zero[x as usize], BinaryField8b::val, "invert");
+
+	let mul_alpha = elements.iter().map(|p| p.mul_alpha());
+	assert_matches(
+		mul_alpha,
+		|x| maps.tower_mul_alpha[x as usize],
+		BinaryField8b::val,
+		"mul_alpha",
+	);
+
+	for a in 0..=255 {
+		let products = elements
+			.iter()
+			.map(|&p| P::broadcast(BinaryField8b::new(a)) * p);
+		assert_matches(products, |b| maps.tower_mul(a, b), BinaryField8b::val, "mul");
+	}
+}
+
+fn check_aes<P: PackedField<Scalar = AESTowerField8b> + MulAlpha>(maps: &Gf256Maps) {
+	let elements = all_elements::<P>(AESTowerField8b::new);
+
+	let square = elements.iter().map(|p| p.square());
+	assert_matches(square, |x| maps.aes_mul(x, x), AESTowerField8b::val, "square");
+
+	let invert = elements.iter().map(|p| p.invert_or_zero());
+	assert_matches(invert, |x| maps.aes_invert_or_zero[x as usize], AESTowerField8b::val, "invert");
+
+	let alpha = maps.tower_to_aes[0x10];
+	let mul_alpha = elements.iter().map(|p| p.mul_alpha());
+	assert_matches(mul_alpha, |x| maps.aes_mul(x, alpha), AESTowerField8b::val, "mul_alpha");
+
+	for a in 0..=255 {
+		let products = elements
+			.iter()
+			.map(|&p| P::broadcast(AESTowerField8b::new(a)) * p);
+		assert_matches(products, |b| maps.aes_mul(a, b), AESTowerField8b::val, "mul");
+	}
+}
+
+/// Checks the packed isomorphisms between `$tower` and `$aes` in both directions.
+macro_rules! check_isomorphism {
+	($maps:expr, $tower:ty, $aes:ty) => {{
+		let maps: &Gf256Maps = $maps;
+
+		let to_aes = make_binary_to_aes_packed_transformer::<$tower, $aes>();
+		let images = all_elements::<$tower>(BinaryField8b::new)
+			.iter()
+			.map(|p| to_aes.transform(p))
+			.collect::<Vec<$aes>>();
+		assert_matches(images, |x| maps.tower_to_aes[x as usize], AESTowerField8b::val, "to_aes");
+
+		let to_tower = make_aes_to_binary_packed_transformer::<$aes, $tower>();
+		let images = all_elements::<$aes>(AESTowerField8b::new)
+			.iter()
+			.map(|p| to_tower.transform(p))
+			.collect::<Vec<$tower>>();
+		assert_matches(images, |x| maps.aes_to_tower[x as usize], BinaryField8b::val, "to_tower");
+	}};
+}
+
+#[test]
+fn test_scalar_known_answers() {
+	let maps = Gf256Maps::load();
+	for x in 0..=255u8 {
+		let tower = BinaryField8b::new(x);
+		let aes = AESTowerField8b::new(x);
+		assert_eq!(AESTowerField8b::from(tower).val(), maps.tower_to_aes[x as usize]);
+		assert_eq!(BinaryField8b::from(aes).val(), maps.aes_to_tower[x as usize]);
+	}
+	check_tower::<BinaryField8b>(&maps);
+	check_aes::<AESTowerField8b>(&maps);
+}
+
+#[test]
+fn test_portable_known_answers() {
+	let maps = Gf256Maps::load();
+	check_tower::<portable::packed_8::PackedBinaryField1x8b>(&maps);
+	check_tower::<portable::packed_16::PackedBinaryField2x8b>(&maps);
+	check_tower::<portable::packed_32::PackedBinaryField4x8b>(&maps);
+	check_tower::<portable::packed_64::PackedBinaryField8x8b>(&maps);
+	check_tower::<portable::packed_128::PackedBinaryField16x8b>(&maps);
+	check_tower::<portable::packed_256::PackedBinaryField32x8b>(&maps);
+	check_tower::<portable::packed_512::PackedBinaryField64x8b>(&maps);
+
+	check_aes::<portable::packed_aes_8::PackedAESBinaryField1x8b>(&maps);
+	check_aes::<portable::packed_aes_16::PackedAESBinaryField2x8b>(&maps);
+	check_aes::<portable::packed_aes_32::PackedAESBinaryField4x8b>(&maps);
+	check_aes::<portable::packed_aes_64::PackedAESBinaryField8x8b>(&maps);
+	check_aes::<portable::packed_aes_128::PackedAESBinaryField16x8b>(&maps);
+	check_aes::<portable::packed_aes_256::PackedAESBinaryField32x8b>(&maps);
+	check_aes::<portable::packed_aes_512::PackedAESBinaryField64x8b>(&maps);
+
+	check_isomorphism!(
+		&maps,
+		portable::packed_128::PackedBinaryField16x8b,
+		portable::packed_aes_128::PackedAESBinaryField16x8b
+	);
+	check_isomorphism!(
+		&maps,
+		portable::packed_256::PackedBinaryField32x8b,
+		portable::packed_aes_256::PackedAESBinaryField32x8b
+	);
+	check_isomorphism!(
+		&maps,
+		portable::packed_512::PackedBinaryField64x8b,
+		portable::packed_aes_512::PackedAESBinaryField64x8b
+	);
+}
+
+#[test]
+fn test_arch_known_answers() {
+	let maps = Gf256Maps::load();
+	check_tower::<super::packed_128::PackedBinaryField16x8b>(&maps);
+	check_tower::<super::packed_256::PackedBinaryField32x8b>(&maps);
+	check_tower::<super::packed_512::PackedBinaryField64x8b>(&maps);
+
+	check_aes::<super::packed_aes_128::PackedAESBinaryField16x8b>(&maps);
+	check_aes::<super::packed_aes_256::PackedAESBinaryField32x8b>(&maps);
+	check_aes::<super::packed_aes_512::PackedAESBinaryField64x8b>(&maps);
+
+	check_isomorphism!(
+		&maps,
+		super::packed_128::PackedBinaryField16x8b,
+		super::packed_aes_128::PackedAESBinaryField16x8b
+	);
+	check_isomorphism!(
+		&maps,
+		super::packed_256::PackedBinaryField32x8b,
+		super::packed_aes_256::PackedAESBinaryField32x8b
+	);
+	check_isomorphism!(
+		&maps,
+		super::packed_512::PackedBinaryField64x8b,
+		super::packed_aes_512::PackedAESBinaryField64x8b
+	);
+}
@@ -4,6 +4,11 @@ use cfg_if::cfg_if;
 
 mod arch_optimal;
 mod binary_utils;
+mod cpu_features;
+#[cfg(test)]
+mod differential_tests;
+#[cfg(test)]
+mod known_answer_tests;
 mod strategies;
 
 cfg_if! {
@@ -18,7 +23,14 @@ cfg_if! {
 		mod portable;
 
 		mod aarch64;
-		pub use aarch64::{packed_128, packed_polyval_128, packed_aes_128};
+		pub use aarch64::{packed_128, packed_polyval_128, packed_aes_128, sve2};
+		pub use portable::{packed_256, packed_512, packed_aes_256, packed_aes_512, packed_polyval_256, packed_polyval_512};
+	} else if #[cfg(target_arch = "wasm32")] {
+		#[allow(dead_code)]
+		mod portable;
+
+		mod wasm32;
+		pub use wasm32::{packed_128, packed_polyval_128, packed_aes_128};
 		pub use portable::{packed_256, packed_512, packed_aes_256, packed_aes_512, packed_polyval_256, packed_polyval_512};
 	} else {
 		mod portable;
@@ -27,8 +39,9 @@ cfg_if! {
 }
 
 pub use arch_optimal::*;
+pub use cpu_features::CpuFeatures;
 pub use portable::{
 	byte_sliced, packed_1, packed_2, packed_4, packed_8, packed_16, packed_32, packed_64,
-	packed_aes_8, packed_aes_16, packed_aes_32, packed_aes_64,
+	packed_256b, packed_aes_8, packed_aes_16, packed_aes_32, packed_aes_64,
 };
 pub use strategies::*;
@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{
+use alloc::{format, vec::Vec};
+use core::{
 	array,
 	fmt::Debug,
 	iter::{Product, Sum, zip},
@@ -109,7 +110,7 @@ macro_rules! define_byte_sliced_3d {
 		}
 
 		impl Debug for $name {
-			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 				let values_str = self
 					.iter()
 					.map(|value| format!("{}", value))
@@ -694,7 +695,7 @@ macro_rules! define_byte_sliced_3d_1b {
 		}
 
 		impl Debug for $name {
-			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 				let values_str = self
 					.iter()
 					.map(|value| format!("{}", value))
@@ -0,0 +1,185 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Constant-time arithmetic for the 8-bit fields.
+//!
+//! The default implementations of the 8-bit field operations use lookup tables, which leak the
+//! operands through the cache timing. The functions in this module only use bitwise operations
+//! with a control flow and memory access pattern that doesn't depend on the values, at the cost of
+//! being several times slower. They are selected instead of the table-based implementations when
+//! the `constant_time_arithmetic` feature is enabled.
+
+use super::packed::PackedPrimitiveType;
+use crate::{
+	AESTowerField8b, BinaryField8b,
+	arch::ConstantTimeStrategy,
+	arithmetic_traits::{TaggedInvertOrZero, TaggedMul, TaggedMulAlpha, TaggedSquare},
+	packed::PackedField,
+	underlier::UnderlierType,
+};
+
+/// Multiplies two elements of the canonical tower field with `2^log_bits` bits.
+///
+/// Uses the Karatsuba recursion over the tower with the reduction $X_k^2 = X_{k-1} X_k + 1$. The
+/// recursion depth only depends on `log_bits`, so the operation is constant time in the operands.
+#[inline]
+fn mul_binary_tower(a: u8, b: u8, log_bits: usize) -> u8 {
+	if log_bits == 0 {
+		return a & b & 1;
+	}
+
+	let half_bits = 1 << (log_bits - 1);
+	let mask = (1u8 << half_bits).wrapping_sub(1);
+	let (a0, a1) = (a & mask, (a >> half_bits) & mask);
+	let (b0, b1) = (b & mask, (b >> half_bits) & mask);
+
+	let z0 = mul_binary_tower(a0, b0, log_bits - 1);
+	let z2 = mul_binary_tower(a1, b1, log_bits - 1);
+	let z1 = mul_binary_tower(a0 ^ a1, b0 ^ b1, log_bits - 1) ^ z0 ^ z2;
+
+	// The primitive element of the subfield, 1 for the lowest level.
+	let alpha = if log_bits == 1 {
+		1
+	} else {
+		1 << (half_bits / 2)
+	};
+	let z2_alpha = mul_binary_tower(z2, alpha, log_bits - 1);
+
+	(z0 ^ z2) | ((z1 ^ z2_alpha) << half_bits)
+}
+
+#[inline]
+fn mul_binary_tower_8b(a: u8, b: u8) -> u8 {
+	mul_binary_tower(a, b, 3)
+}
+
+/// Multiplies two elements of the AES field $GF(2^8) = GF(2)[X] / (X^8 + X^4 + X^3 + X + 1)$.
+#[inline]
+fn mul_aes_8b(a: u8, b: u8) -> u8 {
+	let mut result = 0u8;
+	let mut a = a;
+	for i in 0..8 {
+		// Masks are all ones if the bit is set and all zeros otherwise.
+		result ^= a & 0u8.wrapping_sub((b >> i) & 1);
+		a = (a << 1) ^ (0x1b & 0u8.wrapping_sub(a >> 7));
+	}
+	result
+}
+
+/// Computes $x^{254}$, which is the inverse of a non-zero $x$ and zero otherwise.
+#[inline]
+fn invert_or_zero_8b(x: u8, mul: impl Fn(u8, u8) -> u8) -> u8 {
+	// x^254 = x^2 * x^4 * ... * x^128
+	let mut power = mul(x, x);
+	let mut result = power;
+	for _ in 0..6 {
+		power = mul(power, power);
+		result = mul(result, power);
+	}
+	result
+}
+
+macro_rules! impl_constant_time_ops {
+	($field:ty, $mul:ident, $alpha:expr) => {
+		impl<U: UnderlierType> TaggedMul<ConstantTimeStrategy> for PackedPrimitiveType<U, $field>
+		where
+			Self: PackedField<Scalar = $field>,
+		{
+			#[inline]
+			fn mul(self, rhs: Self) -> Self {
+				Self::from_fn(|i| $mul(self.get(i).val(), rhs.get(i).val()).into())
+			}
+		}
+
+		impl<U: UnderlierType> TaggedSquare<ConstantTimeStrategy> for PackedPrimitiveType<U, $field>
+		where
+			Self: PackedField<Scalar = $field>,
+		{
+			#[inline]
+			fn square(self) -> Self {
+				Self::from_fn(|i| {
+					let x = self.get(i).val();
+					$mul(x, x).into()
+				})
+			}
+		}
+
+		impl<U: UnderlierType> TaggedInvertOrZero<ConstantTimeStrategy>
+			for PackedPrimitiveType<U, $field>
+		where
+			Self: PackedField<Scalar = $field>,
+		{
+			#[inline]
+			fn invert_or_zero(self) -> Self {
+				Self::from_fn(|i| invert_or_zero_8b(self.get(i).val(), $mul).into())
+			}
+		}
+
+		impl<U: UnderlierType> TaggedMulAlpha<ConstantTimeStrategy>
+			for PackedPrimitiveType<U, $field>
+		where
+			Self: PackedField<Scalar = $field>,
+		{
+			#[inline]
+			fn mul_alpha(self) -> Self {
+				Self::from_fn(|i| $mul(self.get(i).val(), $alpha).into())
+			}
+		}
+	};
+}
+
+impl_constant_time_ops!(BinaryField8b, mul_binary_tower_8b, 0x10);
+// 0xD3 corresponds to 0x10 after isomorphism from BinaryField8b to AESField
+impl_constant_time_ops!(AESTowerField8b, mul_aes_8b, 0xD3);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{BinaryField, arch::PairwiseTableStrategy};
+
+	fn check_matches_table<F: BinaryField>()
+	where
+		PackedPrimitiveType<u8, F>: PackedField<Scalar = F>
+			+ TaggedMul<ConstantTimeStrategy>
+			+ TaggedMul<PairwiseTableStrategy>
+			+ TaggedSquare<ConstantTimeStrategy>
+			+ TaggedSquare<PairwiseTableStrategy>
+			+ TaggedInvertOrZero<ConstantTimeStrategy>
+			+ TaggedInvertOrZero<PairwiseTableStrategy>
+			+ TaggedMulAlpha<ConstantTimeStrategy>
+			+ TaggedMulAlpha<PairwiseTableStrategy>,
+	{
+		for a in 0..=255u8 {
+			let a = PackedPrimitiveType::<u8, F>::from(a);
+			assert_eq!(
+				TaggedSquare::<ConstantTimeStrategy>::square(a),
+				TaggedSquare::<PairwiseTableStrategy>::square(a)
+			);
+			assert_eq!(
+				TaggedInvertOrZero::<ConstantTimeStrategy>::invert_or_zero(a),
+				TaggedInvertOrZero::<PairwiseTableStrategy>::invert_or_zero(a)
+			);
+			assert_eq!(
+				TaggedMulAlpha::<ConstantTimeStrategy>::mul_alpha(a),
+				TaggedMulAlpha::<PairwiseTableStrategy>::mul_alpha(a)
+			);
+
+			for b in 0..=255u8 {
+				let b = PackedPrimitiveType::<u8, F>::from(b);
+				assert_eq!(
+					TaggedMul::<ConstantTimeStrategy>::mul(a, b),
+					TaggedMul::<PairwiseTableStrategy>::mul(a, b)
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_binary_tower_8b_matches_table() {
+		check_matches_table::<BinaryField8b>();
+	}
+
+	#[test]
+	fn test_aes_8b_matches_table() {
+		check_matches_table::<AESTowerField8b>();
+	}
+}
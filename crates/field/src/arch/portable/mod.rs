@@ -7,6 +7,7 @@ pub mod packed_128;
 pub mod packed_16;
 pub mod packed_2;
 pub mod packed_256;
+pub mod packed_256b;
 pub mod packed_32;
 pub mod packed_4;
 pub mod packed_512;
@@ -29,6 +30,7 @@ pub mod byte_sliced;
 
 pub(super) mod packed_scaled;
 
+pub(super) mod constant_time_arithmetic;
 pub(super) mod hybrid_recursive_arithmetics;
 pub(super) mod packed_arithmetic;
 pub(super) mod pairwise_arithmetic;
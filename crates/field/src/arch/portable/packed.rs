@@ -6,7 +6,8 @@
 // definition.
 #![allow(clippy::multiple_bound_locations)]
 
-use std::{
+use alloc::{format, vec::Vec};
+use core::{
 	fmt::Debug,
 	iter::{Product, Sum},
 	marker::PhantomData,
@@ -122,7 +123,7 @@ impl<U: UnderlierWithBitOps, Scalar: BinaryField> Debug for PackedPrimitiveType<
 where
 	Self: PackedField,
 {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		let width = checked_int_div(U::BITS, Scalar::N_BITS);
 		let values_str = self
 			.iter()
@@ -423,7 +424,7 @@ pub(crate) use impl_broadcast;
 
 macro_rules! impl_ops_for_zero_height {
 	($name:ty) => {
-		impl std::ops::Mul for $name {
+		impl core::ops::Mul for $name {
 			type Output = Self;
 
 			#[allow(clippy::suspicious_arithmetic_impl)]
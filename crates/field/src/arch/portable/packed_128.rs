@@ -7,7 +7,10 @@ use super::{
 use crate::{
 	BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b, BinaryField16b, BinaryField32b,
 	BinaryField64b, BinaryField128b,
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
+		PairwiseTableOrConstantTimeStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -67,7 +70,7 @@ impl_square_with!(PackedBinaryField1x128b @ PairwiseRecursiveStrategy);
 // Define invert
 impl_invert_with!(PackedBinaryField64x2b @ PackedStrategy);
 impl_invert_with!(PackedBinaryField32x4b @ PackedStrategy);
-impl_invert_with!(PackedBinaryField16x8b @ PairwiseTableStrategy);
+impl_invert_with!(PackedBinaryField16x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_invert_with!(PackedBinaryField8x16b @ PairwiseRecursiveStrategy);
 impl_invert_with!(PackedBinaryField4x32b @ PairwiseStrategy);
 impl_invert_with!(PackedBinaryField2x64b @ PairwiseRecursiveStrategy);
@@ -6,7 +6,10 @@ use super::{
 };
 use crate::{
 	BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b, BinaryField16b,
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
+		PairwiseTableOrConstantTimeStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -39,7 +42,7 @@ impl_tower_constants!(BinaryField8b, u16, { alphas!(u16, 3) });
 // Define multiplication
 impl_mul_with!(PackedBinaryField8x2b @ PackedStrategy);
 impl_mul_with!(PackedBinaryField4x4b @ PackedStrategy);
-impl_mul_with!(PackedBinaryField2x8b @ PairwiseTableStrategy);
+impl_mul_with!(PackedBinaryField2x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_mul_with!(PackedBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define square
@@ -51,7 +54,7 @@ impl_square_with!(PackedBinaryField1x16b @ PairwiseRecursiveStrategy);
 // Define invert
 impl_invert_with!(PackedBinaryField8x2b @ PairwiseRecursiveStrategy);
 impl_invert_with!(PackedBinaryField4x4b @ PairwiseRecursiveStrategy);
-impl_invert_with!(PackedBinaryField2x8b @ PairwiseTableStrategy);
+impl_invert_with!(PackedBinaryField2x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_invert_with!(PackedBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define multiply by alpha
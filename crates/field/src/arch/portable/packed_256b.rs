@@ -0,0 +1,13 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Packed fields of 256-bit tower field elements.
+//!
+//! There is no SIMD register wide enough to hold more than one [`BinaryField256b`] element on the
+//! supported targets, so these are portable scaled packings of the scalar field on every
+//! architecture.
+
+use super::packed_scaled::packed_scaled_field;
+use crate::BinaryField256b;
+
+packed_scaled_field!(PackedBinaryField2x256b = [BinaryField256b; 2]);
+packed_scaled_field!(PackedBinaryField4x256b = [PackedBinaryField2x256b; 2]);
@@ -50,9 +50,9 @@ cfg_if! {
 		impl_mul_with!(PackedBinaryField2x16b => crate::PackedBinaryField8x16b);
 		impl_mul_with!(PackedBinaryField1x32b => crate::PackedBinaryField4x32b);
 	} else {
-		use crate::arch::{HybridRecursiveStrategy, PairwiseTableStrategy};
+		use crate::arch::{HybridRecursiveStrategy, PairwiseTableOrConstantTimeStrategy};
 
-		impl_mul_with!(PackedBinaryField4x8b @ PairwiseTableStrategy);
+		impl_mul_with!(PackedBinaryField4x8b @ PairwiseTableOrConstantTimeStrategy);
 		impl_mul_with!(PackedBinaryField2x16b @ HybridRecursiveStrategy);
 		impl_mul_with!(PackedBinaryField1x32b @ HybridRecursiveStrategy);
 	}
@@ -74,7 +74,7 @@ cfg_if! {
 		impl_invert_with!(PackedBinaryField2x16b => crate::PackedBinaryField8x16b);
 		impl_invert_with!(PackedBinaryField1x32b => crate::PackedBinaryField4x32b);
 	} else {
-		impl_invert_with!(PackedBinaryField4x8b @ PairwiseTableStrategy);
+		impl_invert_with!(PackedBinaryField4x8b @ PairwiseTableOrConstantTimeStrategy);
 		impl_invert_with!(PackedBinaryField2x16b @ PackedStrategy);
 		impl_invert_with!(PackedBinaryField1x32b @ PackedStrategy);
 	}
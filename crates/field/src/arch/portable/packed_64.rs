@@ -55,7 +55,7 @@ cfg_if! {
 		impl_mul_with!(PackedBinaryField2x32b => crate::PackedBinaryField4x32b);
 		impl_mul_with!(PackedBinaryField1x64b => crate::PackedBinaryField2x64b);
 	} else {
-		impl_mul_with!(PackedBinaryField8x8b @ crate::arch::PairwiseTableStrategy);
+		impl_mul_with!(PackedBinaryField8x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_mul_with!(PackedBinaryField4x16b @ PairwiseRecursiveStrategy);
 		impl_mul_with!(PackedBinaryField2x32b @ PairwiseRecursiveStrategy);
 		impl_mul_with!(PackedBinaryField1x64b @ PairwiseRecursiveStrategy);
@@ -72,7 +72,7 @@ cfg_if! {
 		impl_square_with!(PackedBinaryField2x32b => crate::PackedBinaryField4x32b);
 		impl_square_with!(PackedBinaryField1x64b => crate::PackedBinaryField2x64b);
 	} else {
-		impl_square_with!(PackedBinaryField8x8b @ crate::arch::PairwiseTableStrategy);
+		impl_square_with!(PackedBinaryField8x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_square_with!(PackedBinaryField4x16b @ PairwiseStrategy);
 		impl_square_with!(PackedBinaryField2x32b @ PairwiseRecursiveStrategy);
 		impl_square_with!(PackedBinaryField1x64b @ crate::arch::HybridRecursiveStrategy);
@@ -89,7 +89,7 @@ cfg_if! {
 		impl_invert_with!(PackedBinaryField2x32b => crate::PackedBinaryField4x32b);
 		impl_invert_with!(PackedBinaryField1x64b => crate::PackedBinaryField2x64b);
 	} else {
-		impl_invert_with!(PackedBinaryField8x8b @ crate::arch::PairwiseTableStrategy);
+		impl_invert_with!(PackedBinaryField8x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_invert_with!(PackedBinaryField4x16b @ PairwiseStrategy);
 		impl_invert_with!(PackedBinaryField2x32b @ PairwiseStrategy);
 		impl_invert_with!(PackedBinaryField1x64b @ PairwiseRecursiveStrategy);
@@ -6,7 +6,10 @@ use super::{
 };
 use crate::{
 	BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b,
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
+		PairwiseTableOrConstantTimeStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -36,22 +39,22 @@ impl_tower_constants!(BinaryField4b, u8, { alphas!(u8, 2) });
 // Define multiplication
 impl_mul_with!(PackedBinaryField4x2b @ PackedStrategy);
 impl_mul_with!(PackedBinaryField2x4b @ PackedStrategy);
-impl_mul_with!(PackedBinaryField1x8b @ PairwiseTableStrategy);
+impl_mul_with!(PackedBinaryField1x8b @ PairwiseTableOrConstantTimeStrategy);
 
 // Define square
 impl_square_with!(PackedBinaryField4x2b @ PackedStrategy);
 impl_square_with!(PackedBinaryField2x4b @ PackedStrategy);
-impl_square_with!(PackedBinaryField1x8b @ PairwiseTableStrategy);
+impl_square_with!(PackedBinaryField1x8b @ PairwiseTableOrConstantTimeStrategy);
 
 // Define invert
 impl_invert_with!(PackedBinaryField4x2b @ PairwiseRecursiveStrategy);
 impl_invert_with!(PackedBinaryField2x4b @ PairwiseRecursiveStrategy);
-impl_invert_with!(PackedBinaryField1x8b @ PairwiseTableStrategy);
+impl_invert_with!(PackedBinaryField1x8b @ PairwiseTableOrConstantTimeStrategy);
 
 // Define multiply by alpha
 impl_mul_alpha_with!(PackedBinaryField4x2b @ PackedStrategy);
 impl_mul_alpha_with!(PackedBinaryField2x4b @ PackedStrategy);
-impl_mul_alpha_with!(PackedBinaryField1x8b @ PairwiseTableStrategy);
+impl_mul_alpha_with!(PackedBinaryField1x8b @ PairwiseTableOrConstantTimeStrategy);
 
 // Define linear transformations
 impl_transformation_with_strategy!(PackedBinaryField8x1b, PackedStrategy);
@@ -8,7 +8,10 @@ use crate::{
 	aes_field::{
 		AESTowerField8b, AESTowerField16b, AESTowerField32b, AESTowerField64b, AESTowerField128b,
 	},
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
+		PairwiseTableOrConstantTimeStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -37,28 +40,28 @@ impl_tower_constants!(AESTowerField32b, u128, { alphas!(u128, 5) });
 impl_tower_constants!(AESTowerField64b, u128, { alphas!(u128, 6) });
 
 // Define multiplication
-impl_mul_with!(PackedAESBinaryField16x8b @ PairwiseTableStrategy);
+impl_mul_with!(PackedAESBinaryField16x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_mul_with!(PackedAESBinaryField8x16b @ PairwiseRecursiveStrategy);
 impl_mul_with!(PackedAESBinaryField4x32b @ PairwiseRecursiveStrategy);
 impl_mul_with!(PackedAESBinaryField2x64b @ PairwiseRecursiveStrategy);
 impl_mul_with!(PackedAESBinaryField1x128b @ PairwiseRecursiveStrategy);
 
 // Define square
-impl_square_with!(PackedAESBinaryField16x8b @ PairwiseTableStrategy);
+impl_square_with!(PackedAESBinaryField16x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_square_with!(PackedAESBinaryField8x16b @ PairwiseRecursiveStrategy);
 impl_square_with!(PackedAESBinaryField4x32b @ PackedStrategy);
 impl_square_with!(PackedAESBinaryField2x64b @ PackedStrategy);
 impl_square_with!(PackedAESBinaryField1x128b @ PairwiseRecursiveStrategy);
 
 // Define invert
-impl_invert_with!(PackedAESBinaryField16x8b @ PairwiseTableStrategy);
+impl_invert_with!(PackedAESBinaryField16x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_invert_with!(PackedAESBinaryField8x16b @ PairwiseRecursiveStrategy);
 impl_invert_with!(PackedAESBinaryField4x32b @ PairwiseRecursiveStrategy);
 impl_invert_with!(PackedAESBinaryField2x64b @ PairwiseRecursiveStrategy);
 impl_invert_with!(PackedAESBinaryField1x128b @ PairwiseRecursiveStrategy);
 
 // Define multiply by alpha
-impl_mul_alpha_with!(PackedAESBinaryField16x8b @ PairwiseTableStrategy);
+impl_mul_alpha_with!(PackedAESBinaryField16x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField8x16b @ PackedStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField4x32b @ PackedStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField2x64b @ PairwiseRecursiveStrategy);
@@ -6,7 +6,10 @@ use super::{
 };
 use crate::{
 	AESTowerField8b, AESTowerField16b,
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
+		PairwiseTableOrConstantTimeStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -25,19 +28,19 @@ impl_broadcast!(u16, AESTowerField16b);
 impl_tower_constants!(AESTowerField8b, u16, 0x00d3);
 
 // Define multiplication
-impl_mul_with!(PackedAESBinaryField2x8b @ PairwiseTableStrategy);
+impl_mul_with!(PackedAESBinaryField2x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_mul_with!(PackedAESBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define square
-impl_square_with!(PackedAESBinaryField2x8b @ PairwiseTableStrategy);
+impl_square_with!(PackedAESBinaryField2x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_square_with!(PackedAESBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define invert
-impl_invert_with!(PackedAESBinaryField2x8b @ PairwiseTableStrategy);
+impl_invert_with!(PackedAESBinaryField2x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_invert_with!(PackedAESBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define multiply by alpha
-impl_mul_alpha_with!(PackedAESBinaryField2x8b @ PairwiseTableStrategy);
+impl_mul_alpha_with!(PackedAESBinaryField2x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField1x16b @ PackedStrategy);
 
 // Define linear transformations
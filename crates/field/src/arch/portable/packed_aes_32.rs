@@ -8,7 +8,10 @@ use super::{
 };
 use crate::{
 	AESTowerField8b, AESTowerField16b, AESTowerField32b,
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy,
+		PairwiseTableOrConstantTimeStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -36,14 +39,14 @@ cfg_if! {
 		impl_mul_with!(PackedAESBinaryField2x16b => crate::PackedAESBinaryField8x16b);
 		impl_mul_with!(PackedAESBinaryField1x32b => crate::PackedAESBinaryField4x32b);
 	} else {
-		impl_mul_with!(PackedAESBinaryField4x8b @ PairwiseTableStrategy);
+		impl_mul_with!(PackedAESBinaryField4x8b @ PairwiseTableOrConstantTimeStrategy);
 		impl_mul_with!(PackedAESBinaryField2x16b @ PairwiseRecursiveStrategy);
 		impl_mul_with!(PackedAESBinaryField1x32b @ PairwiseRecursiveStrategy);
 	}
 }
 
 // Define square
-impl_square_with!(PackedAESBinaryField4x8b @ PairwiseTableStrategy);
+impl_square_with!(PackedAESBinaryField4x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_square_with!(PackedAESBinaryField2x16b @ PairwiseRecursiveStrategy);
 impl_square_with!(PackedAESBinaryField1x32b @ PairwiseRecursiveStrategy);
 
@@ -54,14 +57,14 @@ cfg_if! {
 		impl_invert_with!(PackedAESBinaryField2x16b => crate::PackedAESBinaryField8x16b);
 		impl_invert_with!(PackedAESBinaryField1x32b => crate::PackedAESBinaryField4x32b);
 	} else {
-		impl_invert_with!(PackedAESBinaryField4x8b @ PairwiseTableStrategy);
+		impl_invert_with!(PackedAESBinaryField4x8b @ PairwiseTableOrConstantTimeStrategy);
 		impl_invert_with!(PackedAESBinaryField2x16b @ PairwiseRecursiveStrategy);
 		impl_invert_with!(PackedAESBinaryField1x32b @ PairwiseRecursiveStrategy);
 	}
 }
 
 // Define multiply by alpha
-impl_mul_alpha_with!(PackedAESBinaryField4x8b @ PairwiseTableStrategy);
+impl_mul_alpha_with!(PackedAESBinaryField4x8b @ PairwiseTableOrConstantTimeStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField2x16b @ PackedStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField1x32b @ PairwiseRecursiveStrategy);
 
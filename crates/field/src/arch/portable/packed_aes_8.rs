@@ -3,7 +3,7 @@
 use super::packed::{PackedPrimitiveType, impl_broadcast};
 use crate::{
 	AESTowerField8b,
-	arch::{PairwiseStrategy, PairwiseTableStrategy},
+	arch::{PairwiseStrategy, PairwiseTableOrConstantTimeStrategy},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -17,16 +17,16 @@ pub type PackedAESBinaryField1x8b = PackedPrimitiveType<u8, AESTowerField8b>;
 impl_broadcast!(u8, AESTowerField8b);
 
 // Define multiplication
-impl_mul_with!(PackedAESBinaryField1x8b @ PairwiseTableStrategy);
+impl_mul_with!(PackedAESBinaryField1x8b @ PairwiseTableOrConstantTimeStrategy);
 
 // Define square
-impl_square_with!(PackedAESBinaryField1x8b @ PairwiseTableStrategy);
+impl_square_with!(PackedAESBinaryField1x8b @ PairwiseTableOrConstantTimeStrategy);
 
 // Define invert
-impl_invert_with!(PackedAESBinaryField1x8b @ PairwiseTableStrategy);
+impl_invert_with!(PackedAESBinaryField1x8b @ PairwiseTableOrConstantTimeStrategy);
 
 // Define multiply by alpha
-impl_mul_alpha_with!(PackedAESBinaryField1x8b @ PairwiseTableStrategy);
+impl_mul_alpha_with!(PackedAESBinaryField1x8b @ PairwiseTableOrConstantTimeStrategy);
 
 // Define linear transformations
 impl_transformation_with_strategy!(PackedAESBinaryField1x8b, PairwiseStrategy);
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use alloc::vec::Vec;
+
 use crate::{
 	PackedExtension, PackedField, TowerField,
 	arch::PackedStrategy,
@@ -403,7 +405,7 @@ where
 
 #[cfg(test)]
 mod tests {
-	use std::fmt::Debug;
+	use core::fmt::Debug;
 
 	use rand::thread_rng;
 
@@ -8,7 +8,7 @@
 //!
 //! <https://bearssl.org/gitweb/?p=BearSSL;a=blob;f=src/hash/ghash_ctmul64.c;hb=4b6046412>
 
-use std::{
+use core::{
 	num::Wrapping,
 	ops::{BitXor, Mul},
 };
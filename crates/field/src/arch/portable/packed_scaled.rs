@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{
+use core::{
 	array,
 	iter::{Product, Sum},
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
@@ -35,7 +35,7 @@ impl<PT, const N: usize> ScaledPackedField<PT, N> {
 	/// In general case PT != Self::Scalar, so this function has a different name from
 	/// `PackedField::from_fn`
 	pub fn from_direct_packed_fn(f: impl FnMut(usize) -> PT) -> Self {
-		Self(std::array::from_fn(f))
+		Self(core::array::from_fn(f))
 	}
 
 	/// We put implementation here to be able to use in the generic code.
@@ -344,7 +344,7 @@ where
 	fn iter_slice(slice: &[Self]) -> impl Iterator<Item = Self::Scalar> + Send + Clone + '_ {
 		// Safety: `Self` has the same layout as `[PT; N]` because it is a transparent wrapper.
 		let cast_slice =
-			unsafe { std::slice::from_raw_parts(slice.as_ptr() as *const [PT; N], slice.len()) };
+			unsafe { core::slice::from_raw_parts(slice.as_ptr() as *const [PT; N], slice.len()) };
 
 		PT::iter_slice(cast_slice.as_flattened())
 	}
@@ -410,7 +410,7 @@ macro_rules! packed_scaled_field {
 	($name:ident = [$inner:ty;$size:literal]) => {
 		pub type $name = $crate::arch::portable::packed_scaled::ScaledPackedField<$inner, $size>;
 
-		impl std::ops::Add<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl core::ops::Add<<$inner as $crate::packed::PackedField>::Scalar> for $name {
 			type Output = Self;
 
 			#[inline]
@@ -424,7 +424,7 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::AddAssign<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl core::ops::AddAssign<<$inner as $crate::packed::PackedField>::Scalar> for $name {
 			#[inline]
 			fn add_assign(&mut self, rhs: <$inner as $crate::packed::PackedField>::Scalar) {
 				let broadcast = <$inner as $crate::packed::PackedField>::broadcast(rhs);
@@ -434,7 +434,7 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::Sub<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl core::ops::Sub<<$inner as $crate::packed::PackedField>::Scalar> for $name {
 			type Output = Self;
 
 			#[inline]
@@ -448,7 +448,7 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::SubAssign<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl core::ops::SubAssign<<$inner as $crate::packed::PackedField>::Scalar> for $name {
 			#[inline]
 			fn sub_assign(&mut self, rhs: <$inner as $crate::packed::PackedField>::Scalar) {
 				let broadcast = <$inner as $crate::packed::PackedField>::broadcast(rhs);
@@ -458,7 +458,7 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::Mul<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl core::ops::Mul<<$inner as $crate::packed::PackedField>::Scalar> for $name {
 			type Output = Self;
 
 			#[inline]
@@ -472,7 +472,7 @@ macro_rules! packed_scaled_field {
 			}
 		}
 
-		impl std::ops::MulAssign<<$inner as $crate::packed::PackedField>::Scalar> for $name {
+		impl core::ops::MulAssign<<$inner as $crate::packed::PackedField>::Scalar> for $name {
 			#[inline]
 			fn mul_assign(&mut self, rhs: <$inner as $crate::packed::PackedField>::Scalar) {
 				let broadcast = <$inner as $crate::packed::PackedField>::broadcast(rhs);
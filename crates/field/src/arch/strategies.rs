@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use cfg_if::cfg_if;
+
 /// Packed strategy for arithmetic operations.
 /// (Uses arithmetic operations with underlier and subfield to simultaneously calculate the result
 /// for all packed values)
@@ -15,12 +17,18 @@ pub struct PairwiseStrategy;
 pub struct PairwiseTableStrategy;
 /// Similar to `PackedStrategy`, but uses SIMD operations supported by the platform.
 pub struct SimdStrategy;
+/// Pairwise strategy that computes the result for each sub-element without lookup tables or
+/// data-dependent branches, so that the timing doesn't depend on the values being processed.
+pub struct ConstantTimeStrategy;
 /// Applicable only for multiply by alpha and square operations.
 /// Reuse multiplication operation for that.
 pub struct ReuseMultiplyStrategy;
 
 /// Use operations with GFNI instructions
 pub struct GfniStrategy;
+/// Use GFNI instructions if they are detected at runtime, otherwise fall back to
+/// `PairwiseTableOrConstantTimeStrategy`.
+pub struct GfniRuntimeStrategy;
 /// Specialized versions of the above to resolve conflicting implementations
 pub struct GfniSpecializedStrategy256b;
 pub struct GfniSpecializedStrategy512b;
@@ -29,3 +37,13 @@ pub struct GfniSpecializedStrategy512b;
 /// Performs conversion to the packed isomorphic AES field, applies the operation and
 /// converts the result back to the canonical tower field.
 pub struct AESIsomorphicStrategy;
+
+cfg_if! {
+	if #[cfg(feature = "constant_time_arithmetic")] {
+		/// Strategy for the 8-bit field operations which are otherwise implemented with lookup tables.
+		pub type PairwiseTableOrConstantTimeStrategy = ConstantTimeStrategy;
+	} else {
+		/// Strategy for the 8-bit field operations which are otherwise implemented with lookup tables.
+		pub type PairwiseTableOrConstantTimeStrategy = PairwiseTableStrategy;
+	}
+}
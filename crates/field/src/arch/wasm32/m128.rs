@@ -0,0 +1,530 @@
+// Copyright 2025 Irreducible Inc.
+
+use core::{
+	arch::wasm32::*,
+	ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Shl, Shr},
+};
+
+use bytemuck::{Pod, Zeroable};
+use derive_more::Not;
+use rand::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::super::portable::{
+	packed::{PackedPrimitiveType, impl_pack_scalar},
+	packed_arithmetic::{UnderlierWithBitConstants, interleave_mask_even, interleave_mask_odd},
+};
+use crate::{
+	BinaryField,
+	arch::binary_utils::{as_array_mut, as_array_ref},
+	arithmetic_traits::Broadcast,
+	tower_levels::TowerLevel,
+	underlier::{
+		NumCast, Random, SmallU, U1, U2, U4, UnderlierType, UnderlierWithBitOps, WithUnderlier,
+		impl_divisible, impl_iteration, transpose_128b_values, unpack_hi_128b_fallback,
+		unpack_lo_128b_fallback,
+	},
+};
+
+/// 128-bit value that is used for WebAssembly SIMD128 operations
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Pod, Zeroable, Not)]
+#[repr(transparent)]
+pub struct M128(pub u128);
+
+impl M128 {
+	pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+		Self(u128::from_le_bytes(bytes))
+	}
+
+	pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+		Self(u128::from_be_bytes(bytes))
+	}
+
+	#[inline]
+	pub fn shuffle_u8(self, src: [u8; 16]) -> Self {
+		u8x16_swizzle(self.into(), Self::from_le_bytes(src).into()).into()
+	}
+}
+
+impl From<M128> for u128 {
+	fn from(value: M128) -> Self {
+		value.0
+	}
+}
+impl From<M128> for v128 {
+	#[inline(always)]
+	fn from(value: M128) -> Self {
+		let [lo, hi]: [u64; 2] = bytemuck::cast(value.0);
+		u64x2(lo, hi)
+	}
+}
+
+impl From<u128> for M128 {
+	fn from(value: u128) -> Self {
+		Self(value)
+	}
+}
+impl From<u64> for M128 {
+	fn from(value: u64) -> Self {
+		Self(value as u128)
+	}
+}
+impl From<u32> for M128 {
+	fn from(value: u32) -> Self {
+		Self(value as u128)
+	}
+}
+impl From<u16> for M128 {
+	fn from(value: u16) -> Self {
+		Self(value as u128)
+	}
+}
+impl From<u8> for M128 {
+	fn from(value: u8) -> Self {
+		Self(value as u128)
+	}
+}
+
+impl<const N: usize> From<SmallU<N>> for M128 {
+	fn from(value: SmallU<N>) -> Self {
+		Self::from(value.val() as u128)
+	}
+}
+
+impl From<v128> for M128 {
+	#[inline(always)]
+	fn from(value: v128) -> Self {
+		let lo = u64x2_extract_lane::<0>(value);
+		let hi = u64x2_extract_lane::<1>(value);
+		Self(bytemuck::cast([lo, hi]))
+	}
+}
+
+impl_divisible!(@pairs M128, u128, u64, u32, u16, u8);
+impl_pack_scalar!(M128);
+
+impl BitAnd for M128 {
+	type Output = Self;
+
+	#[inline]
+	fn bitand(self, rhs: Self) -> Self::Output {
+		v128_and(self.into(), rhs.into()).into()
+	}
+}
+
+impl BitAndAssign for M128 {
+	fn bitand_assign(&mut self, rhs: Self) {
+		*self = *self & rhs;
+	}
+}
+
+impl BitOr for M128 {
+	type Output = Self;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self::Output {
+		v128_or(self.into(), rhs.into()).into()
+	}
+}
+
+impl BitOrAssign for M128 {
+	fn bitor_assign(&mut self, rhs: Self) {
+		*self = *self | rhs;
+	}
+}
+
+impl BitXor for M128 {
+	type Output = Self;
+
+	#[inline]
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		v128_xor(self.into(), rhs.into()).into()
+	}
+}
+
+impl BitXorAssign for M128 {
+	fn bitxor_assign(&mut self, rhs: Self) {
+		*self = *self ^ rhs;
+	}
+}
+
+impl Shr<usize> for M128 {
+	type Output = Self;
+
+	#[inline]
+	fn shr(self, rhs: usize) -> Self::Output {
+		Self(self.0 >> rhs)
+	}
+}
+
+impl Shl<usize> for M128 {
+	type Output = Self;
+
+	#[inline]
+	fn shl(self, rhs: usize) -> Self::Output {
+		Self(self.0 << rhs)
+	}
+}
+
+impl ConstantTimeEq for M128 {
+	fn ct_eq(&self, other: &Self) -> subtle::Choice {
+		self.0.ct_eq(&other.0)
+	}
+}
+
+impl ConditionallySelectable for M128 {
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		ConditionallySelectable::conditional_select(&u128::from(*a), &u128::from(*b), choice).into()
+	}
+}
+
+impl Random for M128 {
+	fn random(rng: impl RngCore) -> Self {
+		Self(u128::random(rng))
+	}
+}
+
+impl core::fmt::Display for M128 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let data: u128 = (*self).into();
+		write!(f, "{data:02X?}")
+	}
+}
+
+impl core::fmt::Debug for M128 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "M128({self})")
+	}
+}
+
+impl UnderlierType for M128 {
+	const LOG_BITS: usize = 7;
+}
+
+impl UnderlierWithBitOps for M128 {
+	const ZERO: Self = Self(0);
+	const ONE: Self = Self(1);
+	const ONES: Self = Self(u128::MAX);
+
+	fn fill_with_bit(val: u8) -> Self {
+		Self(u128::fill_with_bit(val))
+	}
+
+	#[inline(always)]
+	unsafe fn get_subvalue<T>(&self, i: usize) -> T
+	where
+		T: WithUnderlier,
+		T::Underlier: NumCast<Self>,
+	{
+		let result = match T::Underlier::BITS {
+			1 | 2 | 4 => {
+				let elements_in_8 = 8 / T::Underlier::BITS;
+				let shift = (i % elements_in_8) * T::Underlier::BITS;
+				let mask = (1u8 << T::Underlier::BITS) - 1;
+
+				T::Underlier::num_cast_from(as_array_ref::<_, u8, 16, _>(self, |a| {
+					Self::from((a[i / elements_in_8] >> shift) & mask)
+				}))
+			}
+			8 => T::Underlier::num_cast_from(as_array_ref::<_, u8, 16, _>(self, |a| {
+				Self::from(a[i])
+			})),
+			16 => T::Underlier::num_cast_from(as_array_ref::<_, u16, 8, _>(self, |a| {
+				Self::from(a[i])
+			})),
+			32 => T::Underlier::num_cast_from(as_array_ref::<_, u32, 4, _>(self, |a| {
+				Self::from(a[i])
+			})),
+			64 => T::Underlier::num_cast_from(as_array_ref::<_, u64, 2, _>(self, |a| {
+				Self::from(a[i])
+			})),
+			128 => T::Underlier::num_cast_from(*self),
+			_ => panic!("unsupported bit count"),
+		};
+
+		T::from_underlier(result)
+	}
+
+	#[inline(always)]
+	unsafe fn set_subvalue<T>(&mut self, i: usize, val: T)
+	where
+		T: UnderlierWithBitOps,
+		Self: From<T>,
+	{
+		match T::BITS {
+			1 | 2 | 4 => {
+				let elements_in_8 = 8 / T::BITS;
+				let mask = (1u8 << T::BITS) - 1;
+				let shift = (i % elements_in_8) * T::BITS;
+				let val = u8::num_cast_from(Self::from(val)) << shift;
+				let mask = mask << shift;
+
+				as_array_mut::<_, u8, 16>(self, |array| {
+					let element = &mut array[i / elements_in_8];
+					*element &= !mask;
+					*element |= val;
+				});
+			}
+			8 => as_array_mut::<_, u8, 16>(self, |array| {
+				array[i] = u8::num_cast_from(Self::from(val));
+			}),
+			16 => as_array_mut::<_, u16, 8>(self, |array| {
+				array[i] = u16::num_cast_from(Self::from(val));
+			}),
+			32 => as_array_mut::<_, u32, 4>(self, |array| {
+				array[i] = u32::num_cast_from(Self::from(val));
+			}),
+			64 => as_array_mut::<_, u64, 2>(self, |array| {
+				array[i] = u64::num_cast_from(Self::from(val));
+			}),
+			128 => {
+				*self = Self::from(val);
+			}
+			_ => panic!("unsupported bit count"),
+		}
+	}
+
+	#[inline(always)]
+	fn shl_128b_lanes(self, rhs: usize) -> Self {
+		Self(self.0 << rhs)
+	}
+
+	#[inline(always)]
+	fn shr_128b_lanes(self, rhs: usize) -> Self {
+		Self(self.0 >> rhs)
+	}
+
+	#[inline(always)]
+	fn unpack_lo_128b_lanes(self, rhs: Self, log_block_len: usize) -> Self {
+		match log_block_len {
+			0..3 => unpack_lo_128b_fallback(self, rhs, log_block_len),
+			3 => u8x16_shuffle::<0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23>(
+				self.into(),
+				rhs.into(),
+			)
+			.into(),
+			4 => u16x8_shuffle::<0, 8, 1, 9, 2, 10, 3, 11>(self.into(), rhs.into()).into(),
+			5 => u32x4_shuffle::<0, 4, 1, 5>(self.into(), rhs.into()).into(),
+			6 => u64x2_shuffle::<0, 2>(self.into(), rhs.into()).into(),
+			_ => panic!("Unsupported block length"),
+		}
+	}
+
+	#[inline(always)]
+	fn unpack_hi_128b_lanes(self, rhs: Self, log_block_len: usize) -> Self {
+		match log_block_len {
+			0..3 => unpack_hi_128b_fallback(self, rhs, log_block_len),
+			3 => u8x16_shuffle::<8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31>(
+				self.into(),
+				rhs.into(),
+			)
+			.into(),
+			4 => u16x8_shuffle::<4, 12, 5, 13, 6, 14, 7, 15>(self.into(), rhs.into()).into(),
+			5 => u32x4_shuffle::<2, 6, 3, 7>(self.into(), rhs.into()).into(),
+			6 => u64x2_shuffle::<1, 3>(self.into(), rhs.into()).into(),
+			_ => panic!("Unsupported block length"),
+		}
+	}
+
+	#[inline]
+	fn transpose_bytes_from_byte_sliced<TL: TowerLevel>(values: &mut TL::Data<Self>)
+	where
+		u8: NumCast<Self>,
+		Self: From<u8>,
+	{
+		transpose_128b_values::<Self, TL>(values, 0);
+	}
+
+	#[inline]
+	fn transpose_bytes_to_byte_sliced<TL: TowerLevel>(values: &mut TL::Data<Self>)
+	where
+		u8: NumCast<Self>,
+		Self: From<u8>,
+	{
+		if TL::LOG_WIDTH == 0 {
+			return;
+		}
+
+		match TL::LOG_WIDTH {
+			1 => {
+				let shuffle = [0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15];
+				for v in values.as_mut().iter_mut() {
+					*v = v.shuffle_u8(shuffle);
+				}
+			}
+			2 => {
+				let shuffle = [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15];
+				for v in values.as_mut().iter_mut() {
+					*v = v.shuffle_u8(shuffle);
+				}
+			}
+			3 => {
+				let shuffle = [0, 8, 1, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15];
+				for v in values.as_mut().iter_mut() {
+					*v = v.shuffle_u8(shuffle);
+				}
+			}
+			4 => {}
+			_ => unreachable!("Log width must be less than 5"),
+		}
+
+		transpose_128b_values::<_, TL>(values, 4 - TL::LOG_WIDTH);
+	}
+}
+
+impl UnderlierWithBitConstants for M128 {
+	const INTERLEAVE_EVEN_MASK: &'static [Self] = &[
+		Self(interleave_mask_even!(u128, 0)),
+		Self(interleave_mask_even!(u128, 1)),
+		Self(interleave_mask_even!(u128, 2)),
+		Self(interleave_mask_even!(u128, 3)),
+		Self(interleave_mask_even!(u128, 4)),
+		Self(interleave_mask_even!(u128, 5)),
+		Self(interleave_mask_even!(u128, 6)),
+	];
+	const INTERLEAVE_ODD_MASK: &'static [Self] = &[
+		Self(interleave_mask_odd!(u128, 0)),
+		Self(interleave_mask_odd!(u128, 1)),
+		Self(interleave_mask_odd!(u128, 2)),
+		Self(interleave_mask_odd!(u128, 3)),
+		Self(interleave_mask_odd!(u128, 4)),
+		Self(interleave_mask_odd!(u128, 5)),
+		Self(interleave_mask_odd!(u128, 6)),
+	];
+
+	#[inline]
+	fn interleave(self, other: Self, log_block_len: usize) -> (Self, Self) {
+		let (a, b): (v128, v128) = (self.into(), other.into());
+		let (c, d) = match log_block_len {
+			0..=2 => {
+				let shift = 1 << log_block_len;
+				let mask = Self::INTERLEAVE_EVEN_MASK[log_block_len].into();
+				let t = v128_and(v128_xor(u64x2_shr(a, shift), b), mask);
+				(v128_xor(a, u64x2_shl(t, shift)), v128_xor(b, t))
+			}
+			3 => (
+				u8x16_shuffle::<0, 16, 2, 18, 4, 20, 6, 22, 8, 24, 10, 26, 12, 28, 14, 30>(a, b),
+				u8x16_shuffle::<1, 17, 3, 19, 5, 21, 7, 23, 9, 25, 11, 27, 13, 29, 15, 31>(a, b),
+			),
+			4 => (
+				u16x8_shuffle::<0, 8, 2, 10, 4, 12, 6, 14>(a, b),
+				u16x8_shuffle::<1, 9, 3, 11, 5, 13, 7, 15>(a, b),
+			),
+			5 => (u32x4_shuffle::<0, 4, 2, 6>(a, b), u32x4_shuffle::<1, 5, 3, 7>(a, b)),
+			6 => (u64x2_shuffle::<0, 2>(a, b), u64x2_shuffle::<1, 3>(a, b)),
+			_ => panic!("Unsupported block length"),
+		};
+		(c.into(), d.into())
+	}
+
+	#[inline]
+	fn transpose(self, other: Self, log_block_len: usize) -> (Self, Self) {
+		let (a, b): (v128, v128) = (self.into(), other.into());
+		match log_block_len {
+			0..=3 => {
+				let (mut a, mut b) = (
+					Self::from(u8x16_shuffle::<
+						0,
+						2,
+						4,
+						6,
+						8,
+						10,
+						12,
+						14,
+						16,
+						18,
+						20,
+						22,
+						24,
+						26,
+						28,
+						30,
+					>(a, b)),
+					Self::from(u8x16_shuffle::<
+						1,
+						3,
+						5,
+						7,
+						9,
+						11,
+						13,
+						15,
+						17,
+						19,
+						21,
+						23,
+						25,
+						27,
+						29,
+						31,
+					>(a, b)),
+				);
+
+				for log_block_len in (log_block_len..3).rev() {
+					(a, b) = a.interleave(b, log_block_len);
+				}
+
+				(a, b)
+			}
+			4 => (
+				u16x8_shuffle::<0, 2, 4, 6, 8, 10, 12, 14>(a, b).into(),
+				u16x8_shuffle::<1, 3, 5, 7, 9, 11, 13, 15>(a, b).into(),
+			),
+			5 => {
+				(u32x4_shuffle::<0, 2, 4, 6>(a, b).into(), u32x4_shuffle::<1, 3, 5, 7>(a, b).into())
+			}
+			6 => (u64x2_shuffle::<0, 2>(a, b).into(), u64x2_shuffle::<1, 3>(a, b).into()),
+			_ => panic!("Unsupported block length"),
+		}
+	}
+}
+
+impl<Scalar: BinaryField> From<u128> for PackedPrimitiveType<M128, Scalar> {
+	fn from(value: u128) -> Self {
+		Self::from(M128::from(value))
+	}
+}
+
+impl<Scalar: BinaryField> From<PackedPrimitiveType<M128, Scalar>> for u128 {
+	fn from(value: PackedPrimitiveType<M128, Scalar>) -> Self {
+		value.to_underlier().into()
+	}
+}
+
+impl<U: NumCast<u128>> NumCast<M128> for U {
+	fn num_cast_from(val: M128) -> Self {
+		Self::num_cast_from(val.into())
+	}
+}
+
+impl<Scalar: BinaryField> Broadcast<Scalar> for PackedPrimitiveType<M128, Scalar>
+where
+	u128: From<Scalar::Underlier>,
+{
+	#[inline]
+	fn broadcast(scalar: Scalar) -> Self {
+		let tower_level = Scalar::N_BITS.ilog2() as usize;
+		let mut value = u128::from(scalar.to_underlier());
+		for n in tower_level..3 {
+			value |= value << (1 << n);
+		}
+
+		let value = match tower_level {
+			0..=3 => u8x16_splat(value as u8),
+			4 => u16x8_splat(value as u16),
+			5 => u32x4_splat(value as u32),
+			6 => u64x2_splat(value as u64),
+			7 => return value.into(),
+			_ => unreachable!(),
+		};
+
+		M128::from(value).into()
+	}
+}
+
+impl_iteration!(M128,
+	@strategy BitIterationStrategy, U1,
+	@strategy FallbackStrategy, U2, U4,
+	@strategy DivisibleStrategy, u8, u16, u32, u64, u128, M128,
+);
@@ -0,0 +1,26 @@
+// Copyright 2025 Irreducible Inc.
+
+//! WebAssembly SIMD128 implementations of the 128-bit packed fields.
+//!
+//! As on aarch64, the wider packed fields are the portable
+//! [`ScaledPackedField`](super::portable::packed_scaled::ScaledPackedField) types over the 128-bit
+//! packed fields defined here. The GF(2^8) arithmetic uses the `i8x16.swizzle` table lookups, and
+//! the POLYVAL field falls back to the portable arithmetic since SIMD128 has no carry-less
+//! multiplication.
+
+use cfg_if::cfg_if;
+
+cfg_if! {
+	if #[cfg(target_feature = "simd128")] {
+		pub(super) mod m128;
+		pub(super) mod simd_arithmetic;
+
+		pub mod packed_128;
+		pub mod packed_aes_128;
+		pub mod packed_polyval_128;
+	} else {
+		pub use super::portable::packed_128;
+		pub use super::portable::packed_aes_128;
+		pub use super::portable::packed_polyval_128;
+	}
+}
@@ -0,0 +1,52 @@
+// Copyright 2025 Irreducible Inc.
+
+//! POLYVAL field packed in a SIMD128 register.
+//!
+//! SIMD128 has no carry-less multiplication instruction, so the arithmetic operations use the
+//! constant-time software implementation of the portable backend.
+
+use core::ops::Mul;
+
+use super::{super::portable::packed::PackedPrimitiveType, m128::M128};
+use crate::{
+	BinaryField128bPolyval, PackedField,
+	arch::{PairwiseStrategy, ReuseMultiplyStrategy, portable::packed_polyval_128 as portable},
+	arithmetic_traits::{InvertOrZero, impl_square_with, impl_transformation_with_strategy},
+	underlier::WithUnderlier,
+};
+
+pub type PackedBinaryPolyval1x128b = PackedPrimitiveType<M128, BinaryField128bPolyval>;
+
+// Define multiply
+impl Mul for PackedBinaryPolyval1x128b {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self::Output {
+		crate::tracing::trace_multiplication!(PackedBinaryPolyval1x128b);
+
+		from_portable(to_portable(self) * to_portable(rhs))
+	}
+}
+
+// Define square
+impl_square_with!(PackedBinaryPolyval1x128b @ ReuseMultiplyStrategy);
+
+// Define invert
+impl InvertOrZero for PackedBinaryPolyval1x128b {
+	fn invert_or_zero(self) -> Self {
+		from_portable(PackedField::invert_or_zero(to_portable(self)))
+	}
+}
+
+// Define linear transformations
+impl_transformation_with_strategy!(PackedBinaryPolyval1x128b, PairwiseStrategy);
+
+#[inline]
+fn to_portable(value: PackedBinaryPolyval1x128b) -> portable::PackedBinaryPolyval1x128b {
+	portable::PackedBinaryPolyval1x128b::from(u128::from(value.to_underlier()))
+}
+
+#[inline]
+fn from_portable(value: portable::PackedBinaryPolyval1x128b) -> PackedBinaryPolyval1x128b {
+	PackedBinaryPolyval1x128b::from_underlier(M128::from(value.to_underlier()))
+}
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::array;
+use core::array;
 
 use binius_utils::checked_arithmetics::checked_int_div;
 
@@ -288,7 +288,7 @@ impl<U, IP, OP, const BLOCKS: usize, const MATRICES: usize> Transformation<IP, O
 where
 	IP: PackedField + WithUnderlier<Underlier = U>,
 	OP: PackedField + WithUnderlier<Underlier = U>,
-	U: GfniType + std::fmt::Debug,
+	U: GfniType + core::fmt::Debug,
 {
 	fn transform(&self, data: &IP) -> OP {
 		let mut result = OP::Underlier::default();
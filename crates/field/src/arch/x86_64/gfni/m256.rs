@@ -1,7 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use core::arch::x86_64::*;
-use std::array;
+use core::{arch::x86_64::*, array};
 
 use gfni_arithmetics::{GfniType, get_8x8_matrix};
 use seq_macro::seq;
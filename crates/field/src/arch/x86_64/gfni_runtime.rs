@@ -0,0 +1,198 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Runtime dispatch of the 8-bit AES field operations to GFNI instructions.
+//!
+//! This module is compiled only when GFNI is not enabled at compile time, in which case the
+//! `GfniStrategy` implementations are not available. Every packed 8-bit AES field type of the
+//! compiled SIMD widths dispatches to GFNI when [`CpuFeatures`] reports it, using the instruction
+//! encoding of its width: SSE for 128 bits, AVX for 256 bits and AVX-512 for 512 bits, which also
+//! requires AVX-512BW at runtime. Otherwise the operations fall back to the table-based
+//! implementation.
+
+use core::arch::x86_64::*;
+
+use super::m128::M128;
+#[cfg(target_feature = "avx2")]
+use super::m256::M256;
+#[cfg(target_feature = "avx512f")]
+use super::m512::M512;
+use crate::{
+	aes_field::AESTowerField8b,
+	arch::{
+		CpuFeatures, GfniRuntimeStrategy, PairwiseTableOrConstantTimeStrategy,
+		portable::packed::PackedPrimitiveType,
+	},
+	arithmetic_traits::{TaggedInvertOrZero, TaggedMul, TaggedSquare},
+};
+
+/// The identity matrix for `gf2p8affineinv_epi64_epi8`.
+const IDENTITY_MAP: i64 = 0x0102040810204080;
+
+/// # Safety
+/// The CPU must support GFNI.
+#[target_feature(enable = "gfni")]
+unsafe fn gf2p8mul_128(a: M128, b: M128) -> M128 {
+	_mm_gf2p8mul_epi8(a.into(), b.into()).into()
+}
+
+/// # Safety
+/// The CPU must support GFNI.
+#[target_feature(enable = "gfni")]
+unsafe fn gf2p8inv_128(a: M128) -> M128 {
+	_mm_gf2p8affineinv_epi64_epi8::<0>(a.into(), _mm_set1_epi64x(IDENTITY_MAP)).into()
+}
+
+/// # Safety
+/// The CPU must support GFNI and AVX.
+#[cfg(target_feature = "avx2")]
+#[target_feature(enable = "gfni,avx")]
+unsafe fn gf2p8mul_256(a: M256, b: M256) -> M256 {
+	_mm256_gf2p8mul_epi8(a.into(), b.into()).into()
+}
+
+/// # Safety
+/// The CPU must support GFNI and AVX.
+#[cfg(target_feature = "avx2")]
+#[target_feature(enable = "gfni,avx")]
+unsafe fn gf2p8inv_256(a: M256) -> M256 {
+	_mm256_gf2p8affineinv_epi64_epi8::<0>(a.into(), _mm256_set1_epi64x(IDENTITY_MAP)).into()
+}
+
+/// # Safety
+/// The CPU must support GFNI, AVX-512F and AVX-512BW.
+#[cfg(target_feature = "avx512f")]
+#[target_feature(enable = "gfni,avx512f,avx512bw")]
+unsafe fn gf2p8mul_512(a: M512, b: M512) -> M512 {
+	_mm512_gf2p8mul_epi8(a.into(), b.into()).into()
+}
+
+/// # Safety
+/// The CPU must support GFNI, AVX-512F and AVX-512BW.
+#[cfg(target_feature = "avx512f")]
+#[target_feature(enable = "gfni,avx512f,avx512bw")]
+unsafe fn gf2p8inv_512(a: M512) -> M512 {
+	_mm512_gf2p8affineinv_epi64_epi8::<0>(a.into(), _mm512_set1_epi64(IDENTITY_MAP)).into()
+}
+
+/// Implements the runtime-dispatched multiplication, squaring and inversion of the packed 8-bit
+/// AES field over `$underlier`, using the GFNI kernels `$mul` and `$inv` when `$detected` holds.
+macro_rules! impl_gfni_runtime {
+	($underlier:ty, $mul:ident, $inv:ident, |$features:ident| $detected:expr) => {
+		impl TaggedMul<GfniRuntimeStrategy> for PackedPrimitiveType<$underlier, AESTowerField8b> {
+			#[inline]
+			fn mul(self, rhs: Self) -> Self {
+				let $features = CpuFeatures::get();
+				if $detected {
+					// Safety: the required instruction set extensions have been detected.
+					unsafe { $mul(self.to_underlier(), rhs.to_underlier()) }.into()
+				} else {
+					TaggedMul::<PairwiseTableOrConstantTimeStrategy>::mul(self, rhs)
+				}
+			}
+		}
+
+		impl TaggedSquare<GfniRuntimeStrategy>
+			for PackedPrimitiveType<$underlier, AESTowerField8b>
+		{
+			#[inline]
+			fn square(self) -> Self {
+				let $features = CpuFeatures::get();
+				if $detected {
+					// Safety: the required instruction set extensions have been detected.
+					unsafe { $mul(self.to_underlier(), self.to_underlier()) }.into()
+				} else {
+					TaggedSquare::<PairwiseTableOrConstantTimeStrategy>::square(self)
+				}
+			}
+		}
+
+		impl TaggedInvertOrZero<GfniRuntimeStrategy>
+			for PackedPrimitiveType<$underlier, AESTowerField8b>
+		{
+			#[inline]
+			fn invert_or_zero(self) -> Self {
+				let $features = CpuFeatures::get();
+				if $detected {
+					// Safety: the required instruction set extensions have been detected.
+					unsafe { $inv(self.to_underlier()) }.into()
+				} else {
+					TaggedInvertOrZero::<PairwiseTableOrConstantTimeStrategy>::invert_or_zero(self)
+				}
+			}
+		}
+	};
+}
+
+// SSE2 and AVX2 are enabled at compile time for the 128-bit and 256-bit types.
+impl_gfni_runtime!(M128, gf2p8mul_128, gf2p8inv_128, |features| features.gfni);
+#[cfg(target_feature = "avx2")]
+impl_gfni_runtime!(M256, gf2p8mul_256, gf2p8inv_256, |features| features.gfni);
+#[cfg(target_feature = "avx512f")]
+impl_gfni_runtime!(M512, gf2p8mul_512, gf2p8inv_512, |features| features.gfni && features.avx512bw);
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::*;
+	use crate::PackedField;
+
+	fn check_runtime_dispatch_matches_fallback<P>(a: P, b: P)
+	where
+		P: PackedField
+			+ TaggedMul<GfniRuntimeStrategy>
+			+ TaggedMul<PairwiseTableOrConstantTimeStrategy>
+			+ TaggedSquare<GfniRuntimeStrategy>
+			+ TaggedSquare<PairwiseTableOrConstantTimeStrategy>
+			+ TaggedInvertOrZero<GfniRuntimeStrategy>
+			+ TaggedInvertOrZero<PairwiseTableOrConstantTimeStrategy>,
+	{
+		assert_eq!(
+			TaggedMul::<GfniRuntimeStrategy>::mul(a, b),
+			TaggedMul::<PairwiseTableOrConstantTimeStrategy>::mul(a, b)
+		);
+		assert_eq!(
+			TaggedSquare::<GfniRuntimeStrategy>::square(a),
+			TaggedSquare::<PairwiseTableOrConstantTimeStrategy>::square(a)
+		);
+		assert_eq!(
+			TaggedInvertOrZero::<GfniRuntimeStrategy>::invert_or_zero(a),
+			TaggedInvertOrZero::<PairwiseTableOrConstantTimeStrategy>::invert_or_zero(a)
+		);
+		assert_eq!(TaggedInvertOrZero::<GfniRuntimeStrategy>::invert_or_zero(P::zero()), P::zero());
+	}
+
+	proptest! {
+		#[test]
+		fn test_runtime_dispatch_matches_fallback_128(a_val in any::<u128>(), b_val in any::<u128>()) {
+			check_runtime_dispatch_matches_fallback(
+				PackedPrimitiveType::<M128, AESTowerField8b>::from_underlier(a_val.into()),
+				PackedPrimitiveType::<M128, AESTowerField8b>::from_underlier(b_val.into()),
+			);
+		}
+
+		#[cfg(target_feature = "avx2")]
+		#[test]
+		fn test_runtime_dispatch_matches_fallback_256(
+			a_val in any::<[u128; 2]>(),
+			b_val in any::<[u128; 2]>(),
+		) {
+			check_runtime_dispatch_matches_fallback(
+				PackedPrimitiveType::<M256, AESTowerField8b>::from_underlier(a_val.into()),
+				PackedPrimitiveType::<M256, AESTowerField8b>::from_underlier(b_val.into()),
+			);
+		}
+
+		#[cfg(target_feature = "avx512f")]
+		#[test]
+		fn test_runtime_dispatch_matches_fallback_512(
+			a_val in any::<[u128; 4]>(),
+			b_val in any::<[u128; 4]>(),
+		) {
+			check_runtime_dispatch_matches_fallback(
+				PackedPrimitiveType::<M512, AESTowerField8b>::from_underlier(a_val.into()),
+				PackedPrimitiveType::<M512, AESTowerField8b>::from_underlier(b_val.into()),
+			);
+		}
+	}
+}
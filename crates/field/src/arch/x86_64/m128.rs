@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{
+use core::{
 	arch::x86_64::*,
 	array,
 	ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr},
@@ -41,7 +41,7 @@ impl M128 {
 	pub const fn from_u128(val: u128) -> Self {
 		let mut result = Self::ZERO;
 		unsafe {
-			result.0 = std::mem::transmute_copy(&val);
+			result.0 = core::mem::transmute_copy(&val);
 		}
 
 		result
@@ -182,7 +182,7 @@ impl Not for M128 {
 	}
 }
 
-/// `std::cmp::max` isn't const, so we need our own implementation
+/// `core::cmp::max` isn't const, so we need our own implementation
 pub(crate) const fn max_i32(left: i32, right: i32) -> i32 {
 	if left > right { left } else { right }
 }
@@ -256,13 +256,13 @@ impl PartialEq for M128 {
 impl Eq for M128 {}
 
 impl PartialOrd for M128 {
-	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
 		Some(self.cmp(other))
 	}
 }
 
 impl Ord for M128 {
-	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		u128::from(*self).cmp(&u128::from(*other))
 	}
 }
@@ -289,15 +289,15 @@ impl Random for M128 {
 	}
 }
 
-impl std::fmt::Display for M128 {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for M128 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		let data: u128 = (*self).into();
 		write!(f, "{data:02X?}")
 	}
 }
 
-impl std::fmt::Debug for M128 {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for M128 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "M128({self})")
 	}
 }
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{
+use core::{
 	arch::x86_64::*,
 	mem::transmute,
 	ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr},
@@ -254,13 +254,13 @@ impl PartialEq for M256 {
 impl Eq for M256 {}
 
 impl PartialOrd for M256 {
-	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
 		Some(self.cmp(other))
 	}
 }
 
 impl Ord for M256 {
-	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		<[u128; 2]>::from(*self).cmp(&<[u128; 2]>::from(*other))
 	}
 }
@@ -280,7 +280,7 @@ impl ConditionallySelectable for M256 {
 	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
 		let a = <[u128; 2]>::from(*a);
 		let b = <[u128; 2]>::from(*b);
-		let result: [u128; 2] = std::array::from_fn(|i| {
+		let result: [u128; 2] = core::array::from_fn(|i| {
 			ConditionallySelectable::conditional_select(&a[i], &b[i], choice)
 		});
 
@@ -295,15 +295,15 @@ impl Random for M256 {
 	}
 }
 
-impl std::fmt::Display for M256 {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for M256 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		let data: [u128; 2] = (*self).into();
 		write!(f, "{data:02X?}")
 	}
 }
 
-impl std::fmt::Debug for M256 {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for M256 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "M256({self})")
 	}
 }
@@ -1355,14 +1355,14 @@ mod tests {
 
 	impl From<ByteData> for M256 {
 		fn from(value: ByteData) -> Self {
-			let vals: [u128; 2] = unsafe { std::mem::transmute(value) };
+			let vals: [u128; 2] = unsafe { core::mem::transmute(value) };
 			vals.into()
 		}
 	}
 
 	impl From<[u128; 2]> for ByteData {
 		fn from(value: [u128; 2]) -> Self {
-			unsafe { std::mem::transmute(value) }
+			unsafe { core::mem::transmute(value) }
 		}
 	}
 
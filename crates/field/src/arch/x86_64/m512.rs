@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{
+use core::{
 	arch::x86_64::*,
 	mem::transmute_copy,
 	ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr},
@@ -306,13 +306,13 @@ impl PartialEq for M512 {
 impl Eq for M512 {}
 
 impl PartialOrd for M512 {
-	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
 		Some(self.cmp(other))
 	}
 }
 
 impl Ord for M512 {
-	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
 		<[u128; 4]>::from(*self).cmp(&<[u128; 4]>::from(*other))
 	}
 }
@@ -331,7 +331,7 @@ impl ConditionallySelectable for M512 {
 	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
 		let a = <[u128; 4]>::from(*a);
 		let b = <[u128; 4]>::from(*b);
-		let result: [u128; 4] = std::array::from_fn(|i| {
+		let result: [u128; 4] = core::array::from_fn(|i| {
 			ConditionallySelectable::conditional_select(&a[i], &b[i], choice)
 		});
 
@@ -346,15 +346,15 @@ impl Random for M512 {
 	}
 }
 
-impl std::fmt::Display for M512 {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for M512 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		let data: [u128; 4] = (*self).into();
 		write!(f, "{data:02X?}")
 	}
 }
 
-impl std::fmt::Debug for M512 {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for M512 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "M512({})", self)
 	}
 }
@@ -1547,14 +1547,14 @@ mod tests {
 
 	impl From<ByteData> for M512 {
 		fn from(value: ByteData) -> Self {
-			let vals: [u128; 4] = unsafe { std::mem::transmute(value) };
+			let vals: [u128; 4] = unsafe { core::mem::transmute(value) };
 			vals.into()
 		}
 	}
 
 	impl From<[u128; 4]> for ByteData {
 		fn from(value: [u128; 4]) -> Self {
-			unsafe { std::mem::transmute(value) }
+			unsafe { core::mem::transmute(value) }
 		}
 	}
 
@@ -4,6 +4,8 @@ use cfg_if::cfg_if;
 
 #[cfg(target_feature = "gfni")]
 mod gfni;
+#[cfg(all(target_feature = "sse2", not(target_feature = "gfni")))]
+mod gfni_runtime;
 
 #[cfg(target_feature = "pclmulqdq")]
 mod pclmul;
@@ -52,7 +52,7 @@ cfg_if! {
 		impl_mul_with!(PackedBinaryField2x64b @ crate::arch::AESIsomorphicStrategy);
 		impl_mul_with!(PackedBinaryField1x128b @ crate::arch::AESIsomorphicStrategy);
 	} else {
-		impl_mul_with!(PackedBinaryField16x8b @ crate::arch::PairwiseTableStrategy);
+		impl_mul_with!(PackedBinaryField16x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_mul_with!(PackedBinaryField8x16b @ SimdStrategy);
 		impl_mul_with!(PackedBinaryField4x32b @ SimdStrategy);
 		impl_mul_with!(PackedBinaryField2x64b @ SimdStrategy);
@@ -72,7 +72,7 @@ cfg_if! {
 		impl_square_with!(PackedBinaryField2x64b @ crate::arch::AESIsomorphicStrategy);
 		impl_square_with!(PackedBinaryField1x128b @ crate::arch::AESIsomorphicStrategy);
 	} else {
-		impl_square_with!(PackedBinaryField16x8b @ crate::arch::PairwiseTableStrategy);
+		impl_square_with!(PackedBinaryField16x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_square_with!(PackedBinaryField8x16b @ SimdStrategy);
 		impl_square_with!(PackedBinaryField4x32b @ SimdStrategy);
 		impl_square_with!(PackedBinaryField2x64b @ SimdStrategy);
@@ -91,7 +91,7 @@ cfg_if! {
 		impl_invert_with!(PackedBinaryField2x64b @ crate::arch::AESIsomorphicStrategy);
 		impl_invert_with!(PackedBinaryField1x128b @ crate::arch::AESIsomorphicStrategy);
 	} else {
-		impl_invert_with!(PackedBinaryField16x8b @ crate::arch::PairwiseTableStrategy);
+		impl_invert_with!(PackedBinaryField16x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_invert_with!(PackedBinaryField8x16b @ SimdStrategy);
 		impl_invert_with!(PackedBinaryField4x32b @ SimdStrategy);
 		impl_invert_with!(PackedBinaryField2x64b @ SimdStrategy);
@@ -106,7 +106,7 @@ cfg_if! {
 	if #[cfg(target_feature = "gfni")] {
 		impl_mul_alpha_with!(PackedBinaryField16x8b @ crate::arch::ReuseMultiplyStrategy);
 	} else {
-		impl_mul_alpha_with!(PackedBinaryField16x8b @ crate::arch::PairwiseTableStrategy);
+		impl_mul_alpha_with!(PackedBinaryField16x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 	}
 }
 impl_mul_alpha_with!(PackedBinaryField8x16b @ SimdStrategy);
@@ -52,7 +52,7 @@ cfg_if! {
 		impl_mul_with!(PackedBinaryField4x64b @ crate::arch::AESIsomorphicStrategy);
 		impl_mul_with!(PackedBinaryField2x128b @ crate::arch::AESIsomorphicStrategy);
 	} else {
-		impl_mul_with!(PackedBinaryField32x8b @ crate::arch::PairwiseTableStrategy);
+		impl_mul_with!(PackedBinaryField32x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_mul_with!(PackedBinaryField16x16b @ SimdStrategy);
 		impl_mul_with!(PackedBinaryField8x32b @ SimdStrategy);
 		impl_mul_with!(PackedBinaryField4x64b @ SimdStrategy);
@@ -71,7 +71,7 @@ cfg_if! {
 		impl_square_with!(PackedBinaryField4x64b @ crate::arch::AESIsomorphicStrategy);
 		impl_square_with!(PackedBinaryField2x128b @ crate::arch::AESIsomorphicStrategy);
 	} else {
-		impl_square_with!(PackedBinaryField32x8b @ crate::arch::PairwiseTableStrategy);
+		impl_square_with!(PackedBinaryField32x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_square_with!(PackedBinaryField16x16b @ SimdStrategy);
 		impl_square_with!(PackedBinaryField8x32b @ SimdStrategy);
 		impl_square_with!(PackedBinaryField4x64b @ SimdStrategy);
@@ -90,7 +90,7 @@ cfg_if! {
 		impl_invert_with!(PackedBinaryField4x64b @ crate::arch::AESIsomorphicStrategy);
 		impl_invert_with!(PackedBinaryField2x128b @ crate::arch::AESIsomorphicStrategy);
 	} else {
-		impl_invert_with!(PackedBinaryField32x8b @ crate::arch::PairwiseTableStrategy);
+		impl_invert_with!(PackedBinaryField32x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_invert_with!(PackedBinaryField16x16b @ SimdStrategy);
 		impl_invert_with!(PackedBinaryField8x32b @ SimdStrategy);
 		impl_invert_with!(PackedBinaryField4x64b @ SimdStrategy);
@@ -105,7 +105,7 @@ cfg_if! {
 	if #[cfg(target_feature = "gfni")] {
 		impl_mul_alpha_with!(PackedBinaryField32x8b @ crate::arch::ReuseMultiplyStrategy);
 	} else {
-		impl_mul_alpha_with!(PackedBinaryField32x8b @ crate::arch::PairwiseTableStrategy);
+		impl_mul_alpha_with!(PackedBinaryField32x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 	}
 }
 impl_mul_alpha_with!(PackedBinaryField16x16b @ SimdStrategy);
@@ -50,7 +50,7 @@ cfg_if::cfg_if! {
 		impl_mul_with!(PackedBinaryField8x64b @ crate::arch::AESIsomorphicStrategy);
 		impl_mul_with!(PackedBinaryField4x128b @ crate::arch::AESIsomorphicStrategy);
 	} else {
-		impl_mul_with!(PackedBinaryField64x8b @ crate::arch::PairwiseTableStrategy);
+		impl_mul_with!(PackedBinaryField64x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_mul_with!(PackedBinaryField32x16b @ SimdStrategy);
 		impl_mul_with!(PackedBinaryField16x32b @ SimdStrategy);
 		impl_mul_with!(PackedBinaryField8x64b @ SimdStrategy);
@@ -69,7 +69,7 @@ cfg_if::cfg_if! {
 		impl_square_with!(PackedBinaryField8x64b @ crate::arch::AESIsomorphicStrategy);
 		impl_square_with!(PackedBinaryField4x128b @ crate::arch::AESIsomorphicStrategy);
 	} else {
-		impl_square_with!(PackedBinaryField64x8b @ crate::arch::PairwiseTableStrategy);
+		impl_square_with!(PackedBinaryField64x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_square_with!(PackedBinaryField32x16b @ SimdStrategy);
 		impl_square_with!(PackedBinaryField16x32b @ SimdStrategy);
 		impl_square_with!(PackedBinaryField8x64b @ SimdStrategy);
@@ -88,7 +88,7 @@ cfg_if::cfg_if! {
 		impl_invert_with!(PackedBinaryField8x64b @ crate::arch::AESIsomorphicStrategy);
 		impl_invert_with!(PackedBinaryField4x128b @ crate::arch::AESIsomorphicStrategy);
 	} else {
-		impl_invert_with!(PackedBinaryField64x8b @ crate::arch::PairwiseTableStrategy);
+		impl_invert_with!(PackedBinaryField64x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 		impl_invert_with!(PackedBinaryField32x16b @ SimdStrategy);
 		impl_invert_with!(PackedBinaryField16x32b @ SimdStrategy);
 		impl_invert_with!(PackedBinaryField8x64b @ SimdStrategy);
@@ -103,7 +103,7 @@ cfg_if::cfg_if! {
 	if #[cfg(target_feature = "gfni")] {
 		impl_mul_alpha_with!(PackedBinaryField64x8b @ crate::arch::ReuseMultiplyStrategy);
 	} else {
-		impl_mul_alpha_with!(PackedBinaryField64x8b @ crate::arch::PairwiseTableStrategy);
+		impl_mul_alpha_with!(PackedBinaryField64x8b @ crate::arch::PairwiseTableOrConstantTimeStrategy);
 	}
 }
 impl_mul_alpha_with!(PackedBinaryField32x16b @ SimdStrategy);
@@ -21,7 +21,7 @@ impl From<PackedBinaryPolyval2x128b> for [u128; 2] {
 // Define multiplication
 cfg_if! {
 	if #[cfg(target_feature = "vpclmulqdq")] {
-		impl std::ops::Mul for PackedBinaryPolyval2x128b {
+		impl core::ops::Mul for PackedBinaryPolyval2x128b {
 			type Output = Self;
 
 			fn mul(self, rhs: Self) -> Self::Output {
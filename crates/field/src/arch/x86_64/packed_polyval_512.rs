@@ -21,7 +21,7 @@ impl From<PackedBinaryPolyval4x128b> for [u128; 4] {
 // Define multiplication
 cfg_if! {
 	if #[cfg(target_feature = "pclmulqdq")] {
-		impl std::ops::Mul for PackedBinaryPolyval4x128b {
+		impl core::ops::Mul for PackedBinaryPolyval4x128b {
 			type Output = Self;
 
 			fn mul(self, rhs: Self) -> Self::Output {
@@ -47,7 +47,7 @@ cfg_if! {
 
 		impl_transformation_with_gfni_nxn!(PackedBinaryPolyval4x128b, 16);
 	} else {
-		use create::arch::SimdStrategy;
+		use crate::arch::SimdStrategy;
 		use crate::arithmetic_traits::impl_transformation_with_strategy;
 
 		impl_transformation_with_strategy!(PackedBinaryPolyval4x128b, SimdStrategy);
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::montgomery_mul::PolyvalSimdType;
 use crate::arch::x86_64::m256::M256;
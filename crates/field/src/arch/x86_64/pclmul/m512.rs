@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use seq_macro::seq;
 
@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{any::TypeId, arch::x86_64::*};
+use alloc::vec::Vec;
+use core::{any::TypeId, arch::x86_64::*};
 
 use crate::{
 	BinaryField, BinaryField8b, PackedField, TowerField,
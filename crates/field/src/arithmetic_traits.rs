@@ -36,7 +36,7 @@ pub trait TaggedMul<Strategy> {
 
 macro_rules! impl_mul_with {
 	($name:ident @ $strategy:ty) => {
-		impl std::ops::Mul for $name {
+		impl core::ops::Mul for $name {
 			type Output = Self;
 
 			#[inline]
@@ -48,7 +48,7 @@ macro_rules! impl_mul_with {
 		}
 	};
 	($name:ty => $bigger:ty) => {
-		impl std::ops::Mul for $name {
+		impl core::ops::Mul for $name {
 			type Output = Self;
 
 			#[inline]
@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use crate::{
-	ExtensionField, Field, PackedField,
+	BinaryField256b, ExtensionField, Field, PackedField,
 	aes_field::*,
 	arch::{
 		packed_1::*, packed_2::*, packed_4::*, packed_8::*, packed_16::*, packed_32::*,
@@ -53,6 +53,8 @@ impl_as_single_packed_field!(BinaryField16b, PackedBinaryField1x16b);
 impl_as_single_packed_field!(BinaryField32b, PackedBinaryField1x32b);
 impl_as_single_packed_field!(BinaryField64b, PackedBinaryField1x64b);
 impl_as_single_packed_field!(BinaryField128b, PackedBinaryField1x128b);
+// There is no single-element packed type wider than 128 bits, the scalar is its own packing.
+impl_as_single_packed_field!(BinaryField256b, BinaryField256b);
 
 impl_as_single_packed_field!(AESTowerField8b, PackedAESBinaryField1x8b);
 impl_as_single_packed_field!(AESTowerField16b, PackedAESBinaryField1x16b);
@@ -1,6 +1,6 @@
 // Copyright 2023-2025 Irreducible Inc.
 
-use std::{
+use core::{
 	any::TypeId,
 	fmt::{Debug, Display, Formatter},
 	iter::{Product, Sum},
@@ -327,14 +327,14 @@ macro_rules! binary_field {
 		}
 
 		impl Display for $name {
-			fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+			fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
 				write!(f, "0x{repr:0>width$x}", repr=self.val(), width=Self::N_BITS.max(4) / 4)
 			}
 		}
 
 		impl Debug for $name {
-			fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-				let structure_name = std::any::type_name::<$name>().split("::").last().expect("exist");
+			fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+				let structure_name = core::any::type_name::<$name>().split("::").last().expect("exist");
 
 				write!(f, "{}({})",structure_name, self)
 			}
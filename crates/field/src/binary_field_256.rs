@@ -0,0 +1,721 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The 256-bit binary tower field $T_8$.
+//!
+//! [`BinaryField256b`] is the degree-2 extension of [`BinaryField128b`] in the canonical tower,
+//! i.e. $T_8 = T_7[X_7] / (X_7^2 + X_6 X_7 + 1)$. Unlike the lower levels, there is no native
+//! 256-bit integer to back the field, so the elements are stored as a pair of 128-bit halves and
+//! the arithmetic is implemented with the tower formulas on top of [`BinaryField128b`].
+
+use core::{
+	fmt::{Debug, Display, Formatter},
+	iter::{Product, Sum},
+	ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use binius_utils::{
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+	bytes::{Buf, BufMut},
+};
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+use rand::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::{
+	BinaryField, BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b, BinaryField16b,
+	BinaryField32b, BinaryField64b, BinaryField128b, ExtensionField, Field, TowerField,
+	arithmetic_traits::{InvertOrZero, Square},
+	as_packed_field::PackScalar,
+	binary_field::TowerExtensionField,
+	error::Error,
+	underlier::{Random, ScaledUnderlier, WithUnderlier},
+};
+
+/// The element $X_6$ of [`BinaryField128b`], which is the linear coefficient of the defining
+/// polynomial of $T_8$ over $T_7$.
+const X6: BinaryField128b = BinaryField128b::new(1 << 64);
+
+/// The binary tower field with 256 bits, the quadratic extension of [`BinaryField128b`].
+#[derive(
+	Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroable, TransparentWrapper,
+)]
+#[repr(transparent)]
+pub struct BinaryField256b(pub(crate) ScaledUnderlier<u128, 2>);
+
+impl BinaryField256b {
+	/// Creates an element from its low and high 128-bit halves.
+	pub const fn new(value: [u128; 2]) -> Self {
+		Self(ScaledUnderlier(value))
+	}
+
+	/// Returns the low and high 128-bit halves of the element.
+	pub const fn val(self) -> [u128; 2] {
+		self.0.0
+	}
+
+	#[inline]
+	fn from_halves(lo: BinaryField128b, hi: BinaryField128b) -> Self {
+		Self::new([lo.val(), hi.val()])
+	}
+
+	#[inline]
+	fn halves(self) -> (BinaryField128b, BinaryField128b) {
+		let [lo, hi] = self.val();
+		(BinaryField128b::new(lo), BinaryField128b::new(hi))
+	}
+}
+
+unsafe impl Pod for BinaryField256b {}
+
+unsafe impl WithUnderlier for BinaryField256b {
+	type Underlier = ScaledUnderlier<u128, 2>;
+
+	fn to_underlier(self) -> Self::Underlier {
+		TransparentWrapper::peel(self)
+	}
+
+	fn to_underlier_ref(&self) -> &Self::Underlier {
+		TransparentWrapper::peel_ref(self)
+	}
+
+	fn to_underlier_ref_mut(&mut self) -> &mut Self::Underlier {
+		TransparentWrapper::peel_mut(self)
+	}
+
+	fn to_underliers_ref(val: &[Self]) -> &[Self::Underlier] {
+		TransparentWrapper::peel_slice(val)
+	}
+
+	fn to_underliers_ref_mut(val: &mut [Self]) -> &mut [Self::Underlier] {
+		TransparentWrapper::peel_slice_mut(val)
+	}
+
+	fn from_underlier(val: Self::Underlier) -> Self {
+		TransparentWrapper::wrap(val)
+	}
+
+	fn from_underlier_ref(val: &Self::Underlier) -> &Self {
+		TransparentWrapper::wrap_ref(val)
+	}
+
+	fn from_underlier_ref_mut(val: &mut Self::Underlier) -> &mut Self {
+		TransparentWrapper::wrap_mut(val)
+	}
+
+	fn from_underliers_ref(val: &[Self::Underlier]) -> &[Self] {
+		TransparentWrapper::wrap_slice(val)
+	}
+
+	fn from_underliers_ref_mut(val: &mut [Self::Underlier]) -> &mut [Self] {
+		TransparentWrapper::wrap_slice_mut(val)
+	}
+}
+
+impl PackScalar<BinaryField256b> for ScaledUnderlier<u128, 2> {
+	type Packed = BinaryField256b;
+}
+
+impl Neg for BinaryField256b {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		self
+	}
+}
+
+impl Add<Self> for BinaryField256b {
+	type Output = Self;
+
+	#[allow(clippy::suspicious_arithmetic_impl)]
+	fn add(self, rhs: Self) -> Self::Output {
+		Self(self.0 ^ rhs.0)
+	}
+}
+
+impl Add<&Self> for BinaryField256b {
+	type Output = Self;
+
+	fn add(self, rhs: &Self) -> Self::Output {
+		self + *rhs
+	}
+}
+
+impl Sub<Self> for BinaryField256b {
+	type Output = Self;
+
+	#[allow(clippy::suspicious_arithmetic_impl)]
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self(self.0 ^ rhs.0)
+	}
+}
+
+impl Sub<&Self> for BinaryField256b {
+	type Output = Self;
+
+	fn sub(self, rhs: &Self) -> Self::Output {
+		self - *rhs
+	}
+}
+
+impl Mul<Self> for BinaryField256b {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self::Output {
+		crate::tracing::trace_multiplication!(BinaryField256b);
+
+		// Karatsuba multiplication, reducing with X_7^2 = X_6 X_7 + 1.
+		let (a0, a1) = self.halves();
+		let (b0, b1) = rhs.halves();
+		let z0 = a0 * b0;
+		let z2 = a1 * b1;
+		let z1 = (a0 + a1) * (b0 + b1) - z0 - z2;
+		Self::from_halves(z0 + z2, z1 + z2 * X6)
+	}
+}
+
+impl Mul<&Self> for BinaryField256b {
+	type Output = Self;
+
+	fn mul(self, rhs: &Self) -> Self::Output {
+		self * *rhs
+	}
+}
+
+impl AddAssign<Self> for BinaryField256b {
+	fn add_assign(&mut self, rhs: Self) {
+		*self = *self + rhs;
+	}
+}
+
+impl AddAssign<&Self> for BinaryField256b {
+	fn add_assign(&mut self, rhs: &Self) {
+		*self = *self + *rhs;
+	}
+}
+
+impl SubAssign<Self> for BinaryField256b {
+	fn sub_assign(&mut self, rhs: Self) {
+		*self = *self - rhs;
+	}
+}
+
+impl SubAssign<&Self> for BinaryField256b {
+	fn sub_assign(&mut self, rhs: &Self) {
+		*self = *self - *rhs;
+	}
+}
+
+impl MulAssign<Self> for BinaryField256b {
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs;
+	}
+}
+
+impl MulAssign<&Self> for BinaryField256b {
+	fn mul_assign(&mut self, rhs: &Self) {
+		*self = *self * *rhs;
+	}
+}
+
+impl Sum<Self> for BinaryField256b {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self::ZERO, |acc, x| acc + x)
+	}
+}
+
+impl<'a> Sum<&'a Self> for BinaryField256b {
+	fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.fold(Self::ZERO, |acc, x| acc + x)
+	}
+}
+
+impl Product<Self> for BinaryField256b {
+	fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self::ONE, |acc, x| acc * x)
+	}
+}
+
+impl<'a> Product<&'a Self> for BinaryField256b {
+	fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.fold(Self::ONE, |acc, x| acc * x)
+	}
+}
+
+impl ConstantTimeEq for BinaryField256b {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0.ct_eq(&other.0)
+	}
+}
+
+impl ConditionallySelectable for BinaryField256b {
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		let [a_lo, a_hi] = a.val();
+		let [b_lo, b_hi] = b.val();
+		Self::new([
+			u128::conditional_select(&a_lo, &b_lo, choice),
+			u128::conditional_select(&a_hi, &b_hi, choice),
+		])
+	}
+}
+
+impl Square for BinaryField256b {
+	fn square(self) -> Self {
+		let (a0, a1) = self.halves();
+		let a1_squared = Square::square(a1);
+		Self::from_halves(Square::square(a0) + a1_squared, a1_squared * X6)
+	}
+}
+
+impl InvertOrZero for BinaryField256b {
+	fn invert_or_zero(self) -> Self {
+		// The conjugate of a0 + a1 X_7 is (a0 + a1 X_6) + a1 X_7, and the product of the two is the
+		// norm a0^2 + a0 a1 X_6 + a1^2, which lies in the subfield.
+		let (a0, a1) = self.halves();
+		let norm = Square::square(a0) + a0 * a1 * X6 + Square::square(a1);
+		let norm_inv = InvertOrZero::invert_or_zero(norm);
+		Self::from_halves((a0 + a1 * X6) * norm_inv, a1 * norm_inv)
+	}
+}
+
+impl Field for BinaryField256b {
+	const ZERO: Self = Self::new([0, 0]);
+	const ONE: Self = Self::new([1, 0]);
+	const CHARACTERISTIC: usize = 2;
+
+	fn random(mut rng: impl RngCore) -> Self {
+		Self(<ScaledUnderlier<u128, 2> as Random>::random(&mut rng))
+	}
+
+	fn double(&self) -> Self {
+		Self::ZERO
+	}
+}
+
+impl Display for BinaryField256b {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		let [lo, hi] = self.val();
+		write!(f, "0x{hi:032x}{lo:032x}")
+	}
+}
+
+impl Debug for BinaryField256b {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "BinaryField256b({self})")
+	}
+}
+
+impl BinaryField for BinaryField256b {
+	const MULTIPLICATIVE_GENERATOR: Self = Self::new([0x5, 0x2]);
+}
+
+impl TowerField for BinaryField256b {
+	type Canonical = Self;
+
+	fn min_tower_level(self) -> usize {
+		let (lo, hi) = self.halves();
+		if hi == BinaryField128b::ZERO {
+			lo.min_tower_level()
+		} else {
+			Self::TOWER_LEVEL
+		}
+	}
+
+	fn mul_primitive(self, iota: usize) -> Result<Self, Error> {
+		let (a0, a1) = self.halves();
+		match iota {
+			// The primitive element lies in the subfield, so multiply the halves componentwise.
+			0..=6 => Ok(Self::from_halves(a0.mul_primitive(iota)?, a1.mul_primitive(iota)?)),
+			// (a0 + a1 X_7) X_7 = a1 + (a0 + a1 X_6) X_7
+			7 => Ok(Self::from_halves(a1, a0 + a1 * X6)),
+			_ => Err(Error::ExtensionDegreeMismatch),
+		}
+	}
+}
+
+impl From<BinaryField256b> for (BinaryField128b, BinaryField128b) {
+	#[inline]
+	fn from(src: BinaryField256b) -> Self {
+		src.halves()
+	}
+}
+
+impl From<(BinaryField128b, BinaryField128b)> for BinaryField256b {
+	#[inline]
+	fn from((lo, hi): (BinaryField128b, BinaryField128b)) -> Self {
+		Self::from_halves(lo, hi)
+	}
+}
+
+impl TowerExtensionField for BinaryField256b {
+	type DirectSubfield = BinaryField128b;
+}
+
+impl SerializeBytes for BinaryField256b {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		let [lo, hi] = self.val();
+		lo.serialize(&mut write_buf, mode)?;
+		hi.serialize(write_buf, mode)
+	}
+}
+
+impl DeserializeBytes for BinaryField256b {
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		let lo = u128::deserialize(&mut read_buf, mode)?;
+		let hi = u128::deserialize(read_buf, mode)?;
+		Ok(Self::new([lo, hi]))
+	}
+}
+
+/// Implements the subfield conversions, mixed arithmetic and [`ExtensionField`] for a subfield of
+/// [`BinaryField128b`], delegating to the extension of the 128-bit halves.
+macro_rules! impl_extension_over_128b_subfield {
+	($($subfield_name:ident),+ $(,)?) => {
+		$(
+			impl TryFrom<BinaryField256b> for $subfield_name {
+				type Error = ();
+
+				#[inline]
+				fn try_from(elem: BinaryField256b) -> Result<Self, Self::Error> {
+					let (lo, hi) = elem.halves();
+					if hi != BinaryField128b::ZERO {
+						return Err(());
+					}
+					$subfield_name::try_from(lo).map_err(|_| ())
+				}
+			}
+
+			impl From<$subfield_name> for BinaryField256b {
+				#[inline]
+				fn from(elem: $subfield_name) -> Self {
+					Self::from_halves(BinaryField128b::from(elem), BinaryField128b::ZERO)
+				}
+			}
+
+			impl Add<$subfield_name> for BinaryField256b {
+				type Output = Self;
+
+				#[inline]
+				fn add(self, rhs: $subfield_name) -> Self::Output {
+					self + Self::from(rhs)
+				}
+			}
+
+			impl Sub<$subfield_name> for BinaryField256b {
+				type Output = Self;
+
+				#[inline]
+				fn sub(self, rhs: $subfield_name) -> Self::Output {
+					self - Self::from(rhs)
+				}
+			}
+
+			impl Mul<$subfield_name> for BinaryField256b {
+				type Output = Self;
+
+				#[inline]
+				fn mul(self, rhs: $subfield_name) -> Self::Output {
+					$crate::tracing::trace_multiplication!(BinaryField256b, $subfield_name);
+
+					let (lo, hi) = self.halves();
+					Self::from_halves(lo * rhs, hi * rhs)
+				}
+			}
+
+			impl AddAssign<$subfield_name> for BinaryField256b {
+				#[inline]
+				fn add_assign(&mut self, rhs: $subfield_name) {
+					*self = *self + rhs;
+				}
+			}
+
+			impl SubAssign<$subfield_name> for BinaryField256b {
+				#[inline]
+				fn sub_assign(&mut self, rhs: $subfield_name) {
+					*self = *self - rhs;
+				}
+			}
+
+			impl MulAssign<$subfield_name> for BinaryField256b {
+				#[inline]
+				fn mul_assign(&mut self, rhs: $subfield_name) {
+					*self = *self * rhs;
+				}
+			}
+
+			impl Add<BinaryField256b> for $subfield_name {
+				type Output = BinaryField256b;
+
+				#[inline]
+				fn add(self, rhs: BinaryField256b) -> Self::Output {
+					rhs + self
+				}
+			}
+
+			impl Sub<BinaryField256b> for $subfield_name {
+				type Output = BinaryField256b;
+
+				#[allow(clippy::suspicious_arithmetic_impl)]
+				#[inline]
+				fn sub(self, rhs: BinaryField256b) -> Self::Output {
+					rhs + self
+				}
+			}
+
+			impl Mul<BinaryField256b> for $subfield_name {
+				type Output = BinaryField256b;
+
+				#[inline]
+				fn mul(self, rhs: BinaryField256b) -> Self::Output {
+					rhs * self
+				}
+			}
+
+			impl ExtensionField<$subfield_name> for BinaryField256b {
+				const LOG_DEGREE: usize =
+					<BinaryField128b as ExtensionField<$subfield_name>>::LOG_DEGREE + 1;
+
+				#[inline]
+				fn basis_checked(i: usize) -> Result<Self, Error> {
+					let half_degree = <BinaryField128b as ExtensionField<$subfield_name>>::DEGREE;
+					if i >= 2 * half_degree {
+						return Err(Error::ExtensionDegreeMismatch);
+					}
+					let (lo, hi) = if i < half_degree {
+						(<BinaryField128b as ExtensionField<$subfield_name>>::basis(i), BinaryField128b::ZERO)
+					} else {
+						(
+							BinaryField128b::ZERO,
+							<BinaryField128b as ExtensionField<$subfield_name>>::basis(i - half_degree),
+						)
+					};
+					Ok(Self::from_halves(lo, hi))
+				}
+
+				#[inline]
+				fn from_bases_sparse(
+					base_elems: impl IntoIterator<Item = $subfield_name>,
+					log_stride: usize,
+				) -> Result<Self, Error> {
+					let mut result = Self::ZERO;
+					for (i, elem) in base_elems.into_iter().enumerate() {
+						result += <Self as ExtensionField<$subfield_name>>::basis_checked(i << log_stride)? * elem;
+					}
+					Ok(result)
+				}
+
+				#[inline]
+				fn iter_bases(&self) -> impl Iterator<Item = $subfield_name> {
+					<Self as ExtensionField<$subfield_name>>::into_iter_bases(*self)
+				}
+
+				#[inline]
+				fn into_iter_bases(self) -> impl Iterator<Item = $subfield_name> {
+					let (lo, hi) = self.halves();
+					<BinaryField128b as ExtensionField<$subfield_name>>::into_iter_bases(lo)
+						.chain(<BinaryField128b as ExtensionField<$subfield_name>>::into_iter_bases(hi))
+				}
+
+				#[inline]
+				unsafe fn get_base_unchecked(&self, i: usize) -> $subfield_name {
+					let half_degree = <BinaryField128b as ExtensionField<$subfield_name>>::DEGREE;
+					let (lo, hi) = self.halves();
+					unsafe {
+						if i < half_degree {
+							<BinaryField128b as ExtensionField<$subfield_name>>::get_base_unchecked(&lo, i)
+						} else {
+							<BinaryField128b as ExtensionField<$subfield_name>>::get_base_unchecked(
+								&hi,
+								i - half_degree,
+							)
+						}
+					}
+				}
+			}
+		)+
+	};
+}
+
+impl_extension_over_128b_subfield!(
+	BinaryField1b,
+	BinaryField2b,
+	BinaryField4b,
+	BinaryField8b,
+	BinaryField16b,
+	BinaryField32b,
+	BinaryField64b,
+	BinaryField128b,
+);
+
+#[cfg(test)]
+mod tests {
+	use binius_utils::bytes::BytesMut;
+	use proptest::prelude::*;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{PackedBinaryField4x256b, PackedField};
+
+	type BF256 = BinaryField256b;
+
+	fn arb_bf256() -> impl Strategy<Value = BF256> {
+		any::<[u128; 2]>().prop_map(BF256::new)
+	}
+
+	#[test]
+	fn test_mul_vector() {
+		let a = BF256::new([
+			0x0123456789ABCDEF0011223344556677,
+			0x8899AABBCCDDEEFF0F1E2D3C4B5A6978,
+		]);
+		let b = BF256::new([0xFEDCBA98765432100123456789ABCDEF, 0x1]);
+		assert_eq!(
+			a * b,
+			BF256::new([
+				0x6f70e11391a8597082e1a8f2f1275478,
+				0xb0d49889ddd7e5069522984603328888
+			])
+		);
+	}
+
+	#[test]
+	fn test_defining_polynomial() {
+		let x7 = <BF256 as ExtensionField<BinaryField128b>>::basis(1);
+		assert_eq!(x7 * x7, x7 * X6 + BF256::ONE);
+	}
+
+	#[test]
+	fn test_degrees() {
+		assert_eq!(BF256::N_BITS, 256);
+		assert_eq!(BF256::TOWER_LEVEL, 8);
+		assert_eq!(<BF256 as ExtensionField<BinaryField8b>>::DEGREE, 32);
+		assert_eq!(<BF256 as ExtensionField<BinaryField128b>>::DEGREE, 2);
+	}
+
+	#[test]
+	fn test_inverse_on_zero() {
+		assert!(BF256::ZERO.invert().is_none());
+	}
+
+	#[test]
+	fn test_multiplicative_generator() {
+		// The prime factors of 2^256 - 1, grouped by the Fermat numbers 2^(2^i) + 1 dividing it.
+		let fermat_factors: [&[u128]; 8] = [
+			&[3],
+			&[5],
+			&[17],
+			&[257],
+			&[65537],
+			&[641, 6700417],
+			&[274177, 67280421310721],
+			&[59649589127497217, 5704689200685129054721],
+		];
+
+		let g = BF256::MULTIPLICATIVE_GENERATOR;
+		assert_eq!((0..256).fold(g, |acc, _| Square::square(acc)), g);
+
+		for (j, factors) in fermat_factors.iter().enumerate() {
+			for &p in factors.iter() {
+				// Raise to the power (2^256 - 1) / p, one Fermat number at a time.
+				let mut x = g;
+				for i in (0..8).filter(|&i| i != j) {
+					x *= (0..1 << i).fold(x, |acc, _| Square::square(acc));
+				}
+				// 2^128 + 1 does not fit into u128, but it has exactly two prime factors.
+				let cofactor = if j == 7 {
+					factors
+						.iter()
+						.copied()
+						.find(|&q| q != p)
+						.expect("two factors")
+				} else {
+					((1u128 << (1 << j)) + 1) / p
+				};
+				x = Field::pow(&x, [cofactor as u64, (cofactor >> 64) as u64]);
+				assert_ne!(x, BF256::ONE, "generator order divides (2^256 - 1) / {p}");
+			}
+		}
+	}
+
+	#[test]
+	fn test_serialization() {
+		let mode = SerializationMode::CanonicalTower;
+		let mut rng = StdRng::seed_from_u64(0);
+		let a = <BF256 as Field>::random(&mut rng);
+
+		let mut buffer = BytesMut::new();
+		a.serialize(&mut buffer, mode).unwrap();
+		assert_eq!(buffer.len(), 32);
+		let mut read_buffer = buffer.freeze();
+		assert_eq!(BF256::deserialize(&mut read_buffer, mode).unwrap(), a);
+	}
+
+	#[test]
+	fn test_packed() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let a = PackedBinaryField4x256b::random(&mut rng);
+		let b = PackedBinaryField4x256b::random(&mut rng);
+		let c = <BF256 as Field>::random(&mut rng);
+
+		let product = a * b;
+		let scaled = a * c;
+		for i in 0..PackedBinaryField4x256b::WIDTH {
+			assert_eq!(product.get(i), a.get(i) * b.get(i));
+			assert_eq!(scaled.get(i), a.get(i) * c);
+		}
+	}
+
+	proptest! {
+		#[test]
+		fn test_mul_associative(a in arb_bf256(), b in arb_bf256(), c in arb_bf256()) {
+			assert_eq!((a * b) * c, a * (b * c));
+		}
+
+		#[test]
+		fn test_mul_distributive(a in arb_bf256(), b in arb_bf256(), c in arb_bf256()) {
+			assert_eq!(a * (b + c), a * b + a * c);
+		}
+
+		#[test]
+		fn test_square(a in arb_bf256()) {
+			assert_eq!(Square::square(a), a * a);
+		}
+
+		#[test]
+		fn test_inverse(a in arb_bf256()) {
+			prop_assume!(a != BF256::ZERO);
+			assert_eq!(a * a.invert().unwrap(), BF256::ONE);
+		}
+
+		#[test]
+		fn test_subfield_embedding(a in any::<u128>(), b in any::<u128>(), c in any::<u64>()) {
+			let (a, b, c) = (BinaryField128b::new(a), BinaryField128b::new(b), BinaryField64b::new(c));
+			assert_eq!(BF256::from(a) * BF256::from(b), BF256::from(a * b));
+			assert_eq!(BF256::from(a) * c, BF256::from(a * c));
+		}
+
+		#[test]
+		fn test_mul_primitive(a in arb_bf256(), iota in 0usize..9) {
+			let result = a.mul_primitive(iota);
+			let expected = <BF256 as ExtensionField<BinaryField1b>>::basis_checked(1 << iota)
+				.map(|b| a * b);
+			assert_eq!(result.ok(), expected.ok());
+		}
+
+		#[test]
+		fn test_bases_roundtrip(a in arb_bf256()) {
+			let bases = <BF256 as ExtensionField<BinaryField8b>>::iter_bases(&a).collect::<Vec<_>>();
+			assert_eq!(bases.len(), 32);
+			assert_eq!(<BF256 as ExtensionField<BinaryField8b>>::from_bases(bases).unwrap(), a);
+		}
+	}
+}
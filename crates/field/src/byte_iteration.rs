@@ -1,6 +1,7 @@
 // Copyright 2023-2025 Irreducible Inc.
 
-use std::any::TypeId;
+use alloc::vec::Vec;
+use core::any::TypeId;
 
 use binius_utils::random_access_sequence::RandomAccessSequence;
 use bytemuck::{Pod, zeroed_vec};
@@ -134,17 +135,17 @@ unsafe impl SequentialBytes for PackedBinaryPolyval4x128b {}
 #[inline(always)]
 #[allow(clippy::redundant_clone)] // this is intentional in this method
 pub fn is_sequential_bytes<T>() -> bool {
-	struct X<U>(bool, std::marker::PhantomData<U>);
+	struct X<U>(bool, core::marker::PhantomData<U>);
 
 	impl<U> Clone for X<U> {
 		fn clone(&self) -> Self {
-			Self(false, std::marker::PhantomData)
+			Self(false, core::marker::PhantomData)
 		}
 	}
 
 	impl<U: SequentialBytes> Copy for X<U> {}
 
-	let value = [X::<T>(true, std::marker::PhantomData)];
+	let value = [X::<T>(true, core::marker::PhantomData)];
 	let cloned = value.clone();
 
 	cloned[0].0
@@ -214,7 +215,7 @@ macro_rules! iterate_byte_sliced {
 
 		// Safety: the cast is safe because the type is checked by arm statement
 		let data = unsafe {
-			std::slice::from_raw_parts($data.as_ptr() as *const $packed_type, $data.len())
+			core::slice::from_raw_parts($data.as_ptr() as *const $packed_type, $data.len())
 		};
 		let iter = data.iter().flat_map(|value| {
 			(0..<$packed_type>::BYTES).map(move |i| unsafe { value.get_byte_unchecked(i) })
@@ -240,7 +241,7 @@ pub fn iterate_bytes<P: PackedField>(data: &[P], callback: &mut impl ByteIterato
 		// Safety: `P` implements `SequentialBytes` trait, so the following cast is safe
 		// and preserves the order.
 		let bytes = unsafe {
-			std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data))
+			core::slice::from_raw_parts(data.as_ptr() as *const u8, core::mem::size_of_val(data))
 		};
 		callback.call(bytes.iter().copied());
 	} else {
@@ -11,6 +11,8 @@ pub enum Error {
 	MismatchedLengths,
 	#[error("the argument has too large a field extension degree")]
 	ExtensionDegreeTooHigh,
+	#[error("expected {expected} bytes, got {actual}")]
+	ByteLengthMismatch { expected: usize, actual: usize },
 	#[error("index {index} is out of range 0..{max}")]
 	IndexOutOfRange { index: usize, max: usize },
 	/// Thrown when trying to initialize a binary field element with a value bigger than what fits
@@ -1,6 +1,6 @@
 // Copyright 2023-2025 Irreducible Inc.
 
-use std::{
+use core::{
 	iter,
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
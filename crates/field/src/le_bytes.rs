@@ -0,0 +1,233 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Little-endian byte encoding of fields and packed fields.
+//!
+//! The encoding of a value backed by an underlier is the little-endian encoding of the underlier,
+//! viewed as an unsigned integer. Scalar `i` of a packed field with `w`-bit scalars therefore
+//! occupies bits `i * w..(i + 1) * w` of the encoding, where bit `j` is bit `j % 8` of byte
+//! `j / 8`. For example, the encoding of a [`PackedBinaryField128x1b`] is 16 bytes holding the 128
+//! scalars in order, and the encoding of a [`PackedBinaryField8x16b`] is the concatenation of the
+//! little-endian encodings of its scalars. Byte-sliced packed fields are encoded in the
+//! byte-sliced order of their underliers.
+//!
+//! The encoding is the in-memory representation on little-endian targets, which include all
+//! targets with SIMD underliers, so the conversions are copies or zero-copy casts. On big-endian
+//! targets the bytes of every 128-bit lane are swapped.
+//!
+//! [`PackedBinaryField128x1b`]: crate::PackedBinaryField128x1b
+//! [`PackedBinaryField8x16b`]: crate::PackedBinaryField8x16b
+
+use alloc::{borrow::Cow, vec, vec::Vec};
+use core::{mem::size_of_val, slice};
+
+use bytemuck::{Pod, must_cast_slice};
+
+use crate::{
+	Error,
+	underlier::{UnderlierType, WithUnderlier},
+};
+
+/// The largest integer that a portable underlier is made of.
+const LANE_BYTES: usize = 16;
+
+/// Returns the little-endian encoding of the values.
+///
+/// The encoding is borrowed from the values on little-endian targets.
+pub fn to_le_bytes<T: WithUnderlier>(values: &[T]) -> Cow<'_, [u8]> {
+	let bytes = as_bytes(values);
+	if cfg!(target_endian = "little") {
+		Cow::Borrowed(bytes)
+	} else {
+		let mut bytes = bytes.to_vec();
+		swap_lanes::<T>(&mut bytes);
+		Cow::Owned(bytes)
+	}
+}
+
+/// Writes the little-endian encoding of the values to `bytes`.
+///
+/// `bytes` must be exactly as long as the encoding.
+pub fn copy_to_le_bytes<T: WithUnderlier>(values: &[T], bytes: &mut [u8]) -> Result<(), Error> {
+	check_len(size_of_val(values), bytes.len())?;
+	bytes.copy_from_slice(as_bytes(values));
+	swap_lanes::<T>(bytes);
+	Ok(())
+}
+
+/// Reads values from their little-endian encoding.
+///
+/// `bytes` must be exactly as long as the encoding of `values`. Fails with
+/// [`Error::NotInField`] if the bytes do not encode valid underliers, which can only happen for
+/// underliers of less than 8 bits.
+pub fn copy_from_le_bytes<T: WithUnderlier>(bytes: &[u8], values: &mut [T]) -> Result<(), Error> {
+	check_len(size_of_val(values), bytes.len())?;
+	check_valid::<T>(bytes)?;
+
+	// Safety: `T` has the representation of its underlier, which has no padding bytes, and the
+	// bytes encode valid underliers.
+	let dst =
+		unsafe { slice::from_raw_parts_mut(values.as_mut_ptr().cast::<u8>(), size_of_val(values)) };
+	dst.copy_from_slice(bytes);
+	swap_lanes::<T>(dst);
+	Ok(())
+}
+
+/// Reads a vector of values from their little-endian encoding.
+///
+/// The length of `bytes` must be a multiple of the size of `T`.
+pub fn from_le_bytes<T: WithUnderlier>(bytes: &[u8]) -> Result<Vec<T>, Error> {
+	let len = bytes.len().div_ceil(size_of::<T>());
+	let mut values = vec![T::zeroed(); len];
+	copy_from_le_bytes(bytes, &mut values)?;
+	Ok(values)
+}
+
+/// Reinterprets a little-endian encoding as values without copying.
+///
+/// Returns `None` on big-endian targets, or if `bytes` is not aligned for `T` or its length is not
+/// a multiple of the size of `T`. Callers can then fall back to [`from_le_bytes`].
+pub fn try_cast_le_bytes<T>(bytes: &[u8]) -> Option<&[T]>
+where
+	T: WithUnderlier<Underlier: Pod>,
+{
+	if cfg!(target_endian = "big") {
+		return None;
+	}
+	bytemuck::try_cast_slice(bytes)
+		.ok()
+		.map(T::from_underliers_ref)
+}
+
+/// Converts values whose memory holds their little-endian encoding to the native representation,
+/// for example after reading the encoding directly into the memory of the values.
+///
+/// This is a no-op on little-endian targets.
+pub fn from_le_in_place<T>(values: &mut [T])
+where
+	T: WithUnderlier<Underlier: Pod>,
+{
+	swap_lanes::<T>(bytemuck::must_cast_slice_mut(T::to_underliers_ref_mut(values)));
+}
+
+fn as_bytes<T: WithUnderlier>(values: &[T]) -> &[u8] {
+	must_cast_slice(T::to_underliers_ref(values))
+}
+
+fn check_len(expected: usize, actual: usize) -> Result<(), Error> {
+	if expected != actual {
+		return Err(Error::ByteLengthMismatch { expected, actual });
+	}
+	Ok(())
+}
+
+fn check_valid<T: WithUnderlier>(bytes: &[u8]) -> Result<(), Error> {
+	let bits = T::Underlier::BITS;
+	if bits < u8::BITS as usize && bytes.iter().any(|&byte| byte >> bits != 0) {
+		return Err(Error::NotInField);
+	}
+	Ok(())
+}
+
+/// Swaps the bytes of the integers the underliers are made of on big-endian targets.
+fn swap_lanes<T: WithUnderlier>(bytes: &mut [u8]) {
+	if cfg!(target_endian = "big") {
+		let lane_bytes = size_of::<T::Underlier>().min(LANE_BYTES);
+		for lane in bytes.chunks_exact_mut(lane_bytes) {
+			lane.reverse();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		BinaryField1b, BinaryField16b, Field, PackedBinaryField4x1b, PackedBinaryField8x16b,
+		PackedBinaryField128x1b, PackedField,
+	};
+
+	#[test]
+	fn test_bit_packed_encoding() {
+		let mut value = PackedBinaryField128x1b::zero();
+		value.set(0, BinaryField1b::ONE);
+		value.set(9, BinaryField1b::ONE);
+		value.set(127, BinaryField1b::ONE);
+
+		let values = [value];
+		let bytes = to_le_bytes(&values);
+		let mut expected = [0u8; 16];
+		expected[0] = 0x01;
+		expected[1] = 0x02;
+		expected[15] = 0x80;
+		assert_eq!(*bytes, expected);
+
+		assert_eq!(from_le_bytes::<PackedBinaryField128x1b>(&bytes).unwrap(), [value]);
+	}
+
+	#[test]
+	fn test_scalar_order_encoding() {
+		let value = PackedBinaryField8x16b::from_scalars(
+			(0..8).map(|i| BinaryField16b::new(0x0100 * i + 0x10 + i)),
+		);
+
+		let mut bytes = [0u8; 16];
+		copy_to_le_bytes(&[value], &mut bytes).unwrap();
+		let expected = (0..8u16)
+			.flat_map(|i| (0x0100 * i + 0x10 + i).to_le_bytes())
+			.collect::<Vec<_>>();
+		assert_eq!(bytes.as_slice(), expected);
+
+		let mut decoded = [PackedBinaryField8x16b::zero()];
+		copy_from_le_bytes(&bytes, &mut decoded).unwrap();
+		assert_eq!(decoded, [value]);
+	}
+
+	#[test]
+	fn test_small_underlier_validation() {
+		let values = from_le_bytes::<PackedBinaryField4x1b>(&[0x05, 0x0f]).unwrap();
+		assert_eq!(values[0].get(0), BinaryField1b::ONE);
+		assert_eq!(values[0].get(1), BinaryField1b::ZERO);
+		assert_eq!(*to_le_bytes(&values), [0x05, 0x0f]);
+
+		assert!(matches!(from_le_bytes::<PackedBinaryField4x1b>(&[0x10]), Err(Error::NotInField)));
+	}
+
+	#[test]
+	fn test_length_mismatch() {
+		assert!(matches!(
+			from_le_bytes::<PackedBinaryField8x16b>(&[0; 17]),
+			Err(Error::ByteLengthMismatch {
+				expected: 32,
+				actual: 17
+			})
+		));
+		assert!(matches!(
+			copy_to_le_bytes(&[PackedBinaryField8x16b::zero()], &mut [0; 15]),
+			Err(Error::ByteLengthMismatch {
+				expected: 16,
+				actual: 15
+			})
+		));
+	}
+
+	#[test]
+	fn test_zero_copy_cast() {
+		let values = [
+			PackedBinaryField128x1b::broadcast(BinaryField1b::ONE),
+			PackedBinaryField128x1b::zero(),
+		];
+		let bytes = to_le_bytes(&values);
+		if cfg!(target_endian = "little") {
+			assert_eq!(try_cast_le_bytes::<PackedBinaryField128x1b>(&bytes), Some(&values[..]));
+		}
+		assert_eq!(try_cast_le_bytes::<PackedBinaryField128x1b>(&bytes[1..17]), None);
+
+		let mut decoded = [PackedBinaryField128x1b::zero(); 2];
+		bytemuck::must_cast_slice_mut::<_, u8>(PackedBinaryField128x1b::to_underliers_ref_mut(
+			&mut decoded,
+		))
+		.copy_from_slice(&bytes);
+		from_le_in_place(&mut decoded);
+		assert_eq!(decoded, values);
+	}
+}
@@ -7,23 +7,32 @@
 //! extension degree $2^{\iota}$ for any tower height $\iota$. Mathematically, we label these sets
 //! $T_{\iota}$.
 //!
+//! The crate builds without the standard library, with the `alloc` collections, when the default
+//! `std` feature is disabled. The CPU features are then taken from the compile-time target
+//! features instead of being detected at runtime.
+//!
 //! [DP23]: https://eprint.iacr.org/2023/1784
 
 #![cfg_attr(
 	all(feature = "nightly_features", target_arch = "x86_64"),
 	feature(stdarch_x86_avx512)
 )]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod aes_field;
 pub mod arch;
 pub mod arithmetic_traits;
 pub mod as_packed_field;
 pub mod binary_field;
+mod binary_field_256;
 mod binary_field_arithmetic;
 pub mod byte_iteration;
 pub mod error;
 pub mod extension;
 pub mod field;
+pub mod le_bytes;
 pub mod linear_transformation;
 mod macros;
 pub mod packed;
@@ -45,6 +54,7 @@ pub mod util;
 pub use aes_field::*;
 pub use arch::byte_sliced::*;
 pub use binary_field::*;
+pub use binary_field_256::*;
 pub use error::*;
 pub use extension::*;
 pub use field::Field;
@@ -55,4 +65,4 @@ pub use packed_extension::*;
 pub use packed_extension_ops::*;
 pub use packed_polyval::*;
 pub use polyval::*;
-pub use transpose::{Error as TransposeError, square_transpose};
+pub use transpose::{Error as TransposeError, square_transpose, transpose_scalars};
@@ -4,7 +4,8 @@
 //!
 //! Interfaces are derived from [`plonky2`](https://github.com/mir-protocol/plonky2).
 
-use std::{
+use alloc::vec::Vec;
+use core::{
 	fmt::Debug,
 	iter::{self, Product, Sum},
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
@@ -836,7 +837,7 @@ mod tests {
 
 	fn check_slice_iteration<P: PackedField>(mut rng: impl RngCore) {
 		for len in [0, 1, 5] {
-			let packed = std::iter::repeat_with(|| P::random(&mut rng))
+			let packed = core::iter::repeat_with(|| P::random(&mut rng))
 				.take(len)
 				.collect::<Vec<_>>();
 
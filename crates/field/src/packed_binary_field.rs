@@ -2,7 +2,7 @@
 
 pub use crate::arch::{
 	packed_1::*, packed_2::*, packed_4::*, packed_8::*, packed_16::*, packed_32::*, packed_64::*,
-	packed_128::*, packed_256::*, packed_512::*,
+	packed_128::*, packed_256::*, packed_256b::*, packed_512::*,
 };
 
 /// Common code to test different multiply, square and invert implementations
@@ -88,7 +88,7 @@ pub mod test_utils {
 
 			impl<T> TestMulTrait<$crate::packed_binary_field::test_utils::Unit> for T {}
 
-			struct TestMult<T>(std::marker::PhantomData<T>);
+			struct TestMult<T>(core::marker::PhantomData<T>);
 
 			impl<T: $constraint + PackedField + $crate::underlier::WithUnderlier> TestMult<T> {
 				fn test_mul(
@@ -118,7 +118,7 @@ pub mod test_utils {
 
 			impl<T> TestSquareTrait<$crate::packed_binary_field::test_utils::Unit> for T {}
 
-			struct TestSquare<T>(std::marker::PhantomData<T>);
+			struct TestSquare<T>(core::marker::PhantomData<T>);
 
 			impl<T: $constraint + PackedField + $crate::underlier::WithUnderlier> TestSquare<T> {
 				fn test_square(a: <T as $crate::underlier::WithUnderlier>::Underlier) {
@@ -144,7 +144,7 @@ pub mod test_utils {
 
 			impl<T> TestInvertTrait<$crate::packed_binary_field::test_utils::Unit> for T {}
 
-			struct TestInvert<T>(std::marker::PhantomData<T>);
+			struct TestInvert<T>(core::marker::PhantomData<T>);
 
 			#[allow(unused)]
 			impl<T: $constraint + PackedField + $crate::underlier::WithUnderlier> TestInvert<T> {
@@ -178,7 +178,7 @@ pub mod test_utils {
 
 			impl<T> TestMulAlphaTrait<$crate::packed_binary_field::test_utils::Unit> for T {}
 
-			struct TestMulAlpha<T>(std::marker::PhantomData<T>);
+			struct TestMulAlpha<T>(core::marker::PhantomData<T>);
 
 			impl<T: $constraint + PackedField + $crate::underlier::WithUnderlier> TestMulAlpha<T>
 			where
@@ -209,7 +209,7 @@ pub mod test_utils {
 
 			impl<T> TestTransformationTrait<$crate::packed_binary_field::test_utils::Unit> for T {}
 
-			struct TestTransformation<T>(std::marker::PhantomData<T>);
+			struct TestTransformation<T>(core::marker::PhantomData<T>);
 
 			impl<T: $constraint + PackedField + $crate::underlier::WithUnderlier>
 				TestTransformation<T>
@@ -869,7 +869,7 @@ pub mod test_utils {
 
 #[cfg(test)]
 mod tests {
-	use std::{iter::repeat_with, ops::Mul, slice};
+	use core::{iter::repeat_with, ops::Mul, slice};
 
 	use proptest::prelude::*;
 	use rand::{SeedableRng, rngs::StdRng, thread_rng};
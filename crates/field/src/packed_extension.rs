@@ -46,14 +46,14 @@ pub fn is_packed_field_indexable<P: PackedField>() -> bool {
 
 	struct X<T> {
 		cloned: bool,
-		_pd: std::marker::PhantomData<T>,
+		_pd: core::marker::PhantomData<T>,
 	}
 
 	impl<T> Clone for X<T> {
 		fn clone(&self) -> Self {
 			Self {
 				cloned: true,
-				_pd: std::marker::PhantomData,
+				_pd: core::marker::PhantomData,
 			}
 		}
 	}
@@ -62,7 +62,7 @@ pub fn is_packed_field_indexable<P: PackedField>() -> bool {
 
 	let arr = [X::<P> {
 		cloned: false,
-		_pd: std::marker::PhantomData,
+		_pd: core::marker::PhantomData,
 	}];
 	let cloned = arr.clone();
 
@@ -77,7 +77,7 @@ pub fn unpack_if_possible<P: PackedField, R>(
 ) -> R {
 	if is_packed_field_indexable::<P>() {
 		let unpacked = unsafe {
-			std::slice::from_raw_parts(
+			core::slice::from_raw_parts(
 				slice.as_ptr() as *const P::Scalar,
 				slice.len() << P::LOG_WIDTH,
 			)
@@ -96,7 +96,7 @@ pub fn unpack_if_possible_mut<P: PackedField, R>(
 ) -> R {
 	if is_packed_field_indexable::<P>() {
 		let unpacked = unsafe {
-			std::slice::from_raw_parts_mut(
+			core::slice::from_raw_parts_mut(
 				slice.as_mut_ptr() as *mut P::Scalar,
 				slice.len() << P::LOG_WIDTH,
 			)
@@ -87,7 +87,7 @@ pub fn ext_base_op_par<PE, F, Func>(
 where
 	PE: PackedExtension<F>,
 	F: Field,
-	Func: Fn(usize, PE, PE::PackedSubfield) -> PE + std::marker::Sync,
+	Func: Fn(usize, PE, PE::PackedSubfield) -> PE + core::marker::Sync,
 {
 	if lhs.len() != rhs.len() * PE::Scalar::DEGREE {
 		return Err(Error::MismatchedLengths);
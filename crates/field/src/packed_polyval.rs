@@ -133,7 +133,7 @@ mod test_utils {
 
 #[cfg(test)]
 mod tests {
-	use std::ops::Mul;
+	use core::ops::Mul;
 
 	use proptest::{arbitrary::any, proptest};
 
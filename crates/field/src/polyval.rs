@@ -2,7 +2,7 @@
 
 //! Binary field implementation of GF(2^128) with a modulus of X^128 + X^127 + X^126 + 1.
 
-use std::{
+use core::{
 	any::TypeId,
 	fmt::{self, Debug, Display, Formatter},
 	iter::{Product, Sum},
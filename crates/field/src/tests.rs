@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::iter;
+use core::iter;
 
 use proptest::prelude::*;
 
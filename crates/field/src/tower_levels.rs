@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{
+use core::{
 	array,
 	ops::{Add, AddAssign, Index, IndexMut},
 };
@@ -1,8 +1,14 @@
 // Copyright 2023-2025 Irreducible Inc.
 
+use alloc::{
+	format,
+	string::{String, ToString},
+	vec,
+};
+
 use binius_utils::checked_arithmetics::log2_strict_usize;
 
-use super::packed::PackedField;
+use super::packed::{PackedField, get_packed_slice, set_packed_slice};
 
 /// Error thrown when a transpose operation fails.
 #[derive(Clone, thiserror::Error, Debug)]
@@ -68,10 +74,81 @@ pub fn square_transpose<P: PackedField>(log_n: usize, elems: &mut [P]) -> Result
 	Ok(())
 }
 
+/// Transpose a matrix of scalars stored in packed field elements.
+///
+/// The scalars of `src` are interpreted as a matrix with `2^log_rows` rows and `2^log_cols`
+/// columns in row-major order, and its transpose, with `2^log_cols` rows and `2^log_rows`
+/// columns, is written to `dst` in row-major order. When both dimensions are at least the packing
+/// width, the matrix is processed in square blocks of packed elements with [`square_transpose`],
+/// which uses the SIMD interleave instructions of the target; otherwise the scalars are moved one
+/// by one.
+///
+/// Over [`crate::BinaryField1b`], this converts between a column of `2^log_cols`-bit integers and
+/// its bit-sliced representation: row `i` of the input holds the bits of the `i`-th integer, and
+/// row `j` of the output holds the `j`-th bits of all integers.
+///
+/// # Arguments
+///
+/// * `src`: The packed elements of the input matrix, of length `2^(log_rows + log_cols)` scalars
+///   rounded up to a whole packed element.
+/// * `dst`: The packed elements of the output matrix, of the same length as `src`.
+pub fn transpose_scalars<P: PackedField>(
+	src: &[P],
+	dst: &mut [P],
+	log_rows: usize,
+	log_cols: usize,
+) -> Result<(), Error> {
+	let len = 1 << (log_rows + log_cols).saturating_sub(P::LOG_WIDTH);
+	if src.len() != len {
+		return Err(Error::InvalidBufferSize {
+			param: "src",
+			msg: format!("expected {len} packed elements, got {}", src.len()),
+		});
+	}
+	if dst.len() != len {
+		return Err(Error::InvalidBufferSize {
+			param: "dst",
+			msg: format!("expected {len} packed elements, got {}", dst.len()),
+		});
+	}
+
+	if log_rows < P::LOG_WIDTH || log_cols < P::LOG_WIDTH {
+		for i in 0..1 << log_rows {
+			for j in 0..1 << log_cols {
+				let scalar = get_packed_slice(src, (i << log_cols) | j);
+				set_packed_slice(dst, (j << log_rows) | i, scalar);
+			}
+		}
+		return Ok(());
+	}
+
+	let row_blocks = 1 << (log_rows - P::LOG_WIDTH);
+	let col_blocks = 1 << (log_cols - P::LOG_WIDTH);
+	let mut block = vec![P::default(); P::WIDTH];
+	for i in 0..row_blocks {
+		for j in 0..col_blocks {
+			for (k, elem) in block.iter_mut().enumerate() {
+				*elem = src[((i * P::WIDTH + k) * col_blocks) + j];
+			}
+			square_transpose(P::LOG_WIDTH, &mut block)?;
+			for (k, elem) in block.iter().enumerate() {
+				dst[((j * P::WIDTH + k) * row_blocks) + i] = *elem;
+			}
+		}
+	}
+
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+	use rand::{SeedableRng, rngs::StdRng};
+
 	use super::*;
-	use crate::{PackedBinaryField64x2b, PackedBinaryField128x1b};
+	use crate::{
+		PackedBinaryField4x32b, PackedBinaryField16x8b, PackedBinaryField64x2b,
+		PackedBinaryField128x1b,
+	};
 
 	#[test]
 	fn test_square_transpose_128x1b() {
@@ -154,4 +231,37 @@ mod tests {
 		.map(PackedBinaryField64x2b::from);
 		assert_eq!(elems, expected);
 	}
+
+	fn check_transpose_scalars<P: PackedField>(log_rows: usize, log_cols: usize) {
+		let mut rng = StdRng::seed_from_u64(0);
+		let len = 1 << (log_rows + log_cols).saturating_sub(P::LOG_WIDTH);
+		let src = (0..len).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+		let mut dst = vec![P::default(); len];
+		transpose_scalars(&src, &mut dst, log_rows, log_cols).unwrap();
+
+		for i in 0..1 << log_rows {
+			for j in 0..1 << log_cols {
+				assert_eq!(
+					get_packed_slice(&dst, (j << log_rows) | i),
+					get_packed_slice(&src, (i << log_cols) | j)
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_transpose_scalars() {
+		for (log_rows, log_cols) in [(0, 0), (2, 3), (7, 7), (9, 5), (5, 8), (8, 9)] {
+			check_transpose_scalars::<PackedBinaryField128x1b>(log_rows, log_cols);
+			check_transpose_scalars::<PackedBinaryField16x8b>(log_rows, log_cols);
+			check_transpose_scalars::<PackedBinaryField4x32b>(log_rows, log_cols);
+		}
+	}
+
+	#[test]
+	fn test_transpose_scalars_rejects_wrong_length() {
+		let src = [PackedBinaryField16x8b::default(); 2];
+		let mut dst = [PackedBinaryField16x8b::default(); 4];
+		assert!(transpose_scalars(&src, &mut dst, 3, 3).is_err());
+	}
 }
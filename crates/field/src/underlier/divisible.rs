@@ -1,8 +1,8 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use core::slice;
-use std::{
+use core::{
 	mem::{align_of, size_of},
+	slice,
 	slice::{from_raw_parts, from_raw_parts_mut},
 };
 
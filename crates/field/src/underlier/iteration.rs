@@ -1,6 +1,6 @@
 // Copyright 2025 Irreducible Inc.
 
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 use binius_utils::{checked_arithmetics::checked_int_div, iter::IterExtensions};
 
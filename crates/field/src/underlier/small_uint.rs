@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{
+use core::{
 	fmt::{Debug, Display, LowerHex},
 	hash::{Hash, Hasher},
 	ops::{Not, Shl, Shr},
@@ -76,19 +76,19 @@ impl<const N: usize> SmallU<N> {
 }
 
 impl<const N: usize> Debug for SmallU<N> {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		Debug::fmt(&self.val(), f)
 	}
 }
 
 impl<const N: usize> Display for SmallU<N> {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		Display::fmt(&self.val(), f)
 	}
 }
 
 impl<const N: usize> LowerHex for SmallU<N> {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		LowerHex::fmt(&self.0, f)
 	}
 }
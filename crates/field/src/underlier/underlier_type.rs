@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 use bytemuck::{NoUninit, Zeroable};
 use rand::{
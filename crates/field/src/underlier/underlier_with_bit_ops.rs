@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr};
 
 use binius_utils::checked_arithmetics::{checked_int_div, checked_log_2};
 
@@ -447,7 +447,7 @@ where
 	U: UnderlierWithBitOps + From<T>,
 	T: UnderlierType + NumCast<U>,
 {
-	std::array::from_fn(|i| unsafe { value.get_subvalue::<T>(block_idx * BLOCK_LEN + i) })
+	core::array::from_fn(|i| unsafe { value.get_subvalue::<T>(block_idx * BLOCK_LEN + i) })
 }
 
 /// A helper functions for implementing `UnderlierWithBitOps::spread_unchecked` for SIMD types.
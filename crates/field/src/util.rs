@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::iter;
+use alloc::vec::Vec;
+use core::iter;
 
 use binius_maybe_rayon::prelude::*;
 use binius_utils::checked_arithmetics::checked_int_div;
@@ -85,8 +86,158 @@ pub fn powers<F: Field>(val: F) -> impl Iterator<Item = F> {
 	iter::successors(Some(F::ONE), move |&power| Some(power * val))
 }
 
+/// Inverts every element of a slice in place using Montgomery's trick, mapping zero to zero.
+///
+/// Montgomery's trick replaces $n$ inversions with a single inversion, $4n$ multiplications and
+/// $n$ squarings. Zero lanes are replaced by one in the running product, which costs a lane-wise
+/// scan of every element in each direction, and are mapped back to zero by computing each inverse
+/// as $x \cdot x'^{-2}$, where $x'$ is $x$ with its zero lanes replaced. Packed elements are
+/// inverted lane-wise, so the multiplications use the SIMD arithmetic of the packed type. Large
+/// slices are split into chunks that are processed in parallel, each with its own inversion.
+pub fn batch_invert_or_zero<P: PackedField>(values: &mut [P]) {
+	// Chosen so that the single inversion per chunk is amortized over many multiplications.
+	const CHUNK_SIZE: usize = 1024;
+	if values.len() <= CHUNK_SIZE {
+		batch_invert_or_zero_sequential(values);
+	} else {
+		values
+			.par_chunks_mut(CHUNK_SIZE)
+			.for_each(batch_invert_or_zero_sequential);
+	}
+}
+
+/// Inverts every element of a slice using Montgomery's trick.
+///
+/// Returns `None` if any scalar in the slice is zero. See [`batch_invert_or_zero`].
+pub fn batch_invert<P: PackedField>(values: &[P]) -> Option<Vec<P>> {
+	if values
+		.iter()
+		.any(|value| value.iter().any(|scalar| scalar == P::Scalar::ZERO))
+	{
+		return None;
+	}
+	let mut inverses = values.to_vec();
+	batch_invert_or_zero(&mut inverses);
+	Some(inverses)
+}
+
+fn batch_invert_or_zero_sequential<P: PackedField>(values: &mut [P]) {
+	// Zero lanes are replaced by one so that they do not annihilate the running product.
+	let mut product = P::one();
+	let prefix_products = values
+		.iter()
+		.map(|&value| {
+			let prefix_product = product;
+			product *= replace_zeros_with_one(value);
+			prefix_product
+		})
+		.collect::<Vec<_>>();
+
+	let mut product_inv = product.invert_or_zero();
+	for (value, prefix_product) in iter::zip(values, prefix_products).rev() {
+		let nonzero_value = replace_zeros_with_one(*value);
+		let value_inv = product_inv * prefix_product;
+		product_inv *= nonzero_value;
+		// x * (x')^{-2} is the inverse of x in lanes where x is non-zero and zero elsewhere.
+		*value *= value_inv.square();
+	}
+}
+
+#[inline]
+fn replace_zeros_with_one<P: PackedField>(value: P) -> P {
+	if value.iter().all(|scalar| scalar != P::Scalar::ZERO) {
+		value
+	} else {
+		P::from_fn(|i| {
+			let scalar = value.get(i);
+			if scalar == P::Scalar::ZERO {
+				P::Scalar::ONE
+			} else {
+				scalar
+			}
+		})
+	}
+}
+
+/// Multiplies `lhs` and `rhs` element-wise into `out`.
+///
+/// On aarch64 CPUs with SVE2, slices of packed [`AESTowerField8b`](crate::AESTowerField8b) and, if
+/// the SVE2 AES extension is also available,
+/// [`BinaryField128bPolyval`](crate::BinaryField128bPolyval) elements are multiplied with the SVE2
+/// kernels of `arch::sve2`, which use the full vector length of the CPU. Other slices are
+/// multiplied with the packed field multiplication.
+///
+/// ## Preconditions
+///
+/// * `lhs`, `rhs` and `out` must have the same length
+pub fn mul_packed_slices<P: PackedField>(lhs: &[P], rhs: &[P], out: &mut [P]) {
+	assert_eq!(lhs.len(), rhs.len());
+	assert_eq!(lhs.len(), out.len());
+
+	#[cfg(target_arch = "aarch64")]
+	if crate::byte_iteration::is_sequential_bytes::<P>() {
+		use core::any::TypeId;
+
+		use crate::{
+			AESTowerField8b, BinaryField128bPolyval,
+			arch::{CpuFeatures, sve2},
+		};
+
+		// Without the instructions, the kernels multiply one scalar at a time, which is slower
+		// than the packed multiplication below.
+		let features = CpuFeatures::get();
+		match TypeId::of::<P::Scalar>() {
+			x if x == TypeId::of::<AESTowerField8b>() && features.sve2 => {
+				// Safety: the scalar type is checked by the match arm, and the packed elements
+				// with sequential bytes are laid out as their scalars.
+				let (lhs, rhs, out) = unsafe { cast_scalar_slices(lhs, rhs, out) };
+				sve2::mul_aes_8b(lhs, rhs, out);
+				return;
+			}
+			x if x == TypeId::of::<BinaryField128bPolyval>()
+				&& features.sve2
+				&& features.sve2_aes =>
+			{
+				// Safety: the scalar type is checked by the match arm, and the packed elements
+				// with sequential bytes are laid out as their scalars.
+				let (lhs, rhs, out) = unsafe { cast_scalar_slices(lhs, rhs, out) };
+				sve2::mul_polyval_128b(lhs, rhs, out);
+				return;
+			}
+			_ => {}
+		}
+	}
+
+	for ((out, &lhs), &rhs) in iter::zip(iter::zip(out, lhs), rhs) {
+		*out = lhs * rhs;
+	}
+}
+
+/// Reinterprets slices of packed elements as slices of their scalars.
+///
+/// # Safety
+/// `P` must be laid out in memory as `P::WIDTH` consecutive values of `F`.
+#[cfg(target_arch = "aarch64")]
+unsafe fn cast_scalar_slices<'a, P: PackedField, F>(
+	lhs: &'a [P],
+	rhs: &'a [P],
+	out: &'a mut [P],
+) -> (&'a [F], &'a [F], &'a mut [F]) {
+	unsafe {
+		(
+			core::slice::from_raw_parts(lhs.as_ptr() as *const F, lhs.len() * P::WIDTH),
+			core::slice::from_raw_parts(rhs.as_ptr() as *const F, rhs.len() * P::WIDTH),
+			core::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut F, out.len() * P::WIDTH),
+		)
+	}
+}
+
 #[cfg(test)]
 mod tests {
+	use core::iter::repeat_with;
+
+	use rand::{SeedableRng, rngs::StdRng};
+
 	use super::*;
 	use crate::PackedBinaryField4x32b;
 
@@ -160,6 +311,85 @@ mod tests {
 		assert_eq!(result, expected);
 	}
 
+	#[test]
+	fn test_batch_invert_or_zero_scalars() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let values = (0..100)
+			.map(|i| {
+				if i % 7 == 0 {
+					F::ZERO
+				} else {
+					<F as Field>::random(&mut rng)
+				}
+			})
+			.collect::<Vec<_>>();
+
+		let mut inverses = values.clone();
+		batch_invert_or_zero(&mut inverses);
+		for (value, inverse) in iter::zip(values, inverses) {
+			assert_eq!(inverse, value.invert_or_zero());
+		}
+	}
+
+	#[test]
+	fn test_batch_invert_or_zero_packed_parallel() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let values = (0..3000)
+			.map(|i| {
+				let mut value = P::random(&mut rng);
+				if i % 5 == 0 {
+					value.set(i % P::WIDTH, F::ZERO);
+				}
+				value
+			})
+			.collect::<Vec<_>>();
+
+		let mut inverses = values.clone();
+		batch_invert_or_zero(&mut inverses);
+		for (value, inverse) in iter::zip(values, inverses) {
+			assert_eq!(inverse, value.invert_or_zero());
+		}
+	}
+
+	#[test]
+	fn test_batch_invert() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut values = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(10)
+			.collect::<Vec<_>>();
+		let inverses = batch_invert(&values).unwrap();
+		for (value, inverse) in iter::zip(&values, inverses) {
+			assert_eq!(*value * inverse, F::ONE);
+		}
+
+		values[3] = F::ZERO;
+		assert!(batch_invert(&values).is_none());
+		assert_eq!(batch_invert::<F>(&[]), Some(vec![]));
+	}
+
+	#[test]
+	fn test_mul_packed_slices() {
+		fn check<P: PackedField>() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let lhs = repeat_with(|| P::random(&mut rng))
+				.take(33)
+				.collect::<Vec<_>>();
+			let rhs = repeat_with(|| P::random(&mut rng))
+				.take(33)
+				.collect::<Vec<_>>();
+			let mut out = vec![P::zero(); 33];
+			mul_packed_slices(&lhs, &rhs, &mut out);
+			for ((&out, &lhs), &rhs) in iter::zip(iter::zip(&out, &lhs), &rhs) {
+				assert_eq!(out, lhs * rhs);
+			}
+		}
+
+		check::<P>();
+		check::<crate::PackedAESBinaryField16x8b>();
+		check::<crate::PackedAESBinaryField64x8b>();
+		check::<crate::PackedBinaryPolyval2x128b>();
+	}
+
 	#[test]
 	fn test_inner_product_par_empty() {
 		// Case: Empty input should return 0
@@ -0,0 +1,112 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::fmt::Debug;
+
+use binius_field::{Field, PackedExtension, PackedField};
+use binius_math::{
+	CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly, MultilinearQueryRef,
+};
+
+use crate::{
+	ComputationBackend, CpuBackend, Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear,
+	make_portable_backend,
+};
+
+/// A backend for NVIDIA GPUs, which falls back to the portable [`CpuBackend`].
+///
+/// This is the skeleton of the CUDA backend: it is only compiled with the non-default `cuda`
+/// feature, and fixes the interface the device kernels will be added behind, so that provers can
+/// be written against it. The kernels for the tensor product expansion, the sumcheck round
+/// evaluation, the multilinear folding and the partial evaluation are not implemented yet, so
+/// every operation currently runs on the fallback backend, see [`Self::is_accelerated`].
+#[derive(Clone, Debug)]
+pub struct CudaBackend {
+	fallback: CpuBackend,
+}
+
+pub const fn make_cuda_backend() -> CudaBackend {
+	CudaBackend {
+		fallback: make_portable_backend(),
+	}
+}
+
+impl CudaBackend {
+	/// Whether the operations run on a CUDA device rather than on the fallback backend.
+	///
+	/// Always `false` until the device kernels are implemented.
+	pub const fn is_accelerated(&self) -> bool {
+		false
+	}
+}
+
+impl ComputationBackend for CudaBackend {
+	// Host memory, until the operations return device buffers.
+	type Vec<P: Send + Sync + Debug + 'static> = Vec<P>;
+
+	fn to_hal_slice<P: Debug + Send + Sync + 'static>(v: Vec<P>) -> Self::Vec<P> {
+		v
+	}
+
+	fn tensor_product_full_query<P: PackedField>(
+		&self,
+		query: &[P::Scalar],
+	) -> Result<Self::Vec<P>, Error> {
+		self.fallback.tensor_product_full_query(query)
+	}
+
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		evaluators: &[Evaluator],
+		nontrivial_evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+		Composition: CompositionPoly<P>,
+	{
+		self.fallback.sumcheck_compute_round_evals(
+			evaluation_order,
+			n_vars,
+			tensor_query,
+			multilinears,
+			evaluators,
+			nontrivial_evaluation_points,
+		)
+	}
+
+	fn sumcheck_fold_multilinears<P, M>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		multilinears: &mut [SumcheckMultilinear<P, M>],
+		challenge: P::Scalar,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+	) -> Result<bool, Error>
+	where
+		P: PackedField,
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		self.fallback.sumcheck_fold_multilinears(
+			evaluation_order,
+			n_vars,
+			multilinears,
+			challenge,
+			tensor_query,
+		)
+	}
+
+	fn evaluate_partial_high<P: PackedField>(
+		&self,
+		multilinear: &impl MultilinearPoly<P>,
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.fallback
+			.evaluate_partial_high(multilinear, query_expansion)
+	}
+}
@@ -6,11 +6,14 @@
 //! The HAL is consumed by the `binius_core` crate. The interfaces are currently designed around
 //! the architecture of [Irreducible's](https://www.irreducible.com) custom FPGA platform. The
 //! crate exposes a default, portable CPU backend that can be created with
-//! [`crate::make_portable_backend`].
+//! [`crate::make_portable_backend`]. The `cuda` feature adds the skeleton of a CUDA backend, which
+//! runs every operation on the portable backend until its device kernels are implemented.
 
 mod backend;
 mod common;
 mod cpu;
+#[cfg(feature = "cuda")]
+mod cuda;
 mod error;
 mod sumcheck_evaluator;
 mod sumcheck_folding;
@@ -19,6 +22,8 @@ mod sumcheck_round_calculation;
 
 pub use backend::*;
 pub use cpu::*;
+#[cfg(feature = "cuda")]
+pub use cuda::*;
 pub use error::*;
 pub use sumcheck_evaluator::*;
 pub use sumcheck_multilinear::*;
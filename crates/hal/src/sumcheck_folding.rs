@@ -2,7 +2,8 @@
 
 use binius_field::PackedField;
 use binius_math::{
-	EvaluationOrder, MultilinearPoly, MultilinearQueryRef, fold_left_lerp_inplace, fold_right_lerp,
+	EvaluationOrder, MultilinearPoly, MultilinearQueryRef, fold_left_lerp_inplace,
+	fold_right_lerp_inplace,
 };
 use binius_maybe_rayon::prelude::*;
 use binius_utils::checked_arithmetics::log2_ceil_usize;
@@ -115,31 +116,28 @@ where
 				suffix_eval,
 			} => {
 				// Post-switchover, we perform single variable folding (linear interpolation).
-				// NB: Lerp folding in low-to-high evaluation order can be made inplace, but not
-				// easily so if multithreading is desired.
-				let mut new_evals = zeroed_vec::<P>(evals.len().div_ceil(2));
+				// NB: The fold is performed in place, multithreading happens across multilinears.
+				let evals_len = evals.len();
 
-				fold_right_lerp(
-					evals.as_slice(),
+				fold_right_lerp_inplace(
+					evals,
 					// evals is optimally truncated, upper bound on non const scalars is quite
 					// tight
-					evals.len() * P::WIDTH,
+					evals_len * P::WIDTH,
 					challenge,
 					suffix_eval,
-					&mut new_evals,
 				)?;
 
 				// Pad up the result with suffix_eval
-				if !evals.is_empty() && evals.len() % 2 == 1 && P::LOG_WIDTH > 0 {
+				if evals_len % 2 == 1 && P::LOG_WIDTH > 0 {
 					for i in P::WIDTH >> 1..P::WIDTH {
-						new_evals
+						evals
 							.last_mut()
 							.expect("nonemptiness validated above")
 							.set(i, suffix_eval);
 					}
 				}
 
-				*evals = new_evals;
 				Ok(false)
 			}
 		}
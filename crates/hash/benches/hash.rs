@@ -3,8 +3,12 @@
 use std::{array, mem::MaybeUninit};
 
 use binius_hash::{
-	VisionHasherDigest, VisionHasherDigestByteSliced, groestl::Groestl256,
+	PseudoCompressionFunction, VisionHasherDigest, VisionHasherDigestByteSliced,
+	groestl::{
+		Groestl256, Groestl256ByteCompression, Groestl256ByteSliced, HASHES_PER_BYTE_SLICED_GROESTL,
+	},
 	multi_digest::MultiDigest,
+	sha2::Sha256Compression,
 };
 use criterion::{Criterion, Throughput, criterion_group, criterion_main};
 use digest::Digest;
@@ -26,6 +30,74 @@ fn bench_groestl(c: &mut Criterion) {
 	group.bench_function("Groestl256-RustCrypto", |bench| {
 		bench.iter(|| groestl_crypto::Groestl256::digest(data));
 	});
+	group.bench_function("Groestl256-Parallel64", |bench| {
+		const LEN: usize = N / HASHES_PER_BYTE_SLICED_GROESTL;
+		bench.iter(|| {
+			let mut out = [MaybeUninit::<digest::Output<Groestl256>>::uninit();
+				HASHES_PER_BYTE_SLICED_GROESTL];
+			Groestl256ByteSliced::digest(
+				array::from_fn(|i| &data[i * LEN..(i + 1) * LEN]),
+				&mut out,
+			);
+
+			out
+		})
+	});
+
+	group.finish()
+}
+
+fn bench_groestl_short(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Grøstl short inputs");
+
+	let mut rng = thread_rng();
+
+	let mut data = [0u8; 64];
+	rng.fill_bytes(&mut data);
+
+	group.throughput(Throughput::Bytes(data.len() as u64));
+	group.bench_function("Groestl256-64B", |bench| {
+		bench.iter(|| Groestl256::digest(data));
+	});
+	group.bench_function("Groestl256-64B-RustCrypto", |bench| {
+		bench.iter(|| groestl_crypto::Groestl256::digest(data));
+	});
+
+	let compression = Groestl256ByteCompression;
+	let input = [
+		*digest::Output::<Groestl256>::from_slice(&data[..32]),
+		*digest::Output::<Groestl256>::from_slice(&data[32..]),
+	];
+	group.bench_function("Groestl256ByteCompression", |bench| {
+		bench.iter(|| compression.compress(input));
+	});
+
+	group.finish()
+}
+
+fn bench_sha256(c: &mut Criterion) {
+	let mut group = c.benchmark_group("SHA-256");
+
+	let mut rng = thread_rng();
+
+	const N: usize = 1 << 16;
+	let mut data = [0u8; N];
+	rng.fill_bytes(&mut data);
+
+	group.throughput(Throughput::Bytes(N as u64));
+	group.bench_function("Sha256", |bench| {
+		bench.iter(|| sha2::Sha256::digest(data));
+	});
+
+	let compression = Sha256Compression::default();
+	let input = [
+		*digest::Output::<sha2::Sha256>::from_slice(&data[..32]),
+		*digest::Output::<sha2::Sha256>::from_slice(&data[32..64]),
+	];
+	group.throughput(Throughput::Bytes(64));
+	group.bench_function("Sha256Compression", |bench| {
+		bench.iter(|| compression.compress(input));
+	});
 
 	group.finish()
 }
@@ -57,5 +129,5 @@ fn bench_vision32(c: &mut Criterion) {
 	group.finish()
 }
 
-criterion_group!(hash, bench_groestl, bench_vision32);
+criterion_group!(hash, bench_groestl, bench_groestl_short, bench_sha256, bench_vision32);
 criterion_main!(hash);
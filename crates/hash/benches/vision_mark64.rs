@@ -0,0 +1,42 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{array, mem::MaybeUninit};
+
+use binius_hash::{
+	multi_digest::MultiDigest,
+	vision_mark64::{Vision64HasherDigest, Vision64HasherDigestByteSliced},
+};
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use digest::Digest;
+use rand::{RngCore, thread_rng};
+
+fn bench_vision64(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Vision Mark-64");
+
+	let mut rng = thread_rng();
+
+	const N: usize = 1 << 16;
+	let mut data = [0u8; N];
+	rng.fill_bytes(&mut data);
+
+	group.throughput(Throughput::Bytes(N as u64));
+	group
+		.bench_function("Vision-Single", |bench| bench.iter(|| Vision64HasherDigest::digest(data)));
+
+	group.bench_function("Vision-Parallel32", |bench| {
+		bench.iter(|| {
+			let mut out = [MaybeUninit::<digest::Output<Vision64HasherDigest>>::uninit(); 32];
+			Vision64HasherDigestByteSliced::digest(
+				array::from_fn(|i| &data[i * N / 32..(i + 1) * N / 32]),
+				&mut out,
+			);
+
+			out
+		})
+	});
+
+	group.finish()
+}
+
+criterion_group!(vision_mark64, bench_vision64);
+criterion_main!(vision_mark64);
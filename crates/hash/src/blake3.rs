@@ -0,0 +1,152 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The [BLAKE3] hash function and matching many-to-one compression functions.
+//!
+//! BLAKE3 is not efficient to arithmetize in a Binius constraint system, but it is much faster
+//! than Grøstl-256 on CPUs. It is a good choice for Merkle tree commitments when the verifier does
+//! not need to be recursively verified.
+//!
+//! [BLAKE3]: <https://github.com/BLAKE3-team/BLAKE3-specs>
+
+use std::{array, mem::MaybeUninit};
+
+use digest::Output;
+
+use crate::{CompressionFunction, PseudoCompressionFunction, multi_digest::MultiDigest};
+
+/// The BLAKE3 hash function with 256-bit output.
+pub type Blake3 = blake3::Hasher;
+
+/// An `N`-to-one compression function for BLAKE3 digests.
+///
+/// The compression is the keyed BLAKE3 hash of the concatenated inputs, where the key is derived
+/// from a fixed domain separation string. For two inputs the data is exactly one 64-byte block, so
+/// this is a single invocation of the BLAKE3 compression function. Up to 32 inputs fit in a
+/// single BLAKE3 chunk, which is what higher-arity Merkle trees use.
+#[derive(Debug, Clone)]
+pub struct Blake3DigestCompression {
+	key: [u8; 32],
+}
+
+impl Default for Blake3DigestCompression {
+	fn default() -> Self {
+		Self {
+			key: blake3::derive_key("BINIUS BLAKE3 COMPRESS", &[]),
+		}
+	}
+}
+
+impl<const N: usize> PseudoCompressionFunction<Output<Blake3>, N> for Blake3DigestCompression {
+	fn compress(&self, input: [Output<Blake3>; N]) -> Output<Blake3> {
+		let mut hasher = blake3::Hasher::new_keyed(&self.key);
+		for digest in &input {
+			hasher.update(digest.as_slice());
+		}
+		<[u8; 32]>::from(hasher.finalize()).into()
+	}
+}
+
+impl<const N: usize> CompressionFunction<Output<Blake3>, N> for Blake3DigestCompression {}
+
+/// The BLAKE3 compression for Merkle trees, named consistently with
+/// [`crate::groestl::Groestl256ByteCompression`].
+pub type Blake3ByteCompression = Blake3DigestCompression;
+
+/// Computes `N` BLAKE3 digests at once.
+///
+/// The `blake3` crate does not expose its internal multi-input SIMD kernel, so the lanes are
+/// hashed independently. This still lets BLAKE3 be used wherever a [`MultiDigest`] is expected,
+/// for example through [`crate::multi_digest::ParallelMulidigestImpl`].
+#[derive(Debug, Clone)]
+pub struct Blake3MultiDigest<const N: usize> {
+	hashers: [Blake3; N],
+}
+
+impl<const N: usize> Default for Blake3MultiDigest<N> {
+	fn default() -> Self {
+		Self {
+			hashers: array::from_fn(|_| Blake3::new()),
+		}
+	}
+}
+
+impl<const N: usize> MultiDigest<N> for Blake3MultiDigest<N> {
+	type Digest = Blake3;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: [&[u8]; N]) {
+		for (hasher, data) in self.hashers.iter_mut().zip(data) {
+			hasher.update(data);
+		}
+	}
+
+	fn finalize_into(self, out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		for (hasher, out) in self.hashers.iter().zip(out.iter_mut()) {
+			out.write(<[u8; 32]>::from(hasher.finalize()).into());
+		}
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		for (hasher, out) in self.hashers.iter_mut().zip(out.iter_mut()) {
+			out.write(<[u8; 32]>::from(hasher.finalize()).into());
+			hasher.reset();
+		}
+	}
+
+	fn reset(&mut self) {
+		for hasher in &mut self.hashers {
+			hasher.reset();
+		}
+	}
+
+	fn digest(data: [&[u8]; N], out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		for (data, out) in data.into_iter().zip(out.iter_mut()) {
+			out.write(<[u8; 32]>::from(blake3::hash(data)).into());
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use digest::Digest;
+
+	use super::*;
+
+	#[test]
+	fn test_blake3_digest_matches_reference() {
+		let message = b"the quick brown fox jumps over the lazy dog";
+		let expected = blake3::hash(message);
+		assert_eq!(Blake3::digest(message).as_slice(), expected.as_bytes());
+	}
+
+	#[test]
+	fn test_blake3_multi_digest_matches_single() {
+		let data: [&[u8]; 4] = [b"a", b"bc", b"def", b"ghij"];
+		let mut out = array::from_fn::<_, 4, _>(|_| MaybeUninit::uninit());
+		Blake3MultiDigest::<4>::new()
+			.chain_update(data)
+			.finalize_into(&mut out);
+
+		for (data, out) in data.into_iter().zip(out) {
+			assert_eq!(unsafe { out.assume_init() }, Blake3::digest(data));
+		}
+	}
+
+	#[test]
+	fn test_blake3_compression_is_keyed() {
+		let compression = Blake3DigestCompression::default();
+		let left = Blake3::digest(b"left");
+		let right = Blake3::digest(b"right");
+
+		let mut concatenated = [0u8; 64];
+		concatenated[..32].copy_from_slice(&left);
+		concatenated[32..].copy_from_slice(&right);
+
+		let output = compression.compress([left, right]);
+		assert_ne!(output.as_slice(), blake3::hash(&concatenated).as_bytes());
+		assert_ne!(output, compression.compress([right, left]));
+	}
+}
@@ -0,0 +1,54 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::arch::x86_64::*;
+
+use crate::groestl::byte_sliced::{ByteSlicedLanes, HASHES_PER_BYTE_SLICED_GROESTL};
+
+/// The bit matrix of the AES S-box affine transformation, in the layout expected by
+/// `gf2p8affineinv`.
+const AES_AFFINE_MATRIX: i64 = 0xF1E3C78F1F3E7CF8u64 as i64;
+const AES_AFFINE_CONSTANT: i32 = 0x63;
+
+/// Byte-sliced lanes in a single AVX-512 register. GFNI computes the S-box with one
+/// `gf2p8affineinv` and multiplications by `x` with one `gf2p8mul`.
+#[derive(Debug, Clone, Copy)]
+pub struct ByteSlicedLanesImpl(__m512i);
+
+impl ByteSlicedLanes for ByteSlicedLanesImpl {
+	#[inline]
+	fn from_bytes(bytes: &[u8; HASHES_PER_BYTE_SLICED_GROESTL]) -> Self {
+		Self(unsafe { _mm512_loadu_si512(bytes.as_ptr().cast()) })
+	}
+
+	#[inline]
+	fn to_bytes(self) -> [u8; HASHES_PER_BYTE_SLICED_GROESTL] {
+		let mut out = [0u8; HASHES_PER_BYTE_SLICED_GROESTL];
+		unsafe { _mm512_storeu_si512(out.as_mut_ptr().cast(), self.0) };
+		out
+	}
+
+	#[inline]
+	fn splat(byte: u8) -> Self {
+		Self(unsafe { _mm512_set1_epi8(byte as i8) })
+	}
+
+	#[inline]
+	fn xor(self, rhs: Self) -> Self {
+		Self(unsafe { _mm512_xor_si512(self.0, rhs.0) })
+	}
+
+	#[inline]
+	fn sub_bytes(self) -> Self {
+		Self(unsafe {
+			_mm512_gf2p8affineinv_epi64_epi8::<AES_AFFINE_CONSTANT>(
+				self.0,
+				_mm512_set1_epi64(AES_AFFINE_MATRIX),
+			)
+		})
+	}
+
+	#[inline]
+	fn mul_x(self) -> Self {
+		Self(unsafe { _mm512_gf2p8mul_epi8(self.0, _mm512_set1_epi8(0x02)) })
+	}
+}
@@ -0,0 +1,73 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::arch::aarch64::*;
+
+use crate::groestl::byte_sliced::{ByteSlicedLanes, HASHES_PER_BYTE_SLICED_GROESTL, SBOX};
+
+/// Byte-sliced lanes in four NEON registers. The S-box is evaluated with four 64-byte table
+/// lookups, relying on `tbl` returning zero for out-of-range indices.
+#[derive(Clone, Copy)]
+pub struct ByteSlicedLanesImpl(uint8x16x4_t);
+
+#[inline]
+fn map(lanes: uint8x16x4_t, f: impl Fn(uint8x16_t) -> uint8x16_t) -> uint8x16x4_t {
+	uint8x16x4_t(f(lanes.0), f(lanes.1), f(lanes.2), f(lanes.3))
+}
+
+#[inline]
+fn sub_bytes_x16(x: uint8x16_t) -> uint8x16_t {
+	unsafe {
+		let mut result = vdupq_n_u8(0);
+		for (i, table) in SBOX.chunks_exact(64).enumerate() {
+			let table = vld1q_u8_x4(table.as_ptr());
+			let index = vsubq_u8(x, vdupq_n_u8(64 * i as u8));
+			result = vorrq_u8(result, vqtbl4q_u8(table, index));
+		}
+		result
+	}
+}
+
+impl ByteSlicedLanes for ByteSlicedLanesImpl {
+	#[inline]
+	fn from_bytes(bytes: &[u8; HASHES_PER_BYTE_SLICED_GROESTL]) -> Self {
+		Self(unsafe { vld1q_u8_x4(bytes.as_ptr()) })
+	}
+
+	#[inline]
+	fn to_bytes(self) -> [u8; HASHES_PER_BYTE_SLICED_GROESTL] {
+		let mut out = [0u8; HASHES_PER_BYTE_SLICED_GROESTL];
+		unsafe { vst1q_u8_x4(out.as_mut_ptr(), self.0) };
+		out
+	}
+
+	#[inline]
+	fn splat(byte: u8) -> Self {
+		let x = unsafe { vdupq_n_u8(byte) };
+		Self(uint8x16x4_t(x, x, x, x))
+	}
+
+	#[inline]
+	fn xor(self, rhs: Self) -> Self {
+		unsafe {
+			Self(uint8x16x4_t(
+				veorq_u8(self.0.0, rhs.0.0),
+				veorq_u8(self.0.1, rhs.0.1),
+				veorq_u8(self.0.2, rhs.0.2),
+				veorq_u8(self.0.3, rhs.0.3),
+			))
+		}
+	}
+
+	#[inline]
+	fn sub_bytes(self) -> Self {
+		Self(map(self.0, sub_bytes_x16))
+	}
+
+	#[inline]
+	fn mul_x(self) -> Self {
+		Self(map(self.0, |x| unsafe {
+			let carry = vreinterpretq_u8_s8(vshrq_n_s8::<7>(vreinterpretq_s8_u8(x)));
+			veorq_u8(vshlq_n_u8::<1>(x), vandq_u8(carry, vdupq_n_u8(0x1B)))
+		}))
+	}
+}
@@ -0,0 +1,45 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::array;
+
+use crate::groestl::byte_sliced::{ByteSlicedLanes, HASHES_PER_BYTE_SLICED_GROESTL, SBOX};
+
+/// Portable byte-sliced lanes. The lane-wise loops are simple enough for the compiler to
+/// auto-vectorize everything except the S-box lookups.
+#[derive(Debug, Clone, Copy)]
+pub struct ByteSlicedLanesImpl([u8; HASHES_PER_BYTE_SLICED_GROESTL]);
+
+impl ByteSlicedLanes for ByteSlicedLanesImpl {
+	#[inline]
+	fn from_bytes(bytes: &[u8; HASHES_PER_BYTE_SLICED_GROESTL]) -> Self {
+		Self(*bytes)
+	}
+
+	#[inline]
+	fn to_bytes(self) -> [u8; HASHES_PER_BYTE_SLICED_GROESTL] {
+		self.0
+	}
+
+	#[inline]
+	fn splat(byte: u8) -> Self {
+		Self([byte; HASHES_PER_BYTE_SLICED_GROESTL])
+	}
+
+	#[inline]
+	fn xor(self, rhs: Self) -> Self {
+		Self(array::from_fn(|i| self.0[i] ^ rhs.0[i]))
+	}
+
+	#[inline]
+	fn sub_bytes(self) -> Self {
+		Self(self.0.map(|byte| SBOX[byte as usize]))
+	}
+
+	#[inline]
+	fn mul_x(self) -> Self {
+		Self(
+			self.0
+				.map(|byte| (byte << 1) ^ (((byte as i8) >> 7) as u8 & 0x1B)),
+		)
+	}
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::arch::CpuFeatures;
+
+use super::{
+	groestl_avx512::{
+		AlignedArray, combined_perm_m512i, load, perm_p_m512i, perm_q_m512i, store, xor_blocks,
+	},
+	portable,
+};
+use crate::groestl::GroestlShortInternal;
+
+/// An implementation of Grøstl256 that uses the AVX-512 permutations of
+/// [`super::groestl_avx512`] if the CPU supports them, and the portable implementation otherwise.
+///
+/// This implementation is used on x86_64 when the AVX-512 and GFNI target features are not enabled
+/// at compile time, so that binaries built for a baseline target still use the faster
+/// permutations when they are available. The implementation is selected once, when the state of
+/// a digest is created, and the state is kept in the representation of that implementation.
+#[derive(Clone, Default, Debug)]
+pub struct GroestlShortImpl;
+
+/// The state of [`GroestlShortImpl`], in the representation of the selected implementation.
+#[derive(Clone, Debug)]
+pub enum GroestlRuntimeState {
+	/// The state of the AVX-512 implementation, only created if the CPU supports it.
+	Avx512(AlignedArray),
+	Portable(<portable::GroestlShortImpl as GroestlShortInternal>::State),
+}
+
+fn has_avx512_gfni() -> bool {
+	let features = CpuFeatures::get();
+	features.avx512f && features.avx512bw && features.avx512vbmi && features.gfni
+}
+
+/// # Safety
+/// The CPU must support the AVX-512 F, BW and VBMI extensions and GFNI.
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi,gfni")]
+unsafe fn p_perm_avx512(h: &mut AlignedArray) {
+	store(perm_p_m512i(load(h)), h);
+}
+
+/// # Safety
+/// The CPU must support the AVX-512 F, BW and VBMI extensions and GFNI.
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi,gfni")]
+unsafe fn q_perm_avx512(h: &mut AlignedArray) {
+	store(perm_q_m512i(load(h)), h);
+}
+
+/// # Safety
+/// The CPU must support the AVX-512 F, BW and VBMI extensions and GFNI.
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi,gfni")]
+unsafe fn compress_avx512(h: &mut AlignedArray, m: &[u8; 64]) {
+	let h_block = load(h);
+	let q = load(&AlignedArray(*m));
+	let (p, q) = combined_perm_m512i(xor_blocks(h_block, q), q);
+	store(xor_blocks(h_block, xor_blocks(p, q)), h);
+}
+
+impl GroestlShortInternal for GroestlShortImpl {
+	type State = GroestlRuntimeState;
+
+	fn state_from_bytes(block: &[u8; 64]) -> Self::State {
+		if has_avx512_gfni() {
+			GroestlRuntimeState::Avx512(AlignedArray(*block))
+		} else {
+			GroestlRuntimeState::Portable(portable::GroestlShortImpl::state_from_bytes(block))
+		}
+	}
+
+	fn state_to_bytes(state: &Self::State) -> [u8; 64] {
+		match state {
+			GroestlRuntimeState::Avx512(state) => state.0,
+			GroestlRuntimeState::Portable(state) => {
+				portable::GroestlShortImpl::state_to_bytes(state)
+			}
+		}
+	}
+
+	fn xor_state(h: &mut Self::State, m: &Self::State) {
+		match (h, m) {
+			(GroestlRuntimeState::Avx512(h), GroestlRuntimeState::Avx512(m)) => {
+				for (h, m) in h.0.iter_mut().zip(m.0) {
+					*h ^= m;
+				}
+			}
+			(GroestlRuntimeState::Portable(h), GroestlRuntimeState::Portable(m)) => {
+				portable::GroestlShortImpl::xor_state(h, m);
+			}
+			_ => unreachable!(
+				"all the states are created with the implementation supported by the CPU"
+			),
+		}
+	}
+
+	fn p_perm(h: &mut Self::State) {
+		match h {
+			// Safety: the AVX-512 state is only created if the target features are detected.
+			GroestlRuntimeState::Avx512(h) => unsafe { p_perm_avx512(h) },
+			GroestlRuntimeState::Portable(h) => portable::GroestlShortImpl::p_perm(h),
+		}
+	}
+
+	fn q_perm(h: &mut Self::State) {
+		match h {
+			// Safety: the AVX-512 state is only created if the target features are detected.
+			GroestlRuntimeState::Avx512(h) => unsafe { q_perm_avx512(h) },
+			GroestlRuntimeState::Portable(h) => portable::GroestlShortImpl::q_perm(h),
+		}
+	}
+
+	fn compress(h: &mut Self::State, m: &[u8; 64]) {
+		match h {
+			// Safety: the AVX-512 state is only created if the target features are detected.
+			GroestlRuntimeState::Avx512(h) => unsafe { compress_avx512(h, m) },
+			GroestlRuntimeState::Portable(h) => portable::GroestlShortImpl::compress(h, m),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::*;
+
+	proptest! {
+		#[test]
+		fn test_runtime_dispatch_matches_portable(
+			h in prop::array::uniform32(any::<u8>()),
+			m in prop::array::uniform32(any::<u8>()),
+		) {
+			let mut block = [0u8; 64];
+			block[..32].copy_from_slice(&h);
+			block[32..].copy_from_slice(&m);
+
+			let mut state = GroestlShortImpl::state_from_bytes(&block);
+			GroestlShortImpl::compress(&mut state, &block);
+			GroestlShortImpl::p_perm(&mut state);
+			GroestlShortImpl::q_perm(&mut state);
+
+			let mut expected = portable::GroestlShortImpl::state_from_bytes(&block);
+			portable::GroestlShortImpl::compress(&mut expected, &block);
+			portable::GroestlShortImpl::p_perm(&mut expected);
+			portable::GroestlShortImpl::q_perm(&mut expected);
+
+			prop_assert_eq!(
+				GroestlShortImpl::state_to_bytes(&state),
+				portable::GroestlShortImpl::state_to_bytes(&expected)
+			);
+		}
+	}
+}
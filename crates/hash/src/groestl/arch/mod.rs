@@ -4,13 +4,33 @@ use cfg_if::cfg_if;
 
 // We will choose the AVX512 Implementation of Grøstl if our machine supports the various AVX512
 // extensions, otherwise defaults to the portable implementation which was found to be fast in most
-// machines
+// machines. On x86_64 without these extensions enabled at compile time, the AVX512 implementation
+// is selected at runtime if the CPU supports it.
 cfg_if! {
 	if #[cfg(all(feature = "nightly_features", target_arch = "x86_64",target_feature = "avx512bw",target_feature = "avx512vbmi",target_feature = "avx512f",target_feature = "gfni",))] {
 		mod groestl_avx512;
 		pub use groestl_avx512::GroestlShortImpl;
+	} else if #[cfg(all(feature = "nightly_features", target_arch = "x86_64"))] {
+		mod groestl_avx512;
+		mod groestl_runtime;
+		mod portable;
+		pub use groestl_runtime::GroestlShortImpl;
 	} else {
 		mod portable;
 		pub use portable::GroestlShortImpl;
 	}
 }
+
+// The byte-sliced lanes use the same AVX512 extensions, and NEON on aarch64.
+cfg_if! {
+	if #[cfg(all(feature = "nightly_features", target_arch = "x86_64",target_feature = "avx512bw",target_feature = "avx512vbmi",target_feature = "avx512f",target_feature = "gfni",))] {
+		mod byte_sliced_avx512;
+		pub use byte_sliced_avx512::ByteSlicedLanesImpl;
+	} else if #[cfg(all(target_arch = "aarch64", target_feature = "neon"))] {
+		mod byte_sliced_neon;
+		pub use byte_sliced_neon::ByteSlicedLanesImpl;
+	} else {
+		mod byte_sliced_portable;
+		pub use byte_sliced_portable::ByteSlicedLanesImpl;
+	}
+}
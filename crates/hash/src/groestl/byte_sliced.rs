@@ -0,0 +1,412 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Byte-sliced Grøstl-256 computing many independent digests at once.
+//!
+//! The state of every instance is an 8x8 matrix of bytes. In the byte-sliced layout, the `i`-th
+//! byte of all instances is stored in one [`ByteSlicedLanes`] value, so each step of the Grøstl
+//! round function is a handful of lane-wise operations:
+//!
+//! * AddRoundConstant is a XOR with a broadcast byte,
+//! * SubBytes is a lane-wise AES S-box,
+//! * ShiftBytes is a renaming of the state bytes and costs nothing,
+//! * MixBytes is a sequence of XORs and multiplications by `x` in $GF(2^8)$.
+
+use std::{array, mem::MaybeUninit};
+
+use digest::Output;
+
+use super::{Groestl256, arch::ByteSlicedLanesImpl};
+use crate::multi_digest::{MultiDigest, ParallelMulidigestImpl};
+
+/// The number of Grøstl-256 instances computed by [`Groestl256ByteSliced`].
+pub const HASHES_PER_BYTE_SLICED_GROESTL: usize = 64;
+
+const BLOCK_SIZE: usize = 64;
+const OUTPUT_SIZE: usize = 32;
+const ROUNDS: u8 = 10;
+
+/// Row shifts of the ShiftBytes step of the P permutation.
+const SHIFTS_P: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+/// Row shifts of the ShiftBytes step of the Q permutation.
+const SHIFTS_Q: [usize; 8] = [1, 3, 5, 7, 0, 2, 4, 6];
+
+/// The AES S-box, used by the table-based lane implementations.
+#[allow(dead_code)] // The AVX-512 lanes compute the S-box with GFNI instead.
+pub(super) const SBOX: [u8; 256] = aes_sbox();
+
+/// One byte of [`HASHES_PER_BYTE_SLICED_GROESTL`] independent Grøstl states.
+///
+/// Lane `i` belongs to the `i`-th hash instance. All arithmetic is in $GF(2^8)$ modulo the AES
+/// polynomial $x^8 + x^4 + x^3 + x + 1$.
+pub(super) trait ByteSlicedLanes: Copy + Send + Sync {
+	fn from_bytes(bytes: &[u8; HASHES_PER_BYTE_SLICED_GROESTL]) -> Self;
+
+	fn to_bytes(self) -> [u8; HASHES_PER_BYTE_SLICED_GROESTL];
+
+	fn splat(byte: u8) -> Self;
+
+	fn xor(self, rhs: Self) -> Self;
+
+	/// Applies the AES S-box to every lane.
+	fn sub_bytes(self) -> Self;
+
+	/// Multiplies every lane by `x`, i.e. `0x02`.
+	fn mul_x(self) -> Self;
+}
+
+type State = [ByteSlicedLanesImpl; BLOCK_SIZE];
+
+/// Computes [`HASHES_PER_BYTE_SLICED_GROESTL`] Grøstl-256 digests at once.
+///
+/// The digests are identical to [`Groestl256`]. Depending on the target, the lanes are processed
+/// with AVX-512 GFNI instructions, NEON table lookups, or portable code that the compiler can
+/// auto-vectorize.
+#[derive(Clone)]
+pub struct Groestl256ByteSliced {
+	state: State,
+	buffer: [[u8; BLOCK_SIZE]; HASHES_PER_BYTE_SLICED_GROESTL],
+	filled_bytes: usize,
+	blocks_len: u64,
+}
+
+impl Default for Groestl256ByteSliced {
+	fn default() -> Self {
+		Self {
+			state: initial_state(),
+			buffer: [[0; BLOCK_SIZE]; HASHES_PER_BYTE_SLICED_GROESTL],
+			filled_bytes: 0,
+			blocks_len: 0,
+		}
+	}
+}
+
+impl Groestl256ByteSliced {
+	fn compress_blocks(&mut self, blocks: [&[u8; BLOCK_SIZE]; HASHES_PER_BYTE_SLICED_GROESTL]) {
+		let m = transpose_blocks(blocks);
+		compress(&mut self.state, &m);
+		self.blocks_len += 1;
+	}
+
+	fn finalize(
+		&mut self,
+		out: &mut [MaybeUninit<Output<Groestl256>>; HASHES_PER_BYTE_SLICED_GROESTL],
+	) {
+		// Same padding as the single instance: a one bit, zeros, and the 64-bit big-endian number
+		// of blocks including the padding.
+		let filled_bytes = self.filled_bytes;
+		let blocks_len = if filled_bytes >= BLOCK_SIZE - 8 {
+			self.blocks_len + 2
+		} else {
+			self.blocks_len + 1
+		};
+
+		for block in &mut self.buffer {
+			block[filled_bytes] = 0x80;
+			block[filled_bytes + 1..].fill(0);
+		}
+		if filled_bytes >= BLOCK_SIZE - 8 {
+			let buffer = self.buffer;
+			self.compress_blocks(array::from_fn(|i| &buffer[i]));
+			for block in &mut self.buffer {
+				block.fill(0);
+			}
+		}
+		for block in &mut self.buffer {
+			block[BLOCK_SIZE - 8..].copy_from_slice(&blocks_len.to_be_bytes());
+		}
+		let buffer = self.buffer;
+		self.compress_blocks(array::from_fn(|i| &buffer[i]));
+
+		// Output transformation: truncate(P(h) ^ h)
+		let mut p = self.state;
+		permute_p(&mut p);
+
+		let output_bytes = array::from_fn::<_, OUTPUT_SIZE, _>(|i| {
+			let index = BLOCK_SIZE - OUTPUT_SIZE + i;
+			p[index].xor(self.state[index]).to_bytes()
+		});
+		for (instance, out) in out.iter_mut().enumerate() {
+			let mut digest = Output::<Groestl256>::default();
+			for (byte, output_bytes) in digest.iter_mut().zip(&output_bytes) {
+				*byte = output_bytes[instance];
+			}
+			out.write(digest);
+		}
+	}
+}
+
+impl MultiDigest<HASHES_PER_BYTE_SLICED_GROESTL> for Groestl256ByteSliced {
+	type Digest = Groestl256;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: [&[u8]; HASHES_PER_BYTE_SLICED_GROESTL]) {
+		for row in 1..HASHES_PER_BYTE_SLICED_GROESTL {
+			debug_assert_eq!(data[row].len(), data[0].len());
+		}
+
+		let len = data[0].len();
+		let mut offset = 0;
+
+		if self.filled_bytes > 0 {
+			let to_copy = std::cmp::min(len, BLOCK_SIZE - self.filled_bytes);
+			for (block, row) in self.buffer.iter_mut().zip(&data) {
+				block[self.filled_bytes..self.filled_bytes + to_copy]
+					.copy_from_slice(&row[..to_copy]);
+			}
+			self.filled_bytes += to_copy;
+			offset = to_copy;
+
+			if self.filled_bytes == BLOCK_SIZE {
+				let buffer = self.buffer;
+				self.compress_blocks(array::from_fn(|i| &buffer[i]));
+				self.filled_bytes = 0;
+			}
+		}
+
+		while offset + BLOCK_SIZE <= len {
+			let blocks = array::from_fn(|i| {
+				(&data[i][offset..offset + BLOCK_SIZE])
+					.try_into()
+					.expect("slice has block size")
+			});
+			self.compress_blocks(blocks);
+			offset += BLOCK_SIZE;
+		}
+
+		if offset < len {
+			for (block, row) in self.buffer.iter_mut().zip(&data) {
+				block[..len - offset].copy_from_slice(&row[offset..]);
+			}
+			self.filled_bytes = len - offset;
+		}
+	}
+
+	fn finalize_into(
+		mut self,
+		out: &mut [MaybeUninit<Output<Self::Digest>>; HASHES_PER_BYTE_SLICED_GROESTL],
+	) {
+		self.finalize(out);
+	}
+
+	fn finalize_into_reset(
+		&mut self,
+		out: &mut [MaybeUninit<Output<Self::Digest>>; HASHES_PER_BYTE_SLICED_GROESTL],
+	) {
+		self.finalize(out);
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		self.state = initial_state();
+		self.filled_bytes = 0;
+		self.blocks_len = 0;
+	}
+
+	fn digest(
+		data: [&[u8]; HASHES_PER_BYTE_SLICED_GROESTL],
+		out: &mut [MaybeUninit<Output<Self::Digest>>; HASHES_PER_BYTE_SLICED_GROESTL],
+	) {
+		let mut digest = Self::default();
+		digest.update(data);
+		digest.finalize_into(out);
+	}
+}
+
+/// A [`crate::multi_digest::ParallelDigest`] computing Grøstl-256 digests with
+/// [`Groestl256ByteSliced`].
+pub type Groestl256Parallel =
+	ParallelMulidigestImpl<Groestl256ByteSliced, HASHES_PER_BYTE_SLICED_GROESTL>;
+
+fn initial_state() -> State {
+	// The initial value encodes the output size in bits, 256, in the last bytes of the state.
+	let mut state = [ByteSlicedLanesImpl::splat(0); BLOCK_SIZE];
+	state[BLOCK_SIZE - 2] = ByteSlicedLanesImpl::splat(0x01);
+	state
+}
+
+fn transpose_blocks(blocks: [&[u8; BLOCK_SIZE]; HASHES_PER_BYTE_SLICED_GROESTL]) -> State {
+	array::from_fn(|byte_index| {
+		ByteSlicedLanesImpl::from_bytes(&array::from_fn(|instance| blocks[instance][byte_index]))
+	})
+}
+
+/// The compression function $f(h, m) = P(h \oplus m) \oplus Q(m) \oplus h$.
+fn compress(h: &mut State, m: &State) {
+	let mut p = array::from_fn(|i| h[i].xor(m[i]));
+	let mut q = *m;
+	permute_p(&mut p);
+	permute_q(&mut q);
+	for ((h, p), q) in h.iter_mut().zip(p).zip(q) {
+		*h = h.xor(p).xor(q);
+	}
+}
+
+// The state byte at index `8 * column + row` holds the matrix entry at (row, column), matching the
+// column-major byte order of the Grøstl specification.
+
+fn permute_p(state: &mut State) {
+	for round in 0..ROUNDS {
+		for column in 0..8 {
+			let index = 8 * column;
+			state[index] =
+				state[index].xor(ByteSlicedLanesImpl::splat(((column as u8) << 4) ^ round));
+		}
+		sub_shift_mix(state, &SHIFTS_P);
+	}
+}
+
+fn permute_q(state: &mut State) {
+	let all_ones = ByteSlicedLanesImpl::splat(0xFF);
+	for round in 0..ROUNDS {
+		for column in 0..8 {
+			for row in 0..7 {
+				let index = 8 * column + row;
+				state[index] = state[index].xor(all_ones);
+			}
+			let index = 8 * column + 7;
+			state[index] =
+				state[index].xor(ByteSlicedLanesImpl::splat(!((column as u8) << 4) ^ round));
+		}
+		sub_shift_mix(state, &SHIFTS_Q);
+	}
+}
+
+/// Applies SubBytes, ShiftBytes and MixBytes.
+fn sub_shift_mix(state: &mut State, shifts: &[usize; 8]) {
+	let substituted = state.map(ByteSlicedLanesImpl::sub_bytes);
+
+	for column in 0..8 {
+		let a: [ByteSlicedLanesImpl; 8] =
+			array::from_fn(|row| substituted[8 * ((column + shifts[row]) % 8) + row]);
+		let a2 = a.map(ByteSlicedLanesImpl::mul_x);
+		let a4 = a2.map(ByteSlicedLanesImpl::mul_x);
+
+		// MixBytes multiplies each column by the circulant matrix with the first row
+		// (02, 02, 03, 04, 05, 03, 05, 07).
+		for row in 0..8 {
+			let j = |offset: usize| (row + offset) % 8;
+			let mut acc = a2[j(0)].xor(a2[j(1)]);
+			acc = acc.xor(a2[j(2)]).xor(a[j(2)]);
+			acc = acc.xor(a4[j(3)]);
+			acc = acc.xor(a4[j(4)]).xor(a[j(4)]);
+			acc = acc.xor(a2[j(5)]).xor(a[j(5)]);
+			acc = acc.xor(a4[j(6)]).xor(a[j(6)]);
+			acc = acc.xor(a4[j(7)]).xor(a2[j(7)]).xor(a[j(7)]);
+			state[8 * column + row] = acc;
+		}
+	}
+}
+
+const fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+	let mut result = 0;
+	while b != 0 {
+		if b & 1 != 0 {
+			result ^= a;
+		}
+		let carry = a & 0x80;
+		a <<= 1;
+		if carry != 0 {
+			a ^= 0x1B;
+		}
+		b >>= 1;
+	}
+	result
+}
+
+const fn aes_sbox() -> [u8; 256] {
+	let mut sbox = [0u8; 256];
+	let mut x = 0;
+	while x < 256 {
+		// The multiplicative inverse is x^254, with 0 mapping to 0.
+		let mut inverse = 1u8;
+		let mut i = 0;
+		while i < 254 {
+			inverse = gf256_mul(inverse, x as u8);
+			i += 1;
+		}
+		if x == 0 {
+			inverse = 0;
+		}
+
+		let affine = inverse
+			^ inverse.rotate_left(1)
+			^ inverse.rotate_left(2)
+			^ inverse.rotate_left(3)
+			^ inverse.rotate_left(4)
+			^ 0x63;
+		sbox[x] = affine;
+		x += 1;
+	}
+	sbox
+}
+
+#[cfg(test)]
+mod tests {
+	use digest::Digest;
+	use rand::{RngCore, SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	fn check_matches_single_instance(len: usize) {
+		let mut rng = StdRng::seed_from_u64(len as u64);
+		let data = array::from_fn::<_, HASHES_PER_BYTE_SLICED_GROESTL, _>(|_| {
+			let mut bytes = vec![0u8; len];
+			rng.fill_bytes(&mut bytes);
+			bytes
+		});
+
+		let mut out = [MaybeUninit::uninit(); HASHES_PER_BYTE_SLICED_GROESTL];
+		Groestl256ByteSliced::digest(array::from_fn(|i| data[i].as_slice()), &mut out);
+
+		for (data, out) in data.iter().zip(out) {
+			assert_eq!(unsafe { out.assume_init() }, Groestl256::digest(data));
+		}
+	}
+
+	#[test]
+	fn test_sbox() {
+		assert_eq!(SBOX[0x00], 0x63);
+		assert_eq!(SBOX[0x01], 0x7C);
+		assert_eq!(SBOX[0x53], 0xED);
+		assert_eq!(SBOX[0xFF], 0x16);
+	}
+
+	#[test]
+	fn test_byte_sliced_matches_single_instance() {
+		for len in [0, 1, 55, 56, 63, 64, 65, 200] {
+			check_matches_single_instance(len);
+		}
+	}
+
+	#[test]
+	fn test_byte_sliced_chunked_updates() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let data = array::from_fn::<_, HASHES_PER_BYTE_SLICED_GROESTL, _>(|_| {
+			let mut bytes = vec![0u8; 150];
+			rng.fill_bytes(&mut bytes);
+			bytes
+		});
+
+		let mut hasher = Groestl256ByteSliced::new();
+		for range in [0..10, 10..70, 70..71, 71..150] {
+			hasher.update(array::from_fn(|i| &data[i][range.clone()]));
+		}
+
+		let mut out = [MaybeUninit::uninit(); HASHES_PER_BYTE_SLICED_GROESTL];
+		hasher.finalize_into_reset(&mut out);
+		for (data, out) in data.iter().zip(out) {
+			assert_eq!(unsafe { out.assume_init() }, Groestl256::digest(data));
+		}
+
+		// The hasher is reusable after a reset.
+		let mut out = [MaybeUninit::uninit(); HASHES_PER_BYTE_SLICED_GROESTL];
+		hasher.update(array::from_fn(|i| data[i].as_slice()));
+		hasher.finalize_into(&mut out);
+		for (data, out) in data.iter().zip(out) {
+			assert_eq!(unsafe { out.assume_init() }, Groestl256::digest(data));
+		}
+	}
+}
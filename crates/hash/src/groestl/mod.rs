@@ -1,6 +1,7 @@
 // Copyright 2025 Irreducible Inc.
 
 mod arch;
+mod byte_sliced;
 mod compression;
 mod digest;
 #[cfg(test)]
@@ -35,5 +36,6 @@ pub trait GroestlShortInternal {
 }
 
 pub use arch::GroestlShortImpl;
+pub use byte_sliced::{Groestl256ByteSliced, Groestl256Parallel, HASHES_PER_BYTE_SLICED_GROESTL};
 pub use compression::*;
 pub use digest::Groestl256;
@@ -0,0 +1,62 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The Keccak-256 hash function, as used by Ethereum.
+//!
+//! Keccak-256 is the only hash function that is cheap to evaluate in the EVM. Instantiating the
+//! Merkle tree commitments and the Fiat-Shamir transcript with it allows proofs to be verified by
+//! smart contracts.
+
+use digest::{Digest, Output};
+
+use crate::{CompressionFunction, PseudoCompressionFunction};
+
+/// The Keccak-256 hash function with the original Keccak padding, not SHA3-256.
+pub type Keccak256 = sha3::Keccak256;
+
+/// A two-to-one compression function for Keccak-256 digests.
+///
+/// The compression is the Keccak-256 hash of the concatenated inputs, which matches
+/// `keccak256(abi.encodePacked(left, right))` in Solidity.
+#[derive(Debug, Default, Clone)]
+pub struct Keccak256ByteCompression;
+
+impl PseudoCompressionFunction<Output<Keccak256>, 2> for Keccak256ByteCompression {
+	fn compress(&self, input: [Output<Keccak256>; 2]) -> Output<Keccak256> {
+		Keccak256::new()
+			.chain_update(input[0])
+			.chain_update(input[1])
+			.finalize()
+	}
+}
+
+impl CompressionFunction<Output<Keccak256>, 2> for Keccak256ByteCompression {}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	use super::*;
+
+	#[test]
+	fn test_keccak256_empty() {
+		assert_eq!(
+			Keccak256::digest(b"")[..],
+			hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+		);
+	}
+
+	#[test]
+	fn test_keccak256_compression_matches_concatenation() {
+		let left = Keccak256::digest(b"left");
+		let right = Keccak256::digest(b"right");
+
+		let mut concatenated = [0u8; 64];
+		concatenated[..32].copy_from_slice(&left);
+		concatenated[32..].copy_from_slice(&right);
+
+		assert_eq!(
+			Keccak256ByteCompression.compress([left, right]),
+			Keccak256::digest(concatenated)
+		);
+	}
+}
@@ -0,0 +1,85 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Known-answer tests for the hash functions in this crate.
+//!
+//! The vectors in `test_vectors/` are checked against the scalar digests and against the
+//! byte-sliced multi-digests, so that every backend selected for the target architecture is
+//! covered by the same reference values.
+
+use std::{array, mem::MaybeUninit};
+
+use binius_utils::test_vectors::{KnownAnswer, parse_known_answers};
+use digest::Digest;
+use sha2::Sha256;
+
+use crate::{
+	VisionHasherDigest, VisionHasherDigestByteSliced,
+	groestl::{Groestl256, Groestl256ByteSliced, HASHES_PER_BYTE_SLICED_GROESTL},
+	multi_digest::MultiDigest,
+	vision::permutation::HASHES_PER_BYTE_SLICED_PERMUTATION,
+};
+
+const GROESTL256_VECTORS: &str = include_str!("../test_vectors/groestl256.rsp");
+const SHA256_VECTORS: &str = include_str!("../test_vectors/sha256.rsp");
+const VISION32_VECTORS: &str = include_str!("../test_vectors/vision32.rsp");
+
+fn load(vectors: &str) -> Vec<KnownAnswer> {
+	let records = parse_known_answers(vectors).expect("test vectors are well-formed");
+	assert!(!records.is_empty());
+	records
+}
+
+/// Checks `D` on every vector, both in one update and in several updates that are not aligned to
+/// the block size.
+fn check_digest<D: Digest>(vectors: &str) {
+	for record in load(vectors) {
+		let (msg, expected) = (record.field("Msg"), record.field("MD"));
+
+		assert_eq!(&D::digest(msg)[..], expected, "vector at line {}", record.line);
+
+		let mut hasher = D::new();
+		for chunk in msg.chunks(17) {
+			hasher.update(chunk);
+		}
+		assert_eq!(&hasher.finalize()[..], expected, "vector at line {}", record.line);
+	}
+}
+
+/// Checks every lane of `M` on every vector, feeding each message in two updates.
+fn check_multi_digest<const N: usize, M: MultiDigest<N>>(vectors: &str) {
+	for record in load(vectors) {
+		let (msg, expected) = (record.field("Msg"), record.field("MD"));
+		let (head, tail) = msg.split_at(msg.len() / 2);
+
+		let mut hasher = M::new();
+		hasher.update([head; N]);
+		hasher.update([tail; N]);
+		let mut out = array::from_fn(|_| MaybeUninit::uninit());
+		hasher.finalize_into(&mut out);
+
+		for (lane, digest) in out.into_iter().enumerate() {
+			// SAFETY: `finalize_into` initializes all outputs.
+			let digest = unsafe { digest.assume_init() };
+			assert_eq!(&digest[..], expected, "vector at line {}, lane {lane}", record.line);
+		}
+	}
+}
+
+#[test]
+fn test_groestl256_known_answers() {
+	check_digest::<Groestl256>(GROESTL256_VECTORS);
+	check_multi_digest::<HASHES_PER_BYTE_SLICED_GROESTL, Groestl256ByteSliced>(GROESTL256_VECTORS);
+}
+
+#[test]
+fn test_sha256_known_answers() {
+	check_digest::<Sha256>(SHA256_VECTORS);
+}
+
+#[test]
+fn test_vision32_known_answers() {
+	check_digest::<VisionHasherDigest>(VISION32_VECTORS);
+	check_multi_digest::<HASHES_PER_BYTE_SLICED_PERMUTATION, VisionHasherDigestByteSliced>(
+		VISION32_VECTORS,
+	);
+}
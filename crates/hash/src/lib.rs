@@ -10,18 +10,29 @@
 //! This crate also provides an implementation of [Vision Mark-32], a cryptographic sponge function
 //! designed for efficient Binius arithmetization.
 //!
+//! For settings where the verifier does not need to be arithmetized, [BLAKE3] is provided as a
+//! fast alternative for Merkle tree commitments and Fiat-Shamir. For verifiers arithmetized in a
+//! SNARK over BN254, [Poseidon2] over the BN254 scalar field is provided as a Fiat-Shamir sponge.
+//!
 //! [Grøstl-256]: <https://www.groestl.info/>
 //! [Vision Mark-32]: <https://eprint.iacr.org/2024/633>
+//! [Poseidon2]: <https://eprint.iacr.org/2023/323>
+//! [BLAKE3]: <https://github.com/BLAKE3-team/BLAKE3-specs>
 
 #![cfg_attr(
 	all(target_arch = "x86_64", feature = "nightly_features"),
 	feature(stdarch_x86_avx512)
 )]
 
+pub mod blake3;
 pub mod compression;
 pub mod groestl;
+pub mod keccak;
+#[cfg(test)]
+mod known_answer_tests;
 pub mod multi_digest;
 pub mod permutation;
+pub mod poseidon2;
 mod serialization;
 pub mod sha2;
 mod vision;
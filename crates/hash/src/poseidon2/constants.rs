@@ -0,0 +1,510 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Round constants of Poseidon2 over the BN254 scalar field with width 3.
+//!
+//! The constants are generated with the Grain LFSR of the Poseidon2 reference implementation for
+//! the parameters $t = 3$, $R_F = 8$, $R_P = 56$, and are stored in Montgomery form.
+
+use super::{N_EXTERNAL_ROUNDS, N_INTERNAL_ROUNDS, WIDTH};
+
+pub(super) const EXTERNAL_ROUND_CONSTANTS: [[[u64; 4]; WIDTH]; N_EXTERNAL_ROUNDS] = [
+	[
+		[
+			0xd722b5d4ce14484c,
+			0x28fdf04ef431d35f,
+			0x0af406c6d8a909c9,
+			0x2d545ba8d234efa0,
+		],
+		[
+			0x55a2a5fc348a5584,
+			0xd73cd95929e45265,
+			0x66e861c483269614,
+			0x040e6777b2b473e0,
+		],
+		[
+			0x0a830ec1f8020b2a,
+			0x8197a4f899305e94,
+			0x3c2e69437ac4428f,
+			0x17eceef02f845328,
+		],
+	],
+	[
+		[
+			0xa51d3e5bc97d6f8a,
+			0x054ffe26dc378def,
+			0xd8440cef8abe9c97,
+			0x005cbb47536245d6,
+		],
+		[
+			0x042cdbb0e05e41ad,
+			0xc2bbc7d386048513,
+			0x24521f75749e5203,
+			0x2b353c0d0eb46678,
+		],
+		[
+			0x452e08a370279600,
+			0x8a72c098f325f46c,
+			0x6c50ae68b9aa1466,
+			0x0485044559c97364,
+		],
+	],
+	[
+		[
+			0x0ad536bde225df77,
+			0x618246ab1b44ea62,
+			0x9e92bf71d6d89f5f,
+			0x143c32bc7134fbff,
+		],
+		[
+			0xb88574faafd66bd9,
+			0x009eff80fe2d1c84,
+			0xd8e71adb450d4afa,
+			0x2eda25b9568f5057,
+		],
+		[
+			0x25725962246fe49e,
+			0xed9f756a0d2ac3d7,
+			0x3ae3c2d34ac9abe4,
+			0x0190cdb5e0b6b88c,
+		],
+	],
+	[
+		[
+			0xe7adc99750fd3fe6,
+			0xb747a57693fc3913,
+			0x249a1e43051ca986,
+			0x1307eb2ed23a561b,
+		],
+		[
+			0x9615931ff79536d3,
+			0x24525c947a923c33,
+			0x467ae014f2988ff5,
+			0x2c023f4762b95029,
+		],
+		[
+			0x92d843f6e7c528e0,
+			0x0cc5a90ee754e747,
+			0x191e21b2f55f9236,
+			0x28f936f9d4331f4b,
+		],
+	],
+	[
+		[
+			0x8e8db2dcae74b354,
+			0x18d52d0c368291c9,
+			0x5ec6273241ab4b08,
+			0x1284ed5f098eabd3,
+		],
+		[
+			0x1fbe709efc6734e0,
+			0x84a4299630bc8815,
+			0x9bf5e155b0e2c32e,
+			0x08e211c4f350b3ef,
+		],
+		[
+			0x9423ffa0b7c9731a,
+			0xa70300acead2eb93,
+			0x1526253741d22c44,
+			0x2fae94ed023f12e0,
+		],
+	],
+	[
+		[
+			0x4b5ff60265185ab2,
+			0xbea20e3f0d9953e3,
+			0x0dfcbf8f224890c4,
+			0x14bccf4609c0effd,
+		],
+		[
+			0xe4cab4612da2e433,
+			0x11e05277a8d64ce1,
+			0xbbcaa97bc7ed715e,
+			0x0ecfcc925bca9fc1,
+		],
+		[
+			0xdfb8951370941941,
+			0x960042e3ce168be1,
+			0x6d54135d6cabdbbb,
+			0x0221b16be464a69a,
+		],
+	],
+	[
+		[
+			0x3918cf0b20a0f689,
+			0xecd0d188622af6db,
+			0x4e1b574c8a26e246,
+			0x2d85fbe5a0d6fa59,
+		],
+		[
+			0x0fc72998c5b60d86,
+			0x2a5351528fdc2a51,
+			0x7adec22b4eabd232,
+			0x0a08535ef111a956,
+		],
+		[
+			0x0df1d9e2ca492b78,
+			0x4a01db83a8d99ad4,
+			0xeb2bcb74cbf1383d,
+			0x2e9984824b7a3011,
+		],
+	],
+	[
+		[
+			0x267cc7a6c20b3c54,
+			0x619ad1e0d0e2fdca,
+			0x9668fc44cfe99da2,
+			0x15f960d8396bc175,
+		],
+		[
+			0x17e87a80f8b105ca,
+			0x666e49d9fa2042ea,
+			0xc40330f54734c297,
+			0x2a656ded4524e0dc,
+		],
+		[
+			0x1a8a41ea3fa848fd,
+			0x68c01b2742aea9aa,
+			0xc5ff17a405f7acad,
+			0x05932f8c2731e92a,
+		],
+	],
+];
+
+pub(super) const INTERNAL_ROUND_CONSTANTS: [[u64; 4]; N_INTERNAL_ROUNDS] = [
+	[
+		0x622adaa22bc0a5cf,
+		0xd1c5d8543b6eaf8a,
+		0x330f3ecad0f62457,
+		0x1007cfff156de150,
+	],
+	[
+		0x7fcd7a7b51a3a11a,
+		0xf3effc9d64057c75,
+		0xbc8a4ec31300cb0d,
+		0x30322f4f7dcad9a8,
+	],
+	[
+		0x475cd06622b141d5,
+		0x5d64d8cf28285fae,
+		0x6e095ffe4d284a15,
+		0x2643235c9b4cd1c2,
+	],
+	[
+		0x7c0b0359a212daf5,
+		0xd75ada0d5af14f5d,
+		0xa4345628061ee257,
+		0x17b7c8b84e8ae776,
+	],
+	[
+		0x980c58b1742b36f2,
+		0xa565f2cadfe11248,
+		0x67ef3bea07c3028c,
+		0x2c28234902b5fb1a,
+	],
+	[
+		0xbde1186c2d096ab6,
+		0xe1703272efe9a103,
+		0xbc6f3904ba8072b1,
+		0x29302878a3f0234c,
+	],
+	[
+		0x3ee7ba77b4128e99,
+		0xc51c0395c6a895f6,
+		0xccbf96f2605cbe98,
+		0x1e22e2745ca3afca,
+	],
+	[
+		0x1d3ad6691c835661,
+		0xfb5f95a2a21fab6c,
+		0xc1659970e72c8dce,
+		0x295474529436cfb0,
+	],
+	[
+		0x23328b4257f54073,
+		0x204e4f688af8e279,
+		0xa956bf6fe8162f7b,
+		0x2f5d17dee06b555a,
+	],
+	[
+		0x4a3ada744a172d09,
+		0x0c507c89be5ad794,
+		0x7c7f8bd5c89c352c,
+		0x1d2576a41f2c7644,
+	],
+	[
+		0x9bd727e302eeb228,
+		0xdf6d767c8b1ea1f2,
+		0xca141180f54307d2,
+		0x2505c0d6236cad38,
+	],
+	[
+		0xb1e10961954c97c6,
+		0x3c4f0682d3f9fef2,
+		0x0c80d2cf71008c30,
+		0x0e15691f05a18dcd,
+	],
+	[
+		0xeffe6ebd635f9952,
+		0xeb59bc51e7671c93,
+		0x96411255e253cbde,
+		0x12c2c08937fe27c9,
+	],
+	[
+		0xb874c9413a1317c4,
+		0x16e2f4333112a9a7,
+		0xc706227f94375e0a,
+		0x17541af3ee77ed31,
+	],
+	[
+		0x1c8578d3964189af,
+		0x9606117dd33043f7,
+		0x47321da7576a465f,
+		0x1d221ece05653649,
+	],
+	[
+		0x4fe812537544679e,
+		0x22c277f99fab065d,
+		0x559c1f97037b2a59,
+		0x0d335d8cb64c1dee,
+	],
+	[
+		0xfca74a7911772ad0,
+		0x7794ea0b1ae81a44,
+		0x8599b6205c8410db,
+		0x14a9bc1904fdb4e8,
+	],
+	[
+		0x27c775f5459458c7,
+		0x8328d2c0eb439f06,
+		0x40673f0d734180d6,
+		0x15277115e92859b9,
+	],
+	[
+		0xaf861af4a830e3cb,
+		0x9dac43c8663d4a43,
+		0x667d238c169c8dad,
+		0x1fd335e93980c88f,
+	],
+	[
+		0x76cd7452dabee7be,
+		0x179f9669b3470c47,
+		0xa2bcc37a7e3e4c39,
+		0x00c438f3ae0da84f,
+	],
+	[
+		0xaddee77e65896478,
+		0xf76501d71142693b,
+		0xa2bcea588c00e94a,
+		0x01722a620d1ff415,
+	],
+	[
+		0x52e1361a9c23e520,
+		0x4da1bc258d71d221,
+		0x4833163a6bd5021f,
+		0x0454fd58808e54b0,
+	],
+	[
+		0xa34eec98af509057,
+		0x2e045fe456fc3d06,
+		0x90bb41281158c2a0,
+		0x0f8cf45c26351255,
+	],
+	[
+		0x243306b131dcc1c4,
+		0xd17d829d08381589,
+		0xe0c512c5e114798e,
+		0x003d28d510b59fb3,
+	],
+	[
+		0x25b75c234f6444fb,
+		0xeb353c51fae5b32d,
+		0x00fb023df1f10957,
+		0x2f2711c2a9795a71,
+	],
+	[
+		0xffe40ffd76a3c33c,
+		0x901a3707506b5ac6,
+		0xae56f79fb1e48843,
+		0x12c0e58cb67b0272,
+	],
+	[
+		0x6309182356f1402d,
+		0xb3f95ba486d54d35,
+		0x5a2ddb75a5857b1a,
+		0x28fe9993cc4197a9,
+	],
+	[
+		0xb0c450f4a3f6b5b0,
+		0xfb32607c6a8264d2,
+		0xcf4f33c877693cd9,
+		0x1688e51814e723b6,
+	],
+	[
+		0x8ad5b2a903c59de3,
+		0x7d1fd0b54c75b7ed,
+		0x7b4a673a13715eb5,
+		0x135ae381c9abc9ef,
+	],
+	[
+		0x985df3dd87e82218,
+		0x8a8f90a81c7b6afc,
+		0x4e5335ed59689ddb,
+		0x0d57011fefb878b7,
+	],
+	[
+		0xbd085218ff45c733,
+		0xc45006a6febbde0c,
+		0x4dd8cc03d08175e9,
+		0x01489fd5585e3a76,
+	],
+	[
+		0xc298e7cec2adf0e4,
+		0xaff1e5dc96d4e0aa,
+		0xf813062aed3650d3,
+		0x141502464af64218,
+	],
+	[
+		0x2a225f3b96eac478,
+		0xe2c4637710a0c288,
+		0x334a38b2af702e8d,
+		0x078cff44e2702e40,
+	],
+	[
+		0x160dc4b8ecd11192,
+		0xbfd46405af580450,
+		0xc859ded4e1574ad2,
+		0x0e24f08edbbdf10e,
+	],
+	[
+		0x91e59108bab52fbd,
+		0x9253af9dec62a826,
+		0x7e946b84d0b98ef8,
+		0x2fbb7b1ab2e483ea,
+	],
+	[
+		0xac92d3aa83eda039,
+		0xa46909098e1b86ad,
+		0x4ffd74303bc0dd1a,
+		0x023ca89463c8a8f3,
+	],
+	[
+		0x3fd205fcec8cac00,
+		0x4eb6b7a0e62b9259,
+		0x7a2aeaf511dc0089,
+		0x0c3bec0390e63b2c,
+	],
+	[
+		0xaf9390f4c48910dd,
+		0x5d5890c5a83287eb,
+		0x72c8b90e7fb055f1,
+		0x1841ff432dea9c62,
+	],
+	[
+		0x69faa71e59bf0bb5,
+		0x5bc0f299809df839,
+		0x270e1a316c468e1c,
+		0x29a11242d6147416,
+	],
+	[
+		0x8972b665b65fdc48,
+		0x5c9c56f94edd199f,
+		0x7655c00b06c983ae,
+		0x0b0f3ab31e51c321,
+	],
+	[
+		0xa060ea214656c733,
+		0x3e03eaf6fcfc3711,
+		0x1283ed55768e5a4e,
+		0x1d52067c7b3ed73b,
+	],
+	[
+		0xd3e688c7184334a4,
+		0x3300ee2a8b440d8e,
+		0x09cb5cabeb30bc1a,
+		0x10b468936b68a8c9,
+	],
+	[
+		0xd905c6a45919c64d,
+		0x42c29960a1ecb91d,
+		0xdb15563a04a42f1e,
+		0x222da53fd2ff55be,
+	],
+	[
+		0x795621e7baf9856a,
+		0xbd06eab74a37377f,
+		0xd69262b4482dfab7,
+		0x06336772940098e7,
+	],
+	[
+		0xb15532dc0bbc434a,
+		0x935ec462c1914f69,
+		0xd263edc8605fe849,
+		0x201a1b25dcce9527,
+	],
+	[
+		0x7f9233db7d37cc55,
+		0x39e290933fa1e7cb,
+		0x4b7f45bfcb35976a,
+		0x095f02ff83f0c587,
+	],
+	[
+		0xf4eb6a6839d344c8,
+		0x61d3173fcc408889,
+		0x358b1b65754bc487,
+		0x041a46d8f8d020da,
+	],
+	[
+		0x76955850eea956d1,
+		0x329bb5c33f7ce928,
+		0xb89bbc088b21b882,
+		0x1d8400cddbf59315,
+	],
+	[
+		0xe7afa14d64087d30,
+		0x7e1d1f05521f9766,
+		0xda7886ca0435f6c2,
+		0x162043960c90400d,
+	],
+	[
+		0x2406277f2efd539c,
+		0x7619c402bf80b5ee,
+		0x8df98925b1e02b30,
+		0x29aa9e804f8ba893,
+	],
+	[
+		0x61637e664dda1e31,
+		0x451c6e5727eb3f1d,
+		0x33a1432b3c6bc83c,
+		0x12c1abfc08d0e74f,
+	],
+	[
+		0xcc07040ff65773dd,
+		0x4c04f1390c2ec344,
+		0xe7e912e6ca1e8523,
+		0x0d8eaffd17a6be33,
+	],
+	[
+		0xde8160aa9f9fc719,
+		0x78c44db32a8900c9,
+		0x60a764e3354fdd17,
+		0x1c20317d24a81952,
+	],
+	[
+		0x27a34e474a38f228,
+		0xdc1f7f9c49138197,
+		0x6a7345260fcc6063,
+		0x0fd1c6328c7ed4d2,
+	],
+	[
+		0x1a23634f913af446,
+		0x089c939a6ff10352,
+		0x7ba874ea0250b0fe,
+		0x081b59ca4ea4de4a,
+	],
+	[
+		0xf58f374a9ce9ff43,
+		0xfef4b21e9aab157d,
+		0x6cb30b885302aee7,
+		0x25685b31c9911102,
+	],
+];
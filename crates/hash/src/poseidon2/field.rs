@@ -0,0 +1,216 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The scalar field of the BN254 elliptic curve.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+/// The modulus $p$, as little-endian 64-bit limbs.
+const MODULUS: [u64; 4] = [
+	0x43e1f593f0000001,
+	0x2833e84879b97091,
+	0xb85045b68181585d,
+	0x30644e72e131a029,
+];
+
+/// $2^{512} \bmod p$, for converting into Montgomery form.
+const R2: [u64; 4] = [
+	0x1bb8e645ae216da7,
+	0x53fe3ab1e35c59e3,
+	0x8c49833d53bb8085,
+	0x0216d0b17f4e44a5,
+];
+
+/// $-p^{-1} \bmod 2^{64}$.
+const INV: u64 = 0xc2e1f593efffffff;
+
+/// An element of the scalar field of BN254, the field of the arithmetic circuits of SNARKs over
+/// BN254.
+///
+/// Elements are stored in Montgomery form with $R = 2^{256}$.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bn254Fr([u64; 4]);
+
+impl Bn254Fr {
+	pub const ZERO: Self = Self([0; 4]);
+
+	/// Creates an element from its Montgomery form.
+	pub(super) const fn from_montgomery(limbs: [u64; 4]) -> Self {
+		Self(limbs)
+	}
+
+	/// Decodes an element from its canonical little-endian encoding.
+	///
+	/// Returns `None` if the encoded integer is not less than the modulus.
+	pub fn from_le_bytes(bytes: &[u8; 32]) -> Option<Self> {
+		let limbs = le_bytes_to_limbs(bytes);
+		if !less_than(&limbs, &MODULUS) {
+			return None;
+		}
+		Some(Self(mont_mul(&limbs, &R2)))
+	}
+
+	/// Decodes an element from at most 31 little-endian bytes, which always encode an integer
+	/// less than the modulus.
+	pub fn from_le_bytes_short(bytes: &[u8]) -> Self {
+		assert!(bytes.len() < 32, "precondition: bytes must have length less than 32");
+		let mut padded = [0u8; 32];
+		padded[..bytes.len()].copy_from_slice(bytes);
+		Self(mont_mul(&le_bytes_to_limbs(&padded), &R2))
+	}
+
+	/// Returns the canonical little-endian encoding of the element.
+	pub fn to_le_bytes(self) -> [u8; 32] {
+		let limbs = mont_mul(&self.0, &[1, 0, 0, 0]);
+		let mut bytes = [0u8; 32];
+		for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs) {
+			chunk.copy_from_slice(&limb.to_le_bytes());
+		}
+		bytes
+	}
+
+	/// Returns the fifth power of the element, the S-box of Poseidon2 over BN254.
+	pub fn pow5(self) -> Self {
+		let square = self * self;
+		square * square * self
+	}
+}
+
+impl From<u64> for Bn254Fr {
+	fn from(value: u64) -> Self {
+		Self(mont_mul(&[value, 0, 0, 0], &R2))
+	}
+}
+
+impl Add for Bn254Fr {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		let mut sum = [0u64; 4];
+		let mut carry = false;
+		for ((sum, &lhs), &rhs) in sum.iter_mut().zip(&self.0).zip(&rhs.0) {
+			let (limb, carry_1) = lhs.overflowing_add(rhs);
+			let (limb, carry_2) = limb.overflowing_add(carry as u64);
+			*sum = limb;
+			carry = carry_1 | carry_2;
+		}
+		// The modulus is less than 2^254, so the sum does not overflow.
+		debug_assert!(!carry);
+		Self(reduce_once(sum))
+	}
+}
+
+impl AddAssign for Bn254Fr {
+	fn add_assign(&mut self, rhs: Self) {
+		*self = *self + rhs;
+	}
+}
+
+impl Mul for Bn254Fr {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self {
+		Self(mont_mul(&self.0, &rhs.0))
+	}
+}
+
+impl MulAssign for Bn254Fr {
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs;
+	}
+}
+
+fn le_bytes_to_limbs(bytes: &[u8; 32]) -> [u64; 4] {
+	let mut limbs = [0u64; 4];
+	for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+		*limb = u64::from_le_bytes(chunk.try_into().expect("chunk has length 8"));
+	}
+	limbs
+}
+
+fn less_than(a: &[u64; 4], b: &[u64; 4]) -> bool {
+	a.iter().rev().lt(b.iter().rev())
+}
+
+/// Subtracts the modulus from a value less than twice the modulus if the value is not reduced.
+fn reduce_once(value: [u64; 4]) -> [u64; 4] {
+	if less_than(&value, &MODULUS) {
+		return value;
+	}
+	let mut diff = [0u64; 4];
+	let mut borrow = false;
+	for ((diff, &value), &modulus) in diff.iter_mut().zip(&value).zip(&MODULUS) {
+		let (limb, borrow_1) = value.overflowing_sub(modulus);
+		let (limb, borrow_2) = limb.overflowing_sub(borrow as u64);
+		*diff = limb;
+		borrow = borrow_1 | borrow_2;
+	}
+	diff
+}
+
+/// Montgomery multiplication $a b R^{-1} \bmod p$, with the CIOS method.
+#[allow(clippy::needless_range_loop)]
+fn mont_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+	let mut t = [0u64; 6];
+	for &b_i in b {
+		let mut carry = 0u128;
+		for j in 0..4 {
+			let product = t[j] as u128 + a[j] as u128 * b_i as u128 + carry;
+			t[j] = product as u64;
+			carry = product >> 64;
+		}
+		let sum = t[4] as u128 + carry;
+		t[4] = sum as u64;
+		t[5] = (sum >> 64) as u64;
+
+		let m = t[0].wrapping_mul(INV);
+		let mut carry = (t[0] as u128 + m as u128 * MODULUS[0] as u128) >> 64;
+		for j in 1..4 {
+			let product = t[j] as u128 + m as u128 * MODULUS[j] as u128 + carry;
+			t[j - 1] = product as u64;
+			carry = product >> 64;
+		}
+		let sum = t[4] as u128 + carry;
+		t[3] = sum as u64;
+		t[4] = t[5] + (sum >> 64) as u64;
+	}
+	// The modulus is less than 2^254, so the result is less than twice the modulus.
+	debug_assert_eq!(t[4], 0);
+	reduce_once([t[0], t[1], t[2], t[3]])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_arithmetic_matches_integers() {
+		let a = Bn254Fr::from(0xffff_ffff_ffff_ffff);
+		let b = Bn254Fr::from(3);
+		let mut product = [0u8; 32];
+		product[..16].copy_from_slice(&(0xffff_ffff_ffff_ffff_u128 * 3).to_le_bytes());
+		assert_eq!((a * b).to_le_bytes(), product);
+		assert_eq!(b.pow5(), Bn254Fr::from(243));
+		assert_eq!(
+			a + b,
+			Bn254Fr::from_le_bytes(&{
+				let mut sum = [0u8; 32];
+				sum[..16].copy_from_slice(&(0xffff_ffff_ffff_ffff_u128 + 3).to_le_bytes());
+				sum
+			})
+			.unwrap()
+		);
+
+		// p - 1 + 1 = 0
+		let mut p_minus_one = [0u8; 32];
+		for (chunk, limb) in p_minus_one.chunks_exact_mut(8).zip(MODULUS) {
+			chunk.copy_from_slice(&limb.to_le_bytes());
+		}
+		p_minus_one[0] -= 1;
+		let minus_one = Bn254Fr::from_le_bytes(&p_minus_one).unwrap();
+		assert_eq!(minus_one + Bn254Fr::from(1), Bn254Fr::ZERO);
+		assert_eq!(minus_one * minus_one, Bn254Fr::from(1));
+
+		p_minus_one[0] += 1;
+		assert_eq!(Bn254Fr::from_le_bytes(&p_minus_one), None);
+	}
+}
@@ -0,0 +1,172 @@
+// Copyright 2025 Irreducible Inc.
+
+//! [Poseidon2] over the scalar field of BN254, and a sponge over it for Fiat-Shamir.
+//!
+//! Binius transcripts hashed with this sponge can be re-verified efficiently by a SNARK over BN254,
+//! for instance to wrap a Binius proof in a Groth16 or PLONK proof. The permutation is the width-3
+//! instance of the Poseidon2 reference implementation, with the S-box $x^5$, 8 external rounds and
+//! 56 internal rounds.
+//!
+//! [Poseidon2]: <https://eprint.iacr.org/2023/323>
+
+mod constants;
+mod field;
+
+use constants::{EXTERNAL_ROUND_CONSTANTS, INTERNAL_ROUND_CONSTANTS};
+pub use field::Bn254Fr;
+
+use crate::permutation::{CryptographicPermutation, Permutation};
+
+/// The number of field elements in the state.
+pub const WIDTH: usize = 3;
+const N_EXTERNAL_ROUNDS: usize = 8;
+const N_INTERNAL_ROUNDS: usize = 56;
+
+/// The Poseidon2 permutation over the BN254 scalar field with width 3.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Poseidon2Bn254;
+
+impl Poseidon2Bn254 {
+	/// The external linear layer, multiplication by the circulant matrix `circ(2, 1, 1)`.
+	fn external_linear_layer(state: &mut [Bn254Fr; WIDTH]) {
+		let sum = state[0] + state[1] + state[2];
+		for x in state {
+			*x += sum;
+		}
+	}
+
+	/// The internal linear layer, multiplication by `1 + diag(0, 0, 1)`.
+	fn internal_linear_layer(state: &mut [Bn254Fr; WIDTH]) {
+		let sum = state[0] + state[1] + state[2];
+		state[2] += state[2];
+		for x in state {
+			*x += sum;
+		}
+	}
+
+	fn external_round(state: &mut [Bn254Fr; WIDTH], round_constants: &[[u64; 4]; WIDTH]) {
+		for (x, &constant) in state.iter_mut().zip(round_constants) {
+			*x = (*x + Bn254Fr::from_montgomery(constant)).pow5();
+		}
+		Self::external_linear_layer(state);
+	}
+}
+
+impl Permutation<[Bn254Fr; WIDTH]> for Poseidon2Bn254 {
+	fn permute_mut(&self, state: &mut [Bn254Fr; WIDTH]) {
+		let (initial_constants, terminal_constants) =
+			EXTERNAL_ROUND_CONSTANTS.split_at(N_EXTERNAL_ROUNDS / 2);
+
+		Self::external_linear_layer(state);
+		for round_constants in initial_constants {
+			Self::external_round(state, round_constants);
+		}
+		for &constant in &INTERNAL_ROUND_CONSTANTS {
+			state[0] = (state[0] + Bn254Fr::from_montgomery(constant)).pow5();
+			Self::internal_linear_layer(state);
+		}
+		for round_constants in terminal_constants {
+			Self::external_round(state, round_constants);
+		}
+	}
+}
+
+impl CryptographicPermutation<[Bn254Fr; WIDTH]> for Poseidon2Bn254 {}
+
+/// A duplex sponge over [`Poseidon2Bn254`] with rate 2 and capacity 1, absorbing and squeezing
+/// bytes.
+///
+/// A block of [`Self::ABSORB_RATE`] bytes is split into two chunks of 31 bytes, which are decoded
+/// as little-endian integers and added to the rate elements of the state. Squeezing outputs the
+/// low 16 bytes of the little-endian encoding of each rate element, which are statistically close
+/// to uniform.
+#[derive(Debug, Clone, Default)]
+pub struct Poseidon2Bn254Sponge {
+	state: [Bn254Fr; WIDTH],
+}
+
+impl Poseidon2Bn254Sponge {
+	/// The number of bytes absorbed per permutation.
+	pub const ABSORB_RATE: usize = 2 * 31;
+	/// The number of bytes squeezed per permutation.
+	pub const SQUEEZE_RATE: usize = 2 * 16;
+
+	/// Adds `block` to the rate portion of the state and permutes the state.
+	///
+	/// ## Preconditions
+	///
+	/// * `block` must have length [`Self::ABSORB_RATE`]
+	pub fn absorb_block(&mut self, block: &[u8]) {
+		assert_eq!(
+			block.len(),
+			Self::ABSORB_RATE,
+			"precondition: block must have length ABSORB_RATE"
+		);
+		for (x, chunk) in self.state.iter_mut().zip(block.chunks_exact(31)) {
+			*x += Bn254Fr::from_le_bytes_short(chunk);
+		}
+		Poseidon2Bn254.permute_mut(&mut self.state);
+	}
+
+	/// Writes bytes of the rate portion of the state into `out` and permutes the state.
+	///
+	/// ## Preconditions
+	///
+	/// * `out` must have length [`Self::SQUEEZE_RATE`]
+	pub fn squeeze_block(&mut self, out: &mut [u8]) {
+		assert_eq!(
+			out.len(),
+			Self::SQUEEZE_RATE,
+			"precondition: out must have length SQUEEZE_RATE"
+		);
+		for (x, chunk) in self.state.iter().zip(out.chunks_exact_mut(16)) {
+			chunk.copy_from_slice(&x.to_le_bytes()[..16]);
+		}
+		Poseidon2Bn254.permute_mut(&mut self.state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	use super::*;
+
+	fn from_be_bytes(mut bytes: [u8; 32]) -> Bn254Fr {
+		bytes.reverse();
+		Bn254Fr::from_le_bytes(&bytes).unwrap()
+	}
+
+	#[test]
+	fn test_permutation_reference_vector() {
+		// Test vector of the Poseidon2 reference implementation.
+		let state = Poseidon2Bn254.permute([0, 1, 2].map(Bn254Fr::from));
+		assert_eq!(
+			state,
+			[
+				from_be_bytes(hex!(
+					"0bb61d24daca55eebcb1929a82650f328134334da98ea4f847f760054f4a3033"
+				)),
+				from_be_bytes(hex!(
+					"303b6f7c86d043bfcbcc80214f26a30277a15d3f74ca654992defe7ff8d03570"
+				)),
+				from_be_bytes(hex!(
+					"1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8"
+				)),
+			]
+		);
+	}
+
+	#[test]
+	fn test_sponge_vector() {
+		let block = (0..Poseidon2Bn254Sponge::ABSORB_RATE as u8).collect::<Vec<_>>();
+		let mut sponge = Poseidon2Bn254Sponge::default();
+		sponge.absorb_block(&block);
+
+		let mut out = [0u8; Poseidon2Bn254Sponge::SQUEEZE_RATE];
+		sponge.squeeze_block(&mut out);
+		assert_eq!(out, hex!("e04f9faa42fc2e1383896b09fbe3d51831df6caf46cac5893e90f2bb9c2dff7a"));
+		sponge.squeeze_block(&mut out);
+		assert_eq!(out, hex!("6c6b09faf87bfc459afbba9d04f1d79f9c7e468a88f8c4ec25c4a8e34eb1a63b"));
+	}
+}
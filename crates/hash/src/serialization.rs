@@ -64,27 +64,69 @@ impl<D: Digest + BlockSizeUser> Drop for HashBuffer<'_, D> {
 	}
 }
 
-/// Hashes a sequence of serializable items.
-pub fn hash_serialize<T, D>(items: impl IntoIterator<Item = impl Borrow<T>>) -> Output<D>
-where
-	T: SerializeBytes,
-	D: Digest + BlockSizeUser,
-{
-	let mut hasher = D::new();
-	{
-		let mut buffer = HashBuffer::new(&mut hasher);
+/// Incremental hasher over a stream of serializable items.
+///
+/// Unlike [`hash_serialize`], the items do not need to be available all at once. This is useful
+/// when hashing long sequences of field elements that are produced lazily, for example the
+/// columns of a large trace, without materializing them in one buffer first.
+#[derive(Debug, Default, Clone)]
+pub struct SerializingHasher<D> {
+	digest: D,
+}
+
+impl<D: Digest + BlockSizeUser> SerializingHasher<D> {
+	pub fn new() -> Self {
+		Self { digest: D::new() }
+	}
+
+	/// Absorbs a single item.
+	pub fn update<T: SerializeBytes>(&mut self, item: &T) {
+		self.update_iter::<T>(std::iter::once(item));
+	}
+
+	/// Absorbs a sequence of items.
+	pub fn update_iter<T: SerializeBytes>(
+		&mut self,
+		items: impl IntoIterator<Item = impl Borrow<T>>,
+	) {
+		let mut buffer = HashBuffer::new(&mut self.digest);
 		for item in items {
 			item.borrow()
 				.serialize(&mut buffer, SerializationMode::CanonicalTower)
 				.expect("HashBuffer has infinite capacity");
 		}
 	}
+
+	/// Returns the digest of all items absorbed so far.
+	pub fn finalize(self) -> Output<D> {
+		self.digest.finalize()
+	}
+
+	/// Returns the digest of all items absorbed so far and resets the hasher to its initial state.
+	pub fn finalize_reset(&mut self) -> Output<D>
+	where
+		D: digest::FixedOutputReset,
+	{
+		Digest::finalize_reset(&mut self.digest)
+	}
+}
+
+/// Hashes a sequence of serializable items.
+pub fn hash_serialize<T, D>(items: impl IntoIterator<Item = impl Borrow<T>>) -> Output<D>
+where
+	T: SerializeBytes,
+	D: Digest + BlockSizeUser,
+{
+	let mut hasher = SerializingHasher::<D>::new();
+	hasher.update_iter::<T>(items);
 	hasher.finalize()
 }
 
 #[cfg(test)]
 mod tests {
+	use binius_field::{BinaryField32b, Field};
 	use groestl_crypto::Groestl256;
+	use rand::{SeedableRng, rngs::StdRng};
 
 	use super::*;
 
@@ -104,4 +146,25 @@ mod tests {
 		}
 		assert_eq!(hasher.finalize(), expected_digest);
 	}
+
+	#[test]
+	fn test_serializing_hasher_matches_hash_serialize() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let items = std::iter::repeat_with(|| BinaryField32b::random(&mut rng))
+			.take(100)
+			.collect::<Vec<_>>();
+
+		let expected_digest = hash_serialize::<BinaryField32b, Groestl256>(&items);
+
+		let mut hasher = SerializingHasher::<Groestl256>::new();
+		for chunk in items.chunks(7) {
+			hasher.update_iter::<BinaryField32b>(chunk);
+		}
+		assert_eq!(hasher.finalize_reset(), expected_digest);
+
+		for item in &items {
+			hasher.update(item);
+		}
+		assert_eq!(hasher.finalize(), expected_digest);
+	}
 }
@@ -1,5 +1,13 @@
 // Copyright 2023-2025 Irreducible Inc.
 
+//! The SHA-256 hash function.
+//!
+//! SHA-256 is implemented by the RustCrypto `sha2` crate, which selects its backend at runtime: the
+//! SHA-NI instructions on x86 and x86_64, the SHA2 instructions of the ARMv8 cryptography
+//! extensions on aarch64 targets that support CPU feature detection, and a portable implementation
+//! otherwise. Both [`Sha256`] digests and [`Sha256Compression`] use the hardware instructions when
+//! they are available, without any build configuration.
+
 use bytemuck::{bytes_of_mut, must_cast};
 use digest::{Digest, core_api::Block};
 use sha2::{Sha256, compress256, digest::Output};
@@ -33,3 +41,38 @@ impl PseudoCompressionFunction<Output<Sha256>, 2> for Sha256Compression {
 }
 
 impl CompressionFunction<Output<Sha256>, 2> for Sha256Compression {}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+
+	use super::*;
+
+	#[test]
+	fn test_sha256_known_answers() {
+		assert_eq!(
+			Sha256::digest(b"abc")[..],
+			hex!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+		);
+		assert_eq!(
+			Sha256::digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")[..],
+			hex!("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1")
+		);
+	}
+
+	#[test]
+	fn test_sha256_compression_is_one_block_compression() {
+		let left = Sha256::digest(b"left");
+		let right = Sha256::digest(b"right");
+		let compression = Sha256Compression::default();
+
+		let mut state = compression.initial_state;
+		let mut block = <Block<Sha256>>::default();
+		block[..32].copy_from_slice(&left);
+		block[32..].copy_from_slice(&right);
+		compress256(&mut state, &[block]);
+
+		assert_eq!(compression.compress([left, right])[..], must_cast::<[u32; 8], [u8; 32]>(state));
+		assert_ne!(compression.compress([left, right]), compression.compress([right, left]));
+	}
+}
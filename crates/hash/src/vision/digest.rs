@@ -37,7 +37,7 @@ lazy_static! {
 
 
 	// Padding block for the case when the input is a multiple of the rate.
-	static ref PADDING_BLOCK: [u8; RATE_AS_U8] = {
+	pub(super) static ref PADDING_BLOCK: [u8; RATE_AS_U8] = {
 		let mut block = [0; RATE_AS_U8];
 		block[0] = PADDING_START;
 		block[RATE_AS_U8 - 1] |= PADDING_END;
@@ -162,7 +162,7 @@ impl FixedOutputReset for VisionHasherDigest {
 
 /// Fill the data using Keccak padding scheme.
 #[inline(always)]
-fn fill_padding(data: &mut [u8]) {
+pub(super) fn fill_padding(data: &mut [u8]) {
 	debug_assert!(!data.is_empty() && data.len() <= RATE_AS_U8);
 
 	data.fill(0);
@@ -170,6 +170,58 @@ fn fill_padding(data: &mut [u8]) {
 	data[data.len() - 1] |= PADDING_END;
 }
 
+/// The Vision Mark-32 permutation as a duplex sponge over bytes.
+///
+/// Absorbed blocks overwrite the rate portion of the state, exactly as in [`VisionHasherDigest`],
+/// and squeezed blocks are read from the rate portion in the canonical tower basis. Padding is
+/// left to the caller.
+#[derive(Clone)]
+pub struct Vision32Sponge {
+	state: [PackedAESBinaryField8x32b; 3],
+}
+
+impl Default for Vision32Sponge {
+	fn default() -> Self {
+		Self {
+			state: [PackedAESBinaryField8x32b::zero(); 3],
+		}
+	}
+}
+
+impl Vision32Sponge {
+	/// The number of bytes absorbed or squeezed per permutation.
+	pub const RATE: usize = RATE_AS_U8;
+
+	/// Overwrites the rate portion of the state with `block` and permutes the state.
+	///
+	/// ## Preconditions
+	///
+	/// * `block` must have length [`Self::RATE`]
+	pub fn absorb_block(&mut self, block: &[u8]) {
+		assert_eq!(block.len(), Self::RATE, "precondition: block must have length RATE");
+		VisionHasherDigest::permute(&mut self.state, block);
+	}
+
+	/// Writes the rate portion of the state into `out` and permutes the state.
+	///
+	/// ## Preconditions
+	///
+	/// * `out` must have length [`Self::RATE`]
+	pub fn squeeze_block(&mut self, out: &mut [u8]) {
+		assert_eq!(out.len(), Self::RATE, "precondition: out must have length RATE");
+		for (state, out) in self.state[..2]
+			.iter()
+			.zip(out.chunks_exact_mut(RATE_AS_U8 / 2))
+		{
+			let canonical_tower: PackedBinaryField8x32b = TRANS_AES_TO_CANONICAL.transform(state);
+			out.copy_from_slice(BinaryField8b::to_underliers_ref(
+				PackedBinaryField8x32b::unpack_base_scalars(std::slice::from_ref(&canonical_tower)),
+			));
+		}
+		PERMUTATION.permute_mut(&mut self.state);
+	}
+}
+
 #[derive(Clone)]
 pub struct VisionHasherDigestByteSliced {
 	// The hashed state
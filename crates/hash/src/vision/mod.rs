@@ -4,6 +4,8 @@ pub mod compression;
 pub mod constants;
 pub mod digest;
 pub mod permutation;
+#[cfg(feature = "experimental_vision_mark64")]
+pub mod vision_mark64;
 
 pub use compression::*;
 pub use constants::*;
@@ -0,0 +1,158 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The constants of Vision Mark-64.
+//!
+//! Vision Mark-64 is the Vision Mark-32 construction over [`BinaryField64b`]: the state has
+//! [`STATE_SIZE_64`] elements, of which 8 are the rate, and every round applies the inverse S-box
+//! and the forward S-box, each followed by the MDS transform and a round key addition. The state
+//! has the size of the Mark-32 state, and the permutation has the same number of rounds.
+//!
+//! The constants are derived from the output of SHAKE-256 on [`SEED`], read as a stream of field
+//! elements of 8 little-endian bytes each, in the canonical tower basis:
+//!
+//! 1. The linear part of the affine map $B(x) = L(x) + c$ of the S-boxes is the linearized
+//!    polynomial $L(x) = b_1 x + b_2 x^2 + b_3 x^4$, whose coefficients are the next three
+//!    elements. The coefficients are drawn again until $L$ is invertible.
+//! 2. The constant $c$ is the next element.
+//! 3. The round keys are the next $12 (2 r + 1)$ elements, where $r$ is [`NUM_ROUNDS_64`].
+//!
+//! As in Mark-32, the forward S-box is $B(x^{-1})$ and the inverse S-box is $B^{-1}(x^{-1})$. The
+//! maps are given in the AES basis, in which the permutation is computed.
+
+use std::array;
+
+use binius_field::{AESTowerField64b, BinaryField64b};
+use digest::{ExtendableOutput, Update, XofReader};
+use lazy_static::lazy_static;
+use sha3::Shake256;
+
+// The actual number of loops is twice as many because of the 2 sboxes
+pub const NUM_ROUNDS_64: usize = 8;
+
+/// The number of field elements of the Vision Mark-64 state.
+pub const STATE_SIZE_64: usize = 12;
+
+/// The input of the SHAKE-256 stream the constants are derived from.
+pub const SEED: &[u8] = b"Vision Mark-64";
+
+lazy_static! {
+	static ref CONSTANTS: Vision64Constants = Vision64Constants::derive();
+	pub static ref AFFINE_FWD_AES_64: [AESTowerField64b; 64] = CONSTANTS.affine_fwd_aes;
+	pub static ref AFFINE_FWD_CONST_AES_64: AESTowerField64b = CONSTANTS.affine_fwd_const_aes;
+	pub static ref AFFINE_INV_AES_64: [AESTowerField64b; 64] = CONSTANTS.affine_inv_aes;
+	pub static ref AFFINE_INV_CONST_AES_64: AESTowerField64b = CONSTANTS.affine_inv_const_aes;
+	pub static ref ROUND_KEYS_64: [[BinaryField64b; STATE_SIZE_64]; 2 * NUM_ROUNDS_64 + 1] =
+		CONSTANTS.round_keys;
+}
+
+struct Vision64Constants {
+	affine_fwd_aes: [AESTowerField64b; 64],
+	affine_fwd_const_aes: AESTowerField64b,
+	affine_inv_aes: [AESTowerField64b; 64],
+	affine_inv_const_aes: AESTowerField64b,
+	round_keys: [[BinaryField64b; STATE_SIZE_64]; 2 * NUM_ROUNDS_64 + 1],
+}
+
+impl Vision64Constants {
+	fn derive() -> Self {
+		let mut stream = ConstantStream::new();
+
+		let (affine_fwd, affine_inv) = loop {
+			let [b1, b2, b3]: [BinaryField64b; 3] = array::from_fn(|_| stream.next_element());
+			let linear = |x: BinaryField64b| {
+				let x2 = x * x;
+				b1 * x + b2 * x2 + b3 * x2 * x2
+			};
+
+			// The columns of L in the AES basis.
+			let columns = array::from_fn(|i| {
+				let basis = BinaryField64b::from(AESTowerField64b::new(1 << i));
+				AESTowerField64b::from(linear(basis)).val()
+			});
+			if let Some(inverse) = invert_linear(&columns) {
+				break (columns, inverse);
+			}
+		};
+
+		let affine_fwd_const = AESTowerField64b::from(stream.next_element());
+		let affine_inv_const = apply_linear(&affine_inv, affine_fwd_const.val());
+		let round_keys = array::from_fn(|_| array::from_fn(|_| stream.next_element()));
+
+		Self {
+			affine_fwd_aes: affine_fwd.map(AESTowerField64b::new),
+			affine_fwd_const_aes: affine_fwd_const,
+			affine_inv_aes: affine_inv.map(AESTowerField64b::new),
+			affine_inv_const_aes: AESTowerField64b::new(affine_inv_const),
+			round_keys,
+		}
+	}
+}
+
+/// The stream of field elements read from SHAKE-256 on [`SEED`].
+struct ConstantStream(<Shake256 as ExtendableOutput>::Reader);
+
+impl ConstantStream {
+	fn new() -> Self {
+		let mut hasher = Shake256::default();
+		hasher.update(SEED);
+		Self(hasher.finalize_xof())
+	}
+
+	fn next_element(&mut self) -> BinaryField64b {
+		let mut bytes = [0; 8];
+		self.0.read(&mut bytes);
+		BinaryField64b::new(u64::from_le_bytes(bytes))
+	}
+}
+
+/// Applies the $\mathbb{F}_2$-linear map with the given columns to `x`.
+fn apply_linear(columns: &[u64; 64], x: u64) -> u64 {
+	(0..64)
+		.filter(|i| (x >> i) & 1 == 1)
+		.fold(0, |acc, i| acc ^ columns[i])
+}
+
+/// Inverts the $\mathbb{F}_2$-linear map with the given columns, if it is invertible.
+fn invert_linear(columns: &[u64; 64]) -> Option<[u64; 64]> {
+	// Reduce the images of the unit vectors to the unit vectors with column operations, applying
+	// the same operations to the unit vectors.
+	let mut images = *columns;
+	let mut preimages: [u64; 64] = array::from_fn(|i| 1 << i);
+	for bit in 0..64 {
+		let pivot = (bit..64).find(|&i| (images[i] >> bit) & 1 == 1)?;
+		images.swap(bit, pivot);
+		preimages.swap(bit, pivot);
+		for i in 0..64 {
+			if i != bit && (images[i] >> bit) & 1 == 1 {
+				images[i] ^= images[bit];
+				preimages[i] ^= preimages[bit];
+			}
+		}
+	}
+	Some(preimages)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		Field,
+		linear_transformation::{FieldLinearTransformation, Transformation},
+	};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	#[test]
+	fn test_affine_maps_are_inverse() {
+		let fwd = FieldLinearTransformation::new(&AFFINE_FWD_AES_64[..]);
+		let inv = FieldLinearTransformation::new(&AFFINE_INV_AES_64[..]);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..100 {
+			let x = AESTowerField64b::random(&mut rng);
+			let y: AESTowerField64b = fwd.transform(&x) + *AFFINE_FWD_CONST_AES_64;
+			let x_again: AESTowerField64b = inv.transform(&y) + *AFFINE_INV_CONST_AES_64;
+			assert_eq!(x_again, x);
+		}
+	}
+}
@@ -0,0 +1,407 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{array, mem::MaybeUninit};
+
+use binius_field::{
+	AesToBinaryTransformation, BinaryToAesTransformation, ByteSlicedAES32x64b,
+	PackedAESBinaryField4x64b, PackedBinaryField4x64b, PackedField,
+	linear_transformation::Transformation, make_aes_to_binary_packed_transformer,
+	make_binary_to_aes_packed_transformer, underlier::WithUnderlier,
+};
+use binius_utils::mem::slice_assume_init_mut;
+use digest::{
+	FixedOutput, FixedOutputReset, HashMarker, OutputSizeUser, Reset, Update,
+	consts::{U32, U96},
+	core_api::BlockSizeUser,
+};
+use lazy_static::lazy_static;
+
+use super::{constants::STATE_SIZE_64, permutation::PERMUTATION_64};
+use crate::{
+	multi_digest::{MultiDigest, ParallelMulidigestImpl},
+	permutation::Permutation,
+	vision::{
+		digest::{PADDING_BLOCK, fill_padding},
+		permutation::HASHES_PER_BYTE_SLICED_PERMUTATION,
+	},
+};
+
+const RATE_AS_U64: usize = 8;
+const RATE_AS_U8: usize = RATE_AS_U64 * std::mem::size_of::<u64>();
+
+lazy_static! {
+	static ref TRANS_AES_TO_CANONICAL: AesToBinaryTransformation<PackedAESBinaryField4x64b, PackedBinaryField4x64b> =
+		make_aes_to_binary_packed_transformer::<PackedAESBinaryField4x64b, PackedBinaryField4x64b>();
+	static ref TRANS_CANONICAL_TO_AES: BinaryToAesTransformation<PackedBinaryField4x64b, PackedAESBinaryField4x64b> =
+		make_binary_to_aes_packed_transformer::<PackedBinaryField4x64b, PackedAESBinaryField4x64b>();
+}
+
+/// The Vision Mark-64 hash function.
+///
+/// The sponge construction and the padding are the ones of [`VisionHasherDigest`], with the
+/// rate of 8 elements of the state read as little-endian 64-bit words.
+///
+/// [`VisionHasherDigest`]: super::VisionHasherDigest
+#[derive(Clone)]
+pub struct Vision64HasherDigest {
+	// The hashed state
+	state: [PackedAESBinaryField4x64b; 3],
+	buffer: [u8; RATE_AS_U8],
+	filled_bytes: usize,
+}
+
+impl Default for Vision64HasherDigest {
+	fn default() -> Self {
+		Self {
+			state: [PackedAESBinaryField4x64b::zero(); 3],
+			buffer: [0; RATE_AS_U8],
+			filled_bytes: 0,
+		}
+	}
+}
+
+impl Vision64HasherDigest {
+	fn permute(state: &mut [PackedAESBinaryField4x64b; 3], data: &[u8]) {
+		debug_assert_eq!(data.len(), RATE_AS_U8);
+
+		for (i, state_element) in state[..2].iter_mut().enumerate() {
+			let canonical = PackedBinaryField4x64b::from_fn(|j| {
+				let offset = (i * 4 + j) * 8;
+				u64::from_le_bytes(
+					data[offset..offset + 8]
+						.try_into()
+						.expect("chunk is 8 bytes"),
+				)
+				.into()
+			});
+			*state_element = TRANS_CANONICAL_TO_AES.transform(&canonical);
+		}
+
+		PERMUTATION_64.permute_mut(state);
+	}
+
+	fn finalize_into(&mut self, out: &mut digest::Output<Self>) {
+		if self.filled_bytes != 0 {
+			fill_padding(&mut self.buffer[self.filled_bytes..]);
+			Self::permute(&mut self.state, &self.buffer);
+		} else {
+			Self::permute(&mut self.state, &*PADDING_BLOCK);
+		}
+
+		let canonical_tower: PackedBinaryField4x64b =
+			TRANS_AES_TO_CANONICAL.transform(&self.state[0]);
+		for (out, scalar) in out.chunks_exact_mut(8).zip(canonical_tower.iter()) {
+			out.copy_from_slice(&scalar.to_underlier().to_le_bytes());
+		}
+	}
+}
+
+impl HashMarker for Vision64HasherDigest {}
+
+impl Update for Vision64HasherDigest {
+	fn update(&mut self, mut data: &[u8]) {
+		if self.filled_bytes != 0 {
+			let to_copy = std::cmp::min(data.len(), RATE_AS_U8 - self.filled_bytes);
+			self.buffer[self.filled_bytes..self.filled_bytes + to_copy]
+				.copy_from_slice(&data[..to_copy]);
+			data = &data[to_copy..];
+			self.filled_bytes += to_copy;
+
+			if self.filled_bytes == RATE_AS_U8 {
+				Self::permute(&mut self.state, &self.buffer);
+				self.filled_bytes = 0;
+			}
+		}
+
+		let mut chunks = data.chunks_exact(RATE_AS_U8);
+		for chunk in &mut chunks {
+			Self::permute(&mut self.state, chunk);
+		}
+
+		let remaining = chunks.remainder();
+		if !remaining.is_empty() {
+			self.buffer[..remaining.len()].copy_from_slice(remaining);
+			self.filled_bytes = remaining.len();
+		}
+	}
+}
+
+impl OutputSizeUser for Vision64HasherDigest {
+	type OutputSize = U32;
+}
+
+impl BlockSizeUser for Vision64HasherDigest {
+	type BlockSize = U96;
+}
+
+impl FixedOutput for Vision64HasherDigest {
+	fn finalize_into(mut self, out: &mut digest::Output<Self>) {
+		Self::finalize_into(&mut self, out);
+	}
+}
+
+impl Reset for Vision64HasherDigest {
+	fn reset(&mut self) {
+		bytemuck::fill_zeroes(&mut self.state);
+		bytemuck::fill_zeroes(&mut self.buffer);
+		self.filled_bytes = 0;
+	}
+}
+
+impl FixedOutputReset for Vision64HasherDigest {
+	fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
+		Self::finalize_into(self, out);
+		Reset::reset(self);
+	}
+}
+
+#[derive(Clone)]
+pub struct Vision64HasherDigestByteSliced {
+	// The hashed state
+	state: [ByteSlicedAES32x64b; STATE_SIZE_64],
+	// Buffer to hold the temporary data
+	buffer: [[u8; RATE_AS_U8]; HASHES_PER_BYTE_SLICED_PERMUTATION],
+	filled_bytes: usize,
+}
+
+impl Default for Vision64HasherDigestByteSliced {
+	fn default() -> Self {
+		Self {
+			state: [ByteSlicedAES32x64b::zero(); STATE_SIZE_64],
+			buffer: [[0; RATE_AS_U8]; HASHES_PER_BYTE_SLICED_PERMUTATION],
+			filled_bytes: 0,
+		}
+	}
+}
+
+impl Vision64HasherDigestByteSliced {
+	fn permute(
+		state: &mut [ByteSlicedAES32x64b; STATE_SIZE_64],
+		data: [&[u8; RATE_AS_U8]; HASHES_PER_BYTE_SLICED_PERMUTATION],
+	) {
+		for (i, state_element) in state[..RATE_AS_U64].iter_mut().enumerate() {
+			let ordinary_range_data: [PackedAESBinaryField4x64b; 8] = array::from_fn(|j| {
+				let canonical = PackedBinaryField4x64b::from_fn(|k| {
+					u64::from_le_bytes(
+						(data[j * 4 + k][8 * i..8 * i + 8])
+							.try_into()
+							.expect("chunk is 8 bytes"),
+					)
+					.into()
+				});
+
+				TRANS_CANONICAL_TO_AES.transform(&canonical)
+			});
+
+			*state_element = ByteSlicedAES32x64b::transpose_from(&ordinary_range_data);
+		}
+
+		PERMUTATION_64.permute_mut(state);
+	}
+
+	fn finalize(
+		&mut self,
+		out: &mut [MaybeUninit<digest::Output<Vision64HasherDigest>>;
+			     HASHES_PER_BYTE_SLICED_PERMUTATION],
+	) {
+		if self.filled_bytes > 0 {
+			for row in 0..HASHES_PER_BYTE_SLICED_PERMUTATION {
+				fill_padding(&mut self.buffer[row][self.filled_bytes..]);
+			}
+
+			Self::permute(&mut self.state, array::from_fn(|i| &self.buffer[i]));
+		} else {
+			Self::permute(&mut self.state, array::from_fn(|_| &*PADDING_BLOCK));
+		}
+
+		let out: &mut [digest::Output<Vision64HasherDigest>; HASHES_PER_BYTE_SLICED_PERMUTATION] =
+			unsafe { slice_assume_init_mut(out) }
+				.try_into()
+				.expect("array is 32 elements");
+		for (i, state_data) in self.state[0..4].iter().enumerate() {
+			let mut transposed_aes = Default::default();
+			state_data.transpose_to(&mut transposed_aes);
+
+			for (j, transposed_aes) in transposed_aes.iter().enumerate() {
+				let transposed_canonical: PackedBinaryField4x64b =
+					TRANS_AES_TO_CANONICAL.transform(transposed_aes);
+				for (k, scalar) in transposed_canonical.iter().enumerate() {
+					out[j * 4 + k][i * 8..i * 8 + 8]
+						.copy_from_slice(&scalar.to_underlier().to_le_bytes());
+				}
+			}
+		}
+	}
+}
+
+impl MultiDigest<HASHES_PER_BYTE_SLICED_PERMUTATION> for Vision64HasherDigestByteSliced {
+	type Digest = Vision64HasherDigest;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: [&[u8]; HASHES_PER_BYTE_SLICED_PERMUTATION]) {
+		for row in 1..HASHES_PER_BYTE_SLICED_PERMUTATION {
+			debug_assert_eq!(data[row].len(), data[0].len());
+		}
+
+		let mut offset = if self.filled_bytes > 0 {
+			let to_copy = std::cmp::min(data[0].len(), RATE_AS_U8 - self.filled_bytes);
+			for (row_i, row) in data.iter().enumerate() {
+				self.buffer[row_i][self.filled_bytes..self.filled_bytes + to_copy]
+					.copy_from_slice(&row[..to_copy]);
+			}
+
+			self.filled_bytes += to_copy;
+
+			if self.filled_bytes == RATE_AS_U8 {
+				Self::permute(&mut self.state, array::from_fn(|i| &self.buffer[i]));
+				self.filled_bytes = 0;
+			}
+
+			to_copy
+		} else {
+			0
+		};
+
+		while offset + RATE_AS_U8 <= data[0].len() {
+			let chunk = array::from_fn(|i| {
+				(&data[i][offset..offset + RATE_AS_U8])
+					.try_into()
+					.expect("array is 64 bytes")
+			});
+			Self::permute(&mut self.state, chunk);
+			offset += RATE_AS_U8;
+		}
+
+		if offset < data[0].len() {
+			for (row_i, row) in data.iter().enumerate() {
+				self.buffer[row_i][..row.len() - offset].copy_from_slice(&row[offset..]);
+			}
+
+			self.filled_bytes = data[0].len() - offset;
+		}
+	}
+
+	fn finalize_into(
+		mut self,
+		out: &mut [MaybeUninit<digest::Output<Self::Digest>>; HASHES_PER_BYTE_SLICED_PERMUTATION],
+	) {
+		self.finalize(out);
+	}
+
+	fn finalize_into_reset(
+		&mut self,
+		out: &mut [MaybeUninit<digest::Output<Self::Digest>>; HASHES_PER_BYTE_SLICED_PERMUTATION],
+	) {
+		self.finalize(out);
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		bytemuck::fill_zeroes(&mut self.state);
+		self.filled_bytes = 0;
+	}
+
+	fn digest(
+		data: [&[u8]; HASHES_PER_BYTE_SLICED_PERMUTATION],
+		out: &mut [MaybeUninit<digest::Output<Self::Digest>>; HASHES_PER_BYTE_SLICED_PERMUTATION],
+	) {
+		let mut digest = Self::default();
+		digest.update(data);
+		digest.finalize_into(out);
+	}
+}
+
+pub type Vision64ParallelDigest =
+	ParallelMulidigestImpl<Vision64HasherDigestByteSliced, HASHES_PER_BYTE_SLICED_PERMUTATION>;
+
+#[cfg(test)]
+mod tests {
+	use std::{array, mem::MaybeUninit};
+
+	use digest::Digest;
+
+	use super::{
+		HASHES_PER_BYTE_SLICED_PERMUTATION, MultiDigest, PADDING_BLOCK, Vision64HasherDigest,
+		Vision64HasherDigestByteSliced,
+	};
+
+	#[test]
+	fn test_multi_block_split() {
+		let input = "One part of the state is the rate, the other part is the capacity, and the \
+			capacity is never touched by the input nor output directly.";
+		let expected = Vision64HasherDigest::digest(input.as_bytes());
+
+		let mut hasher = Vision64HasherDigest::default();
+		let input_as_b = input.as_bytes();
+		hasher.update(&input_as_b[0..63]);
+		hasher.update(&input_as_b[63..128]);
+		hasher.update(&input_as_b[128..]);
+		assert_eq!(expected, hasher.finalize());
+	}
+
+	#[test]
+	fn test_distinct_inputs() {
+		let empty = Vision64HasherDigest::digest([0u8; 0]);
+		let padding_block = Vision64HasherDigest::digest(&*PADDING_BLOCK);
+		let one_byte = Vision64HasherDigest::digest([0]);
+		assert_ne!(empty, padding_block);
+		assert_ne!(empty, one_byte);
+		assert_ne!(padding_block, one_byte);
+	}
+
+	fn check_multihash_consistency(chunks: &[[&[u8]; HASHES_PER_BYTE_SLICED_PERMUTATION]]) {
+		let mut scalar_digests = array::from_fn::<_, HASHES_PER_BYTE_SLICED_PERMUTATION, _>(|_| {
+			Vision64HasherDigest::default()
+		});
+		let mut multidigest = Vision64HasherDigestByteSliced::default();
+
+		for chunk in chunks {
+			for (scalar_digest, data) in scalar_digests.iter_mut().zip(chunk.iter()) {
+				scalar_digest.update(data);
+			}
+
+			multidigest.update(*chunk);
+		}
+
+		let scalar_digests = scalar_digests.map(|d| d.finalize());
+		let mut output = [MaybeUninit::uninit(); HASHES_PER_BYTE_SLICED_PERMUTATION];
+		multidigest.finalize_into(&mut output);
+		let output = unsafe { output.map(|output| output.assume_init()) };
+
+		assert_eq!(scalar_digests, output);
+	}
+
+	#[test]
+	fn test_multihash_consistency_small_data() {
+		let data = array::from_fn::<_, HASHES_PER_BYTE_SLICED_PERMUTATION, _>(|i| {
+			[i as u8, (i + 1) as _, (i + 2) as _, (i + 3) as _]
+		});
+
+		check_multihash_consistency(&[array::from_fn(|i| &data[i][..])]);
+	}
+
+	#[test]
+	fn test_multihash_consistency_large_rate() {
+		let data = array::from_fn::<_, HASHES_PER_BYTE_SLICED_PERMUTATION, _>(|i| [i as u8; 1024]);
+
+		check_multihash_consistency(&[array::from_fn(|i| &data[i][..])]);
+	}
+
+	#[test]
+	fn test_multihash_consistency_several_chunks() {
+		let data_0 = array::from_fn::<_, HASHES_PER_BYTE_SLICED_PERMUTATION, _>(|i| [i as u8; 48]);
+		let data_1 =
+			array::from_fn::<_, HASHES_PER_BYTE_SLICED_PERMUTATION, _>(|i| [(i + 1) as u8; 64]);
+		let data_2 =
+			array::from_fn::<_, HASHES_PER_BYTE_SLICED_PERMUTATION, _>(|i| [(i + 2) as u8; 128]);
+
+		check_multihash_consistency(&[
+			array::from_fn(|i| &data_0[i][..]),
+			array::from_fn(|i| &data_1[i][..]),
+			array::from_fn(|i| &data_2[i][..]),
+		]);
+	}
+}
@@ -0,0 +1,19 @@
+// Copyright 2025 Irreducible Inc.
+
+//! An experimental Vision Mark-64 sponge over 64-bit binary field elements.
+//!
+//! **This hash function is not for production use.** There is no published parameter set for
+//! Vision Mark-64: the affine maps of the S-boxes, the round keys and the number of rounds are
+//! derived ad hoc, as described in [`constants`], and have not been analysed for security. The
+//! module exists to experiment with the performance of the construction, and is only compiled
+//! with the non-default `experimental_vision_mark64` feature. Use [Vision Mark-32] when a Vision
+//! hash is needed.
+//!
+//! [Vision Mark-32]: crate::VisionHasherDigest
+
+pub mod constants;
+pub mod digest;
+pub mod permutation;
+
+pub use digest::*;
+pub use permutation::{Vision64MDSTransform, Vision64bPermutation};
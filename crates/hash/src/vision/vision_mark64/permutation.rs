@@ -0,0 +1,288 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{array, slice};
+
+use binius_field::{
+	AESTowerField8b, AESTowerField64b, BinaryField8b, ByteSlicedAES32x64b,
+	PackedAESBinaryField4x64b, PackedAESBinaryField32x8b, PackedExtension, PackedField,
+	linear_transformation::{FieldLinearTransformation, PackedTransformationFactory},
+	packed::packed_from_fn_with_offset,
+};
+use binius_ntt::{AdditiveNTT, NTTShape, SingleThreadedNTT, twiddle::OnTheFlyTwiddleAccess};
+use lazy_static::lazy_static;
+
+use super::constants::{
+	AFFINE_FWD_AES_64, AFFINE_FWD_CONST_AES_64, AFFINE_INV_AES_64, AFFINE_INV_CONST_AES_64,
+	NUM_ROUNDS_64, ROUND_KEYS_64, STATE_SIZE_64,
+};
+use crate::{
+	permutation::Permutation,
+	vision::permutation::{HASHES_PER_BYTE_SLICED_PERMUTATION, add_packed_768, sbox_multi},
+};
+
+type PackedTransformationType4x64bAES = <PackedAESBinaryField4x64b as PackedTransformationFactory<
+	PackedAESBinaryField4x64b,
+>>::PackedTransformation<&'static [AESTowerField64b]>;
+type PackedTransformationType32x64bAES = <ByteSlicedAES32x64b as PackedTransformationFactory<
+	ByteSlicedAES32x64b,
+>>::PackedTransformation<&'static [AESTowerField64b]>;
+type AdditiveNTT8b = SingleThreadedNTT<AESTowerField8b, OnTheFlyTwiddleAccess<AESTowerField8b>>;
+
+/// The number of elements of the state in a coset of the MDS transform.
+const LOG_COSET_SIZE: usize = 2;
+
+lazy_static! {
+	/// The additive NTT of the MDS transform, on 3 cosets of 4 elements for the input and 3
+	/// cosets for the output.
+	static ref ADDITIVE_NTT_AES_64: AdditiveNTT8b = {
+		let log_rate = 1;
+		SingleThreadedNTT::<AESTowerField8b>::with_domain_field::<BinaryField8b>(
+			LOG_COSET_SIZE + 2 + log_rate,
+		)
+		.expect("log_domain_size is less than 32")
+	};
+
+	pub static ref FWD_PACKED_TRANS_AES_64: PackedTransformationType4x64bAES = <PackedAESBinaryField4x64b as PackedTransformationFactory<
+		PackedAESBinaryField4x64b,
+	>>::make_packed_transformation(FieldLinearTransformation::new(&AFFINE_FWD_AES_64[..]));
+	pub static ref INV_PACKED_TRANS_AES_64: PackedTransformationType4x64bAES = <PackedAESBinaryField4x64b as PackedTransformationFactory<
+		PackedAESBinaryField4x64b,
+	>>::make_packed_transformation(FieldLinearTransformation::new(&AFFINE_INV_AES_64[..]));
+
+	pub static ref FWD_PACKED_TRANS_AES_64_BYTE_SLICED: PackedTransformationType32x64bAES = <ByteSlicedAES32x64b as PackedTransformationFactory<
+		ByteSlicedAES32x64b,
+	>>::make_packed_transformation(FieldLinearTransformation::new(&AFFINE_FWD_AES_64[..]));
+	pub static ref INV_PACKED_TRANS_AES_64_BYTE_SLICED: PackedTransformationType32x64bAES = <ByteSlicedAES32x64b as PackedTransformationFactory<
+		ByteSlicedAES32x64b,
+	>>::make_packed_transformation(FieldLinearTransformation::new(&AFFINE_INV_AES_64[..]));
+
+	static ref FWD_CONST_AES_64: PackedAESBinaryField4x64b = PackedField::broadcast(*AFFINE_FWD_CONST_AES_64);
+	static ref INV_CONST_AES_64: PackedAESBinaryField4x64b = PackedField::broadcast(*AFFINE_INV_CONST_AES_64);
+
+	static ref FWD_CONST_AES_64_BYTE_SLICED: ByteSlicedAES32x64b = ByteSlicedAES32x64b::broadcast(*AFFINE_FWD_CONST_AES_64);
+	static ref INV_CONST_AES_64_BYTE_SLICED: ByteSlicedAES32x64b = ByteSlicedAES32x64b::broadcast(*AFFINE_INV_CONST_AES_64);
+
+	static ref ROUND_KEYS_PACKED_AES_64: [[PackedAESBinaryField4x64b; 3]; 2 * NUM_ROUNDS_64 + 1] =
+		ROUND_KEYS_64.map(|round_consts| {
+			array::from_fn(|i| packed_from_fn_with_offset(i, |j| round_consts[j].into()))
+		});
+	static ref ROUND_KEYS_PACKED_AES_64_BYTE_SLICED: [[ByteSlicedAES32x64b; STATE_SIZE_64]; 2 * NUM_ROUNDS_64 + 1] =
+		ROUND_KEYS_64.map(|round_consts| {
+			round_consts.map(|round_const_i| ByteSlicedAES32x64b::broadcast(round_const_i.into()))
+		});
+
+	pub static ref PERMUTATION_64: Vision64bPermutation = Vision64bPermutation::default();
+}
+
+/// This is the complete permutation function for the Vision Mark-64 hash which implements
+/// `Permutation` over `PackedAESBinaryField4x64b` as well as over `ByteSlicedAES32x64b`
+#[derive(Clone, Default)]
+pub struct Vision64bPermutation {
+	mds: Vision64MDSTransform,
+}
+
+impl Permutation<[PackedAESBinaryField4x64b; 3]> for Vision64bPermutation {
+	fn permute_mut(&self, input: &mut [PackedAESBinaryField4x64b; 3]) {
+		add_packed_768(input, &ROUND_KEYS_PACKED_AES_64[0]);
+		for r in 0..NUM_ROUNDS_64 {
+			sbox_multi(input, &*INV_PACKED_TRANS_AES_64, &*INV_CONST_AES_64);
+
+			let input_bases = PackedAESBinaryField4x64b::cast_bases_mut(input);
+			self.mds
+				.transform(input_bases.try_into().expect("input is 3 elements"));
+
+			add_packed_768(input, &ROUND_KEYS_PACKED_AES_64[1 + 2 * r]);
+			sbox_multi(input, &*FWD_PACKED_TRANS_AES_64, &*FWD_CONST_AES_64);
+			let input_bases = PackedAESBinaryField4x64b::cast_bases_mut(input);
+			self.mds
+				.transform(input_bases.try_into().expect("input is 3 elements"));
+			add_packed_768(input, &ROUND_KEYS_PACKED_AES_64[2 + 2 * r]);
+		}
+	}
+}
+
+impl Permutation<[ByteSlicedAES32x64b; STATE_SIZE_64]> for Vision64bPermutation {
+	fn permute_mut(&self, input: &mut [ByteSlicedAES32x64b; STATE_SIZE_64]) {
+		add_packed_768(input, &ROUND_KEYS_PACKED_AES_64_BYTE_SLICED[0]);
+		for r in 0..NUM_ROUNDS_64 {
+			sbox_multi(input, &*INV_PACKED_TRANS_AES_64_BYTE_SLICED, &INV_CONST_AES_64_BYTE_SLICED);
+
+			self.mds
+				.transform_byte_sliced(bytemuck::must_cast_mut(input));
+
+			add_packed_768(input, &ROUND_KEYS_PACKED_AES_64_BYTE_SLICED[1 + 2 * r]);
+			sbox_multi(input, &*FWD_PACKED_TRANS_AES_64_BYTE_SLICED, &FWD_CONST_AES_64_BYTE_SLICED);
+			self.mds
+				.transform_byte_sliced(bytemuck::must_cast_mut(input));
+			add_packed_768(input, &ROUND_KEYS_PACKED_AES_64_BYTE_SLICED[2 + 2 * r]);
+		}
+	}
+}
+
+/// The MDS step in the Vision Mark-64 permutation which uses AdditiveNTT to compute matrix
+/// multiplication of the state vector 12x64b
+///
+/// As in Mark-32, the matrix is the Reed–Solomon encoding that maps the evaluations of a
+/// polynomial on the first 3 cosets of the NTT domain to its evaluations on the next 3 cosets,
+/// applied to every byte of the state elements.
+#[derive(Debug, Clone)]
+pub struct Vision64MDSTransform {
+	x: PackedAESBinaryField32x8b,
+	y: PackedAESBinaryField32x8b,
+	z: PackedAESBinaryField32x8b,
+}
+
+impl Default for Vision64MDSTransform {
+	fn default() -> Self {
+		Self {
+			x: PackedField::broadcast(ADDITIVE_NTT_AES_64.get_subspace_eval(LOG_COSET_SIZE, 1)),
+			y: PackedField::broadcast(ADDITIVE_NTT_AES_64.get_subspace_eval(LOG_COSET_SIZE, 2)),
+			z: PackedField::broadcast(ADDITIVE_NTT_AES_64.get_subspace_eval(LOG_COSET_SIZE + 1, 1)),
+		}
+	}
+}
+
+impl Vision64MDSTransform {
+	pub fn transform(&self, data: &mut [PackedAESBinaryField32x8b; 3]) {
+		// Every packed element holds the 8 bytes of each of the 4 elements of a coset.
+		let shape = NTTShape {
+			log_x: 3,
+			log_y: LOG_COSET_SIZE,
+			log_z: 0,
+		};
+		for (coset, data) in data.iter_mut().enumerate() {
+			ADDITIVE_NTT_AES_64
+				.inverse_transform(slice::from_mut(data), shape, coset as _, 0)
+				.expect("shape is valid");
+		}
+
+		data[1] += data[0];
+		let x = self.x * data[1];
+		data[2] += x + data[0];
+
+		let y = self.y * data[1];
+		let z = self.z * data[2];
+
+		let stash_0 = data[0];
+		let stash_1 = data[1];
+		data[0] += x + data[1] + data[2];
+		data[1] = stash_0 + y + z;
+		data[2] = data[1] + stash_1;
+
+		for (coset, data) in data.iter_mut().enumerate() {
+			ADDITIVE_NTT_AES_64
+				.forward_transform(slice::from_mut(data), shape, (3 + coset) as _, 0)
+				.expect("shape is valid");
+		}
+	}
+
+	pub fn transform_byte_sliced(
+		&self,
+		data: &mut [[PackedAESBinaryField32x8b; HASHES_PER_BYTE_SLICED_PERMUTATION]; 3],
+	) {
+		// Every packed element holds a byte of an element of a coset for all the hashes.
+		let shape = NTTShape {
+			log_x: 8,
+			log_y: LOG_COSET_SIZE,
+			log_z: 0,
+		};
+		for (coset, data) in data.iter_mut().enumerate() {
+			ADDITIVE_NTT_AES_64
+				.inverse_transform(data, shape, coset as _, 0)
+				.expect("shape is valid");
+		}
+
+		for i in 0..HASHES_PER_BYTE_SLICED_PERMUTATION {
+			data[1][i] += data[0][i];
+			let x = self.x * data[1][i];
+			data[2][i] += x + data[0][i];
+
+			let y = self.y * data[1][i];
+			let z = self.z * data[2][i];
+
+			let stash_0 = data[0][i];
+			let stash_1 = data[1][i];
+			data[0][i] += x + data[1][i] + data[2][i];
+			data[1][i] = stash_0 + y + z;
+			data[2][i] = data[1][i] + stash_1;
+		}
+
+		for (coset, data) in data.iter_mut().enumerate() {
+			ADDITIVE_NTT_AES_64
+				.forward_transform(data, shape, (3 + coset) as _, 0)
+				.expect("shape is valid");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{Field, packed::get_packed_slice};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	fn domain_point(i: usize) -> AESTowerField8b {
+		AESTowerField8b::from(BinaryField8b::new(i as u8))
+	}
+
+	#[test]
+	fn test_mds_is_reed_solomon_encoding() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut data: [PackedAESBinaryField4x64b; 3] =
+			array::from_fn(|_| PackedAESBinaryField4x64b::random(&mut rng));
+		let input: [AESTowerField64b; STATE_SIZE_64] =
+			array::from_fn(|i| get_packed_slice(&data, i));
+
+		Vision64MDSTransform::default().transform(
+			PackedAESBinaryField4x64b::cast_bases_mut(&mut data)
+				.try_into()
+				.unwrap(),
+		);
+
+		// Evaluate the polynomial with the evaluations `input` on the first 12 points of the
+		// domain on the next 12 points with Lagrange interpolation.
+		for k in 0..STATE_SIZE_64 {
+			let point = domain_point(STATE_SIZE_64 + k);
+			let expected = (0..STATE_SIZE_64)
+				.map(|i| {
+					let basis = (0..STATE_SIZE_64)
+						.filter(|&j| j != i)
+						.map(|j| {
+							(point - domain_point(j))
+								* (domain_point(i) - domain_point(j))
+									.invert()
+									.expect("domain points are distinct")
+						})
+						.product::<AESTowerField8b>();
+					input[i] * AESTowerField64b::from(basis)
+				})
+				.sum::<AESTowerField64b>();
+			assert_eq!(get_packed_slice(&data, k), expected);
+		}
+	}
+
+	#[test]
+	fn test_permutation_consistency() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut data: [ByteSlicedAES32x64b; STATE_SIZE_64] =
+			array::from_fn(|_| ByteSlicedAES32x64b::random(&mut rng));
+
+		let get_single_permutation = |i: usize, data: &[ByteSlicedAES32x64b; STATE_SIZE_64]| {
+			array::from_fn(|j| PackedAESBinaryField4x64b::from_fn(|k| data[4 * j + k].get(i)))
+		};
+
+		let single_permutations = array::from_fn::<_, HASHES_PER_BYTE_SLICED_PERMUTATION, _>(|i| {
+			let mut data = get_single_permutation(i, &data);
+
+			Vision64bPermutation::default().permute_mut(&mut data);
+			data
+		});
+
+		Vision64bPermutation::default().permute_mut(&mut data);
+
+		for (i, single_permutation) in single_permutations.iter().enumerate() {
+			assert_eq!(*single_permutation, get_single_permutation(i, &data));
+		}
+	}
+}
@@ -161,6 +161,10 @@ pub enum ColumnDef<F: TowerField = B128> {
 		poly: Arc<dyn MultivariatePoly<F>>,
 		data: Vec<F>,
 	},
+	Static {
+		poly: Arc<dyn MultivariatePoly<F>>,
+		data: Vec<F>,
+	},
 	StructuredDynSize(StructuredDynSize),
 	StructuredFixedSize {
 		expr: ArithCircuit<F>,
@@ -635,6 +635,10 @@ fn add_oracle_for_column<F: TowerField>(
 				.repeating(oracle_id_original, n_vars - shape.log_values_per_row)?;
 			oracle_lookup.register_transparent(*column_id, oracle_id_original, oracle_id_repeating);
 		}
+		ColumnDef::Static { poly, .. } => {
+			let oracle_id = oracles.add_named(name).transparent(poly.clone())?;
+			oracle_lookup.register_regular(*column_id, oracle_id);
+		}
 		ColumnDef::StructuredDynSize(structured) => {
 			let expr = structured.expr(n_vars)?;
 			let oracle_id = oracles
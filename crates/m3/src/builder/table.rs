@@ -396,6 +396,56 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		)
 	}
 
+	/// Adds a column to the table with fixed values known at circuit definition time.
+	///
+	/// This is useful for preprocessed tables, such as round constants or S-box lookup tables.
+	/// Unlike a constant column, the cell value varies across the rows of the table. The column is
+	/// not committed: the verifier evaluates the multilinear extension of the values directly, so
+	/// the same column can be reused by every statement proven with the constraint system.
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the table does not have a fixed size, set with
+	/// [`Self::require_fixed_size`], or if `values` does not contain exactly `V` values for each
+	/// row of the table.
+	pub fn add_static<FSub, const V: usize>(
+		&mut self,
+		name: impl ToString,
+		values: &[FSub],
+	) -> anyhow::Result<Col<FSub, V>>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+		OptimalUnderlier: PackScalar<FSub> + PackScalar<F>,
+	{
+		let TableSizeSpec::Fixed { log_size } = self.table.table_size_spec else {
+			anyhow::bail!("static columns may only be added to tables with a fixed size");
+		};
+		anyhow::ensure!(
+			values.len() == V << log_size,
+			"static column has {} values, expected {} for a table with {} rows",
+			values.len(),
+			V << log_size,
+			1 << log_size
+		);
+
+		let namespaced_name = self.namespaced_name(name);
+		let n_vars = log2_strict_usize(values.len());
+		let packed_values: Vec<PackedType<OptimalUnderlier, FSub>> = pack_slice(values);
+		let mle = MultilinearExtensionTransparent::<
+			PackedType<OptimalUnderlier, FSub>,
+			PackedType<OptimalUnderlier, F>,
+			_,
+		>::from_values_and_mu(packed_values, n_vars)?;
+		Ok(self.table.new_column(
+			namespaced_name,
+			ColumnDef::Static {
+				poly: Arc::new(mle),
+				data: values.iter().map(|&f_sub| f_sub.into()).collect(),
+			},
+		))
+	}
+
 	/// Adds field exponentiation column with a fixed base
 	///
 	/// ## Parameters
@@ -824,7 +874,7 @@ pub fn log_capacity(table_size: usize) -> usize {
 #[cfg(test)]
 mod tests {
 	use super::{Table, TableBuilder};
-	use crate::builder::B128;
+	use crate::builder::{B8, B128};
 
 	#[test]
 	fn namespace_nesting() {
@@ -834,4 +884,17 @@ mod tests {
 		let tb_ns_2 = tb_ns_1.with_namespace("ns2");
 		assert_eq!(tb_ns_2.namespaced_name("column"), "ns1::ns2::column");
 	}
+
+	#[test]
+	fn add_static_rejects_mismatched_size() {
+		let values = vec![B8::new(1); 8];
+
+		let mut table = Table::<B128>::new(0, "table");
+		let mut tb = TableBuilder::new(&mut table);
+		assert!(tb.add_static::<B8, 1>("static", &values).is_err());
+
+		tb.require_fixed_size(2);
+		assert!(tb.add_static::<B8, 1>("static", &values).is_err());
+		assert!(tb.add_static::<B8, 2>("static", &values).is_ok());
+	}
 }
@@ -138,8 +138,7 @@ pub fn validate_system_witness_with_prove_verify<U>(
 			_,
 		>(
 			&ccs,
-			LOG_INV_RATE,
-			SECURITY_BITS,
+			binius_core::constraint_system::SecurityLevel::new(SECURITY_BITS, LOG_INV_RATE),
 			&statement.boundaries,
 			witness,
 			&binius_hal::make_portable_backend(),
@@ -152,7 +151,12 @@ pub fn validate_system_witness_with_prove_verify<U>(
 			Groestl256,
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
-		>(&ccs, LOG_INV_RATE, SECURITY_BITS, &statement.boundaries, proof)
+		>(
+			&ccs,
+			binius_core::constraint_system::SecurityLevel::new(SECURITY_BITS, LOG_INV_RATE),
+			&statement.boundaries,
+			proof,
+		)
 		.unwrap();
 	}
 }
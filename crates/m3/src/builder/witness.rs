@@ -290,8 +290,8 @@ where
 		+ PackedExtension<B64>
 		+ PackedExtension<B128>,
 {
-	/// Automatically populate the witness data for all the constant columns in all the tables with
-	/// a [`TableWitnessIndex<P>`].
+	/// Automatically populate the witness data for all the constant and static columns in all the
+	/// tables with a [`TableWitnessIndex<P>`].
 	pub fn fill_constant_cols(&mut self) -> Result<(), Error> {
 		for table in self.tables.iter_mut() {
 			match table.as_mut() {
@@ -301,7 +301,9 @@ where
 					let table = table_witness_index.table();
 					let segment = table_witness_index.full_segment();
 					for col in table.columns.iter() {
-						if let ColumnDef::Constant { data, .. } = &col.col {
+						if let ColumnDef::Constant { data, .. } | ColumnDef::Static { data, .. } =
+							&col.col
+						{
 							let mut witness_data = segment.get_dyn_mut(col.id)?;
 							let len = witness_data.size();
 							for (i, scalar) in data.iter().cycle().take(len).enumerate() {
@@ -1298,7 +1300,7 @@ where
 
 #[cfg(test)]
 mod tests {
-	use std::{array, iter::repeat_with};
+	use std::{array, iter, iter::repeat_with};
 
 	use assert_matches::assert_matches;
 	use binius_core::oracle::{MultilinearOracleSet, OracleId};
@@ -1311,6 +1313,7 @@ mod tests {
 	use super::*;
 	use crate::builder::{
 		ConstraintSystem, Statement, TableBuilder,
+		test_utils::{ClosureFiller, validate_system_witness},
 		types::{B1, B8, B16, B32},
 	};
 
@@ -1760,4 +1763,39 @@ mod tests {
 			assert_eq!(got, pack_const_arr[index % 4].into());
 		}
 	}
+
+	#[test]
+	fn test_static_filling() {
+		let log_size = 8;
+		let sbox = (0..1 << log_size)
+			.map(|i| B8::new((i as u8).wrapping_mul(37).wrapping_add(11)))
+			.collect::<Vec<_>>();
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("sbox");
+		table.require_fixed_size(log_size);
+		let table_id = table.id();
+		let expected_col = table.add_committed::<B8, 1>("expected");
+		let sbox_col = table.add_static::<B8, 1>("sbox", &sbox).unwrap();
+		table.assert_zero("expected = sbox", expected_col - sbox_col);
+
+		let allocator = Bump::new();
+		let mut index =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		index
+			.fill_table_sequential(
+				&ClosureFiller::new(table_id, |events, index| {
+					let mut expected_col = index.get_scalars_mut::<B8, 1>(expected_col)?;
+					for (&&i, col_i) in iter::zip(events, &mut *expected_col) {
+						*col_i = sbox[i];
+					}
+					Ok(())
+				}),
+				&(0..1 << log_size).collect::<Vec<_>>(),
+			)
+			.unwrap();
+		index.fill_constant_cols().unwrap();
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, index, vec![]);
+	}
 }
@@ -1,8 +1,21 @@
 // Copyright 2025 Irreducible Inc.
 
+//! High-level models of M3 statements, used to validate traces in tests.
+//!
+//! A statement is modelled by typed event structs, one per table, and a set of channels. Every
+//! event implements [`Event`], which fires the flushes of a row of its table on the channels, and
+//! a trace is valid if the channels are balanced once the boundary values and all events are
+//! flushed. The [`define_channels`](crate::define_channels) and
+//! [`impl_event`](crate::impl_event) macros derive the channel set and the flushing rules, so
+//! that a model only lists the flushes of every table.
+
 use std::{collections::BTreeMap, fmt::Debug};
 
 /// A channel used to validate a high-level M3 trace.
+///
+/// This only checks the balancing of the modelled events. Channels of a constraint system are
+/// created with [`ConstraintSystem::add_channel`](crate::builder::ConstraintSystem::add_channel)
+/// and are compiled to a grand-product argument along with the rest of the system.
 #[derive(Debug)]
 pub struct Channel<T> {
 	net_multiplicities: BTreeMap<T, isize>,
@@ -18,25 +31,30 @@ impl<T> Default for Channel<T> {
 
 impl<T: Eq + PartialEq + Ord + PartialOrd> Channel<T> {
 	pub fn push(&mut self, val: T) {
-		match self.net_multiplicities.get_mut(&val) {
-			Some(multiplicity) => {
-				*multiplicity += 1;
-
-				// Remove the key if the multiplicity is zero, to improve Debug behavior.
-				if *multiplicity == 0 {
-					self.net_multiplicities.remove(&val);
-				}
-			}
-			None => {
-				let _ = self.net_multiplicities.insert(val, 1);
-			}
-		}
+		self.push_with_multiplicity(val, 1);
 	}
 
 	pub fn pull(&mut self, val: T) {
+		self.pull_with_multiplicity(val, 1);
+	}
+
+	/// Pushes `multiplicity` copies of a token, which balances as many pulls of the token.
+	pub fn push_with_multiplicity(&mut self, val: T, multiplicity: usize) {
+		self.flush(val, multiplicity as isize);
+	}
+
+	/// Pulls `multiplicity` copies of a token, which balances as many pushes of the token.
+	pub fn pull_with_multiplicity(&mut self, val: T, multiplicity: usize) {
+		self.flush(val, -(multiplicity as isize));
+	}
+
+	fn flush(&mut self, val: T, delta: isize) {
+		if delta == 0 {
+			return;
+		}
 		match self.net_multiplicities.get_mut(&val) {
 			Some(multiplicity) => {
-				*multiplicity -= 1;
+				*multiplicity += delta;
 
 				// Remove the key if the multiplicity is zero, to improve Debug behavior.
 				if *multiplicity == 0 {
@@ -44,7 +62,7 @@ impl<T: Eq + PartialEq + Ord + PartialOrd> Channel<T> {
 				}
 			}
 			None => {
-				let _ = self.net_multiplicities.insert(val, -1);
+				let _ = self.net_multiplicities.insert(val, delta);
 			}
 		}
 	}
@@ -82,3 +100,233 @@ impl<T: Debug + Ord + PartialOrd> Channel<T> {
 		}
 	}
 }
+
+/// A set of channels flushed by the events of a statement.
+///
+/// Usually derived with [`define_channels`](crate::define_channels).
+pub trait Channels: Default {
+	/// Returns whether all channels are balanced.
+	fn is_balanced(&self) -> bool;
+
+	/// Panics with the unbalanced tokens if some channel is not balanced.
+	fn assert_balanced(&self);
+}
+
+/// An event of a statement, which corresponds to a row of a table.
+///
+/// Usually derived with [`impl_event`](crate::impl_event).
+pub trait Event {
+	/// The channels flushed by the event.
+	type Channels;
+
+	/// Flushes the tokens of the event to the channels.
+	fn fire(&self, channels: &mut Self::Channels);
+}
+
+/// Fires every event of a table.
+pub fn fire_all<'a, E: Event + 'a>(
+	events: impl IntoIterator<Item = &'a E>,
+	channels: &mut E::Channels,
+) {
+	for event in events {
+		event.fire(channels);
+	}
+}
+
+/// Defines a struct of [`Channel`]s, given the token type of every channel, and implements
+/// [`Channels`] for it.
+///
+/// ```
+/// binius_m3::define_channels! {
+///     pub struct FibonacciChannels {
+///         /// Consecutive pairs of the sequence.
+///         sequence: (u32, u32),
+///     }
+/// }
+///
+/// let mut channels = FibonacciChannels::default();
+/// channels.sequence.push((0, 1));
+/// channels.sequence.pull((0, 1));
+/// binius_m3::emulate::Channels::assert_balanced(&channels);
+/// ```
+#[macro_export]
+macro_rules! define_channels {
+	(
+		$(#[$meta:meta])*
+		$vis:vis struct $name:ident {
+			$(
+				$(#[$field_meta:meta])*
+				$field:ident: $token:ty
+			),* $(,)?
+		}
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Default)]
+		$vis struct $name {
+			$(
+				$(#[$field_meta])*
+				pub $field: $crate::emulate::Channel<$token>,
+			)*
+		}
+
+		impl $crate::emulate::Channels for $name {
+			fn is_balanced(&self) -> bool {
+				true $(&& self.$field.is_balanced())*
+			}
+
+			#[track_caller]
+			fn assert_balanced(&self) {
+				$(self.$field.assert_balanced();)*
+			}
+		}
+	};
+}
+
+/// Implements [`Event`] for an event struct, given the flushes of a row.
+///
+/// Every flush is a `push` or a `pull` of a token to a field of the channel struct, optionally
+/// followed by a condition. A token of several values is flushed as a tuple. A multiplicity in
+/// brackets after the direction flushes that many copies of the token at once. The event is bound
+/// to the identifier between bars.
+///
+/// ```
+/// binius_m3::define_channels! {
+///     pub struct CollatzChannels {
+///         sequence: u32,
+///     }
+/// }
+///
+/// pub struct StepEvent {
+///     val: u32,
+///     /// The number of sequences that go through the value.
+///     count: usize,
+/// }
+///
+/// binius_m3::impl_event! {
+///     StepEvent => CollatzChannels |event| {
+///         pull[event.count] sequence(event.val);
+///         push[event.count] sequence(event.val / 2) if event.val % 2 == 0;
+///         push[event.count] sequence(3 * event.val + 1) if event.val % 2 == 1;
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_event {
+	(
+		$event:ty => $channels:ty |$this:ident| {
+			$(
+				$direction:ident$([$multiplicity:expr])? $channel:ident($($value:expr),+ $(,)?)
+					$(if $cond:expr)?;
+			)*
+		}
+	) => {
+		impl $crate::emulate::Event for $event {
+			type Channels = $channels;
+
+			#[allow(unused_variables)]
+			fn fire(&self, channels: &mut Self::Channels) {
+				let $this = self;
+				$(
+					if true $(&& $cond)? {
+						let multiplicity = 1 $(* $multiplicity)?;
+						$crate::__flush!(channels.$channel, $direction, multiplicity, $($value),+);
+					}
+				)*
+			}
+		}
+	};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __flush {
+	($channel:expr, push, $multiplicity:expr, $value:expr) => {
+		$channel.push_with_multiplicity($value, $multiplicity)
+	};
+	($channel:expr, pull, $multiplicity:expr, $value:expr) => {
+		$channel.pull_with_multiplicity($value, $multiplicity)
+	};
+	($channel:expr, $direction:ident, $multiplicity:expr, $first:expr, $($rest:expr),+) => {
+		$crate::__flush!($channel, $direction, $multiplicity, ($first, $($rest),+))
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	define_channels! {
+		struct SequenceChannels {
+			sequence: (u32, u32),
+		}
+	}
+
+	struct StepEvent {
+		index: u32,
+		val: u32,
+		last: bool,
+	}
+
+	impl_event! {
+		StepEvent => SequenceChannels |event| {
+			pull sequence(event.index, event.val);
+			push sequence(event.index + 1, event.val + 1) if !event.last;
+		}
+	}
+
+	#[test]
+	fn test_fire_all() {
+		let events = (0..4)
+			.map(|index| StepEvent {
+				index,
+				val: 10 + index,
+				last: index == 3,
+			})
+			.collect::<Vec<_>>();
+
+		let mut channels = SequenceChannels::default();
+		channels.sequence.push((0, 10));
+		fire_all(&events, &mut channels);
+		channels.assert_balanced();
+
+		let mut channels = SequenceChannels::default();
+		channels.sequence.push((0, 11));
+		fire_all(&events, &mut channels);
+		assert!(!channels.is_balanced());
+	}
+
+	#[test]
+	fn test_flush_with_multiplicity() {
+		let mut channel = Channel::default();
+		channel.push_with_multiplicity(7u32, 3);
+		channel.pull(7);
+		assert!(!channel.is_balanced());
+		channel.pull_with_multiplicity(7, 2);
+		channel.assert_balanced();
+
+		// Flushing no copies leaves the channel unchanged.
+		channel.pull_with_multiplicity(8, 0);
+		channel.assert_balanced();
+	}
+
+	struct RootEvent {
+		root: u32,
+		count: usize,
+	}
+
+	impl_event! {
+		RootEvent => SequenceChannels |event| {
+			pull[event.count] sequence(event.root, event.root);
+		}
+	}
+
+	#[test]
+	fn test_event_with_multiplicity() {
+		let mut channels = SequenceChannels::default();
+		for _ in 0..5 {
+			channels.sequence.push((3, 3));
+		}
+		RootEvent { root: 3, count: 5 }.fire(&mut channels);
+		channels.assert_balanced();
+	}
+}
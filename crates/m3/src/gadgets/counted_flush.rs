@@ -0,0 +1,168 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::{Result, ensure};
+use binius_core::constraint_system::channel::{ChannelId, FlushDirection};
+use binius_field::{ExtensionField, PackedExtension, PackedField, PackedSubfield, TowerField};
+use itertools::Itertools;
+
+use crate::builder::{B1, B128, Col, FlushOpts, TableBuilder, TableWitnessSegment};
+
+/// A flush of the value columns of every row with a prover-chosen multiplicity.
+///
+/// A row flushed with multiplicity `n` balances `n` flushes of the same values in the opposite
+/// direction, so repeated tokens take a single row. The multiplicities are committed in binary,
+/// and the `i`-th bit selects a flush with multiplicity `2^i`. A multiplicity of zero disables the
+/// row.
+#[derive(Debug)]
+pub struct CountedFlush {
+	multiplicity_bits: Vec<Col<B1>>,
+}
+
+impl CountedFlush {
+	pub fn new<FSub>(
+		table: &mut TableBuilder,
+		chan: ChannelId,
+		direction: FlushDirection,
+		value_cols: &[Col<FSub>],
+		n_multiplicity_bits: usize,
+	) -> Self
+	where
+		B128: ExtensionField<FSub>,
+		FSub: TowerField,
+	{
+		let multiplicity_bits = (0..n_multiplicity_bits)
+			.map(|i| table.add_committed::<B1, 1>(format!("multiplicity_bits[{i}]")))
+			.collect::<Vec<_>>();
+
+		for (i, &multiplicity_col) in multiplicity_bits.iter().enumerate() {
+			let opts = FlushOpts {
+				multiplicity: 1 << i,
+				selectors: vec![multiplicity_col],
+			};
+			match direction {
+				FlushDirection::Push => {
+					table.push_with_opts(chan, value_cols.iter().copied(), opts)
+				}
+				FlushDirection::Pull => {
+					table.pull_with_opts(chan, value_cols.iter().copied(), opts)
+				}
+			}
+		}
+
+		Self { multiplicity_bits }
+	}
+
+	/// Populate the multiplicity witness columns.
+	pub fn populate<P>(
+		&self,
+		index: &mut TableWitnessSegment<P>,
+		counts: impl Iterator<Item = u32> + Clone,
+	) -> Result<(), anyhow::Error>
+	where
+		P: PackedExtension<B1>,
+		P::Scalar: TowerField,
+	{
+		if self.multiplicity_bits.len() < u32::BITS as usize {
+			for count in counts.clone() {
+				ensure!(
+					count < (1 << self.multiplicity_bits.len()) as u32,
+					"count {count} exceeds maximum configured multiplicity; \
+					try raising the multiplicity bits in the constraint system"
+				);
+			}
+		}
+
+		// TODO: Optimize the gadget for bit-transposing u32s
+		for (j, &multiplicity_col) in self.multiplicity_bits.iter().enumerate().take(32) {
+			let mut multiplicity_col = index.get_mut(multiplicity_col)?;
+			for (packed, counts) in multiplicity_col
+				.iter_mut()
+				.zip(&counts.clone().chunks(<PackedSubfield<P, B1>>::WIDTH))
+			{
+				for (i, count) in counts.enumerate() {
+					packed.set(i, B1::from((count >> j) & 1 == 1))
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{iter, iter::repeat_with};
+
+	use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use bumpalo::Bump;
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::{
+		ConstraintSystem, WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	#[test]
+	fn test_counted_pull() {
+		let mut cs = ConstraintSystem::new();
+		let chan = cs.add_channel("values");
+
+		// Every row of the pusher table pushes a value once, and every value is pulled by a
+		// single row of the counted table.
+		let mut pusher = cs.add_table("pusher");
+		let pusher_id = pusher.id();
+		let pusher_vals = pusher.add_committed::<B128, 1>("values");
+		pusher.push(chan, [pusher_vals]);
+
+		let mut counted = cs.add_table("counted");
+		let counted_id = counted.id();
+		let counted_vals = counted.add_committed::<B128, 1>("values");
+		let counted_flush =
+			CountedFlush::new(&mut counted, chan, FlushDirection::Pull, &[counted_vals], 5);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let values_and_counts = repeat_with(|| (B128::random(&mut rng), rng.gen_range(0..32u32)))
+			.take(13)
+			.collect::<Vec<_>>();
+		let pushed = values_and_counts
+			.iter()
+			.flat_map(|&(val, count)| iter::repeat_n(val, count as usize))
+			.collect::<Vec<_>>();
+
+		let allocator = Bump::new();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(pusher_id, |pushed, witness| {
+					let mut pusher_vals = witness.get_scalars_mut(pusher_vals)?;
+					for (dst, src) in iter::zip(&mut *pusher_vals, pushed) {
+						*dst = **src;
+					}
+					Ok(())
+				}),
+				&pushed,
+			)
+			.unwrap();
+
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(counted_id, |values_and_counts, witness| {
+					{
+						let mut counted_vals = witness.get_scalars_mut(counted_vals)?;
+						for (dst, (val, _)) in iter::zip(&mut *counted_vals, values_and_counts) {
+							*dst = *val;
+						}
+					}
+					counted_flush
+						.populate(witness, values_and_counts.iter().map(|(_, count)| *count))?;
+					Ok(())
+				}),
+				&values_and_counts,
+			)
+			.unwrap();
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, vec![]);
+	}
+}
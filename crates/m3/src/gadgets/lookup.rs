@@ -1,11 +1,11 @@
 // Copyright 2025 Irreducible Inc.
 
-use anyhow::{Result, ensure};
-use binius_core::constraint_system::channel::ChannelId;
-use binius_field::{ExtensionField, PackedExtension, PackedField, PackedSubfield, TowerField};
-use itertools::Itertools;
+use anyhow::Result;
+use binius_core::constraint_system::channel::{ChannelId, FlushDirection};
+use binius_field::{ExtensionField, PackedExtension, TowerField};
 
-use crate::builder::{B1, B128, Col, FlushOpts, TableBuilder, TableWitnessSegment};
+use super::counted_flush::CountedFlush;
+use crate::builder::{B1, B128, Col, TableBuilder, TableWitnessSegment};
 
 /// A lookup producer gadget is used to create a lookup table.
 ///
@@ -14,7 +14,7 @@ use crate::builder::{B1, B128, Col, FlushOpts, TableBuilder, TableWitnessSegment
 /// times. Table values are given as tuples of column entries.
 #[derive(Debug)]
 pub struct LookupProducer {
-	multiplicity_bits: Vec<Col<B1>>,
+	flush: CountedFlush,
 }
 
 impl LookupProducer {
@@ -28,22 +28,9 @@ impl LookupProducer {
 		B128: ExtensionField<FSub>,
 		FSub: TowerField,
 	{
-		let multiplicity_bits = (0..n_multiplicity_bits)
-			.map(|i| table.add_committed::<B1, 1>(format!("multiplicity_bits[{i}]")))
-			.collect::<Vec<_>>();
-
-		for (i, &multiplicity_col) in multiplicity_bits.iter().enumerate() {
-			table.push_with_opts(
-				chan,
-				value_cols.iter().copied(),
-				FlushOpts {
-					multiplicity: 1 << i,
-					selectors: vec![multiplicity_col],
-				},
-			);
-		}
-
-		Self { multiplicity_bits }
+		let flush =
+			CountedFlush::new(table, chan, FlushDirection::Push, value_cols, n_multiplicity_bits);
+		Self { flush }
 	}
 
 	/// Populate the multiplicity witness columns.
@@ -60,29 +47,7 @@ impl LookupProducer {
 		P: PackedExtension<B1>,
 		P::Scalar: TowerField,
 	{
-		if self.multiplicity_bits.len() < u32::BITS as usize {
-			for count in counts.clone() {
-				ensure!(
-					count < (1 << self.multiplicity_bits.len()) as u32,
-					"count {count} exceeds maximum configured multiplicity; \
-					try raising the multiplicity bits in the constraint system"
-				);
-			}
-		}
-
-		// TODO: Optimize the gadget for bit-transposing u32s
-		for (j, &multiplicity_col) in self.multiplicity_bits.iter().enumerate().take(32) {
-			let mut multiplicity_col = index.get_mut(multiplicity_col)?;
-			for (packed, counts) in multiplicity_col
-				.iter_mut()
-				.zip(&counts.clone().chunks(<PackedSubfield<P, B1>>::WIDTH))
-			{
-				for (i, count) in counts.enumerate() {
-					packed.set(i, B1::from((count >> j) & 1 == 1))
-				}
-			}
-		}
-		Ok(())
+		self.flush.populate(index, counts)
 	}
 }
 
@@ -90,8 +55,9 @@ impl LookupProducer {
 mod tests {
 	use std::{cmp::Reverse, iter, iter::repeat_with};
 
-	use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use binius_field::{PackedField, arch::OptimalUnderlier128b, as_packed_field::PackedType};
 	use bumpalo::Bump;
+	use itertools::Itertools;
 	use rand::{Rng, SeedableRng, rngs::StdRng};
 
 	use super::*;
@@ -2,6 +2,7 @@
 
 pub mod add;
 pub mod barrel_shifter;
+pub mod counted_flush;
 pub mod hash;
 pub mod lookup;
 
@@ -45,6 +45,13 @@
 //! from a channel. _Boundary values_ are the inputs to a statement, which are known to the
 //! verifier.
 //!
+//! Channels are declared with [`builder::ConstraintSystem::add_channel`] and flushed to with
+//! [`builder::TableBuilder::push`] and [`builder::TableBuilder::pull`]. No manual construction of
+//! flush oracles is needed: [`builder::ConstraintSystem::compile`] lowers every flush to a flush
+//! of the core constraint system, and the core prover reduces the channel balancing check to a
+//! batched grand-product argument proven with GKR. The [`emulate::Channel`] type is only a
+//! high-level model for validating traces in tests, and is not part of the compiled system.
+//!
 //! See the [M3] for documentation for further information and examples.
 //!
 //! [M3]: <https://www.binius.xyz/basics/arithmetization/m3>
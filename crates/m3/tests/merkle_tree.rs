@@ -4,7 +4,7 @@
 /// compression function.
 mod model {
 	use binius_hash::groestl::{GroestlShortImpl, GroestlShortInternal};
-	use binius_m3::emulate::Channel;
+	use binius_m3::emulate::{Channels, fire_all};
 	use rand::{Rng, SeedableRng, rngs::StdRng};
 
 	// Signature of the Nodes channel: (Root ID, Data, Depth, Index)
@@ -16,29 +16,22 @@ mod model {
 	/// leaf, and the siblings on the path to the root from the leaf.
 	type MerklePath = (u8, usize, [u8; 32], Vec<[u8; 32]>);
 
-	/// A struct whose fields contain the channels involved in the trace to verify merkle paths for
-	/// a binary merkle tree
-	#[allow(clippy::tabs_in_doc_comments)]
-	pub struct MerkleTreeChannels {
-		/// This channel gets flushed with tokens during "intermediate" steps of the verification
-		/// where the tokens are the values of the parent digest of the claimed siblings along with
-		/// associated position information such as the root it is associated to, the values of
-		/// the child digests, the depth and the index.
-		nodes: Channel<NodeFlushToken>,
-
-		/// This channel contains flushes that validate that the "final" digest obtained in a
-		/// merkle path is matches that of one of the claimed roots, pushed as boundary values.
-		roots: Channel<RootFlushToken>,
-	}
-
-	impl MerkleTreeChannels {
-		pub fn new() -> Self {
-			Self {
-				nodes: Channel::default(),
-				roots: Channel::default(),
-			}
+	binius_m3::define_channels! {
+		/// A struct whose fields contain the channels involved in the trace to verify merkle paths
+		/// for a binary merkle tree
+		pub struct MerkleTreeChannels {
+			/// This channel gets flushed with tokens during "intermediate" steps of the
+			/// verification where the tokens are the values of the parent digest of the claimed
+			/// siblings along with associated position information such as the root it is
+			/// associated to, the values of the child digests, the depth and the index.
+			nodes: NodeFlushToken,
+
+			/// This channel contains flushes that validate that the "final" digest obtained in a
+			/// merkle path is matches that of one of the claimed roots, pushed as boundary values.
+			roots: RootFlushToken,
 		}
 	}
+
 	/// A table representing a step in verifying a merkle path for inclusion.
 	pub struct MerklePathEvent {
 		pub root_id: u8,
@@ -55,11 +48,17 @@ mod model {
 	pub struct MerkleRootEvent {
 		pub root_id: u8,
 		pub digest: [u8; 32],
+		/// The number of paths that end at the root.
+		pub multiplicity: usize,
 	}
 
 	impl MerkleRootEvent {
-		pub fn new(root_id: u8, digest: [u8; 32]) -> Self {
-			Self { root_id, digest }
+		pub fn new(root_id: u8, digest: [u8; 32], multiplicity: usize) -> Self {
+			Self {
+				root_id,
+				digest,
+				multiplicity,
+			}
 		}
 	}
 
@@ -151,41 +150,29 @@ mod model {
 		}
 	}
 
-	impl MerklePathEvent {
-		pub fn fire(&self, node_channel: &mut Channel<NodeFlushToken>) {
-			// Push the parent digest to the nodes channel and optionally pull the left or right
-			// child depending on the flush flags.
-			node_channel.push((self.root_id, self.parent, self.parent_depth, self.parent_index));
-			if self.flush_left {
-				node_channel.pull((
-					self.root_id,
-					self.left,
-					self.parent_depth + 1,
-					2 * self.parent_index,
-				));
-			} else if self.flush_right {
-				node_channel.pull((
-					self.root_id,
-					self.right,
-					self.parent_depth + 1,
-					2 * self.parent_index + 1,
-				));
-			}
+	// Push the parent digest to the nodes channel and pull the left or right child depending on
+	// the flush flags.
+	binius_m3::impl_event! {
+		MerklePathEvent => MerkleTreeChannels |event| {
+			push nodes(event.root_id, event.parent, event.parent_depth, event.parent_index);
+			pull nodes(event.root_id, event.left, event.parent_depth + 1, 2 * event.parent_index)
+				if event.flush_left;
+			pull nodes(
+				event.root_id,
+				event.right,
+				event.parent_depth + 1,
+				2 * event.parent_index + 1,
+			) if event.flush_right;
 		}
 	}
 
-	impl MerkleRootEvent {
-		pub fn fire(
-			&self,
-			node_channel: &mut Channel<NodeFlushToken>,
-			root_channel: &mut Channel<RootFlushToken>,
-		) {
-			// Pull the root node value presumed to have been pushed to the nodes channel from the
-			// merkle path table.
-			node_channel.pull((self.root_id, self.digest, 0, 0));
-			// Pull the root node from the roots channel, presumed to have been pushed as a boundary
-			// value.
-			root_channel.pull((self.root_id, self.digest));
+	// Pull the root node value presumed to have been pushed to the nodes channel from the merkle
+	// path table, and the root from the roots channel, presumed to have been pushed as a boundary
+	// value, once for every path that ends at the root.
+	binius_m3::impl_event! {
+		MerkleRootEvent => MerkleTreeChannels |event| {
+			pull[event.multiplicity] nodes(event.root_id, event.digest, 0, 0);
+			pull[event.multiplicity] roots(event.root_id, event.digest);
 		}
 	}
 
@@ -254,8 +241,8 @@ mod model {
 			}
 
 			for (i, root) in roots.iter().enumerate() {
-				for _ in 0..root_multiplicities[i] {
-					root_vec.push(MerkleRootEvent::new(i as u8, *root));
+				if root_multiplicities[i] > 0 {
+					root_vec.push(MerkleRootEvent::new(i as u8, *root, root_multiplicities[i]));
 				}
 			}
 
@@ -267,26 +254,18 @@ mod model {
 		}
 
 		fn validate(&self) {
-			let mut channels = MerkleTreeChannels::new();
+			let mut channels = MerkleTreeChannels::default();
 			// Push the boundary values to the nodes and roots channels.
 			for boundary in &self.boundaries {
 				channels.nodes.push(boundary.leaf);
 				channels.roots.push(boundary.root);
 			}
 
-			// Push the roots to the roots channel.
-			for root in &self.root {
-				root.fire(&mut channels.nodes, &mut channels.roots);
-			}
-
-			// Push the nodes to the nodes channel.
-			for node in &self.nodes {
-				node.fire(&mut channels.nodes);
-			}
+			fire_all(&self.root, &mut channels);
+			fire_all(&self.nodes, &mut channels);
 
 			// Assert that the nodes and roots channels are balanced.
-			channels.nodes.assert_balanced();
-			channels.roots.assert_balanced();
+			channels.assert_balanced();
 		}
 	}
 
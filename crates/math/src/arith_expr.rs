@@ -689,6 +689,154 @@ impl<F: Field> ArithCircuit<F> {
 
 		self
 	}
+
+	/// Splits the circuit into circuits of degree at most `max_degree` by introducing auxiliary
+	/// variables for high-degree subexpressions.
+	///
+	/// The auxiliary variables are numbered starting from `n_vars`, which must be at least
+	/// [`Self::n_vars`]. The `i`-th auxiliary definition may refer to the original variables and
+	/// to the auxiliary variables preceding it. The original circuit is zero on a point if and
+	/// only if the reduced circuit is zero on the point extended with the values of the
+	/// auxiliary definitions.
+	///
+	/// Common subexpressions are extracted only once.
+	///
+	/// ## Panics
+	///
+	/// * if `max_degree` is less than 2
+	/// * if `n_vars` is less than [`Self::n_vars`]
+	pub fn reduce_degree(&self, n_vars: usize, max_degree: usize) -> DegreeReduction<F> {
+		assert!(max_degree >= 2, "degree can't be reduced below 2");
+		assert!(n_vars >= self.n_vars(), "n_vars is less than the number of circuit variables");
+
+		let circuit = self.clone().optimize();
+		let mut reducer = DegreeReducer {
+			n_vars,
+			max_degree,
+			steps: Vec::with_capacity(circuit.steps.len()),
+			extracted: HashMap::new(),
+			aux_definitions: Vec::new(),
+		};
+
+		// For each step of the original circuit, the index of the corresponding step in the
+		// reducer and its degree.
+		let mut reduced = Vec::<(usize, usize)>::with_capacity(circuit.steps.len());
+		for step in &circuit.steps {
+			let value = match *step {
+				ArithCircuitStep::Const(value) => (reducer.push(ArithCircuitStep::Const(value)), 0),
+				ArithCircuitStep::Var(index) => (reducer.push(ArithCircuitStep::Var(index)), 1),
+				ArithCircuitStep::Add(left, right) => {
+					let (left, left_degree) = reduced[left];
+					let (right, right_degree) = reduced[right];
+					(
+						reducer.push(ArithCircuitStep::Add(left, right)),
+						left_degree.max(right_degree),
+					)
+				}
+				ArithCircuitStep::Mul(left, right) => reducer.mul(reduced[left], reduced[right]),
+				ArithCircuitStep::Pow(base, exp) => reducer.pow(reduced[base], exp),
+			};
+			reduced.push(value);
+		}
+
+		DegreeReduction {
+			aux_definitions: reducer.aux_definitions,
+			circuit: Self {
+				steps: reducer.steps,
+			}
+			.optimize(),
+		}
+	}
+}
+
+/// The result of [`ArithCircuit::reduce_degree`].
+#[derive(Debug, Clone)]
+pub struct DegreeReduction<F: Field> {
+	/// Definitions of the auxiliary variables, in the order of their indices.
+	pub aux_definitions: Vec<ArithCircuit<F>>,
+	/// The circuit over the original and the auxiliary variables.
+	pub circuit: ArithCircuit<F>,
+}
+
+/// Rebuilds a circuit step by step, replacing the operands of products which exceed the maximum
+/// degree with auxiliary variables.
+struct DegreeReducer<F: Field> {
+	n_vars: usize,
+	max_degree: usize,
+	steps: Vec<ArithCircuitStep<F>>,
+	/// Maps the extracted steps to the steps of their auxiliary variables.
+	extracted: HashMap<usize, usize>,
+	aux_definitions: Vec<ArithCircuit<F>>,
+}
+
+impl<F: Field> DegreeReducer<F> {
+	fn push(&mut self, step: ArithCircuitStep<F>) -> usize {
+		self.steps.push(step);
+		self.steps.len() - 1
+	}
+
+	/// Replaces the step with an auxiliary variable, returning the step of the variable.
+	fn extract(&mut self, step: usize) -> usize {
+		if let Some(&var_step) = self.extracted.get(&step) {
+			return var_step;
+		}
+
+		// Steps are pushed in topological order, so the prefix is a circuit computing `step`.
+		let definition = ArithCircuit {
+			steps: self.steps[..=step].to_vec(),
+		}
+		.optimize();
+		let var_index = self.n_vars + self.aux_definitions.len();
+		self.aux_definitions.push(definition);
+
+		let var_step = self.push(ArithCircuitStep::Var(var_index));
+		self.extracted.insert(step, var_step);
+		var_step
+	}
+
+	fn mul(
+		&mut self,
+		(mut left, mut left_degree): (usize, usize),
+		(mut right, mut right_degree): (usize, usize),
+	) -> (usize, usize) {
+		// Terminates because the product of two degree 1 operands never exceeds `max_degree`.
+		while left_degree + right_degree > self.max_degree {
+			if left_degree >= right_degree {
+				left = self.extract(left);
+				left_degree = 1;
+			} else {
+				right = self.extract(right);
+				right_degree = 1;
+			}
+		}
+		(self.push(ArithCircuitStep::Mul(left, right)), left_degree + right_degree)
+	}
+
+	fn pow(&mut self, (base, base_degree): (usize, usize), exp: u64) -> (usize, usize) {
+		let degree = base_degree.saturating_mul(exp as usize);
+		if degree <= self.max_degree {
+			return (self.push(ArithCircuitStep::Pow(base, exp)), degree);
+		}
+
+		// Square-and-multiply, reducing the degree of each product.
+		let mut result = None;
+		let mut power = (base, base_degree);
+		let mut exp = exp;
+		loop {
+			if exp & 1 == 1 {
+				result = Some(match result {
+					Some(result) => self.mul(result, power),
+					None => power,
+				});
+			}
+			exp >>= 1;
+			if exp == 0 {
+				break;
+			}
+			power = self.mul(power, power);
+		}
+		result.expect("exp is non-zero, because the degree is positive")
+	}
 }
 
 impl<F: Field> From<&ArithExpr<F>> for ArithCircuit<F> {
@@ -1153,11 +1301,12 @@ impl Add for EvalCost {
 
 #[cfg(test)]
 mod tests {
-	use std::collections::HashSet;
+	use std::{collections::HashSet, iter::repeat_with};
 
 	use assert_matches::assert_matches;
 	use binius_field::{BinaryField, BinaryField1b, BinaryField8b, BinaryField128b};
 	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+	use rand::{SeedableRng, rngs::StdRng};
 
 	use super::*;
 
@@ -1526,4 +1675,76 @@ mod tests {
 			.unwrap();
 		assert_eq!(result, F::new(2) + F::new(3) * (F::new(4) + F::new(5)).pow(5));
 	}
+
+	fn check_degree_reduction(
+		expr: ArithCircuit<BinaryField128b>,
+		n_vars: usize,
+		max_degree: usize,
+	) -> DegreeReduction<BinaryField128b> {
+		let reduction = expr.reduce_degree(n_vars, max_degree);
+		assert!(reduction.circuit.degree() <= max_degree);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..10 {
+			let mut query = repeat_with(|| BinaryField128b::random(&mut rng))
+				.take(n_vars)
+				.collect::<Vec<_>>();
+			for definition in &reduction.aux_definitions {
+				assert!(definition.degree() <= max_degree);
+				query.push(definition.evaluate(&query).unwrap());
+			}
+			assert_eq!(
+				reduction.circuit.evaluate(&query).unwrap(),
+				expr.evaluate(&query[..n_vars]).unwrap()
+			);
+		}
+		reduction
+	}
+
+	#[test]
+	fn test_reduce_degree_keeps_low_degree_circuit() {
+		let expr = ArithCircuit::var(0) * ArithCircuit::var(1) + ArithCircuit::var(2);
+		let reduction = check_degree_reduction(expr.clone(), 3, 2);
+		assert!(reduction.aux_definitions.is_empty());
+		assert_eq!(reduction.circuit, expr);
+	}
+
+	#[test]
+	fn test_reduce_degree_of_product() {
+		let expr = ArithCircuit::var(0)
+			* ArithCircuit::var(1)
+			* ArithCircuit::var(2)
+			* ArithCircuit::var(3)
+			* ArithCircuit::var(4);
+		let reduction = check_degree_reduction(expr.clone(), 5, 2);
+		assert_eq!(reduction.aux_definitions.len(), 3);
+
+		let reduction = check_degree_reduction(expr, 5, 3);
+		assert_eq!(reduction.aux_definitions.len(), 1);
+	}
+
+	#[test]
+	fn test_reduce_degree_of_pow() {
+		let expr = (ArithCircuit::var(0) * ArithCircuit::var(1)).pow(7) + ArithCircuit::var(2);
+		check_degree_reduction(expr, 3, 2);
+
+		let expr = ArithCircuit::var(0).pow(13);
+		check_degree_reduction(expr, 1, 4);
+	}
+
+	#[test]
+	fn test_reduce_degree_extracts_common_subexpression_once() {
+		let common = ArithCircuit::var(0) * ArithCircuit::var(1) * ArithCircuit::var(2);
+		let expr = common.clone() * ArithCircuit::var(3) + common * ArithCircuit::var(4);
+		let reduction = check_degree_reduction(expr, 5, 3);
+		assert_eq!(reduction.aux_definitions.len(), 1);
+	}
+
+	#[test]
+	fn test_reduce_degree_with_unused_vars() {
+		let expr = ArithCircuit::var(0).pow(3);
+		let reduction = check_degree_reduction(expr, 4, 2);
+		assert_eq!(reduction.aux_definitions.len(), 1);
+		assert_eq!(reduction.circuit.n_vars(), 5);
+	}
 }
@@ -579,6 +579,60 @@ where
 	Ok(())
 }
 
+/// Inplace right linear interpolation (lerp, single variable) fold.
+///
+/// Computes the same result as [`fold_right_lerp`] with `PE = P`, writing the folded evaluations
+/// to the beginning of `evals` and truncating it, so that no new vector is allocated in each
+/// sumcheck round. Whole pairs of packed elements are deinterleaved with [`PackedField::unzip`],
+/// which has SIMD implementations for the small packed fields, including the 1-bit and 8-bit ones.
+///
+/// Please note that this method is single threaded, see [`fold_right_lerp`].
+pub fn fold_right_lerp_inplace<P>(
+	evals: &mut Vec<P>,
+	evals_size: usize,
+	lerp_query: P::Scalar,
+	suffix_eval: P::Scalar,
+) -> Result<(), Error>
+where
+	P: PackedField,
+{
+	check_right_lerp_fold_arguments::<_, P, _>(evals, evals_size, evals)?;
+
+	let folded_evals_size = evals_size >> 1;
+
+	// The output at index `i` is computed from the inputs at indices `2i` and `2i + 1`, which are
+	// never overwritten before being read.
+	let n_packed_pairs = if P::LOG_WIDTH > 0 {
+		evals_size >> (P::LOG_WIDTH + 1)
+	} else {
+		0
+	};
+	for i in 0..n_packed_pairs {
+		let (eval0, eval1) = evals[2 * i].unzip(evals[2 * i + 1], 0);
+		evals[i] = eval0 + (eval1 - eval0) * lerp_query;
+	}
+	for index in n_packed_pairs << P::LOG_WIDTH..folded_evals_size {
+		let eval0 = get_packed_slice(evals, index << 1);
+		let eval1 = get_packed_slice(evals, (index << 1) | 1);
+		set_packed_slice(evals, index, eval0 + (eval1 - eval0) * lerp_query);
+	}
+
+	if evals_size % 2 == 1 {
+		let eval0 = get_packed_slice(evals, folded_evals_size << 1);
+		set_packed_slice(evals, folded_evals_size, eval0 + (suffix_eval - eval0) * lerp_query);
+	}
+
+	let new_evals_size = evals_size.div_ceil(2);
+	evals.truncate(new_evals_size.div_ceil(P::WIDTH).max(1));
+	if let Some(first) = evals.first_mut() {
+		for i in new_evals_size.min(P::WIDTH)..P::WIDTH {
+			first.set(i, P::Scalar::ZERO);
+		}
+	}
+
+	Ok(())
+}
+
 /// Left linear interpolation (lerp, single variable) fold
 ///
 /// Please note that this method is single threaded. Currently we always have some
@@ -1370,6 +1424,33 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_fold_right_lerp_inplace_conforms_reference() {
+		fn check<P: PackedField>(log_evals_size: usize) {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut evals = repeat_with(|| P::random(&mut rng))
+				.take(1 << log_evals_size.saturating_sub(P::LOG_WIDTH))
+				.collect::<Vec<_>>();
+			let lerp_query = <P::Scalar as Field>::random(&mut rng);
+
+			for evals_size in [(1 << log_evals_size) - 1, 1 << (log_evals_size - 1)] {
+				let suffix_eval = <P::Scalar as Field>::random(&mut rng);
+				let mut out = vec![P::zero(); evals_size.div_ceil(2 * P::WIDTH)];
+				fold_right_lerp(&evals, evals_size, lerp_query, suffix_eval, &mut out).unwrap();
+				fold_right_lerp_inplace(&mut evals, evals_size, lerp_query, suffix_eval).unwrap();
+
+				for i in 0..evals_size.div_ceil(2) {
+					assert_eq!(get_packed_slice(&evals, i), get_packed_slice(&out, i));
+				}
+			}
+		}
+
+		check::<PackedBinaryField128x1b>(10);
+		check::<PackedBinaryField16x8b>(10);
+		check::<PackedBinaryField16x32b>(10);
+		check::<PackedBinaryField16x32b>(3);
+	}
+
 	#[test]
 	fn test_check_fold_arguments_valid() {
 		let evals = vec![PackedBinaryField128x1b::default(); 8];
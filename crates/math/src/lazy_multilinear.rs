@@ -0,0 +1,362 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use binius_field::{Field, PackedField, packed::get_packed_slice};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+
+use super::{
+	Error, MultilinearExtension, MultilinearPoly, MultilinearQueryRef,
+	mle_adapters::validate_subcube_partial_evals_params,
+};
+
+/// A multilinear polynomial whose hypercube evaluations are computed on demand.
+///
+/// The evaluation at the hypercube vertex with index `i` is given by a closure, so structured
+/// polynomials, like transparent columns, can be used as [`MultilinearPoly`] trait objects without
+/// ever materializing their $2^n$ evaluations. Partial evaluations and subcubes are computed
+/// directly from the closure; only [`MultilinearPoly::zero_pad`] materializes the polynomial.
+pub struct LazyMultilinear<P, Eval>
+where
+	P: PackedField,
+	Eval: Fn(usize) -> P::Scalar + Send + Sync,
+{
+	n_vars: usize,
+	eval: Eval,
+	_p_marker: PhantomData<P>,
+}
+
+impl<P, Eval> LazyMultilinear<P, Eval>
+where
+	P: PackedField,
+	Eval: Fn(usize) -> P::Scalar + Send + Sync,
+{
+	/// Creates a polynomial with `n_vars` variables whose evaluation at the hypercube vertex with
+	/// index `i` is `eval(i)`.
+	pub const fn new(n_vars: usize, eval: Eval) -> Self {
+		Self {
+			n_vars,
+			eval,
+			_p_marker: PhantomData,
+		}
+	}
+
+	/// Computes all hypercube evaluations.
+	pub fn materialize(&self) -> Result<MultilinearExtension<P>, Error> {
+		let mut evals = vec![P::zero(); 1 << self.n_vars.saturating_sub(P::LOG_WIDTH)];
+		self.subcube_evals(self.n_vars, 0, 0, &mut evals)?;
+		MultilinearExtension::new(self.n_vars, evals)
+	}
+
+	/// Evaluates the polynomial at the hypercube vertex `out_index` of the partial evaluation at
+	/// `query` on the variables starting at `start_index`.
+	fn fold_scalar(
+		&self,
+		query: MultilinearQueryRef<P>,
+		start_index: usize,
+		out_index: usize,
+	) -> P::Scalar {
+		let query_n_vars = query.n_vars();
+		let low = out_index & ((1 << start_index) - 1);
+		let high = (out_index >> start_index) << (start_index + query_n_vars);
+		(0..1 << query_n_vars)
+			.map(|i| {
+				get_packed_slice(query.expansion(), i) * (self.eval)(high | i << start_index | low)
+			})
+			.sum()
+	}
+
+	/// Fills `out` with the scalars `f(0), f(1), ...`, up to `1 << n_vars` scalars, and pads the
+	/// remaining scalars with zeros.
+	fn fill_packed(out: &mut [P], n_vars: usize, f: impl Fn(usize) -> P::Scalar + Sync) {
+		out.par_iter_mut().enumerate().for_each(|(i, packed)| {
+			*packed = P::from_fn(|j| {
+				let index = i << P::LOG_WIDTH | j;
+				if index < 1 << n_vars {
+					f(index)
+				} else {
+					P::Scalar::ZERO
+				}
+			});
+		});
+	}
+}
+
+impl<'a, P, Eval> LazyMultilinear<P, Eval>
+where
+	P: PackedField,
+	Eval: Fn(usize) -> P::Scalar + Send + Sync + 'a,
+{
+	pub fn upcast_arc_dyn(self) -> Arc<dyn MultilinearPoly<P> + Send + Sync + 'a> {
+		Arc::new(self)
+	}
+}
+
+impl<P, Eval> Debug for LazyMultilinear<P, Eval>
+where
+	P: PackedField,
+	Eval: Fn(usize) -> P::Scalar + Send + Sync,
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("LazyMultilinear")
+			.field("n_vars", &self.n_vars)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<F, P, Eval> MultilinearPoly<P> for LazyMultilinear<P, Eval>
+where
+	F: Field,
+	P: PackedField<Scalar = F>,
+	Eval: Fn(usize) -> F + Send + Sync,
+{
+	#[inline]
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	#[inline]
+	fn log_extension_degree(&self) -> usize {
+		0
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<F, Error> {
+		let max_index = 1 << self.n_vars;
+		if index >= max_index {
+			bail!(Error::ArgumentRangeError {
+				arg: "index".to_string(),
+				range: 0..max_index,
+			});
+		}
+		Ok((self.eval)(index))
+	}
+
+	fn evaluate_on_hypercube_and_scale(&self, index: usize, scalar: F) -> Result<F, Error> {
+		let eval = self.evaluate_on_hypercube(index)?;
+		Ok(scalar * eval)
+	}
+
+	fn evaluate(&self, query: MultilinearQueryRef<P>) -> Result<F, Error> {
+		if query.n_vars() != self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+				actual: query.n_vars()
+			});
+		}
+		Ok(self.fold_scalar(query, 0, 0))
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.evaluate_partial(query, 0)
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		if query.n_vars() > self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+				actual: query.n_vars()
+			});
+		}
+		self.evaluate_partial(query, self.n_vars - query.n_vars())
+	}
+
+	fn evaluate_partial(
+		&self,
+		query: MultilinearQueryRef<P>,
+		start_index: usize,
+	) -> Result<MultilinearExtension<P>, Error> {
+		if start_index + query.n_vars() > self.n_vars {
+			bail!(Error::IncorrectStartIndex {
+				expected: self.n_vars
+			});
+		}
+
+		let new_n_vars = self.n_vars - query.n_vars();
+		let mut evals = vec![P::zero(); 1 << new_n_vars.saturating_sub(P::LOG_WIDTH)];
+		Self::fill_packed(&mut evals, new_n_vars, |index| {
+			self.fold_scalar(query, start_index, index)
+		});
+		MultilinearExtension::new(new_n_vars, evals)
+	}
+
+	fn zero_pad(
+		&self,
+		n_pad_vars: usize,
+		start_index: usize,
+		nonzero_index: usize,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.materialize()?
+			.zero_pad(n_pad_vars, start_index, nonzero_index)
+	}
+
+	fn subcube_partial_low_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_low_evals: &mut [P],
+	) -> Result<(), Error> {
+		validate_subcube_partial_evals_params(
+			self.n_vars,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_low_evals,
+		)?;
+
+		let subcube_start = subcube_index << subcube_vars;
+		Self::fill_packed(partial_low_evals, subcube_vars, |index| {
+			self.fold_scalar(query, 0, subcube_start | index)
+		});
+		Ok(())
+	}
+
+	fn subcube_partial_high_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_high_evals: &mut [P],
+	) -> Result<(), Error> {
+		validate_subcube_partial_evals_params(
+			self.n_vars,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_high_evals,
+		)?;
+
+		let start_index = self.n_vars - query.n_vars();
+		let subcube_start = subcube_index << subcube_vars;
+		Self::fill_packed(partial_high_evals, subcube_vars, |index| {
+			self.fold_scalar(query, start_index, subcube_start | index)
+		});
+		Ok(())
+	}
+
+	fn subcube_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		log_embedding_degree: usize,
+		evals: &mut [P],
+	) -> Result<(), Error> {
+		let n_vars = self.n_vars;
+		if subcube_vars > n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_vars".to_string(),
+				range: 0..n_vars + 1,
+			});
+		}
+
+		if log_embedding_degree != 0 {
+			bail!(Error::LogEmbeddingDegreeTooLarge {
+				log_embedding_degree
+			});
+		}
+
+		let correct_len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+		if evals.len() != correct_len {
+			bail!(Error::ArgumentRangeError {
+				arg: "evals.len()".to_string(),
+				range: correct_len..correct_len + 1,
+			});
+		}
+
+		let max_index = 1 << (n_vars - subcube_vars);
+		if subcube_index >= max_index {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_index".to_string(),
+				range: 0..max_index,
+			});
+		}
+
+		let subcube_start = subcube_index << subcube_vars;
+		Self::fill_packed(evals, subcube_vars, |index| (self.eval)(subcube_start | index));
+		Ok(())
+	}
+
+	fn packed_evals(&self) -> Option<&[P]> {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField32b, PackedBinaryField4x32b, PackedField};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{MLEDirectAdapter, MultilinearQuery};
+
+	type F = BinaryField32b;
+	type P = PackedBinaryField4x32b;
+
+	#[test]
+	fn test_lazy_multilinear_matches_materialized() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 7;
+		let values = repeat_with(|| F::random(&mut rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+
+		let lazy = LazyMultilinear::<P, _>::new(n_vars, |i| values[i]);
+		let direct = MLEDirectAdapter::from(lazy.materialize().unwrap());
+		assert!(P::iter_slice(direct.as_ref().evals()).eq(values.iter().copied()));
+
+		let q = repeat_with(|| F::random(&mut rng))
+			.take(3)
+			.collect::<Vec<_>>();
+		let query = MultilinearQuery::<P>::expand(&q);
+		let query = query.to_ref();
+
+		assert_eq!(
+			lazy.evaluate_partial_low(query).unwrap(),
+			direct.evaluate_partial_low(query).unwrap()
+		);
+		assert_eq!(
+			lazy.evaluate_partial_high(query).unwrap(),
+			direct.evaluate_partial_high(query).unwrap()
+		);
+		assert_eq!(
+			lazy.evaluate_partial(query, 2).unwrap(),
+			direct.evaluate_partial(query, 2).unwrap()
+		);
+
+		let mut lazy_evals = vec![P::zero(); 2];
+		let mut direct_evals = vec![P::zero(); 2];
+		lazy.subcube_partial_low_evals(query, 3, 1, &mut lazy_evals)
+			.unwrap();
+		direct
+			.subcube_partial_low_evals(query, 3, 1, &mut direct_evals)
+			.unwrap();
+		assert_eq!(lazy_evals, direct_evals);
+
+		lazy.subcube_partial_high_evals(query, 3, 1, &mut lazy_evals)
+			.unwrap();
+		direct
+			.subcube_partial_high_evals(query, 3, 1, &mut direct_evals)
+			.unwrap();
+		assert_eq!(lazy_evals, direct_evals);
+
+		let full_q = repeat_with(|| F::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		let full_query = MultilinearQuery::<P>::expand(&full_q);
+		assert_eq!(
+			lazy.evaluate(full_query.to_ref()).unwrap(),
+			direct.evaluate(full_query.to_ref()).unwrap()
+		);
+		assert!(lazy.evaluate(query).is_err());
+		assert!(lazy.evaluate_on_hypercube(1 << n_vars).is_err());
+	}
+}
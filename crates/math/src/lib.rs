@@ -19,6 +19,7 @@ mod composition_poly;
 mod error;
 mod evaluation_order;
 mod fold;
+mod lazy_multilinear;
 mod matrix;
 mod mle_adapters;
 mod multilinear;
@@ -27,6 +28,7 @@ mod multilinear_query;
 mod packing_deref;
 mod piecewise_multilinear;
 mod rows_batch;
+mod sparse_multilinear;
 mod tensor_prod_eq_ind;
 mod univariate;
 
@@ -36,6 +38,7 @@ pub use composition_poly::*;
 pub use error::*;
 pub use evaluation_order::*;
 pub use fold::*;
+pub use lazy_multilinear::*;
 pub use matrix::*;
 pub use mle_adapters::*;
 pub use multilinear::*;
@@ -44,5 +47,6 @@ pub use multilinear_query::*;
 pub use packing_deref::*;
 pub use piecewise_multilinear::*;
 pub use rows_batch::*;
+pub use sparse_multilinear::*;
 pub use tensor_prod_eq_ind::*;
 pub use univariate::*;
@@ -573,7 +573,7 @@ where
 	}
 }
 
-fn validate_subcube_partial_evals_params<P: PackedField>(
+pub(crate) fn validate_subcube_partial_evals_params<P: PackedField>(
 	n_vars: usize,
 	query: MultilinearQueryRef<P>,
 	subcube_vars: usize,
@@ -13,7 +13,8 @@ use bytemuck::zeroed_vec;
 use tracing::instrument;
 
 use crate::{
-	Error, MultilinearQueryRef, PackingDeref, fold::fold_left, fold_middle, fold_right, zero_pad,
+	Error, MultilinearQueryRef, PackingDeref, fold::fold_left, fold_left_lerp_inplace, fold_middle,
+	fold_right, fold_right_lerp_inplace, zero_pad,
 };
 
 /// A multilinear polynomial represented by its evaluations over the boolean hypercube.
@@ -51,6 +52,40 @@ impl<P: PackedField> MultilinearExtension<P> {
 	pub fn into_evals(self) -> Vec<P> {
 		self.evals
 	}
+
+	/// Partially evaluates the polynomial at `challenge` on its lowest variable, in place.
+	///
+	/// This is equivalent to [`Self::evaluate_partial_low`] with a single variable query, but
+	/// reuses the evaluation vector instead of allocating a new one.
+	pub fn fold_low_var(&mut self, challenge: P::Scalar) -> Result<(), Error> {
+		if self.mu == 0 {
+			bail!(Error::IncorrectQuerySize {
+				expected: 0,
+				actual: 1
+			});
+		}
+
+		fold_right_lerp_inplace(&mut self.evals, 1 << self.mu, challenge, P::Scalar::ZERO)?;
+		self.mu -= 1;
+		Ok(())
+	}
+
+	/// Partially evaluates the polynomial at `challenge` on its highest variable, in place.
+	///
+	/// This is equivalent to [`Self::evaluate_partial_high`] with a single variable query, but
+	/// reuses the evaluation vector instead of allocating a new one.
+	pub fn fold_high_var(&mut self, challenge: P::Scalar) -> Result<(), Error> {
+		if self.mu == 0 {
+			bail!(Error::IncorrectQuerySize {
+				expected: 0,
+				actual: 1
+			});
+		}
+
+		fold_left_lerp_inplace(&mut self.evals, 1 << self.mu, P::Scalar::ZERO, self.mu, challenge)?;
+		self.mu -= 1;
+		Ok(())
+	}
 }
 
 impl<P: PackedField, Data: Deref<Target = [P]>> MultilinearExtension<P, Data> {
@@ -856,6 +891,41 @@ mod tests {
 		assert_eq!(eval_1, eval_2);
 	}
 
+	#[test]
+	fn test_fold_var_inplace_matches_evaluate_partial() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let values: Vec<_> = repeat_with(|| PackedBinaryField4x32b::random(&mut rng))
+			.take(1 << 4)
+			.collect();
+
+		let mut mle_low = MultilinearExtension::from_values(values).unwrap();
+		let mut mle_high = mle_low.clone();
+		while mle_low.n_vars() > 0 {
+			let r = <BinaryField32b as PackedField>::random(&mut rng);
+			let query = multilinear_query(&[r]);
+
+			let expected_low = mle_low.evaluate_partial_low(query.to_ref()).unwrap();
+			let expected_high = mle_high.evaluate_partial_high(query.to_ref()).unwrap();
+			mle_low.fold_low_var(r).unwrap();
+			mle_high.fold_high_var(r).unwrap();
+
+			assert_eq!(mle_low.n_vars(), expected_low.n_vars());
+			assert_eq!(mle_high.n_vars(), expected_high.n_vars());
+			for i in 0..1 << mle_low.n_vars() {
+				assert_eq!(
+					mle_low.evaluate_on_hypercube(i).unwrap(),
+					expected_low.evaluate_on_hypercube(i).unwrap()
+				);
+				assert_eq!(
+					mle_high.evaluate_on_hypercube(i).unwrap(),
+					expected_high.evaluate_on_hypercube(i).unwrap()
+				);
+			}
+		}
+
+		assert!(mle_low.fold_low_var(BinaryField32b::ONE).is_err());
+	}
+
 	#[test]
 	fn test_new_mle_with_tiny_nvars() {
 		MultilinearExtension::new(
@@ -0,0 +1,458 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{collections::BTreeMap, fmt::Debug, marker::PhantomData, ops::Range, sync::Arc};
+
+use binius_field::{
+	Field, PackedField,
+	packed::{get_packed_slice, set_packed_slice},
+};
+use binius_utils::bail;
+
+use super::{
+	Error, MultilinearExtension, MultilinearPoly, MultilinearQueryRef,
+	mle_adapters::validate_subcube_partial_evals_params,
+};
+
+/// A multilinear polynomial represented by its nonzero hypercube evaluations.
+///
+/// The evaluations are stored as a list of `(index, value)` pairs sorted by index, so that
+/// evaluation, partial evaluation and folding take time proportional to the number of nonzero
+/// evaluations rather than to the size of the hypercube. This is well suited for selector columns,
+/// which are zero on almost all rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMultilinear<P: PackedField> {
+	n_vars: usize,
+	entries: Vec<(usize, P::Scalar)>,
+	_p_marker: PhantomData<P>,
+}
+
+impl<P: PackedField> SparseMultilinear<P> {
+	/// Creates a polynomial with `n_vars` variables from its nonzero evaluations.
+	///
+	/// Entries may be given in any order. If an index appears several times, the last value is
+	/// kept. Zero values are dropped.
+	pub fn new(
+		n_vars: usize,
+		entries: impl IntoIterator<Item = (usize, P::Scalar)>,
+	) -> Result<Self, Error> {
+		let entries = entries.into_iter().collect::<BTreeMap<_, _>>();
+		if let Some((&index, _)) = entries.last_key_value() {
+			if index >= 1 << n_vars {
+				bail!(Error::ArgumentRangeError {
+					arg: "index".to_string(),
+					range: 0..1 << n_vars,
+				});
+			}
+		}
+
+		Ok(Self::from_sorted_unchecked(
+			n_vars,
+			entries
+				.into_iter()
+				.filter(|(_, value)| *value != P::Scalar::ZERO)
+				.collect(),
+		))
+	}
+
+	fn from_sorted_unchecked(n_vars: usize, entries: Vec<(usize, P::Scalar)>) -> Self {
+		Self {
+			n_vars,
+			entries,
+			_p_marker: PhantomData,
+		}
+	}
+
+	/// The nonzero evaluations, sorted by hypercube index.
+	pub fn entries(&self) -> &[(usize, P::Scalar)] {
+		&self.entries
+	}
+
+	/// The number of nonzero evaluations.
+	pub fn n_nonzero(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Computes all hypercube evaluations.
+	pub fn materialize(&self) -> Result<MultilinearExtension<P>, Error> {
+		let mut evals = vec![P::zero(); 1 << self.n_vars.saturating_sub(P::LOG_WIDTH)];
+		for &(index, value) in &self.entries {
+			set_packed_slice(&mut evals, index, value);
+		}
+		MultilinearExtension::new(self.n_vars, evals)
+	}
+
+	/// Partially evaluates the polynomial at `query` on the variables starting at `start_index`,
+	/// keeping the result sparse.
+	///
+	/// This runs in time proportional to the number of nonzero evaluations.
+	pub fn fold(&self, query: MultilinearQueryRef<P>, start_index: usize) -> Result<Self, Error> {
+		self.check_partial_eval(query, start_index)?;
+
+		let mut entries = BTreeMap::<usize, P::Scalar>::new();
+		for &(index, value) in &self.entries {
+			let (out_index, query_index) = Self::split_index(index, query.n_vars(), start_index);
+			*entries.entry(out_index).or_default() +=
+				value * get_packed_slice(query.expansion(), query_index);
+		}
+
+		Ok(Self::from_sorted_unchecked(
+			self.n_vars - query.n_vars(),
+			entries
+				.into_iter()
+				.filter(|(_, value)| *value != P::Scalar::ZERO)
+				.collect(),
+		))
+	}
+
+	fn check_partial_eval(
+		&self,
+		query: MultilinearQueryRef<P>,
+		start_index: usize,
+	) -> Result<(), Error> {
+		if start_index + query.n_vars() > self.n_vars {
+			bail!(Error::IncorrectStartIndex {
+				expected: self.n_vars
+			});
+		}
+		Ok(())
+	}
+
+	/// Splits a hypercube index into the index of the vertex after partial evaluation on the
+	/// `query_n_vars` variables starting at `start_index`, and the index into the query.
+	#[inline]
+	const fn split_index(index: usize, query_n_vars: usize, start_index: usize) -> (usize, usize) {
+		let low = index & ((1 << start_index) - 1);
+		let query_index = (index >> start_index) & ((1 << query_n_vars) - 1);
+		let high = index >> (start_index + query_n_vars);
+		(high << start_index | low, query_index)
+	}
+
+	/// The entries with hypercube indices in the given range.
+	fn entries_in(&self, range: Range<usize>) -> &[(usize, P::Scalar)] {
+		let start = self
+			.entries
+			.partition_point(|&(index, _)| index < range.start);
+		let end = self
+			.entries
+			.partition_point(|&(index, _)| index < range.end);
+		&self.entries[start..end]
+	}
+
+	fn evaluate_partial_dense(
+		&self,
+		query: MultilinearQueryRef<P>,
+		start_index: usize,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.check_partial_eval(query, start_index)?;
+
+		let new_n_vars = self.n_vars - query.n_vars();
+		let mut evals = vec![P::zero(); 1 << new_n_vars.saturating_sub(P::LOG_WIDTH)];
+		for &(index, value) in &self.entries {
+			let (out_index, query_index) = Self::split_index(index, query.n_vars(), start_index);
+			let eval = get_packed_slice(&evals, out_index)
+				+ value * get_packed_slice(query.expansion(), query_index);
+			set_packed_slice(&mut evals, out_index, eval);
+		}
+		MultilinearExtension::new(new_n_vars, evals)
+	}
+}
+
+impl<'a, P: PackedField> SparseMultilinear<P> {
+	pub fn upcast_arc_dyn(self) -> Arc<dyn MultilinearPoly<P> + Send + Sync + 'a> {
+		Arc::new(self)
+	}
+}
+
+impl<F, P> MultilinearPoly<P> for SparseMultilinear<P>
+where
+	F: Field,
+	P: PackedField<Scalar = F>,
+{
+	#[inline]
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	#[inline]
+	fn log_extension_degree(&self) -> usize {
+		0
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<F, Error> {
+		let max_index = 1 << self.n_vars;
+		if index >= max_index {
+			bail!(Error::ArgumentRangeError {
+				arg: "index".to_string(),
+				range: 0..max_index,
+			});
+		}
+		let eval = self
+			.entries
+			.binary_search_by_key(&index, |&(index, _)| index)
+			.map_or(F::ZERO, |position| self.entries[position].1);
+		Ok(eval)
+	}
+
+	fn evaluate_on_hypercube_and_scale(&self, index: usize, scalar: F) -> Result<F, Error> {
+		let eval = self.evaluate_on_hypercube(index)?;
+		Ok(scalar * eval)
+	}
+
+	fn evaluate(&self, query: MultilinearQueryRef<P>) -> Result<F, Error> {
+		if query.n_vars() != self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+				actual: query.n_vars()
+			});
+		}
+		Ok(self
+			.entries
+			.iter()
+			.map(|&(index, value)| value * get_packed_slice(query.expansion(), index))
+			.sum())
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.evaluate_partial_dense(query, 0)
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		if query.n_vars() > self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+				actual: query.n_vars()
+			});
+		}
+		self.evaluate_partial_dense(query, self.n_vars - query.n_vars())
+	}
+
+	fn evaluate_partial(
+		&self,
+		query: MultilinearQueryRef<P>,
+		start_index: usize,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.evaluate_partial_dense(query, start_index)
+	}
+
+	fn zero_pad(
+		&self,
+		n_pad_vars: usize,
+		start_index: usize,
+		nonzero_index: usize,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.materialize()?
+			.zero_pad(n_pad_vars, start_index, nonzero_index)
+	}
+
+	fn subcube_partial_low_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_low_evals: &mut [P],
+	) -> Result<(), Error> {
+		validate_subcube_partial_evals_params(
+			self.n_vars,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_low_evals,
+		)?;
+
+		let query_n_vars = query.n_vars();
+		let subcube_start = subcube_index << (query_n_vars + subcube_vars);
+		let subcube_end = (subcube_index + 1) << (query_n_vars + subcube_vars);
+
+		partial_low_evals.fill(P::zero());
+		for &(index, value) in self.entries_in(subcube_start..subcube_end) {
+			let (out_index, query_index) = Self::split_index(index, query_n_vars, 0);
+			let out_index = out_index - (subcube_index << subcube_vars);
+			let eval = get_packed_slice(partial_low_evals, out_index)
+				+ value * get_packed_slice(query.expansion(), query_index);
+			set_packed_slice(partial_low_evals, out_index, eval);
+		}
+		Ok(())
+	}
+
+	fn subcube_partial_high_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_high_evals: &mut [P],
+	) -> Result<(), Error> {
+		validate_subcube_partial_evals_params(
+			self.n_vars,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_high_evals,
+		)?;
+
+		let query_n_vars = query.n_vars();
+		let start_index = self.n_vars - query_n_vars;
+		let subcube_start = subcube_index << subcube_vars;
+
+		partial_high_evals.fill(P::zero());
+		for query_index in 0..1 << query_n_vars {
+			let range_start = query_index << start_index | subcube_start;
+			let entries = self.entries_in(range_start..range_start + (1 << subcube_vars));
+			if entries.is_empty() {
+				continue;
+			}
+
+			let query_factor = get_packed_slice(query.expansion(), query_index);
+			for &(index, value) in entries {
+				let out_index = index - range_start;
+				let eval = get_packed_slice(partial_high_evals, out_index) + value * query_factor;
+				set_packed_slice(partial_high_evals, out_index, eval);
+			}
+		}
+		Ok(())
+	}
+
+	fn subcube_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		log_embedding_degree: usize,
+		evals: &mut [P],
+	) -> Result<(), Error> {
+		let n_vars = self.n_vars;
+		if subcube_vars > n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_vars".to_string(),
+				range: 0..n_vars + 1,
+			});
+		}
+
+		if log_embedding_degree != 0 {
+			bail!(Error::LogEmbeddingDegreeTooLarge {
+				log_embedding_degree
+			});
+		}
+
+		let correct_len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+		if evals.len() != correct_len {
+			bail!(Error::ArgumentRangeError {
+				arg: "evals.len()".to_string(),
+				range: correct_len..correct_len + 1,
+			});
+		}
+
+		let max_index = 1 << (n_vars - subcube_vars);
+		if subcube_index >= max_index {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_index".to_string(),
+				range: 0..max_index,
+			});
+		}
+
+		let subcube_start = subcube_index << subcube_vars;
+		evals.fill(P::zero());
+		for &(index, value) in self.entries_in(subcube_start..subcube_start + (1 << subcube_vars)) {
+			set_packed_slice(evals, index - subcube_start, value);
+		}
+		Ok(())
+	}
+
+	fn packed_evals(&self) -> Option<&[P]> {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField32b, PackedBinaryField4x32b, PackedField};
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{MLEDirectAdapter, MultilinearQuery};
+
+	type F = BinaryField32b;
+	type P = PackedBinaryField4x32b;
+
+	#[test]
+	fn test_sparse_multilinear_matches_materialized() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 8;
+		let sparse = SparseMultilinear::<P>::new(
+			n_vars,
+			repeat_with(|| (rng.gen_range(0..1 << n_vars), F::random(&mut rng))).take(20),
+		)
+		.unwrap();
+		let dense = MLEDirectAdapter::from(sparse.materialize().unwrap());
+
+		for index in 0..1 << n_vars {
+			assert_eq!(
+				sparse.evaluate_on_hypercube(index).unwrap(),
+				dense.evaluate_on_hypercube(index).unwrap()
+			);
+		}
+
+		let q = repeat_with(|| F::random(&mut rng))
+			.take(3)
+			.collect::<Vec<_>>();
+		let query = MultilinearQuery::<P>::expand(&q);
+		let query = query.to_ref();
+
+		assert_eq!(
+			sparse.evaluate_partial_low(query).unwrap(),
+			dense.evaluate_partial_low(query).unwrap()
+		);
+		assert_eq!(
+			sparse.evaluate_partial_high(query).unwrap(),
+			dense.evaluate_partial_high(query).unwrap()
+		);
+		assert_eq!(
+			sparse.evaluate_partial(query, 2).unwrap(),
+			dense.evaluate_partial(query, 2).unwrap()
+		);
+		assert_eq!(
+			sparse.fold(query, 2).unwrap().materialize().unwrap(),
+			dense.evaluate_partial(query, 2).unwrap()
+		);
+
+		let mut sparse_evals = vec![P::zero(); 2];
+		let mut dense_evals = vec![P::zero(); 2];
+		for subcube_index in 0..4 {
+			sparse
+				.subcube_partial_low_evals(query, 3, subcube_index, &mut sparse_evals)
+				.unwrap();
+			dense
+				.subcube_partial_low_evals(query, 3, subcube_index, &mut dense_evals)
+				.unwrap();
+			assert_eq!(sparse_evals, dense_evals);
+
+			sparse
+				.subcube_partial_high_evals(query, 3, subcube_index, &mut sparse_evals)
+				.unwrap();
+			dense
+				.subcube_partial_high_evals(query, 3, subcube_index, &mut dense_evals)
+				.unwrap();
+			assert_eq!(sparse_evals, dense_evals);
+		}
+
+		let full_q = repeat_with(|| F::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		let full_query = MultilinearQuery::<P>::expand(&full_q);
+		assert_eq!(
+			sparse.evaluate(full_query.to_ref()).unwrap(),
+			dense.evaluate(full_query.to_ref()).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_sparse_multilinear_rejects_out_of_range_index() {
+		assert!(SparseMultilinear::<P>::new(4, [(16, F::ONE)]).is_err());
+	}
+}
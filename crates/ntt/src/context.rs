@@ -0,0 +1,198 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::sync::{Arc, RwLock};
+
+use binius_field::BinaryField;
+use binius_math::BinarySubspace;
+use binius_utils::rayon::get_log_max_threads;
+
+use super::{
+	error::Error,
+	multithreaded::MultithreadedNTT,
+	single_threaded::SingleThreadedNTT,
+	twiddle::{SharedTwiddleAccess, TwiddleTable},
+};
+
+/// A multithreaded NTT with precomputed twiddle factors from a shared [`TwiddleTable`].
+pub type PrecomputedNTT<F> = MultithreadedNTT<F, SharedTwiddleAccess<F>>;
+
+/// A cache of NTTs with precomputed twiddle factors, shared across encodings.
+///
+/// Precomputing the twiddle factors of an NTT over a domain of size $2^k$ takes $O(2^k)$ field
+/// operations and memory, which is significant for large commitments. The context creates the NTT
+/// for an evaluation domain on first use, and returns the same instance for the subsequent
+/// encodings over that domain. The NTTs index into shared [`TwiddleTable`]s, and the domains
+/// spanned by a prefix of the basis of a larger domain reuse its table, so for the default domains
+/// the twiddle factors are computed once for the largest domain rather than once per domain.
+///
+/// The context can be shared between threads, which all look up the same tables.
+#[derive(Debug)]
+pub struct NTTContext<F: BinaryField> {
+	log_max_threads: usize,
+	tables: RwLock<Vec<TwiddleTable<F>>>,
+	ntts: RwLock<Vec<(BinarySubspace<F>, Arc<PrecomputedNTT<F>>)>>,
+}
+
+impl<F: BinaryField> NTTContext<F> {
+	/// Creates an empty context for NTTs using the default number of threads.
+	pub fn new() -> Self {
+		Self::with_max_threads(get_log_max_threads())
+	}
+
+	/// Creates an empty context for NTTs using `1 << log_max_threads` threads.
+	pub const fn with_max_threads(log_max_threads: usize) -> Self {
+		Self {
+			log_max_threads,
+			tables: RwLock::new(Vec::new()),
+			ntts: RwLock::new(Vec::new()),
+		}
+	}
+
+	/// Returns the NTT over the default evaluation domain of dimension `log_domain_size`.
+	pub fn ntt(&self, log_domain_size: usize) -> Result<Arc<PrecomputedNTT<F>>, Error> {
+		self.ntt_with_subspace(&BinarySubspace::with_dim(log_domain_size)?)
+	}
+
+	/// Returns the NTT over the evaluation domain `subspace`.
+	///
+	/// The NTT is created if the context doesn't contain one for the domain yet, with the twiddle
+	/// factors of [`Self::twiddle_table`].
+	pub fn ntt_with_subspace(
+		&self,
+		subspace: &BinarySubspace<F>,
+	) -> Result<Arc<PrecomputedNTT<F>>, Error> {
+		if let Some(ntt) = self.find(subspace) {
+			return Ok(ntt);
+		}
+
+		let table = self.twiddle_table(subspace)?;
+		let ntt = Arc::new(
+			SingleThreadedNTT::with_twiddle_table(&table, subspace)?
+				.multithreaded_with_max_threads(self.log_max_threads),
+		);
+
+		let mut ntts = self.ntts.write().expect("lock is not poisoned");
+		// Another thread may have inserted the NTT while the twiddles were being computed.
+		if let Some((_, ntt)) = ntts.iter().find(|(domain, _)| domain == subspace) {
+			return Ok(ntt.clone());
+		}
+		ntts.push((subspace.clone(), ntt.clone()));
+		Ok(ntt)
+	}
+
+	/// Returns a twiddle table containing the evaluation domain `subspace`.
+	///
+	/// If no table of the context contains the domain, the table of the domain is precomputed and
+	/// replaces the tables of the domains it contains.
+	pub fn twiddle_table(&self, subspace: &BinarySubspace<F>) -> Result<TwiddleTable<F>, Error> {
+		let find = |tables: &[TwiddleTable<F>]| {
+			tables
+				.iter()
+				.find(|table| table.contains(subspace))
+				.cloned()
+		};
+		if let Some(table) = find(&self.tables.read().expect("lock is not poisoned")) {
+			return Ok(table);
+		}
+
+		let table = TwiddleTable::new(subspace)?;
+
+		let mut tables = self.tables.write().expect("lock is not poisoned");
+		// Another thread may have inserted a table for the domain in the meantime.
+		if let Some(table) = find(&tables) {
+			return Ok(table);
+		}
+		tables.retain(|other| !table.contains(other.subspace()));
+		tables.push(table.clone());
+		Ok(table)
+	}
+
+	/// The number of cached NTTs.
+	pub fn len(&self) -> usize {
+		self.ntts.read().expect("lock is not poisoned").len()
+	}
+
+	/// Whether the context doesn't contain any NTT.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	fn find(&self, subspace: &BinarySubspace<F>) -> Option<Arc<PrecomputedNTT<F>>> {
+		self.ntts
+			.read()
+			.expect("lock is not poisoned")
+			.iter()
+			.find(|(domain, _)| domain == subspace)
+			.map(|(_, ntt)| ntt.clone())
+	}
+}
+
+impl<F: BinaryField> Default for NTTContext<F> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use binius_field::{BinaryField32b, Field};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{AdditiveNTT, NTTShape};
+
+	#[test]
+	fn test_context_reuses_ntts() {
+		let context = NTTContext::<BinaryField32b>::with_max_threads(1);
+		assert!(context.is_empty());
+
+		let ntt = context.ntt(10).unwrap();
+		assert_eq!(ntt.log_domain_size(), 10);
+		assert!(Arc::ptr_eq(&ntt, &context.ntt(10).unwrap()));
+		assert_eq!(context.len(), 1);
+
+		let other = context.ntt(8).unwrap();
+		assert!(!Arc::ptr_eq(&ntt, &other));
+		assert_eq!(context.len(), 2);
+
+		let subspace = BinarySubspace::with_dim(10).unwrap();
+		assert!(Arc::ptr_eq(&ntt, &context.ntt_with_subspace(&subspace).unwrap()));
+	}
+
+	#[test]
+	fn test_context_shares_twiddle_tables() {
+		let context = NTTContext::<BinaryField32b>::with_max_threads(1);
+		let ntt = context.ntt(10).unwrap();
+		let smaller = context.ntt(8).unwrap();
+		let table = context
+			.twiddle_table(&BinarySubspace::with_dim(8).unwrap())
+			.unwrap();
+		assert_eq!(table.subspace().dim(), 10);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let shape = NTTShape {
+			log_y: 8,
+			..Default::default()
+		};
+		let mut data = (0..1 << 8)
+			.map(|_| BinaryField32b::random(&mut rng))
+			.collect::<Vec<_>>();
+		let mut expected = data.clone();
+		SingleThreadedNTT::new(8)
+			.unwrap()
+			.forward_transform(&mut expected, shape, 0, 0)
+			.unwrap();
+		smaller.forward_transform(&mut data, shape, 0, 0).unwrap();
+		assert_eq!(data, expected);
+
+		let mut expected = data.clone();
+		SingleThreadedNTT::new(10)
+			.unwrap()
+			.forward_transform(&mut expected, shape, 3, 0)
+			.unwrap();
+		ntt.forward_transform(&mut data, shape, 3, 0).unwrap();
+		assert_eq!(data, expected);
+	}
+}
@@ -22,6 +22,8 @@ pub enum Error {
 	SkipRoundsTooLarge,
 	#[error("odd interpolation length mismatch, expected to be exactly {expected_len}")]
 	OddInterpolateIncorrectLength { expected_len: usize },
+	#[error("the twiddle table does not contain the evaluation subspace")]
+	SubspaceNotInTable,
 	#[error("math error: {0}")]
 	MathError(#[from] binius_math::Error),
 }
@@ -8,6 +8,7 @@
 //! [DP24]: <https://eprint.iacr.org/2024/504>
 
 mod additive_ntt;
+mod context;
 mod dynamic_dispatch;
 mod error;
 mod multithreaded;
@@ -19,6 +20,7 @@ mod tests;
 pub mod twiddle;
 
 pub use additive_ntt::{AdditiveNTT, NTTShape};
+pub use context::{NTTContext, PrecomputedNTT};
 pub use dynamic_dispatch::{DynamicDispatchNTT, NTTOptions, ThreadingSettings};
 pub use error::Error;
 pub use multithreaded::MultithreadedNTT;
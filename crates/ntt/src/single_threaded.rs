@@ -4,13 +4,17 @@ use std::{cmp, marker::PhantomData};
 
 use binius_field::{BinaryField, PackedField, TowerField};
 use binius_math::BinarySubspace;
+use binius_utils::bail;
 
 use super::{
 	additive_ntt::{AdditiveNTT, NTTShape},
 	error::Error,
 	twiddle::TwiddleAccess,
 };
-use crate::twiddle::{OnTheFlyTwiddleAccess, PrecomputedTwiddleAccess, expand_subspace_evals};
+use crate::twiddle::{
+	OnTheFlyTwiddleAccess, PrecomputedTwiddleAccess, SharedTwiddleAccess, TwiddleTable,
+	expand_subspace_evals,
+};
 
 /// Implementation of `AdditiveNTT` that performs the computation single-threaded.
 #[derive(Debug)]
@@ -49,6 +53,24 @@ impl<F: BinaryField> SingleThreadedNTT<F> {
 	}
 }
 
+impl<F: BinaryField> SingleThreadedNTT<F, SharedTwiddleAccess<F>> {
+	/// Constructs an NTT over `subspace` whose twiddle factors are looked up in `table`.
+	///
+	/// No twiddle factors are computed or copied, so NTTs over all the subspaces contained in a
+	/// table can be created for the cost of computing the table once.
+	pub fn with_twiddle_table(
+		table: &TwiddleTable<F>,
+		subspace: &BinarySubspace<F>,
+	) -> Result<Self, Error> {
+		if subspace.dim() == 0 {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size: 1,
+			});
+		}
+		Ok(Self::with_twiddle_access(table.twiddles(subspace)?))
+	}
+}
+
 impl<F: TowerField> SingleThreadedNTT<F> {
 	/// A specialization of [`with_domain_field`](Self::with_domain_field) to the canonical tower
 	/// field.
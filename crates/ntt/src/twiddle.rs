@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{iter, marker::PhantomData, ops::Deref};
+use std::{iter, marker::PhantomData, ops::Deref, sync::Arc};
 
 use binius_field::{BinaryField, Field};
 use binius_math::BinarySubspace;
@@ -352,6 +352,96 @@ where
 		.collect()
 }
 
+/// The precomputed twiddle factors of the NTT over a subspace, shared between NTT instances.
+///
+/// Round $i$ of the NTT over the span of $\beta_0,\ldots ,\beta_{d-1}$ uses the evaluations of
+/// $\hat{W}\_i$ on the span of $\beta_{i+1},\ldots ,\beta_{d-1}$ in binary counting order. Since
+/// $\hat{W}\_i$ only depends on $\beta_0,\ldots ,\beta_i$, the twiddle factors of the NTT over the
+/// span of a prefix of the basis are prefixes of these evaluations. The table is computed once,
+/// and the NTTs over all such subspaces index into it without copying, see
+/// [`SingleThreadedNTT::with_twiddle_table`](crate::SingleThreadedNTT::with_twiddle_table).
+///
+/// Cloning the table is cheap and the clones can be shared between threads.
+#[derive(Debug, Clone)]
+pub struct TwiddleTable<F> {
+	subspace: BinarySubspace<F>,
+	evals: Arc<[Vec<F>]>,
+}
+
+impl<F: BinaryField> TwiddleTable<F> {
+	/// Precomputes the twiddle factors of the NTT over `subspace`.
+	pub fn new(subspace: &BinarySubspace<F>) -> Result<Self, Error> {
+		let evals = PrecomputedTwiddleAccess::generate(subspace)?
+			.into_iter()
+			.map(|twiddle_access| twiddle_access.s_evals)
+			.collect();
+		Ok(Self {
+			subspace: subspace.clone(),
+			evals,
+		})
+	}
+
+	pub const fn subspace(&self) -> &BinarySubspace<F> {
+		&self.subspace
+	}
+
+	/// Whether the basis of `subspace` is a prefix of the basis of the table's subspace, in which
+	/// case the table holds the twiddle factors of the NTT over `subspace`.
+	pub fn contains(&self, subspace: &BinarySubspace<F>) -> bool {
+		self.subspace.basis().starts_with(subspace.basis())
+	}
+
+	/// Returns the twiddle accesses of the NTT over `subspace`, one for each round.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::SubspaceNotInTable`] if the table doesn't [contain](Self::contains) `subspace`.
+	pub fn twiddles(
+		&self,
+		subspace: &BinarySubspace<F>,
+	) -> Result<Vec<SharedTwiddleAccess<F>>, Error> {
+		if !self.contains(subspace) {
+			bail!(Error::SubspaceNotInTable);
+		}
+		let log_domain_size = subspace.dim();
+		let twiddles = (0..log_domain_size)
+			.map(|round| {
+				let log_n = log_domain_size - 1 - round;
+				PrecomputedTwiddleAccess {
+					log_n,
+					s_evals: TwiddleSlice {
+						evals: self.evals.clone(),
+						round,
+						len: 1 << log_n,
+					},
+					_marker: PhantomData,
+				}
+			})
+			.collect();
+		Ok(twiddles)
+	}
+}
+
+/// The twiddle factors of one NTT round, viewed in a [`TwiddleTable`].
+#[derive(Debug, Clone)]
+pub struct TwiddleSlice<F> {
+	evals: Arc<[Vec<F>]>,
+	round: usize,
+	len: usize,
+}
+
+impl<F> Deref for TwiddleSlice<F> {
+	type Target = [F];
+
+	#[inline]
+	fn deref(&self) -> &[F] {
+		&self.evals[self.round][..self.len]
+	}
+}
+
+/// Precomputed twiddle access for one round, backed by a shared [`TwiddleTable`].
+pub type SharedTwiddleAccess<F> = PrecomputedTwiddleAccess<F, TwiddleSlice<F>>;
+
 #[cfg(test)]
 mod tests {
 	use binius_field::{BinaryField, BinaryField8b, BinaryField16b, BinaryField32b};
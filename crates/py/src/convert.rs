@@ -0,0 +1,70 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Conversion of row-major values into the byte layout of packed witness columns.
+//!
+//! A column of a tower field with `2^k` bits holds its rows densely packed, the first row in the
+//! lowest bits of the first byte. Columns of at least 8 bits are plain little-endian arrays, and
+//! narrower columns pack several rows per byte.
+
+use anyhow::{bail, ensure};
+use bytemuck::Pod;
+
+/// Packs values of `1 << log_bits` bits each, with `log_bits < 3`, into bytes.
+///
+/// Fails if a value does not fit in `1 << log_bits` bits.
+pub fn pack_sub_byte(values: &[u8], log_bits: usize) -> anyhow::Result<Vec<u8>> {
+	ensure!(log_bits < 3, "values of {} bits are not packed", 1 << log_bits);
+	let bits = 1 << log_bits;
+	let per_byte = 8 / bits;
+	let mut packed = vec![0u8; values.len().div_ceil(per_byte)];
+	for (row, &value) in values.iter().enumerate() {
+		if value >> bits != 0 {
+			bail!("value {value} at row {row} does not fit in {bits} bits");
+		}
+		packed[row / per_byte] |= value << (row % per_byte * bits);
+	}
+	Ok(packed)
+}
+
+/// Returns the little-endian bytes of values of at least 8 bits.
+pub fn to_le_bytes<T: Pod>(values: &[T]) -> Vec<u8> {
+	// The packed fields are only implemented for little-endian targets.
+	bytemuck::cast_slice(values).to_vec()
+}
+
+/// Transposes values into `n_bits` packed 1-bit columns, least significant bit first.
+///
+/// Column `i` holds bit `i` of every value. Fails if a value has a set bit beyond `n_bits`.
+pub fn transpose_bits(values: &[u64], n_bits: usize) -> anyhow::Result<Vec<Vec<u8>>> {
+	ensure!(n_bits <= 64, "cannot decompose into {n_bits} bits");
+	let mut columns = vec![vec![0u8; values.len().div_ceil(8)]; n_bits];
+	for (row, &value) in values.iter().enumerate() {
+		if n_bits < 64 && value >> n_bits != 0 {
+			bail!("value {value} at row {row} does not fit in {n_bits} bits");
+		}
+		for (bit, column) in columns.iter_mut().enumerate() {
+			column[row / 8] |= (((value >> bit) & 1) as u8) << (row % 8);
+		}
+	}
+	Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_pack_sub_byte() {
+		assert_eq!(pack_sub_byte(&[1, 0, 1, 1, 0, 0, 0, 0, 1], 0).unwrap(), vec![0b1101, 1]);
+		assert_eq!(pack_sub_byte(&[3, 1, 2], 1).unwrap(), vec![0b10_01_11]);
+		assert_eq!(pack_sub_byte(&[0xa, 0x5], 2).unwrap(), vec![0x5a]);
+		assert!(pack_sub_byte(&[2], 0).is_err());
+	}
+
+	#[test]
+	fn test_transpose_bits() {
+		let columns = transpose_bits(&[0b01, 0b11, 0b10], 2).unwrap();
+		assert_eq!(columns, vec![vec![0b011], vec![0b110]]);
+		assert!(transpose_bits(&[4], 2).is_err());
+	}
+}
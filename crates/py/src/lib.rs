@@ -0,0 +1,383 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Python bindings for building, proving and verifying Binius circuits.
+//!
+//! The `binius` Python module exposes a [`CircuitBuilder`] wrapping the
+//! [`ConstraintSystemBuilder`] with its committed columns, namespaces and a selection of gadgets.
+//! Witness columns are filled from numpy arrays with one value per row, which are converted to
+//! the packed layout of the columns by the [`convert`] module. Proofs use the same configuration
+//! and serialization as the C API, so they can be verified by any of the bindings.
+
+pub mod convert;
+
+use std::fmt::Display;
+
+use binius_circuits::{
+	arithmetic::{self, mul::Mul},
+	builder::{
+		ConstraintSystemBuilder,
+		types::{F, U},
+		witness,
+	},
+	gadget::Gadget,
+	unconstrained::unconstrained,
+};
+use binius_core::{
+	constraint_system::{self, ConstraintSystem, Proof, SecurityLevel, channel::Boundary},
+	fiat_shamir::HasherChallenger,
+	oracle::OracleId,
+};
+use binius_field::{
+	BinaryField1b, BinaryField2b, BinaryField4b, BinaryField8b, BinaryField16b, BinaryField32b,
+	BinaryField64b, BinaryField128b, ExtensionField, TowerField, as_packed_field::PackScalar,
+	tower::CanonicalTowerFamily,
+};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+use numpy::{PyArray1, PyArray2, PyArrayMethods};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+
+/// Calls `$f::<FS>` with the binary tower field `FS` of the given tower level.
+macro_rules! with_tower_level {
+	($tower_level:expr, $f:ident($($arg:expr),*)) => {
+		match $tower_level {
+			0 => $f::<BinaryField1b>($($arg),*),
+			1 => $f::<BinaryField2b>($($arg),*),
+			2 => $f::<BinaryField4b>($($arg),*),
+			3 => $f::<BinaryField8b>($($arg),*),
+			4 => $f::<BinaryField16b>($($arg),*),
+			5 => $f::<BinaryField32b>($($arg),*),
+			6 => $f::<BinaryField64b>($($arg),*),
+			7 => $f::<BinaryField128b>($($arg),*),
+			tower_level => Err(anyhow::anyhow!("unsupported tower level {tower_level}")),
+		}
+	};
+}
+
+fn py_err(err: impl Display) -> PyErr {
+	PyValueError::new_err(err.to_string())
+}
+
+/// Builds a circuit and its witness.
+///
+/// Oracles are referred to by their integer index. Once [`CircuitBuilder::prove`] has been called,
+/// the builder is consumed and every method fails.
+#[pyclass(unsendable)]
+pub struct CircuitBuilder {
+	// Borrows `_storage`, so it is declared first to be dropped first.
+	builder: Option<ConstraintSystemBuilder<'static>>,
+	_storage: Box<bumpalo::Bump>,
+}
+
+impl CircuitBuilder {
+	fn builder(&mut self) -> PyResult<&mut ConstraintSystemBuilder<'static>> {
+		self.builder
+			.as_mut()
+			.ok_or_else(|| py_err("the circuit has already been proven"))
+	}
+
+	fn witness(&mut self) -> PyResult<&mut witness::Builder<'static>> {
+		self.builder()?
+			.witness()
+			.ok_or_else(|| py_err("the builder has no witness"))
+	}
+
+	fn log_rows(&mut self, id: OracleId) -> PyResult<usize> {
+		self.builder()?.log_rows([id]).map_err(py_err)
+	}
+}
+
+#[pymethods]
+impl CircuitBuilder {
+	#[new]
+	fn new() -> Self {
+		let storage = Box::new(bumpalo::Bump::new());
+		// Safety: the storage is heap-allocated, so it doesn't move with `Self`, and it is dropped
+		// after the builder, which is the only borrower.
+		let storage_ref = unsafe { &*(storage.as_ref() as *const bumpalo::Bump) };
+		Self {
+			builder: Some(ConstraintSystemBuilder::new_with_witness(storage_ref)),
+			_storage: storage,
+		}
+	}
+
+	fn add_committed(
+		&mut self,
+		name: &str,
+		log_rows: usize,
+		tower_level: usize,
+	) -> PyResult<usize> {
+		Ok(self
+			.builder()?
+			.add_committed(name, log_rows, tower_level)
+			.index())
+	}
+
+	fn push_namespace(&mut self, name: &str) -> PyResult<()> {
+		self.builder()?.push_namespace(name);
+		Ok(())
+	}
+
+	fn pop_namespace(&mut self) -> PyResult<()> {
+		self.builder()?.pop_namespace();
+		Ok(())
+	}
+
+	/// Adds a committed column with random values.
+	fn unconstrained(
+		&mut self,
+		name: &str,
+		log_rows: usize,
+		tower_level: usize,
+	) -> PyResult<usize> {
+		let builder = self.builder()?;
+		let id = with_tower_level!(tower_level, unconstrained(builder, name, log_rows))
+			.map_err(py_err)?;
+		Ok(id.index())
+	}
+
+	/// Multiplies two integers given as little-endian bit columns, see [`Mul`].
+	fn mul(
+		&mut self,
+		name: &str,
+		xin_bits: Vec<usize>,
+		yin_bits: Vec<usize>,
+	) -> PyResult<Vec<usize>> {
+		let input = (oracle_ids(xin_bits), oracle_ids(yin_bits));
+		let output = Mul::<BinaryField64b>::new(name)
+			.build(self.builder()?, input)
+			.map_err(py_err)?;
+		Ok(output.iter().map(OracleId::index).collect())
+	}
+
+	/// Adds two 32-bit integers stored as packed 1-bit columns, see [`arithmetic::u32::add`].
+	fn u32_add(&mut self, name: &str, xin: usize, yin: usize) -> PyResult<usize> {
+		let id = arithmetic::u32::add(
+			self.builder()?,
+			name,
+			OracleId::from_index(xin),
+			OracleId::from_index(yin),
+			arithmetic::Flags::Unchecked,
+		)
+		.map_err(py_err)?;
+		Ok(id.index())
+	}
+
+	/// Fills a column from a numpy array with one value per row.
+	///
+	/// The array has dtype `uint8` for tower levels up to 3, `uint16`, `uint32` and `uint64` for
+	/// levels 4 to 6, and for level 7 it is a `uint64` array of shape `(rows, 2)` holding the low
+	/// and high halves of the values.
+	fn fill_column(
+		&mut self,
+		oracle_id: usize,
+		tower_level: usize,
+		values: &Bound<'_, PyAny>,
+	) -> PyResult<()> {
+		let id = OracleId::from_index(oracle_id);
+		let rows = 1 << self.log_rows(id)?;
+		let oracle_tower_level = self.builder()?.tower_level(id);
+		if tower_level != oracle_tower_level {
+			return Err(py_err(format!(
+				"oracle {oracle_id} has tower level {oracle_tower_level}, got values of tower level \
+				 {tower_level}"
+			)));
+		}
+		let (n_values, bytes) = match tower_level {
+			0..=2 => {
+				let values = extract::<u8>(values)?;
+				(values.len(), convert::pack_sub_byte(&values, tower_level).map_err(py_err)?)
+			}
+			3 => column_bytes(extract::<u8>(values)?),
+			4 => column_bytes(extract::<u16>(values)?),
+			5 => column_bytes(extract::<u32>(values)?),
+			6 => column_bytes(extract::<u64>(values)?),
+			7 => {
+				let array = values.downcast::<PyArray2<u64>>()?.readonly();
+				let array = array.as_array();
+				if array.ncols() != 2 {
+					return Err(py_err("values of tower level 7 must have shape (rows, 2)"));
+				}
+				(array.nrows(), column_bytes(array.iter().copied().collect()).1)
+			}
+			_ => return Err(py_err(format!("unsupported tower level {tower_level}"))),
+		};
+		if n_values != rows {
+			return Err(py_err(format!("expected {rows} values, got {n_values}")));
+		}
+
+		let witness = self.witness()?;
+		with_tower_level!(tower_level, write_column(witness, id, &bytes)).map_err(py_err)
+	}
+
+	/// Fills 1-bit columns with the bits of a `uint64` numpy array, least significant bit first.
+	///
+	/// Fails if a value has a set bit beyond the number of columns.
+	fn fill_bit_columns(
+		&mut self,
+		oracle_ids: Vec<usize>,
+		values: &Bound<'_, PyAny>,
+	) -> PyResult<()> {
+		let values = extract::<u64>(values)?;
+		let columns = convert::transpose_bits(&values, oracle_ids.len()).map_err(py_err)?;
+		for (&oracle_id, bytes) in oracle_ids.iter().zip(&columns) {
+			let id = OracleId::from_index(oracle_id);
+			let rows = 1 << self.log_rows(id)?;
+			if values.len() != rows {
+				return Err(py_err(format!("expected {rows} values, got {}", values.len())));
+			}
+			write_column::<BinaryField1b>(self.witness()?, id, bytes).map_err(py_err)?;
+		}
+		Ok(())
+	}
+
+	/// Builds the constraint system and proves the witness.
+	///
+	/// Returns the serialized constraint system, boundaries and proof, which can be passed to
+	/// [`verify`].
+	fn prove<'py>(
+		&mut self,
+		py: Python<'py>,
+		security_bits: usize,
+		log_inv_rate: usize,
+	) -> PyResult<(Bound<'py, PyBytes>, Bound<'py, PyBytes>, Bound<'py, PyBytes>)> {
+		let builder = self
+			.builder
+			.take()
+			.ok_or_else(|| py_err("the circuit has already been proven"))?;
+		let (cs_bytes, boundary_bytes, proof_bytes) =
+			prove_serialized(builder, security_bits, log_inv_rate).map_err(py_err)?;
+		Ok((
+			PyBytes::new(py, &cs_bytes),
+			PyBytes::new(py, &boundary_bytes),
+			PyBytes::new(py, &proof_bytes),
+		))
+	}
+}
+
+/// Verifies a proof returned by [`CircuitBuilder::prove`].
+#[pyfunction]
+fn verify(
+	constraint_system: &[u8],
+	boundaries: &[u8],
+	proof: &[u8],
+	security_bits: usize,
+	log_inv_rate: usize,
+) -> PyResult<bool> {
+	let result =
+		verify_serialized(constraint_system, boundaries, proof, security_bits, log_inv_rate);
+	Ok(result.is_ok())
+}
+
+#[pymodule]
+fn binius(module: &Bound<'_, PyModule>) -> PyResult<()> {
+	module.add_class::<CircuitBuilder>()?;
+	module.add_function(wrap_pyfunction!(verify, module)?)?;
+	Ok(())
+}
+
+/// Builds the constraint system of `builder` and proves its witness, returning the serialized
+/// constraint system, boundaries and proof.
+fn prove_serialized(
+	mut builder: ConstraintSystemBuilder<'_>,
+	security_bits: usize,
+	log_inv_rate: usize,
+) -> anyhow::Result<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+	let boundaries = builder.public_inputs().to_vec();
+	let witness = builder.take_witness()?;
+	let constraint_system = builder.build()?;
+
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		SecurityLevel::new(security_bits, log_inv_rate),
+		&boundaries,
+		witness,
+		&make_portable_backend(),
+	)?;
+
+	let mode = SerializationMode::CanonicalTower;
+	let mut cs_bytes = Vec::new();
+	constraint_system.serialize(&mut cs_bytes, mode)?;
+	let mut boundary_bytes = Vec::new();
+	boundaries.serialize(&mut boundary_bytes, mode)?;
+	let mut proof_bytes = Vec::with_capacity(proof.serialized_size());
+	proof.serialize(&mut proof_bytes, mode)?;
+	Ok((cs_bytes, boundary_bytes, proof_bytes))
+}
+
+/// Verifies a proof serialized by [`prove_serialized`].
+fn verify_serialized(
+	constraint_system: &[u8],
+	boundaries: &[u8],
+	proof: &[u8],
+	security_bits: usize,
+	log_inv_rate: usize,
+) -> anyhow::Result<()> {
+	let mode = SerializationMode::CanonicalTower;
+	let constraint_system = ConstraintSystem::<F>::deserialize(constraint_system, mode)?;
+	let boundaries = Vec::<Boundary<F>>::deserialize(boundaries, mode)?;
+	let proof = Proof::deserialize(proof, mode)?;
+	constraint_system::verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, SecurityLevel::new(security_bits, log_inv_rate), &boundaries, proof)?;
+	Ok(())
+}
+
+fn oracle_ids(indices: Vec<usize>) -> Vec<OracleId> {
+	indices.into_iter().map(OracleId::from_index).collect()
+}
+
+fn extract<T: numpy::Element + Copy>(values: &Bound<'_, PyAny>) -> PyResult<Vec<T>> {
+	let array = values.downcast::<PyArray1<T>>()?.readonly();
+	Ok(array.as_array().iter().copied().collect())
+}
+
+fn column_bytes<T: bytemuck::Pod>(values: Vec<T>) -> (usize, Vec<u8>) {
+	(values.len(), convert::to_le_bytes(&values))
+}
+
+fn write_column<FS>(witness: &witness::Builder, id: OracleId, bytes: &[u8]) -> anyhow::Result<()>
+where
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+	FS: TowerField,
+{
+	let mut column = witness.new_column::<FS>(id)?;
+	let data = column.as_mut_slice::<u8>();
+	anyhow::ensure!(bytes.len() <= data.len(), "the values do not fit in the column");
+	data[..bytes.len()].copy_from_slice(bytes);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_prove_verify_roundtrip() {
+		let mut circuit = CircuitBuilder::new();
+		let x = circuit.unconstrained("x", 10, 0).unwrap();
+		let y = circuit.unconstrained("y", 10, 0).unwrap();
+		circuit.u32_add("add", x, y).unwrap();
+
+		let builder = circuit.builder.take().unwrap();
+		let (constraint_system, boundaries, mut proof) = prove_serialized(builder, 100, 1).unwrap();
+		verify_serialized(&constraint_system, &boundaries, &proof, 100, 1).unwrap();
+
+		let last = proof.len() - 1;
+		proof[last] ^= 1;
+		assert!(verify_serialized(&constraint_system, &boundaries, &proof, 100, 1).is_err());
+	}
+}
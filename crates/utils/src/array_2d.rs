@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::ops::{AddAssign, Deref, DerefMut, Index, IndexMut};
+use alloc::{vec, vec::Vec};
+use core::ops::{AddAssign, Deref, DerefMut, Index, IndexMut};
 
 use bytemuck::{Zeroable, allocation::zeroed_vec};
 
@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::cmp::Ordering;
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
 
 /// Finds connected components using a Kruskal-like approach.
 /// Each input slice of usizes represents a set of nodes that form a complete subgraph
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::iter::FusedIterator;
+use core::iter::FusedIterator;
 
 pub trait IterExtensions: Iterator + Sized {
 	fn map_skippable<R, F>(self, f: F) -> SkippableMap<Self, F>
@@ -15,9 +15,9 @@ impl<T: Iterator + Sized> IterExtensions for T {}
 
 /// A map iterator that skips values when `nth` is called.
 ///
-/// `std::iter::Map` guarantees that the function will be called for every value of the inner
+/// `core::iter::Map` guarantees that the function will be called for every value of the inner
 /// iterator. However, it makes it impossible to implement `nth` in efficient way. `SkippableMap`
-/// pretty much follows the interface of the `std::iter::Map` except that F is required to be `Fn`
+/// pretty much follows the interface of the `core::iter::Map` except that F is required to be `Fn`
 /// instead of `FnMut`.
 #[derive(Debug, Clone)]
 pub struct SkippableMap<I, F> {
@@ -75,7 +75,7 @@ impl<B, I: FusedIterator, F> FusedIterator for SkippableMap<I, F> where F: Fn(I:
 
 #[cfg(test)]
 mod tests {
-	use std::cell::RefCell;
+	use core::cell::RefCell;
 
 	use super::*;
 
@@ -1,9 +1,17 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 //! Utility modules used in Binius.
+//!
+//! The crate builds without the standard library, with the `alloc` collections, when the default
+//! `std` feature is disabled. The modules that read the environment are only available with `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod array_2d;
 pub mod checked_arithmetics;
+#[cfg(feature = "std")]
 pub mod env;
 pub mod error_utils;
 pub mod felts;
@@ -16,6 +24,7 @@ pub mod rayon;
 pub mod serialization;
 pub mod sorting;
 pub mod sparse_index;
+pub mod test_vectors;
 
 pub use bytes;
 pub use serialization::{DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
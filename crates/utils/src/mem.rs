@@ -1,6 +1,6 @@
 // Copyright 2025 Irreducible Inc.
 
-use std::mem::MaybeUninit;
+use core::mem::MaybeUninit;
 
 /// Turn a given slice of `T` into a slice of `MaybeUninit<T>`.
 ///
@@ -8,7 +8,7 @@ use std::mem::MaybeUninit;
 ///
 /// This function is going to panic in case `T` has a destructor.
 pub fn slice_uninit_mut<T>(slice: &mut [T]) -> &mut [MaybeUninit<T>] {
-	assert!(!std::mem::needs_drop::<T>());
+	assert!(!core::mem::needs_drop::<T>());
 	unsafe {
 		// SAFETY:
 		//
@@ -27,7 +27,7 @@ pub fn slice_uninit_mut<T>(slice: &mut [T]) -> &mut [MaybeUninit<T>] {
 		//
 		// The returned slice takes over the lifetime of the input slice making the lifetime
 		// correct.
-		std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut MaybeUninit<T>, slice.len())
+		core::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut MaybeUninit<T>, slice.len())
 	}
 }
 
@@ -44,7 +44,7 @@ pub fn slice_uninit_mut<T>(slice: &mut [T]) -> &mut [MaybeUninit<T>] {
 ///
 /// [`assume_init_mut`]: MaybeUninit::assume_init_mut
 pub const unsafe fn slice_assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
-	unsafe { std::mem::transmute(slice) }
+	unsafe { core::mem::transmute(slice) }
 }
 
 /// This can be removed when MaybeUninit::slice_assume_init_ref is stabilized
@@ -60,5 +60,5 @@ pub const unsafe fn slice_assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &m
 ///
 /// [`assume_init_ref`]: MaybeUninit::assume_init_ref
 pub const unsafe fn slice_assume_init_ref<T>(slice: &[MaybeUninit<T>]) -> &[T] {
-	unsafe { std::mem::transmute(slice) }
+	unsafe { core::mem::transmute(slice) }
 }
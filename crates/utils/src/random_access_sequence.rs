@@ -93,7 +93,7 @@ pub struct SequenceSubrange<'a, T: Copy, Inner: RandomAccessSequence<T>> {
 	inner: &'a Inner,
 	offset: usize,
 	len: usize,
-	_marker: std::marker::PhantomData<T>,
+	_marker: core::marker::PhantomData<T>,
 }
 
 impl<'a, T: Copy, Inner: RandomAccessSequence<T>> SequenceSubrange<'a, T, Inner> {
@@ -105,7 +105,7 @@ impl<'a, T: Copy, Inner: RandomAccessSequence<T>> SequenceSubrange<'a, T, Inner>
 			inner,
 			offset,
 			len,
-			_marker: std::marker::PhantomData,
+			_marker: core::marker::PhantomData,
 		}
 	}
 }
@@ -129,7 +129,7 @@ pub struct SequenceSubrangeMut<'a, T: Copy, Inner: RandomAccessSequenceMut<T>> {
 	inner: &'a mut Inner,
 	offset: usize,
 	len: usize,
-	_marker: std::marker::PhantomData<&'a T>,
+	_marker: core::marker::PhantomData<&'a T>,
 }
 
 impl<'a, T: Copy, Inner: RandomAccessSequenceMut<T>> SequenceSubrangeMut<'a, T, Inner> {
@@ -141,7 +141,7 @@ impl<'a, T: Copy, Inner: RandomAccessSequenceMut<T>> SequenceSubrangeMut<'a, T,
 			inner,
 			offset,
 			len,
-			_marker: std::marker::PhantomData,
+			_marker: core::marker::PhantomData,
 		}
 	}
 }
@@ -171,7 +171,7 @@ impl<T: Copy, Inner: RandomAccessSequenceMut<T>> RandomAccessSequenceMut<T>
 
 #[cfg(test)]
 mod tests {
-	use std::fmt::Debug;
+	use core::fmt::Debug;
 
 	use rand::{Rng, SeedableRng, rngs::StdRng};
 
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use alloc::{boxed::Box, string::String, vec::Vec};
+
 use auto_impl::auto_impl;
 use bytes::{Buf, BufMut};
 use thiserror::Error;
@@ -45,11 +47,13 @@ pub enum SerializationError {
 	#[error("Multiple deserializers with the same name {name} has been registered")]
 	DeserializerNameConflict { name: String },
 	#[error("FromUtf8Error: {0}")]
-	FromUtf8Error(#[from] std::string::FromUtf8Error),
+	FromUtf8Error(#[from] alloc::string::FromUtf8Error),
 	#[error("Invalid construction of {name}")]
 	InvalidConstruction { name: &'static str },
 	#[error("usize {size} is too large to serialize (max is {max})", max = u32::MAX)]
 	UsizeTooLarge { size: usize },
+	#[error("Unsupported format version {version} for {name}")]
+	UnsupportedVersion { name: &'static str, version: u32 },
 }
 
 // Copyright 2025 Irreducible Inc.
@@ -97,7 +101,7 @@ impl SerializeBytes for u128 {
 		mut write_buf: impl BufMut,
 		_mode: SerializationMode,
 	) -> Result<(), SerializationError> {
-		assert_enough_space_for(&write_buf, std::mem::size_of::<Self>())?;
+		assert_enough_space_for(&write_buf, core::mem::size_of::<Self>())?;
 		write_buf.put_u128_le(*self);
 		Ok(())
 	}
@@ -111,7 +115,7 @@ impl DeserializeBytes for u128 {
 	where
 		Self: Sized,
 	{
-		assert_enough_data_for(&read_buf, std::mem::size_of::<Self>())?;
+		assert_enough_data_for(&read_buf, core::mem::size_of::<Self>())?;
 		Ok(read_buf.get_u128_le())
 	}
 }
@@ -122,7 +126,7 @@ impl SerializeBytes for u64 {
 		mut write_buf: impl BufMut,
 		_mode: SerializationMode,
 	) -> Result<(), SerializationError> {
-		assert_enough_space_for(&write_buf, std::mem::size_of::<Self>())?;
+		assert_enough_space_for(&write_buf, core::mem::size_of::<Self>())?;
 		write_buf.put_u64_le(*self);
 		Ok(())
 	}
@@ -136,7 +140,7 @@ impl DeserializeBytes for u64 {
 	where
 		Self: Sized,
 	{
-		assert_enough_data_for(&read_buf, std::mem::size_of::<Self>())?;
+		assert_enough_data_for(&read_buf, core::mem::size_of::<Self>())?;
 		Ok(read_buf.get_u64_le())
 	}
 }
@@ -147,7 +151,7 @@ impl SerializeBytes for u32 {
 		mut write_buf: impl BufMut,
 		_mode: SerializationMode,
 	) -> Result<(), SerializationError> {
-		assert_enough_space_for(&write_buf, std::mem::size_of::<Self>())?;
+		assert_enough_space_for(&write_buf, core::mem::size_of::<Self>())?;
 		write_buf.put_u32_le(*self);
 		Ok(())
 	}
@@ -161,7 +165,7 @@ impl DeserializeBytes for u32 {
 	where
 		Self: Sized,
 	{
-		assert_enough_data_for(&read_buf, std::mem::size_of::<Self>())?;
+		assert_enough_data_for(&read_buf, core::mem::size_of::<Self>())?;
 		Ok(read_buf.get_u32_le())
 	}
 }
@@ -172,7 +176,7 @@ impl SerializeBytes for u16 {
 		mut write_buf: impl BufMut,
 		_mode: SerializationMode,
 	) -> Result<(), SerializationError> {
-		assert_enough_space_for(&write_buf, std::mem::size_of::<Self>())?;
+		assert_enough_space_for(&write_buf, core::mem::size_of::<Self>())?;
 		write_buf.put_u16_le(*self);
 		Ok(())
 	}
@@ -186,7 +190,7 @@ impl DeserializeBytes for u16 {
 	where
 		Self: Sized,
 	{
-		assert_enough_data_for(&read_buf, std::mem::size_of::<Self>())?;
+		assert_enough_data_for(&read_buf, core::mem::size_of::<Self>())?;
 		Ok(read_buf.get_u16_le())
 	}
 }
@@ -197,7 +201,7 @@ impl SerializeBytes for u8 {
 		mut write_buf: impl BufMut,
 		_mode: SerializationMode,
 	) -> Result<(), SerializationError> {
-		assert_enough_space_for(&write_buf, std::mem::size_of::<Self>())?;
+		assert_enough_space_for(&write_buf, core::mem::size_of::<Self>())?;
 		write_buf.put_u8(*self);
 		Ok(())
 	}
@@ -211,7 +215,7 @@ impl DeserializeBytes for u8 {
 	where
 		Self: Sized,
 	{
-		assert_enough_data_for(&read_buf, std::mem::size_of::<Self>())?;
+		assert_enough_data_for(&read_buf, core::mem::size_of::<Self>())?;
 		Ok(read_buf.get_u8())
 	}
 }
@@ -235,7 +239,7 @@ impl DeserializeBytes for bool {
 	}
 }
 
-impl<T> SerializeBytes for std::marker::PhantomData<T> {
+impl<T> SerializeBytes for core::marker::PhantomData<T> {
 	fn serialize(
 		&self,
 		_write_buf: impl BufMut,
@@ -245,7 +249,7 @@ impl<T> SerializeBytes for std::marker::PhantomData<T> {
 	}
 }
 
-impl<T> DeserializeBytes for std::marker::PhantomData<T> {
+impl<T> DeserializeBytes for core::marker::PhantomData<T> {
 	fn deserialize(
 		_read_buf: impl Buf,
 		_mode: SerializationMode,
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use alloc::vec::Vec;
+
 use itertools::Itertools;
 
 /// Returns whether the given values are sorted in ascending order.
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use alloc::vec::Vec;
+
 /// An index mapping positive integer IDs to optional values.
 #[derive(Debug, Clone)]
 pub struct SparseIndex<T> {
@@ -91,8 +93,8 @@ impl<T> Default for SparseIndex<T> {
 
 impl<T> IntoIterator for SparseIndex<T> {
 	type Item = (usize, T);
-	type IntoIter = std::iter::FilterMap<
-		std::iter::Enumerate<std::vec::IntoIter<Option<T>>>,
+	type IntoIter = core::iter::FilterMap<
+		core::iter::Enumerate<alloc::vec::IntoIter<Option<T>>>,
 		fn((usize, Option<T>)) -> Option<(usize, T)>,
 	>;
 
@@ -104,7 +106,7 @@ impl<T> IntoIterator for SparseIndex<T> {
 	}
 }
 
-impl<T> std::iter::FromIterator<(usize, T)> for SparseIndex<T> {
+impl<T> core::iter::FromIterator<(usize, T)> for SparseIndex<T> {
 	fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
 		let mut index = Self::new();
 		for (i, v) in iter {
@@ -114,7 +116,7 @@ impl<T> std::iter::FromIterator<(usize, T)> for SparseIndex<T> {
 	}
 }
 
-impl<T> std::ops::Index<usize> for SparseIndex<T> {
+impl<T> core::ops::Index<usize> for SparseIndex<T> {
 	type Output = T;
 
 	fn index(&self, index: usize) -> &Self::Output {
@@ -122,7 +124,7 @@ impl<T> std::ops::Index<usize> for SparseIndex<T> {
 	}
 }
 
-impl<T> std::ops::IndexMut<usize> for SparseIndex<T> {
+impl<T> core::ops::IndexMut<usize> for SparseIndex<T> {
 	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
 		self.get_mut(index).unwrap()
 	}
@@ -0,0 +1,169 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Parser for known-answer test (KAT) vector files.
+//!
+//! The files use the layout of the NIST CAVP `.rsp` response files. A file is a sequence of
+//! records separated by blank lines. Each record consists of `Name = value` lines, where the value
+//! is a hex string, possibly empty. Lines starting with `#` are comments, and lines of the form
+//! `[...]` are section headers, which are ignored. For example:
+//!
+//! ```text
+//! # SHA-256
+//!
+//! Msg =
+//! MD = e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
+//!
+//! Msg = 616263
+//! MD = ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad
+//! ```
+//!
+//! Test vector files are meant to be embedded into tests with [`include_str!`], so that the
+//! vectors live next to the crate they check and can be reviewed independently of the code.
+
+use alloc::{
+	string::{String, ToString},
+	vec::Vec,
+};
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+	#[error("line {line}: expected `Name = value`")]
+	MalformedLine { line: usize },
+	#[error("line {line}: invalid hex value")]
+	InvalidHex { line: usize },
+	#[error("line {line}: field {name} is repeated within a record")]
+	DuplicateField { line: usize, name: String },
+}
+
+/// A single record of a test vector file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnownAnswer {
+	/// The line number at which the record starts, for error reporting.
+	pub line: usize,
+	fields: Vec<(String, Vec<u8>)>,
+}
+
+impl KnownAnswer {
+	/// Returns the decoded value of the named field, if the record has it.
+	pub fn get(&self, name: &str) -> Option<&[u8]> {
+		self.fields
+			.iter()
+			.find(|(field_name, _)| field_name == name)
+			.map(|(_, value)| value.as_slice())
+	}
+
+	/// Returns the decoded value of the named field.
+	///
+	/// ## Panics
+	///
+	/// Panics if the record has no field with that name.
+	pub fn field(&self, name: &str) -> &[u8] {
+		self.get(name)
+			.unwrap_or_else(|| panic!("record at line {} has no field {name}", self.line))
+	}
+}
+
+/// Parses the contents of a test vector file into its records.
+pub fn parse_known_answers(text: &str) -> Result<Vec<KnownAnswer>, Error> {
+	let mut records = Vec::new();
+	let mut current: Option<KnownAnswer> = None;
+
+	for (index, line) in text.lines().enumerate() {
+		let line_number = index + 1;
+		let line = line.trim();
+
+		if line.is_empty() {
+			records.extend(current.take());
+			continue;
+		}
+		if line.starts_with('#') || (line.starts_with('[') && line.ends_with(']')) {
+			continue;
+		}
+
+		let (name, value) = line
+			.split_once('=')
+			.ok_or(Error::MalformedLine { line: line_number })?;
+		let name = name.trim();
+		if name.is_empty() {
+			return Err(Error::MalformedLine { line: line_number });
+		}
+		let value = decode_hex(value.trim()).ok_or(Error::InvalidHex { line: line_number })?;
+
+		let record = current.get_or_insert_with(|| KnownAnswer {
+			line: line_number,
+			fields: Vec::new(),
+		});
+		if record.get(name).is_some() {
+			return Err(Error::DuplicateField {
+				line: line_number,
+				name: name.to_string(),
+			});
+		}
+		record.fields.push((name.to_string(), value));
+	}
+	records.extend(current);
+
+	Ok(records)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+	fn nibble(c: u8) -> Option<u8> {
+		match c {
+			b'0'..=b'9' => Some(c - b'0'),
+			b'a'..=b'f' => Some(c - b'a' + 10),
+			b'A'..=b'F' => Some(c - b'A' + 10),
+			_ => None,
+		}
+	}
+
+	let hex = hex.as_bytes();
+	if hex.len() % 2 != 0 {
+		return None;
+	}
+	hex.chunks_exact(2)
+		.map(|pair| Some((nibble(pair[0])? << 4) | nibble(pair[1])?))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_records() {
+		let text = "\
+# comment
+[L = 32]
+
+Msg =
+MD = 00ff
+
+Msg = 616263
+MD = A0b1
+";
+		let records = parse_known_answers(text).unwrap();
+		assert_eq!(records.len(), 2);
+		assert_eq!(records[0].line, 4);
+		assert_eq!(records[0].field("Msg"), &[] as &[u8]);
+		assert_eq!(records[0].field("MD"), &[0x00, 0xff]);
+		assert_eq!(records[1].field("Msg"), b"abc");
+		assert_eq!(records[1].field("MD"), &[0xa0, 0xb1]);
+		assert_eq!(records[1].get("Len"), None);
+	}
+
+	#[test]
+	fn test_parse_errors() {
+		assert_eq!(parse_known_answers("Msg 00"), Err(Error::MalformedLine { line: 1 }));
+		assert_eq!(parse_known_answers("\nMsg = 0"), Err(Error::InvalidHex { line: 2 }));
+		assert_eq!(parse_known_answers("Msg = zz"), Err(Error::InvalidHex { line: 1 }));
+		assert_eq!(
+			parse_known_answers("Msg = 00\nMsg = 01"),
+			Err(Error::DuplicateField {
+				line: 2,
+				name: "Msg".to_string()
+			})
+		);
+	}
+}
@@ -65,7 +65,7 @@ fn main() -> Result<()> {
 		let in_b_witness = witness
 			.get::<BinaryField32b>(in_b)?
 			.as_slice::<BinaryField32b>();
-		let mut out_witness = witness.new_column::<BinaryField32b>(out);
+		let mut out_witness = witness.new_column::<BinaryField32b>(out)?;
 
 		let out_scalars = out_witness.as_mut_slice::<BinaryField32b>();
 
@@ -89,15 +89,20 @@ fn main() -> Result<()> {
 
 	let backend = make_portable_backend();
 
-	let proof =
-		constraint_system::prove::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], witness, &backend)?;
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		constraint_system::SecurityLevel::new(SECURITY_BITS, args.log_inv_rate as usize),
+		&[],
+		witness,
+		&backend,
+	)?;
 
 	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
 
@@ -107,7 +112,12 @@ fn main() -> Result<()> {
 		Groestl256,
 		Groestl256ByteCompression,
 		HasherChallenger<Groestl256>,
-	>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], proof)?;
+	>(
+		&constraint_system,
+		constraint_system::SecurityLevel::new(SECURITY_BITS, args.log_inv_rate as usize),
+		&[],
+		proof,
+	)?;
 
 	Ok(())
 }
@@ -102,15 +102,20 @@ fn main() -> Result<()> {
 
 	let backend = make_portable_backend();
 
-	let proof =
-		constraint_system::prove::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], witness, &backend)?;
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		constraint_system::SecurityLevel::new(SECURITY_BITS, args.log_inv_rate as usize),
+		&[],
+		witness,
+		&backend,
+	)?;
 
 	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
 
@@ -120,7 +125,12 @@ fn main() -> Result<()> {
 		Groestl256,
 		Groestl256ByteCompression,
 		HasherChallenger<Groestl256>,
-	>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], proof)?;
+	>(
+		&constraint_system,
+		constraint_system::SecurityLevel::new(SECURITY_BITS, args.log_inv_rate as usize),
+		&[],
+		proof,
+	)?;
 
 	Ok(())
 }
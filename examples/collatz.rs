@@ -73,8 +73,7 @@ fn prove(x0: u32, log_inv_rate: usize) -> Result<(Advice, Proof), anyhow::Error>
 		_,
 	>(
 		&constraint_system,
-		log_inv_rate,
-		SECURITY_BITS,
+		constraint_system::SecurityLevel::new(SECURITY_BITS, log_inv_rate),
 		&boundaries,
 		witness,
 		&make_portable_backend(),
@@ -98,7 +97,12 @@ fn verify(x0: u32, advice: Advice, proof: Proof, log_inv_rate: usize) -> Result<
 		Groestl256,
 		Groestl256ByteCompression,
 		HasherChallenger<Groestl256>,
-	>(&constraint_system, log_inv_rate, SECURITY_BITS, &boundaries, proof)?;
+	>(
+		&constraint_system,
+		constraint_system::SecurityLevel::new(SECURITY_BITS, log_inv_rate),
+		&boundaries,
+		proof,
+	)?;
 
 	Ok(())
 }
@@ -123,8 +123,10 @@ fn main() -> Result<()> {
 		_,
 	>(
 		&ccs,
-		args.log_inv_rate as usize,
-		SECURITY_BITS,
+		binius_core::constraint_system::SecurityLevel::new(
+			SECURITY_BITS,
+			args.log_inv_rate as usize,
+		),
 		&statement.boundaries,
 		witness,
 		&binius_hal::make_portable_backend(),
@@ -139,7 +141,15 @@ fn main() -> Result<()> {
 		Groestl256,
 		Groestl256ByteCompression,
 		HasherChallenger<Groestl256>,
-	>(&ccs, args.log_inv_rate as usize, SECURITY_BITS, &statement.boundaries, proof)
+	>(
+		&ccs,
+		binius_core::constraint_system::SecurityLevel::new(
+			SECURITY_BITS,
+			args.log_inv_rate as usize,
+		),
+		&statement.boundaries,
+		proof,
+	)
 	.unwrap();
 
 	Ok(())
@@ -62,13 +62,15 @@ fn main() -> Result<()> {
 	let modulus = (random_u512(&mut rng) % input_bitmask) + U512::from(1u8);
 
 	if let Some(witness) = builder.witness() {
-		let mut mult_a: [_; WIDTH] =
-			array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(mult_a[byte_idx]));
+		let mut mult_a: [_; WIDTH] = array_util::try_from_fn(|byte_idx| {
+			witness.new_column::<BinaryField8b>(mult_a[byte_idx])
+		})?;
 
 		let mult_a_u8 = mult_a.each_mut().map(|col| col.as_mut_slice::<u8>());
 
-		let mut mult_b: [_; WIDTH] =
-			array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(mult_b[byte_idx]));
+		let mut mult_b: [_; WIDTH] = array_util::try_from_fn(|byte_idx| {
+			witness.new_column::<BinaryField8b>(mult_b[byte_idx])
+		})?;
 
 		let mult_b_u8 = mult_b.each_mut().map(|col| col.as_mut_slice::<u8>());
 
@@ -110,15 +112,20 @@ fn main() -> Result<()> {
 	let constraint_system = builder.build().unwrap();
 	let backend = make_portable_backend();
 
-	let proof =
-		constraint_system::prove::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, &[], witness, &backend)?;
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		constraint_system::SecurityLevel::new(SECURITY_BITS, args.log_inv_rate as usize),
+		&[],
+		witness,
+		&backend,
+	)?;
 
 	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
 
@@ -130,8 +137,7 @@ fn main() -> Result<()> {
 		HasherChallenger<Groestl256>,
 	>(
 		&constraint_system.no_base_constraints(),
-		args.log_inv_rate as usize,
-		SECURITY_BITS,
+		constraint_system::SecurityLevel::new(SECURITY_BITS, args.log_inv_rate as usize),
 		&[],
 		proof,
 	)?;